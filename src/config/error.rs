@@ -1,19 +1,58 @@
 use thiserror::Error;
 
-/// Errors that can occur when working with configuration files.
+/// Errors that can occur when saving or loading configuration and other saved data.
 #[derive(Error, Debug)]
 pub enum ConfigError {
-    /// Failed to read the configuration file from disk.
-    #[error("Failed to read config file: {0}")]
-    Read(#[from] std::io::Error),
+    /// Nothing has been saved under this key or path yet.
+    #[error("Nothing saved at {0}")]
+    NotFound(String),
 
-    /// Failed to parse the TOML configuration file.
-    #[error("Failed to parse config: {0}")]
-    Parse(#[from] toml::de::Error),
+    /// Saved data could not be parsed.
+    #[error("Failed to parse: {0}")]
+    Parse(String),
 
-    /// Failed to serialize configuration to TOML.
-    #[error("Failed to serialize config: {0}")]
-    Serialize(#[from] toml::ser::Error),
+    /// Saved data comes from a newer build and doesn't match this build's layout.
+    #[error("Saved with version {found}, but this build reads up to version {supported}")]
+    VersionMismatch { found: u32, supported: u32 },
+
+    /// Storage could not be read or written.
+    #[error("Storage error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Data could not be serialized for saving.
+    #[error("Failed to serialize: {0}")]
+    Serialize(String),
+}
+
+impl ConfigError {
+    /// Returns true if saved data existed but had to be discarded.
+    pub const fn discards_saved_data(&self) -> bool {
+        matches!(self, Self::Parse(_) | Self::VersionMismatch { .. })
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(e: toml::de::Error) -> Self {
+        Self::Parse(e.to_string())
+    }
+}
+
+impl From<ron::error::SpannedError> for ConfigError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        Self::Parse(e.to_string())
+    }
+}
+
+impl From<toml::ser::Error> for ConfigError {
+    fn from(e: toml::ser::Error) -> Self {
+        Self::Serialize(e.to_string())
+    }
+}
+
+impl From<ron::Error> for ConfigError {
+    fn from(e: ron::Error) -> Self {
+        Self::Serialize(e.to_string())
+    }
 }
 
 /// Type alias for Results that can return ConfigError.
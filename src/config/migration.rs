@@ -0,0 +1,185 @@
+//! Config file schema versioning.
+//!
+//! Every saved `ConfigFile` records the schema `version` it was written with.
+//! On load the raw TOML is upgraded one version at a time before it is
+//! deserialized, so renamed or newly required fields carry the player's
+//! settings forward instead of failing to parse and falling back to defaults.
+//!
+//! To change the schema: bump `CONFIG_VERSION` and add a `migrate_vN_to_vN+1`
+//! step to `migrate` that rewrites the previous version's layout.
+
+use toml::{Table, Value};
+
+use super::error::{ConfigError, ConfigResult};
+use super::resources::ConfigFile;
+
+/// Schema version written by this build.
+pub const CONFIG_VERSION: u32 = 2;
+
+/// Version assumed for config files saved before versioning was added.
+const UNVERSIONED: u32 = 1;
+
+/// Parses a config file, upgrading it to the current schema first.
+///
+/// # Errors
+///
+/// Returns `ConfigError::Parse` if the contents are not valid TOML or the
+/// migrated table still does not match the current `ConfigFile` layout, or
+/// `ConfigError::VersionMismatch` if a file from a newer build doesn't match it.
+pub fn parse_config_file(contents: &str) -> ConfigResult<ConfigFile> {
+    let mut table: Table = toml::from_str(contents)?;
+    let version = file_version(&table);
+    migrate(&mut table);
+    Value::Table(table)
+        .try_into()
+        .map_err(|e: toml::de::Error| {
+            if version > CONFIG_VERSION {
+                ConfigError::VersionMismatch {
+                    found: version,
+                    supported: CONFIG_VERSION,
+                }
+            } else {
+                e.into()
+            }
+        })
+}
+
+/// Returns the schema version a raw config table was saved with.
+fn file_version(table: &Table) -> u32 {
+    table
+        .get("version")
+        .and_then(Value::as_integer)
+        .and_then(|version| u32::try_from(version).ok())
+        .unwrap_or(UNVERSIONED)
+}
+
+/// Upgrades a raw config table to `CONFIG_VERSION` in place.
+///
+/// Files from a newer build are left untouched and parsed as-is.
+pub fn migrate(table: &mut Table) {
+    let mut version = file_version(table);
+
+    if version < 2 {
+        migrate_v1_to_v2(table);
+        version = 2;
+    }
+
+    table.insert("version".to_string(), Value::Integer(version.into()));
+}
+
+/// Unversioned files may predate the `[game]` copies of the VSync and volume
+/// settings, brightness, and difficulty, none of which have serde defaults.
+/// Fills them from `[window]` and `[audio]` or with their defaults.
+fn migrate_v1_to_v2(table: &mut Table) {
+    let window_vsync = section(table, "window").and_then(|window| window.get("vsync").cloned());
+    let audio = section(table, "audio").cloned().unwrap_or_default();
+
+    let game = section_mut(table, "game");
+    if let Some(vsync) = window_vsync {
+        game.entry("vsync").or_insert(vsync);
+    }
+    for volume in ["master_volume", "music_volume", "sfx_volume"] {
+        if let Some(value) = audio.get(volume) {
+            game.entry(volume).or_insert(value.clone());
+        }
+    }
+    game.entry("brightness").or_insert(Value::Float(1.0));
+    game.entry("difficulty")
+        .or_insert(Value::String("Normal".to_string()));
+}
+
+/// Returns a section of the config, if present.
+fn section<'a>(table: &'a Table, name: &str) -> Option<&'a Table> {
+    table.get(name).and_then(Value::as_table)
+}
+
+/// Returns a section of the config, creating it if missing.
+fn section_mut<'a>(table: &'a mut Table, name: &str) -> &'a mut Table {
+    let entry = table
+        .entry(name)
+        .or_insert_with(|| Value::Table(Table::new()));
+    if !entry.is_table() {
+        *entry = Value::Table(Table::new());
+    }
+    entry.as_table_mut().expect("section was just made a table")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::resources::{Difficulty, GameConfig, VsyncMode};
+
+    /// A config saved before versioning, brightness, or the game-section copies
+    /// of the window and audio settings existed.
+    const V1_CONFIG: &str = r#"
+[window]
+vsync = "Adaptive"
+scale_factor = 1.0
+
+[audio]
+master_volume = 0.5
+music_volume = 0.25
+sfx_volume = 0.75
+
+[game]
+current_level = 7
+highest_level_achieved = 9
+"#;
+
+    #[test]
+    fn test_v1_config_migrates_to_current_version() {
+        let config = parse_config_file(V1_CONFIG).expect("v1 config should migrate");
+
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.window.vsync, VsyncMode::Adaptive);
+        assert_eq!(config.game.vsync, VsyncMode::Adaptive);
+        assert_eq!(config.game.master_volume, 0.5);
+        assert_eq!(config.game.music_volume, 0.25);
+        assert_eq!(config.game.sfx_volume, 0.75);
+        assert_eq!(config.game.brightness, 1.0);
+        assert_eq!(config.game.difficulty, Difficulty::Normal);
+        assert_eq!(config.game.current_level, 7);
+        assert_eq!(config.game.highest_level_achieved, 9);
+        assert_eq!(config.game.map_size, GameConfig::default().map_size);
+    }
+
+    #[test]
+    fn test_current_config_round_trips() {
+        let saved = ConfigFile {
+            game: GameConfig {
+                brightness: 1.5,
+                difficulty: Difficulty::Hard,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let contents = toml::to_string_pretty(&saved).unwrap();
+
+        let loaded = parse_config_file(&contents).unwrap();
+        assert_eq!(loaded.version, CONFIG_VERSION);
+        assert_eq!(loaded.game, saved.game);
+    }
+
+    #[test]
+    fn test_unparseable_config_is_a_parse_error() {
+        assert!(matches!(
+            parse_config_file("not = [toml"),
+            Err(ConfigError::Parse(_))
+        ));
+        // Valid TOML that doesn't match the current layout
+        assert!(matches!(
+            parse_config_file("[game]\ndifficulty = 3"),
+            Err(ConfigError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_unreadable_newer_config_is_a_version_mismatch() {
+        let newer = format!("version = {}\n[game]\ndifficulty = 3", CONFIG_VERSION + 1);
+        assert!(matches!(
+            parse_config_file(&newer),
+            Err(ConfigError::VersionMismatch { found, supported })
+                if found == CONFIG_VERSION + 1 && supported == CONFIG_VERSION
+        ));
+    }
+}
@@ -1,7 +1,10 @@
 mod error;
+mod migration;
 mod plugin;
 pub(crate) mod progress;
 mod resources;
+mod scores;
+pub(crate) mod spell_stats;
 mod storage;
 mod systems;
 
@@ -11,6 +14,10 @@ pub use error::{ConfigError, ConfigResult};
 pub use plugin::ConfigPlugin;
 #[allow(unused_imports)]
 pub use resources::{
-    AudioConfig, ConfigChanged, ConfigFile, Difficulty, GameConfig, SaveConfigEvent,
-    SaveDebounceTimer, VsyncMode, WindowConfig,
+    AudioConfig, ConfigChanged, ConfigFile, CorpseMode, Difficulty, GameConfig, GameMode,
+    GraphicsQuality, LoadFailures, MAX_UI_SCALE, MAX_UNIT_RENDER_SCALE, MIN_SCROLL_SPEED,
+    MIN_UI_SCALE, MIN_UNIT_RENDER_SCALE, MapSize, MouseBinding, RenderStyle, SaveConfigEvent,
+    SaveDebounceTimer, TimeOfDayMode, UiTheme, VsyncMode, WindowConfig,
 };
+pub use scores::Scores;
+pub use spell_stats::SpellStats;
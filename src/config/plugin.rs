@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 
 use super::systems::*;
+use crate::game::campaign::components::WaveCleared;
+use crate::state::{AppState, InGameState};
 
 /// Configuration plugin for managing game settings in browser localStorage.
 ///
@@ -9,7 +11,11 @@ use super::systems::*;
 /// - Applies settings to Bevy components (Window, GameConfig, etc.)
 /// - **Bevy components are the single source of truth** (no duplicate state)
 /// - Implements unified debouncing for all config changes
-/// - Persists changes to localStorage after 0.5s of inactivity
+/// - Persists changes to localStorage after 2s of inactivity
+/// - Loads and saves the `Scores` leaderboard (`scores.ron`)
+/// - Loads `SpellStats` (`spell_stats.ron`) and saves them on wave clear, game over
+///   and when a battle ends
+/// - Collects saved data that failed to load in `LoadFailures`
 ///
 /// # Architecture: Single Source of Truth
 ///
@@ -29,7 +35,7 @@ use super::systems::*;
 ///
 /// # Debouncing
 ///
-/// All config changes trigger a unified 0.5s debounce timer via the
+/// All config changes trigger a unified 2s debounce timer via the
 /// `ConfigChanged` message. Any system can trigger a debounced save:
 ///
 /// ```rust
@@ -48,7 +54,8 @@ use super::systems::*;
 ///
 /// Future triggers can easily be added by sending ConfigChanged.
 ///
-/// After 0.5s of inactivity, current state is saved to localStorage.
+/// After 2s of inactivity, current state is saved to localStorage. A slider
+/// drag that changes `GameConfig` every frame therefore produces one write.
 ///
 /// # Manual Save
 ///
@@ -70,6 +77,19 @@ impl Plugin for ConfigPlugin {
     fn build(&self, app: &mut App) {
         // Insert resources
         app.init_resource::<super::resources::SaveDebounceTimer>();
+        // Scores are loaded here rather than at Startup so the main menu can show them
+        let mut load_failures = super::resources::LoadFailures::default();
+        app.insert_resource(
+            load_failures
+                .recover("scores", super::scores::load_scores())
+                .unwrap_or_default(),
+        );
+        app.insert_resource(
+            load_failures
+                .recover("spell stats", super::spell_stats::load_spell_stats())
+                .unwrap_or_default(),
+        );
+        app.insert_resource(load_failures);
         // NOTE: ConfigFile is NOT a resource - it's only used for serialization
 
         // Add messages
@@ -81,6 +101,12 @@ impl Plugin for ConfigPlugin {
         app.add_systems(
             Update,
             (
+                // Keep the window in sync with settings (retries while it's missing)
+                apply_window_config,
+                // Keep the update rate on the frame cap
+                apply_frame_cap,
+                // Keep cameras and lights on the graphics quality preset
+                apply_graphics_quality,
                 // Change detection systems (emit ConfigChanged)
                 detect_window_resize,
                 detect_game_config_changes,
@@ -89,8 +115,14 @@ impl Plugin for ConfigPlugin {
                 // Save systems
                 save_config_on_debounce_timer,
                 save_config_on_event,
-            ),
+                save_scores_on_change,
+            )
+                .chain(),
         );
+        // Spell stats change every hit, so they're written at checkpoints instead
+        app.add_systems(Update, save_spell_stats.run_if(on_message::<WaveCleared>));
+        app.add_systems(OnEnter(InGameState::GameOver), save_spell_stats);
+        app.add_systems(OnExit(AppState::InGame), save_spell_stats);
     }
 
     fn name(&self) -> &str {
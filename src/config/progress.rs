@@ -4,6 +4,7 @@ use std::fmt::Write;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::error::{ConfigError, ConfigResult};
 use super::resources::GameConfig;
 use super::storage;
 
@@ -144,7 +145,7 @@ fn compute_signature(data: &ProgressData) -> String {
 }
 
 /// Saves signed progress to localStorage.
-pub fn save_signed_progress(config: &GameConfig) {
+pub fn save_signed_progress(config: &GameConfig) -> ConfigResult<()> {
     let data = ProgressData {
         current_level: config.current_level,
         highest_level_achieved: config.highest_level_achieved,
@@ -154,29 +155,26 @@ pub fn save_signed_progress(config: &GameConfig) {
     let signature = compute_signature(&data);
     let signed = SignedProgress { signature, data };
 
-    match toml::to_string_pretty(&signed) {
-        Ok(toml_string) => {
-            if let Err(e) = storage::save_progress(&toml_string) {
-                error!("Failed to save signed progress: {}", e);
-            }
-        }
-        Err(e) => {
-            error!("Failed to serialize signed progress: {}", e);
-        }
-    }
+    storage::save_progress(&toml::to_string_pretty(&signed)?)
 }
 
 /// Loads and verifies progress from localStorage.
-/// Returns None if missing, tampered, or invalid.
-pub fn load_verified_progress() -> Option<ProgressData> {
-    let contents = storage::load_progress().ok()?;
-    let signed: SignedProgress = toml::from_str(&contents).ok()?;
+///
+/// # Errors
+///
+/// Returns `ConfigError::NotFound` if no progress was saved, or
+/// `ConfigError::Parse` if it is invalid or its signature doesn't match
+/// (the progress has been tampered with).
+pub fn load_verified_progress() -> ConfigResult<ProgressData> {
+    let contents = storage::load_progress()?;
+    let signed: SignedProgress = toml::from_str(&contents)?;
 
     let expected = compute_signature(&signed.data);
     if expected == signed.signature {
-        Some(signed.data)
+        Ok(signed.data)
     } else {
-        warn!("Progress signature mismatch — progress has been tampered with, resetting");
-        None
+        Err(ConfigError::Parse(
+            "progress signature mismatch, it has been tampered with".to_string(),
+        ))
     }
 }
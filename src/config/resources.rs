@@ -1,6 +1,46 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
+
+use super::error::{ConfigError, ConfigResult};
+use super::migration::CONFIG_VERSION;
+use crate::game::units::wizard::components::Spell;
+
+/// Default number of spells that can be equipped at once.
+pub const DEFAULT_LOADOUT_SIZE: usize = 4;
+
+/// Default preparation time before each wave starts advancing (in seconds).
+pub const DEFAULT_PREPARATION_DURATION: f32 = 5.0;
+
+/// Default number of fixed simulation steps per second.
+pub const DEFAULT_SIMULATION_RATE: u32 = 60;
+
+/// Default cap on magic missiles, and separately on arrows, in flight at once.
+pub const DEFAULT_MAX_PROJECTILES: u32 = 400;
+
+/// Default seconds a corpse lasts before it's gone when corpses decay.
+pub const DEFAULT_CORPSE_DECAY_TIME: f32 = 30.0;
+
+/// Default seconds the cast button can be let go mid-cast without cancelling.
+///
+/// Off by default; players opt in from the settings menu.
+pub const DEFAULT_CAST_GRACE: f32 = 0.0;
+
+/// Smallest allowed unit render scale.
+pub const MIN_UNIT_RENDER_SCALE: f32 = 0.5;
+
+/// Largest allowed unit render scale.
+pub const MAX_UNIT_RENDER_SCALE: f32 = 2.0;
+
+/// Smallest allowed menu and HUD scale.
+pub const MIN_UI_SCALE: f32 = 0.75;
+
+/// Largest allowed menu and HUD scale.
+pub const MAX_UI_SCALE: f32 = 1.5;
+
+/// Slowest allowed menu scroll speed, so scrolling can never be switched off by accident.
+pub const MIN_SCROLL_SPEED: f32 = 0.25;
 
 /// Temporary structure for TOML serialization only.
 ///
@@ -9,8 +49,11 @@ use std::collections::HashMap;
 /// 2. Save: Read from Bevy components → serialize to localStorage
 ///
 /// During runtime, Bevy components are the single source of truth.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+/// Older files are upgraded to the current schema by `migration::parse_config_file`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConfigFile {
+    /// Schema version this file was written with
+    pub version: u32,
     /// Window configuration settings
     pub window: WindowConfig,
     /// Audio configuration settings
@@ -19,6 +62,17 @@ pub struct ConfigFile {
     pub game: GameConfig,
 }
 
+impl Default for ConfigFile {
+    fn default() -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            window: WindowConfig::default(),
+            audio: AudioConfig::default(),
+            game: GameConfig::default(),
+        }
+    }
+}
+
 /// VSync (vertical synchronization) mode options.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub enum VsyncMode {
@@ -31,6 +85,41 @@ pub enum VsyncMode {
     Adaptive,
 }
 
+/// Render quality preset trading visuals for GPU work.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum GraphicsQuality {
+    /// No anti-aliasing, no shadows, and a quarter of the trail effects
+    Low,
+    /// 4x MSAA without shadows (default)
+    #[default]
+    Medium,
+    /// 4x MSAA with sun shadows
+    High,
+}
+
+impl GraphicsQuality {
+    /// Returns the multisample anti-aliasing used by the 3D camera.
+    pub const fn msaa(self) -> Msaa {
+        match self {
+            GraphicsQuality::Low => Msaa::Off,
+            GraphicsQuality::Medium | GraphicsQuality::High => Msaa::Sample4,
+        }
+    }
+
+    /// Returns whether the sun casts shadows.
+    pub const fn shadows(self) -> bool {
+        matches!(self, GraphicsQuality::High)
+    }
+
+    /// Returns the fraction of the usual trail segments that may be alive at once.
+    pub const fn effects_density(self) -> f32 {
+        match self {
+            GraphicsQuality::Low => 0.25,
+            GraphicsQuality::Medium | GraphicsQuality::High => 1.0,
+        }
+    }
+}
+
 /// Window settings for serialization to/from TOML.
 ///
 /// For WASM builds, window size is controlled by the browser canvas via
@@ -42,6 +131,12 @@ pub enum VsyncMode {
 pub struct WindowConfig {
     /// VSync mode (on, off, or adaptive)
     pub vsync: VsyncMode,
+    /// Frame rate cap applied while VSync is off (None = uncapped)
+    #[serde(default)]
+    pub frame_cap: Option<u32>,
+    /// Render quality preset (anti-aliasing, shadows, effect density)
+    #[serde(default)]
+    pub graphics_quality: GraphicsQuality,
     /// Scale factor override (None uses OS default)
     pub scale_factor: Option<f64>,
 }
@@ -50,6 +145,8 @@ impl Default for WindowConfig {
     fn default() -> Self {
         Self {
             vsync: VsyncMode::default(),
+            frame_cap: None,
+            graphics_quality: GraphicsQuality::default(),
             scale_factor: Some(1.0),
         }
     }
@@ -93,6 +190,146 @@ pub enum Difficulty {
     Hard,
 }
 
+impl Difficulty {
+    /// Multiplier applied to damage dealt by attackers.
+    ///
+    /// Defender damage is never scaled.
+    pub const fn damage_multiplier(self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.75,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.25,
+        }
+    }
+
+    /// Multiplier applied to the wizard's mana regeneration.
+    pub const fn mana_regen_multiplier(self) -> f32 {
+        match self {
+            Difficulty::Easy => 1.25,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.8,
+        }
+    }
+}
+
+/// Battlefield size options.
+///
+/// Applied when the next battle starts.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum MapSize {
+    /// Standard battlefield (default)
+    #[default]
+    Standard,
+    /// Large battlefield - enemies spawn further out
+    Large,
+}
+
+/// Color themes for menus and HUD buttons.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum UiTheme {
+    /// Subtle grey buttons with light text (default)
+    #[default]
+    Default,
+    /// Black buttons with bright borders and white text for low-vision players
+    HighContrast,
+}
+
+/// How units are drawn on the battlefield.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum RenderStyle {
+    /// Upright sprites that turn to face the camera (default)
+    #[default]
+    Billboard,
+    /// Circles lying flat on the ground for a cleaner read when zoomed out
+    TopDown,
+}
+
+/// What is left behind when a unit dies.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum CorpseMode {
+    /// Corpses stay for the rest of the battle (default)
+    #[default]
+    Persistent,
+    /// Corpses fade out and disappear after `GameConfig::corpse_decay_time`
+    Decay,
+    /// Units vanish on death: no corpses to slow units or raise
+    None,
+}
+
+/// Lighting over the battlefield: a fixed time of day or a slow day/night cycle.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum TimeOfDayMode {
+    /// Low, warm sun from the east
+    Dawn,
+    /// High, white sun (default)
+    #[default]
+    Noon,
+    /// Low, orange sun from the west
+    Dusk,
+    /// Dim, cool moonlight that darkens the scene
+    Night,
+    /// Starts each battle at dawn and cycles through the day over a few minutes
+    Cycle,
+}
+
+/// Which physical mouse button casts spells; the other one cancels.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum MouseBinding {
+    /// Left button casts, right button cancels (default)
+    #[default]
+    LeftCast,
+    /// Right button casts, left button cancels, for left-handed players
+    RightCast,
+}
+
+impl MouseBinding {
+    /// Returns the physical button bound to casting.
+    pub const fn cast_button(self) -> MouseButton {
+        match self {
+            MouseBinding::LeftCast => MouseButton::Left,
+            MouseBinding::RightCast => MouseButton::Right,
+        }
+    }
+
+    /// Returns the physical button bound to cancelling and unit orders.
+    pub const fn cancel_button(self) -> MouseButton {
+        match self {
+            MouseBinding::LeftCast => MouseButton::Right,
+            MouseBinding::RightCast => MouseButton::Left,
+        }
+    }
+}
+
+/// Game modes selectable on the loadout screen.
+///
+/// Stored in the config so the game over screen reports the matching summary.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum GameMode {
+    /// Level-based waves that raise the level as each one is cleared (default)
+    #[default]
+    Campaign,
+    /// Escalating hordes with no fixed end, scored by time survived and kills
+    Endless,
+    /// Practice sandbox with no waves and no win or loss; units are spawned on demand
+    Sandbox,
+}
+
+impl GameMode {
+    /// Returns every game mode in menu order.
+    pub const fn all() -> &'static [GameMode] {
+        &[GameMode::Campaign, GameMode::Endless, GameMode::Sandbox]
+    }
+
+    /// Returns the display name of the game mode.
+    pub const fn name(self) -> &'static str {
+        match self {
+            GameMode::Campaign => "Campaign",
+            GameMode::Endless => "Endless Horde",
+            GameMode::Sandbox => "Practice",
+        }
+    }
+}
+
 /// Default current level for serde deserialization.
 fn default_current_level() -> u32 {
     1
@@ -108,13 +345,114 @@ fn default_efficiency_ratios() -> HashMap<String, f32> {
     HashMap::new()
 }
 
+/// Default spell loadout for serde deserialization (the first spells in the book).
+fn default_spell_loadout() -> Vec<Spell> {
+    Spell::all()
+        .iter()
+        .copied()
+        .take(DEFAULT_LOADOUT_SIZE)
+        .collect()
+}
+
+/// Default starting spell for serde deserialization.
+fn default_starting_spell() -> Spell {
+    Spell::MagicMissile
+}
+
+/// Default loadout size for serde deserialization.
+fn default_loadout_size() -> usize {
+    DEFAULT_LOADOUT_SIZE
+}
+
+/// Default damage number visibility for serde deserialization.
+fn default_show_damage_numbers() -> bool {
+    true
+}
+
+/// Default spell trail visibility for serde deserialization.
+fn default_show_spell_trails() -> bool {
+    true
+}
+
+/// Default personal ward setting for serde deserialization.
+fn default_personal_ward() -> bool {
+    true
+}
+
+/// Default berserk setting for serde deserialization.
+fn default_berserk() -> bool {
+    false
+}
+
+/// Default menu background battle setting for serde deserialization.
+fn default_menu_background() -> bool {
+    true
+}
+
+/// Default pause-on-focus-loss setting for serde deserialization.
+fn default_pause_on_focus_loss() -> bool {
+    true
+}
+
+/// Default unit render scale for serde deserialization.
+fn default_unit_render_scale() -> f32 {
+    1.0
+}
+
+/// Default menu and HUD scale for serde deserialization.
+fn default_ui_scale() -> f32 {
+    1.0
+}
+
+/// Default menu scroll speed for serde deserialization.
+fn default_scroll_speed() -> f32 {
+    1.0
+}
+
+/// Default preparation duration for serde deserialization.
+fn default_preparation_duration() -> f32 {
+    DEFAULT_PREPARATION_DURATION
+}
+
+/// Default simulation rate for serde deserialization.
+fn default_simulation_rate() -> u32 {
+    DEFAULT_SIMULATION_RATE
+}
+
+/// Default projectile cap for serde deserialization.
+fn default_max_projectiles() -> Option<u32> {
+    Some(DEFAULT_MAX_PROJECTILES)
+}
+
+/// Default corpse decay time for serde deserialization.
+fn default_corpse_decay_time() -> f32 {
+    DEFAULT_CORPSE_DECAY_TIME
+}
+
+/// Default cast grace for serde deserialization.
+fn default_cast_grace() -> f32 {
+    DEFAULT_CAST_GRACE
+}
+
 /// Game configuration resource - runtime source of truth for all user settings.
 ///
 /// This IS a runtime Bevy resource that holds all user-configurable settings:
 /// - VSync mode
+/// - Graphics quality preset
 /// - Audio volumes (master, music, SFX)
 /// - Game difficulty
+/// - Battlefield size
+/// - Game mode
+/// - Spell loadout
+/// - Floating damage numbers
 /// - Global brightness
+/// - UI color theme
+/// - Unit rendering style
+/// - Menu and HUD scale
+/// - Battle simulation rate
+/// - Corpse handling
+/// - Battlefield time of day
+/// - Projectile cap
 ///
 /// Window size/mode is NOT included as it's managed by the browser canvas.
 /// Changes to this resource are automatically persisted to localStorage.
@@ -134,6 +472,12 @@ fn default_efficiency_ratios() -> HashMap<String, f32> {
 pub struct GameConfig {
     /// VSync mode (on, off, or adaptive)
     pub vsync: VsyncMode,
+    /// Frame rate cap applied while VSync is off (None = uncapped)
+    #[serde(default)]
+    pub frame_cap: Option<u32>,
+    /// Render quality preset (anti-aliasing, shadows, effect density)
+    #[serde(default)]
+    pub graphics_quality: GraphicsQuality,
     /// Master volume level (0.0 = muted, 1.0 = full volume)
     pub master_volume: f32,
     /// Music track volume level (0.0 = muted, 1.0 = full volume)
@@ -142,8 +486,32 @@ pub struct GameConfig {
     pub sfx_volume: f32,
     /// Game difficulty setting
     pub difficulty: Difficulty,
+    /// Battlefield size used for the next battle
+    #[serde(default)]
+    pub map_size: MapSize,
+    /// Game mode used for the next battle
+    #[serde(default)]
+    pub game_mode: GameMode,
     /// Global brightness multiplier (0.1 = darkest to prevent soft-lock, 1.0 = normal, 2.0 = brightest)
     pub brightness: f32,
+    /// Color theme for menus and HUD buttons
+    #[serde(default)]
+    pub ui_theme: UiTheme,
+    /// Whether units face the camera or lie flat on the ground
+    #[serde(default)]
+    pub render_style: RenderStyle,
+    /// Visual size multiplier for unit sprites (0.5-2.0); hitboxes are unaffected
+    #[serde(default = "default_unit_render_scale")]
+    pub unit_render_scale: f32,
+    /// Size multiplier for menus and the HUD (0.75-1.5); the battlefield view is unaffected
+    #[serde(default = "default_ui_scale")]
+    pub ui_scale: f32,
+    /// Whether the mouse wheel scrolls menus the opposite way (for natural scrolling)
+    #[serde(default)]
+    pub scroll_invert: bool,
+    /// Menu scroll speed multiplier (never below `MIN_SCROLL_SPEED`)
+    #[serde(default = "default_scroll_speed")]
+    pub scroll_speed: f32,
     /// Current level - restored on game start after page reload
     #[serde(default = "default_current_level")]
     pub current_level: u32,
@@ -154,24 +522,276 @@ pub struct GameConfig {
     /// Key: level number as string, Value: efficiency ratio (0.0 = all defenders lost, 1.0 = no defenders lost)
     #[serde(default = "default_efficiency_ratios")]
     pub efficiency_ratios: HashMap<String, f32>,
+    /// Spells equipped for battle, chosen on the loadout screen
+    #[serde(default = "default_spell_loadout")]
+    pub spell_loadout: Vec<Spell>,
+    /// Maximum number of spells that can be equipped at once
+    #[serde(default = "default_loadout_size")]
+    pub loadout_size: usize,
+    /// Spell primed when a battle starts (falls back to the first equipped spell)
+    #[serde(default = "default_starting_spell")]
+    pub default_spell: Spell,
+    /// Spells starred in the spell book, listed ahead of the rest
+    #[serde(default)]
+    pub favorite_spells: Vec<Spell>,
+    /// Custom spell book order (spells missing from it follow in book order)
+    #[serde(default)]
+    pub spell_order: Vec<Spell>,
+    /// Whether floating damage numbers are shown above units that take damage
+    #[serde(default = "default_show_damage_numbers")]
+    pub show_damage_numbers: bool,
+    /// Whether projectiles leave fading trails (turn off on low-spec machines)
+    #[serde(default = "default_show_spell_trails")]
+    pub show_spell_trails: bool,
+    /// Whether the HUD shows a bar estimating which side is winning
+    #[serde(default)]
+    pub show_battle_hint: bool,
+    /// Whether wind pushes arrows off course (makes archers less accurate)
+    #[serde(default)]
+    pub wind: bool,
+    /// Whether a ward around the wizard pushes back nearby enemies (turn off for a harder game)
+    #[serde(default = "default_personal_ward")]
+    pub personal_ward: bool,
+    /// Whether units near death go berserk and hit harder
+    #[serde(default = "default_berserk")]
+    pub berserk: bool,
+    /// Whether attacker groups advance as shield wall formations from level 8
+    #[serde(default)]
+    pub shield_walls: bool,
+    /// Whether a small idle battle plays behind the main menu (turn off on low-spec machines)
+    #[serde(default = "default_menu_background")]
+    pub menu_background: bool,
+    /// Whether the battle pauses when the game window loses focus
+    #[serde(default = "default_pause_on_focus_loss")]
+    pub pause_on_focus_loss: bool,
+    /// Which mouse button casts spells (the other cancels)
+    #[serde(default)]
+    pub mouse_binding: MouseBinding,
+    /// Seconds the cast button can be let go mid-cast and pressed again to resume (0 = cancel at once)
+    #[serde(default = "default_cast_grace")]
+    pub cast_grace: f32,
+    /// Whether the first-run tutorial has been completed (cleared to replay it)
+    #[serde(default)]
+    pub tutorial_completed: bool,
+    /// Seconds attackers stay frozen at the start of each wave (0 disables the countdown)
+    #[serde(default = "default_preparation_duration")]
+    pub preparation_duration: f32,
+    /// Fixed steps per second for movement, projectiles, and combat
+    #[serde(default = "default_simulation_rate")]
+    pub simulation_rate: u32,
+    /// What is left behind when a unit dies
+    #[serde(default)]
+    pub corpse_mode: CorpseMode,
+    /// Seconds before a corpse is gone when `corpse_mode` is `Decay`
+    #[serde(default = "default_corpse_decay_time")]
+    pub corpse_decay_time: f32,
+    /// Battlefield lighting, applied at the start of each battle
+    #[serde(default)]
+    pub time_of_day: TimeOfDayMode,
+    /// Most magic missiles, and separately most arrows, in flight at once (None = unlimited)
+    #[serde(default = "default_max_projectiles")]
+    pub max_projectiles: Option<u32>,
 }
 
 impl Default for GameConfig {
     fn default() -> Self {
         Self {
             vsync: VsyncMode::default(),
+            frame_cap: None,
+            graphics_quality: GraphicsQuality::default(),
             master_volume: 1.0,
             music_volume: 0.8,
             sfx_volume: 0.8,
             difficulty: Difficulty::default(),
+            map_size: MapSize::default(),
+            game_mode: GameMode::default(),
             brightness: 1.0,
+            ui_theme: UiTheme::default(),
+            render_style: RenderStyle::default(),
+            unit_render_scale: 1.0,
+            ui_scale: default_ui_scale(),
+            scroll_invert: false,
+            scroll_speed: 1.0,
             current_level: 1,
             highest_level_achieved: 1,
             efficiency_ratios: HashMap::new(),
+            spell_loadout: default_spell_loadout(),
+            loadout_size: DEFAULT_LOADOUT_SIZE,
+            default_spell: default_starting_spell(),
+            favorite_spells: Vec::new(),
+            spell_order: Vec::new(),
+            show_damage_numbers: true,
+            show_spell_trails: true,
+            show_battle_hint: false,
+            wind: false,
+            personal_ward: true,
+            berserk: default_berserk(),
+            shield_walls: false,
+            menu_background: true,
+            pause_on_focus_loss: true,
+            mouse_binding: MouseBinding::default(),
+            cast_grace: DEFAULT_CAST_GRACE,
+            tutorial_completed: false,
+            preparation_duration: DEFAULT_PREPARATION_DURATION,
+            simulation_rate: DEFAULT_SIMULATION_RATE,
+            corpse_mode: CorpseMode::default(),
+            corpse_decay_time: DEFAULT_CORPSE_DECAY_TIME,
+            time_of_day: TimeOfDayMode::default(),
+            max_projectiles: default_max_projectiles(),
         }
     }
 }
 
+impl GameConfig {
+    /// Returns the equipped spells in the player's spell book order.
+    ///
+    /// Spells beyond `loadout_size` are ignored in case the limit was lowered.
+    pub fn equipped_spells(&self) -> impl Iterator<Item = Spell> + '_ {
+        self.ordered_spells()
+            .into_iter()
+            .filter(|spell| self.is_equipped(*spell))
+            .take(self.loadout_size)
+    }
+
+    /// Returns the spell to prime when a battle starts.
+    ///
+    /// Uses `default_spell` if it is equipped, otherwise the first equipped spell.
+    pub fn starting_spell(&self) -> Option<Spell> {
+        if self
+            .equipped_spells()
+            .any(|spell| spell == self.default_spell)
+        {
+            Some(self.default_spell)
+        } else {
+            self.equipped_spells().next()
+        }
+    }
+
+    /// Makes the equipped spell after the current starting spell the new default.
+    pub fn cycle_starting_spell(&mut self) {
+        let equipped: Vec<Spell> = self.equipped_spells().collect();
+        let next = self
+            .starting_spell()
+            .and_then(|current| equipped.iter().position(|spell| *spell == current))
+            .map(|index| equipped[(index + 1) % equipped.len()]);
+        if let Some(next) = next {
+            self.default_spell = next;
+        }
+    }
+
+    /// Returns the equipped spell `steps` places after `current`, wrapping around.
+    ///
+    /// Negative steps go backward. Without a current equipped spell, stepping
+    /// forward starts at the first equipped spell and backward at the last.
+    pub fn step_equipped_spell(&self, current: Option<Spell>, steps: i32) -> Option<Spell> {
+        let equipped: Vec<Spell> = self.equipped_spells().collect();
+        if equipped.is_empty() {
+            return None;
+        }
+
+        let start = match current.and_then(|current| equipped.iter().position(|s| *s == current)) {
+            Some(index) => index as i32,
+            None if steps > 0 => -1,
+            None => 0,
+        };
+        let index = (start + steps).rem_euclid(equipped.len() as i32);
+        Some(equipped[index as usize])
+    }
+
+    /// Returns every spell in the player's order: favorites first, then the rest.
+    ///
+    /// Within each group spells follow `spell_order`, with any spells it doesn't
+    /// list appended in spell book order.
+    pub fn ordered_spells(&self) -> Vec<Spell> {
+        let mut order: Vec<Spell> = Vec::with_capacity(Spell::all().len());
+        for spell in self.spell_order.iter().chain(Spell::all()) {
+            if !order.contains(spell) {
+                order.push(*spell);
+            }
+        }
+
+        // Stable sort keeps the custom order within each group
+        order.sort_by_key(|spell| !self.is_favorite(*spell));
+        order
+    }
+
+    /// Returns true if the spell is starred as a favorite.
+    pub fn is_favorite(&self, spell: Spell) -> bool {
+        self.favorite_spells.contains(&spell)
+    }
+
+    /// Stars or unstars a spell.
+    pub fn toggle_favorite(&mut self, spell: Spell) {
+        if let Some(index) = self.favorite_spells.iter().position(|s| *s == spell) {
+            self.favorite_spells.remove(index);
+        } else {
+            self.favorite_spells.push(spell);
+        }
+    }
+
+    /// Moves an equipped spell `offset` places later (or earlier, if negative)
+    /// among the equipped spells, as the spell book shows them.
+    ///
+    /// Unequipped spells in between are stepped over. Spells stay within their
+    /// group, so a favorite can't move behind the non-favorites and vice versa.
+    pub fn move_spell(&mut self, spell: Spell, offset: isize) {
+        let equipped: Vec<Spell> = self.equipped_spells().collect();
+        let Some(index) = equipped.iter().position(|s| *s == spell) else {
+            return;
+        };
+
+        let favorites = equipped.iter().filter(|s| self.is_favorite(**s)).count();
+        let (first, last) = if self.is_favorite(spell) {
+            (0, favorites - 1)
+        } else {
+            (favorites, equipped.len() - 1)
+        };
+        let target = index.saturating_add_signed(offset).clamp(first, last);
+        if target == index {
+            return;
+        }
+
+        // Take the place of the equipped spell being passed in the full order
+        let mut order = self.ordered_spells();
+        order.retain(|s| *s != spell);
+        let Some(mut position) = order.iter().position(|s| *s == equipped[target]) else {
+            return;
+        };
+        if target > index {
+            position += 1;
+        }
+        order.insert(position, spell);
+        self.spell_order = order;
+    }
+
+    /// Returns true if the spell is in the loadout.
+    pub fn is_equipped(&self, spell: Spell) -> bool {
+        self.spell_loadout.contains(&spell)
+    }
+
+    /// Equips or unequips a spell.
+    ///
+    /// Returns false if the spell could not be equipped because the loadout is full.
+    pub fn toggle_spell(&mut self, spell: Spell) -> bool {
+        if let Some(index) = self.spell_loadout.iter().position(|s| *s == spell) {
+            self.spell_loadout.remove(index);
+            return true;
+        }
+
+        if self.spell_loadout.len() >= self.loadout_size {
+            return false;
+        }
+
+        self.spell_loadout.push(spell);
+        true
+    }
+
+    /// Returns true if the loadout can be taken into battle (at least one spell equipped).
+    pub fn has_valid_loadout(&self) -> bool {
+        self.equipped_spells().next().is_some()
+    }
+}
+
 /// Message that triggers saving the current configuration to localStorage.
 ///
 /// Send this message when you want to manually persist the current
@@ -183,7 +803,7 @@ pub struct SaveConfigEvent;
 ///
 /// Send this message whenever any configuration changes that should be
 /// persisted to localStorage. The ConfigPlugin will debounce these messages
-/// and save after 0.5s of inactivity.
+/// and save after `SAVE_DEBOUNCE_SECONDS` of inactivity.
 ///
 /// # Examples
 ///
@@ -202,23 +822,261 @@ pub struct SaveConfigEvent;
 #[derive(Message)]
 pub struct ConfigChanged;
 
+/// Seconds of inactivity before pending config changes are written to storage.
+pub const SAVE_DEBOUNCE_SECONDS: f32 = 2.0;
+
 /// Resource that tracks debounce timer for automatic config saving.
 ///
-/// This prevents excessive file writes during window resizing by waiting
-/// for a period of inactivity before saving to disk.
+/// This prevents excessive writes while settings change continuously (window
+/// resizing, slider drags) by waiting for a period of inactivity before saving.
+/// Every change marks the config dirty and restarts the timer, so a burst of
+/// changes is coalesced into a single write.
 #[derive(Resource)]
 pub struct SaveDebounceTimer {
-    /// Timer that counts down after a window resize event
+    /// Timer that restarts on every config change
     pub timer: Timer,
     /// Whether a save is pending after the timer expires
     pub pending: bool,
 }
 
+impl SaveDebounceTimer {
+    /// Marks the config dirty and restarts the debounce interval.
+    pub fn mark_dirty(&mut self) {
+        self.timer.reset();
+        self.pending = true;
+    }
+
+    /// Advances the timer and returns true when a pending save should be written.
+    ///
+    /// Clears the pending flag when it fires, so each burst of changes
+    /// produces exactly one save.
+    pub fn tick(&mut self, delta: Duration) -> bool {
+        if !self.pending {
+            return false;
+        }
+
+        self.timer.tick(delta);
+        if self.timer.is_finished() {
+            self.pending = false;
+            return true;
+        }
+        false
+    }
+}
+
 impl Default for SaveDebounceTimer {
     fn default() -> Self {
         Self {
-            timer: Timer::from_seconds(2.0, TimerMode::Once),
+            timer: Timer::from_seconds(SAVE_DEBOUNCE_SECONDS, TimerMode::Once),
             pending: false,
         }
     }
 }
+
+/// Saved data that couldn't be loaded this session and was reset to defaults.
+///
+/// Every loader reports its failures here so they are logged the same way.
+/// Failures that discarded saved data (corrupt or from a newer build) are
+/// kept and shown on the main menu, so the reset isn't silent.
+#[derive(Resource, Debug, Default)]
+pub struct LoadFailures {
+    pub messages: Vec<String>,
+}
+
+impl LoadFailures {
+    /// Logs a failed load of `what`, keeping a message if saved data was lost.
+    ///
+    /// Missing data is expected on a first run and only noted.
+    pub fn report(&mut self, what: &str, error: &ConfigError) {
+        match error {
+            ConfigError::NotFound(_) => info!("No saved {}, using defaults", what),
+            _ if error.discards_saved_data() => {
+                warn!("Failed to load {}: {}, resetting to defaults", what, error);
+                self.messages
+                    .push(format!("Couldn't load {what}, using defaults ({error})"));
+            }
+            _ => warn!("Failed to load {}: {}, using defaults", what, error),
+        }
+    }
+
+    /// Returns the loaded value, or reports the failure and returns None.
+    pub fn recover<T>(&mut self, what: &str, result: ConfigResult<T>) -> Option<T> {
+        match result {
+            Ok(value) => {
+                info!("Loaded {}", what);
+                Some(value)
+            }
+            Err(e) => {
+                self.report(what, &e);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_loadout_is_full() {
+        let config = GameConfig::default();
+        assert_eq!(config.equipped_spells().count(), DEFAULT_LOADOUT_SIZE);
+        assert!(config.has_valid_loadout());
+    }
+
+    #[test]
+    fn test_toggle_respects_loadout_size() {
+        let mut config = GameConfig::default();
+        let extra = Spell::all()[DEFAULT_LOADOUT_SIZE];
+
+        assert!(!config.toggle_spell(extra));
+        assert!(!config.is_equipped(extra));
+
+        // Freeing a slot allows the new spell
+        assert!(config.toggle_spell(Spell::MagicMissile));
+        assert!(config.toggle_spell(extra));
+        assert!(config.is_equipped(extra));
+        assert!(!config.is_equipped(Spell::MagicMissile));
+    }
+
+    #[test]
+    fn test_empty_loadout_is_invalid() {
+        let mut config = GameConfig::default();
+        for spell in config.spell_loadout.clone() {
+            config.toggle_spell(spell);
+        }
+
+        assert_eq!(config.equipped_spells().count(), 0);
+        assert!(!config.has_valid_loadout());
+    }
+
+    #[test]
+    fn test_equipped_spells_follow_spell_book_order() {
+        let config = GameConfig {
+            spell_loadout: vec![Spell::FrostNova, Spell::MagicMissile],
+            ..Default::default()
+        };
+
+        let equipped: Vec<Spell> = config.equipped_spells().collect();
+        assert_eq!(equipped, vec![Spell::MagicMissile, Spell::FrostNova]);
+    }
+
+    #[test]
+    fn test_starting_spell_falls_back_to_first_equipped() {
+        let mut config = GameConfig {
+            spell_loadout: vec![Spell::Fireball, Spell::FrostNova],
+            default_spell: Spell::FrostNova,
+            ..Default::default()
+        };
+        assert_eq!(config.starting_spell(), Some(Spell::FrostNova));
+
+        config.cycle_starting_spell();
+        assert_eq!(config.starting_spell(), Some(Spell::Fireball));
+
+        // Unequipping the default falls back to the first equipped spell
+        config.default_spell = Spell::MagicMissile;
+        assert_eq!(config.starting_spell(), Some(Spell::Fireball));
+    }
+
+    #[test]
+    fn test_favorites_lead_the_spell_order() {
+        let mut config = GameConfig {
+            spell_loadout: vec![Spell::MagicMissile, Spell::FrostNova],
+            ..Default::default()
+        };
+
+        config.toggle_favorite(Spell::FrostNova);
+        assert_eq!(config.ordered_spells()[0], Spell::FrostNova);
+        let equipped: Vec<Spell> = config.equipped_spells().collect();
+        assert_eq!(equipped, vec![Spell::FrostNova, Spell::MagicMissile]);
+
+        config.toggle_favorite(Spell::FrostNova);
+        assert_eq!(config.ordered_spells(), Spell::all().to_vec());
+    }
+
+    #[test]
+    fn test_moving_spells_stays_within_favorite_group() {
+        let mut config = GameConfig::default();
+        let all = Spell::all();
+
+        config.move_spell(all[1], -1);
+        assert_eq!(config.ordered_spells()[..2], [all[1], all[0]]);
+
+        // A favorite can't be moved behind the non-favorites
+        config.toggle_favorite(all[2]);
+        config.move_spell(all[2], 5);
+        assert_eq!(config.ordered_spells()[0], all[2]);
+
+        // ...and a non-favorite can't jump ahead of it
+        config.move_spell(all[0], -5);
+        assert_eq!(config.ordered_spells()[..2], [all[2], all[0]]);
+    }
+
+    #[test]
+    fn test_moving_spells_steps_over_unequipped_spells() {
+        let all = Spell::all();
+        let mut config = GameConfig {
+            spell_loadout: vec![all[0], all[3], all[5]],
+            ..default()
+        };
+        let equipped = |config: &GameConfig| config.equipped_spells().collect::<Vec<_>>();
+
+        config.move_spell(all[3], -1);
+        assert_eq!(equipped(&config), [all[3], all[0], all[5]]);
+
+        config.move_spell(all[3], 1);
+        assert_eq!(equipped(&config), [all[0], all[3], all[5]]);
+
+        config.move_spell(all[0], 2);
+        assert_eq!(equipped(&config), [all[3], all[5], all[0]]);
+    }
+
+    #[test]
+    fn test_rapid_changes_coalesce_into_one_save() {
+        let mut debounce = SaveDebounceTimer::default();
+        let frame = Duration::from_secs_f32(1.0 / 60.0);
+        let mut saves = 0;
+
+        // A slider drag changes the config every frame for two seconds
+        for _ in 0..120 {
+            debounce.mark_dirty();
+            if debounce.tick(frame) {
+                saves += 1;
+            }
+        }
+        assert_eq!(saves, 0);
+
+        // Then the config sits idle well past the debounce window
+        for _ in 0..600 {
+            if debounce.tick(frame) {
+                saves += 1;
+            }
+        }
+        assert_eq!(saves, 1);
+        assert!(!debounce.pending);
+    }
+
+    #[test]
+    fn test_no_save_without_changes() {
+        let mut debounce = SaveDebounceTimer::default();
+        assert!(!debounce.tick(Duration::from_secs_f32(SAVE_DEBOUNCE_SECONDS * 2.0)));
+    }
+
+    #[test]
+    fn test_only_discarded_saves_are_shown() {
+        let mut failures = LoadFailures::default();
+
+        let missing: ConfigResult<u32> = Err(ConfigError::NotFound("scores.ron".to_string()));
+        assert_eq!(failures.recover("scores", missing), None);
+        let unreadable: ConfigResult<u32> = Err(std::io::Error::other("disk on fire").into());
+        assert_eq!(failures.recover("scores", unreadable), None);
+        assert!(failures.messages.is_empty());
+
+        let corrupt: ConfigResult<u32> = Err(ConfigError::Parse("bad".to_string()));
+        assert_eq!(failures.recover("spell stats", corrupt), None);
+        assert_eq!(failures.recover("scores", Ok(3)), Some(3));
+        assert_eq!(failures.messages.len(), 1);
+        assert!(failures.messages[0].contains("spell stats"));
+    }
+}
@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::error::ConfigResult;
+use super::storage;
+
+/// Persistent personal bests, stored in localStorage as `scores.ron`.
+///
+/// Loaded once when the config plugin is built so the main menu can show it
+/// immediately, and saved whenever a record is beaten.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Scores {
+    /// Highest level the player has played.
+    pub best_level: u32,
+    /// Most attackers killed in a single game.
+    pub most_attackers_killed: u32,
+    /// Most undead killed in a single game.
+    pub most_undead_killed: u32,
+    /// True if the current game has beaten any record so far (not persisted).
+    ///
+    /// A game can be recorded several times (each cleared wave, game over, and
+    /// leaving the battle), so this stays set until `start_game` clears it.
+    #[serde(skip)]
+    pub new_best: bool,
+}
+
+impl Scores {
+    /// Records the result of a game, keeping the best of each stat.
+    ///
+    /// Returns true if this call beat any record.
+    pub fn record(&mut self, level: u32, attackers_killed: u32, undead_killed: u32) -> bool {
+        let mut beaten = false;
+
+        if level > self.best_level {
+            self.best_level = level;
+            beaten = true;
+        }
+        if attackers_killed > self.most_attackers_killed {
+            self.most_attackers_killed = attackers_killed;
+            beaten = true;
+        }
+        if undead_killed > self.most_undead_killed {
+            self.most_undead_killed = undead_killed;
+            beaten = true;
+        }
+
+        self.new_best |= beaten;
+        beaten
+    }
+
+    /// Forgets whether the previous game beat a record.
+    pub fn start_game(&mut self) {
+        self.new_best = false;
+    }
+}
+
+/// Loads scores from localStorage.
+///
+/// # Errors
+///
+/// Returns `ConfigError::NotFound` on first run, `ConfigError::Parse` if the
+/// stored scores are corrupt, or `ConfigError::Io` if localStorage can't be read.
+pub fn load_scores() -> ConfigResult<Scores> {
+    let contents = storage::load_scores()?;
+    Ok(ron::from_str(&contents)?)
+}
+
+/// Saves scores to localStorage.
+pub fn save_scores(scores: &Scores) -> ConfigResult<()> {
+    let ron_string = ron::ser::to_string_pretty(scores, ron::ser::PrettyConfig::default())?;
+    storage::save_scores(&ron_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_best_survives_repeat_records_until_next_game() {
+        let mut scores = Scores::default();
+
+        // A cleared wave sets a record, then game over records the same run again
+        assert!(scores.record(3, 10, 0));
+        assert!(!scores.record(3, 10, 0));
+        assert!(scores.new_best);
+
+        scores.start_game();
+        assert!(!scores.record(2, 5, 0));
+        assert!(!scores.new_best);
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::error::ConfigResult;
+use super::storage;
+use crate::game::units::wizard::components::Spell;
+
+/// Lifetime usage of a single spell.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SpellUsage {
+    /// Number of resolved casts.
+    pub casts: u32,
+    /// Total damage dealt, after resistances.
+    pub damage: f64,
+}
+
+impl SpellUsage {
+    /// Average damage per cast, or zero if the spell was never cast.
+    pub fn damage_per_cast(&self) -> f64 {
+        if self.casts == 0 {
+            0.0
+        } else {
+            self.damage / f64::from(self.casts)
+        }
+    }
+}
+
+/// Per-spell usage across every run, stored in localStorage as `spell_stats.ron`.
+///
+/// Loaded once when the config plugin is built so the statistics screen can
+/// show it, updated as spells resolve and deal damage, and saved when a battle ends.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SpellStats {
+    pub spells: HashMap<Spell, SpellUsage>,
+}
+
+impl SpellStats {
+    /// Returns the recorded usage of `spell` (zeroes if it was never cast).
+    pub fn usage(&self, spell: Spell) -> SpellUsage {
+        self.spells.get(&spell).copied().unwrap_or_default()
+    }
+
+    /// Counts one resolved cast of `spell`.
+    pub fn record_cast(&mut self, spell: Spell) {
+        self.spells.entry(spell).or_default().casts += 1;
+    }
+
+    /// Adds damage dealt by `spell`.
+    pub fn record_damage(&mut self, spell: Spell, amount: f32) {
+        self.spells.entry(spell).or_default().damage += f64::from(amount);
+    }
+}
+
+/// Parses spell statistics stored as RON.
+pub fn parse_spell_stats(contents: &str) -> ConfigResult<SpellStats> {
+    Ok(ron::from_str(contents)?)
+}
+
+/// Serializes spell statistics to the RON stored in localStorage.
+pub fn serialize_spell_stats(stats: &SpellStats) -> ConfigResult<String> {
+    Ok(ron::ser::to_string_pretty(
+        stats,
+        ron::ser::PrettyConfig::default(),
+    )?)
+}
+
+/// Loads spell statistics from localStorage.
+///
+/// # Errors
+///
+/// Returns `ConfigError::NotFound` on first run, `ConfigError::Parse` if the
+/// stored statistics are corrupt, or `ConfigError::Io` if localStorage can't be read.
+pub fn load_spell_stats() -> ConfigResult<SpellStats> {
+    parse_spell_stats(&storage::load_spell_stats()?)
+}
+
+/// Saves spell statistics to localStorage.
+pub fn save_spell_stats(stats: &SpellStats) -> ConfigResult<()> {
+    storage::save_spell_stats(&serialize_spell_stats(stats)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigError;
+
+    #[test]
+    fn test_spell_stats_survive_a_round_trip() {
+        let mut stats = SpellStats::default();
+        stats.record_cast(Spell::Fireball);
+        stats.record_cast(Spell::Fireball);
+        stats.record_damage(Spell::Fireball, 45.0);
+        stats.record_cast(Spell::Teleport);
+
+        let saved = serialize_spell_stats(&stats).unwrap();
+        let loaded = parse_spell_stats(&saved).unwrap();
+
+        assert_eq!(loaded, stats);
+        assert_eq!(loaded.usage(Spell::Fireball).casts, 2);
+        assert_eq!(loaded.usage(Spell::Fireball).damage_per_cast(), 22.5);
+        assert_eq!(loaded.usage(Spell::Blizzard), SpellUsage::default());
+    }
+
+    #[test]
+    fn test_corrupt_spell_stats_are_a_parse_error() {
+        assert!(matches!(
+            parse_spell_stats("not ron {"),
+            Err(ConfigError::Parse(_))
+        ));
+        assert!(matches!(
+            parse_spell_stats("(spells: {Fireball: 3})"),
+            Err(ConfigError::Parse(_))
+        ));
+    }
+}
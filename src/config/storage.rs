@@ -1,145 +1,114 @@
-use web_sys::window;
+use std::io::{Error, ErrorKind};
 
-use super::error::ConfigResult;
+use web_sys::{Storage, window};
+
+use super::error::{ConfigError, ConfigResult};
 
 const CONFIG_KEY: &str = "court_wizard_config";
 
+/// Returns the browser's localStorage.
+///
+/// # Errors
+///
+/// Returns `ConfigError::Io` if the window object or localStorage is not available.
+fn local_storage() -> ConfigResult<Storage> {
+    let window = window().ok_or_else(|| Error::new(ErrorKind::Unsupported, "No window object"))?;
+    let storage = window
+        .local_storage()
+        .map_err(|_| Error::other("Failed to get localStorage"))?
+        .ok_or_else(|| Error::new(ErrorKind::Unsupported, "localStorage not available"))?;
+    Ok(storage)
+}
+
+/// Writes `data` to localStorage under `key`.
+fn save(key: &str, data: &str) -> ConfigResult<()> {
+    local_storage()?
+        .set_item(key, data)
+        .map_err(|_| Error::other(format!("Failed to save {key} to localStorage")))?;
+    Ok(())
+}
+
+/// Reads the string stored in localStorage under `key`.
+///
+/// # Errors
+///
+/// Returns `ConfigError::NotFound` if nothing is stored under `key`, or
+/// `ConfigError::Io` if localStorage can't be read.
+fn load(key: &str) -> ConfigResult<String> {
+    local_storage()?
+        .get_item(key)
+        .map_err(|_| Error::other(format!("Failed to read {key} from localStorage")))?
+        .ok_or_else(|| ConfigError::NotFound(key.to_string()))
+}
+
 /// Saves config string to browser localStorage.
 ///
 /// # Arguments
 ///
 /// * `config_toml` - TOML-formatted configuration string
 ///
-/// # Returns
-///
-/// `Ok(())` on success, `Err(ConfigError)` on failure
-///
 /// # Errors
 ///
-/// Returns an error if:
-/// - Window object is not available
-/// - localStorage API is not available
-/// - Setting the item fails
+/// Returns `ConfigError::Io` if localStorage is not available or setting the item fails.
 pub fn save_config(config_toml: &str) -> ConfigResult<()> {
-    let window = window()
-        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "No window object"))?;
-    let storage = window
-        .local_storage()
-        .map_err(|_| std::io::Error::other("Failed to get localStorage"))?
-        .ok_or_else(|| {
-            std::io::Error::new(std::io::ErrorKind::NotFound, "localStorage not available")
-        })?;
-
-    storage
-        .set_item(CONFIG_KEY, config_toml)
-        .map_err(|_| std::io::Error::other("Failed to save to localStorage"))?;
-    Ok(())
+    save(CONFIG_KEY, config_toml)
 }
 
 /// Loads config string from browser localStorage.
 ///
-/// # Returns
-///
-/// `Ok(String)` containing TOML config on success, `Err(ConfigError)` on failure
-///
 /// # Errors
 ///
-/// Returns an error if:
-/// - Window object is not available
-/// - localStorage API is not available
-/// - No config is found in localStorage
-/// - Reading the item fails
+/// Returns `ConfigError::NotFound` if no config has been saved, or
+/// `ConfigError::Io` if localStorage is not available or reading fails.
 pub fn load_config() -> ConfigResult<String> {
-    let window = window()
-        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "No window object"))?;
-    let storage = window
-        .local_storage()
-        .map_err(|_| std::io::Error::other("Failed to get localStorage"))?
-        .ok_or_else(|| {
-            std::io::Error::new(std::io::ErrorKind::NotFound, "localStorage not available")
-        })?;
-
-    let config = storage
-        .get_item(CONFIG_KEY)
-        .map_err(|_| std::io::Error::other("Failed to read from localStorage"))?
-        .ok_or_else(|| {
-            std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                "No config found in localStorage",
-            )
-        })?;
-
-    Ok(config)
+    load(CONFIG_KEY)
 }
 
 const PROGRESS_KEY: &str = "court_wizard_progress";
 
 /// Saves signed progress string to browser localStorage.
 pub fn save_progress(data: &str) -> ConfigResult<()> {
-    let window = window()
-        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "No window object"))?;
-    let storage = window
-        .local_storage()
-        .map_err(|_| std::io::Error::other("Failed to get localStorage"))?
-        .ok_or_else(|| {
-            std::io::Error::new(std::io::ErrorKind::NotFound, "localStorage not available")
-        })?;
-
-    storage
-        .set_item(PROGRESS_KEY, data)
-        .map_err(|_| std::io::Error::other("Failed to save progress to localStorage"))?;
-    Ok(())
+    save(PROGRESS_KEY, data)
 }
 
 /// Loads signed progress string from browser localStorage.
 pub fn load_progress() -> ConfigResult<String> {
-    let window = window()
-        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "No window object"))?;
-    let storage = window
-        .local_storage()
-        .map_err(|_| std::io::Error::other("Failed to get localStorage"))?
-        .ok_or_else(|| {
-            std::io::Error::new(std::io::ErrorKind::NotFound, "localStorage not available")
-        })?;
-
-    let data = storage
-        .get_item(PROGRESS_KEY)
-        .map_err(|_| std::io::Error::other("Failed to read progress from localStorage"))?
-        .ok_or_else(|| {
-            std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                "No progress found in localStorage",
-            )
-        })?;
-
-    Ok(data)
+    load(PROGRESS_KEY)
+}
+
+const SCORES_KEY: &str = "scores.ron";
+
+/// Saves RON-formatted scores string to browser localStorage.
+pub fn save_scores(data: &str) -> ConfigResult<()> {
+    save(SCORES_KEY, data)
+}
+
+/// Loads RON-formatted scores string from browser localStorage.
+pub fn load_scores() -> ConfigResult<String> {
+    load(SCORES_KEY)
+}
+
+const SPELL_STATS_KEY: &str = "spell_stats.ron";
+
+/// Saves RON-formatted spell statistics string to browser localStorage.
+pub fn save_spell_stats(data: &str) -> ConfigResult<()> {
+    save(SPELL_STATS_KEY, data)
+}
+
+/// Loads RON-formatted spell statistics string from browser localStorage.
+pub fn load_spell_stats() -> ConfigResult<String> {
+    load(SPELL_STATS_KEY)
 }
 
 /// Clears config from localStorage.
 ///
-/// # Returns
-///
-/// `Ok(())` on success, `Err(ConfigError)` on failure
-///
 /// # Errors
 ///
-/// Returns an error if:
-/// - Window object is not available
-/// - localStorage API is not available
-/// - Removing the item fails
+/// Returns `ConfigError::Io` if localStorage is not available or removing the item fails.
 #[allow(dead_code)]
 pub fn clear_config() -> ConfigResult<()> {
-    let window = window()
-        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "No window object"))?;
-    let storage = window
-        .local_storage()
-        .map_err(|_| std::io::Error::other("Failed to get localStorage"))?
-        .ok_or_else(|| {
-            std::io::Error::new(std::io::ErrorKind::NotFound, "localStorage not available")
-        })?;
-
-    storage
+    local_storage()?
         .remove_item(CONFIG_KEY)
-        .map_err(|_| std::io::Error::other("Failed to clear localStorage"))?;
+        .map_err(|_| Error::other("Failed to clear localStorage"))?;
     Ok(())
 }
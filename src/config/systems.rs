@@ -1,84 +1,117 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
 use bevy::window::{PresentMode, PrimaryWindow, Window as BevyWindow, WindowResized};
+use bevy::winit::{UpdateMode, WinitSettings};
 
+use super::error::{ConfigError, ConfigResult};
+use super::migration::{self, CONFIG_VERSION};
 use super::progress;
 use super::resources::*;
+use super::scores::{self, Scores};
+use super::spell_stats::{self, SpellStats};
 use super::storage;
 
 /// System that loads configuration from localStorage at startup and applies settings.
 ///
 /// This system runs during the `Startup` schedule and performs the following:
 /// 1. Loads the configuration from browser localStorage (or uses defaults if missing/invalid)
-/// 2. Applies window settings to Bevy's `Window` component
+/// 2. Restores progress from its signed copy (reset if missing or tampered with)
 /// 3. Inserts `GameConfig` as a Bevy resource for runtime access
 ///
+/// Window settings are applied separately by `apply_window_config`, so a
+/// missing primary window at startup never leaves the game without a config.
+///
 /// After this system runs, ConfigFile is discarded. Bevy components are the single
 /// source of truth during runtime.
 ///
 /// # Arguments
 ///
 /// * `commands` - Bevy command buffer for inserting resources
-/// * `windows` - Query for the primary window
+/// * `load_failures` - Where failed loads are reported
 ///
 /// # Error Handling
 ///
 /// This system is designed to never fail. If the config cannot be loaded
-/// or parsed, it falls back to sensible defaults and logs a warning.
-pub fn load_and_apply_config(
-    mut commands: Commands,
-    mut windows: Query<&mut BevyWindow, With<PrimaryWindow>>,
-) {
-    let config_file = match storage::load_config() {
-        Ok(contents) => match toml::from_str::<ConfigFile>(&contents) {
-            Ok(config) => {
-                info!("Loaded config from localStorage");
-                config
-            }
-            Err(e) => {
-                warn!("Failed to parse config: {}, using defaults", e);
-                ConfigFile::default()
-            }
-        },
-        Err(_) => {
-            info!("No config in localStorage, using defaults");
+/// or parsed, it falls back to sensible defaults and reports the failure
+/// to `LoadFailures`.
+pub fn load_and_apply_config(mut commands: Commands, mut load_failures: ResMut<LoadFailures>) {
+    let loaded =
+        storage::load_config().and_then(|contents| migration::parse_config_file(&contents));
+    let config_file = match loaded {
+        Ok(config) => {
+            info!("Loaded config from localStorage");
+            config
+        }
+        Err(e) => {
+            load_failures.report("settings", &e);
             let config = ConfigFile::default();
-            // Save defaults to localStorage
-            if let Ok(toml_string) = toml::to_string_pretty(&config) {
-                let _ = storage::save_config(&toml_string);
+            // Save defaults on first run
+            if matches!(e, ConfigError::NotFound(_))
+                && let Err(e) = save_config_file(&config)
+            {
+                error!("Failed to save default config: {}", e);
             }
             config
         }
     };
 
-    // Apply VSync to Bevy's Window
-    let Ok(mut window) = windows.single_mut() else {
-        warn!("Could not find primary window to apply config");
-        return;
-    };
-    apply_vsync_config(config_file.window.vsync, &mut window);
-
     // Create GameConfig resource from config file
     let mut game_config = GameConfig {
         vsync: config_file.window.vsync,
+        frame_cap: config_file.window.frame_cap,
+        graphics_quality: config_file.window.graphics_quality,
         master_volume: config_file.audio.master_volume,
         music_volume: config_file.audio.music_volume,
         sfx_volume: config_file.audio.sfx_volume,
         difficulty: config_file.game.difficulty,
+        map_size: config_file.game.map_size,
+        game_mode: config_file.game.game_mode,
         brightness: config_file.game.brightness.max(0.1), // Ensure minimum 10% to prevent soft-lock
+        ui_theme: config_file.game.ui_theme,
+        render_style: config_file.game.render_style,
+        unit_render_scale: config_file
+            .game
+            .unit_render_scale
+            .clamp(MIN_UNIT_RENDER_SCALE, MAX_UNIT_RENDER_SCALE),
+        ui_scale: config_file.game.ui_scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE),
+        scroll_invert: config_file.game.scroll_invert,
+        scroll_speed: config_file.game.scroll_speed.max(MIN_SCROLL_SPEED),
         current_level: config_file.game.current_level,
         highest_level_achieved: config_file.game.highest_level_achieved,
         efficiency_ratios: config_file.game.efficiency_ratios,
+        spell_loadout: config_file.game.spell_loadout,
+        loadout_size: config_file.game.loadout_size,
+        default_spell: config_file.game.default_spell,
+        favorite_spells: config_file.game.favorite_spells,
+        spell_order: config_file.game.spell_order,
+        show_damage_numbers: config_file.game.show_damage_numbers,
+        show_spell_trails: config_file.game.show_spell_trails,
+        show_battle_hint: config_file.game.show_battle_hint,
+        wind: config_file.game.wind,
+        personal_ward: config_file.game.personal_ward,
+        berserk: config_file.game.berserk,
+        shield_walls: config_file.game.shield_walls,
+        menu_background: config_file.game.menu_background,
+        pause_on_focus_loss: config_file.game.pause_on_focus_loss,
+        mouse_binding: config_file.game.mouse_binding,
+        cast_grace: config_file.game.cast_grace.max(0.0),
+        tutorial_completed: config_file.game.tutorial_completed,
+        preparation_duration: config_file.game.preparation_duration,
+        simulation_rate: config_file.game.simulation_rate.max(1),
+        corpse_mode: config_file.game.corpse_mode,
+        corpse_decay_time: config_file.game.corpse_decay_time.max(0.0),
+        time_of_day: config_file.game.time_of_day,
+        max_projectiles: config_file.game.max_projectiles,
     };
     // Verify progress against signed copy in localStorage
-    match progress::load_verified_progress() {
+    match load_failures.recover("progress", progress::load_verified_progress()) {
         Some(verified) => {
             game_config.current_level = verified.current_level;
             game_config.highest_level_achieved = verified.highest_level_achieved;
             game_config.efficiency_ratios = verified.efficiency_ratios;
-            info!("Loaded verified progress from signed storage");
         }
         None => {
-            warn!("No valid signed progress found, resetting progress to defaults");
             game_config.current_level = 1;
             game_config.highest_level_achieved = 1;
             game_config.efficiency_ratios = std::collections::HashMap::new();
@@ -90,6 +123,42 @@ pub fn load_and_apply_config(
     // ConfigFile is now discarded - GameConfig is the source of truth
 }
 
+/// Applies window settings from `GameConfig` to the primary window.
+///
+/// Runs every frame but only touches the window when the settings differ from
+/// what was last applied. The window is looked up fresh each time, so a window
+/// recreated during a mode switch gets the current settings. If there is no
+/// primary window, a warning is logged once and the settings are retried next frame.
+///
+/// # Arguments
+///
+/// * `config` - Current game settings
+/// * `windows` - Query for the primary window
+/// * `applied` - VSync mode last applied to the window
+/// * `warned` - Whether the missing window has already been reported
+pub fn apply_window_config(
+    config: Res<GameConfig>,
+    mut windows: Query<&mut BevyWindow, With<PrimaryWindow>>,
+    mut applied: Local<Option<VsyncMode>>,
+    mut warned: Local<bool>,
+) {
+    if *applied == Some(config.vsync) {
+        return;
+    }
+
+    let Ok(mut window) = windows.single_mut() else {
+        if !*warned {
+            warn!("Primary window unavailable, window settings will apply once it returns");
+            *warned = true;
+        }
+        return;
+    };
+
+    apply_vsync_config(config.vsync, &mut window);
+    *applied = Some(config.vsync);
+    *warned = false;
+}
+
 /// Applies VSync configuration to Bevy's Window component.
 ///
 /// # Arguments
@@ -106,6 +175,77 @@ fn apply_vsync_config(vsync: VsyncMode, window: &mut BevyWindow) {
     info!("Applied VSync config: {:?}", vsync);
 }
 
+/// Applies the graphics quality preset to 3D cameras and point lights.
+///
+/// Runs whenever the setting changes, and for cameras and lights spawned
+/// since, so the battlefield sun picks up the preset every battle.
+/// Anti-aliasing and shadows only change when they differ, so change
+/// detection downstream isn't triggered every frame.
+///
+/// # Arguments
+///
+/// * `config` - Current game settings
+/// * `cameras` - Anti-aliasing of every 3D camera
+/// * `lights` - Every point light
+pub fn apply_graphics_quality(
+    config: Res<GameConfig>,
+    mut cameras: Query<&mut Msaa, With<Camera3d>>,
+    mut lights: Query<&mut PointLight>,
+) {
+    let quality = config.graphics_quality;
+
+    for mut msaa in &mut cameras {
+        if config.is_changed() || msaa.is_added() {
+            msaa.set_if_neq(quality.msaa());
+        }
+    }
+    for mut light in &mut lights {
+        if (config.is_changed() || light.is_added()) && light.shadows_enabled != quality.shadows() {
+            light.shadows_enabled = quality.shadows();
+        }
+    }
+}
+
+/// Holds the frame rate at the configured cap through winit's update mode.
+///
+/// Only applies while VSync is off; with VSync on the display already paces
+/// frames. A capped app waits for the next frame slot instead of updating
+/// continuously, which winit schedules in the browser as well as on desktop.
+///
+/// # Arguments
+///
+/// * `config` - Current game settings
+/// * `winit` - Event loop settings (missing when running without a window)
+pub fn apply_frame_cap(config: Res<GameConfig>, winit: Option<ResMut<WinitSettings>>) {
+    let Some(mut winit) = winit else {
+        return;
+    };
+    if !config.is_changed() {
+        return;
+    }
+
+    let focused_mode = frame_cap_update_mode(config.vsync, config.frame_cap);
+    if winit.focused_mode != focused_mode {
+        winit.focused_mode = focused_mode;
+    }
+}
+
+/// Returns the update mode for the window while it has focus.
+///
+/// With a cap, updates run once every 1/`cap` seconds. Input and window
+/// events don't wake the loop early, so they can't push past the cap.
+fn frame_cap_update_mode(vsync: VsyncMode, frame_cap: Option<u32>) -> UpdateMode {
+    match frame_cap {
+        Some(cap) if vsync == VsyncMode::Off => UpdateMode::Reactive {
+            wait: Duration::from_secs_f64(1.0 / cap.max(1) as f64),
+            react_to_device_events: false,
+            react_to_user_events: false,
+            react_to_window_events: false,
+        },
+        _ => UpdateMode::Continuous,
+    }
+}
+
 /// Detects window resize events and triggers config save.
 ///
 /// This system monitors Bevy's WindowResized events and emits a ConfigChanged
@@ -146,6 +286,39 @@ pub fn detect_game_config_changes(
     config_changed.write(ConfigChanged);
 }
 
+/// Saves scores to localStorage whenever they change.
+///
+/// Scores only change when a wave is cleared or a game ends, so they are saved immediately
+/// instead of going through the config debounce timer.
+///
+/// # Arguments
+///
+/// * `scores` - Scores resource
+pub fn save_scores_on_change(scores: Res<Scores>) {
+    if !scores.is_changed() || scores.is_added() {
+        return;
+    }
+
+    if let Err(e) = scores::save_scores(&scores) {
+        error!("Failed to save scores: {}", e);
+    }
+}
+
+/// Saves spell statistics to localStorage.
+///
+/// Stats change with every spell hit, so they're written at checkpoints
+/// (a cleared wave, game over, leaving the battle) rather than on change.
+/// A browser tab closed mid-battle keeps everything up to the last cleared wave.
+///
+/// # Arguments
+///
+/// * `stats` - SpellStats resource
+pub fn save_spell_stats(stats: Res<SpellStats>) {
+    if let Err(e) = spell_stats::save_spell_stats(&stats) {
+        error!("Failed to save spell stats: {}", e);
+    }
+}
+
 /// Unified debounce trigger for ALL config changes.
 ///
 /// This system listens for the ConfigChanged message and resets the
@@ -167,36 +340,28 @@ pub fn mark_save_on_config_changed(
     }
 
     // Reset timer and mark save pending
-    debounce_timer.timer.reset();
-    debounce_timer.pending = true;
+    debounce_timer.mark_dirty();
 }
 
 /// Ticks debounce timer and saves to localStorage when expired.
 ///
 /// This system runs every frame during the `Update` schedule. When the
-/// debounce timer expires (0.5s of no config changes), it reads the current
+/// debounce timer expires (`SAVE_DEBOUNCE_SECONDS` of no config changes), it reads the current
 /// state from Bevy components and saves to localStorage.
 ///
 /// # Arguments
 ///
-/// * `time` - Time resource for delta time
+/// * `time` - Real time, so settings changed while the game is paused still save
 /// * `debounce_timer` - Debounce timer resource
 /// * `windows` - Query for the primary window
 /// * `game_config` - Game configuration resource
 pub fn save_config_on_debounce_timer(
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mut debounce_timer: ResMut<SaveDebounceTimer>,
     game_config: Res<GameConfig>,
 ) {
-    if !debounce_timer.pending {
-        return;
-    }
-
-    debounce_timer.timer.tick(time.delta());
-
-    if debounce_timer.timer.is_finished() {
+    if debounce_timer.tick(time.delta()) {
         persist_config(&game_config);
-        debounce_timer.pending = false;
     }
 }
 
@@ -242,23 +407,20 @@ fn persist_config(game_config: &GameConfig) {
     // Build ConfigFile from current state
     let config_file = build_config_from_game_config(game_config);
 
-    // Serialize and save
-    match toml::to_string_pretty(&config_file) {
-        Ok(toml_string) => match storage::save_config(&toml_string) {
-            Ok(_) => {
-                info!("Config saved to localStorage");
-            }
-            Err(e) => {
-                error!("Failed to save config: {}", e);
-            }
-        },
-        Err(e) => {
-            error!("Failed to serialize config: {}", e);
-        }
+    match save_config_file(&config_file) {
+        Ok(()) => info!("Config saved to localStorage"),
+        Err(e) => error!("Failed to save config: {}", e),
     }
 
     // Also save signed progress
-    progress::save_signed_progress(game_config);
+    if let Err(e) = progress::save_signed_progress(game_config) {
+        error!("Failed to save signed progress: {}", e);
+    }
+}
+
+/// Serializes a config file and writes it to localStorage.
+fn save_config_file(config_file: &ConfigFile) -> ConfigResult<()> {
+    storage::save_config(&toml::to_string_pretty(config_file)?)
 }
 
 /// Builds ConfigFile from current GameConfig.
@@ -276,15 +438,17 @@ fn persist_config(game_config: &GameConfig) {
 fn build_config_from_game_config(game_config: &GameConfig) -> ConfigFile {
     // Load existing config to preserve window settings we don't modify (resolution, etc.)
     let existing_window = match storage::load_config() {
-        Ok(contents) => toml::from_str::<ConfigFile>(&contents)
+        Ok(contents) => migration::parse_config_file(&contents)
             .map(|c| c.window)
             .unwrap_or_default(),
         Err(_) => WindowConfig::default(),
     };
 
-    // Update only the VSync setting, preserve everything else
+    // Update only the VSync, frame cap, and quality settings, preserve everything else
     let window_config = WindowConfig {
         vsync: game_config.vsync,
+        frame_cap: game_config.frame_cap,
+        graphics_quality: game_config.graphics_quality,
         ..existing_window
     };
 
@@ -295,8 +459,99 @@ fn build_config_from_game_config(game_config: &GameConfig) -> ConfigFile {
     };
 
     ConfigFile {
+        version: CONFIG_VERSION,
         window: window_config,
         audio: audio_config,
         game: game_config.clone(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_config_applies_once_primary_window_exists() {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            vsync: VsyncMode::Off,
+            ..default()
+        })
+        .add_systems(Update, apply_window_config);
+
+        // No window yet - nothing to apply, and no panic
+        app.update();
+
+        let window = app
+            .world_mut()
+            .spawn((BevyWindow::default(), PrimaryWindow))
+            .id();
+        app.update();
+
+        let present_mode = app.world().get::<BevyWindow>(window).unwrap().present_mode;
+        assert_eq!(present_mode, PresentMode::AutoNoVsync);
+    }
+
+    #[test]
+    fn test_low_quality_turns_off_msaa_and_shadows() {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            graphics_quality: GraphicsQuality::High,
+            ..default()
+        })
+        .add_systems(Update, apply_graphics_quality);
+
+        let camera = app.world_mut().spawn((Camera3d::default(), Msaa::Off)).id();
+        let light = app.world_mut().spawn(PointLight::default()).id();
+        app.update();
+
+        assert_eq!(*app.world().get::<Msaa>(camera).unwrap(), Msaa::Sample4);
+        assert!(
+            app.world()
+                .get::<PointLight>(light)
+                .unwrap()
+                .shadows_enabled
+        );
+
+        app.world_mut()
+            .resource_mut::<GameConfig>()
+            .graphics_quality = GraphicsQuality::Low;
+        app.update();
+
+        assert_eq!(*app.world().get::<Msaa>(camera).unwrap(), Msaa::Off);
+        assert!(
+            !app.world()
+                .get::<PointLight>(light)
+                .unwrap()
+                .shadows_enabled
+        );
+    }
+
+    #[test]
+    fn test_frame_cap_paces_updates_while_vsync_is_off() {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            vsync: VsyncMode::Off,
+            frame_cap: Some(60),
+            ..default()
+        })
+        .insert_resource(WinitSettings::game())
+        .add_systems(Update, apply_frame_cap);
+        app.update();
+
+        let UpdateMode::Reactive { wait, .. } =
+            app.world().resource::<WinitSettings>().focused_mode
+        else {
+            panic!("a frame cap should pace updates");
+        };
+        assert_eq!(wait, Duration::from_secs_f64(1.0 / 60.0));
+
+        // VSync takes over pacing once it's turned back on
+        app.world_mut().resource_mut::<GameConfig>().vsync = VsyncMode::On;
+        app.update();
+        assert_eq!(
+            app.world().resource::<WinitSettings>().focused_mode,
+            UpdateMode::Continuous
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! Batched entity commands for mass updates.
+//!
+//! Every `EntityCommands` call queues its own command. When a big spell hit
+//! kills dozens of units or spends dozens of projectiles in one frame, systems
+//! collect the entities and queue one of these commands (or
+//! `Commands::insert_batch`) instead.
+
+use bevy::ecs::bundle::Bundle;
+use bevy::prelude::*;
+
+/// Despawns every entity in a single command.
+///
+/// Entities that are already gone are skipped.
+pub fn despawn_batch(entities: Vec<Entity>) -> impl Command {
+    move |world: &mut World| {
+        for entity in entities {
+            // Already despawned this frame (e.g. by a wall and a unit at once)
+            let _ = world.try_despawn(entity);
+        }
+    }
+}
+
+/// Removes a bundle of components from every entity in a single command.
+///
+/// Entities that no longer exist are skipped.
+pub fn remove_batch<B: Bundle>(entities: Vec<Entity>) -> impl Command {
+    move |world: &mut World| {
+        for entity in entities {
+            if let Ok(mut entity) = world.get_entity_mut(entity) {
+                entity.remove::<B>();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn test_despawn_batch_skips_missing_entities() {
+        let mut world = World::new();
+        let first = world.spawn(Marker).id();
+        let second = world.spawn(Marker).id();
+        let survivor = world.spawn(Marker).id();
+        world.despawn(second);
+
+        world.commands().queue(despawn_batch(vec![first, second]));
+        world.flush();
+
+        assert!(world.get_entity(first).is_err());
+        assert!(world.get_entity(survivor).is_ok());
+    }
+
+    #[test]
+    fn test_remove_batch_strips_bundle() {
+        let mut world = World::new();
+        let entities: Vec<Entity> = (0..3)
+            .map(|_| world.spawn((Marker, Transform::default())).id())
+            .collect();
+
+        world
+            .commands()
+            .queue(remove_batch::<Marker>(entities.clone()));
+        world.flush();
+
+        for entity in entities {
+            assert!(world.get::<Marker>(entity).is_none());
+            assert!(world.get::<Transform>(entity).is_some());
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use bevy::prelude::*;
+
+use crate::game::units::components::Team;
+
+/// Estimated balance of the current engagement.
+///
+/// Ranges from -1.0 (attackers favored) to 1.0 (defenders favored).
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq)]
+pub struct BattleBalance(pub f32);
+
+/// A living unit's contribution to the battle balance estimate.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitSnapshot {
+    pub team: Team,
+    /// Current health (excluding temporary hit points).
+    pub health: f32,
+    /// Current effectiveness multiplier.
+    pub effectiveness: f32,
+}
+
+/// Estimates which side is favored from a snapshot of living units.
+///
+/// Each side's strength is its effectiveness-weighted health multiplied by its
+/// unit count (Lanchester's square law: numbers matter more than raw health
+/// because every extra unit also adds damage output). Undead fight against the
+/// defenders, so they count toward the attacking side.
+///
+/// Returns -1.0 (attackers favored) to 1.0 (defenders favored), or 0.0 when
+/// neither side has any strength.
+pub fn estimate_battle_balance(units: &[UnitSnapshot]) -> f32 {
+    let mut defender_health = 0.0;
+    let mut defender_count = 0.0;
+    let mut enemy_health = 0.0;
+    let mut enemy_count = 0.0;
+
+    for unit in units {
+        let effective_health = unit.health.max(0.0) * unit.effectiveness.max(0.0);
+        match unit.team {
+            Team::Defenders => {
+                defender_health += effective_health;
+                defender_count += 1.0;
+            }
+            Team::Attackers | Team::Undead => {
+                enemy_health += effective_health;
+                enemy_count += 1.0;
+            }
+        }
+    }
+
+    let defender_strength = defender_health * defender_count;
+    let enemy_strength = enemy_health * enemy_count;
+    let total = defender_strength + enemy_strength;
+
+    if total > 0.0 {
+        (defender_strength - enemy_strength) / total
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn units(team: Team, count: usize, health: f32, effectiveness: f32) -> Vec<UnitSnapshot> {
+        vec![
+            UnitSnapshot {
+                team,
+                health,
+                effectiveness,
+            };
+            count
+        ]
+    }
+
+    #[test]
+    fn test_even_battle_is_balanced() {
+        let mut snapshot = units(Team::Defenders, 20, 100.0, 1.0);
+        snapshot.extend(units(Team::Attackers, 20, 100.0, 1.0));
+        assert!(estimate_battle_balance(&snapshot).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_lopsided_battle_favors_larger_army() {
+        let mut snapshot = units(Team::Defenders, 5, 100.0, 1.0);
+        snapshot.extend(units(Team::Attackers, 50, 100.0, 1.0));
+        assert!(estimate_battle_balance(&snapshot) < -0.9);
+
+        let mut snapshot = units(Team::Defenders, 50, 100.0, 1.0);
+        snapshot.extend(units(Team::Attackers, 5, 100.0, 1.0));
+        assert!(estimate_battle_balance(&snapshot) > 0.9);
+    }
+
+    #[test]
+    fn test_one_sided_battle_is_maximal() {
+        let snapshot = units(Team::Defenders, 3, 50.0, 1.0);
+        assert_eq!(estimate_battle_balance(&snapshot), 1.0);
+
+        let snapshot = units(Team::Undead, 3, 50.0, 1.0);
+        assert_eq!(estimate_battle_balance(&snapshot), -1.0);
+    }
+
+    #[test]
+    fn test_effectiveness_shifts_even_battle() {
+        let mut snapshot = units(Team::Defenders, 20, 100.0, 1.5);
+        snapshot.extend(units(Team::Attackers, 20, 100.0, 0.5));
+        assert!(estimate_battle_balance(&snapshot) > 0.0);
+    }
+
+    #[test]
+    fn test_undead_count_against_defenders() {
+        let mut snapshot = units(Team::Defenders, 20, 100.0, 1.0);
+        snapshot.extend(units(Team::Attackers, 10, 100.0, 1.0));
+        let without_undead = estimate_battle_balance(&snapshot);
+
+        snapshot.extend(units(Team::Undead, 10, 100.0, 1.0));
+        let with_undead = estimate_battle_balance(&snapshot);
+        assert!(with_undead < without_undead);
+    }
+
+    #[test]
+    fn test_empty_battlefield_is_neutral() {
+        assert_eq!(estimate_battle_balance(&[]), 0.0);
+    }
+}
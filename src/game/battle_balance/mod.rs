@@ -0,0 +1,10 @@
+//! Battle balance module.
+//!
+//! Read-only analysis that estimates which side is winning the current
+//! engagement, used by the optional HUD hint.
+
+pub mod components;
+mod plugin;
+mod systems;
+
+pub use plugin::BattleBalancePlugin;
@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use crate::state::{AppState, InGameState};
+
+use super::components::BattleBalance;
+use super::systems;
+
+/// Plugin that estimates which side is winning the current engagement.
+///
+/// Registers systems for:
+/// - Recomputing the battle balance from living units each frame
+/// - Resetting the estimate for each new game
+pub struct BattleBalancePlugin;
+
+impl Plugin for BattleBalancePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BattleBalance>()
+            .add_systems(OnEnter(AppState::InGame), systems::reset_battle_balance)
+            .add_systems(OnExit(InGameState::GameOver), systems::reset_battle_balance)
+            .add_systems(
+                Update,
+                systems::update_battle_balance.run_if(in_state(InGameState::Running)),
+            );
+    }
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use crate::config::GameConfig;
+use crate::game::units::components::{Corpse, Effectiveness, Health, Team};
+
+/// Recomputes the battle balance estimate from all living units.
+///
+/// Only runs the analysis while the battle hint is enabled in the settings.
+pub fn update_battle_balance(
+    config: Res<GameConfig>,
+    mut balance: ResMut<BattleBalance>,
+    units: Query<(&Team, &Health, Option<&Effectiveness>), Without<Corpse>>,
+) {
+    if !config.show_battle_hint {
+        return;
+    }
+
+    let snapshot: Vec<UnitSnapshot> = units
+        .iter()
+        .map(|(team, health, effectiveness)| UnitSnapshot {
+            team: *team,
+            health: health.current,
+            effectiveness: effectiveness.map_or(1.0, Effectiveness::multiplier),
+        })
+        .collect();
+
+    balance.set_if_neq(BattleBalance(estimate_battle_balance(&snapshot)));
+}
+
+/// Resets the estimate when a new game starts.
+pub fn reset_battle_balance(mut balance: ResMut<BattleBalance>) {
+    *balance = BattleBalance::default();
+}
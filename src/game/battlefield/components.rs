@@ -1,5 +1,13 @@
 use bevy::prelude::*;
 
+use super::styles::{DAWN_LIGHTING, DUSK_LIGHTING, NIGHT_LIGHTING, NOON_LIGHTING};
+use crate::config::{MapSize, TimeOfDayMode};
+use crate::game::constants::{
+    BATTLEFIELD_SIZE, CASTLE_DEPTH, CASTLE_POSITION, CASTLE_ROTATION_DEGREES, CASTLE_WIDTH,
+    LARGE_BATTLEFIELD_SIZE, calculate_grid_cell_position,
+};
+use crate::game::units::components::push_out_of_rectangle;
+
 /// Marker component for the battlefield background.
 #[derive(Component)]
 pub struct Battlefield;
@@ -7,3 +15,347 @@ pub struct Battlefield;
 /// Marker component for the castle battlements.
 #[derive(Component)]
 pub struct Castle;
+
+/// Ground footprint of the castle platform that hostile units cannot walk into.
+///
+/// Units are pushed back to the nearest edge, the same way Wall of Stone
+/// blocks movement, so they have to path around the castle. Attackers besiege
+/// the castle by reaching this edge.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CastleFootprint {
+    /// Center of the footprint on the ground plane.
+    pub center: Vec3,
+    /// Half the platform depth (along `forward`).
+    pub half_depth: f32,
+    /// Half the platform width (along `right`).
+    pub half_width: f32,
+    /// Normalized direction along the platform depth (XZ plane).
+    pub forward: Vec3,
+    /// Normalized direction along the platform width (XZ plane).
+    pub right: Vec3,
+}
+
+impl CastleFootprint {
+    /// Creates the footprint of a platform with the given size and Y rotation.
+    pub fn new(position: Vec3, width: f32, depth: f32, rotation: Quat) -> Self {
+        Self {
+            center: Vec3::new(position.x, 0.0, position.z),
+            half_depth: depth / 2.0,
+            half_width: width / 2.0,
+            forward: rotation * Vec3::Z,
+            right: rotation * Vec3::X,
+        }
+    }
+
+    /// Creates the footprint of the battlefield's castle platform.
+    pub fn castle() -> Self {
+        Self::new(
+            CASTLE_POSITION,
+            CASTLE_WIDTH,
+            CASTLE_DEPTH,
+            Quat::from_rotation_y(CASTLE_ROTATION_DEGREES.to_radians()),
+        )
+    }
+
+    /// Pushes a unit outside the footprint along the nearest edge normal.
+    /// Returns the corrected position if the unit was inside.
+    pub fn push_out(&self, point: Vec3, radius: f32) -> Option<Vec3> {
+        push_out_of_rectangle(
+            point,
+            radius,
+            self.center,
+            self.forward,
+            self.right,
+            self.half_depth,
+            self.half_width,
+        )
+    }
+
+    /// Returns the point on the footprint (at ground level) closest to `point`.
+    ///
+    /// Points inside the footprint are returned unchanged apart from their height.
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let offset = point - self.center;
+        let depth = offset
+            .dot(self.forward)
+            .clamp(-self.half_depth, self.half_depth);
+        let width = offset
+            .dot(self.right)
+            .clamp(-self.half_width, self.half_width);
+        self.center + self.forward * depth + self.right * width
+    }
+
+    /// Returns the ground-plane distance from `point` to the footprint edge (0 inside).
+    pub fn distance_to(&self, point: Vec3) -> f32 {
+        let closest = self.closest_point(point);
+        Vec2::new(point.x - closest.x, point.z - closest.z).length()
+    }
+}
+
+/// Dimensions of the battlefield for the current battle.
+///
+/// The castle keeps its place in the rear corner of the field, so a larger
+/// battlefield extends toward the enemy side rather than in every direction.
+/// Applied from `GameConfig::map_size` when a battle starts.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct BattlefieldConfig {
+    /// Width and depth of the square battlefield.
+    pub size: f32,
+}
+
+impl Default for BattlefieldConfig {
+    fn default() -> Self {
+        Self {
+            size: BATTLEFIELD_SIZE,
+        }
+    }
+}
+
+impl BattlefieldConfig {
+    /// Creates the battlefield config for a map size option.
+    pub fn from_map_size(map_size: MapSize) -> Self {
+        let size = match map_size {
+            MapSize::Standard => BATTLEFIELD_SIZE,
+            MapSize::Large => LARGE_BATTLEFIELD_SIZE,
+        };
+        Self { size }
+    }
+
+    /// Size relative to the standard battlefield.
+    pub fn scale(&self) -> f32 {
+        self.size / BATTLEFIELD_SIZE
+    }
+
+    /// Center of the ground plane.
+    ///
+    /// Shifted away from the castle corner (toward +X, -Z) by the extra size.
+    pub fn center(&self) -> Vec3 {
+        let shift = (self.size - BATTLEFIELD_SIZE) / 2.0;
+        Vec3::new(shift, 0.0, -shift)
+    }
+
+    /// Clamps a ground position to the battlefield bounds.
+    pub fn clamp(&self, x: f32, z: f32) -> (f32, f32) {
+        let center = self.center();
+        let half_size = self.size / 2.0;
+        (
+            x.clamp(center.x - half_size, center.x + half_size),
+            z.clamp(center.z - half_size, center.z + half_size),
+        )
+    }
+
+    /// Returns the attacker spawn position of a formation grid cell.
+    ///
+    /// Row spacing scales with the battlefield and the result stays in bounds.
+    pub fn grid_cell_position(&self, row: u32, col: u32) -> (f32, f32) {
+        let (x, z) = calculate_grid_cell_position(row, col, self.scale());
+        self.clamp(x, z)
+    }
+}
+
+/// Marker component for the point light standing in for the sun (or moon).
+#[derive(Component)]
+pub struct Sun;
+
+/// Distance of the sun from the battlefield center.
+pub const SUN_DISTANCE: f32 = 1000.0;
+
+/// Seconds for the `Cycle` time of day to go from dawn through night and back.
+pub const DAY_CYCLE_DURATION: f32 = 480.0;
+
+/// How the scene is lit at one point in the day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightingPreset {
+    /// Height of the sun above the horizon in degrees.
+    pub sun_elevation: f32,
+    /// Color of the sun.
+    pub color: Color,
+    /// Sun intensity in lumens.
+    pub intensity: f32,
+    /// Ambient light brightness.
+    pub ambient_brightness: f32,
+    /// Camera exposure offset in stops, added on top of the brightness setting.
+    pub exposure: f32,
+    /// Camera white balance shift (positive is warmer).
+    ///
+    /// Most materials are unlit, so exposure and temperature carry most of the
+    /// visible change between presets.
+    pub temperature: f32,
+}
+
+impl LightingPreset {
+    /// Blends between two presets, `t` going from 0 (`self`) to 1 (`other`).
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            sun_elevation: self.sun_elevation.lerp(other.sun_elevation, t),
+            color: self.color.mix(&other.color, t),
+            intensity: self.intensity.lerp(other.intensity, t),
+            ambient_brightness: self.ambient_brightness.lerp(other.ambient_brightness, t),
+            exposure: self.exposure.lerp(other.exposure, t),
+            temperature: self.temperature.lerp(other.temperature, t),
+        }
+    }
+}
+
+/// Where the battle is in the day, which drives the battlefield lighting.
+///
+/// `phase` runs from 0 to 4: dawn at 0, noon at 1, dusk at 2, and night at 3,
+/// blending between neighbours in between. The sun swings a quarter turn
+/// around the battlefield per step.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TimeOfDay {
+    pub phase: f32,
+}
+
+impl Default for TimeOfDay {
+    fn default() -> Self {
+        Self::from_mode(TimeOfDayMode::Noon)
+    }
+}
+
+impl TimeOfDay {
+    const PRESETS: [LightingPreset; 4] =
+        [DAWN_LIGHTING, NOON_LIGHTING, DUSK_LIGHTING, NIGHT_LIGHTING];
+
+    /// Returns the time a battle starts at under `mode` (`Cycle` starts at dawn).
+    pub fn from_mode(mode: TimeOfDayMode) -> Self {
+        let phase = match mode {
+            TimeOfDayMode::Dawn | TimeOfDayMode::Cycle => 0.0,
+            TimeOfDayMode::Noon => 1.0,
+            TimeOfDayMode::Dusk => 2.0,
+            TimeOfDayMode::Night => 3.0,
+        };
+        Self { phase }
+    }
+
+    /// Moves the time of day forward by `seconds` of the day cycle.
+    pub fn advance(&mut self, seconds: f32) {
+        let steps = Self::PRESETS.len() as f32;
+        self.phase = (self.phase + seconds * steps / DAY_CYCLE_DURATION).rem_euclid(steps);
+    }
+
+    /// Returns the lighting for the current time, blended between presets.
+    pub fn lighting(&self) -> LightingPreset {
+        let index = self.phase.floor() as usize % Self::PRESETS.len();
+        let next = (index + 1) % Self::PRESETS.len();
+        Self::PRESETS[index].lerp(&Self::PRESETS[next], self.phase.fract())
+    }
+
+    /// Returns the direction the light travels in (from the sun toward the ground).
+    pub fn light_direction(&self) -> Vec3 {
+        let azimuth = (self.phase * 90.0).to_radians();
+        let elevation = self.lighting().sun_elevation.to_radians();
+        let toward_sun = Vec3::new(
+            elevation.cos() * azimuth.cos(),
+            elevation.sin(),
+            elevation.cos() * azimuth.sin(),
+        );
+        -toward_sun
+    }
+
+    /// Returns the sun's transform, `SUN_DISTANCE` back along `light_direction`
+    /// from the battlefield center.
+    pub fn sun_transform(&self) -> Transform {
+        Transform::from_translation(-self.light_direction() * SUN_DISTANCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_moving_toward_castle_is_pushed_to_edge() {
+        let footprint = CastleFootprint::castle();
+        let radius = 8.0;
+        let start = footprint.center + footprint.right * 400.0 + Vec3::Y * 21.0;
+
+        // Step toward the platform center until the unit overlaps the footprint
+        let step = -footprint.right * 60.0;
+        let mut position = start;
+        let mut corrected = None;
+        for _ in 0..10 {
+            position += step;
+            corrected = footprint.push_out(position, radius);
+            if corrected.is_some() {
+                break;
+            }
+        }
+
+        let corrected = corrected.expect("unit should collide with the platform");
+        let offset = corrected - footprint.center;
+        let edge_distance = footprint.half_width + radius;
+        assert!((offset.dot(footprint.right) - edge_distance).abs() < 1e-3);
+        assert_eq!(corrected.y, start.y);
+        assert!(footprint.push_out(corrected, radius - 0.01).is_none());
+    }
+
+    #[test]
+    fn test_unit_outside_castle_is_not_moved() {
+        let footprint = CastleFootprint::castle();
+        let point = footprint.center + footprint.right * 500.0;
+        assert!(footprint.push_out(point, 8.0).is_none());
+    }
+
+    #[test]
+    fn test_distance_to_castle_edge() {
+        let footprint = CastleFootprint::castle();
+        let beside = footprint.center + footprint.right * (footprint.half_width + 50.0);
+
+        assert!((footprint.distance_to(beside) - 50.0).abs() < 1e-3);
+        assert!(
+            (footprint.closest_point(beside)
+                - (footprint.center + footprint.right * footprint.half_width))
+                .length()
+                < 1e-3
+        );
+        assert_eq!(footprint.distance_to(footprint.center), 0.0);
+    }
+
+    #[test]
+    fn test_standard_battlefield_is_centered() {
+        let config = BattlefieldConfig::default();
+        assert_eq!(config.center(), Vec3::ZERO);
+        assert_eq!(config.clamp(4000.0, -4000.0), (3000.0, -3000.0));
+    }
+
+    #[test]
+    fn test_large_battlefield_keeps_castle_corner() {
+        let standard = BattlefieldConfig::default();
+        let large = BattlefieldConfig::from_map_size(MapSize::Large);
+
+        // The castle-side edges stay where they are
+        assert_eq!(
+            large.clamp(-5000.0, 5000.0),
+            standard.clamp(-5000.0, 5000.0)
+        );
+        // The enemy side grows
+        assert_eq!(large.clamp(7000.0, -7000.0), (6000.0, -6000.0));
+        assert_eq!(
+            large.clamp(CASTLE_POSITION.x, CASTLE_POSITION.z),
+            (CASTLE_POSITION.x, CASTLE_POSITION.z)
+        );
+    }
+
+    #[test]
+    fn test_grid_cells_match_constants_on_standard_battlefield() {
+        let config = BattlefieldConfig::default();
+        assert_eq!(
+            config.grid_cell_position(3, 2),
+            calculate_grid_cell_position(3, 2, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_grid_cells_spread_out_on_large_battlefield() {
+        let standard = BattlefieldConfig::default();
+        let large = BattlefieldConfig::from_map_size(MapSize::Large);
+
+        // Back rows move further out while staying on the field
+        let (sx, sz) = standard.grid_cell_position(5, 2);
+        let (lx, lz) = large.grid_cell_position(5, 2);
+        assert!(lx > sx);
+        assert!(lz < sz);
+        assert_eq!(large.clamp(lx, lz), (lx, lz));
+    }
+}
@@ -1,8 +1,9 @@
 //! Battlefield plugin module.
 //!
-//! Handles the battlefield ground plane, castle platform, and lighting.
+//! Handles the battlefield ground plane, castle platform, lighting, and the
+//! configurable battlefield size.
 
-mod components;
+pub mod components;
 mod plugin;
 mod styles;
 pub mod systems;
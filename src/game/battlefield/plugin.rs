@@ -3,21 +3,44 @@ use bevy::prelude::*;
 use crate::game::run_conditions;
 use crate::state::{AppState, InGameState};
 
+use super::components::{BattlefieldConfig, TimeOfDay};
 use super::systems;
 
 /// Plugin that handles battlefield and castle setup.
 ///
 /// Registers systems for:
+/// - Applying the configured battlefield size before each battle
 /// - Battlefield ground, castle platform, and lighting setup on entering InGame state
 /// - Re-setup when entering Running state from GameOver (for replay)
+/// - Advancing the day/night cycle and applying the time of day to the lights
+/// - Returning to noon lighting when leaving a battle
 pub struct BattlefieldPlugin;
 
 impl Plugin for BattlefieldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(AppState::InGame), systems::setup_battlefield)
+        app.init_resource::<BattlefieldConfig>()
+            .init_resource::<TimeOfDay>()
+            .add_systems(
+                OnExit(AppState::MainMenu),
+                systems::apply_battlefield_config,
+            )
+            .add_systems(
+                OnExit(InGameState::GameOver),
+                systems::apply_battlefield_config,
+            )
+            .add_systems(OnEnter(AppState::InGame), systems::setup_battlefield)
             .add_systems(
                 OnEnter(InGameState::Running),
                 systems::setup_battlefield.run_if(run_conditions::coming_from_game_over),
-            );
+            )
+            .add_systems(
+                Update,
+                (
+                    systems::advance_time_of_day.run_if(in_state(InGameState::Running)),
+                    systems::apply_time_of_day,
+                )
+                    .chain(),
+            )
+            .add_systems(OnExit(AppState::InGame), systems::reset_time_of_day);
     }
 }
@@ -1,5 +1,42 @@
 use bevy::prelude::*;
 
+use super::components::LightingPreset;
+
 // Entity Colors
 pub const CASTLE_COLOR: Color = Color::srgb(0.7, 0.7, 0.7); // Light gray
 pub const BATTLEFIELD_COLOR: Color = Color::srgb(0.4, 0.5, 0.35); // Muted green
+
+// Time of day lighting presets (noon is the battlefield's original overhead light)
+
+pub const DAWN_LIGHTING: LightingPreset = LightingPreset {
+    sun_elevation: 15.0,
+    color: Color::srgb(1.0, 0.75, 0.55), // Pale gold
+    intensity: 600_000.0,
+    ambient_brightness: 32.0,
+    exposure: -0.3,
+    temperature: 0.25,
+};
+pub const NOON_LIGHTING: LightingPreset = LightingPreset {
+    sun_elevation: 90.0,
+    color: Color::WHITE,
+    intensity: 2_000_000.0,
+    ambient_brightness: 80.0,
+    exposure: 0.0,
+    temperature: 0.0,
+};
+pub const DUSK_LIGHTING: LightingPreset = LightingPreset {
+    sun_elevation: 15.0,
+    color: Color::srgb(1.0, 0.6, 0.4), // Orange
+    intensity: 500_000.0,
+    ambient_brightness: 27.0,
+    exposure: -0.5,
+    temperature: 0.4,
+};
+pub const NIGHT_LIGHTING: LightingPreset = LightingPreset {
+    sun_elevation: 45.0,
+    color: Color::srgb(0.55, 0.65, 1.0), // Moonlight blue
+    intensity: 30_000.0,
+    ambient_brightness: 8.0,
+    exposure: -1.5,
+    temperature: -0.4,
+};
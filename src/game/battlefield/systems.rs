@@ -2,32 +2,53 @@ use bevy::prelude::*;
 
 use super::components::*;
 use super::styles::*;
+use crate::config::{GameConfig, TimeOfDayMode};
 use crate::game::components::OnGameplayScreen;
 use crate::game::constants::*;
 
+/// Applies the configured map size before a battle starts.
+///
+/// Runs on the way into a new battle so size changes made mid-battle from the
+/// pause menu take effect on the next one.
+pub fn apply_battlefield_config(
+    config: Res<GameConfig>,
+    mut battlefield: ResMut<BattlefieldConfig>,
+) {
+    battlefield.set_if_neq(BattlefieldConfig::from_map_size(config.map_size));
+}
+
 /// Sets up the battlefield and castle when entering the InGame state.
 ///
-/// Spawns the battlefield ground plane, castle platform, and point light in 3D space.
+/// Spawns the battlefield ground plane (sized from `BattlefieldConfig`), castle
+/// platform, and sun in 3D space, starting the clock at the configured time of day.
 pub fn setup_battlefield(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    battlefield: Res<BattlefieldConfig>,
+    config: Res<GameConfig>,
+    mut time_of_day: ResMut<TimeOfDay>,
 ) {
+    *time_of_day = TimeOfDay::from_mode(config.time_of_day);
+    let lighting = time_of_day.lighting();
+
     // Add a light source so we can see 3D objects
     commands.spawn((
         PointLight {
-            intensity: 2_000_000.0,
+            color: lighting.color,
+            intensity: lighting.intensity,
             shadows_enabled: false,
             ..default()
         },
-        Transform::from_xyz(0.0, 1000.0, 0.0),
+        time_of_day.sun_transform(),
+        Sun,
         OnGameplayScreen,
     ));
 
-    // Spawn battlefield as ground plane at origin
+    // Spawn battlefield as ground plane (centered at origin on the standard map)
     let battlefield_mesh = Plane3d::default()
         .mesh()
-        .size(BATTLEFIELD_SIZE, BATTLEFIELD_SIZE);
+        .size(battlefield.size, battlefield.size);
 
     commands.spawn((
         Mesh3d(meshes.add(battlefield_mesh)),
@@ -36,13 +57,15 @@ pub fn setup_battlefield(
             unlit: true,
             ..default()
         })),
-        Transform::from_xyz(0.0, 0.0, 0.0), // Centered at origin
+        Transform::from_translation(battlefield.center()),
         Battlefield,
         OnGameplayScreen,
     ));
 
-    // Spawn castle as a raised platform (Plane3d) above the battlefield
+    // Spawn castle as a raised platform (Plane3d) above the battlefield.
+    // Its ground footprint keeps hostile units from walking underneath.
     let castle_plane = Plane3d::default().mesh().size(CASTLE_WIDTH, CASTLE_DEPTH);
+    let castle_rotation = Quat::from_rotation_y(CASTLE_ROTATION_DEGREES.to_radians());
 
     commands.spawn((
         Mesh3d(meshes.add(castle_plane)),
@@ -51,9 +74,222 @@ pub fn setup_battlefield(
             unlit: true,
             ..default()
         })),
-        Transform::from_translation(CASTLE_POSITION)
-            .with_rotation(Quat::from_rotation_y(CASTLE_ROTATION_DEGREES.to_radians())),
+        Transform::from_translation(CASTLE_POSITION).with_rotation(castle_rotation),
         Castle,
+        CastleFootprint::castle(),
         OnGameplayScreen,
     ));
 }
+
+/// Moves the `Cycle` time of day forward while the battle runs.
+pub fn advance_time_of_day(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut time_of_day: ResMut<TimeOfDay>,
+) {
+    if config.time_of_day == TimeOfDayMode::Cycle {
+        time_of_day.advance(time.delta_secs());
+    }
+}
+
+/// Moves the sun and sets the light colors whenever the time of day changes.
+///
+/// The matching exposure and white balance are applied to the camera together
+/// with the brightness setting.
+pub fn apply_time_of_day(
+    time_of_day: Res<TimeOfDay>,
+    mut ambient: ResMut<AmbientLight>,
+    mut suns: Query<(&mut PointLight, &mut Transform), With<Sun>>,
+) {
+    if !time_of_day.is_changed() {
+        return;
+    }
+
+    let lighting = time_of_day.lighting();
+    ambient.brightness = lighting.ambient_brightness;
+    for (mut light, mut transform) in &mut suns {
+        light.color = lighting.color;
+        light.intensity = lighting.intensity;
+        *transform = time_of_day.sun_transform();
+    }
+}
+
+/// Returns to neutral noon lighting when leaving a battle.
+pub fn reset_time_of_day(mut time_of_day: ResMut<TimeOfDay>) {
+    *time_of_day = TimeOfDay::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::battlefield::styles::{DAWN_LIGHTING, DUSK_LIGHTING, NIGHT_LIGHTING};
+    use crate::game::shared_systems::enforce_castle_collision;
+    use crate::game::units::archer::systems::spawn_initial_defender_archers;
+    use crate::game::units::components::{Hitbox, Team};
+    use crate::game::units::infantry::systems::spawn_initial_defenders;
+    use crate::game::units::king::components::KingSpawned;
+    use crate::game::units::king::systems::spawn_king;
+    use crate::game::units::wizard::systems::setup_wizard;
+
+    #[test]
+    fn test_castle_platform_leaves_starting_army_in_place() {
+        let mut app = App::new();
+        app.init_resource::<GameConfig>()
+            .init_resource::<BattlefieldConfig>()
+            .init_resource::<TimeOfDay>()
+            .init_resource::<KingSpawned>()
+            .insert_resource(AmbientLight::default())
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_systems(
+                Startup,
+                (
+                    setup_battlefield,
+                    setup_wizard,
+                    spawn_king,
+                    spawn_initial_defenders,
+                    spawn_initial_defender_archers,
+                ),
+            )
+            .add_systems(Update, enforce_castle_collision);
+        app.world_mut().run_schedule(Startup);
+
+        let mut units = app
+            .world_mut()
+            .query_filtered::<(Entity, &Transform), With<Hitbox>>();
+        let before: Vec<(Entity, Vec3)> = units
+            .iter(app.world())
+            .map(|(entity, transform)| (entity, transform.translation))
+            .collect();
+        assert!(before.len() > 100, "wizard, King, and defenders spawned");
+
+        app.update();
+        for (entity, position) in before {
+            assert_eq!(
+                app.world().get::<Transform>(entity).unwrap().translation,
+                position
+            );
+        }
+    }
+
+    #[test]
+    fn test_attacker_cannot_walk_onto_castle_platform() {
+        let mut app = App::new();
+        app.init_resource::<GameConfig>()
+            .init_resource::<BattlefieldConfig>()
+            .init_resource::<TimeOfDay>()
+            .insert_resource(AmbientLight::default())
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_systems(Startup, setup_battlefield)
+            .add_systems(Update, enforce_castle_collision);
+        app.world_mut().run_schedule(Startup);
+
+        let footprint = *app
+            .world_mut()
+            .query::<&CastleFootprint>()
+            .single(app.world())
+            .unwrap();
+        let hitbox = Hitbox::new(8.0, 21.0);
+        let start = footprint.center + footprint.right * 400.0;
+        let attacker = app
+            .world_mut()
+            .spawn((Team::Attackers, Transform::from_translation(start), hitbox))
+            .id();
+
+        // March straight at the platform center, one step per frame
+        for _ in 0..20 {
+            app.world_mut()
+                .get_mut::<Transform>(attacker)
+                .unwrap()
+                .translation -= footprint.right * 60.0;
+            app.update();
+        }
+
+        let position = app.world().get::<Transform>(attacker).unwrap().translation;
+        let edge_distance = footprint.half_width + hitbox.radius;
+        assert!(((position - footprint.center).dot(footprint.right) - edge_distance).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_sun_matches_selected_preset() {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            time_of_day: TimeOfDayMode::Dusk,
+            ..default()
+        })
+        .init_resource::<BattlefieldConfig>()
+        .init_resource::<TimeOfDay>()
+        .insert_resource(AmbientLight::default())
+        .init_resource::<Assets<Mesh>>()
+        .init_resource::<Assets<StandardMaterial>>()
+        .add_systems(Startup, setup_battlefield)
+        .add_systems(Update, apply_time_of_day);
+        app.update();
+
+        let (light, transform) = app
+            .world_mut()
+            .query_filtered::<(&PointLight, &Transform), With<Sun>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(light.color, DUSK_LIGHTING.color);
+        assert_eq!(light.intensity, DUSK_LIGHTING.intensity);
+
+        // Dusk sun sits low in the west (azimuth 180 degrees), shining east and down
+        let elevation = DUSK_LIGHTING.sun_elevation.to_radians();
+        let expected = Vec3::new(elevation.cos(), -elevation.sin(), 0.0);
+        assert!((-transform.translation.normalize() - expected).length() < 1e-4);
+        assert_eq!(
+            app.world().resource::<AmbientLight>().brightness,
+            DUSK_LIGHTING.ambient_brightness
+        );
+    }
+
+    #[test]
+    fn test_noon_keeps_the_original_overhead_light() {
+        let mut app = App::new();
+        app.init_resource::<GameConfig>()
+            .init_resource::<BattlefieldConfig>()
+            .init_resource::<TimeOfDay>()
+            .insert_resource(AmbientLight::default())
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_systems(Startup, setup_battlefield)
+            .add_systems(Update, apply_time_of_day);
+        app.update();
+
+        let (light, transform) = app
+            .world_mut()
+            .query_filtered::<(&PointLight, &Transform), With<Sun>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(light.color, Color::WHITE);
+        assert_eq!(light.intensity, 2_000_000.0);
+        assert!(!light.shadows_enabled);
+        assert!((transform.translation - Vec3::new(0.0, 1000.0, 0.0)).length() < 1e-2);
+        assert_eq!(
+            app.world().resource::<AmbientLight>().brightness,
+            AmbientLight::default().brightness
+        );
+    }
+
+    #[test]
+    fn test_cycle_blends_between_presets() {
+        let mut time_of_day = TimeOfDay::from_mode(TimeOfDayMode::Cycle);
+        assert_eq!(time_of_day, TimeOfDay::from_mode(TimeOfDayMode::Dawn));
+
+        // A quarter of the cycle later it is noon
+        time_of_day.advance(DAY_CYCLE_DURATION / 4.0);
+        assert!((time_of_day.phase - 1.0).abs() < 1e-4);
+
+        // Halfway from night to dawn the exposure sits between the two
+        let mut late_night = TimeOfDay { phase: 3.5 };
+        let lighting = late_night.lighting();
+        assert!(lighting.exposure < DAWN_LIGHTING.exposure);
+        assert!(lighting.exposure > NIGHT_LIGHTING.exposure);
+
+        // The clock wraps back around to dawn
+        late_night.advance(DAY_CYCLE_DURATION / 8.0);
+        assert!(late_night.phase.abs() < 1e-3 || (late_night.phase - 4.0).abs() < 1e-3);
+    }
+}
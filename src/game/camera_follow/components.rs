@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+
+use super::constants::DEFAULT_SMOOTHING;
+
+/// Camera follow mode and the camera's fixed home position.
+#[derive(Resource)]
+pub struct CameraFollow {
+    /// Whether the camera follows the action instead of staying fixed.
+    pub enabled: bool,
+    /// Rate at which the camera eases toward its target (higher = snappier).
+    pub smoothing: f32,
+    /// Camera translation in fixed mode, recorded the first time the camera is moved.
+    pub home: Option<Vec3>,
+}
+
+impl Default for CameraFollow {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            smoothing: DEFAULT_SMOOTHING,
+            home: None,
+        }
+    }
+}
+
+/// Returns the weighted centroid of the given (position, weight) points on the ground plane.
+///
+/// Returns None when there are no points or the weights sum to zero.
+pub fn weighted_centroid(points: impl IntoIterator<Item = (Vec3, f32)>) -> Option<Vec3> {
+    let (sum, total_weight) =
+        points
+            .into_iter()
+            .fold((Vec3::ZERO, 0.0), |(sum, total), (position, weight)| {
+                (
+                    sum + Vec3::new(position.x, 0.0, position.z) * weight,
+                    total + weight,
+                )
+            });
+
+    (total_weight > 0.0).then(|| sum / total_weight)
+}
+
+/// Eases `current` toward `target` at the given rate, independent of frame rate.
+pub fn ease_toward(current: Vec3, target: Vec3, smoothing: f32, delta: f32) -> Vec3 {
+    let t = 1.0 - (-smoothing * delta).exp();
+    current.lerp(target, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::camera_follow::constants::{FIGHTING_WEIGHT, UNIT_WEIGHT};
+
+    #[test]
+    fn test_centroid_is_pulled_toward_heavier_points() {
+        let idle = Vec3::new(100.0, 0.0, 0.0);
+        let fighting = Vec3::new(-100.0, 0.0, 0.0);
+
+        let even = weighted_centroid([(idle, UNIT_WEIGHT), (fighting, UNIT_WEIGHT)]).unwrap();
+        assert_eq!(even, Vec3::ZERO);
+
+        let weighted =
+            weighted_centroid([(idle, UNIT_WEIGHT), (fighting, FIGHTING_WEIGHT)]).unwrap();
+        let expected_x =
+            (100.0 * UNIT_WEIGHT - 100.0 * FIGHTING_WEIGHT) / (UNIT_WEIGHT + FIGHTING_WEIGHT);
+        assert!((weighted.x - expected_x).abs() < 1e-4);
+        assert!(weighted.x < 0.0);
+    }
+
+    #[test]
+    fn test_centroid_ignores_height_and_empty_input() {
+        let centroid = weighted_centroid([(Vec3::new(10.0, 500.0, 20.0), 2.0)]).unwrap();
+        assert_eq!(centroid, Vec3::new(10.0, 0.0, 20.0));
+
+        assert!(weighted_centroid(std::iter::empty()).is_none());
+        assert!(weighted_centroid([(Vec3::ONE, 0.0)]).is_none());
+    }
+
+    #[test]
+    fn test_ease_moves_partway_without_overshooting() {
+        let eased = ease_toward(Vec3::ZERO, Vec3::X * 100.0, DEFAULT_SMOOTHING, 0.1);
+        assert!(eased.x > 0.0 && eased.x < 100.0);
+    }
+}
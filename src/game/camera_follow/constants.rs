@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+/// Key that toggles between the fixed camera and following the action.
+pub const TOGGLE_KEY: KeyCode = KeyCode::KeyC;
+
+/// Ground point the fixed camera looks at.
+pub const HOME_FOCUS: Vec3 = Vec3::ZERO;
+
+/// Centroid weight of the wizard, so the view never strays far from the tower.
+pub const WIZARD_WEIGHT: f32 = 20.0;
+
+/// Centroid weight of a unit locked in melee.
+pub const FIGHTING_WEIGHT: f32 = 4.0;
+
+/// Centroid weight of any other living unit.
+pub const UNIT_WEIGHT: f32 = 1.0;
+
+/// Default rate at which the camera eases toward its target (higher = snappier).
+pub const DEFAULT_SMOOTHING: f32 = 1.5;
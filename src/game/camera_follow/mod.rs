@@ -0,0 +1,11 @@
+//! Camera follow module.
+//!
+//! Optionally eases the camera toward the centroid of the battle, weighted
+//! toward the wizard and units locked in melee, so off-center fights stay framed.
+
+mod components;
+mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::CameraFollowPlugin;
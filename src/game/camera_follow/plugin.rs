@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use crate::state::{AppState, InGameState};
+
+use super::components::CameraFollow;
+use super::systems;
+
+/// Plugin that lets the camera follow the action.
+///
+/// Registers systems for:
+/// - Toggling between the fixed and follow camera with a hotkey
+/// - Easing the camera toward the weighted battle centroid (or back home)
+/// - Returning the camera home when leaving the game
+pub struct CameraFollowPlugin;
+
+impl Plugin for CameraFollowPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraFollow>()
+            .add_systems(
+                Update,
+                (systems::toggle_camera_follow, systems::follow_action)
+                    .chain()
+                    .run_if(in_state(InGameState::Running)),
+            )
+            .add_systems(OnExit(AppState::InGame), systems::reset_camera);
+    }
+}
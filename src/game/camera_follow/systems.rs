@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::*;
+use crate::game::battlefield::components::BattlefieldConfig;
+use crate::game::selection::components::BoxSelection;
+use crate::game::units::components::{Corpse, InMelee, Team};
+use crate::game::units::wizard::components::Wizard;
+
+/// Toggles between the fixed and follow camera with its hotkey.
+pub fn toggle_camera_follow(keys: Res<ButtonInput<KeyCode>>, mut follow: ResMut<CameraFollow>) {
+    if keys.just_pressed(TOGGLE_KEY) {
+        follow.enabled = !follow.enabled;
+    }
+}
+
+/// Eases the camera toward the weighted centroid of the battle.
+///
+/// The camera keeps its angle and slides over the ground so the centroid
+/// (clamped to the battlefield) takes the place of its home focus point.
+/// With follow off, it eases back home instead. The camera holds still while
+/// a drag selection is in progress, so the box stays under the cursor.
+#[allow(clippy::type_complexity)]
+pub fn follow_action(
+    time: Res<Time>,
+    mut follow: ResMut<CameraFollow>,
+    box_selection: Res<BoxSelection>,
+    battlefield: Res<BattlefieldConfig>,
+    mut camera_query: Query<&mut Transform, With<Camera3d>>,
+    wizard_query: Query<&Transform, (With<Wizard>, Without<Camera3d>)>,
+    units: Query<(&Transform, Has<InMelee>), (With<Team>, Without<Corpse>, Without<Camera3d>)>,
+) {
+    let Ok(mut camera) = camera_query.single_mut() else {
+        return;
+    };
+    let home = *follow.home.get_or_insert(camera.translation);
+
+    if box_selection.start.is_some() {
+        return;
+    }
+
+    let focus = if follow.enabled {
+        let points = units
+            .iter()
+            .map(|(transform, fighting)| {
+                let weight = if fighting {
+                    FIGHTING_WEIGHT
+                } else {
+                    UNIT_WEIGHT
+                };
+                (transform.translation, weight)
+            })
+            .chain(
+                wizard_query
+                    .iter()
+                    .map(|transform| (transform.translation, WIZARD_WEIGHT)),
+            );
+        weighted_centroid(points)
+            .map(|centroid| {
+                let (x, z) = battlefield.clamp(centroid.x, centroid.z);
+                Vec3::new(x, HOME_FOCUS.y, z)
+            })
+            .unwrap_or(HOME_FOCUS)
+    } else {
+        HOME_FOCUS
+    };
+
+    let target = home + (focus - HOME_FOCUS);
+    camera.translation = ease_toward(
+        camera.translation,
+        target,
+        follow.smoothing,
+        time.delta_secs(),
+    );
+}
+
+/// Snaps the camera back home and turns follow off when leaving the game.
+///
+/// The main menu's background battle is framed for the fixed camera.
+pub fn reset_camera(
+    mut follow: ResMut<CameraFollow>,
+    mut camera_query: Query<&mut Transform, With<Camera3d>>,
+) {
+    follow.enabled = false;
+    if let Some(home) = follow.home
+        && let Ok(mut camera) = camera_query.single_mut()
+    {
+        camera.translation = home;
+    }
+}
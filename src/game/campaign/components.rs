@@ -0,0 +1,231 @@
+use bevy::prelude::*;
+
+use super::constants::{POINTS_PER_KILL, POINTS_PER_SURVIVOR};
+
+/// Phase of the current wave.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WavePhase {
+    /// Enemies are spawned but frozen while the player prepares.
+    Preparation {
+        /// Seconds until the attackers start advancing.
+        remaining: f32,
+    },
+    /// Enemies are on the field.
+    Fighting,
+    /// Wave cleared, waiting for the next wave to spawn.
+    Intermission {
+        /// Seconds until the next wave spawns.
+        remaining: f32,
+    },
+}
+
+/// Message sent when every attacker and undead unit has been killed.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct WaveCleared {
+    /// Living defenders at the moment the wave was cleared.
+    pub surviving_defenders: u32,
+}
+
+/// Index of the last wave reported by `WaveCleared`.
+///
+/// The field stays empty until the next wave spawns, so each clear is only
+/// reported once: `WaveCleared` is sent when the wave index differs from this.
+#[derive(Resource, Debug, Default)]
+pub struct LastClearedWave(pub Option<u32>);
+
+/// Tracks progress through a campaign of consecutive waves.
+///
+/// Reset when a new game starts or the player leaves the game over screen.
+#[derive(Resource, Debug)]
+pub struct CampaignState {
+    /// Number of waves cleared in this campaign.
+    pub waves_survived: u32,
+    /// Defenders alive when the last wave was cleared (carried into the next wave).
+    pub carried_over_defenders: u32,
+    /// Running score for this campaign.
+    pub score: u32,
+    /// Whether a wave is being fought or the campaign is between waves.
+    pub phase: WavePhase,
+    /// Total enemy kills at the end of the previous wave, used to score each wave.
+    enemy_kills_scored: u32,
+}
+
+impl Default for CampaignState {
+    fn default() -> Self {
+        Self {
+            waves_survived: 0,
+            carried_over_defenders: 0,
+            score: 0,
+            phase: WavePhase::Fighting,
+            enemy_kills_scored: 0,
+        }
+    }
+}
+
+impl CampaignState {
+    /// Records a cleared wave and starts the intermission.
+    ///
+    /// `total_enemy_kills` is the campaign-wide count of attackers and undead
+    /// killed; only kills since the previous wave are scored.
+    pub fn record_wave_cleared(
+        &mut self,
+        surviving_defenders: u32,
+        total_enemy_kills: u32,
+        intermission_duration: f32,
+    ) {
+        let wave_kills = total_enemy_kills.saturating_sub(self.enemy_kills_scored);
+        self.enemy_kills_scored = total_enemy_kills;
+
+        self.waves_survived += 1;
+        self.carried_over_defenders = surviving_defenders;
+        self.score += wave_kills * POINTS_PER_KILL + surviving_defenders * POINTS_PER_SURVIVOR;
+        self.phase = WavePhase::Intermission {
+            remaining: intermission_duration,
+        };
+    }
+
+    /// Advances the intermission timer. Returns true when the next wave should start.
+    pub fn tick_intermission(&mut self, delta: f32) -> bool {
+        let WavePhase::Intermission { remaining } = &mut self.phase else {
+            return false;
+        };
+
+        *remaining -= delta;
+        if *remaining <= 0.0 {
+            self.phase = WavePhase::Fighting;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns true while waiting between waves.
+    pub fn in_intermission(&self) -> bool {
+        matches!(self.phase, WavePhase::Intermission { .. })
+    }
+
+    /// Starts the preparation countdown for a freshly spawned wave.
+    ///
+    /// A non-positive duration skips preparation and starts the fight immediately.
+    pub fn start_preparation(&mut self, duration: f32) {
+        self.phase = if duration > 0.0 {
+            WavePhase::Preparation {
+                remaining: duration,
+            }
+        } else {
+            WavePhase::Fighting
+        };
+    }
+
+    /// Advances the preparation timer. Returns true when the attackers should start advancing.
+    pub fn tick_preparation(&mut self, delta: f32) -> bool {
+        let WavePhase::Preparation { remaining } = &mut self.phase else {
+            return false;
+        };
+
+        *remaining -= delta;
+        if *remaining <= 0.0 {
+            self.phase = WavePhase::Fighting;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns true while the attackers are frozen before a wave.
+    pub fn in_preparation(&self) -> bool {
+        matches!(self.phase, WavePhase::Preparation { .. })
+    }
+
+    /// Resets the campaign for a new game.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_survivors_carry_over_after_wave_clear() {
+        let mut campaign = CampaignState::default();
+        campaign.record_wave_cleared(42, 30, 5.0);
+
+        assert_eq!(campaign.waves_survived, 1);
+        assert_eq!(campaign.carried_over_defenders, 42);
+        assert!(campaign.in_intermission());
+    }
+
+    #[test]
+    fn test_survivor_count_updates_each_wave() {
+        let mut campaign = CampaignState::default();
+        campaign.record_wave_cleared(42, 30, 5.0);
+        assert!(campaign.tick_intermission(5.0));
+
+        // Losses in the second wave shrink the carried-over force
+        campaign.record_wave_cleared(17, 70, 5.0);
+        assert_eq!(campaign.waves_survived, 2);
+        assert_eq!(campaign.carried_over_defenders, 17);
+    }
+
+    #[test]
+    fn test_score_counts_only_new_kills() {
+        let mut campaign = CampaignState::default();
+        campaign.record_wave_cleared(10, 30, 5.0);
+        let first_wave = 30 * POINTS_PER_KILL + 10 * POINTS_PER_SURVIVOR;
+        assert_eq!(campaign.score, first_wave);
+
+        campaign.record_wave_cleared(5, 50, 5.0);
+        let second_wave = 20 * POINTS_PER_KILL + 5 * POINTS_PER_SURVIVOR;
+        assert_eq!(campaign.score, first_wave + second_wave);
+    }
+
+    #[test]
+    fn test_intermission_ends_after_duration() {
+        let mut campaign = CampaignState::default();
+        campaign.record_wave_cleared(10, 30, 5.0);
+
+        assert!(!campaign.tick_intermission(4.0));
+        assert!(campaign.in_intermission());
+        assert!(campaign.tick_intermission(1.0));
+        assert_eq!(campaign.phase, WavePhase::Fighting);
+
+        // No-op while fighting
+        assert!(!campaign.tick_intermission(1.0));
+    }
+
+    #[test]
+    fn test_preparation_counts_down_to_fighting() {
+        let mut campaign = CampaignState::default();
+        campaign.start_preparation(3.0);
+        assert!(campaign.in_preparation());
+
+        assert!(!campaign.tick_preparation(2.0));
+        assert!(campaign.in_preparation());
+        assert!(campaign.tick_preparation(1.0));
+        assert_eq!(campaign.phase, WavePhase::Fighting);
+
+        // No-op while fighting
+        assert!(!campaign.tick_preparation(1.0));
+    }
+
+    #[test]
+    fn test_zero_preparation_starts_fighting() {
+        let mut campaign = CampaignState::default();
+        campaign.start_preparation(0.0);
+        assert_eq!(campaign.phase, WavePhase::Fighting);
+    }
+
+    #[test]
+    fn test_reset_clears_campaign() {
+        let mut campaign = CampaignState::default();
+        campaign.record_wave_cleared(10, 30, 5.0);
+        campaign.reset();
+
+        assert_eq!(campaign.waves_survived, 0);
+        assert_eq!(campaign.carried_over_defenders, 0);
+        assert_eq!(campaign.score, 0);
+        assert_eq!(campaign.phase, WavePhase::Fighting);
+    }
+}
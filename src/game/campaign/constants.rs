@@ -0,0 +1,8 @@
+/// Duration of the intermission between waves in seconds.
+pub const INTERMISSION_DURATION: f32 = 10.0;
+
+/// Score awarded per attacker or undead killed.
+pub const POINTS_PER_KILL: u32 = 10;
+
+/// Score awarded per defender still alive when a wave is cleared.
+pub const POINTS_PER_SURVIVOR: u32 = 25;
@@ -0,0 +1,11 @@
+//! Wave campaign module.
+//!
+//! Clearing a wave starts a short intermission, after which the next, harder
+//! wave spawns while surviving defenders carry over.
+
+pub mod components;
+pub mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::CampaignPlugin;
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+use crate::game::run_conditions;
+use crate::game::win_lose_systems::check_win_lose_conditions;
+use crate::state::{AppState, InGameState};
+
+use super::components::{CampaignState, LastClearedWave, WaveCleared};
+use super::systems;
+
+/// Plugin that runs a campaign of consecutive waves.
+///
+/// Registers systems for:
+/// - Recording cleared waves and raising the level
+/// - Counting down the intermission and spawning the next wave
+/// - Counting down each wave's preparation phase
+/// - Resetting the campaign for each new game
+pub struct CampaignPlugin;
+
+impl Plugin for CampaignPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CampaignState>()
+            .init_resource::<LastClearedWave>()
+            .add_message::<WaveCleared>()
+            .add_systems(OnEnter(AppState::InGame), systems::reset_campaign)
+            .add_systems(OnExit(InGameState::GameOver), systems::reset_campaign)
+            .add_systems(
+                Update,
+                (
+                    // Endless mode schedules its own waves
+                    systems::handle_wave_cleared.run_if(run_conditions::in_campaign_mode),
+                    systems::advance_intermission.run_if(run_conditions::in_campaign_mode),
+                    systems::advance_preparation,
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running))
+                    .before(check_win_lose_conditions),
+            );
+    }
+}
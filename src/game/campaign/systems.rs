@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+
+use super::components::{CampaignState, LastClearedWave, WaveCleared};
+use super::constants::INTERMISSION_DURATION;
+use crate::config::{GameConfig, GameMode, Scores};
+use crate::game::plugin::GlobalAttackCycle;
+use crate::game::resources::{CurrentLevel, KillStats};
+use crate::game::units::archer::components::Arrow;
+use crate::game::units::archer::systems::spawn_initial_attacker_archers;
+use crate::game::units::components::Corpse;
+use crate::game::units::healer::systems::spawn_attacker_healers;
+use crate::game::units::infantry::systems::spawn_initial_attackers;
+use crate::game::waves::components::WaveDefinitions;
+use crate::game::waves::systems::spawn_defined_wave;
+
+/// Records a cleared wave, raises the level, and starts the intermission.
+///
+/// The game so far is also recorded in the persistent scores, so a won wave
+/// counts even if the game is never finished.
+pub fn handle_wave_cleared(
+    mut wave_cleared: MessageReader<WaveCleared>,
+    mut campaign: ResMut<CampaignState>,
+    kill_stats: Res<KillStats>,
+    mut current_level: ResMut<CurrentLevel>,
+    mut config: ResMut<GameConfig>,
+    mut scores: ResMut<Scores>,
+) {
+    let Some(cleared) = wave_cleared.read().last() else {
+        return;
+    };
+    if campaign.in_intermission() {
+        return;
+    }
+
+    let total_enemy_kills = kill_stats.attackers_killed + kill_stats.undead_killed;
+    campaign.record_wave_cleared(
+        cleared.surviving_defenders,
+        total_enemy_kills,
+        INTERMISSION_DURATION,
+    );
+    scores.record(
+        current_level.0,
+        kill_stats.attackers_killed,
+        kill_stats.undead_killed,
+    );
+
+    // Next wave is one level harder
+    current_level.0 += 1;
+    config.current_level = current_level.0;
+    if current_level.0 > config.highest_level_achieved {
+        config.highest_level_achieved = current_level.0;
+    }
+}
+
+/// Counts down the intermission and spawns the next wave when it ends.
+///
+/// Surviving defenders, the King, and the wizard are kept. Corpses and stray
+/// arrows from the previous wave are cleared before the new attackers arrive,
+/// and the new wave starts with a preparation countdown. Levels with a designed
+/// wave spawn it instead of the procedural formula.
+#[allow(clippy::too_many_arguments)]
+pub fn advance_intermission(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut campaign: ResMut<CampaignState>,
+    config: Res<GameConfig>,
+    mut attack_cycle: ResMut<GlobalAttackCycle>,
+    current_level: Res<CurrentLevel>,
+    wave_definitions: Res<WaveDefinitions>,
+    leftovers: Query<Entity, Or<(With<Corpse>, With<Arrow>)>>,
+) {
+    if !campaign.tick_intermission(time.delta_secs()) {
+        return;
+    }
+
+    for entity in &leftovers {
+        commands.entity(entity).despawn();
+    }
+    attack_cycle.current_time = 0.0;
+    campaign.start_preparation(config.preparation_duration);
+
+    // Spawn counts scale with CurrentLevel, which was raised when the wave was cleared
+    if wave_definitions.for_level(current_level.0).is_some() {
+        commands.run_system_cached(spawn_defined_wave);
+    } else {
+        commands.run_system_cached(spawn_initial_attackers);
+        commands.run_system_cached(spawn_initial_attacker_archers);
+        commands.run_system_cached(spawn_attacker_healers);
+    }
+}
+
+/// Counts down the preparation phase until the attackers start advancing.
+pub fn advance_preparation(time: Res<Time>, mut campaign: ResMut<CampaignState>) {
+    campaign.tick_preparation(time.delta_secs());
+}
+
+/// Resets the campaign when a new game starts and begins the first wave's preparation.
+///
+/// Practice games have no waves, so they skip straight to fighting.
+pub fn reset_campaign(
+    mut campaign: ResMut<CampaignState>,
+    mut last_cleared: ResMut<LastClearedWave>,
+    config: Res<GameConfig>,
+) {
+    campaign.reset();
+    last_cleared.0 = None;
+    if config.game_mode != GameMode::Sandbox {
+        campaign.start_preparation(config.preparation_duration);
+    }
+}
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+use super::constants::CASTLE_MAX_HEALTH;
+use crate::game::units::components::Health;
+
+/// Health of the castle the attackers are trying to bring down.
+///
+/// Kept as a resource rather than on the castle entity so area spells that hit
+/// everything with `Health` don't damage it.
+#[derive(Resource)]
+pub struct CastleHealth(pub Health);
+
+impl Default for CastleHealth {
+    fn default() -> Self {
+        Self(Health::new(CASTLE_MAX_HEALTH))
+    }
+}
+
+/// Returns true if any blocker stands within `width` of the ground path from
+/// `from` to `to`.
+pub fn path_is_blocked(from: Vec3, to: Vec3, blockers: &[Vec3], width: f32) -> bool {
+    let start = Vec2::new(from.x, from.z);
+    let path = Vec2::new(to.x, to.z) - start;
+    let length_squared = path.length_squared();
+
+    blockers.iter().any(|blocker| {
+        let offset = Vec2::new(blocker.x, blocker.z) - start;
+        let along = if length_squared > f32::EPSILON {
+            (offset.dot(path) / length_squared).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        offset.distance(path * along) < width
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defender_on_path_blocks_it() {
+        let from = Vec3::ZERO;
+        let to = Vec3::new(1000.0, 0.0, 0.0);
+
+        assert!(path_is_blocked(
+            from,
+            to,
+            &[Vec3::new(500.0, 0.0, 30.0)],
+            80.0
+        ));
+        // Off to the side or behind the attacker doesn't count
+        assert!(!path_is_blocked(
+            from,
+            to,
+            &[Vec3::new(500.0, 0.0, 200.0), Vec3::new(-300.0, 0.0, 0.0)],
+            80.0
+        ));
+        assert!(!path_is_blocked(from, to, &[], 80.0));
+    }
+}
@@ -0,0 +1,11 @@
+/// Castle health at the start of each battle.
+pub const CASTLE_MAX_HEALTH: f32 = 1000.0;
+
+/// Damage per second each attacker deals while at the castle walls.
+pub const CASTLE_SIEGE_DAMAGE_PER_SECOND: f32 = 5.0;
+
+/// How far beyond its hitbox an attacker can be from the walls and still hit them.
+pub const CASTLE_SIEGE_REACH: f32 = 15.0;
+
+/// Defenders this close to an attacker's path to the castle block it.
+pub const CASTLE_PATH_BLOCK_WIDTH: f32 = 80.0;
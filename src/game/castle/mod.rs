@@ -0,0 +1,11 @@
+//! Castle objective module.
+//!
+//! Gives the castle a health pool that attackers wear down once they reach its
+//! walls. The battle is lost if the castle falls, even while defenders remain.
+
+pub mod components;
+pub mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::CastlePlugin;
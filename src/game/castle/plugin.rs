@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use crate::game::plugin::MovementSystemSet;
+use crate::game::run_conditions;
+use crate::state::{AppState, InGameState};
+
+use super::components::CastleHealth;
+use super::systems;
+
+/// Plugin that makes the castle a destructible objective.
+///
+/// Registers systems for:
+/// - Attackers at the castle walls wearing down its health
+/// - Resetting castle health for each new game
+///
+/// Losing when the castle falls is handled by `check_win_lose_conditions`.
+pub struct CastlePlugin;
+
+impl Plugin for CastlePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CastleHealth>()
+            .add_systems(
+                FixedUpdate,
+                systems::siege_castle
+                    .run_if(in_state(InGameState::Running))
+                    .run_if(run_conditions::combat_started)
+                    .after(MovementSystemSet),
+            )
+            .add_systems(OnEnter(AppState::InGame), systems::reset_castle_health)
+            .add_systems(OnExit(InGameState::GameOver), systems::reset_castle_health);
+    }
+}
@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+
+use super::components::CastleHealth;
+use super::constants::*;
+use crate::game::battlefield::components::CastleFootprint;
+use crate::game::units::components::{Corpse, Hitbox, Team};
+
+/// Damages the castle for every attacker standing at its walls.
+pub fn siege_castle(
+    time: Res<Time>,
+    mut castle_health: ResMut<CastleHealth>,
+    castles: Query<&CastleFootprint>,
+    attackers: Query<(&Transform, &Hitbox, &Team), Without<Corpse>>,
+) {
+    let Ok(footprint) = castles.single() else {
+        return;
+    };
+
+    let besiegers = attackers
+        .iter()
+        .filter(|(transform, hitbox, team)| {
+            **team == Team::Attackers
+                && footprint.distance_to(transform.translation)
+                    <= hitbox.radius + CASTLE_SIEGE_REACH
+        })
+        .count();
+
+    if besiegers > 0 {
+        castle_health
+            .0
+            .take_damage(besiegers as f32 * CASTLE_SIEGE_DAMAGE_PER_SECOND * time.delta_secs());
+    }
+}
+
+/// Restores the castle to full health for a new game.
+pub fn reset_castle_health(mut castle_health: ResMut<CastleHealth>) {
+    *castle_health = CastleHealth::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::config::GameConfig;
+    use crate::game::campaign::components::{CampaignState, LastClearedWave, WaveCleared};
+    use crate::game::endless::components::EndlessState;
+    use crate::game::resources::GameOutcome;
+    use crate::game::units::components::Health;
+    use crate::game::units::king::components::KingSpawned;
+    use crate::game::win_lose_systems::check_win_lose_conditions;
+    use crate::state::{AppState, InGameState};
+
+    #[test]
+    fn test_castle_destruction_triggers_game_over() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                250,
+            )))
+            .insert_state(AppState::InGame)
+            .add_sub_state::<InGameState>()
+            .add_message::<WaveCleared>()
+            .insert_resource(GameOutcome::Victory)
+            .insert_resource(KingSpawned(false))
+            .init_resource::<GameConfig>()
+            .init_resource::<CampaignState>()
+            .init_resource::<LastClearedWave>()
+            .init_resource::<EndlessState>()
+            // One 250ms frame of siege damage from a single attacker is enough
+            .insert_resource(CastleHealth(Health::new(1.0)))
+            .add_systems(Update, (siege_castle, check_win_lose_conditions).chain());
+
+        let footprint = CastleFootprint::castle();
+        let hitbox = Hitbox::new(8.0, 25.0);
+        app.world_mut().spawn(footprint);
+        // An attacker at the walls while a defender is still alive elsewhere
+        app.world_mut().spawn((
+            Team::Attackers,
+            hitbox,
+            Transform::from_translation(
+                footprint.center + footprint.right * (footprint.half_width + hitbox.radius),
+            ),
+        ));
+        app.world_mut()
+            .spawn((Team::Defenders, hitbox, Transform::default()));
+
+        // The first frame only starts the clock
+        app.update();
+        app.update();
+        assert!(app.world().resource::<CastleHealth>().0.is_dead());
+
+        // The state change applies on the next frame
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<InGameState>>().get(),
+            InGameState::GameOver
+        );
+        assert!(*app.world().resource::<GameOutcome>() == GameOutcome::DefeatCastleDestroyed);
+    }
+}
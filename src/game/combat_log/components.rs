@@ -0,0 +1,144 @@
+use std::fmt::Write;
+
+use bevy::prelude::*;
+
+use super::constants::{CSV_HEADER, INITIAL_CAPACITY};
+use super::error::CombatLogResult;
+use super::export::save_csv;
+use crate::game::units::components::{DamageSource, Team};
+
+/// A single damage event in the combat log.
+#[derive(Debug, Clone, Copy)]
+pub struct CombatLogEntry {
+    /// Seconds of combat elapsed when the hit landed.
+    pub time: f32,
+    /// Level being played.
+    pub level: u32,
+    /// What dealt the hit.
+    pub source: DamageSource,
+    /// Team of the unit that was hit (None if it was already despawned).
+    pub target_team: Option<Team>,
+    /// Damage dealt after resistances.
+    pub amount: f32,
+    /// Whether the hit killed the unit.
+    pub lethal: bool,
+}
+
+/// Buffers damage events for the current battle.
+///
+/// Recording only pushes a small `Copy` struct; formatting happens once, when
+/// the log is flushed at the end of the battle.
+#[derive(Resource)]
+pub struct CombatLogger {
+    entries: Vec<CombatLogEntry>,
+}
+
+impl Default for CombatLogger {
+    fn default() -> Self {
+        Self {
+            entries: Vec::with_capacity(INITIAL_CAPACITY),
+        }
+    }
+}
+
+impl CombatLogger {
+    /// Buffers a damage event.
+    pub fn record(&mut self, entry: CombatLogEntry) {
+        self.entries.push(entry);
+    }
+
+    /// Returns true if nothing has been recorded since the last flush.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Formats the buffered events as CSV, header first.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::with_capacity(CSV_HEADER.len() + self.entries.len() * 40);
+        csv.push_str(CSV_HEADER);
+        csv.push('\n');
+
+        for entry in &self.entries {
+            // Writing to a String can't fail
+            let _ = writeln!(
+                csv,
+                "{:.3},{},{},{},{:.2},{}",
+                entry.time,
+                entry.level,
+                source_name(entry.source),
+                entry.target_team.map_or("unknown", team_name),
+                entry.amount,
+                entry.lethal
+            );
+        }
+        csv
+    }
+
+    /// Saves the buffered events as `file_name` and clears the buffer.
+    ///
+    /// Desktop builds write the file; the browser downloads it. Returns the
+    /// number of events written. The buffer is kept if saving fails.
+    pub fn flush(&mut self, file_name: &str) -> CombatLogResult<usize> {
+        save_csv(file_name, &self.to_csv())?;
+        let written = self.entries.len();
+        self.clear();
+        Ok(written)
+    }
+
+    /// Discards the buffered events.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Returns the CSV name of a damage source.
+fn source_name(source: DamageSource) -> &'static str {
+    match source {
+        DamageSource::Unit => "unit",
+        DamageSource::Spell(spell) => spell.name(),
+        DamageSource::Ultimate => "ultimate",
+    }
+}
+
+/// Returns the CSV name of a team.
+fn team_name(team: Team) -> &'static str {
+    match team {
+        Team::Defenders => "defenders",
+        Team::Attackers => "attackers",
+        Team::Undead => "undead",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::units::wizard::components::Spell;
+
+    #[test]
+    fn test_csv_has_one_row_per_event() {
+        let mut logger = CombatLogger::default();
+        logger.record(CombatLogEntry {
+            time: 1.5,
+            level: 3,
+            source: DamageSource::Spell(Spell::Fireball),
+            target_team: Some(Team::Attackers),
+            amount: 12.0,
+            lethal: true,
+        });
+        logger.record(CombatLogEntry {
+            time: 2.25,
+            level: 3,
+            source: DamageSource::Unit,
+            target_team: None,
+            amount: 4.5,
+            lethal: false,
+        });
+
+        assert_eq!(
+            logger.to_csv(),
+            format!(
+                "{CSV_HEADER}\n1.500,3,Fireball,attackers,12.00,true\n2.250,3,unit,unknown,4.50,false\n"
+            )
+        );
+    }
+}
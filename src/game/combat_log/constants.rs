@@ -0,0 +1,9 @@
+/// File name of the combat log: written to the working directory on desktop,
+/// downloaded in the browser.
+pub const COMBAT_LOG_PATH: &str = "combat_log.csv";
+
+/// Entries reserved up front so a typical battle never reallocates the buffer.
+pub const INITIAL_CAPACITY: usize = 8192;
+
+/// Header row of the CSV file.
+pub const CSV_HEADER: &str = "time,level,source,target_team,amount,lethal";
@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+/// Errors that can occur when exporting the combat log.
+#[derive(Error, Debug)]
+pub enum CombatLogError {
+    /// Failed to write the combat log file to disk.
+    #[error("Failed to write combat log: {0}")]
+    Write(#[from] std::io::Error),
+
+    /// The browser couldn't start the combat log download.
+    #[cfg(target_arch = "wasm32")]
+    #[error("Failed to download combat log: {0}")]
+    Download(&'static str),
+}
+
+/// Type alias for Results that can return CombatLogError.
+pub type CombatLogResult<T> = Result<T, CombatLogError>;
@@ -0,0 +1,46 @@
+//! Saving the exported combat log: a file on desktop, a download in the browser.
+
+use super::error::CombatLogResult;
+
+/// Saves `csv` as `file_name` in the working directory.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_csv(file_name: &str, csv: &str) -> CombatLogResult<()> {
+    std::fs::write(file_name, csv)?;
+    Ok(())
+}
+
+/// Offers `csv` to the player as a download named `file_name`.
+///
+/// The page has no file system, so the CSV is wrapped in a Blob and a
+/// temporary link to it is clicked.
+#[cfg(target_arch = "wasm32")]
+pub fn save_csv(file_name: &str, csv: &str) -> CombatLogResult<()> {
+    use wasm_bindgen::{JsCast, JsValue};
+    use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+    use super::error::CombatLogError;
+
+    let failed = |_| CombatLogError::Download("the browser refused the download");
+    let window = web_sys::window().ok_or(CombatLogError::Download("no window object"))?;
+    let document = window
+        .document()
+        .ok_or(CombatLogError::Download("no document"))?;
+
+    let options = BlobPropertyBag::new();
+    options.set_type("text/csv");
+    let parts = js_sys::Array::of1(&JsValue::from_str(csv));
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options).map_err(failed)?;
+    let url = Url::create_object_url_with_blob(&blob).map_err(failed)?;
+
+    let anchor: HtmlAnchorElement = document
+        .create_element("a")
+        .map_err(failed)?
+        .dyn_into()
+        .map_err(|_| CombatLogError::Download("could not create a download link"))?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    let _ = Url::revoke_object_url(&url);
+    Ok(())
+}
@@ -0,0 +1,14 @@
+//! Combat log export module.
+//!
+//! Buffers every damage event of a battle and saves them as a CSV file when
+//! the battle ends (downloaded in the browser), for analyzing balance outside
+//! the game. Only compiled with the `combat_log` cargo feature.
+
+mod components;
+mod constants;
+mod error;
+mod export;
+mod plugin;
+mod systems;
+
+pub use plugin::CombatLogPlugin;
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use crate::state::{AppState, InGameState};
+
+use super::components::CombatLogger;
+use super::systems;
+
+/// Plugin that exports a combat log for balance analysis.
+///
+/// Registers systems for:
+/// - Buffering damage events while the battle runs
+/// - Saving the log (a file on desktop, a download in the browser) when the
+///   battle ends or the player quits to the menu
+/// - Clearing the buffer when a new battle starts
+pub struct CombatLogPlugin;
+
+impl Plugin for CombatLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CombatLogger>()
+            .add_systems(OnEnter(AppState::InGame), systems::reset_combat_log)
+            .add_systems(OnExit(InGameState::GameOver), systems::reset_combat_log)
+            .add_systems(OnEnter(InGameState::GameOver), systems::flush_combat_log)
+            .add_systems(OnExit(AppState::InGame), systems::flush_combat_log)
+            .add_systems(
+                Update,
+                systems::record_damage_events.run_if(in_state(InGameState::Running)),
+            );
+    }
+}
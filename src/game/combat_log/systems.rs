@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+use super::components::{CombatLogEntry, CombatLogger};
+use super::constants::COMBAT_LOG_PATH;
+use crate::game::resources::{BattleTimer, CurrentLevel};
+use crate::game::units::components::{DamageDealt, Team};
+
+/// Buffers every damage event with the battle time, level, and target team.
+pub fn record_damage_events(
+    mut damage_dealt: MessageReader<DamageDealt>,
+    teams: Query<&Team>,
+    battle_timer: Res<BattleTimer>,
+    level: Res<CurrentLevel>,
+    mut logger: ResMut<CombatLogger>,
+) {
+    for damage in damage_dealt.read() {
+        logger.record(CombatLogEntry {
+            time: battle_timer.elapsed,
+            level: level.0,
+            source: damage.source,
+            target_team: teams.get(damage.target).ok().copied(),
+            amount: damage.amount,
+            lethal: damage.lethal,
+        });
+    }
+}
+
+/// Saves the combat log when the battle ends.
+///
+/// Errors (such as a read-only directory, or a browser that blocks the
+/// download) are logged and the game carries on.
+pub fn flush_combat_log(mut logger: ResMut<CombatLogger>) {
+    if logger.is_empty() {
+        return;
+    }
+
+    match logger.flush(COMBAT_LOG_PATH) {
+        Ok(written) => info!("Saved {written} combat log events to {COMBAT_LOG_PATH}"),
+        Err(e) => error!("{e}"),
+    }
+}
+
+/// Discards anything left over from a previous battle.
+pub fn reset_combat_log(mut logger: ResMut<CombatLogger>) {
+    logger.clear();
+}
@@ -11,6 +11,14 @@ pub struct OnGameplayScreen;
 #[derive(Component)]
 pub struct Billboard;
 
+/// Sprite scale of a unit before the unit size setting is applied.
+///
+/// `update_billboards` rewrites unit scales every frame, so units drawn larger
+/// or smaller than normal keep their own scale here. Units without one are
+/// drawn at `Vec3::ONE`.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct BaseScale(pub Vec3);
+
 /// Velocity component for moving units.
 ///
 /// Represents the unit's movement speed on the XZ plane (units per second).
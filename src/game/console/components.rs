@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+
+use super::constants::{MAX_HISTORY_LINES, MAX_SPAWN_COUNT};
+
+/// State of the developer console.
+#[derive(Resource, Default)]
+pub struct ConsoleState {
+    /// Whether the console is currently shown and capturing keyboard input.
+    pub open: bool,
+    /// Text typed on the current input line.
+    pub input: String,
+    /// Previous commands and their output, oldest first.
+    pub history: Vec<String>,
+}
+
+impl ConsoleState {
+    /// Appends a line to the history, dropping the oldest lines past the limit.
+    pub fn push_line(&mut self, line: impl Into<String>) {
+        self.history.push(line.into());
+        if self.history.len() > MAX_HISTORY_LINES {
+            let excess = self.history.len() - MAX_HISTORY_LINES;
+            self.history.drain(..excess);
+        }
+    }
+}
+
+/// A parsed console command, sent as a message for execution.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleCommand {
+    /// `spawn attacker <count>` - spawns attacker infantry at the cursor.
+    SpawnAttackers(u32),
+    /// `mana full` - refills the wizard's mana.
+    FillMana,
+    /// `kill all` - kills every living attacker.
+    KillAll,
+    /// `level <n>` - sets the current level.
+    SetLevel(u32),
+}
+
+impl ConsoleCommand {
+    /// Parses a command line, returning an error line for unknown or malformed commands.
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words.as_slice() {
+            ["spawn", "attacker", count] => match count.parse::<u32>() {
+                Ok(count) if (1..=MAX_SPAWN_COUNT).contains(&count) => {
+                    Ok(Self::SpawnAttackers(count))
+                }
+                _ => Err(format!("Count must be between 1 and {MAX_SPAWN_COUNT}")),
+            },
+            ["spawn", unit, ..] => Err(format!("Unknown unit type: {unit}")),
+            ["mana", "full"] => Ok(Self::FillMana),
+            ["kill", "all"] => Ok(Self::KillAll),
+            ["level", level] => match level.parse::<u32>() {
+                Ok(level) if level >= 1 => Ok(Self::SetLevel(level)),
+                _ => Err(format!("Invalid level: {level}")),
+            },
+            _ => Err(format!("Unknown command: {line}")),
+        }
+    }
+}
+
+/// Marker component for the console panel root node.
+#[derive(Component)]
+pub struct ConsoleRoot;
+
+/// Marker component for the console history text.
+#[derive(Component)]
+pub struct ConsoleHistoryText;
+
+/// Marker component for the console input line text.
+#[derive(Component)]
+pub struct ConsoleInputText;
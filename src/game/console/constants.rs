@@ -0,0 +1,8 @@
+/// Prompt shown before the input line.
+pub const PROMPT: &str = "> ";
+
+/// Maximum number of output lines kept in the console history.
+pub const MAX_HISTORY_LINES: usize = 10;
+
+/// Maximum number of units a single spawn command may create.
+pub const MAX_SPAWN_COUNT: u32 = 500;
@@ -0,0 +1,12 @@
+//! Developer console module.
+//!
+//! A backtick-toggled command console for testing spells against specific
+//! scenarios. Only compiled with the `debug_console` cargo feature.
+
+mod components;
+mod constants;
+mod plugin;
+mod styles;
+mod systems;
+
+pub use plugin::ConsolePlugin;
@@ -0,0 +1,43 @@
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+
+use crate::state::InGameState;
+
+use super::components::{ConsoleCommand, ConsoleState};
+use super::systems;
+
+/// Plugin that provides the developer console.
+///
+/// Registers systems for:
+/// - Toggling the console with the backtick key
+/// - Capturing typed commands while open
+/// - Dispatching commands (spawn units, refill mana, kill all, set level)
+/// - Blocking spell input while the console is open
+/// - Swallowing gameplay hotkeys while the console is open
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ConsoleState>()
+            .add_message::<ConsoleCommand>()
+            .add_systems(
+                PreUpdate,
+                systems::swallow_hotkeys_while_open
+                    .after(InputSystems)
+                    .run_if(in_state(InGameState::Running)),
+            )
+            .add_systems(
+                Update,
+                (
+                    systems::toggle_console,
+                    systems::block_spell_input_while_open,
+                    systems::handle_console_input,
+                    systems::execute_console_commands,
+                    systems::update_console_text,
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running)),
+            )
+            .add_systems(OnExit(InGameState::Running), systems::close_console);
+    }
+}
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+/// Background color of the console panel.
+pub const CONSOLE_BACKGROUND_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.75);
+
+/// Color of history output lines.
+pub const HISTORY_TEXT_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
+
+/// Color of the input line.
+pub const INPUT_TEXT_COLOR: Color = Color::srgb(0.95, 0.95, 0.95);
+
+/// Font size for all console text.
+pub const CONSOLE_FONT_SIZE: f32 = 16.0;
+
+/// Padding inside the console panel in pixels.
+pub const CONSOLE_PADDING: f32 = 10.0;
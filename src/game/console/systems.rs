@@ -0,0 +1,327 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::PROMPT;
+use super::styles::*;
+use crate::config::GameConfig;
+use crate::game::battlefield::components::BattlefieldConfig;
+use crate::game::components::OnGameplayScreen;
+use crate::game::constants::spawn_offset;
+use crate::game::input::components::CursorWorldPosition;
+use crate::game::input::events::BlockSpellInput;
+use crate::game::resources::CurrentLevel;
+use crate::game::units::components::{Corpse, Health, Team};
+use crate::game::units::infantry::systems::spawn_attacker_infantry;
+use crate::game::units::wizard::components::{Mana, Wizard};
+
+/// Toggles the console open and closed with the backtick key.
+pub fn toggle_console(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut console: ResMut<ConsoleState>,
+    root_query: Query<Entity, With<ConsoleRoot>>,
+) {
+    if !keys.just_pressed(KeyCode::Backquote) {
+        return;
+    }
+
+    console.open = !console.open;
+    console.input.clear();
+
+    if console.open {
+        spawn_console_ui(&mut commands);
+    } else {
+        for entity in &root_query {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Swallows gameplay hotkeys while the console is open.
+///
+/// Typed characters reach the console through `KeyboardInput` messages, but
+/// `ButtonInput<KeyCode>` still records them, so overlay and camera hotkeys
+/// would fire mid-command. Every key except the backtick is reset so the
+/// console can still be closed.
+pub fn swallow_hotkeys_while_open(
+    console: Res<ConsoleState>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+) {
+    if !console.open {
+        return;
+    }
+
+    let typed: Vec<KeyCode> = keys
+        .get_pressed()
+        .chain(keys.get_just_released())
+        .copied()
+        .filter(|key| *key != KeyCode::Backquote)
+        .collect();
+    for key in typed {
+        keys.reset(key);
+    }
+}
+
+/// Blocks spell casting while the console is open.
+pub fn block_spell_input_while_open(
+    console: Res<ConsoleState>,
+    mut block_spell_input: MessageWriter<BlockSpellInput>,
+) {
+    if console.open {
+        block_spell_input.write(BlockSpellInput);
+    }
+}
+
+/// Captures typed text while the console is open.
+///
+/// Enter parses the input line and sends the command for execution.
+/// Unknown or malformed commands print an error line instead.
+pub fn handle_console_input(
+    mut keyboard_input: MessageReader<KeyboardInput>,
+    mut console: ResMut<ConsoleState>,
+    mut console_commands: MessageWriter<ConsoleCommand>,
+) {
+    if !console.open {
+        keyboard_input.clear();
+        return;
+    }
+
+    for event in keyboard_input.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+
+                console.push_line(format!("{PROMPT}{line}"));
+                match ConsoleCommand::parse(line) {
+                    Ok(command) => {
+                        console_commands.write(command);
+                    }
+                    Err(error) => console.push_line(error),
+                }
+            }
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Space => console.input.push(' '),
+            Key::Character(text) => {
+                // The toggle key arrives as text too
+                console
+                    .input
+                    .extend(text.chars().filter(|c| *c != '`' && !c.is_control()));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Executes parsed console commands.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_console_commands(
+    mut commands: Commands,
+    mut console_commands: MessageReader<ConsoleCommand>,
+    mut console: ResMut<ConsoleState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut current_level: ResMut<CurrentLevel>,
+    mut config: ResMut<GameConfig>,
+    battlefield: Res<BattlefieldConfig>,
+    mut mana_query: Query<&mut Mana, With<Wizard>>,
+    mut units: Query<(&mut Health, &Team), Without<Corpse>>,
+    cursor: Res<CursorWorldPosition>,
+) {
+    for command in console_commands.read() {
+        let output = match *command {
+            ConsoleCommand::SpawnAttackers(count) => {
+                // Spawn at the cursor, or at the front attacker cell if the cursor is off the field
+                let (center_x, center_z) = cursor
+                    .ground
+                    .map(|pos| (pos.x, pos.z))
+                    .unwrap_or_else(|| battlefield.grid_cell_position(0, 0));
+
+                for i in 0..count {
+                    let offset = spawn_offset(i);
+                    spawn_attacker_infantry(
+                        &mut commands,
+                        &mut meshes,
+                        &mut materials,
+                        center_x + offset.x,
+                        center_z + offset.y,
+                    );
+                }
+                format!("Spawned {count} attackers")
+            }
+            ConsoleCommand::FillMana => {
+                for mut mana in &mut mana_query {
+                    mana.refill();
+                }
+                "Mana refilled".to_string()
+            }
+            ConsoleCommand::KillAll => {
+                let mut killed = 0;
+                for (mut health, team) in &mut units {
+                    if *team == Team::Attackers {
+                        health.current = 0.0;
+                        killed += 1;
+                    }
+                }
+                format!("Killed {killed} attackers")
+            }
+            ConsoleCommand::SetLevel(level) => {
+                current_level.0 = level;
+                config.current_level = level;
+                format!("Level set to {level} (applies to the next game)")
+            }
+        };
+
+        console.push_line(output);
+    }
+}
+
+/// Refreshes the console text when the console state changes.
+pub fn update_console_text(
+    console: Res<ConsoleState>,
+    mut history_query: Query<&mut Text, (With<ConsoleHistoryText>, Without<ConsoleInputText>)>,
+    mut input_query: Query<&mut Text, (With<ConsoleInputText>, Without<ConsoleHistoryText>)>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+
+    for mut text in &mut history_query {
+        **text = console.history.join("\n");
+    }
+    for mut text in &mut input_query {
+        **text = format!("{PROMPT}{}_", console.input);
+    }
+}
+
+/// Closes the console when leaving the Running state.
+pub fn close_console(
+    mut commands: Commands,
+    mut console: ResMut<ConsoleState>,
+    root_query: Query<Entity, With<ConsoleRoot>>,
+) {
+    console.open = false;
+    console.input.clear();
+    for entity in &root_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Spawns the console panel across the top of the screen.
+fn spawn_console_ui(commands: &mut Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(CONSOLE_PADDING)),
+                ..default()
+            },
+            BackgroundColor(CONSOLE_BACKGROUND_COLOR),
+            GlobalZIndex(100),
+            ConsoleRoot,
+            OnGameplayScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: CONSOLE_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(HISTORY_TEXT_COLOR),
+                ConsoleHistoryText,
+            ));
+            parent.spawn((
+                Text::new(PROMPT),
+                TextFont {
+                    font_size: CONSOLE_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(INPUT_TEXT_COLOR),
+                ConsoleInputText,
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stand-in for an overlay toggled by a letter hotkey, read the same way
+    /// the range and effectiveness overlays read theirs.
+    #[derive(Resource, Default)]
+    struct Overlay {
+        enabled: bool,
+    }
+
+    fn toggle_overlay(keys: Res<ButtonInput<KeyCode>>, mut overlay: ResMut<Overlay>) {
+        if keys.just_pressed(KeyCode::KeyV) {
+            overlay.enabled = !overlay.enabled;
+        }
+    }
+
+    fn overlay_app(console_open: bool) -> App {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Overlay>()
+            .insert_resource(ConsoleState {
+                open: console_open,
+                ..default()
+            })
+            .add_systems(PreUpdate, swallow_hotkeys_while_open)
+            .add_systems(Update, toggle_overlay);
+        app
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(key);
+        app.update();
+    }
+
+    #[test]
+    fn test_typing_with_console_open_does_not_toggle_overlay() {
+        let mut app = overlay_app(true);
+
+        press(&mut app, KeyCode::KeyV);
+
+        assert!(!app.world().resource::<Overlay>().enabled);
+    }
+
+    #[test]
+    fn test_hotkey_toggles_overlay_with_console_closed() {
+        let mut app = overlay_app(false);
+
+        press(&mut app, KeyCode::KeyV);
+
+        assert!(app.world().resource::<Overlay>().enabled);
+    }
+
+    #[test]
+    fn test_backtick_still_reaches_toggle_while_console_open() {
+        let mut app = overlay_app(true);
+
+        press(&mut app, KeyCode::Backquote);
+
+        assert!(
+            app.world()
+                .resource::<ButtonInput<KeyCode>>()
+                .just_pressed(KeyCode::Backquote)
+        );
+    }
+}
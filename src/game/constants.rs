@@ -5,11 +5,16 @@
 
 use bevy::prelude::*;
 
+use crate::config::Difficulty;
+
 // ===== Battlefield Dimensions =====
 
 /// Size of the battlefield (width and depth).
 pub const BATTLEFIELD_SIZE: f32 = 6000.0;
 
+/// Size of the large battlefield option (width and depth).
+pub const LARGE_BATTLEFIELD_SIZE: f32 = 9000.0;
+
 // ===== Castle Positioning =====
 
 /// Castle position in 3D space.
@@ -74,11 +79,22 @@ pub const ATTACKER_HITBOX_HEIGHT: f32 = 20.0;
 
 // ===== Spawn Distribution =====
 
-/// Offset multiplier for distributing spawned units in a pattern.
-pub const SPAWN_OFFSET_MULTIPLIER: f32 = 0.31415;
+/// Golden angle in radians: each spawned unit turns this far from the previous one.
+pub const SPAWN_GOLDEN_ANGLE: f32 = 2.399_963;
 
-/// Radius of the spawn distribution area (units spawn within this radius).
-pub const SPAWN_DISTRIBUTION_RADIUS: f32 = 50.0;
+/// Scale of the spawn spiral. The n-th unit sits `SPAWN_SPACING * sqrt(n + 0.5)`
+/// from the spawn point, which keeps neighbors about 1.5x this far apart.
+pub const SPAWN_SPACING: f32 = 12.0;
+
+/// Offset from a spawn point for the `index`-th unit of a group.
+///
+/// Lays units out on a golden-angle (phyllotaxis) spiral whose radius grows
+/// with the index, so a group of any size starts without overlapping hitboxes.
+pub fn spawn_offset(index: u32) -> Vec2 {
+    let angle = index as f32 * SPAWN_GOLDEN_ANGLE;
+    let radius = SPAWN_SPACING * (index as f32 + 0.5).sqrt();
+    Vec2::new(angle.sin(), angle.cos()) * radius
+}
 
 // ===== Movement Constants =====
 
@@ -126,6 +142,18 @@ pub const MIN_DISTANCE_THRESHOLD: f32 = 0.01;
 /// Collision resolution iterations (higher = more accurate but more expensive).
 pub const COLLISION_ITERATIONS: u32 = 4;
 
+/// Frames of position history kept for packed units when watching for jitter.
+pub const JITTER_HISTORY_FRAMES: usize = 8;
+
+/// Overlapping neighbors needed before a unit counts as surrounded.
+pub const JITTER_MIN_NEIGHBORS: usize = 3;
+
+/// A surrounded unit that stays within this distance over its history is stuck.
+pub const JITTER_STILL_DISTANCE: f32 = 0.5;
+
+/// How far a stuck unit is nudged to break a symmetric lock.
+pub const JITTER_NUDGE_DISTANCE: f32 = 2.0;
+
 // ===== Targeting Constants =====
 
 // ===== Combat Constants =====
@@ -139,6 +167,23 @@ pub const ATTACK_DAMAGE: f32 = 10.0;
 /// Duration of one complete attack cycle in seconds.
 pub const ATTACK_CYCLE_DURATION: f32 = 2.0;
 
+/// Furthest a single blast can shove a unit (in units).
+pub const MAX_KNOCKBACK_DISPLACEMENT: f32 = 40.0;
+
+/// Outward velocity gained per unit of knockback displacement.
+pub const KNOCKBACK_VELOCITY_SCALE: f32 = 8.0;
+
+// ===== Entity Budget =====
+
+/// Default cap on living units plus corpses before old corpses are cleared.
+pub const DEFAULT_MAX_ENTITIES: usize = 2000;
+
+/// Seconds between checks of the entity budget.
+pub const CORPSE_CLEANUP_INTERVAL: f32 = 1.0;
+
+/// Seconds a decaying corpse spends fading out before it disappears.
+pub const CORPSE_FADE_DURATION: f32 = 3.0;
+
 // ===== Effectiveness System =====
 
 /// Bonus to effectiveness per ally in melee range (+10% each).
@@ -201,6 +246,105 @@ pub const fn calculate_total_archers(level: u32) -> u32 {
     BASE_ARCHER_COUNT + (level - 1) * ARCHERS_PER_LEVEL
 }
 
+/// First level at which attacker standard bearers join the waves.
+pub const STANDARD_BEARER_MIN_LEVEL: u32 = 6;
+
+/// Returns the indices of the infantry cells that get a standard bearer.
+///
+/// From `STANDARD_BEARER_MIN_LEVEL`, every full group (`MAX_UNITS_PER_CELL`
+/// infantry) carries one banner.
+pub fn standard_bearer_cells(level: u32, units_per_cell: &[u32]) -> Vec<usize> {
+    if level < STANDARD_BEARER_MIN_LEVEL {
+        return Vec::new();
+    }
+
+    units_per_cell
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count >= MAX_UNITS_PER_CELL)
+        .map(|(cell_idx, _)| cell_idx)
+        .collect()
+}
+
+/// First level at which attacker healers join the waves.
+pub const HEALER_MIN_LEVEL: u32 = 4;
+
+/// Levels between each additional healer.
+pub const LEVELS_PER_HEALER: u32 = 3;
+
+/// Calculates total attacker healers for a given level.
+pub const fn calculate_total_healers(level: u32) -> u32 {
+    if level < HEALER_MIN_LEVEL {
+        0
+    } else {
+        1 + (level - HEALER_MIN_LEVEL) / LEVELS_PER_HEALER
+    }
+}
+
+/// First level at which attacker dispellers join the waves.
+pub const DISPELLER_MIN_LEVEL: u32 = 10;
+
+/// Levels between each additional dispeller.
+pub const LEVELS_PER_DISPELLER: u32 = 5;
+
+/// Calculates total attacker dispellers for a given level.
+pub const fn calculate_total_dispellers(level: u32) -> u32 {
+    if level < DISPELLER_MIN_LEVEL {
+        0
+    } else {
+        1 + (level - DISPELLER_MIN_LEVEL) / LEVELS_PER_DISPELLER
+    }
+}
+
+/// Ground-plane distance from the wizard within which `ManaDrain` hits land.
+///
+/// Reaches past the castle edge, since units can't climb onto the platform.
+pub const MANA_DRAIN_REACH: f32 = 350.0;
+
+// ===== Flanking =====
+
+/// Fraction of attacker infantry groups that flank at level 1.
+pub const BASE_FLANK_FRACTION: f32 = 0.2;
+
+/// Flank fraction added per level after level 1.
+pub const FLANK_FRACTION_PER_LEVEL: f32 = 0.02;
+
+/// Upper bound on the fraction of groups that flank.
+pub const MAX_FLANK_FRACTION: f32 = 0.5;
+
+/// How far to the side of the castle flankers aim (in units).
+pub const FLANK_OFFSET: f32 = 800.0;
+
+/// Flankers stop flanking and engage once an enemy is this close (in units).
+pub const FLANK_ENGAGE_DISTANCE: f32 = 400.0;
+
+// ===== Formations =====
+
+/// First level at which attacker infantry groups advance as shield wall formations.
+pub const FORMATION_MIN_LEVEL: u32 = 8;
+
+/// Alignment multiplier for formation members (see `FlockingModifier`).
+pub const FORMATION_ALIGNMENT: f32 = 3.0;
+
+/// Cohesion multiplier for formation members (see `FlockingModifier`).
+pub const FORMATION_COHESION: f32 = 3.0;
+
+/// A formation breaks into a charge once any member is this close to its target (in units).
+pub const FORMATION_BREAK_DISTANCE: f32 = 150.0;
+
+/// Calculates the fraction of attacker infantry groups that flank.
+///
+/// Grows with level and is scaled by difficulty.
+pub fn calculate_flank_fraction(level: u32, difficulty: Difficulty) -> f32 {
+    let difficulty_multiplier = match difficulty {
+        Difficulty::Easy => 0.5,
+        Difficulty::Normal => 1.0,
+        Difficulty::Hard => 1.5,
+    };
+    let fraction = BASE_FLANK_FRACTION + level.saturating_sub(1) as f32 * FLANK_FRACTION_PER_LEVEL;
+    (fraction * difficulty_multiplier).min(MAX_FLANK_FRACTION)
+}
+
 /// Calculates the number of cells needed for a unit count (ceil division by MAX_UNITS_PER_CELL).
 pub const fn cells_needed(total_units: u32) -> u32 {
     total_units.div_ceil(MAX_UNITS_PER_CELL)
@@ -235,13 +379,16 @@ pub fn distribute_units_to_cells(total_units: u32) -> Vec<u32> {
 /// # Arguments
 /// * `row` - Row index (0 = closest to wizard)
 /// * `col` - Column index (0-5, centered around center angle)
+/// * `scale` - Battlefield size relative to the standard map; stretches the
+///   depth of each row while row 0 stays tangent to the range ring
 ///
 /// # Returns
 /// Tuple of (x, z) world coordinates for the cell center
-pub fn calculate_grid_cell_position(row: u32, col: u32) -> (f32, f32) {
+pub fn calculate_grid_cell_position(row: u32, col: u32, scale: f32) -> (f32, f32) {
     let col_offset = col as f32 - 2.5; // centers 6 columns: -2.5 .. 2.5
     let angle = GRID_CENTER_ANGLE + col_offset * GRID_ANGULAR_SPACING;
-    let radius = GRID_GROUND_RANGE + GRID_ROW_DEPTH / 2.0 + row as f32 * GRID_ROW_DEPTH;
+    let row_depth = GRID_ROW_DEPTH * scale;
+    let radius = GRID_GROUND_RANGE + row_depth / 2.0 + row as f32 * row_depth;
     let x = WIZARD_POSITION.x + radius * angle.cos();
     let z = WIZARD_POSITION.z + radius * angle.sin();
     (x, z)
@@ -303,3 +450,23 @@ pub fn calculate_spawn_cells(
 
     (infantry_cells, archer_cells)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::units::infantry::styles::UNIT_RADIUS;
+    use crate::game::units::standard_bearer::styles::STANDARD_BEARER_RADIUS;
+
+    #[test]
+    fn test_spawn_offsets_never_overlap() {
+        let offsets: Vec<Vec2> = (0..INITIAL_DEFENDER_COUNT).map(spawn_offset).collect();
+
+        // The largest unit spawned in groups must still clear its neighbors
+        let min_distance = 2.0 * STANDARD_BEARER_RADIUS.max(UNIT_RADIUS);
+        for (i, a) in offsets.iter().enumerate() {
+            for b in &offsets[i + 1..] {
+                assert!(a.distance(*b) > min_distance);
+            }
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use bevy::prelude::*;
+
+use super::constants::{
+    CYCLE_HISTOGRAM_BAR_WIDTH, CYCLE_HISTOGRAM_BUCKETS, SELECTED_MARKER, STEPS_PER_RANGE,
+};
+use crate::game::resources::TunableConstants;
+use crate::game::units::components::Team;
+
+/// State of the tuning panel.
+#[derive(Resource, Default)]
+pub struct DevToolsState {
+    /// Whether the panel is currently shown.
+    pub open: bool,
+    /// Index into `TunableField::all()` of the selected row.
+    pub selected: usize,
+}
+
+/// A constant exposed in the tuning panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TunableField {
+    SteeringForce,
+    VelocityDamping,
+    SeparationStrength,
+    AlignmentStrength,
+    CohesionStrength,
+    EffectivenessAllyBonus,
+    EffectivenessEnemyPenalty,
+}
+
+impl TunableField {
+    /// Returns all fields in display order.
+    pub const fn all() -> [Self; 7] {
+        [
+            Self::SteeringForce,
+            Self::VelocityDamping,
+            Self::SeparationStrength,
+            Self::AlignmentStrength,
+            Self::CohesionStrength,
+            Self::EffectivenessAllyBonus,
+            Self::EffectivenessEnemyPenalty,
+        ]
+    }
+
+    /// Returns the label shown in the panel.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::SteeringForce => "Steering Force",
+            Self::VelocityDamping => "Velocity Damping",
+            Self::SeparationStrength => "Separation Strength",
+            Self::AlignmentStrength => "Alignment Strength",
+            Self::CohesionStrength => "Cohesion Strength",
+            Self::EffectivenessAllyBonus => "Ally Bonus / Unit",
+            Self::EffectivenessEnemyPenalty => "Enemy Penalty / Unit",
+        }
+    }
+
+    /// Returns the allowed (min, max) range.
+    pub const fn range(self) -> (f32, f32) {
+        match self {
+            Self::SteeringForce => (0.0, 2000.0),
+            Self::VelocityDamping => (0.5, 1.0),
+            Self::SeparationStrength | Self::AlignmentStrength | Self::CohesionStrength => {
+                (0.0, 1.0)
+            }
+            Self::EffectivenessAllyBonus => (0.0, 0.5),
+            Self::EffectivenessEnemyPenalty => (-0.5, 0.0),
+        }
+    }
+
+    /// Reads this field from the tunables.
+    pub fn get(self, tunables: &TunableConstants) -> f32 {
+        match self {
+            Self::SteeringForce => tunables.steering_force,
+            Self::VelocityDamping => tunables.velocity_damping,
+            Self::SeparationStrength => tunables.separation_strength,
+            Self::AlignmentStrength => tunables.alignment_strength,
+            Self::CohesionStrength => tunables.cohesion_strength,
+            Self::EffectivenessAllyBonus => tunables.effectiveness_ally_bonus,
+            Self::EffectivenessEnemyPenalty => tunables.effectiveness_enemy_penalty,
+        }
+    }
+
+    /// Writes this field to the tunables, clamped to its range.
+    pub fn set(self, tunables: &mut TunableConstants, value: f32) {
+        let (min, max) = self.range();
+        let value = value.clamp(min, max);
+        let slot = match self {
+            Self::SteeringForce => &mut tunables.steering_force,
+            Self::VelocityDamping => &mut tunables.velocity_damping,
+            Self::SeparationStrength => &mut tunables.separation_strength,
+            Self::AlignmentStrength => &mut tunables.alignment_strength,
+            Self::CohesionStrength => &mut tunables.cohesion_strength,
+            Self::EffectivenessAllyBonus => &mut tunables.effectiveness_ally_bonus,
+            Self::EffectivenessEnemyPenalty => &mut tunables.effectiveness_enemy_penalty,
+        };
+        *slot = value;
+    }
+
+    /// Moves this field by `steps` adjustment steps.
+    pub fn adjust(self, tunables: &mut TunableConstants, steps: f32) {
+        let (min, max) = self.range();
+        let step = (max - min) / STEPS_PER_RANGE;
+        self.set(tunables, self.get(tunables) + step * steps);
+    }
+}
+
+/// Whether per-unit debug labels are shown.
+#[derive(Resource, Default)]
+pub struct UnitLabels {
+    pub enabled: bool,
+}
+
+/// One unit debug label from the fixed pool, identified by its slot.
+#[derive(Component)]
+pub struct UnitLabel(pub usize);
+
+/// What a unit debug label reports about its unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitLabelInfo {
+    pub entity: Entity,
+    pub team: Team,
+    pub in_melee: bool,
+    /// Distance to the current target, if the unit has one.
+    pub distance_to_target: Option<f32>,
+    /// Whether an archer is backing away from a target inside its minimum range.
+    pub retreating: bool,
+}
+
+impl UnitLabelInfo {
+    /// Returns the label text, e.g. "12v3 Attackers melee d=48".
+    pub fn text(&self) -> String {
+        let mut text = format!("{} {:?}", self.entity, self.team);
+        if self.in_melee {
+            text.push_str(" melee");
+        }
+        if self.retreating {
+            text.push_str(" retreat");
+        }
+        if let Some(distance) = self.distance_to_target {
+            text.push_str(&format!(" d={distance:.0}"));
+        }
+        text
+    }
+}
+
+/// Whether the attack cycle readout is shown.
+#[derive(Resource, Default)]
+pub struct CycleReadout {
+    pub enabled: bool,
+}
+
+/// Marker component for the attack cycle readout text.
+#[derive(Component)]
+pub struct CycleReadoutText;
+
+/// Units' attack offsets in the global attack cycle, bucketed by time.
+///
+/// Clustered buckets mean units strike together in waves; an even spread
+/// means the stagger is working.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttackCycleHistogram {
+    /// Units whose offset falls in each equal slice of the cycle.
+    pub buckets: [u32; CYCLE_HISTOGRAM_BUCKETS],
+    /// Units that have never attacked and may strike at any time.
+    pub unscheduled: u32,
+}
+
+impl AttackCycleHistogram {
+    /// Buckets each unit's `last_attack_time` across a cycle of `cycle_duration` seconds.
+    pub fn from_offsets(
+        offsets: impl IntoIterator<Item = Option<f32>>,
+        cycle_duration: f32,
+    ) -> Self {
+        let mut histogram = Self {
+            buckets: [0; CYCLE_HISTOGRAM_BUCKETS],
+            unscheduled: 0,
+        };
+        for offset in offsets {
+            match offset {
+                Some(time) => histogram.buckets[bucket_index(time, cycle_duration)] += 1,
+                None => histogram.unscheduled += 1,
+            }
+        }
+        histogram
+    }
+
+    /// Returns the readout text: the current cycle time, then one bar per
+    /// bucket with the bucket the cycle is in marked.
+    pub fn text(&self, current_time: f32, cycle_duration: f32) -> String {
+        let bucket_width = cycle_duration / CYCLE_HISTOGRAM_BUCKETS as f32;
+        let current = bucket_index(current_time, cycle_duration);
+        let fullest = self.buckets.iter().copied().max().unwrap_or(0).max(1);
+
+        let mut text = format!("Attack cycle {current_time:.2}s / {cycle_duration:.2}s");
+        for (index, count) in self.buckets.iter().enumerate() {
+            let marker = if index == current {
+                SELECTED_MARKER
+            } else {
+                "  "
+            };
+            let bar = "#".repeat(*count as usize * CYCLE_HISTOGRAM_BAR_WIDTH / fullest as usize);
+            text.push_str(&format!(
+                "\n{marker}{:.2}s {bar} {count}",
+                index as f32 * bucket_width
+            ));
+        }
+        text.push_str(&format!("\nNever attacked: {}", self.unscheduled));
+        text
+    }
+}
+
+/// Returns the histogram bucket that `time` falls in, wrapping to the cycle.
+fn bucket_index(time: f32, cycle_duration: f32) -> usize {
+    let fraction = (time / cycle_duration).rem_euclid(1.0);
+    ((fraction * CYCLE_HISTOGRAM_BUCKETS as f32) as usize).min(CYCLE_HISTOGRAM_BUCKETS - 1)
+}
+
+/// Marker component for the tuning panel root node.
+#[derive(Component)]
+pub struct DevToolsRoot;
+
+/// Marker component for the tuning panel text.
+#[derive(Component)]
+pub struct DevToolsText;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_lie_within_ranges() {
+        let tunables = TunableConstants::default();
+        for field in TunableField::all() {
+            let (min, max) = field.range();
+            let value = field.get(&tunables);
+            assert!(
+                (min..=max).contains(&value),
+                "{} default {value} outside {min}..={max}",
+                field.name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_unit_label_lists_active_states() {
+        let entity = Entity::from_raw_u32(12).unwrap();
+        let mut info = UnitLabelInfo {
+            entity,
+            team: Team::Attackers,
+            in_melee: false,
+            distance_to_target: None,
+            retreating: false,
+        };
+        assert_eq!(info.text(), format!("{entity} Attackers"));
+
+        info.in_melee = true;
+        info.retreating = true;
+        info.distance_to_target = Some(47.6);
+        assert_eq!(
+            info.text(),
+            format!("{entity} Attackers melee retreat d=48")
+        );
+    }
+
+    #[test]
+    fn test_cycle_histogram_buckets_offsets() {
+        let offsets = [
+            Some(0.0),
+            Some(0.05),
+            Some(1.0),
+            Some(1.99),
+            Some(2.0),
+            None,
+        ];
+        let histogram = AttackCycleHistogram::from_offsets(offsets, 2.0);
+
+        // 2.0 wraps back to the start of the cycle
+        assert_eq!(histogram.buckets, [3, 0, 0, 0, 0, 1, 0, 0, 0, 1]);
+        assert_eq!(histogram.unscheduled, 1);
+
+        let text = histogram.text(1.05, 2.0);
+        assert!(text.starts_with("Attack cycle 1.05s / 2.00s"));
+        assert!(text.contains(&format!("{SELECTED_MARKER}1.00s ########## 1")));
+        assert!(text.ends_with("Never attacked: 1"));
+    }
+
+    #[test]
+    fn test_adjust_clamps_to_range() {
+        let mut tunables = TunableConstants::default();
+
+        TunableField::VelocityDamping.adjust(&mut tunables, 1000.0);
+        assert_eq!(tunables.velocity_damping, 1.0);
+
+        TunableField::EffectivenessEnemyPenalty.adjust(&mut tunables, 1000.0);
+        assert_eq!(tunables.effectiveness_enemy_penalty, 0.0);
+
+        TunableField::SteeringForce.adjust(&mut tunables, -1000.0);
+        assert_eq!(tunables.steering_force, 0.0);
+    }
+}
@@ -0,0 +1,29 @@
+/// Number of adjustment steps between a field's minimum and maximum.
+pub const STEPS_PER_RANGE: f32 = 50.0;
+
+/// Marker drawn before the selected row.
+pub const SELECTED_MARKER: &str = "> ";
+
+/// Key that toggles the per-unit debug labels.
+pub const UNIT_LABELS_KEY: bevy::prelude::KeyCode = bevy::prelude::KeyCode::F9;
+
+/// Maximum number of unit debug labels shown at once.
+pub const MAX_UNIT_LABELS: usize = 40;
+
+/// Only units within this many logical pixels of the cursor get a label.
+pub const UNIT_LABEL_REGION_RADIUS: f32 = 250.0;
+
+/// Height above the unit's center where its label is anchored.
+pub const UNIT_LABEL_HEIGHT_OFFSET: f32 = 40.0;
+
+/// Key that toggles the attack cycle readout.
+pub const CYCLE_READOUT_KEY: bevy::prelude::KeyCode = bevy::prelude::KeyCode::F8;
+
+/// Number of histogram buckets the attack cycle is split into.
+pub const CYCLE_HISTOGRAM_BUCKETS: usize = 10;
+
+/// Width in characters of the fullest histogram bar.
+pub const CYCLE_HISTOGRAM_BAR_WIDTH: usize = 30;
+
+/// Help line shown above the tunable rows.
+pub const HELP_TEXT: &str = "F10 close | Up/Down select | Left/Right adjust | R reset";
@@ -0,0 +1,14 @@
+//! Developer tuning panel module.
+//!
+//! An F10-toggled panel for adjusting movement and effectiveness constants
+//! while a battle runs, F9-toggled debug labels over units near the cursor, and
+//! an F8-toggled readout of how unit attacks spread across the attack cycle.
+//! Only compiled with the `dev_tools` cargo feature.
+
+mod components;
+mod constants;
+mod plugin;
+mod styles;
+mod systems;
+
+pub use plugin::DevToolsPlugin;
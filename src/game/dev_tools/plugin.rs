@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use crate::game::resources::TunableConstants;
+use crate::state::{AppState, InGameState};
+
+use super::components::{CycleReadout, DevToolsState, UnitLabels};
+use super::systems;
+
+/// Plugin that provides the developer tuning panel.
+///
+/// Registers systems for:
+/// - Toggling the panel with F10
+/// - Selecting and adjusting tunable constants with the arrow keys
+/// - Refreshing the panel text when a value changes
+/// - Toggling and placing debug labels over units near the cursor (F9)
+/// - Toggling and refreshing the attack cycle readout (F8), off again after each battle
+pub struct DevToolsPlugin;
+
+impl Plugin for DevToolsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DevToolsState>()
+            .init_resource::<TunableConstants>()
+            .init_resource::<UnitLabels>()
+            .init_resource::<CycleReadout>()
+            .add_systems(
+                Update,
+                (
+                    systems::toggle_panel,
+                    systems::adjust_tunables,
+                    systems::update_panel_text,
+                    systems::toggle_unit_labels,
+                    systems::update_unit_labels,
+                    systems::toggle_cycle_readout,
+                    systems::update_cycle_readout,
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running)),
+            )
+            .add_systems(OnExit(InGameState::Running), systems::close_panel)
+            .add_systems(OnExit(AppState::InGame), systems::reset_cycle_readout);
+    }
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+/// Background color of the tuning panel.
+pub const PANEL_BACKGROUND_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.75);
+
+/// Color of the panel text.
+pub const PANEL_TEXT_COLOR: Color = Color::srgb(0.95, 0.95, 0.95);
+
+/// Font size for all panel text.
+pub const PANEL_FONT_SIZE: f32 = 16.0;
+
+/// Font size for unit debug labels.
+pub const UNIT_LABEL_FONT_SIZE: f32 = 11.0;
+
+/// Color of unit debug labels.
+pub const UNIT_LABEL_COLOR: Color = Color::srgb(1.0, 1.0, 0.6);
+
+/// Background behind unit debug labels, for legibility over the battlefield.
+pub const UNIT_LABEL_BACKGROUND_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+
+/// Padding inside the panel in pixels.
+pub const PANEL_PADDING: f32 = 10.0;
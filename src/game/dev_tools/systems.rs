@@ -0,0 +1,337 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use bevy::window::PrimaryWindow;
+
+use super::constants::*;
+use super::styles::*;
+use crate::game::components::OnGameplayScreen;
+use crate::game::plugin::GlobalAttackCycle;
+use crate::game::resources::TunableConstants;
+use crate::game::units::archer::components::AttackRange;
+use crate::game::units::components::{AttackTiming, Corpse, InMelee, TargetingVelocity, Team};
+
+/// Toggles the tuning panel open and closed with F10.
+pub fn toggle_panel(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<DevToolsState>,
+    root_query: Query<Entity, With<DevToolsRoot>>,
+) {
+    if !keys.just_pressed(KeyCode::F10) {
+        return;
+    }
+
+    state.open = !state.open;
+
+    if state.open {
+        spawn_panel_ui(&mut commands);
+    } else {
+        for entity in &root_query {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Selects rows with Up/Down and adjusts the selected value with Left/Right.
+///
+/// Holding Shift adjusts in larger steps. R restores every default.
+pub fn adjust_tunables(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<DevToolsState>,
+    mut tunables: ResMut<TunableConstants>,
+) {
+    if !state.open {
+        return;
+    }
+
+    let fields = TunableField::all();
+    if keys.just_pressed(KeyCode::ArrowDown) {
+        state.selected = (state.selected + 1) % fields.len();
+    }
+    if keys.just_pressed(KeyCode::ArrowUp) {
+        state.selected = (state.selected + fields.len() - 1) % fields.len();
+    }
+
+    let multiplier = if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        5.0
+    } else {
+        1.0
+    };
+    let field = fields[state.selected];
+    if keys.just_pressed(KeyCode::ArrowRight) {
+        field.adjust(&mut tunables, multiplier);
+    }
+    if keys.just_pressed(KeyCode::ArrowLeft) {
+        field.adjust(&mut tunables, -multiplier);
+    }
+
+    if keys.just_pressed(KeyCode::KeyR) {
+        *tunables = TunableConstants::default();
+    }
+}
+
+/// Refreshes the panel text when the selection or a value changes.
+pub fn update_panel_text(
+    state: Res<DevToolsState>,
+    tunables: Res<TunableConstants>,
+    mut text_query: Query<&mut Text, With<DevToolsText>>,
+) {
+    if !state.is_changed() && !tunables.is_changed() {
+        return;
+    }
+
+    let rows: Vec<String> = TunableField::all()
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let marker = if index == state.selected {
+                SELECTED_MARKER
+            } else {
+                "  "
+            };
+            format!("{marker}{}: {:.3}", field.name(), field.get(&tunables))
+        })
+        .collect();
+
+    for mut text in &mut text_query {
+        **text = format!("{HELP_TEXT}\n{}", rows.join("\n"));
+    }
+}
+
+/// Toggles the unit debug labels, spawning or despawning the label pool.
+pub fn toggle_unit_labels(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut labels: ResMut<UnitLabels>,
+    label_query: Query<Entity, With<UnitLabel>>,
+) {
+    if !keys.just_pressed(UNIT_LABELS_KEY) {
+        return;
+    }
+
+    labels.enabled = !labels.enabled;
+
+    for entity in &label_query {
+        commands.entity(entity).despawn();
+    }
+    if !labels.enabled {
+        return;
+    }
+
+    for slot in 0..MAX_UNIT_LABELS {
+        commands.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: UNIT_LABEL_FONT_SIZE,
+                ..default()
+            },
+            TextColor(UNIT_LABEL_COLOR),
+            BackgroundColor(UNIT_LABEL_BACKGROUND_COLOR),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            Visibility::Hidden,
+            Pickable::IGNORE,
+            UnitLabel(slot),
+            OnGameplayScreen,
+        ));
+    }
+}
+
+/// Places debug labels over the living units closest to the cursor.
+///
+/// Labels are screen-space text, so they always face the camera. Only units
+/// within `UNIT_LABEL_REGION_RADIUS` of the cursor are labelled, nearest first,
+/// up to the size of the label pool.
+#[allow(clippy::type_complexity)]
+pub fn update_unit_labels(
+    labels: Res<UnitLabels>,
+    ui_scale: Res<UiScale>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    units: Query<
+        (
+            Entity,
+            &Transform,
+            &Team,
+            Has<InMelee>,
+            Option<&TargetingVelocity>,
+            Option<&AttackRange>,
+        ),
+        Without<Corpse>,
+    >,
+    mut label_query: Query<(&UnitLabel, &mut Text, &mut Node, &mut Visibility)>,
+) {
+    if !labels.enabled {
+        return;
+    }
+
+    let mut nearby: Vec<(f32, Vec2, UnitLabelInfo)> = Vec::new();
+    if let Ok((camera, camera_transform)) = camera_query.single()
+        && let Some(cursor) = window_query
+            .single()
+            .ok()
+            .and_then(|window| window.cursor_position())
+    {
+        for (entity, transform, team, in_melee, targeting, attack_range) in &units {
+            let anchor = transform.translation + Vec3::Y * UNIT_LABEL_HEIGHT_OFFSET;
+            let Ok(screen_pos) = camera.world_to_viewport(camera_transform, anchor) else {
+                continue;
+            };
+            let cursor_distance = screen_pos.distance(cursor);
+            if cursor_distance > UNIT_LABEL_REGION_RADIUS {
+                continue;
+            }
+
+            // Targeting reports f32::MAX when there is nothing to chase
+            let distance_to_target = targeting
+                .map(|targeting| targeting.distance_to_target)
+                .filter(|distance| *distance < f32::MAX);
+            let retreating = attack_range
+                .zip(distance_to_target)
+                .is_some_and(|(range, distance)| distance < range.min_range);
+
+            nearby.push((
+                cursor_distance,
+                screen_pos,
+                UnitLabelInfo {
+                    entity,
+                    team: *team,
+                    in_melee,
+                    distance_to_target,
+                    retreating,
+                },
+            ));
+        }
+    }
+    nearby.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    for (label, mut text, mut node, mut visibility) in &mut label_query {
+        let Some((_, screen_pos, info)) = nearby.get(label.0) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        **text = info.text();
+        // Viewport coordinates are logical pixels; UI Px values are scaled by UiScale
+        node.left = Val::Px(screen_pos.x / ui_scale.0);
+        node.top = Val::Px(screen_pos.y / ui_scale.0);
+        *visibility = Visibility::Inherited;
+    }
+}
+
+/// Toggles the attack cycle readout in the bottom right corner.
+pub fn toggle_cycle_readout(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut readout: ResMut<CycleReadout>,
+    text_query: Query<Entity, With<CycleReadoutText>>,
+) {
+    if !keys.just_pressed(CYCLE_READOUT_KEY) {
+        return;
+    }
+
+    readout.enabled = !readout.enabled;
+
+    for entity in &text_query {
+        commands.entity(entity).despawn();
+    }
+    if !readout.enabled {
+        return;
+    }
+
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: PANEL_FONT_SIZE,
+            ..default()
+        },
+        TextColor(PANEL_TEXT_COLOR),
+        BackgroundColor(PANEL_BACKGROUND_COLOR),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(0.0),
+            right: Val::Px(0.0),
+            padding: UiRect::all(Val::Px(PANEL_PADDING)),
+            ..default()
+        },
+        GlobalZIndex(100),
+        Pickable::IGNORE,
+        CycleReadoutText,
+        OnGameplayScreen,
+    ));
+}
+
+/// Shows the current attack cycle time and a histogram of living units'
+/// attack offsets, to spot units clustering into synchronized waves.
+pub fn update_cycle_readout(
+    readout: Res<CycleReadout>,
+    cycle: Res<GlobalAttackCycle>,
+    units: Query<&AttackTiming, Without<Corpse>>,
+    mut text_query: Query<&mut Text, With<CycleReadoutText>>,
+) {
+    if !readout.enabled {
+        return;
+    }
+
+    let histogram = AttackCycleHistogram::from_offsets(
+        units.iter().map(|timing| timing.last_attack_time),
+        cycle.cycle_duration,
+    );
+    let readout_text = histogram.text(cycle.current_time, cycle.cycle_duration);
+    for mut text in &mut text_query {
+        **text = readout_text.clone();
+    }
+}
+
+/// Turns the attack cycle readout off when leaving a battle.
+///
+/// Its text is despawned with the rest of the gameplay screen, so the next
+/// battle starts with the readout off and F8 shows it again.
+pub fn reset_cycle_readout(mut readout: ResMut<CycleReadout>) {
+    readout.enabled = false;
+}
+
+/// Closes the panel when leaving the Running state.
+pub fn close_panel(
+    mut commands: Commands,
+    mut state: ResMut<DevToolsState>,
+    root_query: Query<Entity, With<DevToolsRoot>>,
+) {
+    state.open = false;
+    for entity in &root_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Spawns the panel in the top right corner of the screen.
+fn spawn_panel_ui(commands: &mut Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                right: Val::Px(0.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(PANEL_PADDING)),
+                ..default()
+            },
+            BackgroundColor(PANEL_BACKGROUND_COLOR),
+            GlobalZIndex(100),
+            DevToolsRoot,
+            OnGameplayScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: PANEL_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(PANEL_TEXT_COLOR),
+                DevToolsText,
+            ));
+        });
+}
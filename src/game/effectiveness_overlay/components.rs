@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use super::constants::MAX_TINT_STRENGTH;
+use super::styles::{EFFECTIVE_TINT_COLOR, INEFFECTIVE_TINT_COLOR};
+use crate::game::constants::{EFFECTIVENESS_MAX, EFFECTIVENESS_MIN};
+
+/// Whether the effectiveness overlay is currently shown.
+#[derive(Resource, Default)]
+pub struct EffectivenessOverlay {
+    pub enabled: bool,
+}
+
+/// A unit's material color from before the overlay tinted it.
+///
+/// Restored and removed when the overlay is turned off.
+#[derive(Component)]
+pub struct OverlayOriginalColor(pub Color);
+
+/// Returns `original` blended toward green above 1.0 effectiveness or red below it.
+///
+/// The blend scales with how far the multiplier is from 1.0 relative to the
+/// effectiveness limits, so a unit at exactly 1.0 keeps its original color.
+pub fn effectiveness_tint(original: Color, multiplier: f32) -> Color {
+    let (tint, strength) = if multiplier >= 1.0 {
+        (
+            EFFECTIVE_TINT_COLOR,
+            (multiplier - 1.0) / (EFFECTIVENESS_MAX - 1.0),
+        )
+    } else {
+        (
+            INEFFECTIVE_TINT_COLOR,
+            (1.0 - multiplier) / (1.0 - EFFECTIVENESS_MIN),
+        )
+    };
+
+    original.mix(&tint, strength.clamp(0.0, 1.0) * MAX_TINT_STRENGTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORIGINAL: Color = Color::srgb(0.5, 0.5, 0.5);
+
+    #[test]
+    fn test_neutral_effectiveness_keeps_original_color() {
+        assert_eq!(effectiveness_tint(ORIGINAL, 1.0), ORIGINAL);
+    }
+
+    #[test]
+    fn test_high_effectiveness_tints_green() {
+        let tinted = effectiveness_tint(ORIGINAL, EFFECTIVENESS_MAX).to_srgba();
+        assert!(tinted.green > 0.5);
+        assert!(tinted.red < 0.5);
+    }
+
+    #[test]
+    fn test_low_effectiveness_tints_red() {
+        let tinted = effectiveness_tint(ORIGINAL, EFFECTIVENESS_MIN).to_srgba();
+        assert!(tinted.red > 0.5);
+        assert!(tinted.green < 0.5);
+    }
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+/// Key that toggles the effectiveness overlay on and off.
+pub const TOGGLE_KEY: KeyCode = KeyCode::KeyE;
+
+/// How far a unit's color is blended toward the tint at the effectiveness limits (0.0 to 1.0).
+pub const MAX_TINT_STRENGTH: f32 = 0.8;
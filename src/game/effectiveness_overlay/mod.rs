@@ -0,0 +1,12 @@
+//! Effectiveness overlay module.
+//!
+//! Optionally tints each unit by its current `Effectiveness` multiplier so the
+//! player can see where local skirmishes are being won or lost.
+
+mod components;
+mod constants;
+mod plugin;
+mod styles;
+mod systems;
+
+pub use plugin::EffectivenessOverlayPlugin;
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use crate::state::InGameState;
+
+use super::components::EffectivenessOverlay;
+use super::systems;
+
+/// Plugin that tints units by their effectiveness multiplier.
+///
+/// Registers systems for:
+/// - Toggling the overlay with a hotkey
+/// - Tinting living units green (winning) or red (losing)
+/// - Restoring original colors when the overlay is turned off
+pub struct EffectivenessOverlayPlugin;
+
+impl Plugin for EffectivenessOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EffectivenessOverlay>().add_systems(
+            Update,
+            (
+                systems::toggle_effectiveness_overlay,
+                systems::restore_unit_colors,
+                systems::tint_units_by_effectiveness,
+            )
+                .chain()
+                .run_if(in_state(InGameState::Running)),
+        );
+    }
+}
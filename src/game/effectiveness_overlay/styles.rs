@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+/// Tint for units fighting above their base effectiveness.
+pub const EFFECTIVE_TINT_COLOR: Color = Color::srgb(0.1, 0.9, 0.1);
+
+/// Tint for units fighting below their base effectiveness.
+pub const INEFFECTIVE_TINT_COLOR: Color = Color::srgb(0.9, 0.1, 0.1);
@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::TOGGLE_KEY;
+use crate::game::units::components::{Corpse, Effectiveness, Health};
+
+/// Toggles the effectiveness overlay with its hotkey.
+pub fn toggle_effectiveness_overlay(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<EffectivenessOverlay>,
+) {
+    if keys.just_pressed(TOGGLE_KEY) {
+        overlay.enabled = !overlay.enabled;
+    }
+}
+
+/// Tints every living unit by its current effectiveness while the overlay is on.
+///
+/// The unit's original color is stored the first time it is tinted so the
+/// tint never compounds and can be undone later.
+pub fn tint_units_by_effectiveness(
+    mut commands: Commands,
+    overlay: Res<EffectivenessOverlay>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    units: Query<
+        (
+            Entity,
+            &Effectiveness,
+            &Health,
+            &MeshMaterial3d<StandardMaterial>,
+            Option<&OverlayOriginalColor>,
+        ),
+        Without<Corpse>,
+    >,
+) {
+    if !overlay.enabled {
+        return;
+    }
+
+    for (entity, effectiveness, health, material_handle, original_color) in &units {
+        // Units that just died are greyed out this frame and become corpses
+        if health.is_dead() {
+            continue;
+        }
+        let Some(material) = materials.get_mut(&material_handle.0) else {
+            continue;
+        };
+
+        let original = match original_color {
+            Some(original_color) => original_color.0,
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(OverlayOriginalColor(material.base_color));
+                material.base_color
+            }
+        };
+
+        material.base_color = effectiveness_tint(original, effectiveness.multiplier());
+    }
+}
+
+/// Restores original colors when the overlay is off.
+///
+/// Corpses keep their death color and just drop the stored original.
+pub fn restore_unit_colors(
+    mut commands: Commands,
+    overlay: Res<EffectivenessOverlay>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    tinted: Query<(
+        Entity,
+        &MeshMaterial3d<StandardMaterial>,
+        &OverlayOriginalColor,
+        Has<Corpse>,
+    )>,
+) {
+    for (entity, material_handle, original_color, is_corpse) in &tinted {
+        if !is_corpse {
+            if overlay.enabled {
+                continue;
+            }
+            if let Some(material) = materials.get_mut(&material_handle.0) {
+                material.base_color = original_color.0;
+            }
+        }
+
+        commands.entity(entity).remove::<OverlayOriginalColor>();
+    }
+}
@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+
+use super::constants::*;
+use crate::game::campaign::constants::POINTS_PER_KILL;
+
+/// Tracks the escalating waves of an endless horde game.
+///
+/// Reset when a new game starts or the player leaves the game over screen.
+#[derive(Resource, Debug)]
+pub struct EndlessState {
+    /// Number of waves spawned so far.
+    pub waves_spawned: u32,
+    /// Seconds of combat until the next wave spawns.
+    pub next_wave_in: f32,
+}
+
+impl Default for EndlessState {
+    fn default() -> Self {
+        // The first wave spawns as soon as the game starts
+        Self {
+            waves_spawned: 0,
+            next_wave_in: 0.0,
+        }
+    }
+}
+
+impl EndlessState {
+    /// Advances the wave timer. Returns true when the next wave should spawn.
+    pub fn tick(&mut self, delta: f32) -> bool {
+        self.next_wave_in -= delta;
+        self.next_wave_in <= 0.0
+    }
+
+    /// Starts the next wave and returns how many attackers it brings.
+    pub fn start_next_wave(&mut self) -> u32 {
+        let size = endless_wave_size(self.waves_spawned);
+        self.waves_spawned += 1;
+        self.next_wave_in = ENDLESS_WAVE_INTERVAL;
+        size
+    }
+
+    /// Brings the next wave forward to at most `delay` seconds away.
+    pub fn hurry(&mut self, delay: f32) {
+        self.next_wave_in = self.next_wave_in.min(delay);
+    }
+
+    /// Resets the horde for a new game.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Calculates the number of attackers in an endless wave (0 = first wave).
+///
+/// Grows smoothly and without bound, so every wave is at least as large as the last.
+pub fn endless_wave_size(wave: u32) -> u32 {
+    let growth = (1.0 + wave as f32 * ENDLESS_WAVE_GROWTH).powf(ENDLESS_WAVE_EXPONENT);
+    (ENDLESS_BASE_WAVE_SIZE as f32 * growth).round() as u32
+}
+
+/// Calculates the endless score from combat time survived and enemies killed.
+pub fn endless_score(seconds_survived: f32, enemy_kills: u32) -> u32 {
+    seconds_survived as u32 * POINTS_PER_SECOND + enemy_kills * POINTS_PER_KILL
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wave_size_grows_every_wave() {
+        assert_eq!(endless_wave_size(0), ENDLESS_BASE_WAVE_SIZE);
+        for wave in 0..200 {
+            assert!(endless_wave_size(wave + 1) >= endless_wave_size(wave));
+        }
+        // Unbounded: far-off waves keep getting bigger
+        assert!(endless_wave_size(100) > 10 * ENDLESS_BASE_WAVE_SIZE);
+        assert!(endless_wave_size(200) > endless_wave_size(100));
+    }
+
+    #[test]
+    fn test_wave_size_grows_smoothly() {
+        // No wave more than doubles the previous one
+        for wave in 0..200 {
+            assert!(endless_wave_size(wave + 1) <= endless_wave_size(wave) * 2);
+        }
+    }
+
+    #[test]
+    fn test_first_wave_spawns_immediately_then_waits() {
+        let mut endless = EndlessState::default();
+        assert!(endless.tick(0.0));
+        assert_eq!(endless.start_next_wave(), ENDLESS_BASE_WAVE_SIZE);
+        assert_eq!(endless.waves_spawned, 1);
+
+        assert!(!endless.tick(ENDLESS_WAVE_INTERVAL - 1.0));
+        assert!(endless.tick(1.0));
+    }
+
+    #[test]
+    fn test_hurry_only_brings_wave_forward() {
+        let mut endless = EndlessState::default();
+        endless.start_next_wave();
+        endless.hurry(ENDLESS_CLEARED_DELAY);
+        assert_eq!(endless.next_wave_in, ENDLESS_CLEARED_DELAY);
+
+        // A longer delay never pushes the wave back
+        endless.hurry(ENDLESS_WAVE_INTERVAL);
+        assert_eq!(endless.next_wave_in, ENDLESS_CLEARED_DELAY);
+    }
+
+    #[test]
+    fn test_score_counts_time_and_kills() {
+        assert_eq!(
+            endless_score(61.9, 12),
+            61 * POINTS_PER_SECOND + 12 * POINTS_PER_KILL
+        );
+    }
+}
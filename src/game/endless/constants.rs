@@ -0,0 +1,17 @@
+/// Attackers in the first endless wave.
+pub const ENDLESS_BASE_WAVE_SIZE: u32 = 30;
+
+/// Relative growth of the wave size per wave.
+pub const ENDLESS_WAVE_GROWTH: f32 = 0.15;
+
+/// Exponent applied to the wave growth, so later waves escalate slightly faster.
+pub const ENDLESS_WAVE_EXPONENT: f32 = 1.2;
+
+/// Seconds of combat between endless waves.
+pub const ENDLESS_WAVE_INTERVAL: f32 = 30.0;
+
+/// Seconds until the next wave once the field has been cleared early.
+pub const ENDLESS_CLEARED_DELAY: f32 = 3.0;
+
+/// Score awarded per second of combat survived.
+pub const POINTS_PER_SECOND: u32 = 2;
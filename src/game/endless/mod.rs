@@ -0,0 +1,11 @@
+//! Endless horde module.
+//!
+//! An alternative to the campaign where attackers keep arriving in ever larger
+//! waves until the castle falls. Runs are scored by time survived and kills.
+
+pub mod components;
+pub mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::EndlessPlugin;
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+use crate::game::run_conditions;
+use crate::game::win_lose_systems::check_win_lose_conditions;
+use crate::state::{AppState, InGameState};
+
+use super::components::EndlessState;
+use super::systems;
+
+/// Plugin that runs the endless horde game mode.
+///
+/// Registers systems for:
+/// - Spawning escalating attacker waves on a timer
+/// - Resetting the horde for each new game
+pub struct EndlessPlugin;
+
+impl Plugin for EndlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EndlessState>()
+            .add_systems(OnEnter(AppState::InGame), systems::reset_endless)
+            .add_systems(OnExit(InGameState::GameOver), systems::reset_endless)
+            .add_systems(
+                Update,
+                systems::advance_endless_waves
+                    .run_if(in_state(InGameState::Running))
+                    .run_if(run_conditions::in_endless_mode)
+                    .before(check_win_lose_conditions),
+            );
+    }
+}
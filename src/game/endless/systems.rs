@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+use super::components::EndlessState;
+use super::constants::ENDLESS_CLEARED_DELAY;
+use crate::game::battlefield::components::BattlefieldConfig;
+use crate::game::campaign::components::{CampaignState, WaveCleared};
+use crate::game::constants::{
+    calculate_spawn_cells, cells_needed, distribute_units_to_cells, spawn_offset,
+};
+use crate::game::resources::MaxEntities;
+use crate::game::units::components::{Corpse, Team};
+use crate::game::units::infantry::systems::spawn_attacker_infantry;
+
+/// Counts down to the next endless wave and spawns it.
+///
+/// The wave timer only runs during combat, and clearing the field early
+/// brings the next wave forward so the player is never left waiting.
+/// A wave is held back while the field is at the entity budget, and trimmed to fit.
+#[allow(clippy::too_many_arguments)]
+pub fn advance_endless_waves(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut endless: ResMut<EndlessState>,
+    mut wave_cleared: MessageReader<WaveCleared>,
+    campaign: Res<CampaignState>,
+    battlefield: Res<BattlefieldConfig>,
+    budget: Res<MaxEntities>,
+    living: Query<(), (With<Team>, Without<Corpse>)>,
+) {
+    if wave_cleared.read().last().is_some() {
+        endless.hurry(ENDLESS_CLEARED_DELAY);
+    }
+
+    let delta = if campaign.in_preparation() {
+        0.0
+    } else {
+        time.delta_secs()
+    };
+    if !endless.tick(delta) {
+        return;
+    }
+
+    let room = budget.room(living.iter().len()) as u32;
+    if room == 0 {
+        return;
+    }
+
+    let wave_size = endless.start_next_wave().min(room);
+    let units_per_cell = distribute_units_to_cells(wave_size);
+    let (cells, _) = calculate_spawn_cells(cells_needed(wave_size), 0);
+
+    // Waves larger than the spawn grid wrap around and stack onto the front cells
+    for (cell_idx, cell_count) in units_per_cell.iter().enumerate() {
+        let (row, col) = cells[cell_idx % cells.len()];
+        let (spawn_x, spawn_z) = battlefield.grid_cell_position(row, col);
+        let first_offset = (cell_idx / cells.len()) as u32 * cell_count;
+
+        for i in first_offset..first_offset + cell_count {
+            let offset = spawn_offset(i);
+            spawn_attacker_infantry(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                spawn_x + offset.x,
+                spawn_z + offset.y,
+            );
+        }
+    }
+}
+
+/// Resets the horde when a new game starts.
+pub fn reset_endless(mut endless: ResMut<EndlessState>) {
+    endless.reset();
+}
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+/// Interpolates a simulated entity's rendered translation between fixed steps.
+///
+/// `Transform` holds the interpolated position between fixed steps and the
+/// simulated position during them. Moves made outside the fixed loop (spells,
+/// teleports) are carried over into the simulated position.
+#[derive(Component, Default)]
+pub struct InterpolatedTranslation {
+    /// Simulated translation at the start of the latest fixed step.
+    pub previous: Vec3,
+    /// Simulated translation at the end of the latest fixed step.
+    pub current: Vec3,
+    /// Translation last written for rendering (None until first rendered).
+    pub rendered: Option<Vec3>,
+}
+
+impl InterpolatedTranslation {
+    /// Returns the simulated translation to restore before the fixed loop runs.
+    ///
+    /// Any offset applied to `translation` since it was rendered is added to
+    /// both snapshots. The first call snaps to `translation`.
+    pub fn restore(&mut self, translation: Vec3) -> Vec3 {
+        match self.rendered {
+            Some(rendered) => {
+                let offset = translation - rendered;
+                self.previous += offset;
+                self.current += offset;
+            }
+            None => {
+                self.previous = translation;
+                self.current = translation;
+            }
+        }
+        self.current
+    }
+
+    /// Returns the translation to render, `overstep` of the way into the next step.
+    pub fn interpolate(&mut self, overstep: f32) -> Vec3 {
+        let rendered = self.previous.lerp(self.current, overstep);
+        self.rendered = Some(rendered);
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpolates_between_steps() {
+        let mut interpolated = InterpolatedTranslation::default();
+        interpolated.restore(Vec3::ZERO);
+        interpolated.current = Vec3::new(10.0, 0.0, 0.0);
+
+        assert_eq!(interpolated.interpolate(0.25), Vec3::new(2.5, 0.0, 0.0));
+        assert_eq!(
+            interpolated.restore(Vec3::new(2.5, 0.0, 0.0)),
+            Vec3::new(10.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_moves_between_steps_carry_over() {
+        let mut interpolated = InterpolatedTranslation::default();
+        interpolated.restore(Vec3::ZERO);
+        interpolated.current = Vec3::new(10.0, 0.0, 0.0);
+        interpolated.interpolate(0.5);
+
+        // A spell shoves the rendered unit 100 units along Z
+        let restored = interpolated.restore(Vec3::new(5.0, 0.0, 100.0));
+        assert_eq!(restored, Vec3::new(10.0, 0.0, 100.0));
+        assert_eq!(interpolated.previous, Vec3::new(0.0, 0.0, 100.0));
+    }
+}
@@ -0,0 +1,12 @@
+//! Fixed timestep module.
+//!
+//! Unit movement, combat, and arrows run in `FixedUpdate` at the rate chosen in
+//! `GameConfig::simulation_rate`, so battles play out the same at any frame
+//! rate and fast arrows cannot step over a unit during a long frame. Rendered
+//! positions are interpolated between the last two simulation steps.
+
+pub mod components;
+mod plugin;
+mod systems;
+
+pub use plugin::FixedTimestepPlugin;
@@ -0,0 +1,34 @@
+use bevy::app::RunFixedMainLoopSystems;
+use bevy::prelude::*;
+
+use super::systems;
+
+/// Plugin that runs the battle simulation on a fixed timestep.
+///
+/// Registers systems for:
+/// - Applying the configured simulation rate to `Time<Fixed>`
+/// - Snapshotting unit and arrow positions around each fixed step
+/// - Interpolating rendered positions between fixed steps
+pub struct FixedTimestepPlugin;
+
+impl Plugin for FixedTimestepPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            RunFixedMainLoop,
+            (
+                systems::apply_simulation_rate,
+                systems::track_simulated_entities,
+                systems::restore_simulated_translation,
+            )
+                .chain()
+                .in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+        )
+        .add_systems(FixedFirst, systems::record_previous_translation)
+        .add_systems(FixedLast, systems::record_current_translation)
+        .add_systems(
+            RunFixedMainLoop,
+            systems::interpolate_rendered_translation
+                .in_set(RunFixedMainLoopSystems::AfterFixedMainLoop),
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+use super::components::InterpolatedTranslation;
+use crate::config::GameConfig;
+use crate::game::units::archer::components::Arrow;
+use crate::game::units::components::Team;
+
+/// Applies the configured simulation rate to the fixed timestep.
+pub fn apply_simulation_rate(config: Res<GameConfig>, mut fixed_time: ResMut<Time<Fixed>>) {
+    if config.is_changed() {
+        fixed_time.set_timestep_hz(config.simulation_rate.into());
+    }
+}
+
+/// Adds interpolation to newly spawned units and arrows.
+pub fn track_simulated_entities(
+    mut commands: Commands,
+    entities: Query<
+        Entity,
+        (
+            Or<(With<Team>, With<Arrow>)>,
+            Without<InterpolatedTranslation>,
+        ),
+    >,
+) {
+    for entity in &entities {
+        commands
+            .entity(entity)
+            .insert(InterpolatedTranslation::default());
+    }
+}
+
+/// Puts simulated entities back at their simulated position before the fixed loop.
+pub fn restore_simulated_translation(
+    mut entities: Query<(&mut Transform, &mut InterpolatedTranslation)>,
+) {
+    for (mut transform, mut interpolated) in &mut entities {
+        transform.translation = interpolated.restore(transform.translation);
+    }
+}
+
+/// Records each entity's translation at the start of a fixed step.
+pub fn record_previous_translation(
+    mut entities: Query<(&Transform, &mut InterpolatedTranslation)>,
+) {
+    for (transform, mut interpolated) in &mut entities {
+        interpolated.previous = transform.translation;
+    }
+}
+
+/// Records each entity's translation at the end of a fixed step.
+pub fn record_current_translation(mut entities: Query<(&Transform, &mut InterpolatedTranslation)>) {
+    for (transform, mut interpolated) in &mut entities {
+        interpolated.current = transform.translation;
+    }
+}
+
+/// Moves simulated entities to their interpolated position for rendering.
+pub fn interpolate_rendered_translation(
+    fixed_time: Res<Time<Fixed>>,
+    mut entities: Query<(&mut Transform, &mut InterpolatedTranslation)>,
+) {
+    let overstep = fixed_time.overstep_fraction();
+    for (mut transform, mut interpolated) in &mut entities {
+        transform.translation = interpolated.interpolate(overstep);
+    }
+}
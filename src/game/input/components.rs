@@ -21,6 +21,30 @@ pub struct MouseButtonState {
     pub left_consumed: bool,
 }
 
+/// Holds back a cast-button release during an unfinished cast.
+///
+/// If the button is pressed again within `GameConfig::cast_grace` seconds the
+/// cast resumes where it was; otherwise the release is sent late and the cast
+/// cancels as usual. Hold-cast spells stay frozen while a release is pending.
+#[derive(Resource, Default, Debug)]
+pub struct CastGrace {
+    /// Real time (in seconds) of the held-back release, if one is pending.
+    pub last_release_time: Option<f32>,
+}
+
+impl CastGrace {
+    /// Returns true while a release is being held back.
+    pub fn is_pending(&self) -> bool {
+        self.last_release_time.is_some()
+    }
+
+    /// Returns true once a pending release has waited out `grace` seconds at `now`.
+    pub fn expired(&self, now: f32, grace: f32) -> bool {
+        self.last_release_time
+            .is_some_and(|released_at| now - released_at >= grace)
+    }
+}
+
 /// Tracks whether spell input is blocked this frame.
 ///
 /// Used for run_if conditions to avoid consuming MessageReader in conditions.
@@ -47,3 +71,61 @@ pub struct MouseLeftHeldThisFrame {
 pub struct MouseRightHeldThisFrame {
     pub held: bool,
 }
+
+/// Cursor ray and battlefield position, computed once per frame.
+///
+/// Spells read this instead of querying the camera and window themselves.
+/// Both fields are None when the cursor is outside the window or there isn't
+/// exactly one 3D camera.
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct CursorWorldPosition {
+    /// Ray from the camera through the cursor.
+    pub ray: Option<Ray3d>,
+    /// Point where the cursor ray meets the battlefield (Y=0 plane).
+    pub ground: Option<Vec3>,
+}
+
+impl CursorWorldPosition {
+    /// Builds the cursor position from a camera ray, projecting it onto Y=0.
+    pub fn from_ray(ray: Option<Ray3d>) -> Self {
+        Self {
+            ray,
+            ground: ray.and_then(ray_ground_intersection),
+        }
+    }
+}
+
+/// Intersects a ray with the Y=0 plane (battlefield surface).
+///
+/// Returns None if the ray is parallel to the plane or points away from it.
+fn ray_ground_intersection(ray: Ray3d) -> Option<Vec3> {
+    if ray.direction.y.abs() < f32::EPSILON {
+        return None;
+    }
+
+    let t = -ray.origin.y / ray.direction.y;
+    (t > 0.0).then(|| ray.origin + ray.direction * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_downward_ray_hits_ground() {
+        let ray = Ray3d::new(Vec3::new(10.0, 100.0, -5.0), Dir3::NEG_Y);
+        let cursor = CursorWorldPosition::from_ray(Some(ray));
+        assert_eq!(cursor.ground, Some(Vec3::new(10.0, 0.0, -5.0)));
+    }
+
+    #[test]
+    fn test_ray_away_from_ground_has_no_ground_point() {
+        let upward = Ray3d::new(Vec3::new(0.0, 100.0, 0.0), Dir3::Y);
+        assert_eq!(CursorWorldPosition::from_ray(Some(upward)).ground, None);
+
+        let parallel = Ray3d::new(Vec3::new(0.0, 100.0, 0.0), Dir3::X);
+        assert_eq!(CursorWorldPosition::from_ray(Some(parallel)).ground, None);
+
+        assert_eq!(CursorWorldPosition::from_ray(None).ground, None);
+    }
+}
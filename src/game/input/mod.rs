@@ -10,3 +10,5 @@ mod systems;
 
 pub use components::MouseButtonState;
 pub use plugin::InputPlugin;
+#[cfg(test)]
+pub(crate) use systems::{detect_mouse_input, update_input_state_for_run_conditions};
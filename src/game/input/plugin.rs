@@ -6,8 +6,8 @@ use crate::state::InGameState;
 
 use super::{
     components::{
-        MouseButtonState, MouseLeftHeldThisFrame, MouseRightHeldThisFrame,
-        SpellInputBlockedThisFrame,
+        CastGrace, CursorWorldPosition, MouseButtonState, MouseLeftHeldThisFrame,
+        MouseRightHeldThisFrame, SpellInputBlockedThisFrame,
     },
     events::*,
     systems,
@@ -16,7 +16,9 @@ use super::{
 /// Plugin that handles all game input detection.
 ///
 /// Queries input state once per frame and sends events that other
-/// systems can consume, avoiding duplicate input queries.
+/// systems can consume, avoiding duplicate input queries. The cursor's
+/// battlefield position is projected in `PreUpdate` so spells in `Update`
+/// see the current frame's value.
 #[derive(Default)]
 pub struct InputPlugin;
 
@@ -25,9 +27,11 @@ impl Plugin for InputPlugin {
         app
             // Initialize input resources
             .init_resource::<MouseButtonState>()
+            .init_resource::<CastGrace>()
             .init_resource::<SpellInputBlockedThisFrame>()
             .init_resource::<MouseLeftHeldThisFrame>()
             .init_resource::<MouseRightHeldThisFrame>()
+            .init_resource::<CursorWorldPosition>()
             // Register input events
             .add_message::<MouseLeftPressed>()
             .add_message::<MouseLeftHeld>()
@@ -40,6 +44,10 @@ impl Plugin for InputPlugin {
             .add_message::<SpacebarReleased>()
             .add_message::<BlockSpellInput>()
             // Add input detection systems
+            .add_systems(
+                PreUpdate,
+                systems::update_cursor_world_position.run_if(in_state(InGameState::Running)),
+            )
             .add_systems(
                 Update,
                 (
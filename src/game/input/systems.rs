@@ -4,11 +4,15 @@
 //! that other systems can consume.
 
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::config::GameConfig;
+use crate::game::units::wizard::components::{CastingState, PrimedSpell, Wizard};
 
 use super::{
     components::{
-        MouseButtonState, MouseLeftHeldThisFrame, MouseRightHeldThisFrame,
-        SpellInputBlockedThisFrame,
+        CastGrace, CursorWorldPosition, MouseButtonState, MouseLeftHeldThisFrame,
+        MouseRightHeldThisFrame, SpellInputBlockedThisFrame,
     },
     events::*,
 };
@@ -16,11 +20,22 @@ use super::{
 /// Detects mouse button input and sends events.
 ///
 /// Runs once per frame to query mouse state and fire appropriate events.
+/// Physical buttons are mapped through the configured `MouseBinding`: the cast
+/// button sends the `MouseLeft*` events and the cancel button the `MouseRight*`
+/// events, so spell systems don't need to know which button is which.
+///
+/// Releasing the cast button before a cast completes is held back for
+/// `GameConfig::cast_grace` seconds (see `CastGrace`), so a quick re-press
+/// resumes the cast instead of cancelling it.
 #[allow(clippy::too_many_arguments)]
 pub fn detect_mouse_input(
+    config: Res<GameConfig>,
+    time: Res<Time<Real>>,
     mut mouse: ResMut<ButtonInput<MouseButton>>,
     windows: Query<&Window>,
     mut mouse_state: ResMut<MouseButtonState>,
+    mut cast_grace: ResMut<CastGrace>,
+    wizard: Query<(&CastingState, Option<&PrimedSpell>), With<Wizard>>,
     mut left_pressed: MessageWriter<MouseLeftPressed>,
     mut left_held: MessageWriter<MouseLeftHeld>,
     mut left_released: MessageWriter<MouseLeftReleased>,
@@ -34,9 +49,12 @@ pub fn detect_mouse_input(
         .ok()
         .and_then(|window| window.cursor_position());
 
-    // Check left mouse button state
+    let cast = config.mouse_binding.cast_button();
+    let cancel = config.mouse_binding.cancel_button();
+
+    // Check cast button state
     // If button is pressed but we're not getting a just_pressed event, it's stuck from losing focus
-    if mouse.pressed(MouseButton::Left) && !mouse.just_pressed(MouseButton::Left) {
+    if mouse.pressed(cast) && !mouse.just_pressed(cast) {
         // Check if we're about to send held events - if so, button is legitimately held
         // If not, it's stuck and we should clear it
         if cursor_position.is_none() {
@@ -47,33 +65,51 @@ pub fn detect_mouse_input(
         }
     }
 
-    if mouse.just_pressed(MouseButton::Left) {
+    let now = time.elapsed_secs();
+    let cast_unfinished = wizard.single().is_ok_and(|(state, primed)| {
+        matches!(state, CastingState::Casting { .. })
+            && primed.is_some_and(|primed| !state.is_complete(primed.cast_time))
+    });
+
+    if mouse.just_pressed(cast) {
+        // Pressing again within the grace resumes the cast
+        cast_grace.last_release_time = None;
         left_pressed.write(MouseLeftPressed { cursor_position });
     }
 
-    if mouse.pressed(MouseButton::Left) {
+    if mouse.pressed(cast) {
         left_held.write(MouseLeftHeld { cursor_position });
     }
 
-    if mouse.just_released(MouseButton::Left) {
+    if mouse.just_released(cast) {
+        if config.cast_grace > 0.0 && cast_unfinished {
+            cast_grace.last_release_time = Some(now);
+        } else {
+            left_released.write(MouseLeftReleased);
+        }
+    }
+
+    // Send a held-back release once the grace runs out (or the cast ended some other way)
+    if cast_grace.is_pending() && (cast_grace.expired(now, config.cast_grace) || !cast_unfinished) {
+        cast_grace.last_release_time = None;
         left_released.write(MouseLeftReleased);
     }
 
     // Only clear consumed flag when button is completely idle (not pressed, not released this frame)
-    if !mouse.pressed(MouseButton::Left) && !mouse.just_released(MouseButton::Left) {
+    if !mouse.pressed(cast) && !mouse.just_released(cast) {
         mouse_state.left_consumed = false;
     }
 
-    // Check right mouse button state
-    if mouse.just_pressed(MouseButton::Right) {
+    // Check cancel button state
+    if mouse.just_pressed(cancel) {
         right_pressed.write(MouseRightPressed { cursor_position });
     }
 
-    if mouse.pressed(MouseButton::Right) {
+    if mouse.pressed(cancel) {
         right_held.write(MouseRightHeld { cursor_position });
     }
 
-    if mouse.just_released(MouseButton::Right) {
+    if mouse.just_released(cancel) {
         right_released.write(MouseRightReleased);
     }
 }
@@ -117,3 +153,68 @@ pub fn update_input_state_for_run_conditions(
     mouse_left_held_state.held = mouse_left_held.read().next().is_some();
     mouse_right_held_state.held = mouse_right_held.read().next().is_some();
 }
+
+/// Projects the cursor onto the battlefield once per frame.
+///
+/// Logs a warning once if there isn't exactly one 3D camera, since every
+/// cursor-targeted spell stops working without one.
+pub fn update_cursor_world_position(
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut cursor: ResMut<CursorWorldPosition>,
+) {
+    let ray = match camera_query.single() {
+        Ok((camera, camera_transform)) => window_query
+            .single()
+            .ok()
+            .and_then(Window::cursor_position)
+            .and_then(|cursor_pos| camera.viewport_to_world(camera_transform, cursor_pos).ok()),
+        Err(error) => {
+            warn_once!("Cursor targeting unavailable, no single 3D camera: {error}");
+            None
+        }
+    };
+
+    *cursor = CursorWorldPosition::from_ray(ray);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MouseBinding;
+
+    fn message_count<M: Message>(app: &mut App) -> usize {
+        app.world_mut()
+            .resource_mut::<Messages<M>>()
+            .drain()
+            .count()
+    }
+
+    #[test]
+    fn test_swapped_binding_casts_with_right_click() {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            mouse_binding: MouseBinding::RightCast,
+            ..Default::default()
+        })
+        .init_resource::<Time<Real>>()
+        .init_resource::<ButtonInput<MouseButton>>()
+        .init_resource::<MouseButtonState>()
+        .init_resource::<CastGrace>()
+        .add_message::<MouseLeftPressed>()
+        .add_message::<MouseLeftHeld>()
+        .add_message::<MouseLeftReleased>()
+        .add_message::<MouseRightPressed>()
+        .add_message::<MouseRightHeld>()
+        .add_message::<MouseRightReleased>()
+        .add_systems(Update, detect_mouse_input);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Right);
+        app.update();
+
+        assert_eq!(message_count::<MouseLeftPressed>(&mut app), 1);
+        assert_eq!(message_count::<MouseRightPressed>(&mut app), 0);
+    }
+}
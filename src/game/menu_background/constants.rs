@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+/// Most living units each side keeps on the field behind the menu.
+pub const MENU_UNITS_PER_SIDE: usize = 24;
+
+/// Most units a side receives per reinforcement.
+pub const MENU_REINFORCEMENT_BATCH: usize = 6;
+
+/// Seconds between reinforcements.
+pub const MENU_REINFORCEMENT_INTERVAL: f32 = 2.0;
+
+/// Ground position (X, Z) where defender reinforcements arrive, in front of the castle.
+pub const MENU_DEFENDER_SPAWN: Vec2 = Vec2::new(-1250.0, 1150.0);
+
+/// Ground position (X, Z) where attacker reinforcements arrive.
+pub const MENU_ATTACKER_SPAWN: Vec2 = Vec2::new(400.0, -400.0);
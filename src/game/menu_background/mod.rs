@@ -0,0 +1,10 @@
+//! Main menu background module.
+//!
+//! Plays a small, endless battle behind the main menu using the gameplay
+//! spawn, movement, flocking, and combat systems, without win or loss logic.
+
+mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::MenuBackgroundPlugin;
@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+
+use crate::game::battlefield::systems::setup_battlefield;
+use crate::game::shared_systems;
+use crate::game::systems::update_billboards;
+use crate::game::units::infantry::systems::{infantry_movement, update_infantry_targeting};
+use crate::state::{AppState, MenuBackground};
+
+use super::constants::MENU_REINFORCEMENT_INTERVAL;
+use super::systems;
+
+/// Plugin that plays an idle battle behind the main menu.
+///
+/// Registers systems for:
+/// - Starting and stopping the battle to follow the menu battle setting
+/// - Battlefield setup and the first units on entering `MenuBackground::Playing`
+/// - Reusing gameplay targeting, flocking, movement, and combat on the menu units
+/// - Reinforcing both sides up to a small cap and despawning the fallen
+/// - Despawning everything on exit, which also happens when a game starts
+pub struct MenuBackgroundPlugin;
+
+impl Plugin for MenuBackgroundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            systems::sync_menu_background.run_if(in_state(AppState::MainMenu)),
+        )
+        .add_systems(
+            OnEnter(MenuBackground::Playing),
+            (setup_battlefield, systems::reinforce_menu_battle),
+        )
+        .add_systems(
+            OnExit(MenuBackground::Playing),
+            shared_systems::cleanup_game,
+        )
+        .add_systems(
+            FixedUpdate,
+            (
+                shared_systems::tick_attack_cycle,
+                update_infantry_targeting,
+                shared_systems::apply_separation,
+                shared_systems::calculate_effectiveness,
+                infantry_movement,
+                shared_systems::enforce_castle_collision,
+                shared_systems::combat,
+                systems::despawn_fallen_units,
+            )
+                .chain()
+                .run_if(in_state(MenuBackground::Playing)),
+        )
+        .add_systems(
+            Update,
+            (
+                update_billboards,
+                systems::reinforce_menu_battle.run_if(on_timer(Duration::from_secs_f32(
+                    MENU_REINFORCEMENT_INTERVAL,
+                ))),
+            )
+                .run_if(in_state(MenuBackground::Playing)),
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+
+use super::constants::*;
+use crate::config::GameConfig;
+use crate::game::constants::spawn_offset;
+use crate::game::units::components::{Corpse, Health, Team};
+use crate::game::units::infantry::systems::{spawn_attacker_infantry, spawn_defender_infantry};
+use crate::state::MenuBackground;
+
+/// Starts or stops the menu battle to match the menu battle setting.
+///
+/// Runs while in the main menu, so toggling the setting takes effect live.
+pub fn sync_menu_background(
+    config: Res<GameConfig>,
+    state: Res<State<MenuBackground>>,
+    mut next_state: ResMut<NextState<MenuBackground>>,
+) {
+    let wanted = if config.menu_background {
+        MenuBackground::Playing
+    } else {
+        MenuBackground::Hidden
+    };
+
+    if *state.get() != wanted {
+        next_state.set(wanted);
+    }
+}
+
+/// Returns how many units a side with `alive` units receives this reinforcement.
+pub fn reinforcement_count(alive: usize) -> usize {
+    MENU_UNITS_PER_SIDE
+        .saturating_sub(alive)
+        .min(MENU_REINFORCEMENT_BATCH)
+}
+
+/// Tops both sides back up toward `MENU_UNITS_PER_SIDE`, so the battle never ends.
+pub fn reinforce_menu_battle(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    units: Query<&Team, Without<Corpse>>,
+) {
+    let defenders = units
+        .iter()
+        .filter(|team| **team == Team::Defenders)
+        .count();
+    let attackers = units
+        .iter()
+        .filter(|team| **team == Team::Attackers)
+        .count();
+
+    for i in 0..reinforcement_count(defenders) {
+        let offset = spawn_offset(i as u32);
+        spawn_defender_infantry(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            MENU_DEFENDER_SPAWN.x + offset.x,
+            MENU_DEFENDER_SPAWN.y + offset.y,
+        );
+    }
+
+    for i in 0..reinforcement_count(attackers) {
+        let offset = spawn_offset(i as u32);
+        spawn_attacker_infantry(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            MENU_ATTACKER_SPAWN.x + offset.x,
+            MENU_ATTACKER_SPAWN.y + offset.y,
+        );
+    }
+}
+
+/// Despawns units that fall behind the menu instead of leaving corpses.
+///
+/// Keeps the menu battle from piling up corpses or touching kill statistics.
+pub fn despawn_fallen_units(mut commands: Commands, units: Query<(Entity, &Health)>) {
+    for (entity, health) in &units {
+        if health.is_dead() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reinforcements_stop_at_the_cap() {
+        assert_eq!(reinforcement_count(0), MENU_REINFORCEMENT_BATCH);
+        assert_eq!(reinforcement_count(MENU_UNITS_PER_SIDE - 2), 2);
+        assert_eq!(reinforcement_count(MENU_UNITS_PER_SIDE), 0);
+        assert_eq!(reinforcement_count(MENU_UNITS_PER_SIDE + 5), 0);
+    }
+}
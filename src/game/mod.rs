@@ -6,17 +6,44 @@
 //! - Defender and attacker unit spawning
 //! - Unit movement and targeting
 //! - Simple collision-based combat
+//! - Campaign, endless horde, and practice sandbox game modes
+//! - Wizard upgrades bought between campaign waves
+//! - An idle battle behind the main menu
 
-mod battlefield;
+mod batch_commands;
+pub mod battle_balance;
+pub mod battlefield;
+mod camera_follow;
+pub mod campaign;
+pub mod castle;
+#[cfg(feature = "combat_log")]
+mod combat_log;
 pub mod components;
+#[cfg(feature = "debug_console")]
+mod console;
 pub mod constants;
+#[cfg(feature = "dev_tools")]
+mod dev_tools;
+mod effectiveness_overlay;
+pub mod endless;
+mod fixed_timestep;
 pub mod input;
+mod menu_background;
 mod plugin;
+mod range_overlay;
+mod recall;
 pub mod resources;
 pub mod run_conditions;
+pub mod sandbox;
+mod selection;
 mod shared_systems;
+mod sound_cues;
 mod systems;
+pub mod ultimate;
 pub mod units;
+pub mod upgrades;
+pub mod waves;
 mod win_lose_systems;
+pub mod wind;
 
 pub use plugin::GamePlugin;
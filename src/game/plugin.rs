@@ -1,15 +1,44 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
 
 use crate::state::{AppState, InGameState};
 
+use super::battle_balance::BattleBalancePlugin;
 use super::battlefield::BattlefieldPlugin;
-use super::constants::ATTACK_CYCLE_DURATION;
+use super::camera_follow::CameraFollowPlugin;
+use super::campaign::CampaignPlugin;
+use super::castle::CastlePlugin;
+#[cfg(feature = "combat_log")]
+use super::combat_log::CombatLogPlugin;
+#[cfg(feature = "debug_console")]
+use super::console::ConsolePlugin;
+use super::constants::{ATTACK_CYCLE_DURATION, CORPSE_CLEANUP_INTERVAL};
+#[cfg(feature = "dev_tools")]
+use super::dev_tools::DevToolsPlugin;
+use super::effectiveness_overlay::EffectivenessOverlayPlugin;
+use super::endless::EndlessPlugin;
+use super::fixed_timestep::FixedTimestepPlugin;
 use super::input::InputPlugin;
-use super::resources::{CurrentLevel, GameOutcome, KillStats};
+use super::menu_background::MenuBackgroundPlugin;
+use super::range_overlay::RangeOverlayPlugin;
+use super::recall::RecallPlugin;
+use super::resources::{
+    BattleTimer, CurrentLevel, GameOutcome, KillStats, MaxEntities, ProjectileCount,
+};
+use super::run_conditions;
+use super::sandbox::SandboxPlugin;
+use super::selection::SelectionPlugin;
 use super::shared_systems;
+use super::sound_cues::SoundCuesPlugin;
 use super::systems;
+use super::ultimate::UltimatePlugin;
 use super::units::UnitsPlugin;
+use super::upgrades::UpgradesPlugin;
+use super::waves::WavesPlugin;
 use super::win_lose_systems;
+use super::wind::WindPlugin;
 
 /// Global attack cycle timer resource.
 ///
@@ -47,6 +76,8 @@ impl GlobalAttackCycle {
 /// - Flocking/Separation: Adds forces to Acceleration
 ///
 /// All systems in this set can run in parallel since they only read Transform.
+/// Runs in `FixedUpdate` so the simulation advances in fixed steps.
+/// Skipped (along with MovementSystemSet) during a wave's preparation phase.
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct VelocitySystemSet;
 
@@ -54,6 +85,7 @@ pub struct VelocitySystemSet;
 ///
 /// Movement systems query their specific unit type (mutable Transform) and apply velocities.
 /// This set runs after velocity calculations to ensure all velocities are computed first.
+/// Runs in `FixedUpdate`; rendered positions are interpolated between steps.
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MovementSystemSet;
 
@@ -61,23 +93,91 @@ pub struct MovementSystemSet;
 ///
 /// Registers sub-plugins for:
 /// - Input handling (InputPlugin)
+/// - Fixed timestep simulation and interpolation (FixedTimestepPlugin)
 /// - Battlefield and castle setup (BattlefieldPlugin)
+/// - Castle health as a siege objective (CastlePlugin)
 /// - All units: wizard, defenders, attackers (UnitsPlugin)
-/// - Shared movement and cleanup systems
+/// - Defender box selection and move orders (SelectionPlugin)
+/// - Recalling defenders to the King (RecallPlugin)
+/// - Ultimate charge and lightning storm (UltimatePlugin)
+/// - Optional wind that deflects arrows (WindPlugin)
+/// - Wave campaign and intermissions (CampaignPlugin)
+/// - Wizard upgrades bought between waves (UpgradesPlugin)
+/// - Designed campaign waves loaded from RON (WavesPlugin)
+/// - Endless horde waves (EndlessPlugin)
+/// - Practice sandbox with units spawned on demand (SandboxPlugin)
+/// - Effectiveness color overlay (EffectivenessOverlayPlugin)
+/// - Attack range ring overlay (RangeOverlayPlugin)
+/// - Optional camera that follows the action (CameraFollowPlugin)
+/// - Battle balance estimate for the HUD hint (BattleBalancePlugin)
+/// - Audio cues for battle milestones (SoundCuesPlugin)
+/// - Idle battle behind the main menu (MenuBackgroundPlugin)
+/// - Developer console (ConsolePlugin, `debug_console` feature only)
+/// - Constant tuning panel (DevToolsPlugin, `dev_tools` feature only)
+/// - Combat log export (CombatLogPlugin, `combat_log` feature only)
+/// - Shared movement and cleanup systems, including clearing old corpses over the entity budget
+/// - Counting projectiles in flight for the projectile cap
+/// - Decaying corpses or removing dead units outright, per the corpse setting
+/// - Freezing game time whenever gameplay is not running
 pub struct GamePlugin;
 
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<GlobalAttackCycle>()
             .init_resource::<KillStats>()
+            .init_resource::<BattleTimer>()
             .init_resource::<CurrentLevel>()
+            .init_resource::<MaxEntities>()
+            .init_resource::<ProjectileCount>()
             .insert_resource(GameOutcome::Victory)
-            .add_plugins((InputPlugin, BattlefieldPlugin, UnitsPlugin))
+            .add_plugins((
+                InputPlugin,
+                FixedTimestepPlugin,
+                BattlefieldPlugin,
+                CastlePlugin,
+                UnitsPlugin,
+                SelectionPlugin,
+                RecallPlugin,
+                UltimatePlugin,
+                WindPlugin,
+            ))
+            .add_plugins((
+                CampaignPlugin,
+                UpgradesPlugin,
+                WavesPlugin,
+                EndlessPlugin,
+                SandboxPlugin,
+                EffectivenessOverlayPlugin,
+                RangeOverlayPlugin,
+                CameraFollowPlugin,
+                BattleBalancePlugin,
+                SoundCuesPlugin,
+                MenuBackgroundPlugin,
+            ))
             .add_systems(
                 OnEnter(AppState::InGame),
                 shared_systems::init_level_from_config,
             )
-            .add_systems(OnExit(AppState::InGame), shared_systems::cleanup_game)
+            .add_systems(
+                PreUpdate,
+                shared_systems::count_projectiles.run_if(in_state(AppState::InGame)),
+            )
+            .add_systems(
+                OnExit(AppState::InGame),
+                (
+                    shared_systems::cleanup_game,
+                    // Menus run on unpaused time even if the game was quit from the pause menu
+                    shared_systems::resume_game_time,
+                ),
+            )
+            .add_systems(
+                OnExit(InGameState::Running),
+                shared_systems::pause_game_time,
+            )
+            .add_systems(
+                OnEnter(InGameState::Running),
+                shared_systems::resume_game_time,
+            )
             .add_systems(
                 OnExit(InGameState::GameOver),
                 (
@@ -86,36 +186,48 @@ impl Plugin for GamePlugin {
                 ),
             )
             .configure_sets(
-                Update,
+                FixedUpdate,
                 (
-                    VelocitySystemSet.run_if(in_state(InGameState::Running)),
+                    VelocitySystemSet
+                        .run_if(in_state(InGameState::Running))
+                        .run_if(run_conditions::combat_started),
                     MovementSystemSet
                         .run_if(in_state(InGameState::Running))
+                        .run_if(run_conditions::combat_started)
                         .after(VelocitySystemSet),
                 ),
             )
             .add_systems(
-                Update,
-                shared_systems::tick_attack_cycle.run_if(in_state(InGameState::Running)),
+                FixedUpdate,
+                (
+                    shared_systems::tick_attack_cycle,
+                    shared_systems::tick_battle_timer,
+                )
+                    .run_if(in_state(InGameState::Running))
+                    .run_if(run_conditions::combat_started),
             )
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     // Separation adds flocking forces (immutable queries)
                     // Unit-specific targeting systems registered in their respective plugins
                     shared_systems::apply_separation,
+                    // Nudge units locked in place by overlap corrections
+                    shared_systems::unstick_jittering_units,
                     shared_systems::apply_wall_avoidance,
                 )
                     .chain()
                     .in_set(VelocitySystemSet),
             )
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     // Calculate effectiveness based on nearby allies/enemies
                     shared_systems::calculate_effectiveness,
                     // Apply rough terrain slowdown before movement
                     shared_systems::apply_rough_terrain_slowdown,
+                    // Units near death go berserk before they strike
+                    shared_systems::update_berserk,
                 )
                     .chain()
                     .run_if(in_state(InGameState::Running))
@@ -123,22 +235,52 @@ impl Plugin for GamePlugin {
                     .before(MovementSystemSet),
             )
             .add_systems(
-                Update,
+                FixedUpdate,
                 (
                     // Unit-specific movement systems run in parallel as a set
                     // (infantry_movement and archer_movement registered in their respective plugins)
                     // They read from TargetingVelocity set by update_targeting
                     shared_systems::enforce_wall_collision,
-                    shared_systems::combat,
+                    shared_systems::enforce_castle_collision,
+                    shared_systems::combat.run_if(run_conditions::combat_started),
+                    shared_systems::drain_wizard_mana.run_if(run_conditions::combat_started),
                     shared_systems::convert_dead_to_corpses,
-                    // Update billboards to face camera
-                    systems::update_billboards,
-                    // Check win/lose conditions
-                    win_lose_systems::check_win_lose_conditions,
                 )
                     .chain()
                     .run_if(in_state(InGameState::Running))
                     .after(MovementSystemSet),
-            );
+            )
+            .add_systems(
+                Update,
+                (
+                    // Update billboards to face camera
+                    systems::update_billboards,
+                    // Check win/lose conditions (practice games cannot be won or lost)
+                    win_lose_systems::check_win_lose_conditions
+                        .run_if(not(run_conditions::in_sandbox_mode)),
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running)),
+            )
+            .add_systems(
+                Update,
+                (
+                    shared_systems::despawn_oldest_corpses
+                        .run_if(on_timer(Duration::from_secs_f32(CORPSE_CLEANUP_INTERVAL))),
+                    shared_systems::decay_corpses,
+                )
+                    .run_if(in_state(InGameState::Running)),
+            )
+            // Runs last so units that vanished this frame can still be looked up
+            .add_systems(Last, shared_systems::despawn_vanishing_units);
+
+        #[cfg(feature = "debug_console")]
+        app.add_plugins(ConsolePlugin);
+
+        #[cfg(feature = "dev_tools")]
+        app.add_plugins(DevToolsPlugin);
+
+        #[cfg(feature = "combat_log")]
+        app.add_plugins(CombatLogPlugin);
     }
 }
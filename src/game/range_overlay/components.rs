@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::game::constants::ATTACK_RANGE_MULTIPLIER;
+use crate::game::units::infantry::styles::UNIT_RADIUS;
+
+/// Whether the attack range overlay is currently shown.
+#[derive(Resource, Default)]
+pub struct RangeOverlay {
+    pub enabled: bool,
+}
+
+/// Returns the center-to-center distance at which a unit of `radius` lands melee hits.
+///
+/// Melee range depends on both hitboxes, so the ring shows the range against
+/// a standard infantry-sized target.
+pub fn melee_attack_range(radius: f32) -> f32 {
+    (radius + UNIT_RADIUS) * ATTACK_RANGE_MULTIPLIER
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_melee_range_matches_combat_check_against_infantry() {
+        let expected = (UNIT_RADIUS + UNIT_RADIUS) * ATTACK_RANGE_MULTIPLIER;
+        assert_eq!(melee_attack_range(UNIT_RADIUS), expected);
+    }
+
+    #[test]
+    fn test_larger_units_reach_further() {
+        assert!(melee_attack_range(2.0 * UNIT_RADIUS) > melee_attack_range(UNIT_RADIUS));
+    }
+}
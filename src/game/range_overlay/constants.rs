@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+/// Key that toggles the attack range overlay on and off.
+pub const TOGGLE_KEY: KeyCode = KeyCode::KeyV;
+
+/// Height of the range rings above the battlefield, to avoid z-fighting with the ground.
+pub const RING_HEIGHT: f32 = 1.0;
@@ -0,0 +1,12 @@
+//! Attack range overlay module.
+//!
+//! Optionally draws each unit's attack range as a ring on the ground so the
+//! player can see why archers hold their distance and when melee triggers.
+
+mod components;
+mod constants;
+mod plugin;
+mod styles;
+mod systems;
+
+pub use plugin::RangeOverlayPlugin;
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use crate::state::InGameState;
+
+use super::components::RangeOverlay;
+use super::systems;
+
+/// Plugin that draws unit attack ranges as ground rings.
+///
+/// Registers systems for:
+/// - Toggling the overlay with a hotkey
+/// - Drawing archer min/max ranges and melee ranges while the overlay is on
+pub struct RangeOverlayPlugin;
+
+impl Plugin for RangeOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RangeOverlay>().add_systems(
+            Update,
+            (
+                systems::toggle_range_overlay,
+                systems::draw_attack_ranges.run_if(systems::range_overlay_enabled),
+            )
+                .chain()
+                .run_if(in_state(InGameState::Running)),
+        );
+    }
+}
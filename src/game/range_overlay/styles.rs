@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+/// Ring color for defender attack ranges (archer maximum range and melee range).
+pub const DEFENDER_RANGE_COLOR: Color = Color::srgba(0.3, 0.6, 1.0, 0.8);
+
+/// Ring color for a defender archer's minimum range.
+pub const DEFENDER_MIN_RANGE_COLOR: Color = Color::srgba(0.3, 0.6, 1.0, 0.35);
+
+/// Ring color for attacker attack ranges (archer maximum range and melee range).
+pub const ATTACKER_RANGE_COLOR: Color = Color::srgba(1.0, 0.35, 0.3, 0.8);
+
+/// Ring color for an attacker archer's minimum range.
+pub const ATTACKER_MIN_RANGE_COLOR: Color = Color::srgba(1.0, 0.35, 0.3, 0.35);
+
+/// Ring color for undead attack ranges, matching the raised undead tint.
+pub const UNDEAD_RANGE_COLOR: Color = Color::srgba(0.3, 0.8, 0.4, 0.8);
+
+/// Ring color for an undead archer's minimum range.
+pub const UNDEAD_MIN_RANGE_COLOR: Color = Color::srgba(0.3, 0.8, 0.4, 0.35);
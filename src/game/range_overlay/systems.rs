@@ -0,0 +1,62 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::{RING_HEIGHT, TOGGLE_KEY};
+use super::styles::*;
+use crate::game::units::archer::components::AttackRange;
+use crate::game::units::components::{Corpse, Hitbox, Team};
+
+/// Toggles the attack range overlay with its hotkey.
+pub fn toggle_range_overlay(keys: Res<ButtonInput<KeyCode>>, mut overlay: ResMut<RangeOverlay>) {
+    if keys.just_pressed(TOGGLE_KEY) {
+        overlay.enabled = !overlay.enabled;
+    }
+}
+
+/// Run condition that returns true while the range overlay is on.
+///
+/// Keeps the drawing system from iterating units at all while the overlay is off.
+pub fn range_overlay_enabled(overlay: Res<RangeOverlay>) -> bool {
+    overlay.enabled
+}
+
+/// Draws each living unit's attack range as ground rings.
+///
+/// Archers show their minimum and maximum ranges; other units show their
+/// melee range. Each team's rings use a different color.
+pub fn draw_attack_ranges(
+    mut gizmos: Gizmos,
+    units: Query<(&Transform, &Hitbox, &Team, Option<&AttackRange>), Without<Corpse>>,
+) {
+    // Gizmo circles are drawn in the XY plane; tip them flat onto the ground
+    let ground_rotation = Quat::from_rotation_x(FRAC_PI_2);
+
+    for (transform, hitbox, team, attack_range) in &units {
+        let (range_color, min_range_color) = match team {
+            Team::Defenders => (DEFENDER_RANGE_COLOR, DEFENDER_MIN_RANGE_COLOR),
+            Team::Attackers => (ATTACKER_RANGE_COLOR, ATTACKER_MIN_RANGE_COLOR),
+            // Raised undead are hostile to both sides, so they get their own color
+            Team::Undead => (UNDEAD_RANGE_COLOR, UNDEAD_MIN_RANGE_COLOR),
+        };
+        let center = Isometry3d::new(
+            Vec3::new(
+                transform.translation.x,
+                RING_HEIGHT,
+                transform.translation.z,
+            ),
+            ground_rotation,
+        );
+
+        match attack_range {
+            Some(range) => {
+                gizmos.circle(center, range.max_range, range_color);
+                gizmos.circle(center, range.min_range, min_range_color);
+            }
+            None => {
+                gizmos.circle(center, melee_attack_range(hitbox.radius), range_color);
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+/// A recall that pulls a defender back toward the King.
+///
+/// While present, the unit steers toward the King (or the castle if the King
+/// is gone) instead of the nearest enemy. Removed when the timer runs out.
+#[derive(Component)]
+pub struct RecallOrder {
+    /// Seconds remaining before the unit returns to auto-targeting.
+    pub timer: f32,
+}
+
+impl RecallOrder {
+    /// Creates a new recall lasting the given duration.
+    pub const fn new(duration: f32) -> Self {
+        Self { timer: duration }
+    }
+
+    /// Advances the recall timer. Returns true if the recall has expired.
+    pub fn tick(&mut self, delta: f32) -> bool {
+        self.timer -= delta;
+        self.timer <= 0.0
+    }
+}
+
+/// Cooldown before the player can recall the defenders again.
+#[derive(Resource, Default)]
+pub struct RecallCooldown {
+    /// Seconds remaining until the recall is ready (0 when ready).
+    pub remaining: f32,
+}
+
+impl RecallCooldown {
+    /// Returns true if the recall can be used.
+    pub fn is_ready(&self) -> bool {
+        self.remaining <= 0.0
+    }
+
+    /// Starts the cooldown after a recall.
+    pub fn start(&mut self, duration: f32) {
+        self.remaining = duration;
+    }
+
+    /// Counts the cooldown down by delta time.
+    pub fn tick(&mut self, delta: f32) {
+        self.remaining = (self.remaining - delta).max(0.0);
+    }
+
+    /// Makes the recall ready again for a new game.
+    pub fn reset(&mut self) {
+        self.remaining = 0.0;
+    }
+}
@@ -0,0 +1,15 @@
+//! Recall constants.
+
+use bevy::prelude::*;
+
+/// Key that recalls all defenders to the King.
+pub const RECALL_KEY: KeyCode = KeyCode::KeyG;
+
+/// How long a recall overrides auto-targeting (in seconds).
+pub const RECALL_DURATION: f32 = 4.0;
+
+/// Seconds before the recall can be used again.
+pub const RECALL_COOLDOWN: f32 = 30.0;
+
+/// Distance from the rally point at which a recalled unit stops being pulled.
+pub const RECALL_ARRIVAL_RADIUS: f32 = 80.0;
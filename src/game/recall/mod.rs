@@ -0,0 +1,11 @@
+//! Recall module.
+//!
+//! Lets the player pull every living defender back to the King on a cooldown,
+//! so units strung out after a chaotic fight can regroup.
+
+pub mod components;
+mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::RecallPlugin;
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+use crate::game::plugin::{MovementSystemSet, VelocitySystemSet};
+use crate::game::run_conditions;
+use crate::state::{AppState, InGameState};
+
+use super::components::RecallCooldown;
+use super::systems;
+
+/// Plugin that handles recalling defenders to the King.
+///
+/// Registers systems for:
+/// - Issuing a recall with a hotkey and counting down its cooldown
+/// - Overriding auto-targeting while a recall is active
+/// - Clearing recalls from units that have died
+/// - Resetting the cooldown for each new game
+pub struct RecallPlugin;
+
+impl Plugin for RecallPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RecallCooldown>()
+            .add_systems(
+                Update,
+                (
+                    systems::tick_recall_cooldown,
+                    systems::issue_recall,
+                    systems::clear_dead_recall_orders,
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running))
+                    .run_if(run_conditions::combat_started),
+            )
+            .add_systems(
+                FixedUpdate,
+                systems::apply_recall_orders
+                    .run_if(in_state(InGameState::Running))
+                    .run_if(run_conditions::combat_started)
+                    .after(VelocitySystemSet)
+                    .before(MovementSystemSet),
+            )
+            .add_systems(OnEnter(AppState::InGame), systems::reset_recall)
+            .add_systems(OnExit(InGameState::GameOver), systems::reset_recall);
+    }
+}
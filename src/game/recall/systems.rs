@@ -0,0 +1,168 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::*;
+use crate::game::constants::CASTLE_POSITION;
+use crate::game::selection::components::{AttackMove, MoveOrder};
+use crate::game::units::components::{Corpse, TargetingVelocity, Team};
+use crate::game::units::king::components::King;
+
+/// Counts down the recall cooldown.
+pub fn tick_recall_cooldown(time: Res<Time>, mut cooldown: ResMut<RecallCooldown>) {
+    cooldown.tick(time.delta_secs());
+}
+
+/// Recalls every living defender to the King when the recall key is pressed.
+///
+/// Replaces any active move orders and starts the cooldown.
+pub fn issue_recall(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut cooldown: ResMut<RecallCooldown>,
+    units: Query<(Entity, &Team), (With<TargetingVelocity>, Without<Corpse>, Without<King>)>,
+) {
+    if !keyboard.just_pressed(RECALL_KEY) || !cooldown.is_ready() {
+        return;
+    }
+
+    for (entity, team) in &units {
+        if *team == Team::Defenders {
+            commands
+                .entity(entity)
+                .remove::<(MoveOrder, AttackMove)>()
+                .insert(RecallOrder::new(RECALL_DURATION));
+        }
+    }
+    cooldown.start(RECALL_COOLDOWN);
+}
+
+/// Steers recalled units toward the King, or the castle if the King is gone.
+///
+/// Runs after the targeting systems and overwrites `TargetingVelocity`, so the
+/// movement systems follow the recall instead of the nearest enemy. A zero
+/// `distance_to_target` gives the recall full steering weight over flocking.
+/// Units already near the rally point fight normally until the recall expires.
+/// A move order issued during the recall takes priority over it.
+#[allow(clippy::type_complexity)]
+pub fn apply_recall_orders(
+    mut commands: Commands,
+    time: Res<Time>,
+    kings: Query<&Transform, (With<King>, Without<Corpse>)>,
+    mut units: Query<
+        (Entity, &Transform, &mut RecallOrder, &mut TargetingVelocity),
+        (Without<Corpse>, Without<King>, Without<MoveOrder>),
+    >,
+) {
+    let delta = time.delta_secs();
+    let rally_point = kings
+        .iter()
+        .next()
+        .map_or(CASTLE_POSITION, |transform| transform.translation);
+
+    for (entity, transform, mut order, mut targeting_velocity) in &mut units {
+        if order.tick(delta) {
+            // Recall finished - auto-targeting takes over next frame
+            commands.entity(entity).remove::<RecallOrder>();
+            continue;
+        }
+
+        let diff = Vec3::new(
+            rally_point.x - transform.translation.x,
+            0.0,
+            rally_point.z - transform.translation.z,
+        );
+        if diff.length() > RECALL_ARRIVAL_RADIUS {
+            targeting_velocity.velocity = diff.normalize_or_zero();
+            targeting_velocity.distance_to_target = 0.0;
+        }
+    }
+}
+
+/// Clears recalls from units that have died.
+pub fn clear_dead_recall_orders(
+    mut commands: Commands,
+    query: Query<Entity, (With<RecallOrder>, With<Corpse>)>,
+) {
+    for entity in &query {
+        commands.entity(entity).remove::<RecallOrder>();
+    }
+}
+
+/// Makes the recall ready again for a new game.
+pub fn reset_recall(mut cooldown: ResMut<RecallCooldown>) {
+    cooldown.reset();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(100);
+
+    fn app_with_defender_and_king(king_position: Vec3) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<RecallCooldown>()
+            .add_systems(Update, (issue_recall, apply_recall_orders).chain());
+
+        app.world_mut().spawn((
+            King,
+            Team::Defenders,
+            Transform::from_translation(king_position),
+        ));
+        let defender = app
+            .world_mut()
+            .spawn((
+                Team::Defenders,
+                Transform::default(),
+                TargetingVelocity {
+                    velocity: Vec3::X,
+                    distance_to_target: 50.0,
+                },
+            ))
+            .id();
+        // The first frame only starts the clock
+        app.update();
+        (app, defender)
+    }
+
+    fn press_recall(app: &mut App) {
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.press(RECALL_KEY);
+        app.update();
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(RECALL_KEY);
+        keyboard.clear();
+    }
+
+    #[test]
+    fn test_recalled_defender_steers_toward_king() {
+        let (mut app, defender) = app_with_defender_and_king(Vec3::new(0.0, 0.0, 500.0));
+        press_recall(&mut app);
+        app.update();
+
+        let targeting = app.world().get::<TargetingVelocity>(defender).unwrap();
+        assert_eq!(targeting.velocity, Vec3::Z);
+        assert_eq!(targeting.distance_to_target, 0.0);
+        assert!(!app.world().resource::<RecallCooldown>().is_ready());
+    }
+
+    #[test]
+    fn test_recall_expires_after_its_duration() {
+        let (mut app, defender) = app_with_defender_and_king(Vec3::new(0.0, 0.0, 500.0));
+        press_recall(&mut app);
+        assert!(app.world().get::<RecallOrder>(defender).is_some());
+
+        let frames = (RECALL_DURATION / FRAME.as_secs_f32()).ceil() as usize + 1;
+        for _ in 0..frames {
+            app.update();
+        }
+        assert!(app.world().get::<RecallOrder>(defender).is_none());
+    }
+}
@@ -1,5 +1,9 @@
 use bevy::prelude::*;
 
+use super::constants::{
+    ALIGNMENT_STRENGTH, COHESION_STRENGTH, DEFAULT_MAX_ENTITIES, EFFECTIVENESS_ALLY_BONUS_PER_UNIT,
+    EFFECTIVENESS_ENEMY_PENALTY_PER_UNIT, SEPARATION_STRENGTH, STEERING_FORCE, VELOCITY_DAMPING,
+};
 use super::units::components::Team;
 
 /// Tracks kill statistics throughout the game for the score screen.
@@ -26,12 +30,137 @@ impl KillStats {
     }
 }
 
+/// Movement and effectiveness constants that can be tuned while the game runs.
+///
+/// Only inserted by the `dev_tools` tuning panel. Systems fall back to the
+/// defaults, the values baked into `constants.rs`, when it is missing.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TunableConstants {
+    pub steering_force: f32,
+    pub velocity_damping: f32,
+    pub separation_strength: f32,
+    pub alignment_strength: f32,
+    pub cohesion_strength: f32,
+    pub effectiveness_ally_bonus: f32,
+    pub effectiveness_enemy_penalty: f32,
+}
+
+impl Default for TunableConstants {
+    fn default() -> Self {
+        Self {
+            steering_force: STEERING_FORCE,
+            velocity_damping: VELOCITY_DAMPING,
+            separation_strength: SEPARATION_STRENGTH,
+            alignment_strength: ALIGNMENT_STRENGTH,
+            cohesion_strength: COHESION_STRENGTH,
+            effectiveness_ally_bonus: EFFECTIVENESS_ALLY_BONUS_PER_UNIT,
+            effectiveness_enemy_penalty: EFFECTIVENESS_ENEMY_PENALTY_PER_UNIT,
+        }
+    }
+}
+
+impl TunableConstants {
+    /// Returns the tuned values, or the baked-in constants when not tuning.
+    pub fn or_default(tunables: Option<Res<Self>>) -> Self {
+        tunables.as_deref().copied().unwrap_or_default()
+    }
+}
+
+/// Magic missiles and arrows in flight, each checked against `GameConfig::max_projectiles`.
+///
+/// The two are counted separately, so archers filling the cap never hold back
+/// the player's missiles. Recounted from the world at the start of each frame and
+/// bumped by the spawners as they fire, so several spawns in one frame can't
+/// overshoot the cap.
+#[derive(Resource, Default)]
+pub struct ProjectileCount {
+    pub missiles: usize,
+    pub arrows: usize,
+}
+
+impl ProjectileCount {
+    /// Returns true if another magic missile fits under `cap` (None = unlimited).
+    pub fn missile_has_room(&self, cap: Option<u32>) -> bool {
+        cap.is_none_or(|cap| self.missiles < cap as usize)
+    }
+
+    /// Returns true if another arrow fits under `cap` (None = unlimited).
+    pub fn arrow_has_room(&self, cap: Option<u32>) -> bool {
+        cap.is_none_or(|cap| self.arrows < cap as usize)
+    }
+
+    /// Counts a magic missile spawned since the last recount.
+    pub fn record_missile(&mut self) {
+        self.missiles += 1;
+    }
+
+    /// Counts an arrow spawned since the last recount.
+    pub fn record_arrow(&mut self) {
+        self.arrows += 1;
+    }
+}
+
+/// Budget on living units plus corpses, to bound memory over long games.
+///
+/// Once over the limit, the oldest resurrectable corpses are cleared and
+/// endless waves hold back until living units make room.
+#[derive(Resource)]
+pub struct MaxEntities {
+    pub limit: usize,
+}
+
+impl Default for MaxEntities {
+    fn default() -> Self {
+        Self {
+            limit: DEFAULT_MAX_ENTITIES,
+        }
+    }
+}
+
+impl MaxEntities {
+    /// Returns how far `count` entities are over the budget.
+    pub fn excess(&self, count: usize) -> usize {
+        count.saturating_sub(self.limit)
+    }
+
+    /// Returns how many more units fit with `count` already on the field.
+    pub fn room(&self, count: usize) -> usize {
+        self.limit.saturating_sub(count)
+    }
+}
+
+/// Tracks how long the current battle has been fought, for the score screen.
+///
+/// Only counts time spent fighting, so pauses and the preparation phase are excluded.
+#[derive(Resource, Default)]
+pub struct BattleTimer {
+    /// Seconds of combat elapsed this game.
+    pub elapsed: f32,
+}
+
+impl BattleTimer {
+    pub fn tick(&mut self, delta: f32) {
+        self.elapsed += delta;
+    }
+
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+
+    /// Returns the elapsed time formatted as minutes and seconds (e.g. "4:07").
+    pub fn display(&self) -> String {
+        let total_seconds = self.elapsed as u32;
+        format!("{}:{:02}", total_seconds / 60, total_seconds % 60)
+    }
+}
+
 /// Tracks whether the player won or lost the game.
 #[derive(Resource, Clone, Copy, PartialEq, Eq)]
 pub enum GameOutcome {
-    Victory,        // Player wins (all attackers and undead eliminated)
-    Defeat,         // Player loses (all defenders eliminated)
-    DefeatKingDied, // Player loses (King was killed)
+    Victory,               // Player wins (all attackers and undead eliminated)
+    Defeat,                // Player loses (all defenders eliminated)
+    DefeatKingDied,        // Player loses (King was killed)
+    DefeatCastleDestroyed, // Player loses (attackers brought down the castle)
 }
 
 /// Current difficulty level - scales enemy spawn counts.
@@ -1,5 +1,9 @@
 use bevy::prelude::*;
 
+use super::campaign::components::CampaignState;
+use super::resources::CurrentLevel;
+use super::waves::components::WaveDefinitions;
+use crate::config::{GameConfig, GameMode};
 use crate::state::InGameState;
 
 /// Run condition that returns true only when transitioning from GameOver to Running.
@@ -14,3 +18,41 @@ pub fn coming_from_game_over(
             && transition.entered == Some(InGameState::Running)
     })
 }
+
+/// Run condition that returns true once the current wave's preparation phase is over.
+///
+/// Unit targeting, movement, and attacks are gated on this so attackers stay
+/// frozen while the player prepares.
+pub fn combat_started(campaign: Res<CampaignState>) -> bool {
+    !campaign.in_preparation()
+}
+
+/// Run condition that returns true while playing the level-based campaign.
+pub fn in_campaign_mode(config: Res<GameConfig>) -> bool {
+    config.game_mode == GameMode::Campaign
+}
+
+/// Run condition that returns true while playing the endless horde mode.
+pub fn in_endless_mode(config: Res<GameConfig>) -> bool {
+    config.game_mode == GameMode::Endless
+}
+
+/// Run condition that returns true while playing the practice sandbox.
+pub fn in_sandbox_mode(config: Res<GameConfig>) -> bool {
+    config.game_mode == GameMode::Sandbox
+}
+
+/// Run condition that returns true when the wizard's personal ward is enabled.
+pub fn personal_ward_enabled(config: Res<GameConfig>) -> bool {
+    config.personal_ward
+}
+
+/// Run condition that returns true when the current level has a designed wave.
+///
+/// The procedural attacker spawners are skipped for such levels.
+pub fn has_defined_wave(
+    definitions: Res<WaveDefinitions>,
+    current_level: Res<CurrentLevel>,
+) -> bool {
+    definitions.for_level(current_level.0).is_some()
+}
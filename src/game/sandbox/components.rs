@@ -0,0 +1,219 @@
+use bevy::prelude::*;
+
+use super::constants::*;
+use crate::game::battlefield::components::BattlefieldConfig;
+use crate::game::constants::{DEFENDER_SPAWN_POINTS, GRID_COLS, GRID_ROWS};
+
+/// Where the practice panel spawns units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpawnPoint {
+    /// Center of the front row of the attacker spawn grid (default)
+    #[default]
+    FrontLine,
+    /// Center of the back row of the attacker spawn grid
+    RearLine,
+    /// Halfway between the attacker front line and the defenders
+    Midfield,
+    /// Just in front of the defenders
+    NearDefenders,
+}
+
+impl SpawnPoint {
+    /// Returns every spawn point in cycling order.
+    pub const fn all() -> &'static [SpawnPoint] {
+        &[
+            SpawnPoint::FrontLine,
+            SpawnPoint::RearLine,
+            SpawnPoint::Midfield,
+            SpawnPoint::NearDefenders,
+        ]
+    }
+
+    /// Returns the display name of the spawn point.
+    pub const fn name(self) -> &'static str {
+        match self {
+            SpawnPoint::FrontLine => "Front Line",
+            SpawnPoint::RearLine => "Rear Line",
+            SpawnPoint::Midfield => "Midfield",
+            SpawnPoint::NearDefenders => "Near Defenders",
+        }
+    }
+
+    /// Returns the spawn point after this one, wrapping around.
+    pub fn next(self) -> Self {
+        let all = Self::all();
+        let index = all.iter().position(|point| *point == self).unwrap_or(0);
+        all[(index + 1) % all.len()]
+    }
+
+    /// Returns the ground position (x, z) units spawn around.
+    pub fn position(self, battlefield: &BattlefieldConfig) -> (f32, f32) {
+        let front = battlefield.grid_cell_position(0, GRID_COLS / 2);
+        match self {
+            SpawnPoint::FrontLine => front,
+            SpawnPoint::RearLine => battlefield.grid_cell_position(GRID_ROWS - 1, GRID_COLS / 2),
+            SpawnPoint::Midfield => toward_front(front, MIDFIELD_FRACTION),
+            SpawnPoint::NearDefenders => toward_front(front, NEAR_DEFENDERS_FRACTION),
+        }
+    }
+}
+
+/// Returns the point `fraction` of the way from the defenders to the attacker front line.
+fn toward_front((front_x, front_z): (f32, f32), fraction: f32) -> (f32, f32) {
+    let count = DEFENDER_SPAWN_POINTS.len() as f32;
+    let defenders_x = DEFENDER_SPAWN_POINTS.iter().map(|(x, _)| x).sum::<f32>() / count;
+    let defenders_z = DEFENDER_SPAWN_POINTS.iter().map(|(_, z)| z).sum::<f32>() / count;
+    (
+        defenders_x + (front_x - defenders_x) * fraction,
+        defenders_z + (front_z - defenders_z) * fraction,
+    )
+}
+
+/// Settings of the practice sandbox, changed from the HUD panel.
+///
+/// Reset when a new game starts.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SandboxConfig {
+    /// Whether the wizard's mana is kept full.
+    pub infinite_mana: bool,
+    /// Units spawned per spawn button press.
+    pub spawn_count: u32,
+    /// Where units are spawned.
+    pub spawn_point: SpawnPoint,
+}
+
+impl Default for SandboxConfig {
+    fn default() -> Self {
+        Self {
+            infinite_mana: false,
+            spawn_count: DEFAULT_SPAWN_COUNT,
+            spawn_point: SpawnPoint::default(),
+        }
+    }
+}
+
+impl SandboxConfig {
+    /// Advances the spawn count to the next option, wrapping around.
+    pub fn cycle_spawn_count(&mut self) {
+        let next = SPAWN_COUNTS
+            .iter()
+            .position(|count| *count == self.spawn_count)
+            .map_or(0, |index| (index + 1) % SPAWN_COUNTS.len());
+        self.spawn_count = SPAWN_COUNTS[next];
+    }
+
+    /// Resets the sandbox for a new game.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// A practice panel action, sent as a message for execution.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandboxCommand {
+    /// Spawns attacker infantry at the spawn point.
+    SpawnAttackers,
+    /// Spawns attacker archers at the spawn point.
+    SpawnArchers,
+    /// Spawns a King at the spawn point if none is alive.
+    SpawnKing,
+    /// Refills the wizard's mana.
+    RefillMana,
+    /// Turns infinite mana on or off.
+    ToggleInfiniteMana,
+    /// Cycles the number of units spawned per press.
+    CycleSpawnCount,
+    /// Cycles where units are spawned.
+    CycleSpawnPoint,
+}
+
+impl SandboxCommand {
+    /// Returns every command in panel order.
+    pub const fn all() -> &'static [SandboxCommand] {
+        &[
+            SandboxCommand::SpawnAttackers,
+            SandboxCommand::SpawnArchers,
+            SandboxCommand::SpawnKing,
+            SandboxCommand::CycleSpawnCount,
+            SandboxCommand::CycleSpawnPoint,
+            SandboxCommand::RefillMana,
+            SandboxCommand::ToggleInfiniteMana,
+        ]
+    }
+
+    /// Returns the button label for this command under the current settings.
+    pub fn label(self, sandbox: &SandboxConfig) -> String {
+        match self {
+            SandboxCommand::SpawnAttackers => format!("Spawn {} Attackers", sandbox.spawn_count),
+            SandboxCommand::SpawnArchers => format!("Spawn {} Archers", sandbox.spawn_count),
+            SandboxCommand::SpawnKing => "Spawn King".to_string(),
+            SandboxCommand::RefillMana => "Refill Mana".to_string(),
+            SandboxCommand::ToggleInfiniteMana => format!(
+                "Infinite Mana: {}",
+                if sandbox.infinite_mana { "On" } else { "Off" }
+            ),
+            SandboxCommand::CycleSpawnCount => format!("Count: {}", sandbox.spawn_count),
+            SandboxCommand::CycleSpawnPoint => format!("At: {}", sandbox.spawn_point.name()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constants::BATTLEFIELD_SIZE;
+
+    #[test]
+    fn test_spawn_count_cycles_and_wraps() {
+        let mut sandbox = SandboxConfig::default();
+        assert_eq!(sandbox.spawn_count, DEFAULT_SPAWN_COUNT);
+
+        let mut seen = Vec::new();
+        for _ in 0..SPAWN_COUNTS.len() {
+            sandbox.cycle_spawn_count();
+            seen.push(sandbox.spawn_count);
+        }
+        // A full cycle visits every option and returns to the start
+        assert_eq!(sandbox.spawn_count, DEFAULT_SPAWN_COUNT);
+        for count in SPAWN_COUNTS {
+            assert!(seen.contains(&count));
+        }
+    }
+
+    #[test]
+    fn test_spawn_points_cycle_to_distinct_positions() {
+        let battlefield = BattlefieldConfig {
+            size: BATTLEFIELD_SIZE,
+        };
+        let mut point = SpawnPoint::default();
+        let mut positions = Vec::new();
+        for _ in 0..SpawnPoint::all().len() {
+            positions.push(point.position(&battlefield));
+            point = point.next();
+        }
+        assert_eq!(point, SpawnPoint::default());
+
+        for (i, a) in positions.iter().enumerate() {
+            for b in &positions[i + 1..] {
+                assert!((a.0 - b.0).abs() + (a.1 - b.1).abs() > 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_labels_follow_settings() {
+        let sandbox = SandboxConfig {
+            infinite_mana: true,
+            spawn_count: 25,
+            ..default()
+        };
+        assert_eq!(
+            SandboxCommand::SpawnArchers.label(&sandbox),
+            "Spawn 25 Archers"
+        );
+        assert_eq!(
+            SandboxCommand::ToggleInfiniteMana.label(&sandbox),
+            "Infinite Mana: On"
+        );
+    }
+}
@@ -0,0 +1,11 @@
+/// Unit counts the spawn buttons cycle through.
+pub const SPAWN_COUNTS: [u32; 5] = [1, 5, 10, 25, 50];
+
+/// Units spawned per button press when a practice game starts.
+pub const DEFAULT_SPAWN_COUNT: u32 = 5;
+
+/// How far from the defenders toward the attacker front line midfield spawns land (0.0 to 1.0).
+pub const MIDFIELD_FRACTION: f32 = 0.5;
+
+/// How far from the defenders toward the attacker front line close-range spawns land (0.0 to 1.0).
+pub const NEAR_DEFENDERS_FRACTION: f32 = 0.2;
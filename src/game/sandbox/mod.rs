@@ -0,0 +1,11 @@
+//! Practice sandbox module.
+//!
+//! A game mode with no waves and no win or loss, where the player spawns
+//! attackers and a King on demand from a HUD panel to try out spells.
+
+pub mod components;
+pub mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::SandboxPlugin;
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use crate::game::run_conditions;
+use crate::state::{AppState, InGameState};
+
+use super::components::{SandboxCommand, SandboxConfig};
+use super::systems;
+
+/// Plugin that runs the practice sandbox game mode.
+///
+/// Registers systems for:
+/// - Executing practice panel commands (spawning units, refilling mana)
+/// - Keeping mana full while infinite mana is on
+/// - Resetting the sandbox settings for each new game
+pub struct SandboxPlugin;
+
+impl Plugin for SandboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SandboxConfig>()
+            .add_message::<SandboxCommand>()
+            .add_systems(OnEnter(AppState::InGame), systems::reset_sandbox)
+            .add_systems(
+                Update,
+                (
+                    systems::execute_sandbox_commands,
+                    systems::apply_infinite_mana,
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running))
+                    .run_if(run_conditions::in_sandbox_mode),
+            );
+    }
+}
@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+
+use super::components::{SandboxCommand, SandboxConfig};
+use crate::game::battlefield::components::BattlefieldConfig;
+use crate::game::constants::spawn_offset;
+use crate::game::resources::MaxEntities;
+use crate::game::units::archer::systems::spawn_attacker_archer;
+use crate::game::units::components::{Corpse, Team};
+use crate::game::units::infantry::systems::spawn_attacker_infantry;
+use crate::game::units::king::components::King;
+use crate::game::units::king::systems::spawn_king_at;
+use crate::game::units::wizard::components::{Mana, Wizard};
+
+/// Executes practice panel commands.
+///
+/// Units spawn around the chosen spawn point and join the battle like any
+/// other unit. Spawns are trimmed to the entity budget, and a King is only
+/// spawned while none is alive.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_sandbox_commands(
+    mut commands: Commands,
+    mut sandbox_commands: MessageReader<SandboxCommand>,
+    mut sandbox: ResMut<SandboxConfig>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    battlefield: Res<BattlefieldConfig>,
+    budget: Res<MaxEntities>,
+    living: Query<(), (With<Team>, Without<Corpse>)>,
+    kings: Query<(), (With<King>, Without<Corpse>)>,
+    mut mana_query: Query<&mut Mana, With<Wizard>>,
+) {
+    let mut room = budget.room(living.iter().len()) as u32;
+    let mut king_alive = !kings.is_empty();
+
+    for command in sandbox_commands.read() {
+        let center = sandbox.spawn_point.position(&battlefield);
+        match command {
+            SandboxCommand::SpawnAttackers | SandboxCommand::SpawnArchers => {
+                let spawn: SpawnFn = if *command == SandboxCommand::SpawnAttackers {
+                    spawn_attacker_infantry
+                } else {
+                    spawn_attacker_archer
+                };
+                let count = sandbox.spawn_count.min(room);
+                room -= count;
+                for i in 0..count {
+                    let offset = spawn_offset(i);
+                    spawn(
+                        &mut commands,
+                        &mut meshes,
+                        &mut materials,
+                        center.0 + offset.x,
+                        center.1 + offset.y,
+                    );
+                }
+            }
+            SandboxCommand::SpawnKing => {
+                if !king_alive {
+                    spawn_king_at(
+                        &mut commands,
+                        &mut meshes,
+                        &mut materials,
+                        center.0,
+                        center.1,
+                    );
+                    king_alive = true;
+                }
+            }
+            SandboxCommand::RefillMana => {
+                for mut mana in &mut mana_query {
+                    mana.refill();
+                }
+            }
+            SandboxCommand::ToggleInfiniteMana => {
+                sandbox.infinite_mana = !sandbox.infinite_mana;
+            }
+            SandboxCommand::CycleSpawnCount => sandbox.cycle_spawn_count(),
+            SandboxCommand::CycleSpawnPoint => {
+                sandbox.spawn_point = sandbox.spawn_point.next();
+            }
+        }
+    }
+}
+
+/// Signature shared by the single-unit attacker spawn functions.
+type SpawnFn =
+    fn(&mut Commands, &mut Assets<Mesh>, &mut Assets<StandardMaterial>, f32, f32) -> Entity;
+
+/// Keeps the wizard's mana full while infinite mana is on.
+pub fn apply_infinite_mana(
+    sandbox: Res<SandboxConfig>,
+    mut mana_query: Query<&mut Mana, With<Wizard>>,
+) {
+    if !sandbox.infinite_mana {
+        return;
+    }
+
+    for mut mana in &mut mana_query {
+        mana.refill();
+    }
+}
+
+/// Resets the sandbox settings when a new game starts.
+pub fn reset_sandbox(mut sandbox: ResMut<SandboxConfig>) {
+    sandbox.reset();
+}
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+
+use crate::game::units::components::Team;
+
+/// Marker component for defenders currently selected by the player.
+#[derive(Component)]
+pub struct Selected;
+
+/// A player-issued move order that overrides a unit's auto-targeting.
+///
+/// While present, the unit steers toward `target` instead of the nearest enemy.
+/// Removed when the timer runs out or the unit arrives.
+#[derive(Component)]
+pub struct MoveOrder {
+    /// Destination on the battlefield (Y=0 plane).
+    pub target: Vec3,
+    /// Seconds remaining before the unit returns to auto-targeting.
+    pub timer: f32,
+}
+
+impl MoveOrder {
+    /// Creates a new move order toward the given target.
+    pub const fn new(target: Vec3, duration: f32) -> Self {
+        Self {
+            target,
+            timer: duration,
+        }
+    }
+
+    /// Advances the order timer. Returns true if the order has expired.
+    pub fn tick(&mut self, delta: f32) -> bool {
+        self.timer -= delta;
+        self.timer <= 0.0
+    }
+}
+
+/// A player-issued attack-move order.
+///
+/// The unit advances on `destination` but stops to fight any enemy that comes
+/// within engagement range, then resumes the advance. Removed on arrival.
+#[derive(Component)]
+pub struct AttackMove {
+    /// Destination on the battlefield (Y=0 plane).
+    pub destination: Vec3,
+}
+
+/// Tracks an in-progress drag selection in window coordinates.
+#[derive(Resource, Default)]
+pub struct BoxSelection {
+    /// Cursor position where the drag started (None when not dragging).
+    pub start: Option<Vec2>,
+    /// Last known cursor position during the drag.
+    pub current: Vec2,
+    /// Entity ID of the on-screen drag rectangle.
+    pub box_entity: Option<Entity>,
+}
+
+/// Marker component for the on-screen drag rectangle UI node.
+#[derive(Component)]
+pub struct SelectionBoxNode;
+
+/// Ground ring drawn under a selected unit.
+#[derive(Component)]
+pub struct SelectionIndicator {
+    /// The selected unit this ring follows.
+    pub unit: Entity,
+}
+
+/// The attacker the player has ordered defenders to focus on.
+///
+/// While set, defenders steer toward and strike this unit ahead of the nearest
+/// enemy when it is within reach. Cleared when it dies or the order is toggled off.
+#[derive(Resource, Default)]
+pub struct FocusTarget {
+    /// The focused unit (None when no focus order is active).
+    pub target: Option<Entity>,
+}
+
+impl FocusTarget {
+    /// Returns the focused unit if units of `team` should prioritize it.
+    ///
+    /// Only defenders take focus orders.
+    pub fn for_team(&self, team: Team) -> Option<Entity> {
+        self.target.filter(|_| team == Team::Defenders)
+    }
+
+    /// Focuses `target`, or clears the order if it is already focused.
+    pub fn toggle(&mut self, target: Entity) {
+        self.target = if self.target == Some(target) {
+            None
+        } else {
+            Some(target)
+        };
+    }
+}
+
+/// Ring drawn around the focused unit.
+#[derive(Component)]
+pub struct FocusMarker;
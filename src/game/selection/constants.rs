@@ -0,0 +1,34 @@
+//! Selection and move order constants.
+
+/// How long a move order overrides auto-targeting (in seconds).
+pub const MOVE_ORDER_DURATION: f32 = 5.0;
+
+/// Distance from the order target at which a unit is considered arrived.
+pub const MOVE_ORDER_ARRIVAL_RADIUS: f32 = 25.0;
+
+/// Attack-moving units stop to fight enemies within this distance (in units).
+pub const ATTACK_MOVE_ENGAGE_RANGE: f32 = 200.0;
+
+/// Minimum drag distance (in pixels) before a click is treated as a box.
+pub const MIN_DRAG_DISTANCE: f32 = 4.0;
+
+/// Inner radius of the selection ring under selected units.
+pub const INDICATOR_INNER_RADIUS: f32 = 10.0;
+
+/// Outer radius of the selection ring under selected units.
+pub const INDICATOR_OUTER_RADIUS: f32 = 13.0;
+
+/// Y position of the selection ring (slightly above ground).
+pub const INDICATOR_Y_POSITION: f32 = 1.0;
+
+/// How close (XZ distance) a right-click must land to an attacker to focus it.
+pub const FOCUS_PICK_RADIUS: f32 = 40.0;
+
+/// Defenders within this distance of the focused unit break off to chase it.
+pub const FOCUS_FIRE_RANGE: f32 = 600.0;
+
+/// Inner radius of the ring around the focused unit.
+pub const FOCUS_MARKER_INNER_RADIUS: f32 = 16.0;
+
+/// Outer radius of the ring around the focused unit.
+pub const FOCUS_MARKER_OUTER_RADIUS: f32 = 21.0;
@@ -0,0 +1,12 @@
+//! Selection plugin module.
+//!
+//! Handles box-selecting defenders, issuing move orders to the selection, and
+//! focusing all defenders on a single attacker.
+
+pub mod components;
+mod constants;
+mod plugin;
+mod styles;
+mod systems;
+
+pub use plugin::SelectionPlugin;
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+
+use crate::game::plugin::{MovementSystemSet, VelocitySystemSet};
+use crate::game::run_conditions;
+use crate::state::{AppState, InGameState};
+
+use super::components::{BoxSelection, FocusTarget};
+use super::systems;
+
+/// Plugin that handles defender selection and move orders.
+///
+/// Registers systems for:
+/// - Shift + left-drag box selection with an on-screen rectangle
+/// - Right-click move orders for the current selection
+/// - Ctrl + right-click attack-move orders that stop to fight along the way
+/// - Right-click focus orders on an attacker for all defenders
+/// - K + right-click orders that send the King to a spot and hold him there
+/// - Overriding auto-targeting while a move or focus order is active
+/// - Ground rings under selected units and around the focused unit
+pub struct SelectionPlugin;
+
+impl Plugin for SelectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BoxSelection>()
+            .init_resource::<FocusTarget>()
+            .add_systems(
+                Update,
+                (
+                    systems::block_spell_input_while_selecting,
+                    systems::handle_box_selection,
+                    systems::issue_move_orders,
+                    systems::issue_focus_fire,
+                    systems::issue_king_order,
+                    systems::deselect_dead_units,
+                    systems::clear_lost_focus_target,
+                    systems::spawn_selection_indicators,
+                    systems::update_selection_indicators,
+                    systems::update_focus_marker,
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    systems::apply_attack_moves,
+                    systems::apply_focus_fire,
+                    systems::apply_move_orders,
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running))
+                    .run_if(run_conditions::combat_started)
+                    .after(VelocitySystemSet)
+                    .before(MovementSystemSet),
+            )
+            .add_systems(OnExit(InGameState::Running), systems::cancel_box_selection)
+            .add_systems(OnEnter(AppState::InGame), systems::reset_focus_target)
+            .add_systems(OnExit(InGameState::GameOver), systems::reset_focus_target);
+    }
+}
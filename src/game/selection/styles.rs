@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+/// Fill color of the drag rectangle (translucent green).
+pub const SELECTION_BOX_COLOR: Color = Color::srgba(0.3, 0.9, 0.3, 0.15);
+
+/// Border color of the drag rectangle.
+pub const SELECTION_BOX_BORDER_COLOR: Color = Color::srgba(0.3, 0.9, 0.3, 0.8);
+
+/// Border width of the drag rectangle in pixels.
+pub const SELECTION_BOX_BORDER_WIDTH: f32 = 1.0;
+
+/// Color of the ring under selected units.
+pub const INDICATOR_COLOR: Color = Color::srgba(0.3, 0.9, 0.3, 0.8);
+
+/// Color of the ring around the focused unit.
+pub const FOCUS_MARKER_COLOR: Color = Color::srgba(1.0, 0.2, 0.2, 0.9);
@@ -0,0 +1,625 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::components::*;
+use super::constants::*;
+use super::styles::*;
+use crate::config::GameConfig;
+use crate::game::components::OnGameplayScreen;
+use crate::game::input::MouseButtonState;
+use crate::game::input::components::CursorWorldPosition;
+use crate::game::input::events::{BlockSpellInput, MouseRightPressed};
+use crate::game::units::archer::components::Archer;
+use crate::game::units::components::{Corpse, TargetingVelocity, Team, nearest_by_distance};
+use crate::game::units::infantry::components::Infantry;
+use crate::game::units::king::components::{King, KingOrder};
+use crate::game::units::king::constants::{KING_ORDER_DURATION, KING_ORDER_KEY};
+
+/// Blocks spell casting while the selection modifier is held or a drag is in progress.
+pub fn block_spell_input_while_selecting(
+    keys: Res<ButtonInput<KeyCode>>,
+    box_selection: Res<BoxSelection>,
+    mut block_spell_input: MessageWriter<BlockSpellInput>,
+) {
+    if shift_held(&keys) || box_selection.start.is_some() {
+        block_spell_input.write(BlockSpellInput);
+    }
+}
+
+/// Handles Shift + left-drag box selection of defenders.
+///
+/// Drags with the cast button, so it moves to the right button when the mouse
+/// binding is swapped. Press starts the drag rectangle, moving the cursor resizes it, and release
+/// selects every defender whose ground position projects inside the rectangle.
+/// Releasing without dragging (a click on empty space) clears the selection,
+/// as does a plain click on ground with no unit under the cursor.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn handle_box_selection(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut mouse_state: ResMut<MouseButtonState>,
+    mut box_selection: ResMut<BoxSelection>,
+    cursor_world: Res<CursorWorldPosition>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut box_query: Query<&mut Node, With<SelectionBoxNode>>,
+    buttons: Query<&Interaction, With<Button>>,
+    units: Query<
+        (Entity, &Transform, &Team, Has<Selected>),
+        (Or<(With<Infantry>, With<Archer>)>, Without<Corpse>),
+    >,
+) {
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+    let cursor = window.cursor_position();
+    let drag_button = config.mouse_binding.cast_button();
+
+    let Some(start) = box_selection.start else {
+        // Not dragging - start a new drag on Shift + left press
+        if shift_held(&keys)
+            && mouse.just_pressed(drag_button)
+            && let Some(cursor) = cursor
+        {
+            let box_entity = commands
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(cursor.x),
+                        top: Val::Px(cursor.y),
+                        width: Val::Px(0.0),
+                        height: Val::Px(0.0),
+                        border: UiRect::all(Val::Px(SELECTION_BOX_BORDER_WIDTH)),
+                        ..default()
+                    },
+                    BackgroundColor(SELECTION_BOX_COLOR),
+                    BorderColor::all(SELECTION_BOX_BORDER_COLOR),
+                    SelectionBoxNode,
+                    OnGameplayScreen,
+                ))
+                .id();
+
+            box_selection.start = Some(cursor);
+            box_selection.current = cursor;
+            box_selection.box_entity = Some(box_entity);
+            mouse_state.left_consumed = true; // Don't let this hold start a spell cast
+        } else if !shift_held(&keys)
+            && !ctrl_held(&keys)
+            && mouse.just_pressed(drag_button)
+            && !buttons
+                .iter()
+                .any(|interaction| *interaction != Interaction::None)
+            && let Some(point) = cursor_world.ground
+            && !units
+                .iter()
+                .any(|(_, transform, _, _)| ground_distance(transform, point) <= FOCUS_PICK_RADIUS)
+        {
+            // Plain click on empty ground
+            for (entity, _, _, is_selected) in &units {
+                if is_selected {
+                    commands.entity(entity).remove::<Selected>();
+                }
+            }
+        }
+        return;
+    };
+
+    if let Some(cursor) = cursor {
+        box_selection.current = cursor;
+    }
+    let min = start.min(box_selection.current);
+    let max = start.max(box_selection.current);
+
+    // Still dragging - resize the rectangle
+    if mouse.pressed(drag_button) {
+        if let Some(box_entity) = box_selection.box_entity
+            && let Ok(mut node) = box_query.get_mut(box_entity)
+        {
+            node.left = Val::Px(min.x);
+            node.top = Val::Px(min.y);
+            node.width = Val::Px(max.x - min.x);
+            node.height = Val::Px(max.y - min.y);
+        }
+        return;
+    }
+
+    // Released - replace the selection with the defenders inside the rectangle
+    let is_click = start.distance(box_selection.current) < MIN_DRAG_DISTANCE;
+    let camera = camera_query.single().ok();
+
+    for (entity, transform, team, is_selected) in &units {
+        let ground_pos = Vec3::new(transform.translation.x, 0.0, transform.translation.z);
+        let inside = !is_click
+            && *team == Team::Defenders
+            && camera.is_some_and(|(camera, camera_transform)| {
+                camera
+                    .world_to_viewport(camera_transform, ground_pos)
+                    .is_ok_and(|screen_pos| {
+                        screen_pos.cmpge(min).all() && screen_pos.cmple(max).all()
+                    })
+            });
+
+        if inside && !is_selected {
+            commands.entity(entity).insert(Selected);
+        } else if !inside && is_selected {
+            commands.entity(entity).remove::<Selected>();
+        }
+    }
+
+    if let Some(box_entity) = box_selection.box_entity.take() {
+        commands.entity(box_entity).despawn();
+    }
+    box_selection.start = None;
+}
+
+/// Issues a move order to all selected defenders on right-click.
+///
+/// Holding Ctrl issues an attack-move instead. Right-clicks on an enemy are
+/// focus orders (see `issue_focus_fire`), and right-clicks with the King order
+/// key held go to the King (see `issue_king_order`). A new order replaces the
+/// previous one.
+pub fn issue_move_orders(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut mouse_right_pressed: MessageReader<MouseRightPressed>,
+    cursor: Res<CursorWorldPosition>,
+    selected: Query<(Entity, &Team), (With<Selected>, Without<Corpse>)>,
+    units: Query<(Entity, &Transform, &Team), Without<Corpse>>,
+) {
+    if mouse_right_pressed.read().next().is_none()
+        || selected.is_empty()
+        || keys.pressed(KING_ORDER_KEY)
+    {
+        return;
+    }
+
+    let Some(target) = cursor.ground else {
+        return;
+    };
+    if enemy_under_cursor(target, &units).is_some() {
+        return;
+    }
+
+    let attack_move = ctrl_held(&keys);
+    for (entity, team) in &selected {
+        if *team != Team::Defenders {
+            continue;
+        }
+
+        if attack_move {
+            commands
+                .entity(entity)
+                .remove::<MoveOrder>()
+                .insert(AttackMove {
+                    destination: target,
+                });
+        } else {
+            commands
+                .entity(entity)
+                .remove::<AttackMove>()
+                .insert(MoveOrder::new(target, MOVE_ORDER_DURATION));
+        }
+    }
+}
+
+/// Focuses all defenders on the enemy under the cursor on right-click.
+///
+/// Attackers and undead are both valid targets. Right-clicking the focused
+/// enemy again clears the order.
+pub fn issue_focus_fire(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut mouse_right_pressed: MessageReader<MouseRightPressed>,
+    cursor: Res<CursorWorldPosition>,
+    units: Query<(Entity, &Transform, &Team), Without<Corpse>>,
+    mut focus: ResMut<FocusTarget>,
+) {
+    if mouse_right_pressed.read().next().is_none() || keys.pressed(KING_ORDER_KEY) {
+        return;
+    }
+
+    let Some(point) = cursor.ground else {
+        return;
+    };
+
+    if let Some(target) = enemy_under_cursor(point, &units) {
+        focus.toggle(target);
+    }
+}
+
+/// Orders the King to the cursor on right-click while the King order key is held.
+///
+/// The King walks there and holds until the order expires; ordering him onto
+/// his own position holds him in place. A new order replaces the previous one.
+pub fn issue_king_order(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut mouse_right_pressed: MessageReader<MouseRightPressed>,
+    cursor: Res<CursorWorldPosition>,
+    kings: Query<Entity, (With<King>, Without<Corpse>)>,
+) {
+    if mouse_right_pressed.read().next().is_none() || !keys.pressed(KING_ORDER_KEY) {
+        return;
+    }
+
+    let Some(target) = cursor.ground else {
+        return;
+    };
+    for king in &kings {
+        commands
+            .entity(king)
+            .insert(KingOrder::new(target, KING_ORDER_DURATION));
+    }
+}
+
+/// Clears the focus order once the focused unit has died or despawned.
+pub fn clear_lost_focus_target(
+    mut focus: ResMut<FocusTarget>,
+    living: Query<(), (With<Team>, Without<Corpse>)>,
+) {
+    if let Some(target) = focus.target
+        && !living.contains(target)
+    {
+        focus.target = None;
+    }
+}
+
+/// Clears the focus order when a new battle starts.
+pub fn reset_focus_target(mut focus: ResMut<FocusTarget>) {
+    focus.target = None;
+}
+
+/// Steers defender infantry toward the focused unit when it is within range.
+///
+/// Runs after the targeting systems and overwrites `TargetingVelocity`, like
+/// move orders do. Units with a move order follow the order instead.
+#[allow(clippy::type_complexity)]
+pub fn apply_focus_fire(
+    focus: Res<FocusTarget>,
+    mut defenders: Query<
+        (&Transform, &Team, &mut TargetingVelocity),
+        (With<Infantry>, Without<MoveOrder>, Without<Corpse>),
+    >,
+    targets: Query<&Transform, Without<Corpse>>,
+) {
+    let Some(target) = focus.target else {
+        return;
+    };
+    let Ok(target_transform) = targets.get(target) else {
+        return;
+    };
+
+    for (transform, team, mut targeting_velocity) in &mut defenders {
+        if focus.for_team(*team).is_none() {
+            continue;
+        }
+
+        let offset = (target_transform.translation - transform.translation).with_y(0.0);
+        let distance = offset.length();
+        if distance > FOCUS_FIRE_RANGE {
+            continue;
+        }
+
+        targeting_velocity.velocity = offset.normalize_or_zero();
+        targeting_velocity.distance_to_target = distance;
+    }
+}
+
+/// Advances attack-moving units on their destination between fights.
+///
+/// Runs after the targeting systems. While an enemy is within
+/// `ATTACK_MOVE_ENGAGE_RANGE`, the targeting velocity is left alone so the unit
+/// fights like normal; otherwise it is overwritten to head for the destination.
+pub fn apply_attack_moves(
+    mut commands: Commands,
+    mut units: Query<(Entity, &Transform, &AttackMove, &mut TargetingVelocity), Without<Corpse>>,
+) {
+    for (entity, transform, attack_move, mut targeting_velocity) in &mut units {
+        let diff = Vec3::new(
+            attack_move.destination.x - transform.translation.x,
+            0.0,
+            attack_move.destination.z - transform.translation.z,
+        );
+
+        if diff.length() <= MOVE_ORDER_ARRIVAL_RADIUS {
+            commands.entity(entity).remove::<AttackMove>();
+            continue;
+        }
+
+        if targeting_velocity.distance_to_target <= ATTACK_MOVE_ENGAGE_RANGE {
+            // Enemy at hand - let auto-targeting fight it
+            continue;
+        }
+
+        targeting_velocity.velocity = diff.normalize_or_zero();
+        targeting_velocity.distance_to_target = 0.0;
+    }
+}
+
+/// Steers units with a move order toward their order target.
+///
+/// Runs after the targeting systems and overwrites `TargetingVelocity`, so the
+/// movement systems follow the order instead of the nearest enemy. A zero
+/// `distance_to_target` gives the order full steering weight over flocking.
+pub fn apply_move_orders(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut units: Query<(Entity, &Transform, &mut MoveOrder, &mut TargetingVelocity), Without<Corpse>>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, transform, mut order, mut targeting_velocity) in &mut units {
+        let diff = Vec3::new(
+            order.target.x - transform.translation.x,
+            0.0,
+            order.target.z - transform.translation.z,
+        );
+
+        if order.tick(delta) || diff.length() <= MOVE_ORDER_ARRIVAL_RADIUS {
+            // Order finished - auto-targeting takes over next frame
+            commands.entity(entity).remove::<MoveOrder>();
+            continue;
+        }
+
+        targeting_velocity.velocity = diff.normalize_or_zero();
+        targeting_velocity.distance_to_target = 0.0;
+    }
+}
+
+/// Clears selection and move orders from units that have died.
+#[allow(clippy::type_complexity)]
+pub fn deselect_dead_units(
+    mut commands: Commands,
+    query: Query<
+        Entity,
+        (
+            Or<(With<Selected>, With<MoveOrder>, With<AttackMove>)>,
+            With<Corpse>,
+        ),
+    >,
+) {
+    for entity in &query {
+        commands
+            .entity(entity)
+            .remove::<(Selected, MoveOrder, AttackMove)>();
+    }
+}
+
+/// Spawns a ground ring under each newly selected unit.
+pub fn spawn_selection_indicators(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    new_selected: Query<(Entity, &Transform), Added<Selected>>,
+) {
+    for (entity, transform) in &new_selected {
+        commands.spawn((
+            Mesh3d(meshes.add(Annulus::new(INDICATOR_INNER_RADIUS, INDICATOR_OUTER_RADIUS))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: INDICATOR_COLOR,
+                unlit: true,
+                alpha_mode: AlphaMode::Blend,
+                ..default()
+            })),
+            Transform::from_xyz(
+                transform.translation.x,
+                INDICATOR_Y_POSITION,
+                transform.translation.z,
+            )
+            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+            SelectionIndicator { unit: entity },
+            OnGameplayScreen,
+        ));
+    }
+}
+
+/// Moves selection rings with their units and despawns rings for deselected units.
+pub fn update_selection_indicators(
+    mut commands: Commands,
+    mut indicators: Query<(Entity, &SelectionIndicator, &mut Transform)>,
+    units: Query<&Transform, (With<Selected>, Without<Corpse>, Without<SelectionIndicator>)>,
+) {
+    for (entity, indicator, mut transform) in &mut indicators {
+        if let Ok(unit_transform) = units.get(indicator.unit) {
+            transform.translation.x = unit_transform.translation.x;
+            transform.translation.z = unit_transform.translation.z;
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Keeps a ring around the focused unit, removing it when no focus order is active.
+pub fn update_focus_marker(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    focus: Res<FocusTarget>,
+    mut markers: Query<(Entity, &mut Transform), With<FocusMarker>>,
+    targets: Query<&Transform, Without<FocusMarker>>,
+) {
+    let Some(target_transform) = focus.target.and_then(|target| targets.get(target).ok()) else {
+        for (entity, _) in &markers {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    let position = Vec3::new(
+        target_transform.translation.x,
+        INDICATOR_Y_POSITION,
+        target_transform.translation.z,
+    );
+
+    if let Some((_, mut transform)) = markers.iter_mut().next() {
+        transform.translation = position;
+        return;
+    }
+
+    commands.spawn((
+        Mesh3d(meshes.add(Annulus::new(
+            FOCUS_MARKER_INNER_RADIUS,
+            FOCUS_MARKER_OUTER_RADIUS,
+        ))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: FOCUS_MARKER_COLOR,
+            unlit: true,
+            alpha_mode: AlphaMode::Blend,
+            ..default()
+        })),
+        Transform::from_translation(position)
+            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        FocusMarker,
+        OnGameplayScreen,
+    ));
+}
+
+/// Abandons any in-progress drag when leaving the Running state.
+pub fn cancel_box_selection(mut commands: Commands, mut box_selection: ResMut<BoxSelection>) {
+    if let Some(box_entity) = box_selection.box_entity.take() {
+        commands.entity(box_entity).despawn();
+    }
+    box_selection.start = None;
+}
+
+/// Returns true if either Shift key is held.
+fn shift_held(keys: &ButtonInput<KeyCode>) -> bool {
+    keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+}
+
+/// Returns true if either Ctrl key is held.
+fn ctrl_held(keys: &ButtonInput<KeyCode>) -> bool {
+    keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+}
+
+/// Returns the defenders' enemy nearest to a ground point, if one is within
+/// `FOCUS_PICK_RADIUS`.
+fn enemy_under_cursor(
+    point: Vec3,
+    units: &Query<(Entity, &Transform, &Team), Without<Corpse>>,
+) -> Option<Entity> {
+    nearest_by_distance(
+        units.iter().filter(|(_, transform, team)| {
+            **team != Team::Defenders && ground_distance(transform, point) <= FOCUS_PICK_RADIUS
+        }),
+        |(_, transform, _)| ground_distance(transform, point),
+    )
+    .map(|(entity, _, _)| entity)
+}
+
+/// Returns the distance from a unit to a ground point, ignoring height.
+fn ground_distance(transform: &Transform, point: Vec3) -> f32 {
+    Vec2::new(
+        transform.translation.x - point.x,
+        transform.translation.z - point.z,
+    )
+    .length()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::units::infantry::systems::update_infantry_targeting;
+
+    #[test]
+    fn test_defender_steers_toward_focus_target_over_closer_enemy() {
+        let mut app = App::new();
+        app.init_resource::<FocusTarget>().add_systems(
+            Update,
+            (update_infantry_targeting, apply_focus_fire).chain(),
+        );
+
+        let world = app.world_mut();
+        let defender = world
+            .spawn((
+                Transform::from_xyz(0.0, 0.0, 0.0),
+                Team::Defenders,
+                Infantry,
+                TargetingVelocity::default(),
+            ))
+            .id();
+        // A closer enemy to the east, the focused one further away to the north
+        world.spawn((Transform::from_xyz(100.0, 0.0, 0.0), Team::Attackers));
+        let focused = world
+            .spawn((Transform::from_xyz(0.0, 0.0, -300.0), Team::Attackers))
+            .id();
+
+        app.update();
+        let velocity = app.world().get::<TargetingVelocity>(defender).unwrap();
+        assert!(velocity.velocity.x > 0.9, "without focus: nearest enemy");
+
+        app.world_mut().resource_mut::<FocusTarget>().target = Some(focused);
+        app.update();
+        let velocity = app.world().get::<TargetingVelocity>(defender).unwrap();
+        assert!(velocity.velocity.z < -0.9, "with focus: focused enemy");
+        assert_eq!(velocity.distance_to_target, 300.0);
+    }
+
+    #[test]
+    fn test_attack_move_fights_nearby_enemy_then_resumes() {
+        let mut app = App::new();
+        app.add_systems(
+            Update,
+            (update_infantry_targeting, apply_attack_moves).chain(),
+        );
+
+        let world = app.world_mut();
+        let defender = world
+            .spawn((
+                Transform::from_xyz(0.0, 0.0, 0.0),
+                Team::Defenders,
+                Infantry,
+                TargetingVelocity::default(),
+                AttackMove {
+                    destination: Vec3::new(1000.0, 0.0, 0.0),
+                },
+            ))
+            .id();
+        // An enemy just to the north, off the line to the destination in the east
+        let enemy = world
+            .spawn((Transform::from_xyz(0.0, 0.0, -100.0), Team::Attackers))
+            .id();
+
+        app.update();
+        let velocity = app.world().get::<TargetingVelocity>(defender).unwrap();
+        assert!(velocity.velocity.z < -0.9, "diverts to the nearby enemy");
+
+        app.world_mut().entity_mut(enemy).insert(Corpse);
+        app.update();
+        let velocity = app.world().get::<TargetingVelocity>(defender).unwrap();
+        assert!(velocity.velocity.x > 0.9, "resumes toward the destination");
+
+        app.world_mut()
+            .get_mut::<Transform>(defender)
+            .unwrap()
+            .translation = Vec3::new(1000.0, 0.0, 0.0);
+        app.update();
+        assert!(app.world().get::<AttackMove>(defender).is_none());
+    }
+
+    #[test]
+    fn test_right_click_on_undead_focuses_them() {
+        let mut app = App::new();
+        app.init_resource::<FocusTarget>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_message::<MouseRightPressed>()
+            .insert_resource(CursorWorldPosition {
+                ray: None,
+                ground: Some(Vec3::new(200.0, 0.0, 0.0)),
+            })
+            .add_systems(Update, issue_focus_fire);
+
+        let world = app.world_mut();
+        world.spawn((Transform::from_xyz(0.0, 0.0, 0.0), Team::Defenders));
+        let undead = world
+            .spawn((Transform::from_xyz(200.0, 0.0, 0.0), Team::Undead))
+            .id();
+        world.write_message(MouseRightPressed {
+            cursor_position: None,
+        });
+
+        app.update();
+        assert_eq!(app.world().resource::<FocusTarget>().target, Some(undead));
+    }
+}
@@ -1,16 +1,25 @@
 use bevy::prelude::*;
 
-use crate::config::GameConfig;
+use crate::config::{CorpseMode, GameConfig};
 
+use super::batch_commands::{despawn_batch, remove_batch};
+use super::battlefield::components::CastleFootprint;
 use super::components::{Acceleration, Velocity};
 use super::constants::*;
 use super::plugin::GlobalAttackCycle;
-use super::resources::CurrentLevel;
+use super::resources::{BattleTimer, CurrentLevel, MaxEntities, ProjectileCount, TunableConstants};
+use super::selection::components::FocusTarget;
+use super::units::archer::components::Arrow;
 use super::units::components::{
-    AttackTiming, Corpse, DamageMultiplier, Effectiveness, Health, Hitbox, MovementSpeed,
-    RoughTerrain, RoughTerrainModifier, Team, TemporaryHitPoints, apply_damage_to_unit,
+    AttackTiming, Berserk, Corpse, CorpseAge, DamageDealt, DamageMultiplier, DamageSource,
+    DamageType, Effectiveness, Health, Hitbox, ManaDrain, MovementSpeed, PermanentCorpse,
+    PositionHistory, Resistances, RoughTerrain, RoughTerrainModifier, Team, TemporaryHitPoints,
+    Vanishing, apply_damage_and_report, nearest_by_distance,
 };
+use super::units::constants::{BERSERK_DAMAGE_PERCENTAGE, BERSERK_HEALTH_THRESHOLD};
 use super::units::king::components::KingSpawned;
+use super::units::wizard::components::{Mana, Wizard};
+use super::units::wizard::spells::MagicMissile;
 
 /// Advances the global attack cycle timer each game frame.
 ///
@@ -20,6 +29,25 @@ pub fn tick_attack_cycle(time: Res<Time>, mut attack_cycle: ResMut<GlobalAttackC
     attack_cycle.tick(time.delta_secs());
 }
 
+/// Advances the battle timer while combat is underway.
+pub fn tick_battle_timer(time: Res<Time>, mut battle_timer: ResMut<BattleTimer>) {
+    battle_timer.tick(time.delta_secs());
+}
+
+/// Freezes game time when gameplay stops running (pause menu, spell book, game over).
+///
+/// Systems that tick from `Time` see a zero delta until time is resumed, so
+/// buffs, cast progress, and projectiles hold still even if a system is not
+/// gated on `InGameState::Running`.
+pub fn pause_game_time(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
+
+/// Resumes game time when gameplay runs again or the player leaves the game.
+pub fn resume_game_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
+
 /// Initializes the current level from saved config.
 ///
 /// This system runs on OnEnter(AppState::InGame) to restore the player's
@@ -39,7 +67,10 @@ pub fn init_level_from_config(mut current_level: ResMut<CurrentLevel>, config: R
 /// units that fight together while penalizing isolated units.
 pub fn calculate_effectiveness(
     mut units: Query<(Entity, &Transform, &Hitbox, &Team, &mut Effectiveness), Without<Corpse>>,
+    tunables: Option<Res<TunableConstants>>,
 ) {
+    let tuning = TunableConstants::or_default(tunables);
+
     // Collect snapshot for symmetric calculations
     let unit_data: Vec<_> = units
         .iter()
@@ -81,7 +112,7 @@ pub fn calculate_effectiveness(
             }
         }
 
-        effectiveness.recalculate(ally_count, enemy_count);
+        effectiveness.recalculate(ally_count, enemy_count, &tuning);
     }
 }
 
@@ -103,7 +134,10 @@ pub fn apply_separation(
         ),
         Without<Corpse>,
     >,
+    tunables: Option<Res<TunableConstants>>,
 ) {
+    let tuning = TunableConstants::or_default(tunables);
+
     // Flocking parameters are defined in constants.rs
 
     // Collect all unit data for comparison
@@ -220,13 +254,15 @@ pub fn apply_separation(
 
         if separation_count > 0 {
             separation /= separation_count as f32;
-            combined_direction += separation.normalize_or_zero() * SEPARATION_STRENGTH * sep_mult;
+            combined_direction +=
+                separation.normalize_or_zero() * tuning.separation_strength * sep_mult;
         }
 
         if neighbor_count > 0 {
             // Alignment direction
             alignment /= neighbor_count as f32;
-            combined_direction += alignment.normalize_or_zero() * ALIGNMENT_STRENGTH * align_mult;
+            combined_direction +=
+                alignment.normalize_or_zero() * tuning.alignment_strength * align_mult;
 
             // Cohesion direction (XZ plane only)
             cohesion /= neighbor_count as f32;
@@ -242,7 +278,7 @@ pub fn apply_separation(
             let cohesion_factor = (distance_to_center / NEIGHBOR_DISTANCE).min(1.0);
 
             combined_direction += cohesion_direction.normalize_or_zero()
-                * COHESION_STRENGTH
+                * tuning.cohesion_strength
                 * cohesion_factor
                 * coh_mult;
         }
@@ -252,6 +288,76 @@ pub fn apply_separation(
     }
 }
 
+/// Detects units jittering in place in a tight pack and nudges them free.
+///
+/// Units with at least `JITTER_MIN_NEIGHBORS` overlapping neighbors keep a short
+/// position history. If one barely moves over its whole history, it is nudged
+/// a small distance in a direction seeded by its entity index. Units stacked
+/// exactly on top of each other get no push from collision resolution, and
+/// the differing directions break that symmetry.
+pub fn unstick_jittering_units(
+    mut commands: Commands,
+    mut units: Query<
+        (
+            Entity,
+            &mut Transform,
+            &Hitbox,
+            Option<&mut PositionHistory>,
+        ),
+        Without<Corpse>,
+    >,
+) {
+    let positions: Vec<_> = units
+        .iter()
+        .map(|(entity, transform, hitbox, _)| (entity, transform.translation, hitbox.radius))
+        .collect();
+
+    for (entity, mut transform, hitbox, history) in &mut units {
+        let neighbors = positions
+            .iter()
+            .filter(|(other, other_pos, other_radius)| {
+                *other != entity
+                    && transform
+                        .translation
+                        .with_y(0.0)
+                        .distance(other_pos.with_y(0.0))
+                        < hitbox.radius + other_radius
+            })
+            .count();
+
+        if neighbors < JITTER_MIN_NEIGHBORS {
+            if history.is_some() {
+                commands.entity(entity).remove::<PositionHistory>();
+            }
+            continue;
+        }
+
+        let Some(mut history) = history else {
+            commands.entity(entity).insert(PositionHistory::default());
+            continue;
+        };
+
+        history.record(transform.translation);
+        if history
+            .spread()
+            .is_some_and(|spread| spread < JITTER_STILL_DISTANCE)
+        {
+            let nudge = jitter_nudge(entity);
+            transform.translation.x += nudge.x;
+            transform.translation.z += nudge.z;
+            history.clear();
+        }
+    }
+}
+
+/// Returns a deterministic nudge for a stuck unit, spread by the golden angle
+/// so neighboring entity indices push in well-separated directions.
+fn jitter_nudge(entity: Entity) -> Vec3 {
+    const GOLDEN_ANGLE: f32 = 2.399_963;
+    let angle = entity.index() as f32 * GOLDEN_ANGLE;
+    Vec3::new(angle.cos(), 0.0, angle.sin()) * JITTER_NUDGE_DISTANCE
+}
+
 /// Applies movement slowdown to units standing on rough terrain (corpses).
 ///
 /// Units walking over corpses have their movement speed temporarily reduced.
@@ -297,8 +403,37 @@ pub fn apply_rough_terrain_slowdown(
     }
 }
 
+/// Units below `BERSERK_HEALTH_THRESHOLD` of their health go berserk.
+///
+/// Adds `Berserk` as a unit drops under the threshold and removes it once the
+/// unit is healed back above it. Turning the setting off calms every unit.
+pub fn update_berserk(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    units: Query<(Entity, &Health, Has<Berserk>), Without<Corpse>>,
+) {
+    for (entity, health, is_berserk) in &units {
+        let enraged = config.berserk
+            && health.max > 0.0
+            && health.current / health.max < BERSERK_HEALTH_THRESHOLD;
+
+        if enraged && !is_berserk {
+            commands
+                .entity(entity)
+                .insert(Berserk(BERSERK_DAMAGE_PERCENTAGE));
+        } else if !enraged && is_berserk {
+            commands.entity(entity).remove::<Berserk>();
+        }
+    }
+}
+
+/// Units strike the nearest enemy in reach on their slot in the attack cycle.
+///
+/// Attacker damage is scaled by the difficulty setting; everyone else hits at base damage.
 pub fn combat(
     attack_cycle: Res<GlobalAttackCycle>,
+    focus: Res<FocusTarget>,
+    config: Res<GameConfig>,
     mut all_units: Query<(
         Entity,
         &Transform,
@@ -307,8 +442,14 @@ pub fn combat(
         &mut AttackTiming,
         &Effectiveness,
         Option<&DamageMultiplier>,
+        Option<&Berserk>,
     )>,
-    mut health_query: Query<(&mut Health, Option<&mut TemporaryHitPoints>)>,
+    mut health_query: Query<(
+        &mut Health,
+        Option<&mut TemporaryHitPoints>,
+        Option<&Resistances>,
+    )>,
+    mut damage_dealt: MessageWriter<DamageDealt>,
 ) {
     let current_time = attack_cycle.current_time;
     let last_time = (current_time - APPROX_FRAME_TIME).max(0.0);
@@ -316,7 +457,7 @@ pub fn combat(
     // Collect snapshot of all units for enemy detection
     let units_snapshot: Vec<_> = all_units
         .iter()
-        .map(|(entity, transform, hitbox, team, _, _, _)| {
+        .map(|(entity, transform, hitbox, team, _, _, _, _)| {
             (entity, transform.translation, *hitbox, *team)
         })
         .collect();
@@ -330,71 +471,155 @@ pub fn combat(
         mut attack_timing,
         effectiveness,
         damage_mult,
+        berserk,
     ) in &mut all_units
     {
-        // Find nearest enemy within attack range
-        if let Some((target_entity, _, _)) = units_snapshot
-            .iter()
-            .filter(|(entity, _, _, team)| {
-                // Skip self and apply team-based targeting logic
-                *entity != attacker_entity
-                    && match (attacker_team, team) {
-                        // Undead don't attack each other
-                        (Team::Undead, Team::Undead) => false,
-                        // Undead attack living
-                        (Team::Undead, _) => true,
-                        // Living attack undead
-                        (_, Team::Undead) => true,
-                        // Normal team logic
-                        _ => *team != *attacker_team,
+        // Enemies within attack range
+        let in_range = || {
+            units_snapshot
+                .iter()
+                .filter(|(entity, _, _, team)| {
+                    // Skip self and apply team-based targeting logic
+                    *entity != attacker_entity
+                        && match (attacker_team, team) {
+                            // Undead don't attack each other
+                            (Team::Undead, Team::Undead) => false,
+                            // Undead attack living
+                            (Team::Undead, _) => true,
+                            // Living attack undead
+                            (_, Team::Undead) => true,
+                            // Normal team logic
+                            _ => *team != *attacker_team,
+                        }
+                })
+                .filter_map(|(entity, target_pos, target_hitbox, _)| {
+                    let distance = attacker_transform.translation.distance(*target_pos);
+                    let attack_range =
+                        (attacker_hitbox.radius + target_hitbox.radius) * ATTACK_RANGE_MULTIPLIER;
+                    if distance <= attack_range {
+                        Some((entity, target_pos, distance))
+                    } else {
+                        None
                     }
-            })
-            .filter_map(|(entity, target_pos, target_hitbox, _)| {
-                let distance = attacker_transform.translation.distance(*target_pos);
-                let attack_range =
-                    (attacker_hitbox.radius + target_hitbox.radius) * ATTACK_RANGE_MULTIPLIER;
-                if distance <= attack_range {
-                    Some((entity, target_pos, distance))
-                } else {
-                    None
-                }
-            })
-            .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap())
-        {
+                })
+        };
+
+        // Strike the focused unit if it is in reach, otherwise the nearest enemy
+        let target = focus
+            .for_team(*attacker_team)
+            .and_then(|focused| in_range().find(|(entity, _, _)| **entity == focused))
+            .or_else(|| nearest_by_distance(in_range(), |(_, _, distance)| *distance));
+
+        if let Some((target_entity, target_pos, _)) = target {
             // Attack if we're in the unit's attack window
             if attack_timing.can_attack(current_time, last_time)
-                && let Ok((mut target_health, mut temp_hp)) = health_query.get_mut(*target_entity)
+                && let Ok((mut target_health, mut temp_hp, resistances)) =
+                    health_query.get_mut(*target_entity)
             {
                 // Apply effectiveness and damage percentage
-                // DamageMultiplier stores percentage bonus (0.5 = +50%, 1.0 = +100%)
+                // DamageMultiplier and Berserk store percentage bonuses (0.5 = +50%, 1.0 = +100%)
                 // Convert to multiplier: damage * (1.0 + percentage)
-                let damage_percentage = damage_mult.map_or(0.0, |d| d.0);
+                let damage_percentage =
+                    damage_mult.map_or(0.0, |d| d.0) + berserk.map_or(0.0, |b| b.0);
                 let damage_multiplier = 1.0 + damage_percentage;
-                let modified_damage =
-                    ATTACK_DAMAGE * effectiveness.multiplier() * damage_multiplier;
-                apply_damage_to_unit(&mut target_health, temp_hp.as_deref_mut(), modified_damage);
+                let difficulty_multiplier = if *attacker_team == Team::Attackers {
+                    config.difficulty.damage_multiplier()
+                } else {
+                    1.0
+                };
+                let modified_damage = ATTACK_DAMAGE
+                    * effectiveness.multiplier()
+                    * damage_multiplier
+                    * difficulty_multiplier;
+                apply_damage_and_report(
+                    &mut target_health,
+                    temp_hp.as_deref_mut(),
+                    resistances,
+                    modified_damage,
+                    DamageType::Physical,
+                    DamageSource::Unit,
+                    *target_entity,
+                    *target_pos,
+                    &mut damage_dealt,
+                );
                 attack_timing.record_attack(current_time);
             }
         }
     }
 }
 
+/// Recounts the magic missiles and arrows in flight for the projectile cap.
+pub fn count_projectiles(
+    mut count: ResMut<ProjectileCount>,
+    missiles: Query<(), With<MagicMissile>>,
+    arrows: Query<(), With<Arrow>>,
+) {
+    count.missiles = missiles.iter().len();
+    count.arrows = arrows.iter().len();
+}
+
+/// Units with `ManaDrain` burn the wizard's mana on their slot in the attack cycle.
+///
+/// A hit lands when the unit is within `MANA_DRAIN_REACH` of the wizard on the
+/// ground plane. It takes mana only; the wizard's health is untouched. Runs after
+/// `combat`, so a drainer also fighting a defender strikes both on the same beat.
+pub fn drain_wizard_mana(
+    attack_cycle: Res<GlobalAttackCycle>,
+    mut wizards: Query<(&Transform, &mut Mana), With<Wizard>>,
+    mut drainers: Query<(&Transform, &ManaDrain, &mut AttackTiming), Without<Corpse>>,
+) {
+    let Ok((wizard_transform, mut mana)) = wizards.single_mut() else {
+        return;
+    };
+    let wizard_pos = wizard_transform.translation;
+    let current_time = attack_cycle.current_time;
+    let last_time = (current_time - APPROX_FRAME_TIME).max(0.0);
+
+    for (transform, drain, mut attack_timing) in &mut drainers {
+        let distance = Vec2::new(
+            transform.translation.x - wizard_pos.x,
+            transform.translation.z - wizard_pos.z,
+        )
+        .length();
+
+        if distance <= MANA_DRAIN_REACH && attack_timing.can_attack(current_time, last_time) {
+            mana.current = (mana.current - drain.amount).max(0.0);
+            attack_timing.record_attack(current_time);
+        }
+    }
+}
+
 /// Converts dead units to corpses instead of despawning them.
 ///
 /// When a unit's health reaches zero, this system grays out the sprite based on team
 /// and converts the unit into a corpse that slows living units walking over it.
-/// Also records the kill in the kill statistics resource.
+/// With `CorpseMode::None` the unit is marked `Vanishing` instead and leaves nothing
+/// behind. Also records the kill in the kill statistics resource.
+#[allow(clippy::too_many_arguments)]
 pub fn convert_dead_to_corpses(
+    time: Res<Time>,
+    config: Res<GameConfig>,
     mut commands: Commands,
     mut kill_stats: ResMut<super::resources::KillStats>,
-    query: Query<(Entity, &Health, &Team, &Transform), Without<Corpse>>,
+    query: Query<(Entity, &Health, &Team, &Transform), (Without<Corpse>, Without<Vanishing>)>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     material_query: Query<&MeshMaterial3d<StandardMaterial>>,
 ) {
+    // Collected so a mass kill queues a few batched commands instead of ~10 per unit
+    let mut corpses = Vec::new();
+    let mut permanent_corpses = Vec::new();
+    let mut vanishing = Vec::new();
+
     for (entity, health, team, transform) in &query {
         if health.is_dead() {
             // Record the kill
             kill_stats.record_kill(*team);
+
+            if config.corpse_mode == CorpseMode::None {
+                vanishing.push((entity, Vanishing));
+                continue;
+            }
+
             // Get existing material handle and gray out the sprite based on team
             if let Ok(material_handle) = material_query.get(entity)
                 && let Some(material) = materials.get_mut(&material_handle.0)
@@ -413,28 +638,135 @@ pub fn convert_dead_to_corpses(
                     .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2));
 
             // Add corpse marker and rough terrain effect
-            let mut entity_commands = commands.entity(entity);
-            entity_commands
-                .insert(Corpse)
-                .insert(corpse_transform)
-                .insert(RoughTerrain {
-                    slowdown_factor: 0.4,
-                }); // 60% speed reduction
+            corpses.push((
+                entity,
+                (
+                    Corpse,
+                    corpse_transform,
+                    RoughTerrain {
+                        slowdown_factor: 0.4,
+                    }, // 60% speed reduction
+                    CorpseAge {
+                        died_at: time.elapsed_secs(),
+                    },
+                ),
+            ));
 
             // Mark undead corpses as permanent (cannot be resurrected)
             if *team == Team::Undead {
-                entity_commands.insert(super::units::components::PermanentCorpse);
+                permanent_corpses.push((entity, super::units::components::PermanentCorpse));
             }
+        }
+    }
+
+    if corpses.is_empty() && vanishing.is_empty() {
+        return;
+    }
+
+    let dead: Vec<Entity> = corpses
+        .iter()
+        .map(|(entity, _)| *entity)
+        .chain(vanishing.iter().map(|(entity, _)| *entity))
+        .collect();
+    if !corpses.is_empty() {
+        commands.insert_batch(corpses);
+    }
+    if !permanent_corpses.is_empty() {
+        commands.insert_batch(permanent_corpses);
+    }
+    if !vanishing.is_empty() {
+        commands.insert_batch(vanishing);
+    }
+    commands.queue(remove_batch::<(
+        Velocity,                           // Stop moving
+        Acceleration,                       // No forces
+        MovementSpeed,                      // Can't move
+        AttackTiming,                       // Can't attack
+        Hitbox,                             // Remove collision
+        crate::game::components::Billboard, // Remove billboard so corpse stays flat
+    )>(dead));
+}
+
+/// Despawns units that died while corpses are turned off.
+///
+/// Runs at the end of the frame (see `Vanishing`).
+pub fn despawn_vanishing_units(mut commands: Commands, query: Query<Entity, With<Vanishing>>) {
+    let vanished: Vec<Entity> = query.iter().collect();
+    if !vanished.is_empty() {
+        commands.queue(despawn_batch(vanished));
+    }
+}
+
+/// Fades out corpses and despawns them once `GameConfig::corpse_decay_time` has passed.
+///
+/// Only runs its course with `CorpseMode::Decay`. Corpses spend their last
+/// `CORPSE_FADE_DURATION` seconds fading to transparent.
+pub fn decay_corpses(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut commands: Commands,
+    corpses: Query<
+        (
+            Entity,
+            &CorpseAge,
+            Option<&MeshMaterial3d<StandardMaterial>>,
+        ),
+        With<Corpse>,
+    >,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if config.corpse_mode != CorpseMode::Decay {
+        return;
+    }
+
+    let now = time.elapsed_secs();
+    let mut decayed = Vec::new();
 
-            entity_commands
-                .remove::<Velocity>() // Stop moving
-                .remove::<Acceleration>() // No forces
-                .remove::<MovementSpeed>() // Can't move
-                .remove::<AttackTiming>() // Can't attack
-                .remove::<Hitbox>() // Remove collision
-                .remove::<crate::game::components::Billboard>(); // Remove billboard so corpse stays flat
+    for (entity, age, material_handle) in &corpses {
+        let remaining = config.corpse_decay_time - (now - age.died_at);
+        if remaining <= 0.0 {
+            decayed.push(entity);
+        } else if remaining < CORPSE_FADE_DURATION
+            && let Some(material_handle) = material_handle
+            && let Some(material) = materials.get_mut(&material_handle.0)
+        {
+            material.alpha_mode = AlphaMode::Blend;
+            material
+                .base_color
+                .set_alpha(remaining / CORPSE_FADE_DURATION);
         }
     }
+
+    if !decayed.is_empty() {
+        commands.queue(despawn_batch(decayed));
+    }
+}
+
+/// Despawns the oldest corpses while living units plus corpses exceed the entity budget.
+///
+/// Permanent (undead) corpses are never cleared, and living units are left to
+/// the spawn throttle.
+pub fn despawn_oldest_corpses(
+    mut commands: Commands,
+    budget: Res<MaxEntities>,
+    living: Query<(), (With<Team>, Without<Corpse>)>,
+    corpses: Query<(Entity, &CorpseAge, Has<PermanentCorpse>), With<Corpse>>,
+) {
+    let excess = budget.excess(living.iter().len() + corpses.iter().len());
+    if excess == 0 {
+        return;
+    }
+
+    let mut clearable: Vec<(Entity, f32)> = corpses
+        .iter()
+        .filter(|(_, _, permanent)| !permanent)
+        .map(|(entity, age, _)| (entity, age.died_at))
+        .collect();
+    clearable.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    for (entity, _) in clearable.into_iter().take(excess) {
+        commands.entity(entity).despawn();
+    }
 }
 
 /// Cleans up all game entities when exiting the InGame state.
@@ -529,6 +861,28 @@ pub fn enforce_wall_collision(
     }
 }
 
+/// Pushes hostile units out of the castle platform footprint.
+///
+/// Runs after movement systems alongside wall collision so attackers and undead
+/// path around the castle. Defenders, including the King, muster underneath the
+/// platform, and the wizard has no team, so neither is pushed.
+pub fn enforce_castle_collision(
+    castles: Query<&CastleFootprint>,
+    mut units: Query<(&mut Transform, &Hitbox, &Team), Without<Corpse>>,
+) {
+    for (mut transform, hitbox, team) in &mut units {
+        if *team == Team::Defenders {
+            continue;
+        }
+        for castle in &castles {
+            if let Some(corrected) = castle.push_out(transform.translation, hitbox.radius) {
+                transform.translation.x = corrected.x;
+                transform.translation.z = corrected.z;
+            }
+        }
+    }
+}
+
 /// Resets game resources when replaying (transitioning from GameOver to Running).
 ///
 /// This system runs on OnExit(InGameState::GameOver) and resets resources like
@@ -542,3 +896,284 @@ pub fn reset_resources_for_replay(
     defenders_activated.active = false;
     king_spawned.0 = false;
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::config::Difficulty;
+    use crate::game::resources::KillStats;
+    use crate::game::units::components::FlockingVelocity;
+
+    #[test]
+    fn test_over_budget_clears_oldest_corpses_first() {
+        let mut app = App::new();
+        app.insert_resource(MaxEntities { limit: 3 })
+            .add_systems(Update, despawn_oldest_corpses);
+
+        let world = app.world_mut();
+        let living = world.spawn(Team::Attackers).id();
+        let corpse = |world: &mut World, died_at: f32| {
+            world
+                .spawn((Team::Defenders, Corpse, CorpseAge { died_at }))
+                .id()
+        };
+        let newest = corpse(world, 30.0);
+        let oldest = corpse(world, 10.0);
+        let older = corpse(world, 20.0);
+        let permanent = world
+            .spawn((
+                Team::Undead,
+                Corpse,
+                PermanentCorpse,
+                CorpseAge { died_at: 0.0 },
+            ))
+            .id();
+
+        // Five entities against a budget of three: the two oldest plain corpses go
+        app.update();
+
+        let world = app.world();
+        assert!(world.get_entity(oldest).is_err());
+        assert!(world.get_entity(older).is_err());
+        assert!(world.get_entity(newest).is_ok());
+        assert!(world.get_entity(living).is_ok());
+        assert!(world.get_entity(permanent).is_ok());
+    }
+
+    /// Builds an app that kills one attacker under `corpse_mode` and runs the
+    /// death handling systems once.
+    fn kill_unit_with_corpse_mode(corpse_mode: CorpseMode) -> (App, Entity) {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            corpse_mode,
+            ..default()
+        })
+        .init_resource::<Time>()
+        .init_resource::<KillStats>()
+        .init_resource::<Assets<StandardMaterial>>()
+        .add_systems(
+            Update,
+            (
+                convert_dead_to_corpses,
+                decay_corpses,
+                despawn_vanishing_units,
+            )
+                .chain(),
+        );
+
+        let mut health = Health::new(10.0);
+        health.take_damage(10.0);
+        let unit = app
+            .world_mut()
+            .spawn((Team::Attackers, health, Transform::default()))
+            .id();
+        app.update();
+        (app, unit)
+    }
+
+    fn attackers_killed(app: &App) -> u32 {
+        app.world().resource::<KillStats>().attackers_killed
+    }
+
+    #[test]
+    fn test_persistent_mode_leaves_a_corpse() {
+        let (mut app, unit) = kill_unit_with_corpse_mode(CorpseMode::Persistent);
+
+        assert!(app.world().get::<Corpse>(unit).is_some());
+        assert!(app.world().get::<RoughTerrain>(unit).is_some());
+        assert_eq!(attackers_killed(&app), 1);
+
+        // Long after death the corpse is still there
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(600));
+        app.update();
+        assert!(app.world().get_entity(unit).is_ok());
+        assert_eq!(attackers_killed(&app), 1);
+    }
+
+    #[test]
+    fn test_decay_mode_removes_corpse_after_decay_time() {
+        let (mut app, unit) = kill_unit_with_corpse_mode(CorpseMode::Decay);
+        assert!(app.world().get::<Corpse>(unit).is_some());
+
+        let decay_time = app.world().resource::<GameConfig>().corpse_decay_time;
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(decay_time - 1.0));
+        app.update();
+        assert!(app.world().get_entity(unit).is_ok());
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(1.0));
+        app.update();
+        assert!(app.world().get_entity(unit).is_err());
+        assert_eq!(attackers_killed(&app), 1);
+    }
+
+    #[test]
+    fn test_none_mode_despawns_without_a_corpse() {
+        let (app, unit) = kill_unit_with_corpse_mode(CorpseMode::None);
+
+        assert!(app.world().get_entity(unit).is_err());
+        assert_eq!(attackers_killed(&app), 1);
+    }
+
+    #[test]
+    fn test_stacked_units_separate_within_bounded_frames() {
+        let mut app = App::new();
+        app.add_systems(Update, (apply_separation, unstick_jittering_units).chain());
+
+        // Four units stacked on one spot get no push from collision resolution alone
+        let units: Vec<_> = (0..4)
+            .map(|_| {
+                app.world_mut()
+                    .spawn((
+                        Transform::from_xyz(100.0, 0.0, 100.0),
+                        Velocity::default(),
+                        FlockingVelocity::default(),
+                        Hitbox::new(10.0, 40.0),
+                    ))
+                    .id()
+            })
+            .collect();
+
+        for _ in 0..JITTER_HISTORY_FRAMES * 3 {
+            app.update();
+        }
+
+        let positions: Vec<_> = units
+            .iter()
+            .map(|unit| app.world().get::<Transform>(*unit).unwrap().translation)
+            .collect();
+        for (i, a) in positions.iter().enumerate() {
+            for b in &positions[i + 1..] {
+                assert!(a.distance(*b) > 10.0, "{a} and {b} are still stuck");
+            }
+        }
+    }
+
+    /// Returns the damage an attacker and a defender deal each other in one exchange.
+    fn exchange_damage(difficulty: Difficulty) -> (f32, f32) {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            difficulty,
+            ..default()
+        })
+        .init_resource::<GlobalAttackCycle>()
+        .init_resource::<FocusTarget>()
+        .add_message::<DamageDealt>()
+        .add_systems(Update, combat);
+
+        let unit = |team| {
+            (
+                Transform::default(),
+                Hitbox::new(10.0, 40.0),
+                team,
+                AttackTiming::default(),
+                Effectiveness::new(),
+                Health::new(1000.0),
+            )
+        };
+        let attacker = app.world_mut().spawn(unit(Team::Attackers)).id();
+        let defender = app.world_mut().spawn(unit(Team::Defenders)).id();
+
+        app.update();
+
+        let damage_taken = |entity| 1000.0 - app.world().get::<Health>(entity).unwrap().current;
+        (damage_taken(defender), damage_taken(attacker))
+    }
+
+    #[test]
+    fn test_hard_attackers_hit_harder_than_easy() {
+        let (easy, easy_defender) = exchange_damage(Difficulty::Easy);
+        let (normal, _) = exchange_damage(Difficulty::Normal);
+        let (hard, hard_defender) = exchange_damage(Difficulty::Hard);
+
+        assert!((normal - ATTACK_DAMAGE).abs() < 1e-3);
+        assert!(hard > normal && normal > easy);
+        // Defenders hit the same on every difficulty
+        assert_eq!(easy_defender, hard_defender);
+    }
+
+    #[test]
+    fn test_dispeller_drains_mana_without_hurting_wizard() {
+        let mut app = App::new();
+        app.insert_resource(GameConfig::default())
+            .init_resource::<GlobalAttackCycle>()
+            .init_resource::<FocusTarget>()
+            .add_message::<DamageDealt>()
+            .add_systems(Update, (combat, drain_wizard_mana).chain());
+
+        let wizard = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(WIZARD_POSITION),
+                Hitbox::new(10.0, 40.0),
+                Health::new(100.0),
+                Mana::new(100.0, 0.0),
+                Wizard::new(1000.0),
+            ))
+            .id();
+        let dispeller = |offset: f32| {
+            (
+                Transform::from_translation(WIZARD_POSITION.with_y(0.0) + Vec3::X * offset),
+                Hitbox::new(8.0, 40.0),
+                Team::Attackers,
+                AttackTiming::default(),
+                Effectiveness::new(),
+                Health::new(40.0),
+                ManaDrain { amount: 15.0 },
+            )
+        };
+        app.world_mut().spawn(dispeller(MANA_DRAIN_REACH * 0.5));
+        // Out of reach: no drain
+        app.world_mut().spawn(dispeller(MANA_DRAIN_REACH * 2.0));
+
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.get::<Mana>(wizard).unwrap().current, 85.0);
+        assert_eq!(world.get::<Health>(wizard).unwrap().current, 100.0);
+    }
+
+    #[test]
+    fn test_units_near_death_go_berserk_until_healed() {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            berserk: true,
+            ..default()
+        })
+        .add_systems(Update, update_berserk);
+
+        let mut health = Health::new(100.0);
+        health.take_damage(100.0 * (1.0 - BERSERK_HEALTH_THRESHOLD) + 1.0);
+        let unit = app.world_mut().spawn((Team::Defenders, health)).id();
+        let healthy = app
+            .world_mut()
+            .spawn((Team::Attackers, Health::new(100.0)))
+            .id();
+
+        app.update();
+        let bonus = |app: &App, entity| app.world().get::<Berserk>(entity).map(|b| b.0);
+        assert_eq!(bonus(&app, unit), Some(BERSERK_DAMAGE_PERCENTAGE));
+        assert_eq!(bonus(&app, healthy), None);
+
+        // Healing back above the threshold calms the unit
+        app.world_mut().get_mut::<Health>(unit).unwrap().heal(40.0);
+        app.update();
+        assert_eq!(bonus(&app, unit), None);
+
+        // With the setting off nobody goes berserk
+        app.world_mut()
+            .get_mut::<Health>(unit)
+            .unwrap()
+            .take_damage(50.0);
+        app.world_mut().resource_mut::<GameConfig>().berserk = false;
+        app.update();
+        assert_eq!(bonus(&app, unit), None);
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::constants::*;
+
+/// Gameplay milestones that have an audio cue.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameEvent {
+    /// The preparation countdown ended and the attackers began their advance.
+    AttackersAdvance,
+    /// The last living attacker on the field was killed.
+    AttackersWiped,
+    /// The King started fighting in melee.
+    KingInMelee,
+    /// A cast was rejected for missing mana or range.
+    CastFizzled,
+}
+
+impl GameEvent {
+    /// Returns the (frequency in Hz, duration in seconds) of the cue's tone.
+    pub const fn tone(self) -> (f32, f32) {
+        match self {
+            GameEvent::AttackersAdvance => (HORN_FREQUENCY, HORN_DURATION),
+            GameEvent::AttackersWiped => (CHEER_FREQUENCY, CHEER_DURATION),
+            GameEvent::KingInMelee => (KING_MELEE_FREQUENCY, KING_MELEE_DURATION),
+            GameEvent::CastFizzled => (FIZZLE_FREQUENCY, FIZZLE_DURATION),
+        }
+    }
+}
+
+/// Sound effects played at a point on the battlefield.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sfx {
+    /// A fireball exploded.
+    FireballExplosion,
+}
+
+impl Sfx {
+    /// Returns the (frequency in Hz, duration in seconds) of the effect's tone.
+    pub const fn tone(self) -> (f32, f32) {
+        match self {
+            Sfx::FireballExplosion => (FIREBALL_EXPLOSION_FREQUENCY, FIREBALL_EXPLOSION_DURATION),
+        }
+    }
+}
+
+/// Message requesting a sound effect at a world position.
+///
+/// Volume falls off with distance from the camera focus (see `distance_gain`).
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct PlaySfx {
+    pub sfx: Sfx,
+    pub position: Vec3,
+}
+
+/// Returns the gain (0.0 to 1.0) for a sound `distance` away from the camera focus.
+///
+/// Full volume inside `SFX_FULL_VOLUME_RADIUS`, then a linear falloff to
+/// silence at `SFX_MAX_AUDIBLE_RADIUS`.
+pub fn distance_gain(distance: f32) -> f32 {
+    let falloff =
+        (distance - SFX_FULL_VOLUME_RADIUS) / (SFX_MAX_AUDIBLE_RADIUS - SFX_FULL_VOLUME_RADIUS);
+    (1.0 - falloff).clamp(0.0, 1.0)
+}
+
+/// Remembers when each cue last played so rapid events don't stack into noise.
+#[derive(Resource, Default)]
+pub struct CueDebounce {
+    /// Time (in seconds) each cue last played.
+    last_played: HashMap<GameEvent, f32>,
+}
+
+impl CueDebounce {
+    /// Returns true and records the play if the cue hasn't played within the debounce window.
+    pub fn try_play(&mut self, event: GameEvent, now: f32) -> bool {
+        if self
+            .last_played
+            .get(&event)
+            .is_some_and(|last| now - last < CUE_DEBOUNCE_SECONDS)
+        {
+            return false;
+        }
+
+        self.last_played.insert(event, now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rapid_repeats_are_debounced() {
+        let mut debounce = CueDebounce::default();
+
+        assert!(debounce.try_play(GameEvent::KingInMelee, 10.0));
+        assert!(!debounce.try_play(GameEvent::KingInMelee, 10.5));
+        assert!(!debounce.try_play(GameEvent::KingInMelee, 11.9));
+        assert!(debounce.try_play(GameEvent::KingInMelee, 12.0));
+    }
+
+    #[test]
+    fn test_distance_gain_falls_off_to_silence() {
+        let midpoint = (SFX_FULL_VOLUME_RADIUS + SFX_MAX_AUDIBLE_RADIUS) / 2.0;
+
+        assert_eq!(distance_gain(0.0), 1.0);
+        assert_eq!(distance_gain(SFX_FULL_VOLUME_RADIUS), 1.0);
+        assert!((distance_gain(midpoint) - 0.5).abs() < 1e-5);
+        assert_eq!(distance_gain(SFX_MAX_AUDIBLE_RADIUS), 0.0);
+        assert_eq!(distance_gain(SFX_MAX_AUDIBLE_RADIUS * 2.0), 0.0);
+        assert!(distance_gain(midpoint - 100.0) > distance_gain(midpoint + 100.0));
+    }
+
+    #[test]
+    fn test_different_cues_debounce_independently() {
+        let mut debounce = CueDebounce::default();
+
+        assert!(debounce.try_play(GameEvent::AttackersWiped, 5.0));
+        assert!(debounce.try_play(GameEvent::CastFizzled, 5.0));
+    }
+}
@@ -0,0 +1,30 @@
+//! Sound cue tones and timing constants.
+
+/// Minimum seconds between two plays of the same cue.
+pub const CUE_DEBOUNCE_SECONDS: f32 = 2.0;
+
+/// Distance from the camera focus within which positional SFX play at full volume.
+pub const SFX_FULL_VOLUME_RADIUS: f32 = 400.0;
+
+/// Distance from the camera focus beyond which positional SFX are silent.
+pub const SFX_MAX_AUDIBLE_RADIUS: f32 = 3000.0;
+
+/// Low horn when the attackers start advancing.
+pub const HORN_FREQUENCY: f32 = 110.0;
+pub const HORN_DURATION: f32 = 1.2;
+
+/// Bright cheer when the last attacker on the field falls.
+pub const CHEER_FREQUENCY: f32 = 880.0;
+pub const CHEER_DURATION: f32 = 0.5;
+
+/// Ominous drone when the King enters melee.
+pub const KING_MELEE_FREQUENCY: f32 = 65.0;
+pub const KING_MELEE_DURATION: f32 = 0.9;
+
+/// Short buzz when a cast fizzles for missing mana or range.
+pub const FIZZLE_FREQUENCY: f32 = 150.0;
+pub const FIZZLE_DURATION: f32 = 0.15;
+
+/// Low thud when a fireball explodes.
+pub const FIREBALL_EXPLOSION_FREQUENCY: f32 = 80.0;
+pub const FIREBALL_EXPLOSION_DURATION: f32 = 0.4;
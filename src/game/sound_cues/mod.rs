@@ -0,0 +1,13 @@
+//! Sound cues module.
+//!
+//! Watches the battle for milestones (attackers advancing, the last attacker
+//! falling, the King entering melee, a cast fizzling) and plays a short tone
+//! for each, so off-screen action still gets audio feedback.
+//! Positional sound effects (`PlaySfx`) fade with distance from the camera focus.
+
+pub mod components;
+mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::SoundCuesPlugin;
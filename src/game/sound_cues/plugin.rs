@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::state::InGameState;
+
+use super::components::{CueDebounce, GameEvent, PlaySfx};
+use super::systems;
+
+/// Plugin that plays audio cues for battle milestones.
+///
+/// Registers systems for:
+/// - Detecting milestones and sending them as `GameEvent` messages
+/// - Playing a debounced tone for each event
+/// - Playing positional sound effects with distance falloff
+pub struct SoundCuesPlugin;
+
+impl Plugin for SoundCuesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<GameEvent>()
+            .add_message::<PlaySfx>()
+            .init_resource::<CueDebounce>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        systems::detect_attackers_advance,
+                        systems::detect_attackers_wiped,
+                        systems::detect_king_melee,
+                        systems::detect_cast_rejected,
+                    ),
+                    (systems::play_sound_cues, systems::play_positional_sfx),
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running)),
+            );
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bevy::audio::{Pitch, Volume};
+use bevy::prelude::*;
+
+use super::components::*;
+use crate::config::GameConfig;
+use crate::game::campaign::components::CampaignState;
+use crate::game::components::OnGameplayScreen;
+use crate::game::units::components::{Corpse, InMelee, Team};
+use crate::game::units::king::components::King;
+use crate::game::units::wizard::components::CastRejected;
+
+/// Sends `AttackersAdvance` when a wave's preparation countdown ends.
+pub fn detect_attackers_advance(
+    campaign: Res<CampaignState>,
+    mut was_preparing: Local<bool>,
+    mut game_events: MessageWriter<GameEvent>,
+) {
+    let preparing = campaign.in_preparation();
+    if *was_preparing && !preparing {
+        game_events.write(GameEvent::AttackersAdvance);
+    }
+    *was_preparing = preparing;
+}
+
+/// Sends `AttackersWiped` when the last living attacker on the field dies.
+pub fn detect_attackers_wiped(
+    units: Query<&Team, Without<Corpse>>,
+    mut had_attackers: Local<bool>,
+    mut game_events: MessageWriter<GameEvent>,
+) {
+    let has_attackers = units.iter().any(|team| *team == Team::Attackers);
+    if *had_attackers && !has_attackers {
+        game_events.write(GameEvent::AttackersWiped);
+    }
+    *had_attackers = has_attackers;
+}
+
+/// Sends `KingInMelee` when the King starts a melee fight.
+pub fn detect_king_melee(
+    kings: Query<(), (With<King>, Added<InMelee>)>,
+    mut game_events: MessageWriter<GameEvent>,
+) {
+    if !kings.is_empty() {
+        game_events.write(GameEvent::KingInMelee);
+    }
+}
+
+/// Sends `CastFizzled` when a cast is rejected.
+pub fn detect_cast_rejected(
+    mut rejected: MessageReader<CastRejected>,
+    mut game_events: MessageWriter<GameEvent>,
+) {
+    if rejected.read().next().is_some() {
+        game_events.write(GameEvent::CastFizzled);
+    }
+}
+
+/// Plays a tone for each game event, skipping cues that played too recently.
+///
+/// Volume follows the master and sound effects settings.
+pub fn play_sound_cues(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut game_events: MessageReader<GameEvent>,
+    mut debounce: ResMut<CueDebounce>,
+    mut pitches: ResMut<Assets<Pitch>>,
+    mut tones: Local<HashMap<GameEvent, Handle<Pitch>>>,
+) {
+    let volume = Volume::Linear(config.master_volume * config.sfx_volume);
+
+    for event in game_events.read() {
+        if !debounce.try_play(*event, time.elapsed_secs()) {
+            continue;
+        }
+
+        let tone = tones.entry(*event).or_insert_with(|| {
+            let (frequency, duration) = event.tone();
+            pitches.add(Pitch::new(frequency, Duration::from_secs_f32(duration)))
+        });
+        commands.spawn((
+            AudioPlayer(tone.clone()),
+            PlaybackSettings::DESPAWN.with_volume(volume),
+            OnGameplayScreen,
+        ));
+    }
+}
+
+/// Plays positional sound effects, quieter the farther they are from the camera focus.
+///
+/// The focus is where the camera's view direction meets the ground. Distance gain
+/// is applied before the master and sound effects settings; inaudible effects are
+/// skipped entirely.
+pub fn play_positional_sfx(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    mut sfx_messages: MessageReader<PlaySfx>,
+    camera_query: Query<&GlobalTransform, With<Camera3d>>,
+    mut pitches: ResMut<Assets<Pitch>>,
+    mut tones: Local<HashMap<Sfx, Handle<Pitch>>>,
+) {
+    let Ok(camera_transform) = camera_query.single() else {
+        sfx_messages.clear();
+        return;
+    };
+    let focus = camera_focus(camera_transform);
+
+    for message in sfx_messages.read() {
+        let gain = distance_gain(focus.distance(message.position));
+        if gain <= 0.0 {
+            continue;
+        }
+
+        let tone = tones.entry(message.sfx).or_insert_with(|| {
+            let (frequency, duration) = message.sfx.tone();
+            pitches.add(Pitch::new(frequency, Duration::from_secs_f32(duration)))
+        });
+        commands.spawn((
+            AudioPlayer(tone.clone()),
+            PlaybackSettings::DESPAWN.with_volume(Volume::Linear(
+                gain * config.master_volume * config.sfx_volume,
+            )),
+            OnGameplayScreen,
+        ));
+    }
+}
+
+/// Returns the point on the ground (Y=0) the camera is looking at.
+///
+/// Falls back to the camera position when it looks at or above the horizon.
+fn camera_focus(camera_transform: &GlobalTransform) -> Vec3 {
+    let origin = camera_transform.translation();
+    let forward = camera_transform.forward();
+
+    if forward.y < 0.0 {
+        origin + forward * (-origin.y / forward.y)
+    } else {
+        origin
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Heard(Vec<GameEvent>);
+
+    fn record_events(mut game_events: MessageReader<GameEvent>, mut heard: ResMut<Heard>) {
+        heard.0.extend(game_events.read().copied());
+    }
+
+    #[test]
+    fn test_cheer_when_last_attacker_dies() {
+        let mut app = App::new();
+        app.add_message::<GameEvent>()
+            .init_resource::<Heard>()
+            .add_systems(Update, (detect_attackers_wiped, record_events).chain());
+
+        let first = app.world_mut().spawn(Team::Attackers).id();
+        let second = app.world_mut().spawn(Team::Attackers).id();
+        app.world_mut().spawn(Team::Defenders);
+        app.update();
+
+        app.world_mut().entity_mut(first).insert(Corpse);
+        app.update();
+        assert!(app.world().resource::<Heard>().0.is_empty());
+
+        app.world_mut().entity_mut(second).insert(Corpse);
+        app.update();
+        assert_eq!(
+            app.world().resource::<Heard>().0,
+            [GameEvent::AttackersWiped]
+        );
+
+        // No cheer again while the field stays clear
+        app.update();
+        assert_eq!(app.world().resource::<Heard>().0.len(), 1);
+    }
+}
@@ -1,14 +1,26 @@
 use bevy::prelude::*;
 
-use super::components::Billboard;
+use super::components::{BaseScale, Billboard};
+use crate::config::{GameConfig, RenderStyle};
+use crate::game::units::components::Team;
 
 /// Updates billboard entities to always face the camera.
 ///
 /// Rotates entities with the Billboard component around the Y axis so they remain
 /// perpendicular to the camera's forward direction on the XZ plane.
+/// With the top-down render style, units lie flat on the ground like corpses
+/// instead; other billboards (such as the wizard) still face the camera.
+/// Units are also scaled by the unit size setting, on top of their own
+/// `BaseScale`; only the sprite grows, the `Hitbox` used by combat and flocking
+/// keeps its size.
+/// Runs every frame, so changing the style applies to units already on the field.
 pub fn update_billboards(
+    config: Res<GameConfig>,
     camera_query: Query<&Transform, With<Camera3d>>,
-    mut billboard_query: Query<&mut Transform, (With<Billboard>, Without<Camera3d>)>,
+    mut billboard_query: Query<
+        (&mut Transform, Has<Team>, Option<&BaseScale>),
+        (With<Billboard>, Without<Camera3d>),
+    >,
 ) {
     let Ok(camera_transform) = camera_query.single() else {
         return;
@@ -21,10 +33,136 @@ pub fn update_billboards(
     // Calculate rotation to face camera (rotate around Y axis)
     // We want the billboard's local -Z axis to point toward the camera
     let rotation = Quat::from_rotation_arc(Vec3::NEG_Z, camera_forward_xz);
+    // Rotate -90 degrees around X axis to face upward
+    let flat_rotation = Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2);
 
     // Apply rotation to all billboards
-    for mut transform in &mut billboard_query {
-        // Keep the existing position and scale, only update rotation
-        transform.rotation = rotation;
+    for (mut transform, is_unit, base_scale) in &mut billboard_query {
+        if is_unit {
+            let base_scale = base_scale.map_or(Vec3::ONE, |base| base.0);
+            transform.scale = base_scale * config.unit_render_scale;
+        }
+
+        // Keep the existing position, only update rotation
+        transform.rotation = if is_unit && config.render_style == RenderStyle::TopDown {
+            flat_rotation
+        } else {
+            rotation
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::units::components::Hitbox;
+
+    fn billboard_rotations(render_style: RenderStyle) -> (Quat, Quat) {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            render_style,
+            ..Default::default()
+        })
+        .add_systems(Update, update_billboards);
+
+        app.world_mut().spawn((
+            Camera3d::default(),
+            Transform::from_xyz(0.0, 500.0, 500.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ));
+        let unit = app
+            .world_mut()
+            .spawn((Billboard, Team::Defenders, Transform::default()))
+            .id();
+        let wizard = app
+            .world_mut()
+            .spawn((Billboard, Transform::default()))
+            .id();
+
+        app.update();
+
+        let rotation = |entity| app.world().get::<Transform>(entity).unwrap().rotation;
+        (rotation(unit), rotation(wizard))
+    }
+
+    #[test]
+    fn test_top_down_lays_units_flat() {
+        let flat = Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2);
+
+        let (unit, wizard) = billboard_rotations(RenderStyle::TopDown);
+        assert!(unit.abs_diff_eq(flat, 1e-5));
+        assert!(wizard.angle_between(flat) > 1.0);
+
+        let (unit, wizard) = billboard_rotations(RenderStyle::Billboard);
+        assert!(unit.abs_diff_eq(wizard, 1e-5));
+        assert!(unit.angle_between(flat) > 1.0);
+    }
+
+    #[test]
+    fn test_unit_render_scale_leaves_hitbox_alone() {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            unit_render_scale: 2.0,
+            ..Default::default()
+        })
+        .add_systems(Update, update_billboards);
+
+        app.world_mut().spawn((
+            Camera3d::default(),
+            Transform::from_xyz(0.0, 500.0, 500.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ));
+        let hitbox = Hitbox::new(10.0, 25.0);
+        let unit = app
+            .world_mut()
+            .spawn((Billboard, Team::Defenders, hitbox, Transform::default()))
+            .id();
+        let wizard = app
+            .world_mut()
+            .spawn((Billboard, Transform::default()))
+            .id();
+
+        app.update();
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<Transform>(unit).unwrap().scale,
+            Vec3::splat(2.0)
+        );
+        assert_eq!(world.get::<Transform>(wizard).unwrap().scale, Vec3::ONE);
+        // Combat and flocking ranges come from the hitbox, which keeps its size
+        let unit_hitbox = world.get::<Hitbox>(unit).unwrap();
+        assert_eq!(unit_hitbox.radius, hitbox.radius);
+        assert_eq!(unit_hitbox.height, hitbox.height);
+    }
+
+    #[test]
+    fn test_unit_render_scale_keeps_base_scale() {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            unit_render_scale: 2.0,
+            ..Default::default()
+        })
+        .add_systems(Update, update_billboards);
+
+        app.world_mut().spawn((
+            Camera3d::default(),
+            Transform::from_xyz(0.0, 500.0, 500.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ));
+        let unit = app
+            .world_mut()
+            .spawn((
+                Billboard,
+                Team::Defenders,
+                BaseScale(Vec3::splat(1.5)),
+                Transform::from_scale(Vec3::splat(1.5)),
+            ))
+            .id();
+
+        app.update();
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Transform>(unit).unwrap().scale,
+            Vec3::splat(3.0)
+        );
     }
 }
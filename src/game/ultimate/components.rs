@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+use super::constants::KILLS_TO_CHARGE;
+
+/// Charge toward the wizard's ultimate, filled by spell kills.
+#[derive(Resource, Default)]
+pub struct UltimateCharge {
+    /// Enemies killed by spells since the ultimate was last used.
+    pub kills: u32,
+}
+
+impl UltimateCharge {
+    /// Credits spell kills, stopping once the charge is full.
+    pub fn add_kills(&mut self, kills: u32) {
+        self.kills = (self.kills + kills).min(KILLS_TO_CHARGE);
+    }
+
+    /// Returns true if the ultimate can be used.
+    pub fn is_full(&self) -> bool {
+        self.kills >= KILLS_TO_CHARGE
+    }
+
+    /// Returns the charge as a fraction (0.0 to 1.0).
+    pub fn fraction(&self) -> f32 {
+        self.kills as f32 / KILLS_TO_CHARGE as f32
+    }
+
+    /// Empties the charge after the ultimate is used or for a new game.
+    pub fn reset(&mut self) {
+        self.kills = 0;
+    }
+}
+
+/// Visual lightning strike from the ultimate's storm.
+#[derive(Component)]
+pub struct LightningStrike {
+    /// Time remaining before the strike despawns.
+    pub lifetime: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_charge_accumulates_until_full() {
+        let mut charge = UltimateCharge::default();
+
+        charge.add_kills(KILLS_TO_CHARGE - 1);
+        assert!(!charge.is_full());
+        assert!(charge.fraction() < 1.0);
+
+        // Kills past a full charge are not banked
+        charge.add_kills(3);
+        assert_eq!(charge.kills, KILLS_TO_CHARGE);
+        assert!(charge.is_full());
+        assert_eq!(charge.fraction(), 1.0);
+    }
+
+    #[test]
+    fn test_reset_empties_charge() {
+        let mut charge = UltimateCharge::default();
+        charge.add_kills(KILLS_TO_CHARGE);
+
+        charge.reset();
+        assert_eq!(charge.kills, 0);
+        assert_eq!(charge.fraction(), 0.0);
+    }
+}
@@ -0,0 +1,24 @@
+//! Ultimate constants.
+
+use bevy::prelude::*;
+
+/// Key that unleashes the lightning storm once the charge is full.
+pub const ULTIMATE_KEY: KeyCode = KeyCode::KeyT;
+
+/// Spell kills needed to fill the ultimate charge.
+pub const KILLS_TO_CHARGE: u32 = 25;
+
+/// Damage each lightning strike deals.
+pub const STORM_DAMAGE: f32 = 150.0;
+
+/// Height the lightning strikes fall from.
+pub const STRIKE_HEIGHT: f32 = 400.0;
+
+/// Width of a lightning strike visual.
+pub const STRIKE_WIDTH: f32 = 10.0;
+
+/// How long a lightning strike visual stays on screen (in seconds).
+pub const STRIKE_LIFETIME: f32 = 0.4;
+
+/// Lightning strike color.
+pub const STRIKE_COLOR: Color = Color::srgb(0.85, 0.9, 1.0);
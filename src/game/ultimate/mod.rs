@@ -0,0 +1,11 @@
+//! Ultimate module.
+//!
+//! Spell kills fill an ultimate charge. Once it is full, the player can call
+//! down a lightning storm that strikes every enemy on the battlefield.
+
+pub mod components;
+mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::UltimatePlugin;
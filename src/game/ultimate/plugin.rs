@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+use crate::game::run_conditions;
+use crate::state::{AppState, InGameState};
+
+use super::components::UltimateCharge;
+use super::systems;
+
+/// Plugin that handles the wizard's ultimate.
+///
+/// Registers systems for:
+/// - Filling the charge from spell kills
+/// - Unleashing the lightning storm with a hotkey once the charge is full
+/// - Fading out the storm's lightning strikes
+/// - Resetting the charge for each new game
+pub struct UltimatePlugin;
+
+impl Plugin for UltimatePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UltimateCharge>()
+            .add_systems(
+                Update,
+                (
+                    systems::charge_from_spell_kills,
+                    systems::unleash_lightning_storm.run_if(run_conditions::combat_started),
+                    systems::update_lightning_strikes,
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running)),
+            )
+            .add_systems(OnEnter(AppState::InGame), systems::reset_ultimate_charge)
+            .add_systems(
+                OnExit(InGameState::GameOver),
+                systems::reset_ultimate_charge,
+            );
+    }
+}
@@ -0,0 +1,199 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::*;
+use crate::game::components::{Billboard, OnGameplayScreen};
+use crate::game::units::components::{
+    Corpse, DamageDealt, DamageSource, DamageType, Health, Resistances, Team, TemporaryHitPoints,
+    apply_damage_and_report,
+};
+
+/// Fills the ultimate charge for every enemy a spell kills.
+///
+/// Enemies are attackers and undead, the same units the wizard's projectiles
+/// target. Kills by units or by the ultimate itself don't count.
+pub fn charge_from_spell_kills(
+    mut damage_dealt: MessageReader<DamageDealt>,
+    mut charge: ResMut<UltimateCharge>,
+    teams: Query<&Team>,
+) {
+    let kills = damage_dealt
+        .read()
+        .filter(|hit| hit.lethal && matches!(hit.source, DamageSource::Spell(_)))
+        .filter(|hit| {
+            teams
+                .get(hit.target)
+                .is_ok_and(|team| matches!(team, Team::Attackers | Team::Undead))
+        })
+        .count();
+
+    if kills > 0 {
+        charge.add_kills(kills as u32);
+    }
+}
+
+/// Strikes every enemy on the battlefield with lightning when the ultimate key
+/// is pressed and the charge is full, then empties the charge.
+#[allow(clippy::type_complexity)]
+pub fn unleash_lightning_storm(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut charge: ResMut<UltimateCharge>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut targets: Query<
+        (
+            Entity,
+            &Transform,
+            &Team,
+            &mut Health,
+            Option<&mut TemporaryHitPoints>,
+            Option<&Resistances>,
+        ),
+        Without<Corpse>,
+    >,
+    mut damage_dealt: MessageWriter<DamageDealt>,
+) {
+    if !keyboard.just_pressed(ULTIMATE_KEY) || !charge.is_full() {
+        return;
+    }
+
+    // Every strike shares one mesh and material
+    let mesh = meshes.add(Rectangle::new(STRIKE_WIDTH, STRIKE_HEIGHT));
+    let material = materials.add(StandardMaterial {
+        base_color: STRIKE_COLOR,
+        unlit: true,
+        ..default()
+    });
+
+    let mut strikes = Vec::new();
+    for (entity, transform, team, mut health, mut temp_hp, resistances) in &mut targets {
+        if !matches!(team, Team::Attackers | Team::Undead) {
+            continue;
+        }
+
+        apply_damage_and_report(
+            &mut health,
+            temp_hp.as_deref_mut(),
+            resistances,
+            STORM_DAMAGE,
+            DamageType::Magic,
+            DamageSource::Ultimate,
+            entity,
+            transform.translation,
+            &mut damage_dealt,
+        );
+
+        let position = transform.translation;
+        strikes.push((
+            LightningStrike {
+                lifetime: STRIKE_LIFETIME,
+            },
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_xyz(position.x, STRIKE_HEIGHT / 2.0, position.z),
+            Billboard,
+            OnGameplayScreen,
+        ));
+    }
+    commands.spawn_batch(strikes);
+
+    charge.reset();
+}
+
+/// Thins out lightning strikes over their lifetime and despawns them.
+pub fn update_lightning_strikes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut strikes: Query<(Entity, &mut LightningStrike, &mut Transform)>,
+) {
+    for (entity, mut strike, mut transform) in &mut strikes {
+        strike.lifetime -= time.delta_secs();
+        if strike.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+        } else {
+            transform.scale.x = strike.lifetime / STRIKE_LIFETIME;
+        }
+    }
+}
+
+/// Empties the ultimate charge for a new game.
+pub fn reset_ultimate_charge(mut charge: ResMut<UltimateCharge>) {
+    charge.reset();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::units::wizard::components::Spell;
+
+    fn hit(target: Entity, source: DamageSource, lethal: bool) -> DamageDealt {
+        DamageDealt {
+            target,
+            position: Vec3::ZERO,
+            amount: 10.0,
+            damage_type: DamageType::Magic,
+            source,
+            lethal,
+        }
+    }
+
+    #[test]
+    fn test_only_spell_kills_of_enemies_charge_the_ultimate() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<DamageDealt>()
+            .init_resource::<UltimateCharge>()
+            .add_systems(Update, charge_from_spell_kills);
+
+        let attacker = app.world_mut().spawn(Team::Attackers).id();
+        let undead = app.world_mut().spawn(Team::Undead).id();
+        let defender = app.world_mut().spawn(Team::Defenders).id();
+
+        app.world_mut().write_message_batch([
+            hit(attacker, DamageSource::Spell(Spell::Fireball), true),
+            hit(undead, DamageSource::Spell(Spell::Fireball), true),
+            // Not kills, not spells, or not enemies
+            hit(attacker, DamageSource::Spell(Spell::Fireball), false),
+            hit(attacker, DamageSource::Unit, true),
+            hit(attacker, DamageSource::Ultimate, true),
+            hit(defender, DamageSource::Spell(Spell::Fireball), true),
+        ]);
+        app.update();
+
+        assert_eq!(app.world().resource::<UltimateCharge>().kills, 2);
+    }
+
+    #[test]
+    fn test_storm_strikes_enemies_and_resets_charge() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<DamageDealt>()
+            .init_resource::<UltimateCharge>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_systems(Update, unleash_lightning_storm);
+
+        let attacker = app
+            .world_mut()
+            .spawn((Team::Attackers, Transform::default(), Health::new(100.0)))
+            .id();
+        let defender = app
+            .world_mut()
+            .spawn((Team::Defenders, Transform::default(), Health::new(100.0)))
+            .id();
+
+        app.world_mut()
+            .resource_mut::<UltimateCharge>()
+            .add_kills(KILLS_TO_CHARGE);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(ULTIMATE_KEY);
+        app.update();
+
+        assert!(app.world().get::<Health>(attacker).unwrap().is_dead());
+        assert!(!app.world().get::<Health>(defender).unwrap().is_dead());
+        assert_eq!(app.world().resource::<UltimateCharge>().kills, 0);
+    }
+}
@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+use super::constants::{
+    ARCHER_ATTACK_DELAY_AFTER_MOVEMENT, ARROW_ACCURACY_RAMP_TIME, ARROW_MIN_SPREAD_FRACTION,
+};
 use crate::game::units::components::Team;
 
 /// Marker component for archer units.
@@ -52,4 +55,16 @@ impl ArcherMovementTimer {
     pub fn can_attack(&self, required_delay: f32) -> bool {
         self.time_since_stopped >= required_delay
     }
+
+    /// Returns the multiplier on arrow power and angle variation.
+    ///
+    /// Starts at 1.0 once the archer may fire and eases down to
+    /// `ARROW_MIN_SPREAD_FRACTION` over `ARROW_ACCURACY_RAMP_TIME` seconds of
+    /// standing still, so entrenched archers shoot tighter volleys.
+    pub fn spread_multiplier(&self) -> f32 {
+        let steady = ((self.time_since_stopped - ARCHER_ATTACK_DELAY_AFTER_MOVEMENT)
+            / ARROW_ACCURACY_RAMP_TIME)
+            .clamp(0.0, 1.0);
+        1.0 - steady * (1.0 - ARROW_MIN_SPREAD_FRACTION)
+    }
 }
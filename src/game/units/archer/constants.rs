@@ -18,5 +18,9 @@ pub const ARROW_WIDTH: f32 = 4.0; // Visual radius (circle)
 pub const ARROW_POWER_VARIATION: f32 = 0.05; // ±5% power variation
 pub const ARROW_ANGLE_VARIATION_DEGREES: f32 = 1.0; // ±1 degree angle variation
 
+// Accuracy ramp (archers that hold position steady their aim)
+pub const ARROW_ACCURACY_RAMP_TIME: f32 = 5.0; // Seconds after the attack delay to reach full accuracy
+pub const ARROW_MIN_SPREAD_FRACTION: f32 = 0.25; // Fraction of the variation left at full accuracy
+
 // Spawn counts (for initial testing)
 pub const INITIAL_ARCHER_DEFENDER_COUNT: u32 = 20;
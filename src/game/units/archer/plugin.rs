@@ -12,31 +12,34 @@ impl Plugin for ArcherPlugin {
             OnEnter(AppState::InGame),
             (
                 spawn_initial_defender_archers,
-                spawn_initial_attacker_archers,
+                spawn_initial_attacker_archers
+                    .run_if(run_conditions::in_campaign_mode)
+                    .run_if(not(run_conditions::has_defined_wave)),
             ),
         )
         .add_systems(
             OnEnter(InGameState::Running),
             (
                 spawn_initial_defender_archers,
-                spawn_initial_attacker_archers,
+                spawn_initial_attacker_archers
+                    .run_if(run_conditions::in_campaign_mode)
+                    .run_if(not(run_conditions::has_defined_wave)),
             )
                 .run_if(run_conditions::coming_from_game_over),
         )
         .add_systems(
-            Update,
+            FixedUpdate,
             update_archer_targeting.in_set(crate::game::plugin::VelocitySystemSet),
         )
         .add_systems(
-            Update,
+            FixedUpdate,
             archer_movement.in_set(crate::game::plugin::MovementSystemSet),
         )
         .add_systems(
-            Update,
+            FixedUpdate,
             (
                 update_archer_movement_timers,
-                archer_melee_combat,
-                archer_ranged_combat,
+                (archer_melee_combat, archer_ranged_combat).run_if(run_conditions::combat_started),
                 move_arrows,
                 check_arrow_collisions,
             )
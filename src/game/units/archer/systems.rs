@@ -4,19 +4,26 @@ use rand::Rng;
 use super::components::*;
 use super::constants::*;
 use super::styles::*;
+use crate::config::GameConfig;
+use crate::game::batch_commands::despawn_batch;
+use crate::game::battlefield::components::BattlefieldConfig;
 use crate::game::components::{Acceleration, Billboard, OnGameplayScreen, Velocity};
 use crate::game::constants::{
-    calculate_grid_cell_position, calculate_spawn_cells, calculate_total_archers,
-    calculate_total_infantry, cells_needed, distribute_units_to_cells, *,
+    calculate_spawn_cells, calculate_total_archers, calculate_total_infantry, cells_needed,
+    distribute_units_to_cells, *,
 };
 use crate::game::plugin::GlobalAttackCycle;
-use crate::game::resources::CurrentLevel;
+use crate::game::resources::{CurrentLevel, ProjectileCount, TunableConstants};
+use crate::game::selection::components::FocusTarget;
 use crate::game::units::components::{
-    AttackTiming, Corpse, Effectiveness, FlockingModifier, FlockingVelocity, Health, Hitbox,
-    KingAuraSpeedModifier, MovementSpeed, RoughTerrainModifier, TargetingVelocity, Team,
-    Teleportable, TemporaryHitPoints, apply_damage_to_unit,
+    AttackTiming, Berserk, Corpse, DamageDealt, DamageSource, DamageType, Effectiveness,
+    FlockingModifier, FlockingVelocity, Health, Hitbox, KingAuraSpeedModifier, MovementSpeed,
+    PermanentCorpse, Resistances, RoughTerrainModifier, Snare, SpawnAnimation, TargetingVelocity,
+    Team, Teleportable, TemporaryHitPoints, apply_damage_and_report, movement_speed_multiplier,
+    nearest_by_distance, spawn_attack_offset,
 };
 use crate::game::units::wizard::spells::wall_of_stone::components::WallOfStone;
+use crate::game::wind::components::Wind;
 
 /// Spawns initial defender archers when entering the game.
 /// Archers spawn at the furthest back spawn point (back-left, away from attackers).
@@ -32,10 +39,10 @@ pub fn spawn_initial_defender_archers(
         let hitbox = Hitbox::new(ARCHER_RADIUS, DEFENDER_HITBOX_HEIGHT);
         let circle = Circle::new(hitbox.radius);
 
-        // Distribute spawns in a circular pattern around this spawn point
-        let offset = i as f32 * SPAWN_OFFSET_MULTIPLIER;
-        let final_x = spawn_x + (offset.sin() * SPAWN_DISTRIBUTION_RADIUS);
-        let final_z = spawn_z + (offset.cos() * SPAWN_DISTRIBUTION_RADIUS);
+        // Spread spawns on a spiral around this spawn point
+        let offset = spawn_offset(i);
+        let final_x = spawn_x + offset.x;
+        let final_z = spawn_z + offset.y;
 
         // Position unit so bottom edge is 1 unit above battlefield (Y=0)
         let spawn_y = hitbox.height / 2.0 + 1.0;
@@ -54,7 +61,7 @@ pub fn spawn_initial_defender_archers(
                 hitbox,
                 Health::new(UNIT_HEALTH),
                 MovementSpeed(ARCHER_MOVEMENT_SPEED),
-                AttackTiming::new(),
+                AttackTiming::with_offset(spawn_attack_offset(final_x, final_z)),
                 Effectiveness::new(),
                 Team::Defenders,
                 Archer,
@@ -69,6 +76,7 @@ pub fn spawn_initial_defender_archers(
                 FlockingVelocity::default(),
                 FlockingModifier::new(1.0, 1.0, 0.0),
                 Teleportable,
+                SpawnAnimation::new(),
                 Billboard,
                 OnGameplayScreen,
             ));
@@ -85,6 +93,7 @@ pub fn spawn_initial_attacker_archers(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     current_level: Res<CurrentLevel>,
+    battlefield: Res<BattlefieldConfig>,
 ) {
     let level = current_level.0;
 
@@ -97,70 +106,81 @@ pub fn spawn_initial_attacker_archers(
 
     // Spawn each archer cell
     for (cell_idx, (row, col)) in archer_cells.iter().enumerate() {
-        let (spawn_x, spawn_z) = calculate_grid_cell_position(*row, *col);
+        let (spawn_x, spawn_z) = battlefield.grid_cell_position(*row, *col);
 
         let cell_count = units_per_cell.get(cell_idx).copied().unwrap_or(0);
 
         // Spawn all units in this cell
         for i in 0..cell_count {
-            let hitbox = Hitbox::new(ARCHER_RADIUS, ATTACKER_HITBOX_HEIGHT);
-            let circle = Circle::new(hitbox.radius);
-
-            // Distribute spawns in a circular pattern around this spawn point
-            let offset = i as f32 * SPAWN_OFFSET_MULTIPLIER;
-            let final_x = spawn_x + (offset.sin() * SPAWN_DISTRIBUTION_RADIUS);
-            let final_z = spawn_z + (offset.cos() * SPAWN_DISTRIBUTION_RADIUS);
-
-            // Position unit so bottom edge is 1 unit above battlefield (Y=0)
-            let spawn_y = hitbox.height / 2.0 + 1.0;
-
-            // Start with velocity toward castle
-            let to_castle = Vec3::new(
-                CASTLE_POSITION.x - final_x,
-                0.0,
-                CASTLE_POSITION.z - final_z,
-            )
-            .normalize_or_zero();
-            let initial_velocity = Velocity {
-                x: to_castle.x * ARCHER_MOVEMENT_SPEED,
-                z: to_castle.z * ARCHER_MOVEMENT_SPEED,
-            };
+            // Spread spawns on a spiral around this spawn point
+            let offset = spawn_offset(i);
+            let final_x = spawn_x + offset.x;
+            let final_z = spawn_z + offset.y;
 
-            commands
-                .spawn((
-                    Mesh3d(meshes.add(circle)),
-                    MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color: ATTACKER_ARCHER_COLOR,
-                        unlit: true,
-                        ..default()
-                    })),
-                    Transform::from_xyz(final_x, spawn_y, final_z),
-                    initial_velocity,
-                    Acceleration::new(),
-                    hitbox,
-                    Health::new(UNIT_HEALTH),
-                    MovementSpeed(ARCHER_MOVEMENT_SPEED),
-                    AttackTiming::new(),
-                    Effectiveness::new(),
-                    Team::Attackers,
-                    Archer,
-                ))
-                .insert((
-                    AttackRange {
-                        min_range: ARCHER_MIN_RANGE,
-                        max_range: ARCHER_MAX_RANGE,
-                    },
-                    ArcherMovementTimer::new(),
-                    TargetingVelocity::default(),
-                    FlockingVelocity::default(),
-                    Teleportable,
-                    Billboard,
-                    OnGameplayScreen,
-                ));
+            spawn_attacker_archer(&mut commands, &mut meshes, &mut materials, final_x, final_z);
         }
     }
 }
 
+/// Spawns a single attacker archer at the given ground position.
+///
+/// The unit starts moving toward the castle. Returns the spawned entity.
+pub fn spawn_attacker_archer(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    x: f32,
+    z: f32,
+) -> Entity {
+    let hitbox = Hitbox::new(ARCHER_RADIUS, ATTACKER_HITBOX_HEIGHT);
+    let circle = Circle::new(hitbox.radius);
+
+    // Position unit so bottom edge is 1 unit above battlefield (Y=0)
+    let spawn_y = hitbox.height / 2.0 + 1.0;
+
+    // Start with velocity toward castle
+    let to_castle =
+        Vec3::new(CASTLE_POSITION.x - x, 0.0, CASTLE_POSITION.z - z).normalize_or_zero();
+    let initial_velocity = Velocity {
+        x: to_castle.x * ARCHER_MOVEMENT_SPEED,
+        z: to_castle.z * ARCHER_MOVEMENT_SPEED,
+    };
+
+    commands
+        .spawn((
+            Mesh3d(meshes.add(circle)),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: ATTACKER_ARCHER_COLOR,
+                unlit: true,
+                ..default()
+            })),
+            Transform::from_xyz(x, spawn_y, z),
+            initial_velocity,
+            Acceleration::new(),
+            hitbox,
+            Health::new(UNIT_HEALTH),
+            MovementSpeed(ARCHER_MOVEMENT_SPEED),
+            AttackTiming::with_offset(spawn_attack_offset(x, z)),
+            Effectiveness::new(),
+            Team::Attackers,
+            Archer,
+        ))
+        .insert((
+            AttackRange {
+                min_range: ARCHER_MIN_RANGE,
+                max_range: ARCHER_MAX_RANGE,
+            },
+            ArcherMovementTimer::new(),
+            TargetingVelocity::default(),
+            FlockingVelocity::default(),
+            Teleportable,
+            SpawnAnimation::new(),
+            Billboard,
+            OnGameplayScreen,
+        ))
+        .id()
+}
+
 /// Updates archer movement timers to track time since stopped moving.
 pub fn update_archer_movement_timers(
     time: Res<Time>,
@@ -205,7 +225,12 @@ pub fn archer_melee_combat(
         (With<Archer>, Without<Corpse>),
     >,
     targets: Query<(Entity, &Transform, &Hitbox, &Team), Without<Corpse>>,
-    mut health_query: Query<(&mut Health, Option<&mut TemporaryHitPoints>)>,
+    mut health_query: Query<(
+        &mut Health,
+        Option<&mut TemporaryHitPoints>,
+        Option<&Resistances>,
+    )>,
+    mut damage_dealt: MessageWriter<DamageDealt>,
 ) {
     let current_time = attack_cycle.current_time;
     let last_time = (current_time - APPROX_FRAME_TIME).max(0.0);
@@ -226,30 +251,42 @@ pub fn archer_melee_combat(
     ) in &mut archers
     {
         // Find nearest enemy within melee range
-        if let Some((target_entity, _, _)) = targets_snapshot
-            .iter()
-            .filter(|(entity, _, _, team)| {
-                *entity != archer_entity && is_valid_target(archer_team, team)
-            })
-            .filter_map(|(entity, target_pos, target_hitbox, _)| {
-                let distance = archer_transform.translation.distance(*target_pos);
-                let melee_range =
-                    (archer_hitbox.radius + target_hitbox.radius) * ATTACK_RANGE_MULTIPLIER;
-                if distance <= melee_range {
-                    Some((entity, target_pos, distance))
-                } else {
-                    None
-                }
-            })
-            .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap())
-        {
+        if let Some((target_entity, target_pos, _)) = nearest_by_distance(
+            targets_snapshot
+                .iter()
+                .filter(|(entity, _, _, team)| {
+                    *entity != archer_entity && is_valid_target(archer_team, team)
+                })
+                .filter_map(|(entity, target_pos, target_hitbox, _)| {
+                    let distance = archer_transform.translation.distance(*target_pos);
+                    let melee_range =
+                        (archer_hitbox.radius + target_hitbox.radius) * ATTACK_RANGE_MULTIPLIER;
+                    if distance <= melee_range {
+                        Some((entity, target_pos, distance))
+                    } else {
+                        None
+                    }
+                }),
+            |(_, _, distance)| *distance,
+        ) {
             // Attack if we're in the unit's attack window
             if attack_timing.can_attack(current_time, last_time)
-                && let Ok((mut target_health, mut temp_hp)) = health_query.get_mut(*target_entity)
+                && let Ok((mut target_health, mut temp_hp, resistances)) =
+                    health_query.get_mut(*target_entity)
             {
                 // Apply effectiveness multiplier to melee damage
                 let modified_damage = ARCHER_MELEE_DAMAGE * effectiveness.multiplier();
-                apply_damage_to_unit(&mut target_health, temp_hp.as_deref_mut(), modified_damage);
+                apply_damage_and_report(
+                    &mut target_health,
+                    temp_hp.as_deref_mut(),
+                    resistances,
+                    modified_damage,
+                    DamageType::Physical,
+                    DamageSource::Unit,
+                    *target_entity,
+                    *target_pos,
+                    &mut damage_dealt,
+                );
                 attack_timing.last_attack_time = Some(current_time);
             }
         }
@@ -257,8 +294,16 @@ pub fn archer_melee_combat(
 }
 
 /// Archer ranged combat system that spawns arrows instead of dealing direct damage.
-/// Only fires if no melee targets are available.
+/// Only fires if no melee targets are available. Defender archers shoot the
+/// focused unit ahead of the nearest enemy when it is in range. Archers hold
+/// their shot while the projectile cap is reached. A berserk archer's bonus is
+/// carried on the arrow, so it still counts if the archer calms down or dies
+/// before it lands.
+#[allow(clippy::too_many_arguments)]
 pub fn archer_ranged_combat(
+    focus: Res<FocusTarget>,
+    config: Res<GameConfig>,
+    mut projectiles: ResMut<ProjectileCount>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
@@ -271,6 +316,7 @@ pub fn archer_ranged_combat(
             &AttackRange,
             &mut AttackTiming,
             &mut ArcherMovementTimer,
+            Option<&Berserk>,
         ),
         (With<Archer>, Without<Corpse>),
     >,
@@ -293,6 +339,7 @@ pub fn archer_ranged_combat(
         attack_range,
         _attack_timing,
         mut movement_timer,
+        berserk,
     ) in archers.iter_mut()
     {
         // Check if enough time has passed since stopping to attack
@@ -306,38 +353,49 @@ pub fn archer_ranged_combat(
             continue;
         }
 
-        // Find nearest enemy within ranged attack max_range
+        // Hold the shot until arrows in flight make room
+        if !projectiles.arrow_has_room(config.max_projectiles) {
+            break;
+        }
+
+        // Enemies within ranged attack max_range
         // Exclude targets in melee with someone on the archer's own team
-        let nearest_enemy = targets
-            .iter()
-            .filter(|(entity, _, team, _, in_melee)| {
-                // Skip self
-                if *entity == archer_entity {
-                    return false;
-                }
-                // Must be a valid enemy
-                if !is_valid_target(archer_team, team) {
-                    return false;
-                }
-                // Skip if target is in melee with archer's own team
-                if let Some(in_melee_component) = in_melee
-                    && in_melee_component.0 == *archer_team
-                {
-                    return false;
-                }
-                true
-            })
-            .filter(|(_, transform, _, _, _)| {
-                let distance = archer_transform.translation.distance(transform.translation);
-                distance <= attack_range.max_range && distance >= attack_range.min_range
-            })
-            .min_by(|a, b| {
-                let dist_a = archer_transform.translation.distance(a.1.translation);
-                let dist_b = archer_transform.translation.distance(b.1.translation);
-                dist_a.partial_cmp(&dist_b).unwrap()
+        let in_range = || {
+            targets
+                .iter()
+                .filter(|(entity, _, team, _, in_melee)| {
+                    // Skip self
+                    if *entity == archer_entity {
+                        return false;
+                    }
+                    // Must be a valid enemy
+                    if !is_valid_target(archer_team, team) {
+                        return false;
+                    }
+                    // Skip if target is in melee with archer's own team
+                    if let Some(in_melee_component) = in_melee
+                        && in_melee_component.0 == *archer_team
+                    {
+                        return false;
+                    }
+                    true
+                })
+                .filter(|(_, transform, _, _, _)| {
+                    let distance = archer_transform.translation.distance(transform.translation);
+                    distance <= attack_range.max_range && distance >= attack_range.min_range
+                })
+        };
+
+        let target = focus
+            .for_team(*archer_team)
+            .and_then(|focused| in_range().find(|(entity, _, _, _, _)| *entity == focused))
+            .or_else(|| {
+                nearest_by_distance(in_range(), |(_, transform, _, _, _)| {
+                    archer_transform.translation.distance(transform.translation)
+                })
             });
 
-        if let Some((_, target_transform, _, _, _)) = nearest_enemy {
+        if let Some((_, target_transform, _, _, _)) = target {
             // Spawn arrow projectile directly above the archer
             spawn_arrow(
                 &mut commands,
@@ -346,7 +404,10 @@ pub fn archer_ranged_combat(
                 archer_transform.translation + Vec3::Y * 10.0,
                 target_transform.translation,
                 *archer_team,
+                ARCHER_ATTACK_DAMAGE * (1.0 + berserk.map_or(0.0, |b| b.0)),
+                movement_timer.spread_multiplier(),
             );
+            projectiles.record_arrow();
             // Reset attack cooldown
             movement_timer.time_since_last_attack = 0.0;
         }
@@ -354,6 +415,8 @@ pub fn archer_ranged_combat(
 }
 
 /// Checks if a target is valid for the given team (same logic as combat system).
+///
+/// Used for archer targeting, firing, and arrow hits.
 fn is_valid_target(source_team: &Team, target_team: &Team) -> bool {
     match (source_team, target_team) {
         (Team::Undead, Team::Undead) => false, // Undead don't attack each other
@@ -364,6 +427,10 @@ fn is_valid_target(source_team: &Team, target_team: &Team) -> bool {
 }
 
 /// Spawns an arrow projectile from archer toward target.
+///
+/// The arrow deals `damage` on impact. `spread` scales the random power and
+/// angle variation (see `ArcherMovementTimer::spread_multiplier`).
+#[allow(clippy::too_many_arguments)]
 fn spawn_arrow(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
@@ -371,26 +438,60 @@ fn spawn_arrow(
     origin: Vec3,
     target: Vec3,
     source_team: Team,
+    damage: f32,
+    spread: f32,
 ) {
+    let Some(velocity) = arrow_launch_velocity(origin, target, spread, &mut rand::thread_rng())
+    else {
+        return;
+    };
+
+    // Spawn arrow as circle mesh
+    let arrow_mesh = Circle::new(ARROW_WIDTH);
+
+    commands.spawn((
+        Mesh3d(meshes.add(arrow_mesh)),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: ARROW_COLOR,
+            unlit: true,
+            ..default()
+        })),
+        Transform::from_translation(origin),
+        Arrow {
+            velocity,
+            damage,
+            source_team,
+        },
+        OnGameplayScreen,
+    ));
+}
+
+/// Returns the launch velocity of an arrow from `origin` aimed at `target`.
+///
+/// Power and angle get random variation scaled by `spread`. Returns None if
+/// the target is directly above or below the origin.
+fn arrow_launch_velocity(
+    origin: Vec3,
+    target: Vec3,
+    spread: f32,
+    rng: &mut impl Rng,
+) -> Option<Vec3> {
     // Calculate horizontal direction and distance
     let horizontal_diff = Vec3::new(target.x - origin.x, 0.0, target.z - origin.z);
     let horizontal_distance = horizontal_diff.length();
 
     // Avoid division by zero
     if horizontal_distance < 0.1 {
-        return;
+        return None;
     }
 
     let horizontal_direction = horizontal_diff.normalize();
 
-    // Add random variations for realism
-    let mut rng = rand::thread_rng();
-
-    // Random power variation (±5%)
-    let power_multiplier = 1.0 + rng.gen_range(-ARROW_POWER_VARIATION..ARROW_POWER_VARIATION);
+    // Random power variation (up to ±5%)
+    let power_multiplier = 1.0 + rng.gen_range(-1.0..1.0) * ARROW_POWER_VARIATION * spread;
 
-    // Random angle variation (±1 degree)
-    let angle_offset = rng.gen_range(-ARROW_ANGLE_VARIATION_DEGREES..ARROW_ANGLE_VARIATION_DEGREES);
+    // Random angle variation (up to ±1 degree)
+    let angle_offset = rng.gen_range(-1.0..1.0) * ARROW_ANGLE_VARIATION_DEGREES * spread;
     let launch_angle = (ARROW_LAUNCH_ANGLE_DEGREES + angle_offset).to_radians();
 
     // Calculate velocity needed to hit target at launch angle (flat ground)
@@ -404,39 +505,28 @@ fn spawn_arrow(
     let horizontal_velocity = horizontal_direction * required_speed * launch_angle.cos();
     let vertical_velocity = required_speed * launch_angle.sin();
 
-    let velocity = Vec3::new(
+    Some(Vec3::new(
         horizontal_velocity.x,
         vertical_velocity,
         horizontal_velocity.z,
-    );
-
-    // Spawn arrow as circle mesh
-    let arrow_mesh = Circle::new(ARROW_WIDTH);
-
-    commands.spawn((
-        Mesh3d(meshes.add(arrow_mesh)),
-        MeshMaterial3d(materials.add(StandardMaterial {
-            base_color: ARROW_COLOR,
-            unlit: true,
-            ..default()
-        })),
-        Transform::from_translation(origin),
-        Arrow {
-            velocity,
-            damage: ARCHER_ATTACK_DAMAGE,
-            source_team,
-        },
-        OnGameplayScreen,
-    ));
+    ))
 }
 
-/// Updates arrow positions with gravity.
-pub fn move_arrows(time: Res<Time>, mut arrows: Query<(&mut Transform, &mut Arrow)>) {
+/// Updates arrow positions with gravity and, when enabled, wind drift.
+pub fn move_arrows(
+    time: Res<Time>,
+    wind: Option<Res<Wind>>,
+    mut arrows: Query<(&mut Transform, &mut Arrow)>,
+) {
     let delta = time.delta_secs();
+    let wind_acceleration = wind.map_or(Vec3::ZERO, |wind| wind.acceleration());
     for (mut transform, mut arrow) in &mut arrows {
         // Apply gravity
         arrow.velocity.y -= ARROW_GRAVITY * delta;
 
+        // Wind pushes the arrow sideways
+        arrow.velocity += wind_acceleration * delta;
+
         // Update position
         transform.translation += arrow.velocity * delta;
     }
@@ -448,16 +538,21 @@ pub fn check_arrow_collisions(
     arrows: Query<(Entity, &Transform, &Arrow)>,
     mut targets: Query<
         (
+            Entity,
             &Transform,
             &Hitbox,
             &Team,
             &mut Health,
             Option<&mut TemporaryHitPoints>,
+            Option<&Resistances>,
         ),
-        Without<Corpse>,
+        (Without<Corpse>, Without<PermanentCorpse>),
     >,
     walls: Query<&WallOfStone>,
+    mut damage_dealt: MessageWriter<DamageDealt>,
 ) {
+    let mut spent = Vec::new();
+
     #[allow(clippy::significant_drop_in_scrutinee)]
     for (arrow_entity, arrow_transform, arrow) in &arrows {
         let arrow_pos = arrow_transform.translation;
@@ -466,7 +561,7 @@ pub fn check_arrow_collisions(
         let mut hit_wall = false;
         for wall in &walls {
             if wall.contains_point_xz(arrow_pos) && arrow_pos.y <= wall.height {
-                commands.entity(arrow_entity).despawn();
+                spent.push(arrow_entity);
                 hit_wall = true;
                 break;
             }
@@ -477,38 +572,43 @@ pub fn check_arrow_collisions(
 
         // Ground collision
         if arrow_pos.y <= 0.0 {
-            commands.entity(arrow_entity).despawn();
+            spent.push(arrow_entity);
             continue;
         }
 
         // Unit collision (skip friendly fire)
-        for (target_transform, hitbox, team, mut health, mut temp_hp) in &mut targets {
-            // Skip same team
-            if *team == arrow.source_team {
-                continue;
-            }
-
-            // Check if enemy (using same logic as combat system)
-            let is_enemy = match (arrow.source_team, *team) {
-                (Team::Undead, Team::Undead) => false,
-                (Team::Undead, _) => true,
-                (_, Team::Undead) => true,
-                _ => *team != arrow.source_team,
-            };
-
-            if !is_enemy {
+        for (target_entity, target_transform, hitbox, team, mut health, mut temp_hp, resistances) in
+            &mut targets
+        {
+            // Judged on the target's team at impact, so a unit raised as undead
+            // mid-flight is fair game for the arrow that was fired past it
+            if !is_valid_target(&arrow.source_team, team) {
                 continue;
             }
 
             // Check collision
             let distance = arrow_pos.distance(target_transform.translation);
             if distance < hitbox.radius + ARROW_WIDTH {
-                apply_damage_to_unit(&mut health, temp_hp.as_deref_mut(), arrow.damage);
-                commands.entity(arrow_entity).despawn();
+                apply_damage_and_report(
+                    &mut health,
+                    temp_hp.as_deref_mut(),
+                    resistances,
+                    arrow.damage,
+                    DamageType::Physical,
+                    DamageSource::Unit,
+                    target_entity,
+                    target_transform.translation,
+                    &mut damage_dealt,
+                );
+                spent.push(arrow_entity);
                 break;
             }
         }
     }
+
+    if !spent.is_empty() {
+        commands.queue(despawn_batch(spent));
+    }
 }
 
 /// Updates archer targeting velocity based on attack range.
@@ -538,26 +638,17 @@ pub fn update_archer_targeting(
     // Update each archer's targeting velocity
     for (entity, transform, team, attack_range, mut targeting_velocity) in &mut archers {
         // Find nearest enemy
-        let nearest_enemy = unit_snapshot
-            .iter()
-            .filter(|(other_entity, _, other_team)| {
-                *other_entity != entity
-                    && match (*team, other_team) {
-                        (Team::Undead, Team::Undead) => false,
-                        (Team::Undead, _) => true,
-                        (_, Team::Undead) => true,
-                        _ => *other_team != *team,
-                    }
-            })
-            .min_by(|a, b| {
-                let dist_a = (transform.translation.x - a.1.x).powi(2)
-                    + (transform.translation.z - a.1.z).powi(2);
-                let dist_b = (transform.translation.x - b.1.x).powi(2)
-                    + (transform.translation.z - b.1.z).powi(2);
-                dist_a
-                    .partial_cmp(&dist_b)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
+        let nearest_enemy = nearest_by_distance(
+            unit_snapshot
+                .iter()
+                .filter(|(other_entity, _, other_team)| {
+                    *other_entity != entity && is_valid_target(team, other_team)
+                }),
+            |(_, pos, _)| {
+                (transform.translation.x - pos.x).powi(2)
+                    + (transform.translation.z - pos.z).powi(2)
+            },
+        );
 
         // Set targeting velocity based on range to enemy
         if let Some(&(_, target_pos, enemy_team)) = nearest_enemy {
@@ -609,6 +700,7 @@ pub fn update_archer_targeting(
 #[allow(clippy::type_complexity)]
 pub fn archer_movement(
     time: Res<Time>,
+    tunables: Option<Res<TunableConstants>>,
     mut archer_units: Query<
         (
             &mut Transform,
@@ -621,11 +713,13 @@ pub fn archer_movement(
             Option<&crate::game::units::components::InMelee>,
             Option<&KingAuraSpeedModifier>,
             Option<&RoughTerrainModifier>,
+            Option<&Snare>,
         ),
         With<Archer>,
     >,
 ) {
     let delta = time.delta_secs();
+    let tuning = TunableConstants::or_default(tunables);
 
     // Process each archer unit
     for (
@@ -639,6 +733,7 @@ pub fn archer_movement(
         in_melee,
         aura_modifier,
         terrain_modifier,
+        snare,
     ) in &mut archer_units
     {
         // Weight targeting vs flocking based on distance to target
@@ -655,23 +750,20 @@ pub fn archer_movement(
             .normalize_or_zero();
 
         // Calculate speed modifiers early to apply to acceleration
-        let aura_percentage = aura_modifier.map_or(0.0, |m| m.0);
-        let terrain_percentage = terrain_modifier.map_or(0.0, |m| m.0);
-        let total_percentage = aura_percentage + terrain_percentage;
-        let speed_multiplier = 1.0 + total_percentage;
+        let speed_multiplier = movement_speed_multiplier(aura_modifier, terrain_modifier, snare);
 
         // Apply as acceleration force with speed modifiers
-        acceleration.add_force(weighted_direction * STEERING_FORCE * speed_multiplier);
+        acceleration.add_force(weighted_direction * tuning.steering_force * speed_multiplier);
 
         // Apply acceleration to velocity
         velocity.x += acceleration.x * delta;
         velocity.z += acceleration.z * delta;
 
         // Apply damping to smooth movement
-        velocity.x *= VELOCITY_DAMPING;
-        velocity.z *= VELOCITY_DAMPING;
+        velocity.x *= tuning.velocity_damping;
+        velocity.z *= tuning.velocity_damping;
 
-        // Calculate max speed based on state with modifiers (aura + terrain)
+        // Calculate max speed based on state with modifiers (aura + terrain + snare)
         let mut max_speed = movement_speed.0 * effectiveness.multiplier() * speed_multiplier;
 
         if in_melee.is_some() {
@@ -702,3 +794,325 @@ pub fn archer_movement(
         acceleration.reset();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::schedule::ScheduleLabel;
+    use bevy::time::TimeUpdateStrategy;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+    use crate::game::units::constants::BERSERK_DAMAGE_PERCENTAGE;
+
+    /// A long frame, as on a slow machine or after a hitch.
+    const FRAME: Duration = Duration::from_millis(100);
+
+    /// Fast enough to cover more than a hitbox per long frame.
+    const ARROW_SPEED: f32 = 1500.0;
+
+    /// Fires a fast arrow at an attacker 100 units away and returns its health after a few frames.
+    fn target_health_after_volley(schedule: impl ScheduleLabel) -> f32 {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+            .insert_resource(Time::<Fixed>::from_hz(60.0))
+            .add_message::<DamageDealt>()
+            .add_systems(schedule, (move_arrows, check_arrow_collisions).chain());
+
+        let target = app
+            .world_mut()
+            .spawn((
+                Team::Attackers,
+                Transform::from_xyz(100.0, 20.0, 0.0),
+                Hitbox::new(ARCHER_RADIUS, DEFENDER_HITBOX_HEIGHT),
+                Health::new(100.0),
+            ))
+            .id();
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 20.0, 0.0),
+            Arrow {
+                velocity: Vec3::new(ARROW_SPEED, 0.0, 0.0),
+                damage: 10.0,
+                source_team: Team::Defenders,
+            },
+        ));
+
+        for _ in 0..4 {
+            app.update();
+        }
+        app.world().get::<Health>(target).unwrap().current
+    }
+
+    #[test]
+    fn test_fast_arrow_hits_at_fixed_timestep() {
+        assert_eq!(target_health_after_volley(FixedUpdate), 90.0);
+    }
+
+    #[test]
+    fn test_fast_arrow_skips_target_with_long_frames() {
+        assert_eq!(target_health_after_volley(Update), 100.0);
+    }
+
+    /// Flies a lobbed arrow until it lands and returns where it came down.
+    fn arrow_landing_point(wind: Option<Wind>) -> Vec3 {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                20,
+            )))
+            .add_systems(Update, move_arrows);
+        if let Some(wind) = wind {
+            app.insert_resource(wind);
+        }
+
+        let arrow = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, 20.0, 0.0),
+                Arrow {
+                    velocity: Vec3::new(300.0, 300.0, 0.0),
+                    damage: 10.0,
+                    source_team: Team::Defenders,
+                },
+            ))
+            .id();
+
+        let position = |app: &App| app.world().get::<Transform>(arrow).unwrap().translation;
+        for _ in 0..500 {
+            app.update();
+            if position(&app).y <= 0.0 {
+                break;
+            }
+        }
+        position(&app)
+    }
+
+    #[test]
+    fn test_wind_deflects_arrow_landing_point() {
+        let calm = arrow_landing_point(None);
+        // Crosswind blowing toward +Z, perpendicular to the arrow's flight
+        let windy = arrow_landing_point(Some(Wind::new(std::f32::consts::FRAC_PI_2, 60.0)));
+
+        assert!(calm.y <= 0.0 && windy.y <= 0.0);
+        assert!(calm.z.abs() < 1e-3);
+        assert!(windy.z > 10.0);
+    }
+
+    #[test]
+    fn test_long_stationary_archer_fires_tighter_spread() {
+        let origin = Vec3::new(0.0, 10.0, 0.0);
+        let target = Vec3::new(500.0, 0.0, 0.0);
+
+        // Widest deviation from a perfectly aimed arrow over many shots
+        let widest_miss = |time_since_stopped: f32| {
+            let timer = ArcherMovementTimer {
+                time_since_stopped,
+                ..ArcherMovementTimer::new()
+            };
+            let aimed =
+                arrow_launch_velocity(origin, target, 0.0, &mut StdRng::seed_from_u64(0)).unwrap();
+            let mut rng = StdRng::seed_from_u64(7);
+            (0..200)
+                .map(|_| {
+                    arrow_launch_velocity(origin, target, timer.spread_multiplier(), &mut rng)
+                        .unwrap()
+                        .distance(aimed)
+                })
+                .fold(0.0, f32::max)
+        };
+
+        let just_stopped = widest_miss(ARCHER_ATTACK_DELAY_AFTER_MOVEMENT);
+        let entrenched = widest_miss(ARCHER_ATTACK_DELAY_AFTER_MOVEMENT + ARROW_ACCURACY_RAMP_TIME);
+
+        assert!(entrenched > 0.0);
+        assert!(
+            entrenched < just_stopped * 0.5,
+            "entrenched {entrenched} vs just stopped {just_stopped}"
+        );
+    }
+
+    /// Drops a defender arrow onto a unit of `team` and returns the unit's health afterwards.
+    fn health_after_defender_arrow(team: Team, corpse: bool) -> f32 {
+        let mut app = App::new();
+        app.add_message::<DamageDealt>()
+            .add_systems(Update, check_arrow_collisions);
+
+        let target = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, 10.0, 0.0),
+                Hitbox::new(10.0, 20.0),
+                team,
+                Health::new(100.0),
+            ))
+            .id();
+        if corpse {
+            app.world_mut()
+                .entity_mut(target)
+                .insert((Corpse, PermanentCorpse));
+        }
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 10.0, 0.0),
+            Arrow {
+                velocity: Vec3::ZERO,
+                damage: ARCHER_ATTACK_DAMAGE,
+                source_team: Team::Defenders,
+            },
+        ));
+        app.update();
+
+        app.world().get::<Health>(target).unwrap().current
+    }
+
+    #[test]
+    fn test_arrow_hits_defender_raised_as_undead() {
+        // A defender raised mid-flight is judged by its live team at impact
+        assert_eq!(health_after_defender_arrow(Team::Defenders, false), 100.0);
+        assert_eq!(
+            health_after_defender_arrow(Team::Undead, false),
+            100.0 - ARCHER_ATTACK_DAMAGE
+        );
+    }
+
+    #[test]
+    fn test_arrow_ignores_corpses() {
+        assert_eq!(health_after_defender_arrow(Team::Attackers, true), 100.0);
+        assert_eq!(
+            health_after_defender_arrow(Team::Attackers, false),
+            100.0 - ARCHER_ATTACK_DAMAGE
+        );
+    }
+
+    #[test]
+    fn test_undead_never_target_undead() {
+        assert!(!is_valid_target(&Team::Undead, &Team::Undead));
+        assert!(is_valid_target(&Team::Undead, &Team::Defenders));
+        assert!(is_valid_target(&Team::Defenders, &Team::Undead));
+        assert!(!is_valid_target(&Team::Attackers, &Team::Attackers));
+    }
+
+    #[test]
+    fn test_archers_hold_fire_at_projectile_cap() {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            max_projectiles: Some(1),
+            ..default()
+        })
+        .init_resource::<ProjectileCount>()
+        .init_resource::<FocusTarget>()
+        .init_resource::<Assets<Mesh>>()
+        .init_resource::<Assets<StandardMaterial>>()
+        .add_systems(
+            Update,
+            (
+                crate::game::shared_systems::count_projectiles,
+                archer_ranged_combat,
+            )
+                .chain(),
+        );
+
+        for z in [0.0, 50.0] {
+            app.world_mut().spawn((
+                Archer,
+                Transform::from_xyz(0.0, 20.0, z),
+                Hitbox::new(ARCHER_RADIUS, DEFENDER_HITBOX_HEIGHT),
+                Team::Defenders,
+                AttackRange {
+                    min_range: 0.0,
+                    max_range: 1000.0,
+                },
+                AttackTiming::default(),
+                ArcherMovementTimer {
+                    time_since_stopped: ARCHER_ATTACK_DELAY_AFTER_MOVEMENT,
+                    ..ArcherMovementTimer::new()
+                },
+            ));
+        }
+        app.world_mut().spawn((
+            Team::Attackers,
+            Transform::from_xyz(500.0, 20.0, 0.0),
+            Hitbox::new(ARCHER_RADIUS, DEFENDER_HITBOX_HEIGHT),
+        ));
+
+        let arrows = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<Entity, With<Arrow>>()
+                .iter(app.world())
+                .collect::<Vec<_>>()
+        };
+
+        // Both archers are ready, but only one arrow fits
+        app.update();
+        assert_eq!(arrows(&mut app).len(), 1);
+        app.update();
+        assert_eq!(arrows(&mut app).len(), 1);
+
+        // Once it lands, the other archer gets its shot off
+        let landed = arrows(&mut app)[0];
+        app.world_mut().despawn(landed);
+        app.update();
+        let remaining = arrows(&mut app);
+        assert_eq!(remaining.len(), 1);
+        assert_ne!(remaining[0], landed);
+    }
+
+    #[test]
+    fn test_berserk_archer_fires_harder_arrows() {
+        let mut app = App::new();
+        app.init_resource::<GameConfig>()
+            .init_resource::<ProjectileCount>()
+            .init_resource::<FocusTarget>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_systems(Update, archer_ranged_combat);
+
+        let ready_timer = || ArcherMovementTimer {
+            time_since_stopped: ARCHER_ATTACK_DELAY_AFTER_MOVEMENT,
+            ..ArcherMovementTimer::new()
+        };
+        for (z, berserk) in [
+            (0.0, None),
+            (50.0, Some(Berserk(BERSERK_DAMAGE_PERCENTAGE))),
+        ] {
+            let mut archer = app.world_mut().spawn((
+                Archer,
+                Transform::from_xyz(0.0, 20.0, z),
+                Hitbox::new(ARCHER_RADIUS, DEFENDER_HITBOX_HEIGHT),
+                Team::Defenders,
+                AttackRange {
+                    min_range: 0.0,
+                    max_range: 1000.0,
+                },
+                AttackTiming::default(),
+                ready_timer(),
+            ));
+            if let Some(berserk) = berserk {
+                archer.insert(berserk);
+            }
+        }
+        app.world_mut().spawn((
+            Team::Attackers,
+            Transform::from_xyz(500.0, 20.0, 0.0),
+            Hitbox::new(ARCHER_RADIUS, DEFENDER_HITBOX_HEIGHT),
+        ));
+        app.update();
+
+        let mut damages: Vec<f32> = app
+            .world_mut()
+            .query::<&Arrow>()
+            .iter(app.world())
+            .map(|arrow| arrow.damage)
+            .collect();
+        damages.sort_by(f32::total_cmp);
+        assert_eq!(
+            damages,
+            [
+                ARCHER_ATTACK_DAMAGE,
+                ARCHER_ATTACK_DAMAGE * (1.0 + BERSERK_DAMAGE_PERCENTAGE)
+            ]
+        );
+    }
+}
@@ -1,5 +1,14 @@
 use bevy::prelude::*;
 
+use crate::game::components::Velocity;
+use crate::game::constants::{
+    ATTACK_CYCLE_DURATION, JITTER_HISTORY_FRAMES, KNOCKBACK_VELOCITY_SCALE,
+    MAX_KNOCKBACK_DISPLACEMENT,
+};
+use crate::game::resources::TunableConstants;
+use crate::game::units::constants::SPAWN_ANIMATION_DURATION;
+use crate::game::units::wizard::components::Spell;
+
 /// Team component for all units.
 ///
 /// Determines which side a unit is on. Units attack members of opposing teams.
@@ -34,6 +43,14 @@ pub struct MovementSpeed(pub f32);
 #[derive(Component)]
 pub struct DamageMultiplier(pub f32);
 
+/// Damage bonus as a percentage for a unit fighting on at low health.
+///
+/// Added and removed as the unit's health crosses `BERSERK_HEALTH_THRESHOLD`.
+/// Kept apart from `DamageMultiplier` so it stacks with auras:
+/// damage * (1.0 + aura_percentage + berserk_percentage).
+#[derive(Component)]
+pub struct Berserk(pub f32);
+
 /// Movement speed modifier from King's aura as a percentage.
 ///
 /// Applied to defenders within the King's aura range.
@@ -50,23 +67,125 @@ pub struct KingAuraSpeedModifier(pub f32);
 #[derive(Component)]
 pub struct RoughTerrainModifier(pub f32);
 
+/// Slow effect applied by frost spells and similar snares.
+///
+/// Unlike the percentage modifiers, this is a multiplier applied on top of them.
+/// Examples: 0.5 = half speed while snared.
+/// Movement systems apply this as: speed * (1.0 + sum_of_all_modifiers) * slow_factor.
+#[derive(Component)]
+pub struct Snare {
+    /// Speed multiplier while snared (0.0 to 1.0).
+    pub slow_factor: f32,
+    /// Time remaining before the snare wears off (in seconds).
+    pub timer: f32,
+}
+
+impl Snare {
+    /// Creates a new snare with a duration.
+    pub const fn new(slow_factor: f32, duration: f32) -> Self {
+        Self {
+            slow_factor,
+            timer: duration,
+        }
+    }
+
+    /// Updates the timer, returning true if expired.
+    pub fn update(&mut self, delta: f32) -> bool {
+        self.timer -= delta;
+        self.timer <= 0.0
+    }
+}
+
+/// Limited lifespan for summoned units.
+///
+/// The unit is despawned (leaving no corpse) once the lifespan runs out.
+#[derive(Component)]
+pub struct Temporary {
+    /// Time remaining before the unit is despawned (in seconds).
+    pub lifespan: f32,
+}
+
+impl Temporary {
+    /// Creates a new lifespan in seconds.
+    pub const fn new(lifespan: f32) -> Self {
+        Self { lifespan }
+    }
+
+    /// Updates the lifespan, returning true if expired.
+    pub fn update(&mut self, delta: f32) -> bool {
+        self.lifespan -= delta;
+        self.lifespan <= 0.0
+    }
+}
+
+/// Grows a newly spawned unit's sprite from nothing to full size.
+///
+/// Only the rendered sprite is scaled; the `Hitbox` is full size from the start.
+/// Removed once the animation finishes.
+#[derive(Component)]
+pub struct SpawnAnimation {
+    /// Time since the unit spawned.
+    pub timer: Timer,
+}
+
+impl SpawnAnimation {
+    /// Creates a spawn animation lasting `SPAWN_ANIMATION_DURATION`.
+    pub fn new() -> Self {
+        Self {
+            timer: Timer::from_seconds(SPAWN_ANIMATION_DURATION, TimerMode::Once),
+        }
+    }
+
+    /// Returns the sprite scale factor, easing out from 0.0 to 1.0.
+    pub fn scale(&self) -> f32 {
+        1.0 - (1.0 - self.timer.fraction()).powi(3)
+    }
+}
+
+impl Default for SpawnAnimation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Combines all movement speed modifiers into a single multiplier.
+///
+/// Aura and terrain percentages are summed, then the snare multiplier is applied.
+/// Shared by all movement systems so modifiers stack the same way for every unit.
+pub fn movement_speed_multiplier(
+    aura_modifier: Option<&KingAuraSpeedModifier>,
+    terrain_modifier: Option<&RoughTerrainModifier>,
+    snare: Option<&Snare>,
+) -> f32 {
+    let aura_percentage = aura_modifier.map_or(0.0, |m| m.0);
+    let terrain_percentage = terrain_modifier.map_or(0.0, |m| m.0);
+    let total_percentage = aura_percentage + terrain_percentage;
+    let slow_factor = snare.map_or(1.0, |s| s.slow_factor);
+    (1.0 + total_percentage) * slow_factor
+}
+
 /// Attack timing component for all units.
 ///
 /// Tracks when in the global attack cycle a unit can attack.
 /// Units attack at a specific time offset (0.0 to cycle_duration) and can only
 /// attack again when the global timer cycles back to that offset. This naturally
 /// staggers attacks across all units.
-#[derive(Component)]
+///
+/// The default has no attack scheduled, so the unit may strike right away.
+#[derive(Component, Default)]
 pub struct AttackTiming {
     /// The time offset in the cycle when this unit last attacked, or None if never attacked
     pub last_attack_time: Option<f32>,
 }
 
 impl AttackTiming {
-    /// Creates a new AttackTiming with no attack scheduled.
-    pub const fn new() -> Self {
+    /// Creates an AttackTiming whose first attack waits for `offset` in the cycle.
+    ///
+    /// Spawners pass `spawn_attack_offset` so a freshly spawned army doesn't
+    /// strike all at once on the first frame of combat.
+    pub const fn with_offset(offset: f32) -> Self {
         Self {
-            last_attack_time: None,
+            last_attack_time: Some(offset),
         }
     }
 
@@ -96,6 +215,16 @@ impl AttackTiming {
     }
 }
 
+/// Returns a spread-out attack cycle offset for a unit spawned at `(x, z)`.
+///
+/// Deterministic for a given spawn position, so replays stagger the same way.
+pub fn spawn_attack_offset(x: f32, z: f32) -> f32 {
+    let hash = ((x * 12.9898 + z * 78.233).sin() * 43_758.547)
+        .fract()
+        .abs();
+    hash * ATTACK_CYCLE_DURATION
+}
+
 /// Hitbox component for all units.
 ///
 /// Represents a cylindrical collision volume for the unit.
@@ -225,22 +354,82 @@ impl Health {
     }
 }
 
-/// Applies damage to a unit, absorbing with temporary HP first.
+/// Type of damage a hit deals, used to look up the target's resistance and spell combos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageType {
+    /// Melee swings and arrows.
+    Physical,
+    /// Spells.
+    Magic,
+    /// Fire spells (fireball impacts). Resisted like magic.
+    Fire,
+    /// Ignores resistances.
+    True,
+}
+
+/// Burns the wizard's mana each time the unit lands a hit within reach of it.
+///
+/// See `shared_systems::drain_wizard_mana`; the wizard itself has no health to
+/// lose to these hits.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ManaDrain {
+    /// Mana removed per hit.
+    pub amount: f32,
+}
+
+/// Per-unit resistance to each damage type.
+///
+/// Each value is the fraction of incoming damage ignored: 0.0 takes full
+/// damage, 0.5 halves it, 1.0 is immune, and negative values make the unit
+/// vulnerable (-0.5 takes 150%). Units without this component take full damage.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct Resistances {
+    /// Resistance to physical damage.
+    pub physical: f32,
+    /// Resistance to magic damage.
+    pub magic: f32,
+}
+
+impl Resistances {
+    /// Scales damage of the given type by this resistance.
+    ///
+    /// Resistance above 1.0 is treated as immunity rather than healing.
+    pub fn scale(&self, damage: f32, damage_type: DamageType) -> f32 {
+        let resistance = match damage_type {
+            DamageType::Physical => self.physical,
+            DamageType::Magic | DamageType::Fire => self.magic,
+            DamageType::True => 0.0,
+        };
+        damage * (1.0 - resistance.min(1.0))
+    }
+}
+
+/// Applies damage to a unit, scaling by resistance and then absorbing with
+/// temporary HP.
 ///
 /// This function should be used instead of directly calling `health.take_damage()`
-/// when temporary hit points should be respected. Damage is first absorbed by
-/// temporary HP (if present), and any overflow damage is applied to real health.
+/// when temporary hit points should be respected. Damage is first scaled by the
+/// unit's resistance to its type, then absorbed by temporary HP (if present),
+/// and any overflow damage is applied to real health.
+///
+/// Returns the damage after resistances, before temporary HP absorbed any.
 ///
 /// # Arguments
 ///
 /// * `health` - The unit's Health component
 /// * `temp_hp` - Optional TemporaryHitPoints component
+/// * `resistances` - Optional Resistances component
 /// * `damage` - Amount of damage to apply
+/// * `damage_type` - Type of the damage, matched against `resistances`
 pub fn apply_damage_to_unit(
     health: &mut Health,
     temp_hp: Option<&mut TemporaryHitPoints>,
+    resistances: Option<&Resistances>,
     damage: f32,
-) {
+    damage_type: DamageType,
+) -> f32 {
+    let damage = resistances.map_or(damage, |resistances| resistances.scale(damage, damage_type));
+
     let overflow = if let Some(temp) = temp_hp {
         temp.absorb_damage(damage)
     } else {
@@ -248,6 +437,221 @@ pub fn apply_damage_to_unit(
     };
 
     health.take_damage(overflow);
+    damage
+}
+
+/// What dealt a hit, so kills can be credited to the wizard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageSource {
+    /// Melee attacks and arrows from units.
+    Unit,
+    /// The wizard's spells and their projectiles, with the spell that dealt the hit.
+    Spell(Spell),
+    /// The wizard's ultimate, whose kills don't recharge it.
+    Ultimate,
+}
+
+/// Message sent whenever a living unit takes damage.
+///
+/// Carries the hit position and amount so combat feedback (floating damage
+/// numbers) doesn't need to know about every damage source.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct DamageDealt {
+    /// Unit that was hit.
+    pub target: Entity,
+    /// World position of the unit that was hit.
+    pub position: Vec3,
+    /// Damage dealt after resistances, before temporary hit points absorb any of it.
+    pub amount: f32,
+    /// Type of the damage dealt.
+    #[allow(dead_code)] // No listener distinguishes damage types yet
+    pub damage_type: DamageType,
+    /// What dealt the hit.
+    pub source: DamageSource,
+    /// Whether the hit brought the unit to zero health.
+    pub lethal: bool,
+}
+
+/// Applies damage to a unit and reports it with a `DamageDealt` message.
+///
+/// Damage application points use this instead of calling `apply_damage_to_unit`
+/// directly. Hits on units that are already dead are applied but not reported.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_damage_and_report(
+    health: &mut Health,
+    temp_hp: Option<&mut TemporaryHitPoints>,
+    resistances: Option<&Resistances>,
+    damage: f32,
+    damage_type: DamageType,
+    source: DamageSource,
+    target: Entity,
+    position: Vec3,
+    damage_dealt: &mut MessageWriter<DamageDealt>,
+) {
+    let was_alive = health.current > 0.0;
+    let damage = apply_damage_to_unit(health, temp_hp, resistances, damage, damage_type);
+
+    if was_alive && damage > 0.0 {
+        damage_dealt.write(DamageDealt {
+            target,
+            position,
+            amount: damage,
+            damage_type,
+            source,
+            lethal: health.is_dead(),
+        });
+    }
+}
+
+/// Status effect on a unit that spell combos can react to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusEffect {
+    /// Slowed by a `Snare` (frost).
+    Snared,
+}
+
+impl StatusEffect {
+    /// Returns the status effects currently active on a unit.
+    pub fn active(snare: Option<&Snare>) -> Vec<Self> {
+        snare.map(|_| Self::Snared).into_iter().collect()
+    }
+}
+
+/// A spell synergy: hits of `damage_type` on a unit with `effect` are multiplied.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpellCombo {
+    /// Name shown when the combo triggers.
+    pub name: &'static str,
+    /// Status effect the target must have.
+    pub effect: StatusEffect,
+    /// Damage type that triggers the combo.
+    pub damage_type: DamageType,
+    /// Damage multiplier applied to the hit.
+    pub multiplier: f32,
+}
+
+/// Finds the first combo in `SPELL_COMBOS` matching a hit on a unit with `effects`.
+pub fn find_combo(
+    effects: &[StatusEffect],
+    damage_type: DamageType,
+) -> Option<&'static SpellCombo> {
+    super::constants::SPELL_COMBOS
+        .iter()
+        .find(|combo| combo.damage_type == damage_type && effects.contains(&combo.effect))
+}
+
+/// Returns the damage a hit deals after combos, along with the combo that triggered.
+pub fn apply_combo(
+    damage: f32,
+    damage_type: DamageType,
+    effects: &[StatusEffect],
+) -> (f32, Option<&'static SpellCombo>) {
+    match find_combo(effects, damage_type) {
+        Some(combo) => (damage * combo.multiplier, Some(combo)),
+        None => (damage, None),
+    }
+}
+
+/// Message sent when a hit triggers a spell combo.
+///
+/// Lets combat feedback call out the combo without knowing about every spell.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct ComboTriggered {
+    /// Name of the combo that triggered.
+    pub name: &'static str,
+    /// World position of the unit that was hit.
+    pub position: Vec3,
+    /// Extra damage the combo added on top of the base hit.
+    pub bonus_damage: f32,
+}
+
+/// Pushes a unit out of a rectangular footprint on the XZ plane.
+///
+/// The rectangle is centered at `center` with `half_length` along `forward` and
+/// `half_width` along `right`. The unit is pushed along the axis with the least
+/// penetration so it ends up touching the nearest edge.
+/// Returns the corrected position if the unit overlapped the rectangle.
+pub fn push_out_of_rectangle(
+    point: Vec3,
+    radius: f32,
+    center: Vec3,
+    forward: Vec3,
+    right: Vec3,
+    half_length: f32,
+    half_width: f32,
+) -> Option<Vec3> {
+    let diff = Vec3::new(point.x - center.x, 0.0, point.z - center.z);
+    let forward_proj = diff.dot(forward);
+    let right_proj = diff.dot(right);
+
+    let forward_pen = half_length + radius - forward_proj.abs();
+    let right_pen = half_width + radius - right_proj.abs();
+
+    if forward_pen <= 0.0 || right_pen <= 0.0 {
+        return None; // Not overlapping
+    }
+
+    // Push along axis with least penetration
+    let (axis, penetration, sign) = if forward_pen < right_pen {
+        (forward, forward_pen, forward_proj.signum())
+    } else {
+        (right, right_pen, right_proj.signum())
+    };
+
+    Some(Vec3::new(
+        point.x + axis.x * penetration * sign,
+        point.y,
+        point.z + axis.z * penetration * sign,
+    ))
+}
+
+/// Shoves a unit away from a blast center.
+///
+/// The push falls off linearly from `strength` at the center to nothing at
+/// `radius`, and the one-shot displacement is capped at
+/// `MAX_KNOCKBACK_DISPLACEMENT` so units never jump across the map. The unit
+/// also gains outward velocity; wall collision and separation resolve any
+/// overlaps on the next movement pass.
+pub fn apply_knockback(
+    origin: Vec3,
+    radius: f32,
+    strength: f32,
+    transform: &mut Transform,
+    velocity: &mut Velocity,
+) {
+    let offset = Vec3::new(
+        transform.translation.x - origin.x,
+        0.0,
+        transform.translation.z - origin.z,
+    );
+    let distance = offset.length();
+    if radius <= 0.0 || distance >= radius {
+        return;
+    }
+
+    let falloff = 1.0 - distance / radius;
+    let displacement = (offset.normalize_or_zero() * strength * falloff)
+        .clamp_length_max(MAX_KNOCKBACK_DISPLACEMENT);
+
+    transform.translation += displacement;
+    velocity.x += displacement.x * KNOCKBACK_VELOCITY_SCALE;
+    velocity.z += displacement.z * KNOCKBACK_VELOCITY_SCALE;
+}
+
+/// Returns the item with the smallest distance.
+///
+/// Items whose distance is NaN (e.g. a unit with a corrupted position) are
+/// skipped instead of panicking the comparison.
+pub fn nearest_by_distance<T>(
+    items: impl IntoIterator<Item = T>,
+    mut distance: impl FnMut(&T) -> f32,
+) -> Option<T> {
+    items
+        .into_iter()
+        .map(|item| (distance(&item), item))
+        .filter(|(dist, _)| !dist.is_nan())
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, item)| item)
 }
 
 /// Marker component for dead units (corpses).
@@ -263,6 +667,20 @@ pub struct Corpse;
 #[derive(Component)]
 pub struct PermanentCorpse;
 
+/// When a corpse fell, so the oldest can be cleared first once over the entity budget.
+#[derive(Component)]
+pub struct CorpseAge {
+    /// Game time of death (in seconds).
+    pub died_at: f32,
+}
+
+/// Marker component for units that died while corpses are turned off.
+///
+/// They are despawned at the end of the frame rather than on death, so systems
+/// reading that frame's `DamageDealt` messages can still look them up.
+#[derive(Component)]
+pub struct Vanishing;
+
 /// Marker component for units that can be teleported.
 ///
 /// Applied to all combat units (defenders, attackers, undead) but not the wizard.
@@ -315,14 +733,12 @@ impl Effectiveness {
     /// # Arguments
     /// * `ally_count` - Number of allies in melee range
     /// * `enemy_count` - Number of enemies in melee range
-    pub fn recalculate(&mut self, ally_count: i32, enemy_count: i32) {
-        use crate::game::constants::{
-            EFFECTIVENESS_ALLY_BONUS_PER_UNIT, EFFECTIVENESS_ENEMY_PENALTY_PER_UNIT,
-            EFFECTIVENESS_MAX, EFFECTIVENESS_MIN,
-        };
+    /// * `tuning` - Per-unit ally bonus and enemy penalty
+    pub fn recalculate(&mut self, ally_count: i32, enemy_count: i32, tuning: &TunableConstants) {
+        use crate::game::constants::{EFFECTIVENESS_MAX, EFFECTIVENESS_MIN};
 
-        let proximity_modifier = (ally_count as f32 * EFFECTIVENESS_ALLY_BONUS_PER_UNIT)
-            + (enemy_count as f32 * EFFECTIVENESS_ENEMY_PENALTY_PER_UNIT);
+        let proximity_modifier = (ally_count as f32 * tuning.effectiveness_ally_bonus)
+            + (enemy_count as f32 * tuning.effectiveness_enemy_penalty);
 
         self.current = (self.base + proximity_modifier + self.spell_bonus)
             .clamp(EFFECTIVENESS_MIN, EFFECTIVENESS_MAX);
@@ -343,11 +759,55 @@ impl Default for Effectiveness {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
+
     use crate::game::constants::{
         EFFECTIVENESS_ALLY_BONUS_PER_UNIT, EFFECTIVENESS_ENEMY_PENALTY_PER_UNIT, EFFECTIVENESS_MAX,
         EFFECTIVENESS_MIN,
     };
 
+    #[test]
+    fn test_spawn_offsets_stagger_the_first_attack() {
+        let timings: Vec<_> = (0..20)
+            .flat_map(|row| (0..10).map(move |col| (row as f32 * 30.0, col as f32 * 30.0)))
+            .map(|(x, z)| AttackTiming::with_offset(spawn_attack_offset(x, z)))
+            .collect();
+
+        for timing in &timings {
+            let offset = timing.last_attack_time.unwrap();
+            assert!((0.0..ATTACK_CYCLE_DURATION).contains(&offset));
+        }
+
+        // Only the few units whose offset lands in the first frame strike then
+        let first_frame = timings
+            .iter()
+            .filter(|timing| timing.can_attack(0.016, 0.0))
+            .count();
+        assert!(
+            first_frame < timings.len() / 10,
+            "{first_frame} attacked at once"
+        );
+    }
+
+    #[test]
+    fn test_spawn_animation_eases_to_full_size() {
+        let mut animation = SpawnAnimation::new();
+        assert_eq!(animation.scale(), 0.0);
+
+        animation
+            .timer
+            .tick(Duration::from_secs_f32(SPAWN_ANIMATION_DURATION / 2.0));
+        let halfway = animation.scale();
+        // Eases out, so the sprite is past half size halfway through
+        assert!(halfway > 0.5 && halfway < 1.0);
+
+        animation
+            .timer
+            .tick(Duration::from_secs_f32(SPAWN_ANIMATION_DURATION));
+        assert_eq!(animation.scale(), 1.0);
+        assert!(animation.timer.is_finished());
+    }
+
     #[test]
     fn test_effectiveness_base_values() {
         let eff = Effectiveness::new();
@@ -367,14 +827,14 @@ mod tests {
     #[test]
     fn test_effectiveness_ally_bonus() {
         let mut eff = Effectiveness::new();
-        eff.recalculate(3, 0); // 3 allies, 0 enemies
+        eff.recalculate(3, 0, &TunableConstants::default()); // 3 allies, 0 enemies
         assert_eq!(eff.current, 1.0 + 3.0 * EFFECTIVENESS_ALLY_BONUS_PER_UNIT);
     }
 
     #[test]
     fn test_effectiveness_enemy_penalty() {
         let mut eff = Effectiveness::new();
-        eff.recalculate(0, 2); // 0 allies, 2 enemies
+        eff.recalculate(0, 2, &TunableConstants::default()); // 0 allies, 2 enemies
         assert_eq!(
             eff.current,
             1.0 + 2.0 * EFFECTIVENESS_ENEMY_PENALTY_PER_UNIT
@@ -384,7 +844,7 @@ mod tests {
     #[test]
     fn test_effectiveness_mixed() {
         let mut eff = Effectiveness::new();
-        eff.recalculate(2, 1); // 2 allies, 1 enemy
+        eff.recalculate(2, 1, &TunableConstants::default()); // 2 allies, 1 enemy
         let expected = 1.0
             + 2.0 * EFFECTIVENESS_ALLY_BONUS_PER_UNIT
             + 1.0 * EFFECTIVENESS_ENEMY_PENALTY_PER_UNIT;
@@ -394,14 +854,14 @@ mod tests {
     #[test]
     fn test_effectiveness_clamping_min() {
         let mut eff = Effectiveness::new();
-        eff.recalculate(0, 10); // Many enemies
+        eff.recalculate(0, 10, &TunableConstants::default()); // Many enemies
         assert_eq!(eff.current, EFFECTIVENESS_MIN);
     }
 
     #[test]
     fn test_effectiveness_clamping_max() {
         let mut eff = Effectiveness::new();
-        eff.recalculate(20, 0); // Many allies
+        eff.recalculate(20, 0, &TunableConstants::default()); // Many allies
         assert_eq!(eff.current, EFFECTIVENESS_MAX);
     }
 
@@ -409,16 +869,186 @@ mod tests {
     fn test_effectiveness_with_spell_bonus() {
         let mut eff = Effectiveness::new();
         eff.spell_bonus = 0.5;
-        eff.recalculate(0, 0); // No proximity modifiers
+        eff.recalculate(0, 0, &TunableConstants::default()); // No proximity modifiers
         assert_eq!(eff.current, 1.0 + 0.5);
     }
 
     #[test]
     fn test_effectiveness_multiplier() {
         let mut eff = Effectiveness::new();
-        eff.recalculate(2, 1);
+        eff.recalculate(2, 1, &TunableConstants::default());
         assert_eq!(eff.multiplier(), eff.current);
     }
+
+    #[test]
+    fn test_snare_slows_movement() {
+        let snare = Snare::new(0.5, 3.0);
+        let normal = movement_speed_multiplier(None, None, None);
+        let snared = movement_speed_multiplier(None, None, Some(&snare));
+        assert_eq!(normal, 1.0);
+        assert_eq!(snared, 0.5);
+        assert!(snared < normal);
+    }
+
+    #[test]
+    fn test_snare_stacks_with_modifiers() {
+        let snare = Snare::new(0.5, 3.0);
+        let aura = KingAuraSpeedModifier(0.25);
+        let terrain = RoughTerrainModifier(-0.6);
+        let multiplier = movement_speed_multiplier(Some(&aura), Some(&terrain), Some(&snare));
+        assert!((multiplier - (1.0 + 0.25 - 0.6) * 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_snare_expires() {
+        let mut snare = Snare::new(0.5, 1.0);
+        assert!(!snare.update(0.5));
+        assert!(snare.update(0.6));
+    }
+
+    #[test]
+    fn test_nearest_by_distance_picks_closest() {
+        let origin = Vec3::ZERO;
+        let positions = [
+            Vec3::new(30.0, 0.0, 0.0),
+            Vec3::new(10.0, 0.0, 0.0),
+            Vec3::new(20.0, 0.0, 0.0),
+        ];
+        let nearest = nearest_by_distance(positions, |pos| origin.distance(*pos));
+        assert_eq!(nearest, Some(Vec3::new(10.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_nearest_by_distance_skips_nan_position() {
+        let origin = Vec3::ZERO;
+        let positions = [
+            Vec3::new(f32::NAN, 0.0, 0.0),
+            Vec3::new(20.0, 0.0, 0.0),
+            Vec3::new(10.0, 0.0, f32::NAN),
+        ];
+        let nearest = nearest_by_distance(positions, |pos| origin.distance(*pos));
+        assert_eq!(nearest, Some(Vec3::new(20.0, 0.0, 0.0)));
+
+        let only_nan = [Vec3::NAN];
+        assert_eq!(
+            nearest_by_distance(only_nan, |pos| origin.distance(*pos)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fire_on_snared_unit_shatters() {
+        let snare = Snare::new(0.5, 3.0);
+        let effects = StatusEffect::active(Some(&snare));
+
+        let (damage, combo) = apply_combo(20.0, DamageType::Fire, &effects);
+
+        assert_eq!(combo.map(|combo| combo.name), Some("Shatter"));
+        assert!(damage > 20.0);
+        assert_eq!(damage, 20.0 * combo.unwrap().multiplier);
+    }
+
+    #[test]
+    fn test_fire_on_unaffected_unit_has_no_combo() {
+        let effects = StatusEffect::active(None);
+
+        let (damage, combo) = apply_combo(20.0, DamageType::Fire, &effects);
+
+        assert!(combo.is_none());
+        assert_eq!(damage, 20.0);
+    }
+
+    #[test]
+    fn test_unit_near_blast_gains_outward_velocity() {
+        let origin = Vec3::ZERO;
+        let mut transform = Transform::from_xyz(30.0, 21.0, 0.0);
+        let mut velocity = Velocity::default();
+
+        apply_knockback(origin, 100.0, 50.0, &mut transform, &mut velocity);
+
+        assert!(velocity.x > 0.0);
+        assert_eq!(velocity.z, 0.0);
+        assert!(transform.translation.x > 30.0);
+        // Height is untouched
+        assert_eq!(transform.translation.y, 21.0);
+    }
+
+    #[test]
+    fn test_knockback_falls_off_and_is_clamped() {
+        let push = |x: f32| {
+            let mut transform = Transform::from_xyz(x, 0.0, 0.0);
+            apply_knockback(
+                Vec3::ZERO,
+                100.0,
+                1000.0,
+                &mut transform,
+                &mut Velocity::default(),
+            );
+            transform.translation.x - x
+        };
+
+        // A huge strength never moves a unit further than the cap
+        assert_eq!(push(1.0), MAX_KNOCKBACK_DISPLACEMENT);
+        // Units outside the blast are untouched
+        assert_eq!(push(150.0), 0.0);
+
+        let mut near = Transform::from_xyz(10.0, 0.0, 0.0);
+        let mut far = Transform::from_xyz(90.0, 0.0, 0.0);
+        apply_knockback(Vec3::ZERO, 100.0, 30.0, &mut near, &mut Velocity::default());
+        apply_knockback(Vec3::ZERO, 100.0, 30.0, &mut far, &mut Velocity::default());
+        assert!(near.translation.x - 10.0 > far.translation.x - 90.0);
+    }
+
+    #[test]
+    fn test_resistances_scale_by_damage_type() {
+        let king = Resistances {
+            physical: 0.5,
+            magic: -0.5,
+        };
+
+        assert_eq!(king.scale(20.0, DamageType::Physical), 10.0);
+        assert_eq!(king.scale(20.0, DamageType::Magic), 30.0);
+        assert_eq!(king.scale(20.0, DamageType::Fire), 30.0);
+        assert_eq!(king.scale(20.0, DamageType::True), 20.0);
+        // Resistance past immunity never heals
+        let immune = Resistances {
+            physical: 2.0,
+            magic: 0.0,
+        };
+        assert_eq!(immune.scale(20.0, DamageType::Physical), 0.0);
+    }
+
+    #[test]
+    fn test_missing_resistances_take_full_damage() {
+        let mut health = Health::new(100.0);
+        let dealt = apply_damage_to_unit(&mut health, None, None, 20.0, DamageType::Magic);
+
+        assert_eq!(dealt, 20.0);
+        assert_eq!(health.current, 80.0);
+    }
+
+    #[test]
+    fn test_resistance_applies_before_temporary_hit_points() {
+        let mut health = Health::new(100.0);
+        let mut temp_hp = TemporaryHitPoints::new(10.0, 5.0);
+        let resistances = Resistances {
+            physical: 0.5,
+            magic: 0.0,
+        };
+
+        // 40 physical is halved to 20, then 10 is absorbed by temp HP
+        let dealt = apply_damage_to_unit(
+            &mut health,
+            Some(&mut temp_hp),
+            Some(&resistances),
+            40.0,
+            DamageType::Physical,
+        );
+
+        assert_eq!(dealt, 20.0);
+        assert_eq!(temp_hp.amount, 0.0);
+        assert_eq!(health.current, 90.0);
+    }
 }
 
 /// Component indicating a unit is currently engaged in melee combat with a specific team.
@@ -464,6 +1094,46 @@ impl FlockingModifier {
 #[derive(Component)]
 pub struct KingsGuard(pub u32);
 
+/// Recent positions of a unit packed in among its neighbors.
+///
+/// Added while a unit is surrounded so units locked in place by overlap
+/// corrections can be spotted and nudged free. Removed once it has room.
+#[derive(Component, Default)]
+pub struct PositionHistory {
+    samples: [Vec3; JITTER_HISTORY_FRAMES],
+    recorded: usize,
+}
+
+impl PositionHistory {
+    /// Records this frame's position, overwriting the oldest sample once full.
+    pub fn record(&mut self, position: Vec3) {
+        self.samples[self.recorded % JITTER_HISTORY_FRAMES] = position;
+        self.recorded += 1;
+    }
+
+    /// Returns the size of the area the recorded positions span on the XZ plane,
+    /// or None until a full history has been recorded.
+    pub fn spread(&self) -> Option<f32> {
+        if self.recorded < JITTER_HISTORY_FRAMES {
+            return None;
+        }
+
+        let (min, max) = self.samples.iter().fold(
+            (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+            |(min, max), sample| {
+                let point = Vec2::new(sample.x, sample.z);
+                (min.min(point), max.max(point))
+            },
+        );
+        Some((max - min).length())
+    }
+
+    /// Forgets the recorded positions so the unit gets a fresh window after a nudge.
+    pub fn clear(&mut self) {
+        self.recorded = 0;
+    }
+}
+
 /// Flocking velocity from separation, alignment, and cohesion forces.
 ///
 /// The flocking system calculates this based on nearby allies.
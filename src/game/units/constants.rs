@@ -1,3 +1,25 @@
 //! Shared unit constants.
 //!
 //! Contains constants used across multiple unit types.
+
+use super::components::{DamageType, SpellCombo, StatusEffect};
+
+/// Time for a newly spawned unit's sprite to grow to full size (in seconds).
+pub const SPAWN_ANIMATION_DURATION: f32 = 0.3;
+
+/// Health fraction below which a unit goes berserk.
+pub const BERSERK_HEALTH_THRESHOLD: f32 = 0.25;
+
+/// Damage bonus while berserk (+40%), stacking with other damage bonuses.
+pub const BERSERK_DAMAGE_PERCENTAGE: f32 = 0.4;
+
+/// Spell combos: hits of a damage type on a unit with a status effect deal bonus damage.
+pub const SPELL_COMBOS: &[SpellCombo] = &[
+    // Frozen (snared) units shatter under fire impacts
+    SpellCombo {
+        name: "Shatter",
+        effect: StatusEffect::Snared,
+        damage_type: DamageType::Fire,
+        multiplier: 1.5,
+    },
+];
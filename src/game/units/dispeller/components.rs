@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// Attacker that heads for the wizard and burns its mana instead of fighting for ground.
+#[derive(Component)]
+pub struct Dispeller;
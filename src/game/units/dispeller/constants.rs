@@ -0,0 +1,8 @@
+// Health
+pub const DISPELLER_HEALTH: f32 = 40.0; // Sturdier than a healer (30), weaker than infantry (50)
+
+// Mana drain
+pub const DISPELLER_MANA_DRAIN: f32 = 15.0; // Mana burned per hit on the wizard
+
+// Positioning
+pub const DISPELLER_HOLD_DISTANCE: f32 = 250.0; // Stops steering once this close to the wizard (ground plane)
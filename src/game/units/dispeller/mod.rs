@@ -0,0 +1,12 @@
+//! Dispeller plugin module.
+//!
+//! Handles attacker dispellers, rare late-game units that march on the wizard
+//! and drain mana with every hit that reaches it.
+
+pub mod components;
+pub mod constants;
+mod plugin;
+pub mod styles;
+pub mod systems;
+
+pub use plugin::DispellerPlugin;
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+use crate::game::run_conditions;
+use crate::state::{AppState, InGameState};
+
+use super::systems;
+
+/// Plugin that handles attacker dispellers.
+///
+/// Registers systems for:
+/// - Spawning dispellers on game start from the level where they appear
+///   (campaign mode only)
+/// - Re-spawn when entering Running state from GameOver (for replay)
+/// - Steering dispellers toward the wizard
+///
+/// Their mana drain runs with the rest of combat.
+pub struct DispellerPlugin;
+
+impl Plugin for DispellerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::InGame),
+            systems::spawn_dispellers
+                .run_if(run_conditions::in_campaign_mode)
+                .run_if(not(run_conditions::has_defined_wave)),
+        )
+        .add_systems(
+            OnEnter(InGameState::Running),
+            systems::spawn_dispellers
+                .run_if(run_conditions::in_campaign_mode)
+                .run_if(not(run_conditions::has_defined_wave))
+                .run_if(run_conditions::coming_from_game_over),
+        )
+        .add_systems(
+            FixedUpdate,
+            systems::update_dispeller_targeting.in_set(crate::game::plugin::VelocitySystemSet),
+        );
+    }
+}
@@ -0,0 +1,4 @@
+use bevy::prelude::*;
+
+pub const DISPELLER_COLOR: Color = Color::srgb(0.3, 0.85, 0.95); // Pale cyan, the color of unravelled magic
+pub const DISPELLER_RADIUS: f32 = 8.0; // Same size as infantry
@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::*;
+use super::styles::*;
+use crate::game::battlefield::components::BattlefieldConfig;
+use crate::game::components::{Acceleration, Billboard, OnGameplayScreen, Velocity};
+use crate::game::constants::{
+    calculate_spawn_cells, calculate_total_archers, calculate_total_dispellers,
+    calculate_total_infantry, cells_needed, *,
+};
+use crate::game::resources::CurrentLevel;
+use crate::game::units::components::{
+    AttackTiming, Corpse, Effectiveness, FlockingVelocity, Health, Hitbox, ManaDrain,
+    MovementSpeed, SpawnAnimation, TargetingVelocity, Team, Teleportable, spawn_attack_offset,
+};
+
+/// Spawns attacker dispellers based on level.
+///
+/// None before `DISPELLER_MIN_LEVEL`, then one more every `LEVELS_PER_DISPELLER`
+/// levels. Dispellers spawn among the infantry in the front rows of the formation grid.
+pub fn spawn_dispellers(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    current_level: Res<CurrentLevel>,
+    battlefield: Res<BattlefieldConfig>,
+) {
+    let level = current_level.0;
+
+    let total_dispellers = calculate_total_dispellers(level);
+    let num_infantry_cells = cells_needed(calculate_total_infantry(level));
+    let num_archer_cells = cells_needed(calculate_total_archers(level));
+    let (infantry_cells, _) = calculate_spawn_cells(num_infantry_cells, num_archer_cells);
+    if infantry_cells.is_empty() {
+        return;
+    }
+
+    // Spread dispellers across the front cells, one per cell before doubling up
+    for i in 0..total_dispellers as usize {
+        let (row, col) = infantry_cells[i % infantry_cells.len()];
+        let (spawn_x, spawn_z) = battlefield.grid_cell_position(row, col);
+
+        let offset = spawn_offset((i / infantry_cells.len()) as u32);
+        spawn_dispeller(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            spawn_x + offset.x,
+            spawn_z + offset.y,
+        );
+    }
+}
+
+/// Spawns a single attacker dispeller at the given ground position.
+///
+/// Dispellers fight like infantry when enemies block their way, and drain
+/// `DISPELLER_MANA_DRAIN` mana per hit once the wizard is in reach. They move
+/// with the infantry movement system. Returns the spawned entity.
+pub fn spawn_dispeller(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    x: f32,
+    z: f32,
+) -> Entity {
+    let hitbox = Hitbox::new(DISPELLER_RADIUS, ATTACKER_HITBOX_HEIGHT);
+    let circle = Circle::new(hitbox.radius);
+
+    // Position unit so bottom edge is 1 unit above battlefield (Y=0)
+    let spawn_y = hitbox.height / 2.0 + 1.0;
+
+    commands
+        .spawn((
+            Mesh3d(meshes.add(circle)),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: DISPELLER_COLOR,
+                unlit: true,
+                ..default()
+            })),
+            Transform::from_xyz(x, spawn_y, z),
+            Velocity::default(),
+            Acceleration::new(),
+            hitbox,
+            Health::new(DISPELLER_HEALTH),
+            MovementSpeed(UNIT_MOVEMENT_SPEED),
+            AttackTiming::with_offset(spawn_attack_offset(x, z)),
+            Effectiveness::new(),
+            Team::Attackers,
+            Dispeller,
+        ))
+        .insert((
+            ManaDrain {
+                amount: DISPELLER_MANA_DRAIN,
+            },
+            TargetingVelocity::default(),
+            FlockingVelocity::default(),
+            Teleportable,
+            SpawnAnimation::new(),
+            Billboard,
+            OnGameplayScreen,
+        ))
+        .id()
+}
+
+/// Steers dispellers straight for the wizard, stopping at `DISPELLER_HOLD_DISTANCE`.
+///
+/// Distance is measured on the ground plane, since the wizard stands on the castle.
+pub fn update_dispeller_targeting(
+    mut dispellers: Query<(&Transform, &mut TargetingVelocity), (With<Dispeller>, Without<Corpse>)>,
+) {
+    for (transform, mut targeting_velocity) in &mut dispellers {
+        let position = transform.translation;
+        let offset = Vec3::new(
+            WIZARD_POSITION.x - position.x,
+            0.0,
+            WIZARD_POSITION.z - position.z,
+        );
+        targeting_velocity.distance_to_target = offset.length();
+        targeting_velocity.velocity = if offset.length() > DISPELLER_HOLD_DISTANCE {
+            offset.normalize_or_zero()
+        } else {
+            Vec3::ZERO
+        };
+    }
+}
@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+
+/// Attacker support unit that heals wounded allies on a timer.
+#[derive(Component)]
+pub struct Healer {
+    /// Seconds until the next healing pulse.
+    pub pulse_timer: f32,
+}
+
+impl Healer {
+    /// Creates a healer whose first pulse is ready immediately.
+    pub const fn new() -> Self {
+        Self { pulse_timer: 0.0 }
+    }
+
+    /// Advances the pulse timer. Returns true and restarts it when a pulse is due.
+    pub fn tick(&mut self, delta: f32, interval: f32) -> bool {
+        self.pulse_timer -= delta;
+        if self.pulse_timer <= 0.0 {
+            self.pulse_timer = interval;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns the spot a healer steers toward: `standoff` units behind `anchor`
+/// on the side away from `threat`.
+///
+/// Keeping an ally between the healer and the enemy keeps it off the front line.
+pub fn behind_ally(anchor: Vec3, threat: Vec3, standoff: f32) -> Vec3 {
+    let away = Vec3::new(anchor.x - threat.x, 0.0, anchor.z - threat.z).normalize_or_zero();
+    Vec3::new(anchor.x, 0.0, anchor.z) + away * standoff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_healer_keeps_ally_between_itself_and_threat() {
+        let ally = Vec3::new(500.0, 0.0, 0.0);
+        let threat = Vec3::ZERO;
+
+        let spot = behind_ally(ally, threat, 100.0);
+
+        assert!(spot.distance(threat) > ally.distance(threat));
+        assert!((spot.distance(ally) - 100.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,11 @@
+// Health
+pub const HEALER_HEALTH: f32 = 30.0; // Fragile compared to infantry (50)
+
+// Healing pulse
+pub const HEAL_AMOUNT: f32 = 15.0; // Health restored per pulse
+pub const HEAL_INTERVAL: f32 = 3.0; // Seconds between pulses
+pub const HEAL_RADIUS: f32 = 250.0; // Reach of a pulse
+
+// Positioning
+pub const HEALER_STANDOFF: f32 = 120.0; // How far behind its nearest ally a healer stays
+pub const HEALER_ARRIVAL_RADIUS: f32 = 20.0; // Stops steering once this close to its spot
@@ -0,0 +1,12 @@
+//! Healer plugin module.
+//!
+//! Handles attacker healers, fragile support units that keep behind their
+//! allies and periodically restore the most wounded attacker nearby.
+
+pub mod components;
+pub mod constants;
+mod plugin;
+pub mod styles;
+pub mod systems;
+
+pub use plugin::HealerPlugin;
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+use crate::game::run_conditions;
+use crate::state::{AppState, InGameState};
+
+use super::systems;
+
+/// Plugin that handles attacker healers.
+///
+/// Registers systems for:
+/// - Spawning healers on game start from the level where they appear
+///   (campaign mode only)
+/// - Re-spawn when entering Running state from GameOver (for replay)
+/// - Steering healers behind their allies
+/// - Healing pulses on a timer
+pub struct HealerPlugin;
+
+impl Plugin for HealerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::InGame),
+            systems::spawn_attacker_healers
+                .run_if(run_conditions::in_campaign_mode)
+                .run_if(not(run_conditions::has_defined_wave)),
+        )
+        .add_systems(
+            OnEnter(InGameState::Running),
+            systems::spawn_attacker_healers
+                .run_if(run_conditions::in_campaign_mode)
+                .run_if(not(run_conditions::has_defined_wave))
+                .run_if(run_conditions::coming_from_game_over),
+        )
+        .add_systems(
+            FixedUpdate,
+            systems::update_healer_targeting.in_set(crate::game::plugin::VelocitySystemSet),
+        )
+        .add_systems(
+            FixedUpdate,
+            systems::heal_wounded_attackers
+                .run_if(in_state(InGameState::Running))
+                .run_if(run_conditions::combat_started),
+        );
+    }
+}
@@ -0,0 +1,4 @@
+use bevy::prelude::*;
+
+pub const ATTACKER_HEALER_COLOR: Color = Color::srgb(0.95, 0.45, 0.75); // Pink, stands out as a priority target
+pub const HEALER_RADIUS: f32 = 7.0; // Slightly smaller than infantry
@@ -0,0 +1,259 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::*;
+use super::styles::*;
+use crate::game::battlefield::components::BattlefieldConfig;
+use crate::game::components::{Acceleration, Billboard, OnGameplayScreen, Velocity};
+use crate::game::constants::{
+    calculate_spawn_cells, calculate_total_archers, calculate_total_healers,
+    calculate_total_infantry, cells_needed, *,
+};
+use crate::game::resources::CurrentLevel;
+use crate::game::units::components::{
+    Corpse, Effectiveness, FlockingVelocity, Health, Hitbox, MovementSpeed, SpawnAnimation,
+    TargetingVelocity, Team, Teleportable, nearest_by_distance,
+};
+
+/// Spawns attacker healers based on level.
+///
+/// None before `HEALER_MIN_LEVEL`, then one more every `LEVELS_PER_HEALER` levels.
+/// Healers spawn among the archers in the back rows of the formation grid.
+pub fn spawn_attacker_healers(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    current_level: Res<CurrentLevel>,
+    battlefield: Res<BattlefieldConfig>,
+) {
+    let level = current_level.0;
+
+    let total_healers = calculate_total_healers(level);
+    let num_infantry_cells = cells_needed(calculate_total_infantry(level));
+    let num_archer_cells = cells_needed(calculate_total_archers(level));
+    let (_, archer_cells) = calculate_spawn_cells(num_infantry_cells, num_archer_cells);
+    if archer_cells.is_empty() {
+        return;
+    }
+
+    // Spread healers across the back cells, one per cell before doubling up
+    for i in 0..total_healers as usize {
+        let (row, col) = archer_cells[i % archer_cells.len()];
+        let (spawn_x, spawn_z) = battlefield.grid_cell_position(row, col);
+
+        let offset = spawn_offset((i / archer_cells.len()) as u32);
+        spawn_attacker_healer(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            spawn_x + offset.x,
+            spawn_z + offset.y,
+        );
+    }
+}
+
+/// Spawns a single attacker healer at the given ground position.
+///
+/// Healers have no `AttackTiming`, so they never fight. They move with the
+/// infantry movement system. Returns the spawned entity.
+pub fn spawn_attacker_healer(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    x: f32,
+    z: f32,
+) -> Entity {
+    let hitbox = Hitbox::new(HEALER_RADIUS, ATTACKER_HITBOX_HEIGHT);
+    let circle = Circle::new(hitbox.radius);
+
+    // Position unit so bottom edge is 1 unit above battlefield (Y=0)
+    let spawn_y = hitbox.height / 2.0 + 1.0;
+
+    commands
+        .spawn((
+            Mesh3d(meshes.add(circle)),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: ATTACKER_HEALER_COLOR,
+                unlit: true,
+                ..default()
+            })),
+            Transform::from_xyz(x, spawn_y, z),
+            Velocity::default(),
+            Acceleration::new(),
+            hitbox,
+            Health::new(HEALER_HEALTH),
+            MovementSpeed(UNIT_MOVEMENT_SPEED),
+            Effectiveness::new(),
+            Team::Attackers,
+            Healer::new(),
+        ))
+        .insert((
+            TargetingVelocity::default(),
+            FlockingVelocity::default(),
+            Teleportable,
+            SpawnAnimation::new(),
+            Billboard,
+            OnGameplayScreen,
+        ))
+        .id()
+}
+
+/// Steers healers to a spot behind their nearest ally, away from the enemy.
+///
+/// Healers with no allies left walk toward the castle like any other attacker.
+pub fn update_healer_targeting(
+    mut healers: Query<(&Transform, &mut TargetingVelocity), (With<Healer>, Without<Corpse>)>,
+    all_units: Query<(&Transform, &Team, Has<Healer>), Without<Corpse>>,
+) {
+    let allies: Vec<Vec3> = all_units
+        .iter()
+        .filter(|(_, team, is_healer)| **team == Team::Attackers && !is_healer)
+        .map(|(transform, _, _)| transform.translation)
+        .collect();
+    let enemies: Vec<Vec3> = all_units
+        .iter()
+        .filter(|(_, team, _)| **team != Team::Attackers)
+        .map(|(transform, _, _)| transform.translation)
+        .collect();
+
+    for (transform, mut targeting_velocity) in &mut healers {
+        let position = transform.translation;
+        let distance_2d =
+            |other: &&Vec3| (position.x - other.x).powi(2) + (position.z - other.z).powi(2);
+
+        let spot = match nearest_by_distance(allies.iter(), distance_2d) {
+            Some(&anchor) => {
+                let threat = nearest_by_distance(enemies.iter(), |enemy| {
+                    (anchor.x - enemy.x).powi(2) + (anchor.z - enemy.z).powi(2)
+                })
+                .copied()
+                .unwrap_or(CASTLE_POSITION);
+                behind_ally(anchor, threat, HEALER_STANDOFF)
+            }
+            None => CASTLE_POSITION,
+        };
+
+        let offset = Vec3::new(spot.x - position.x, 0.0, spot.z - position.z);
+        targeting_velocity.distance_to_target = offset.length();
+        targeting_velocity.velocity = if offset.length() > HEALER_ARRIVAL_RADIUS {
+            offset.normalize_or_zero()
+        } else {
+            Vec3::ZERO
+        };
+    }
+}
+
+/// Heals the most wounded attacker within reach of each healer on a timer.
+///
+/// A healer never heals itself, so it stays a fragile priority target, and
+/// units at 0 health that haven't turned into corpses yet can't be healed back.
+pub fn heal_wounded_attackers(
+    time: Res<Time>,
+    mut healers: Query<(Entity, &Transform, &mut Healer), Without<Corpse>>,
+    mut patients: Query<(Entity, &Transform, &Team, &mut Health), Without<Corpse>>,
+) {
+    let delta = time.delta_secs();
+
+    for (healer_entity, healer_transform, mut healer) in &mut healers {
+        if !healer.tick(delta, HEAL_INTERVAL) {
+            continue;
+        }
+
+        let healer_pos = healer_transform.translation;
+        let most_wounded = patients
+            .iter()
+            .filter(|(entity, transform, team, health)| {
+                *entity != healer_entity
+                    && **team == Team::Attackers
+                    && !health.is_dead()
+                    && health.current < health.max
+                    && transform.translation.distance(healer_pos) <= HEAL_RADIUS
+            })
+            .max_by(|(_, _, _, a), (_, _, _, b)| {
+                (a.max - a.current).total_cmp(&(b.max - b.current))
+            })
+            .map(|(entity, _, _, _)| entity);
+
+        if let Some(entity) = most_wounded
+            && let Ok((_, _, _, mut health)) = patients.get_mut(entity)
+        {
+            health.heal(HEAL_AMOUNT);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wounded(team: Team, position: Vec3, current: f32) -> (Team, Transform, Health) {
+        let mut health = Health::new(UNIT_HEALTH);
+        health.current = current;
+        (team, Transform::from_translation(position), health)
+    }
+
+    #[test]
+    fn test_healer_restores_nearby_attacker_up_to_max() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, heal_wounded_attackers);
+
+        app.world_mut().spawn((
+            Healer::new(),
+            Team::Attackers,
+            Transform::default(),
+            Health::new(HEALER_HEALTH),
+        ));
+        let nearby = app
+            .world_mut()
+            .spawn(wounded(
+                Team::Attackers,
+                Vec3::new(50.0, 0.0, 0.0),
+                UNIT_HEALTH - HEAL_AMOUNT / 2.0,
+            ))
+            .id();
+        let distant = app
+            .world_mut()
+            .spawn(wounded(
+                Team::Attackers,
+                Vec3::new(HEAL_RADIUS * 2.0, 0.0, 0.0),
+                1.0,
+            ))
+            .id();
+        let defender = app
+            .world_mut()
+            .spawn(wounded(Team::Defenders, Vec3::new(0.0, 0.0, 50.0), 1.0))
+            .id();
+
+        app.update();
+
+        let health = |entity| app.world().get::<Health>(entity).unwrap().current;
+        // Healed, but not past max
+        assert_eq!(health(nearby), UNIT_HEALTH);
+        assert_eq!(health(distant), 1.0);
+        assert_eq!(health(defender), 1.0);
+    }
+
+    #[test]
+    fn test_healer_does_not_revive_dead_attacker() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, heal_wounded_attackers);
+
+        app.world_mut().spawn((
+            Healer::new(),
+            Team::Attackers,
+            Transform::default(),
+            Health::new(HEALER_HEALTH),
+        ));
+        // Killed this frame, not yet a corpse
+        let dead = app
+            .world_mut()
+            .spawn(wounded(Team::Attackers, Vec3::new(50.0, 0.0, 0.0), 0.0))
+            .id();
+
+        app.update();
+
+        assert!(app.world().get::<Health>(dead).unwrap().is_dead());
+    }
+}
@@ -4,6 +4,33 @@ use bevy::prelude::*;
 #[derive(Component)]
 pub struct Infantry;
 
+/// Marker for attacker infantry whose group flanks the castle.
+///
+/// Assigned per spawn group. Flankers swing around the side of the castle with
+/// the fewest defenders until an enemy comes within engagement range.
+#[derive(Component)]
+pub struct Flanker;
+
+/// Attacker infantry advancing with its spawn group as a shield wall.
+///
+/// Members flock with stronger alignment and cohesion and all move at the pace
+/// of the group's slowest member. Removed from the whole group, along with its
+/// `FlockingModifier`, once any member closes within `FORMATION_BREAK_DISTANCE`
+/// of its target.
+#[derive(Component)]
+pub struct Formation {
+    /// Spawn group id shared by every member.
+    pub group: u32,
+    /// Speed multiplier of the group's slowest member, applied to every member.
+    pub pace: f32,
+}
+
+impl Formation {
+    pub const fn new(group: u32) -> Self {
+        Self { group, pace: 1.0 }
+    }
+}
+
 /// Resource tracking whether defenders should be active.
 ///
 /// Defenders share activation - once any attacker gets within range,
@@ -20,3 +47,79 @@ impl Default for DefendersActivated {
         }
     }
 }
+
+/// Returns the normalized XZ direction a flanker should steer in.
+///
+/// Defenders between `position` and `target` are split by which side of that
+/// line they stand on. The flanker aims `offset` units to the side of `target`
+/// that has fewer of them.
+pub fn flank_direction(position: Vec3, target: Vec3, defenders: &[Vec3], offset: f32) -> Vec3 {
+    let to_target = Vec3::new(target.x - position.x, 0.0, target.z - position.z);
+    let distance = to_target.length();
+    let forward = to_target.normalize_or_zero();
+    // Perpendicular to forward in the XZ plane
+    let side = Vec3::new(-forward.z, 0.0, forward.x);
+
+    let mut positive_side = 0;
+    let mut negative_side = 0;
+    for defender in defenders {
+        let relative = Vec3::new(defender.x - position.x, 0.0, defender.z - position.z);
+        let along = relative.dot(forward);
+        if along < 0.0 || along > distance {
+            continue;
+        }
+        if relative.dot(side) >= 0.0 {
+            positive_side += 1;
+        } else {
+            negative_side += 1;
+        }
+    }
+
+    let open_side = if positive_side <= negative_side {
+        side
+    } else {
+        -side
+    };
+    let waypoint = Vec3::new(target.x, 0.0, target.z) + open_side * offset;
+    Vec3::new(waypoint.x - position.x, 0.0, waypoint.z - position.z).normalize_or_zero()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flankers_avoid_defender_heavy_center_and_side() {
+        let position = Vec3::new(2000.0, 0.0, 0.0);
+        let target = Vec3::ZERO;
+
+        // Defenders hold the direct line and the +Z side; -Z is open
+        let mut defenders: Vec<Vec3> = (0..20)
+            .map(|i| Vec3::new(500.0 + i as f32 * 10.0, 0.0, 5.0 + (i % 3) as f32))
+            .collect();
+        defenders.extend((0..10).map(|i| Vec3::new(800.0, 0.0, 200.0 + i as f32 * 20.0)));
+        defenders.push(Vec3::new(900.0, 0.0, -150.0));
+
+        let direction = flank_direction(position, target, &defenders, 800.0);
+
+        assert!(direction.z < 0.0, "should steer toward the open -Z flank");
+        assert!(direction.x < 0.0, "should still advance toward the target");
+        assert!((direction.length() - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_defenders_behind_the_flanker_are_ignored() {
+        let position = Vec3::new(2000.0, 0.0, 0.0);
+        let target = Vec3::ZERO;
+
+        // The only defenders on -Z stand behind the flanker
+        let defenders = vec![
+            Vec3::new(1000.0, 0.0, 100.0),
+            Vec3::new(3000.0, 0.0, -100.0),
+            Vec3::new(3000.0, 0.0, -200.0),
+        ];
+
+        let direction = flank_direction(position, target, &defenders, 800.0);
+        assert!(direction.z < 0.0);
+    }
+}
@@ -4,7 +4,7 @@
 
 pub mod components;
 mod plugin;
-mod styles;
+pub mod styles;
 pub mod systems;
 
 pub use plugin::InfantryPlugin;
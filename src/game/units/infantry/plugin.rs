@@ -9,9 +9,11 @@ use super::systems;
 /// Plugin that handles infantry units (both defenders and attackers).
 ///
 /// Registers systems for:
-/// - Initial spawn of defenders and attackers on game start
+/// - Initial spawn of defenders and attackers on game start (endless mode
+///   spawns its own attacker waves)
 /// - Re-spawn when entering Running state from GameOver (for replay)
 /// - Updating defender and attacker targeting
+/// - Holding attacker shield wall formations together until contact
 /// - Shared activation system for defenders
 pub struct InfantryPlugin;
 
@@ -22,7 +24,9 @@ impl Plugin for InfantryPlugin {
                 OnEnter(AppState::InGame),
                 (
                     systems::spawn_initial_defenders,
-                    systems::spawn_initial_attackers,
+                    systems::spawn_initial_attackers
+                        .run_if(run_conditions::in_campaign_mode)
+                        .run_if(not(run_conditions::has_defined_wave)),
                     systems::spawn_kings_guard,
                 ),
             )
@@ -30,17 +34,26 @@ impl Plugin for InfantryPlugin {
                 OnEnter(InGameState::Running),
                 (
                     systems::spawn_initial_defenders,
-                    systems::spawn_initial_attackers,
+                    systems::spawn_initial_attackers
+                        .run_if(run_conditions::in_campaign_mode)
+                        .run_if(not(run_conditions::has_defined_wave)),
                     systems::spawn_kings_guard,
                 )
                     .run_if(run_conditions::coming_from_game_over),
             )
             .add_systems(
-                Update,
+                FixedUpdate,
                 systems::update_infantry_targeting.in_set(crate::game::plugin::VelocitySystemSet),
             )
             .add_systems(
-                Update,
+                FixedUpdate,
+                systems::update_formations
+                    .run_if(in_state(InGameState::Running))
+                    .after(crate::game::plugin::VelocitySystemSet)
+                    .before(crate::game::plugin::MovementSystemSet),
+            )
+            .add_systems(
+                FixedUpdate,
                 systems::infantry_movement.in_set(crate::game::plugin::MovementSystemSet),
             );
     }
@@ -1,17 +1,28 @@
+use std::collections::HashMap;
+
 use bevy::prelude::*;
 
 use super::components::*;
 use super::styles::*;
+use crate::config::GameConfig;
+use crate::game::battlefield::components::{BattlefieldConfig, CastleFootprint};
+use crate::game::castle::components::path_is_blocked;
+use crate::game::castle::constants::CASTLE_PATH_BLOCK_WIDTH;
 use crate::game::components::{Acceleration, Billboard, OnGameplayScreen, Velocity};
 use crate::game::constants::{
-    calculate_grid_cell_position, calculate_spawn_cells, calculate_total_archers,
-    calculate_total_infantry, cells_needed, distribute_units_to_cells, *,
+    calculate_spawn_cells, calculate_total_archers, calculate_total_infantry, cells_needed,
+    distribute_units_to_cells, *,
 };
-use crate::game::resources::CurrentLevel;
+use crate::game::resources::{CurrentLevel, TunableConstants};
 use crate::game::units::components::{
-    AttackTiming, Effectiveness, FlockingVelocity, Health, Hitbox, KingAuraSpeedModifier,
-    KingsGuard, MovementSpeed, RoughTerrainModifier, TargetingVelocity, Team, Teleportable,
+    AttackTiming, Corpse, Effectiveness, FlockingModifier, FlockingVelocity, Health, Hitbox,
+    KingAuraSpeedModifier, KingsGuard, MovementSpeed, RoughTerrainModifier, Snare, SpawnAnimation,
+    TargetingVelocity, Team, Teleportable, movement_speed_multiplier, nearest_by_distance,
+    spawn_attack_offset,
 };
+use crate::game::units::dispeller::components::Dispeller;
+use crate::game::units::healer::components::Healer;
+use crate::game::units::standard_bearer::components::StandardBearer;
 
 /// Spawns initial defenders when entering the game.
 ///
@@ -30,53 +41,72 @@ pub fn spawn_initial_defenders(
     let spawn_z = centroid_z;
 
     for i in 0..INITIAL_DEFENDER_COUNT {
-        // Define defender hitbox (cylinder) - this determines sprite size
-        let hitbox = Hitbox::new(UNIT_RADIUS, DEFENDER_HITBOX_HEIGHT);
-
-        // Spawn defender as a circle billboard sized to match the hitbox
-        let circle = Circle::new(hitbox.radius);
-
-        // Distribute spawns in a circular pattern around this spawn point
-        let offset = i as f32 * SPAWN_OFFSET_MULTIPLIER;
-        let final_x = spawn_x + (offset.sin() * SPAWN_DISTRIBUTION_RADIUS);
-        let final_z = spawn_z + (offset.cos() * SPAWN_DISTRIBUTION_RADIUS);
-
-        // Position unit so bottom edge is 1 unit above battlefield (Y=0)
-        let spawn_y = hitbox.height / 2.0 + 1.0;
+        // Spread spawns on a spiral around this spawn point
+        let offset = spawn_offset(i);
+        let final_x = spawn_x + offset.x;
+        let final_z = spawn_z + offset.y;
 
-        commands
-            .spawn((
-                Mesh3d(meshes.add(circle)),
-                MeshMaterial3d(materials.add(StandardMaterial {
-                    base_color: DEFENDER_COLOR,
-                    unlit: true,
-                    ..default()
-                })),
-                Transform::from_xyz(final_x, spawn_y, final_z),
-                Velocity::default(),
-                Acceleration::new(),
-                hitbox,
-                Health::new(UNIT_HEALTH),
-                MovementSpeed(UNIT_MOVEMENT_SPEED),
-                AttackTiming::new(),
-                Effectiveness::new(),
-                Team::Defenders,
-                Infantry,
-            ))
-            .insert((
-                TargetingVelocity::default(),
-                FlockingVelocity::default(),
-                Teleportable,
-                Billboard,
-                OnGameplayScreen,
-            ));
+        spawn_defender_infantry(&mut commands, &mut meshes, &mut materials, final_x, final_z);
     }
 }
 
+/// Spawns a single defender infantry unit at the given ground position.
+///
+/// Returns the spawned entity.
+pub fn spawn_defender_infantry(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    x: f32,
+    z: f32,
+) -> Entity {
+    // Define defender hitbox (cylinder) - this determines sprite size
+    let hitbox = Hitbox::new(UNIT_RADIUS, DEFENDER_HITBOX_HEIGHT);
+
+    // Spawn defender as a circle billboard sized to match the hitbox
+    let circle = Circle::new(hitbox.radius);
+
+    // Position unit so bottom edge is 1 unit above battlefield (Y=0)
+    let spawn_y = hitbox.height / 2.0 + 1.0;
+
+    commands
+        .spawn((
+            Mesh3d(meshes.add(circle)),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: DEFENDER_COLOR,
+                unlit: true,
+                ..default()
+            })),
+            Transform::from_xyz(x, spawn_y, z),
+            Velocity::default(),
+            Acceleration::new(),
+            hitbox,
+            Health::new(UNIT_HEALTH),
+            MovementSpeed(UNIT_MOVEMENT_SPEED),
+            AttackTiming::with_offset(spawn_attack_offset(x, z)),
+            Effectiveness::new(),
+            Team::Defenders,
+            Infantry,
+        ))
+        .insert((
+            TargetingVelocity::default(),
+            FlockingVelocity::default(),
+            Teleportable,
+            SpawnAnimation::new(),
+            Billboard,
+            OnGameplayScreen,
+        ))
+        .id()
+}
+
 /// Updates infantry targeting velocity toward nearest enemy.
 ///
-/// Infantry always move directly toward the nearest enemy.
+/// Infantry move directly toward the nearest enemy, except flankers, which
+/// swing around the castle's least defended side until an enemy is within
+/// `FLANK_ENGAGE_DISTANCE`. Other attackers head for the castle walls instead
+/// when no enemy is within melee range and no defender stands in the way.
 /// Also sets InMelee component if an enemy is within melee range.
+#[allow(clippy::type_complexity)]
 pub fn update_infantry_targeting(
     mut commands: Commands,
     mut infantry: Query<
@@ -85,6 +115,7 @@ pub fn update_infantry_targeting(
             &Transform,
             &Team,
             &mut crate::game::units::components::TargetingVelocity,
+            Has<Flanker>,
         ),
         (
             With<Infantry>,
@@ -92,46 +123,84 @@ pub fn update_infantry_targeting(
         ),
     >,
     all_units: Query<(Entity, &Transform, &Team), Without<crate::game::units::components::Corpse>>,
+    castles: Query<&CastleFootprint>,
 ) {
+    let castle = castles.single().ok();
+
     // Collect snapshot of all unit positions
     let unit_snapshot: Vec<_> = all_units
         .iter()
         .map(|(entity, transform, team)| (entity, transform.translation, *team))
         .collect();
+    let defender_positions: Vec<Vec3> = unit_snapshot
+        .iter()
+        .filter(|(_, _, team)| *team == Team::Defenders)
+        .map(|(_, pos, _)| *pos)
+        .collect();
 
     // Update each infantry's targeting velocity
-    for (entity, transform, team, mut targeting_velocity) in &mut infantry {
+    for (entity, transform, team, mut targeting_velocity, is_flanker) in &mut infantry {
         // Find nearest enemy
-        let nearest_enemy = unit_snapshot
-            .iter()
-            .filter(|(other_entity, _, other_team)| {
-                *other_entity != entity
-                    && match (*team, other_team) {
-                        (Team::Undead, Team::Undead) => false,
-                        (Team::Undead, _) => true,
-                        (_, Team::Undead) => true,
-                        _ => *other_team != *team,
-                    }
-            })
-            .min_by(|a, b| {
-                let dist_a = (transform.translation.x - a.1.x).powi(2)
-                    + (transform.translation.z - a.1.z).powi(2);
-                let dist_b = (transform.translation.x - b.1.x).powi(2)
-                    + (transform.translation.z - b.1.z).powi(2);
-                dist_a
-                    .partial_cmp(&dist_b)
-                    .unwrap_or(std::cmp::Ordering::Equal)
+        let nearest_enemy = nearest_by_distance(
+            unit_snapshot
+                .iter()
+                .filter(|(other_entity, _, other_team)| {
+                    *other_entity != entity
+                        && match (*team, other_team) {
+                            (Team::Undead, Team::Undead) => false,
+                            (Team::Undead, _) => true,
+                            (_, Team::Undead) => true,
+                            _ => *other_team != *team,
+                        }
+                }),
+            |(_, pos, _)| {
+                (transform.translation.x - pos.x).powi(2)
+                    + (transform.translation.z - pos.z).powi(2)
+            },
+        );
+
+        // Attackers with an open path and no enemy at hand march on the castle
+        let castle_point = castle
+            .filter(|_| *team == Team::Attackers && !is_flanker)
+            .map(|footprint| footprint.closest_point(transform.translation))
+            .filter(|castle_point| {
+                let enemy_at_hand = nearest_enemy.is_some_and(|(_, pos, _)| {
+                    transform.translation.distance(*pos) < MELEE_SLOWDOWN_DISTANCE
+                });
+                !enemy_at_hand
+                    && !path_is_blocked(
+                        transform.translation,
+                        *castle_point,
+                        &defender_positions,
+                        CASTLE_PATH_BLOCK_WIDTH,
+                    )
             });
 
         // Set targeting velocity toward target (normalized direction)
-        if let Some(&(_, target_pos, enemy_team)) = nearest_enemy {
-            let direction = (target_pos - transform.translation).normalize_or_zero();
-            targeting_velocity.velocity = Vec3::new(direction.x, 0.0, direction.z);
-
+        if let Some(castle_point) = castle_point {
+            let offset = (castle_point - transform.translation).with_y(0.0);
+            targeting_velocity.distance_to_target = offset.length();
+            targeting_velocity.velocity = offset.normalize_or_zero();
+            commands
+                .entity(entity)
+                .remove::<crate::game::units::components::InMelee>();
+        } else if let Some(&(_, target_pos, enemy_team)) = nearest_enemy {
             // Store distance for formation weighting
             let distance = transform.translation.distance(target_pos);
             targeting_velocity.distance_to_target = distance;
 
+            let direction = if is_flanker && distance > FLANK_ENGAGE_DISTANCE {
+                flank_direction(
+                    transform.translation,
+                    CASTLE_POSITION,
+                    &defender_positions,
+                    FLANK_OFFSET,
+                )
+            } else {
+                (target_pos - transform.translation).normalize_or_zero()
+            };
+            targeting_velocity.velocity = Vec3::new(direction.x, 0.0, direction.z);
+
             // Check if enemy is in melee range
             if distance < MELEE_SLOWDOWN_DISTANCE {
                 commands
@@ -152,14 +221,59 @@ pub fn update_infantry_targeting(
     }
 }
 
-/// Infantry-specific movement system.
+/// Keeps shield wall formations together until they reach the enemy.
+///
+/// Sets every member's pace to the speed multiplier of the slowest member of
+/// its group. Once any member is within `FORMATION_BREAK_DISTANCE` of its
+/// target, the whole group drops its formation and charges individually.
+/// Turning shield walls off mid-battle breaks up every formation.
+#[allow(clippy::type_complexity)]
+pub fn update_formations(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    mut members: Query<
+        (
+            Entity,
+            &mut Formation,
+            &TargetingVelocity,
+            Option<&KingAuraSpeedModifier>,
+            Option<&RoughTerrainModifier>,
+            Option<&Snare>,
+        ),
+        Without<Corpse>,
+    >,
+) {
+    // Slowest pace and whether contact was made, per group
+    let mut groups: HashMap<u32, (f32, bool)> = HashMap::new();
+    for (_, formation, targeting_velocity, aura_modifier, terrain_modifier, snare) in &members {
+        let pace = movement_speed_multiplier(aura_modifier, terrain_modifier, snare);
+        let group = groups.entry(formation.group).or_insert((f32::MAX, false));
+        group.0 = group.0.min(pace);
+        group.1 |= targeting_velocity.distance_to_target < FORMATION_BREAK_DISTANCE;
+    }
+
+    for (entity, mut formation, ..) in &mut members {
+        let (pace, contact) = groups[&formation.group];
+        if contact || !config.shield_walls {
+            commands
+                .entity(entity)
+                .remove::<(Formation, FlockingModifier)>();
+        } else {
+            formation.pace = pace;
+        }
+    }
+}
+
+/// Infantry movement system, also used by healers and standard bearers.
 ///
 /// Uses acceleration-based physics with maximum speed capping.
 /// TargetingVelocity and FlockingVelocity are treated as acceleration forces.
-/// Units slow down when in melee to prevent erratic movement.
+/// Units slow down when in melee to prevent erratic movement. Formation
+/// members move at their group's pace.
 #[allow(clippy::type_complexity)]
 pub fn infantry_movement(
     time: Res<Time>,
+    tunables: Option<Res<TunableConstants>>,
     mut infantry_units: Query<
         (
             &mut Transform,
@@ -172,11 +286,19 @@ pub fn infantry_movement(
             Option<&crate::game::units::components::InMelee>,
             Option<&KingAuraSpeedModifier>,
             Option<&RoughTerrainModifier>,
+            Option<&Snare>,
+            Option<&Formation>,
         ),
-        With<Infantry>,
+        Or<(
+            With<Infantry>,
+            With<Healer>,
+            With<StandardBearer>,
+            With<Dispeller>,
+        )>,
     >,
 ) {
     let delta = time.delta_secs();
+    let tuning = TunableConstants::or_default(tunables);
 
     // Process each infantry unit
     for (
@@ -190,6 +312,8 @@ pub fn infantry_movement(
         in_melee,
         aura_modifier,
         terrain_modifier,
+        snare,
+        formation,
     ) in &mut infantry_units
     {
         // Weight targeting vs flocking based on distance to target
@@ -206,23 +330,24 @@ pub fn infantry_movement(
             .normalize_or_zero();
 
         // Calculate speed modifiers early to apply to acceleration
-        let aura_percentage = aura_modifier.map_or(0.0, |m| m.0);
-        let terrain_percentage = terrain_modifier.map_or(0.0, |m| m.0);
-        let total_percentage = aura_percentage + terrain_percentage;
-        let speed_multiplier = 1.0 + total_percentage;
+        let mut speed_multiplier =
+            movement_speed_multiplier(aura_modifier, terrain_modifier, snare);
+        if let Some(formation) = formation {
+            speed_multiplier = speed_multiplier.min(formation.pace);
+        }
 
         // Apply as acceleration force with speed modifiers
-        acceleration.add_force(weighted_direction * STEERING_FORCE * speed_multiplier);
+        acceleration.add_force(weighted_direction * tuning.steering_force * speed_multiplier);
 
         // Apply acceleration to velocity
         velocity.x += acceleration.x * delta;
         velocity.z += acceleration.z * delta;
 
         // Apply damping to smooth movement
-        velocity.x *= VELOCITY_DAMPING;
-        velocity.z *= VELOCITY_DAMPING;
+        velocity.x *= tuning.velocity_damping;
+        velocity.z *= tuning.velocity_damping;
 
-        // Calculate max speed with effectiveness, modifiers (aura + terrain), and melee slowdown
+        // Calculate max speed with effectiveness, modifiers (aura + terrain + snare), and melee slowdown
         let mut max_speed = movement_speed.0 * effectiveness.multiplier() * speed_multiplier;
         if in_melee.is_some() {
             max_speed *= MELEE_SLOWDOWN_FACTOR;
@@ -254,11 +379,18 @@ pub fn infantry_movement(
 /// Level 1: 3 groups of 10
 /// Every odd level: +1 group
 /// Every even level: +1 unit per group
+///
+/// The outermost groups are tagged as `Flanker`s, with the share set by
+/// level and difficulty. With shield walls on, from `FORMATION_MIN_LEVEL` the
+/// other groups advance as shield wall `Formation`s, using the cell index as
+/// the group id.
 pub fn spawn_initial_attackers(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     current_level: Res<CurrentLevel>,
+    battlefield: Res<BattlefieldConfig>,
+    config: Res<GameConfig>,
 ) {
     let level = current_level.0;
 
@@ -269,69 +401,99 @@ pub fn spawn_initial_attackers(
     let (infantry_cells, _) = calculate_spawn_cells(num_infantry_cells, num_archer_cells);
     let units_per_cell = distribute_units_to_cells(total_infantry);
 
+    // Cells are ordered center-out, so the last ones are the outermost groups
+    let flank_fraction = calculate_flank_fraction(level, config.difficulty);
+    let flanking_cells = (flank_fraction * infantry_cells.len() as f32).round() as usize;
+    let first_flanking_cell = infantry_cells.len() - flanking_cells.min(infantry_cells.len());
+
     // Spawn each infantry cell
     for (cell_idx, (row, col)) in infantry_cells.iter().enumerate() {
-        let (spawn_x, spawn_z) = calculate_grid_cell_position(*row, *col);
+        let (spawn_x, spawn_z) = battlefield.grid_cell_position(*row, *col);
         let cell_count = units_per_cell.get(cell_idx).copied().unwrap_or(0);
 
         // Spawn all units in this cell
         for i in 0..cell_count {
-            // Define attacker hitbox (cylinder) - this determines sprite size
-            let hitbox = Hitbox::new(UNIT_RADIUS, ATTACKER_HITBOX_HEIGHT);
-
-            // Spawn attacker as a circle billboard sized to match the hitbox
-            let circle = Circle::new(hitbox.radius);
-
-            // Distribute spawns in a circular pattern around this spawn point
-            let offset = i as f32 * SPAWN_OFFSET_MULTIPLIER;
-            let final_x = spawn_x + (offset.sin() * SPAWN_DISTRIBUTION_RADIUS);
-            let final_z = spawn_z + (offset.cos() * SPAWN_DISTRIBUTION_RADIUS);
-
-            // Position unit so bottom edge is 1 unit above battlefield (Y=0)
-            let spawn_y = hitbox.height / 2.0 + 1.0;
-
-            // Start with velocity toward castle
-            let to_castle = Vec3::new(
-                CASTLE_POSITION.x - final_x,
-                0.0,
-                CASTLE_POSITION.z - final_z,
-            )
-            .normalize_or_zero();
-            let initial_velocity = Velocity {
-                x: to_castle.x * UNIT_MOVEMENT_SPEED,
-                z: to_castle.z * UNIT_MOVEMENT_SPEED,
-            };
-
-            commands
-                .spawn((
-                    Mesh3d(meshes.add(circle)),
-                    MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color: ATTACKER_COLOR,
-                        unlit: true,
-                        ..default()
-                    })),
-                    Transform::from_xyz(final_x, spawn_y, final_z),
-                    initial_velocity,
-                    Acceleration::new(),
-                    hitbox,
-                    Health::new(UNIT_HEALTH),
-                    MovementSpeed(UNIT_MOVEMENT_SPEED),
-                    AttackTiming::new(),
-                    Effectiveness::new(),
-                    Team::Attackers,
-                    Infantry,
-                ))
-                .insert((
-                    TargetingVelocity::default(),
-                    FlockingVelocity::default(),
-                    Teleportable,
-                    Billboard,
-                    OnGameplayScreen,
+            // Spread spawns on a spiral around this spawn point
+            let offset = spawn_offset(i);
+            let final_x = spawn_x + offset.x;
+            let final_z = spawn_z + offset.y;
+
+            let attacker = spawn_attacker_infantry(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                final_x,
+                final_z,
+            );
+            if cell_idx >= first_flanking_cell {
+                commands.entity(attacker).insert(Flanker);
+            } else if config.shield_walls && level >= FORMATION_MIN_LEVEL {
+                commands.entity(attacker).insert((
+                    Formation::new(cell_idx as u32),
+                    FlockingModifier::new(1.0, FORMATION_ALIGNMENT, FORMATION_COHESION),
                 ));
+            }
         }
     }
 }
 
+/// Spawns a single attacker infantry unit at the given ground position.
+///
+/// The unit starts moving toward the castle. Returns the spawned entity.
+pub fn spawn_attacker_infantry(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    x: f32,
+    z: f32,
+) -> Entity {
+    // Define attacker hitbox (cylinder) - this determines sprite size
+    let hitbox = Hitbox::new(UNIT_RADIUS, ATTACKER_HITBOX_HEIGHT);
+
+    // Spawn attacker as a circle billboard sized to match the hitbox
+    let circle = Circle::new(hitbox.radius);
+
+    // Position unit so bottom edge is 1 unit above battlefield (Y=0)
+    let spawn_y = hitbox.height / 2.0 + 1.0;
+
+    // Start with velocity toward castle
+    let to_castle =
+        Vec3::new(CASTLE_POSITION.x - x, 0.0, CASTLE_POSITION.z - z).normalize_or_zero();
+    let initial_velocity = Velocity {
+        x: to_castle.x * UNIT_MOVEMENT_SPEED,
+        z: to_castle.z * UNIT_MOVEMENT_SPEED,
+    };
+
+    commands
+        .spawn((
+            Mesh3d(meshes.add(circle)),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: ATTACKER_COLOR,
+                unlit: true,
+                ..default()
+            })),
+            Transform::from_xyz(x, spawn_y, z),
+            initial_velocity,
+            Acceleration::new(),
+            hitbox,
+            Health::new(UNIT_HEALTH),
+            MovementSpeed(UNIT_MOVEMENT_SPEED),
+            AttackTiming::with_offset(spawn_attack_offset(x, z)),
+            Effectiveness::new(),
+            Team::Attackers,
+            Infantry,
+        ))
+        .insert((
+            TargetingVelocity::default(),
+            FlockingVelocity::default(),
+            Teleportable,
+            SpawnAnimation::new(),
+            Billboard,
+            OnGameplayScreen,
+        ))
+        .id()
+}
+
 /// Spawns King's Guard units at the King's position.
 ///
 /// These are defender infantry locked to fixed positions around the King.
@@ -368,7 +530,7 @@ pub fn spawn_kings_guard(
                 Transform::from_xyz(final_x, spawn_y, final_z),
                 hitbox,
                 Health::new(UNIT_HEALTH),
-                AttackTiming::new(),
+                AttackTiming::with_offset(spawn_attack_offset(final_x, final_z)),
                 Effectiveness::new(),
                 Team::Defenders,
                 Infantry,
@@ -377,3 +539,119 @@ pub fn spawn_kings_guard(
             .insert((Teleportable, Billboard, OnGameplayScreen));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::game::shared_systems::apply_separation;
+
+    /// Spawns a column of ten attackers marching in +X, every other one snared.
+    fn spawn_group(world: &mut World, z: f32, formation: bool) -> Vec<Entity> {
+        (0..10)
+            .map(|i| {
+                let offset = spawn_offset(i);
+                let unit = world
+                    .spawn((
+                        Transform::from_xyz(offset.x, 0.0, z + offset.y),
+                        Velocity::default(),
+                        Acceleration::new(),
+                        Hitbox::new(UNIT_RADIUS, ATTACKER_HITBOX_HEIGHT),
+                        MovementSpeed(UNIT_MOVEMENT_SPEED),
+                        Effectiveness::new(),
+                        TargetingVelocity {
+                            velocity: Vec3::X,
+                            distance_to_target: 5000.0,
+                        },
+                        FlockingVelocity::default(),
+                        Team::Attackers,
+                        Infantry,
+                    ))
+                    .id();
+                if i % 2 == 0 {
+                    world.entity_mut(unit).insert(Snare::new(0.5, 1000.0));
+                }
+                if formation {
+                    world.entity_mut(unit).insert((
+                        Formation::new(0),
+                        FlockingModifier::new(1.0, FORMATION_ALIGNMENT, FORMATION_COHESION),
+                    ));
+                }
+                unit
+            })
+            .collect()
+    }
+
+    /// Returns the spread of the group along the march and of its speeds.
+    fn spreads(world: &World, units: &[Entity]) -> (f32, f32) {
+        let spread = |values: Vec<f32>| {
+            let max = values.iter().copied().fold(f32::MIN, f32::max);
+            let min = values.iter().copied().fold(f32::MAX, f32::min);
+            max - min
+        };
+        let xs = units
+            .iter()
+            .map(|unit| world.get::<Transform>(*unit).unwrap().translation.x)
+            .collect();
+        let speeds = units
+            .iter()
+            .map(|unit| {
+                let velocity = world.get::<Velocity>(*unit).unwrap();
+                Vec2::new(velocity.x, velocity.z).length()
+            })
+            .collect();
+        (spread(xs), spread(speeds))
+    }
+
+    #[test]
+    fn test_formation_keeps_ranks_and_a_uniform_pace() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(GameConfig {
+                shield_walls: true,
+                ..default()
+            })
+            .add_systems(
+                Update,
+                (apply_separation, update_formations, infantry_movement).chain(),
+            );
+
+        // Far enough apart that the two groups don't flock together
+        let formation = spawn_group(app.world_mut(), 0.0, true);
+        let loose = spawn_group(app.world_mut(), 2000.0, false);
+
+        for _ in 0..300 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(1.0 / 64.0));
+            app.update();
+        }
+
+        let (formation_depth, formation_speeds) = spreads(app.world(), &formation);
+        let (loose_depth, loose_speeds) = spreads(app.world(), &loose);
+        assert!(
+            formation_depth < loose_depth,
+            "formation {formation_depth} should be tighter than loose {loose_depth}"
+        );
+        assert!(
+            formation_speeds < loose_speeds * 0.25,
+            "formation speeds vary by {formation_speeds}, loose by {loose_speeds}"
+        );
+    }
+
+    #[test]
+    fn test_turning_shield_walls_off_breaks_formations() {
+        let mut app = App::new();
+        app.init_resource::<GameConfig>()
+            .add_systems(Update, update_formations);
+
+        let formation = spawn_group(app.world_mut(), 0.0, true);
+        app.update();
+
+        for unit in formation {
+            assert!(app.world().get::<Formation>(unit).is_none());
+            assert!(app.world().get::<FlockingModifier>(unit).is_none());
+        }
+    }
+}
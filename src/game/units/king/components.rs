@@ -1,10 +1,73 @@
 use bevy::prelude::*;
 
+use super::constants::{
+    KING_AURA_BASE_RADIUS, KING_AURA_MAX_RADIUS, KING_AURA_RADIUS_PER_DEFENDER,
+};
+
 /// Marker component for the King unit.
 #[derive(Component)]
 pub struct King;
 
+/// A player-issued order that overrides the King's auto-targeting.
+///
+/// While present, the King steers toward `target` instead of the nearest enemy
+/// and holds there once he arrives. Removed when the timer runs out, which only
+/// counts down while he is at the target.
+#[derive(Component)]
+pub struct KingOrder {
+    /// Destination on the battlefield (Y=0 plane).
+    pub target: Vec3,
+    /// Seconds of holding at `target` left before the King returns to auto-targeting.
+    pub timer: f32,
+}
+
+impl KingOrder {
+    /// Creates a new order toward the given target.
+    pub const fn new(target: Vec3, duration: f32) -> Self {
+        Self {
+            target,
+            timer: duration,
+        }
+    }
+
+    /// Advances the order timer. Returns true if the order has expired.
+    pub fn tick(&mut self, delta: f32) -> bool {
+        self.timer -= delta;
+        self.timer <= 0.0
+    }
+}
+
 /// Tracks whether a King has been spawned this round.
 /// Used by win/lose system to trigger defeat on King death.
 #[derive(Resource, Default)]
 pub struct KingSpawned(pub bool);
+
+/// Marker component for the King's aura sphere (a child of the King).
+///
+/// The sphere mesh is built at the base aura radius and scaled to the current radius.
+#[derive(Component)]
+pub struct KingAuraSphere;
+
+/// Calculates the King's aura radius from the number of living defenders.
+///
+/// A bigger army inspires from farther away, up to `KING_AURA_MAX_RADIUS`.
+pub fn king_aura_radius(defenders: usize) -> f32 {
+    (KING_AURA_BASE_RADIUS + defenders as f32 * KING_AURA_RADIUS_PER_DEFENDER)
+        .min(KING_AURA_MAX_RADIUS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aura_radius_grows_with_defenders_then_caps() {
+        assert_eq!(king_aura_radius(0), KING_AURA_BASE_RADIUS);
+        assert!(king_aura_radius(10) > king_aura_radius(0));
+        assert!(king_aura_radius(20) > king_aura_radius(10));
+
+        // A huge army never pushes the aura past the cap
+        assert_eq!(king_aura_radius(10_000), KING_AURA_MAX_RADIUS);
+        assert!(king_aura_radius(10) < KING_AURA_MAX_RADIUS);
+    }
+}
@@ -10,8 +10,15 @@ pub const KING_RADIUS: f32 = 14.0; // Larger than UNIT_RADIUS (8.0)
 pub const KING_HITBOX_HEIGHT: f32 = 35.0; // Taller than DEFENDER_HITBOX_HEIGHT (25.0)
 pub const KING_MOVEMENT_SPEED: f32 = 100.0; // Same as standard infantry
 
+// Player orders
+pub const KING_ORDER_KEY: KeyCode = KeyCode::KeyK; // Hold and right-click to order the King
+pub const KING_ORDER_DURATION: f32 = 8.0; // Seconds the King holds at an ordered spot before fighting on his own
+pub const KING_ORDER_ARRIVAL_RADIUS: f32 = 20.0; // The King holds position once this close to the order target
+
 // Cohesion aura constants
-pub const KING_AURA_RADIUS: f32 = 200.0; // Range within which defenders feel pull, receive buffs, and enemies are detected
+pub const KING_AURA_BASE_RADIUS: f32 = 200.0; // Aura radius with no defenders; defenders feel pull, receive buffs, and enemies are detected within the aura
+pub const KING_AURA_RADIUS_PER_DEFENDER: f32 = 1.5; // Aura radius gained per living defender
+pub const KING_AURA_MAX_RADIUS: f32 = 300.0; // Largest aura radius, however large the army
 pub const KING_COHESION_BASE: f32 = 0.0; // No cohesion when no enemies inside aura
 pub const KING_COHESION_THREATENED: f32 = 1.2; // Cohesion strength when enemies are inside aura
 pub const KING_AURA_DAMAGE_PERCENTAGE: f32 = 0.5; // 50% damage bonus for units in King's aura
@@ -1,6 +1,6 @@
 pub mod components;
 pub mod constants;
 mod plugin;
-mod systems;
+pub mod systems;
 
 pub use plugin::KingPlugin;
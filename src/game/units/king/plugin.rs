@@ -19,19 +19,22 @@ impl Plugin for KingPlugin {
                 systems::spawn_king.run_if(run_conditions::coming_from_game_over),
             )
             .add_systems(
-                Update,
+                FixedUpdate,
                 systems::update_king_targeting.in_set(VelocitySystemSet),
             )
-            .add_systems(Update, systems::king_movement.in_set(MovementSystemSet))
             .add_systems(
-                Update,
+                FixedUpdate,
+                systems::king_movement.in_set(MovementSystemSet),
+            )
+            .add_systems(
+                FixedUpdate,
                 systems::king_cohesion_aura
                     .after(apply_separation)
                     .before(MovementSystemSet)
                     .run_if(in_state(InGameState::Running)),
             )
             .add_systems(
-                Update,
+                FixedUpdate,
                 systems::snap_kings_guard_to_king
                     .in_set(MovementSystemSet)
                     .after(systems::king_movement),
@@ -4,10 +4,12 @@ use super::components::*;
 use super::constants::*;
 use crate::game::components::{Acceleration, Billboard, OnGameplayScreen, Velocity};
 use crate::game::constants::*;
+use crate::game::resources::TunableConstants;
 use crate::game::units::components::{
     AttackTiming, Corpse, DamageMultiplier, Effectiveness, FlockingModifier, FlockingVelocity,
-    Health, Hitbox, KingAuraSpeedModifier, KingsGuard, MovementSpeed, RoughTerrainModifier,
-    TargetingVelocity, Team, Teleportable,
+    Health, Hitbox, KingAuraSpeedModifier, KingsGuard, MovementSpeed, RoughTerrainModifier, Snare,
+    SpawnAnimation, TargetingVelocity, Team, Teleportable, movement_speed_multiplier,
+    nearest_by_distance, spawn_attack_offset,
 };
 
 /// Spawns the King unit at the exact center of all defender spawn points.
@@ -29,6 +31,22 @@ pub fn spawn_king(
     let spawn_x = centroid_x + 100.0;
     let spawn_z = centroid_z;
 
+    spawn_king_at(&mut commands, &mut meshes, &mut materials, spawn_x, spawn_z);
+
+    // Mark that King has been spawned
+    king_spawned.0 = true;
+}
+
+/// Spawns a King unit and its aura at the given ground position.
+///
+/// Returns the King entity.
+pub fn spawn_king_at(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    spawn_x: f32,
+    spawn_z: f32,
+) -> Entity {
     // Define King hitbox (larger than standard units)
     let hitbox = Hitbox::new(KING_RADIUS, KING_HITBOX_HEIGHT);
 
@@ -53,7 +71,7 @@ pub fn spawn_king(
             hitbox,
             Health::new(KING_HEALTH),
             MovementSpeed(KING_MOVEMENT_SPEED),
-            AttackTiming::new(),
+            AttackTiming::with_offset(spawn_attack_offset(spawn_x, spawn_z)),
             Effectiveness::new(),
             DamageMultiplier(KING_DAMAGE_PERCENTAGE),
             Team::Defenders,
@@ -64,14 +82,15 @@ pub fn spawn_king(
             FlockingVelocity::default(),
             Teleportable,
             FlockingModifier::new(1.0, 0.0, 0.0),
+            SpawnAnimation::new(),
             Billboard,
             OnGameplayScreen,
         ))
         .id();
 
     // Spawn visual aura sphere as a child entity centered on the King
-    // The sphere's radius exactly represents the 3D distance check used by the aura system
-    let aura_sphere = Sphere::new(KING_AURA_RADIUS);
+    // The sphere is scaled so its radius exactly represents the 3D distance check used by the aura system
+    let aura_sphere = Sphere::new(KING_AURA_BASE_RADIUS);
     commands
         .spawn((
             Mesh3d(meshes.add(aura_sphere)),
@@ -85,26 +104,39 @@ pub fn spawn_king(
             // Center sphere on King (relative position 0,0,0 since it's a child entity)
             // This accurately represents the 3D spherical distance check
             Transform::from_xyz(0.0, 0.0, 0.0),
+            KingAuraSphere,
             OnGameplayScreen,
         ))
         .set_parent_in_place(king_entity);
 
-    // Mark that King has been spawned
-    king_spawned.0 = true;
+    king_entity
 }
 
 /// Updates King targeting velocity toward nearest enemy.
 ///
-/// The King always moves directly toward the nearest enemy.
+/// The King moves directly toward the nearest enemy unless he has a
+/// `KingOrder`, which takes priority until it expires. The order's timer only
+/// runs once he has arrived, so a long walk doesn't eat into the hold. A zero
+/// `distance_to_target` gives the order full steering weight over flocking.
 /// Also sets InMelee component if an enemy is within melee range.
+#[allow(clippy::type_complexity)]
 pub fn update_king_targeting(
     mut commands: Commands,
+    time: Res<Time>,
     mut king: Query<
-        (Entity, &Transform, &Team, &mut TargetingVelocity),
+        (
+            Entity,
+            &Transform,
+            &Team,
+            &mut TargetingVelocity,
+            Option<&mut KingOrder>,
+        ),
         (With<King>, Without<Corpse>),
     >,
     all_units: Query<(Entity, &Transform, &Team), Without<Corpse>>,
 ) {
+    let delta = time.delta_secs();
+
     // Collect snapshot of all unit positions
     let unit_snapshot: Vec<_> = all_units
         .iter()
@@ -112,28 +144,25 @@ pub fn update_king_targeting(
         .collect();
 
     // Update King's targeting velocity
-    for (entity, transform, team, mut targeting_velocity) in &mut king {
+    for (entity, transform, team, mut targeting_velocity, order) in &mut king {
         // Find nearest enemy
-        let nearest_enemy = unit_snapshot
-            .iter()
-            .filter(|(other_entity, _, other_team)| {
-                *other_entity != entity
-                    && match (*team, other_team) {
-                        (Team::Undead, Team::Undead) => false,
-                        (Team::Undead, _) => true,
-                        (_, Team::Undead) => true,
-                        _ => *other_team != *team,
-                    }
-            })
-            .min_by(|a, b| {
-                let dist_a = (transform.translation.x - a.1.x).powi(2)
-                    + (transform.translation.z - a.1.z).powi(2);
-                let dist_b = (transform.translation.x - b.1.x).powi(2)
-                    + (transform.translation.z - b.1.z).powi(2);
-                dist_a
-                    .partial_cmp(&dist_b)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
+        let nearest_enemy = nearest_by_distance(
+            unit_snapshot
+                .iter()
+                .filter(|(other_entity, _, other_team)| {
+                    *other_entity != entity
+                        && match (*team, other_team) {
+                            (Team::Undead, Team::Undead) => false,
+                            (Team::Undead, _) => true,
+                            (_, Team::Undead) => true,
+                            _ => *other_team != *team,
+                        }
+                }),
+            |(_, pos, _)| {
+                (transform.translation.x - pos.x).powi(2)
+                    + (transform.translation.z - pos.z).powi(2)
+            },
+        );
 
         // Set targeting velocity toward target (normalized direction)
         if let Some(&(_, target_pos, enemy_team)) = nearest_enemy {
@@ -161,6 +190,21 @@ pub fn update_king_targeting(
                 .entity(entity)
                 .remove::<crate::game::units::components::InMelee>();
         }
+
+        if let Some(mut order) = order {
+            let diff = (order.target - transform.translation).with_y(0.0);
+            if diff.length() > KING_ORDER_ARRIVAL_RADIUS {
+                targeting_velocity.velocity = diff.normalize_or_zero();
+            } else if order.tick(delta) {
+                // Held long enough - auto-targeting takes over next frame
+                commands.entity(entity).remove::<KingOrder>();
+                continue;
+            } else {
+                // Arrived - hold position until the order expires
+                targeting_velocity.velocity = Vec3::ZERO;
+            }
+            targeting_velocity.distance_to_target = 0.0;
+        }
     }
 }
 
@@ -172,6 +216,7 @@ pub fn update_king_targeting(
 #[allow(clippy::type_complexity)]
 pub fn king_movement(
     time: Res<Time>,
+    tunables: Option<Res<TunableConstants>>,
     mut king_units: Query<
         (
             &mut Transform,
@@ -184,11 +229,13 @@ pub fn king_movement(
             Option<&crate::game::units::components::InMelee>,
             Option<&KingAuraSpeedModifier>,
             Option<&RoughTerrainModifier>,
+            Option<&Snare>,
         ),
         With<King>,
     >,
 ) {
     let delta = time.delta_secs();
+    let tuning = TunableConstants::or_default(tunables);
 
     // Process King unit
     for (
@@ -202,6 +249,7 @@ pub fn king_movement(
         in_melee,
         aura_modifier,
         terrain_modifier,
+        snare,
     ) in &mut king_units
     {
         // Weight targeting vs flocking based on distance to target
@@ -218,23 +266,20 @@ pub fn king_movement(
             .normalize_or_zero();
 
         // Calculate speed modifiers early to apply to acceleration
-        let aura_percentage = aura_modifier.map_or(0.0, |m| m.0);
-        let terrain_percentage = terrain_modifier.map_or(0.0, |m| m.0);
-        let total_percentage = aura_percentage + terrain_percentage;
-        let speed_multiplier = 1.0 + total_percentage;
+        let speed_multiplier = movement_speed_multiplier(aura_modifier, terrain_modifier, snare);
 
         // Apply as acceleration force with speed modifiers
-        acceleration.add_force(weighted_direction * STEERING_FORCE * speed_multiplier);
+        acceleration.add_force(weighted_direction * tuning.steering_force * speed_multiplier);
 
         // Apply acceleration to velocity
         velocity.x += acceleration.x * delta;
         velocity.z += acceleration.z * delta;
 
         // Apply damping to smooth movement
-        velocity.x *= VELOCITY_DAMPING;
-        velocity.z *= VELOCITY_DAMPING;
+        velocity.x *= tuning.velocity_damping;
+        velocity.z *= tuning.velocity_damping;
 
-        // Calculate max speed with effectiveness, modifiers (aura + terrain), and melee slowdown
+        // Calculate max speed with effectiveness, modifiers (aura + terrain + snare), and melee slowdown
         let mut max_speed = movement_speed.0 * effectiveness.multiplier() * speed_multiplier;
         if in_melee.is_some() {
             max_speed *= MELEE_SLOWDOWN_FACTOR;
@@ -265,6 +310,9 @@ pub fn king_movement(
 /// Defenders are drawn to protect the King, attackers are drawn to kill the King.
 /// Also applies/removes damage and speed buffs to defenders within aura range.
 /// The King himself also receives the aura buffs.
+/// The aura radius grows with the number of living defenders, and the aura
+/// sphere is rescaled to match.
+#[allow(clippy::type_complexity)]
 pub fn king_cohesion_aura(
     mut commands: Commands,
     king_query: Query<(Entity, &Transform), (With<King>, Without<Corpse>)>,
@@ -273,6 +321,7 @@ pub fn king_cohesion_aura(
         (Without<King>, Without<Corpse>),
     >,
     all_units: Query<(&Transform, &Team), Without<Corpse>>,
+    mut aura_spheres: Query<&mut Transform, (With<KingAuraSphere>, Without<King>, Without<Team>)>,
 ) {
     // Get King entity and position (should only be one)
     let Ok((king_entity, king_transform)) = king_query.single() else {
@@ -281,21 +330,32 @@ pub fn king_cohesion_aura(
 
     let king_pos = king_transform.translation;
 
-    // Find nearest enemy to King
-    let nearest_enemy_distance = all_units
+    let defenders = all_affected_units
         .iter()
-        .filter(|(_, team)| **team != Team::Defenders)
-        .map(|(transform, _)| transform.translation.distance(king_pos))
-        .min_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
-        .unwrap_or(f32::MAX);
+        .filter(|(_, _, team, _)| **team == Team::Defenders)
+        .count();
+    let aura_radius = king_aura_radius(defenders);
+    for mut sphere_transform in &mut aura_spheres {
+        sphere_transform.scale = Vec3::splat(aura_radius / KING_AURA_BASE_RADIUS);
+    }
+
+    // Find nearest enemy to King
+    let nearest_enemy_distance = nearest_by_distance(
+        all_units
+            .iter()
+            .filter(|(_, team)| **team != Team::Defenders)
+            .map(|(transform, _)| transform.translation.distance(king_pos)),
+        |distance| *distance,
+    )
+    .unwrap_or(f32::MAX);
 
     // Calculate threat level: interpolate between BASE and THREATENED
-    // If enemy is far (> AURA_RADIUS), use BASE
-    // If enemy is close (< AURA_RADIUS), interpolate to THREATENED
-    let threat_factor = if nearest_enemy_distance > KING_AURA_RADIUS {
+    // If enemy is far (> aura radius), use BASE
+    // If enemy is close (< aura radius), interpolate to THREATENED
+    let threat_factor = if nearest_enemy_distance > aura_radius {
         0.0
     } else {
-        1.0 - (nearest_enemy_distance / KING_AURA_RADIUS)
+        1.0 - (nearest_enemy_distance / aura_radius)
     };
 
     let cohesion_strength =
@@ -307,7 +367,7 @@ pub fn king_cohesion_aura(
         let distance_to_king = unit_pos.distance(king_pos);
 
         // Check if unit is within aura radius
-        if distance_to_king < KING_AURA_RADIUS && distance_to_king > 0.1 {
+        if distance_to_king < aura_radius && distance_to_king > 0.1 {
             // Apply cohesion force only to defenders (they protect the King)
             // Attackers use their normal targeting behavior to attack the King
             if *team == Team::Defenders {
@@ -316,7 +376,7 @@ pub fn king_cohesion_aura(
 
                 // Add cohesion force to flocking velocity
                 // Scale by distance (stronger pull when closer to edge of aura)
-                let distance_factor = distance_to_king / KING_AURA_RADIUS;
+                let distance_factor = distance_to_king / aura_radius;
                 let cohesion_force = to_king * cohesion_strength * distance_factor;
 
                 flocking_velocity.velocity += Vec3::new(cohesion_force.x, 0.0, cohesion_force.z);
@@ -365,3 +425,87 @@ pub fn snap_kings_guard_to_king(
         transform.translation.z = king_pos.z + KINGS_GUARD_ORBIT_RADIUS * angle.sin();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    #[test]
+    fn test_ordered_king_steers_toward_order_over_nearest_enemy() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_systems(Update, update_king_targeting);
+
+        let world = app.world_mut();
+        let king = world
+            .spawn((
+                King,
+                Team::Defenders,
+                Transform::default(),
+                TargetingVelocity::default(),
+                KingOrder::new(Vec3::new(0.0, 0.0, 400.0), KING_ORDER_DURATION),
+            ))
+            .id();
+        world.spawn((Team::Attackers, Transform::from_xyz(100.0, 0.0, 0.0)));
+
+        app.update();
+
+        let targeting = app.world().get::<TargetingVelocity>(king).unwrap();
+        assert_eq!(targeting.velocity, Vec3::Z);
+        assert_eq!(targeting.distance_to_target, 0.0);
+
+        // Without the order he goes back to chasing the nearest enemy
+        app.world_mut().entity_mut(king).remove::<KingOrder>();
+        app.update();
+
+        let targeting = app.world().get::<TargetingVelocity>(king).unwrap();
+        assert_eq!(targeting.velocity, Vec3::X);
+    }
+
+    #[test]
+    fn test_order_timer_starts_when_the_king_arrives() {
+        // Virtual time caps each frame at 250ms, so step by exactly that
+        let step = Duration::from_millis(250);
+        let hold_frames = (KING_ORDER_DURATION / step.as_secs_f32()) as usize;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(step))
+            .add_systems(Update, update_king_targeting);
+
+        let target = Vec3::new(0.0, 0.0, 400.0);
+        let king = app
+            .world_mut()
+            .spawn((
+                King,
+                Team::Defenders,
+                Transform::default(),
+                TargetingVelocity::default(),
+                KingOrder::new(target, KING_ORDER_DURATION),
+            ))
+            .id();
+
+        // Walking there takes longer than the order lasts, but the clock hasn't started
+        for _ in 0..hold_frames + 4 {
+            app.update();
+        }
+        let order = app.world().get::<KingOrder>(king).unwrap();
+        assert_eq!(order.timer, KING_ORDER_DURATION);
+
+        // Once he is there, he holds for the full duration and then moves on
+        app.world_mut()
+            .get_mut::<Transform>(king)
+            .unwrap()
+            .translation = target;
+        for _ in 1..hold_frames {
+            app.update();
+            assert!(app.world().get::<KingOrder>(king).is_some());
+        }
+        app.update();
+        assert!(app.world().get::<KingOrder>(king).is_none());
+    }
+}
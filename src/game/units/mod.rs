@@ -1,12 +1,16 @@
 //! Units plugin module.
 //!
-//! Contains all game unit types: wizard, infantry, and archers.
+//! Contains all game unit types: wizard, infantry, archers, healers,
+//! standard bearers, and dispellers.
 
 pub mod archer;
 pub mod components;
 pub mod constants;
+pub mod dispeller;
+pub mod healer;
 pub mod infantry;
 pub mod king;
+pub mod standard_bearer;
 mod systems;
 pub mod wizard;
 
@@ -1,10 +1,15 @@
 use bevy::prelude::*;
 
-use crate::state::InGameState;
+use crate::game::systems::update_billboards;
+use crate::state::{InGameState, MenuBackground};
 
 use super::archer::ArcherPlugin;
+use super::components::{ComboTriggered, DamageDealt};
+use super::dispeller::DispellerPlugin;
+use super::healer::HealerPlugin;
 use super::infantry::InfantryPlugin;
 use super::king::KingPlugin;
+use super::standard_bearer::StandardBearerPlugin;
 use super::systems;
 use super::wizard::WizardPlugin;
 
@@ -14,18 +19,46 @@ use super::wizard::WizardPlugin;
 /// - Wizard entity (WizardPlugin)
 /// - Infantry units on both teams (InfantryPlugin)
 /// - Archer units on both teams (ArcherPlugin)
+/// - Attacker healers (HealerPlugin)
+/// - Attacker standard bearers (StandardBearerPlugin)
+/// - Attacker dispellers (DispellerPlugin)
 /// - King unit (defender only) (KingPlugin)
 ///
-/// Also registers global unit systems for:
+/// Also registers the `DamageDealt` and `ComboTriggered` messages and global
+/// unit systems for:
 /// - Temporary hit points expiration
+/// - Snare expiration
+/// - Temporary (summoned) unit expiration
+/// - Spawn animations, in battle and in the menu background battle
 pub struct UnitsPlugin;
 
 impl Plugin for UnitsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((WizardPlugin, InfantryPlugin, ArcherPlugin, KingPlugin))
+        app.add_message::<DamageDealt>()
+            .add_message::<ComboTriggered>()
+            .add_plugins((
+                WizardPlugin,
+                InfantryPlugin,
+                ArcherPlugin,
+                HealerPlugin,
+                StandardBearerPlugin,
+                DispellerPlugin,
+                KingPlugin,
+            ))
             .add_systems(
                 Update,
-                systems::update_temporary_hit_points.run_if(in_state(InGameState::Running)),
+                (
+                    systems::update_temporary_hit_points,
+                    systems::update_snares,
+                    systems::update_temporary_units,
+                )
+                    .run_if(in_state(InGameState::Running)),
+            )
+            .add_systems(
+                Update,
+                systems::animate_unit_spawns
+                    .after(update_billboards)
+                    .run_if(in_state(InGameState::Running).or(in_state(MenuBackground::Playing))),
             );
     }
 }
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+
+/// Attacker banner carrier that buffs the damage of nearby attackers.
+#[derive(Component)]
+pub struct StandardBearer;
+
+/// Marks an attacker that is inside a standard bearer's aura.
+///
+/// Tracks which `DamageMultiplier`s the aura added, so leaving the aura only
+/// removes those.
+#[derive(Component)]
+pub struct BannerMorale;
@@ -0,0 +1,10 @@
+// Health
+pub const STANDARD_BEARER_HEALTH: f32 = 40.0; // A little sturdier than a healer (30)
+
+// Morale aura
+pub const STANDARD_BEARER_AURA_RADIUS: f32 = 200.0; // Smaller than the King's aura
+pub const STANDARD_BEARER_DAMAGE_PERCENTAGE: f32 = 0.2; // +20% damage, weaker than the King's +50%
+
+// Positioning
+pub const STANDARD_BEARER_FOLLOW_RADIUS: f32 = 300.0; // Allies within this distance count as its group
+pub const STANDARD_BEARER_ARRIVAL_RADIUS: f32 = 20.0; // Stops steering once this close to its group's center
@@ -0,0 +1,12 @@
+//! Standard bearer plugin module.
+//!
+//! Handles attacker standard bearers, banner carriers that march with their
+//! group and raise the damage of nearby attackers, like a smaller enemy King.
+
+pub mod components;
+pub mod constants;
+mod plugin;
+pub mod styles;
+pub mod systems;
+
+pub use plugin::StandardBearerPlugin;
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+use crate::game::run_conditions;
+use crate::state::{AppState, InGameState};
+
+use super::systems;
+
+/// Plugin that handles attacker standard bearers.
+///
+/// Registers systems for:
+/// - Spawning one standard bearer per full infantry group from the level where
+///   they appear (campaign mode only)
+/// - Re-spawn when entering Running state from GameOver (for replay)
+/// - Keeping standard bearers in the middle of their group
+/// - Granting and removing the damage aura on nearby attackers
+pub struct StandardBearerPlugin;
+
+impl Plugin for StandardBearerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(AppState::InGame),
+            systems::spawn_standard_bearers
+                .run_if(run_conditions::in_campaign_mode)
+                .run_if(not(run_conditions::has_defined_wave)),
+        )
+        .add_systems(
+            OnEnter(InGameState::Running),
+            systems::spawn_standard_bearers
+                .run_if(run_conditions::in_campaign_mode)
+                .run_if(not(run_conditions::has_defined_wave))
+                .run_if(run_conditions::coming_from_game_over),
+        )
+        .add_systems(
+            FixedUpdate,
+            systems::update_standard_bearer_targeting
+                .in_set(crate::game::plugin::VelocitySystemSet),
+        )
+        .add_systems(
+            FixedUpdate,
+            systems::standard_bearer_aura.run_if(in_state(InGameState::Running)),
+        );
+    }
+}
@@ -0,0 +1,4 @@
+use bevy::prelude::*;
+
+pub const STANDARD_BEARER_COLOR: Color = Color::srgb(0.95, 0.75, 0.2); // Gold banner, a priority target
+pub const STANDARD_BEARER_RADIUS: f32 = 9.0; // Slightly larger than infantry
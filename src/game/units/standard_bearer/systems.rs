@@ -0,0 +1,216 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::*;
+use super::styles::*;
+use crate::game::battlefield::components::BattlefieldConfig;
+use crate::game::components::{Acceleration, Billboard, OnGameplayScreen, Velocity};
+use crate::game::constants::{
+    calculate_spawn_cells, calculate_total_archers, calculate_total_infantry, cells_needed,
+    distribute_units_to_cells, standard_bearer_cells, *,
+};
+use crate::game::resources::CurrentLevel;
+use crate::game::units::components::{
+    Corpse, DamageMultiplier, Effectiveness, FlockingVelocity, Health, Hitbox, MovementSpeed,
+    SpawnAnimation, TargetingVelocity, Team, Teleportable,
+};
+
+/// Spawns attacker standard bearers based on level.
+///
+/// None before `STANDARD_BEARER_MIN_LEVEL`, then one in the middle of every
+/// full infantry group.
+pub fn spawn_standard_bearers(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    current_level: Res<CurrentLevel>,
+    battlefield: Res<BattlefieldConfig>,
+) {
+    let level = current_level.0;
+
+    let total_infantry = calculate_total_infantry(level);
+    let num_infantry_cells = cells_needed(total_infantry);
+    let num_archer_cells = cells_needed(calculate_total_archers(level));
+    let (infantry_cells, _) = calculate_spawn_cells(num_infantry_cells, num_archer_cells);
+    let units_per_cell = distribute_units_to_cells(total_infantry);
+
+    for cell_idx in standard_bearer_cells(level, &units_per_cell) {
+        let Some(&(row, col)) = infantry_cells.get(cell_idx) else {
+            continue;
+        };
+        let (spawn_x, spawn_z) = battlefield.grid_cell_position(row, col);
+        spawn_standard_bearer(&mut commands, &mut meshes, &mut materials, spawn_x, spawn_z);
+    }
+}
+
+/// Spawns a single attacker standard bearer at the given ground position.
+///
+/// Standard bearers have no `AttackTiming`, so they never fight. They move with
+/// the infantry movement system. Returns the spawned entity.
+pub fn spawn_standard_bearer(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    x: f32,
+    z: f32,
+) -> Entity {
+    let hitbox = Hitbox::new(STANDARD_BEARER_RADIUS, ATTACKER_HITBOX_HEIGHT);
+    let circle = Circle::new(hitbox.radius);
+
+    // Position unit so bottom edge is 1 unit above battlefield (Y=0)
+    let spawn_y = hitbox.height / 2.0 + 1.0;
+
+    commands
+        .spawn((
+            Mesh3d(meshes.add(circle)),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: STANDARD_BEARER_COLOR,
+                unlit: true,
+                ..default()
+            })),
+            Transform::from_xyz(x, spawn_y, z),
+            Velocity::default(),
+            Acceleration::new(),
+            hitbox,
+            Health::new(STANDARD_BEARER_HEALTH),
+            MovementSpeed(UNIT_MOVEMENT_SPEED),
+            Effectiveness::new(),
+            Team::Attackers,
+            StandardBearer,
+        ))
+        .insert((
+            TargetingVelocity::default(),
+            FlockingVelocity::default(),
+            Teleportable,
+            SpawnAnimation::new(),
+            Billboard,
+            OnGameplayScreen,
+        ))
+        .id()
+}
+
+/// Steers standard bearers to the center of the attackers around them.
+///
+/// Standard bearers with no allies nearby walk toward the castle like any other
+/// attacker.
+pub fn update_standard_bearer_targeting(
+    mut bearers: Query<
+        (&Transform, &mut TargetingVelocity),
+        (With<StandardBearer>, Without<Corpse>),
+    >,
+    all_units: Query<(&Transform, &Team, Has<StandardBearer>), Without<Corpse>>,
+) {
+    let allies: Vec<Vec3> = all_units
+        .iter()
+        .filter(|(_, team, is_bearer)| **team == Team::Attackers && !is_bearer)
+        .map(|(transform, _, _)| transform.translation)
+        .collect();
+
+    for (transform, mut targeting_velocity) in &mut bearers {
+        let position = transform.translation;
+        let group: Vec<Vec3> = allies
+            .iter()
+            .filter(|ally| ally.distance(position) <= STANDARD_BEARER_FOLLOW_RADIUS)
+            .copied()
+            .collect();
+
+        let spot = if group.is_empty() {
+            CASTLE_POSITION
+        } else {
+            group.iter().sum::<Vec3>() / group.len() as f32
+        };
+
+        let offset = Vec3::new(spot.x - position.x, 0.0, spot.z - position.z);
+        targeting_velocity.distance_to_target = offset.length();
+        targeting_velocity.velocity = if offset.length() > STANDARD_BEARER_ARRIVAL_RADIUS {
+            offset.normalize_or_zero()
+        } else {
+            Vec3::ZERO
+        };
+    }
+}
+
+/// Grants the damage aura to attackers near a standard bearer.
+///
+/// Attackers within `STANDARD_BEARER_AURA_RADIUS` of any standard bearer gain
+/// `BannerMorale` and a `DamageMultiplier`; both are removed once they leave
+/// every aura. Standard bearers don't buff themselves.
+pub fn standard_bearer_aura(
+    mut commands: Commands,
+    bearers: Query<&Transform, (With<StandardBearer>, Without<Corpse>)>,
+    attackers: Query<
+        (Entity, &Transform, &Team, Has<BannerMorale>),
+        (Without<StandardBearer>, Without<Corpse>),
+    >,
+) {
+    let banners: Vec<Vec3> = bearers
+        .iter()
+        .map(|transform| transform.translation)
+        .collect();
+
+    for (entity, transform, team, has_morale) in &attackers {
+        if *team != Team::Attackers {
+            continue;
+        }
+
+        let in_aura = banners
+            .iter()
+            .any(|banner| banner.distance(transform.translation) < STANDARD_BEARER_AURA_RADIUS);
+
+        if in_aura && !has_morale {
+            commands.entity(entity).insert((
+                BannerMorale,
+                DamageMultiplier(STANDARD_BEARER_DAMAGE_PERCENTAGE),
+            ));
+        } else if !in_aura && has_morale {
+            commands
+                .entity(entity)
+                .remove::<(BannerMorale, DamageMultiplier)>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attackers_gain_and_lose_banner_morale() {
+        let mut app = App::new();
+        app.add_systems(Update, standard_bearer_aura);
+
+        app.world_mut()
+            .spawn((StandardBearer, Team::Attackers, Transform::default()));
+        let near = app
+            .world_mut()
+            .spawn((Team::Attackers, Transform::from_xyz(50.0, 0.0, 0.0)))
+            .id();
+        let defender = app
+            .world_mut()
+            .spawn((Team::Defenders, Transform::from_xyz(50.0, 0.0, 0.0)))
+            .id();
+
+        app.update();
+        let multiplier = |app: &App, entity| {
+            app.world()
+                .get::<DamageMultiplier>(entity)
+                .map(|multiplier| multiplier.0)
+        };
+        assert_eq!(
+            multiplier(&app, near),
+            Some(STANDARD_BEARER_DAMAGE_PERCENTAGE)
+        );
+        assert!(app.world().get::<BannerMorale>(near).is_some());
+        assert_eq!(multiplier(&app, defender), None);
+
+        // Walking out of the aura removes the buff
+        app.world_mut()
+            .get_mut::<Transform>(near)
+            .unwrap()
+            .translation
+            .x = STANDARD_BEARER_AURA_RADIUS * 2.0;
+        app.update();
+        assert_eq!(multiplier(&app, near), None);
+        assert!(app.world().get::<BannerMorale>(near).is_none());
+    }
+}
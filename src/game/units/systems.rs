@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::components::TemporaryHitPoints;
+use super::components::{Snare, SpawnAnimation, Temporary, TemporaryHitPoints};
 
 /// Updates all temporary hit points timers and removes expired components.
 ///
@@ -21,3 +21,177 @@ pub fn update_temporary_hit_points(
         }
     }
 }
+
+/// Updates all snare timers and removes expired snares.
+///
+/// Units recover their normal movement speed once the snare is removed.
+pub fn update_snares(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Snare)>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut snare) in query.iter_mut() {
+        if snare.update(delta) {
+            commands.entity(entity).remove::<Snare>();
+        }
+    }
+}
+
+/// Updates summoned unit lifespans and despawns units whose time is up.
+pub fn update_temporary_units(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Temporary)>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut temporary) in query.iter_mut() {
+        if temporary.update(delta) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Plays the spawn animation on newly spawned units.
+///
+/// Runs after `update_billboards` has applied the unit size setting, shrinking
+/// the sprite by the animation's scale. The component is removed once finished,
+/// leaving the sprite at its normal render size.
+pub fn animate_unit_spawns(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Transform, &mut SpawnAnimation)>,
+) {
+    for (entity, mut transform, mut animation) in &mut query {
+        animation.timer.tick(time.delta());
+        transform.scale *= animation.scale();
+
+        if animation.timer.is_finished() {
+            commands.entity(entity).remove::<SpawnAnimation>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::game::shared_systems::{pause_game_time, resume_game_time};
+    use crate::game::units::components::{Team, movement_speed_multiplier};
+    use crate::state::{AppState, InGameState};
+
+    const FRAME: Duration = Duration::from_millis(100);
+
+    fn app_with_buffed_unit() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+            .insert_state(AppState::InGame)
+            .add_sub_state::<InGameState>()
+            .add_systems(OnExit(InGameState::Running), pause_game_time)
+            .add_systems(OnEnter(InGameState::Running), resume_game_time)
+            // Deliberately ungated, so only the frozen clock can stop the timer
+            .add_systems(Update, update_temporary_hit_points);
+
+        let unit = app
+            .world_mut()
+            .spawn(TemporaryHitPoints::new(50.0, 10.0))
+            .id();
+        // The first frame only starts the clock
+        app.update();
+        (app, unit)
+    }
+
+    fn time_remaining(app: &App, unit: Entity) -> f32 {
+        app.world()
+            .get::<TemporaryHitPoints>(unit)
+            .expect("buff should not expire")
+            .time_remaining
+    }
+
+    fn set_in_game_state(app: &mut App, state: InGameState) {
+        app.world_mut()
+            .resource_mut::<NextState<InGameState>>()
+            .set(state);
+        app.update();
+    }
+
+    #[test]
+    fn test_temporary_hit_points_freeze_while_paused() {
+        let (mut app, unit) = app_with_buffed_unit();
+        app.update();
+        let before_pause = time_remaining(&app, unit);
+        assert!(before_pause < 10.0);
+
+        set_in_game_state(&mut app, InGameState::Paused);
+        let paused_at = time_remaining(&app, unit);
+        for _ in 0..20 {
+            app.update();
+        }
+        assert_eq!(time_remaining(&app, unit), paused_at);
+
+        // The buff counts down again once the game resumes
+        set_in_game_state(&mut app, InGameState::Running);
+        app.update();
+        assert!(time_remaining(&app, unit) < paused_at);
+    }
+
+    #[test]
+    fn test_conjured_unit_despawns_after_lifespan() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+            .add_systems(Update, update_temporary_units);
+
+        let guardian = app
+            .world_mut()
+            .spawn((Team::Defenders, Temporary::new(1.0)))
+            .id();
+        // The first frame only starts the clock
+        app.update();
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(app.world().get_entity(guardian).is_ok());
+
+        for _ in 0..6 {
+            app.update();
+        }
+        assert!(app.world().get_entity(guardian).is_err());
+    }
+
+    #[test]
+    fn test_snared_unit_recovers_speed_after_expiry() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+            .add_systems(Update, update_snares);
+
+        let unit = app
+            .world_mut()
+            .spawn((Team::Attackers, Snare::new(0.5, 1.0)))
+            .id();
+        let speed =
+            |app: &App| movement_speed_multiplier(None, None, app.world().get::<Snare>(unit));
+        // The first frame only starts the clock
+        app.update();
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(speed(&app), 0.5);
+
+        for _ in 0..6 {
+            app.update();
+        }
+        assert!(app.world().get::<Snare>(unit).is_none());
+        assert_eq!(speed(&app), 1.0);
+    }
+}
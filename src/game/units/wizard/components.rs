@@ -1,7 +1,8 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Available spells.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Serialize, Deserialize)]
 pub enum Spell {
     MagicMissile,
     Disintegrate,
@@ -12,6 +13,9 @@ pub enum Spell {
     RaiseTheDead,
     Teleport,
     WallOfStone,
+    FrostNova,
+    ConjureGuardian,
+    Blizzard,
 }
 
 impl Spell {
@@ -27,6 +31,9 @@ impl Spell {
             Spell::RaiseTheDead,
             Spell::Teleport,
             Spell::WallOfStone,
+            Spell::FrostNova,
+            Spell::ConjureGuardian,
+            Spell::Blizzard,
         ]
     }
 
@@ -42,6 +49,9 @@ impl Spell {
             Spell::RaiseTheDead => "Raise The Dead",
             Spell::Teleport => "Teleport",
             Spell::WallOfStone => "Wall of Stone",
+            Spell::FrostNova => "Frost Nova",
+            Spell::ConjureGuardian => "Conjure Guardian",
+            Spell::Blizzard => "Blizzard",
         }
     }
 
@@ -71,6 +81,15 @@ impl Spell {
             Spell::WallOfStone => {
                 "Drag to raise an impassable stone wall that blocks all movement and projectiles for 20 seconds."
             }
+            Spell::FrostNova => {
+                "Unleashes a burst of frost at the cursor that slows enemies caught in it."
+            }
+            Spell::ConjureGuardian => {
+                "Summons a hardy golden guardian at the cursor that fights for 20 seconds."
+            }
+            Spell::Blizzard => {
+                "Calls down a freezing storm at the cursor that damages enemies standing in it for 6 seconds."
+            }
         }
     }
 
@@ -86,14 +105,18 @@ impl Spell {
             Spell::RaiseTheDead => "Click and hold to channel",
             Spell::Teleport => "Click to place destination, then click and hold to cast",
             Spell::WallOfStone => "Click and drag to place wall",
+            Spell::FrostNova => "Click and hold to cast",
+            Spell::ConjureGuardian => "Click and hold to cast",
+            Spell::Blizzard => "Click and hold to cast",
         }
     }
 
     /// Returns the PrimedSpell configuration for this spell.
     pub const fn primed_config(self) -> PrimedSpell {
         use crate::game::units::wizard::spells::{
-            chain_lightning_constants, disintegrate_constants, finger_of_death_constants,
-            fireball_constants, guardian_circle_constants, magic_missile_constants,
+            blizzard_constants, chain_lightning_constants, conjure_guardian_constants,
+            disintegrate_constants, finger_of_death_constants, fireball_constants,
+            frost_nova_constants, guardian_circle_constants, magic_missile_constants,
             raise_the_dead_constants, teleport_constants, wall_of_stone_constants,
         };
 
@@ -107,8 +130,47 @@ impl Spell {
             Spell::RaiseTheDead => raise_the_dead_constants::PRIMED_RAISE_THE_DEAD,
             Spell::Teleport => teleport_constants::PRIMED_TELEPORT,
             Spell::WallOfStone => wall_of_stone_constants::PRIMED_WALL_OF_STONE,
+            Spell::FrostNova => frost_nova_constants::PRIMED_FROST_NOVA,
+            Spell::ConjureGuardian => conjure_guardian_constants::PRIMED_CONJURE_GUARDIAN,
+            Spell::Blizzard => blizzard_constants::PRIMED_BLIZZARD,
         }
     }
+
+    /// Returns true if the wizard has enough mana to start casting this spell.
+    ///
+    /// Mirrors the check each spell makes when the cast begins.
+    pub fn is_affordable(self, mana: &Mana) -> bool {
+        use crate::game::units::wizard::spells::{
+            blizzard_constants, chain_lightning_constants, conjure_guardian_constants,
+            disintegrate_constants, finger_of_death_constants, fireball_constants,
+            frost_nova_constants, guardian_circle_constants, magic_missile_constants,
+            raise_the_dead_constants, teleport_constants, wall_of_stone_constants,
+        };
+
+        match self {
+            Spell::MagicMissile => mana.can_afford(magic_missile_constants::MANA_COST),
+            Spell::Disintegrate => mana.can_afford(disintegrate_constants::MIN_MANA_TO_CAST),
+            Spell::Fireball => mana.can_afford(fireball_constants::MANA_COST),
+            Spell::GuardianCircle => mana.can_afford(guardian_circle_constants::MANA_COST),
+            Spell::ChainLightning => mana.can_afford(chain_lightning_constants::MANA_COST),
+            Spell::FingerOfDeath => {
+                mana.percentage() >= finger_of_death_constants::MANA_REQUIREMENT_PERCENT
+            }
+            Spell::RaiseTheDead => mana.can_afford(raise_the_dead_constants::MANA_COST_PER_CORPSE),
+            Spell::Teleport => mana.can_afford(teleport_constants::MANA_COST),
+            Spell::WallOfStone => mana.can_afford(wall_of_stone_constants::MANA_COST),
+            Spell::FrostNova => mana.can_afford(frost_nova_constants::MANA_COST),
+            Spell::ConjureGuardian => mana.can_afford(conjure_guardian_constants::MANA_COST),
+            Spell::Blizzard => mana.can_afford(blizzard_constants::MANA_COST),
+        }
+    }
+
+    /// Returns true if this spell is limited to the wizard's spell range.
+    ///
+    /// Chain lightning seeks its own target and raise the dead works anywhere.
+    pub const fn is_range_limited(self) -> bool {
+        !matches!(self, Spell::ChainLightning | Spell::RaiseTheDead)
+    }
 }
 
 /// Component tracking which spell is currently primed for casting.
@@ -121,6 +183,15 @@ pub struct PrimedSpell {
     pub cast_time: f32,
 }
 
+/// Spell waiting to be primed once the current cast or channel ends.
+///
+/// Set when a spell is primed while the wizard is busy, so the prime isn't
+/// dropped and doesn't interrupt the cast in progress.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct QueuedSpell {
+    pub spell: PrimedSpell,
+}
+
 /// Message sent to prime a spell for casting.
 /// Used by UI systems to request spell changes without direct component access.
 #[derive(Message, Debug, Clone, Copy)]
@@ -128,6 +199,105 @@ pub struct PrimeSpellMessage {
     pub spell: PrimedSpell,
 }
 
+/// Message sent to unprime the current spell and cancel any cast in progress.
+///
+/// Each spell listens for it to despawn its own placement indicators.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct CancelSpellMessage;
+
+/// Message sent when a spell cast resolves, for the cast history log.
+///
+/// Instant spells send it once their effect lands. Channeled spells send one
+/// for the whole channel when it ends (see `ChannelTally`).
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct SpellCastRecord {
+    pub spell: Spell,
+    /// Mana spent on the cast.
+    pub mana_cost: f32,
+    /// Number of units hit or otherwise affected.
+    pub affected: u32,
+}
+
+/// Why a cast attempt was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastRejectReason {
+    /// The wizard doesn't have enough mana to start the spell.
+    NotEnoughMana,
+    /// The target is beyond the wizard's spell range.
+    OutOfRange,
+}
+
+/// Message sent when the player tries to cast a spell that can't be cast at the cursor.
+///
+/// Drives the fizzle sound and the mana bar flash.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CastRejected {
+    pub spell: Spell,
+    pub reason: CastRejectReason,
+}
+
+/// Returns why `spell` can't be cast at `target` right now, or None if it can.
+///
+/// The wizard needs enough mana, and range-limited spells need the target
+/// within `spell_range` of the wizard. Missing mana is reported first.
+pub fn cast_rejection(
+    spell: Spell,
+    mana: &Mana,
+    wizard_pos: Vec3,
+    spell_range: f32,
+    target: Vec3,
+) -> Option<CastRejectReason> {
+    if !spell.is_affordable(mana) {
+        Some(CastRejectReason::NotEnoughMana)
+    } else if spell.is_range_limited() && wizard_pos.distance(target) > spell_range {
+        Some(CastRejectReason::OutOfRange)
+    } else {
+        None
+    }
+}
+
+/// Running totals for a channeled spell on the wizard.
+///
+/// Reported as a single `SpellCastRecord` once the wizard stops channeling.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ChannelTally {
+    pub spell: Spell,
+    /// Mana spent so far this channel.
+    pub mana_spent: f32,
+    /// Distinct units affected so far this channel.
+    pub affected: Vec<Entity>,
+}
+
+impl ChannelTally {
+    /// Starts an empty tally for a channel of `spell`.
+    pub const fn new(spell: Spell) -> Self {
+        Self {
+            spell,
+            mana_spent: 0.0,
+            affected: Vec::new(),
+        }
+    }
+
+    /// Adds spent mana and affected units, counting each unit once.
+    pub fn add(&mut self, mana: f32, units: impl IntoIterator<Item = Entity>) {
+        self.mana_spent += mana;
+        for unit in units {
+            if !self.affected.contains(&unit) {
+                self.affected.push(unit);
+            }
+        }
+    }
+
+    /// Returns the record reported for this channel.
+    pub fn record(&self) -> SpellCastRecord {
+        SpellCastRecord {
+            spell: self.spell,
+            mana_cost: self.mana_spent,
+            affected: self.affected.len() as u32,
+        }
+    }
+}
+
 /// Wizard component with spell casting range.
 #[derive(Component)]
 pub struct Wizard {
@@ -144,19 +314,39 @@ impl Wizard {
 
 /// Mana component for the wizard.
 ///
-/// Tracks current and maximum mana for spell casting.
+/// Tracks current and maximum mana for spell casting, and how fast it refills.
 #[derive(Component)]
 pub struct Mana {
     /// Current mana amount.
     pub current: f32,
     /// Maximum mana capacity.
     pub max: f32,
+    /// Mana regenerated per second (before the difficulty multiplier).
+    pub regen_per_sec: f32,
 }
 
 impl Mana {
-    /// Creates a new Mana component with the given maximum.
-    pub fn new(max: f32) -> Self {
-        Self { current: max, max }
+    /// Creates a full Mana component with the given maximum and regeneration rate.
+    pub const fn new(max: f32, regen_per_sec: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            regen_per_sec,
+        }
+    }
+
+    /// Changes the maximum mana, as upgrades do.
+    ///
+    /// Raising the maximum grants the extra mana right away; lowering it
+    /// clamps the current mana.
+    pub fn set_max(&mut self, max: f32) {
+        self.current = (self.current + (max - self.max).max(0.0)).min(max);
+        self.max = max;
+    }
+
+    /// Fills mana back up to max.
+    pub fn refill(&mut self) {
+        self.current = self.max;
     }
 
     /// Returns true if there is enough mana for the cost.
@@ -179,6 +369,11 @@ impl Mana {
         self.current = (self.current + amount).min(self.max);
     }
 
+    /// Regenerates `delta` seconds' worth of mana at `regen_per_sec` scaled by `multiplier`.
+    pub fn tick(&mut self, delta: f32, multiplier: f32) {
+        self.regenerate(self.regen_per_sec * multiplier * delta);
+    }
+
     /// Returns mana as a percentage (0.0 to 1.0).
     pub fn percentage(&self) -> f32 {
         if self.max > 0.0 {
@@ -189,22 +384,6 @@ impl Mana {
     }
 }
 
-/// Mana regeneration component.
-///
-/// Defines how fast mana regenerates per second.
-#[derive(Component)]
-pub struct ManaRegen {
-    /// Mana regenerated per second.
-    pub rate: f32,
-}
-
-impl ManaRegen {
-    /// Creates a new ManaRegen component.
-    pub const fn new(rate: f32) -> Self {
-        Self { rate }
-    }
-}
-
 /// Casting state component for the wizard.
 ///
 /// Tracks active spell casting progress and channeling.
@@ -341,3 +520,51 @@ impl CastingState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regeneration_clamps_at_max() {
+        let mut mana = Mana::new(100.0, 5.0);
+        mana.current = 90.0;
+
+        mana.tick(1.0, 1.0);
+        assert_eq!(mana.current, 95.0);
+
+        // A long tick, scaled up by difficulty, stops at max
+        mana.tick(10.0, 1.25);
+        assert_eq!(mana.current, 100.0);
+
+        // A slower difficulty multiplier refills less
+        mana.current = 0.0;
+        mana.tick(2.0, 0.8);
+        assert_eq!(mana.current, 8.0);
+    }
+
+    #[test]
+    fn test_percentage_across_max_values() {
+        for max in [50.0, 100.0, 250.0] {
+            let mut mana = Mana::new(max, 0.0);
+            assert_eq!(mana.percentage(), 1.0);
+            mana.current = max / 4.0;
+            assert_eq!(mana.percentage(), 0.25);
+        }
+
+        let empty = Mana::new(0.0, 0.0);
+        assert_eq!(empty.percentage(), 0.0);
+    }
+
+    #[test]
+    fn test_raising_max_grants_the_difference() {
+        let mut mana = Mana::new(100.0, 5.0);
+        mana.current = 40.0;
+
+        mana.set_max(150.0);
+        assert_eq!((mana.current, mana.max), (90.0, 150.0));
+
+        mana.set_max(60.0);
+        assert_eq!((mana.current, mana.max), (60.0, 60.0));
+    }
+}
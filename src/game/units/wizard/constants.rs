@@ -2,6 +2,8 @@
 //!
 //! Contains all hardcoded values for wizard behavior.
 
+use bevy::prelude::KeyCode;
+
 /// Wizard hitbox radius.
 pub const HITBOX_RADIUS: f32 = 30.0;
 
@@ -19,3 +21,6 @@ pub const MANA_REGEN: f32 = 5.0;
 
 /// Wizard default spell range (units from wizard).
 pub const DEFAULT_SPELL_RANGE: f32 = 3000.0;
+
+/// Key that unprimes the current spell and cancels any cast in progress.
+pub const CANCEL_SPELL_KEY: KeyCode = KeyCode::KeyQ;
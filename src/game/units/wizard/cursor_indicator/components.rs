@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+
+use crate::game::units::wizard::components::{Mana, Spell, cast_rejection};
+
+/// Marker component for the ring that follows the cursor.
+#[derive(Component)]
+pub struct CursorRing;
+
+/// Returns true if `spell` can be cast at `target` right now.
+///
+/// The wizard needs enough mana, and range-limited spells need the target
+/// within `spell_range` of the wizard.
+pub fn is_castable(
+    spell: Spell,
+    mana: &Mana,
+    wizard_pos: Vec3,
+    spell_range: f32,
+    target: Vec3,
+) -> bool {
+    cast_rejection(spell, mana, wizard_pos, spell_range, target).is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_castable_needs_mana_and_range() {
+        let mut mana = Mana::new(100.0, 0.0);
+        let wizard_pos = Vec3::new(0.0, 100.0, 0.0);
+        let near = Vec3::new(50.0, 0.0, 0.0);
+        let far = Vec3::new(1000.0, 0.0, 0.0);
+
+        assert!(is_castable(Spell::Fireball, &mana, wizard_pos, 500.0, near));
+        assert!(!is_castable(Spell::Fireball, &mana, wizard_pos, 500.0, far));
+        // Chain lightning finds its own target, so range doesn't matter
+        assert!(is_castable(
+            Spell::ChainLightning,
+            &mana,
+            wizard_pos,
+            500.0,
+            far
+        ));
+
+        mana.current = 0.0;
+        assert!(!is_castable(
+            Spell::Fireball,
+            &mana,
+            wizard_pos,
+            500.0,
+            near
+        ));
+    }
+}
@@ -0,0 +1,15 @@
+//! Cursor indicator visual constants.
+
+use bevy::prelude::*;
+
+/// Color of the cursor ring when the primed spell can be cast (green).
+pub const CASTABLE_COLOR: Color = Color::srgba(0.3, 1.0, 0.4, 0.6);
+
+/// Color of the cursor ring when the primed spell cannot be cast (red).
+pub const NOT_CASTABLE_COLOR: Color = Color::srgba(1.0, 0.25, 0.2, 0.6);
+
+/// Radius of the cursor ring.
+pub const CURSOR_RING_RADIUS: f32 = 15.0;
+
+/// Thickness of the cursor ring.
+pub const CURSOR_RING_THICKNESS: f32 = 2.0;
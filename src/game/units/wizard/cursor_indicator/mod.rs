@@ -0,0 +1,10 @@
+//! Cursor indicator module.
+//!
+//! Shows a ring under the cursor that tells whether the primed spell can be cast there.
+
+mod components;
+mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::CursorIndicatorPlugin;
@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use crate::state::InGameState;
+
+use super::systems;
+
+/// Plugin that handles cursor castability feedback.
+///
+/// Shows a green ring under the cursor when the primed spell can be cast there,
+/// and a red one when mana is short or the cursor is out of range.
+pub struct CursorIndicatorPlugin;
+
+impl Plugin for CursorIndicatorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                systems::setup_cursor_indicator,
+                systems::update_cursor_indicator,
+            )
+                .chain()
+                .run_if(in_state(InGameState::Running)),
+        );
+    }
+}
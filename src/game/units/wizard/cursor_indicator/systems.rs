@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::*;
+use crate::game::components::OnGameplayScreen;
+use crate::game::input::components::CursorWorldPosition;
+use crate::game::units::wizard::components::{Mana, PrimedSpell, Wizard};
+
+/// Spawns the hidden cursor ring when the wizard is created.
+pub fn setup_cursor_indicator(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    wizard_query: Query<(), Added<Wizard>>,
+    ring_query: Query<Entity, With<CursorRing>>,
+) {
+    if wizard_query.is_empty() {
+        return;
+    }
+
+    // Replace any ring left over from a previous run
+    for entity in ring_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let material = materials.add(StandardMaterial {
+        base_color: CASTABLE_COLOR,
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+
+    // Torus lies flat on the ground (XZ plane) by default
+    let torus = Torus {
+        major_radius: CURSOR_RING_RADIUS,
+        minor_radius: CURSOR_RING_THICKNESS,
+    };
+
+    commands.spawn((
+        Mesh3d(meshes.add(torus)),
+        MeshMaterial3d(material),
+        Transform::from_xyz(0.0, 1.0, 0.0),
+        Visibility::Hidden,
+        CursorRing,
+        OnGameplayScreen,
+    ));
+}
+
+/// Moves the cursor ring to the cursor and colors it by castability.
+///
+/// The ring is hidden when no spell is primed or the cursor is off the battlefield.
+pub fn update_cursor_indicator(
+    cursor: Res<CursorWorldPosition>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    wizard_query: Query<(&Transform, &Wizard, &Mana, Option<&PrimedSpell>), Without<CursorRing>>,
+    mut ring_query: Query<
+        (
+            &mut Transform,
+            &mut Visibility,
+            &MeshMaterial3d<StandardMaterial>,
+        ),
+        With<CursorRing>,
+    >,
+) {
+    let Ok((mut ring_transform, mut visibility, material_handle)) = ring_query.single_mut() else {
+        return;
+    };
+
+    let Ok((wizard_transform, wizard, mana, Some(primed))) = wizard_query.single() else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    let Some(target) = cursor.ground else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    *visibility = Visibility::Visible;
+    ring_transform.translation = Vec3::new(target.x, 1.0, target.z);
+
+    let castable = is_castable(
+        primed.spell,
+        mana,
+        wizard_transform.translation,
+        wizard.spell_range,
+        target,
+    );
+    if let Some(material) = materials.get_mut(material_handle) {
+        material.base_color = if castable {
+            CASTABLE_COLOR
+        } else {
+            NOT_CASTABLE_COLOR
+        };
+    }
+}
@@ -3,11 +3,13 @@
 //! Handles the wizard entity, castle setup, and spells.
 
 pub mod components;
-mod constants;
+pub mod constants;
+mod cursor_indicator;
 mod plugin;
 mod spell_range_indicator;
 pub mod spells;
 mod styles;
 pub mod systems;
+mod ward;
 
 pub use plugin::WizardPlugin;
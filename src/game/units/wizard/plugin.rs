@@ -3,36 +3,70 @@ use bevy::prelude::*;
 use crate::game::run_conditions;
 use crate::state::{AppState, InGameState};
 
-use super::components::PrimeSpellMessage;
+use super::components::{CancelSpellMessage, CastRejected, PrimeSpellMessage, SpellCastRecord};
+use super::cursor_indicator::CursorIndicatorPlugin;
 use super::spell_range_indicator::SpellRangeIndicatorPlugin;
 use super::spells::SpellsPlugin;
 use super::systems;
+use super::ward::WardPlugin;
 
 /// Plugin that handles wizard entity setup and spells.
 ///
 /// Registers systems for:
-/// - Wizard entity setup on entering InGame state
+/// - Wizard entity setup on entering InGame state, priming the starting spell
 /// - Re-setup when entering Running state from GameOver (for replay)
 /// - Mana regeneration during gameplay
-/// - Spell priming via messages
+/// - Spell priming via messages, queueing the next spell during a cast
+/// - Spell cancelling via the cancel key
+/// - Reporting finished channels to the cast history
+/// - Reporting cast presses rejected for missing mana or range
+/// - Tallying lifetime casts and damage per spell (outside Practice)
 /// - Spell casting and projectile management (via SpellsPlugin)
 /// - Spell range visualization (via SpellRangeIndicatorPlugin)
+/// - Castability feedback at the cursor (via CursorIndicatorPlugin)
+/// - Repelling enemies that get close to the wizard (via WardPlugin)
 pub struct WizardPlugin;
 
 impl Plugin for WizardPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<PrimeSpellMessage>()
-            .add_plugins((SpellsPlugin, SpellRangeIndicatorPlugin))
-            .add_systems(OnEnter(AppState::InGame), systems::setup_wizard)
+            .add_message::<CancelSpellMessage>()
+            .add_message::<SpellCastRecord>()
+            .add_message::<CastRejected>()
+            .add_plugins((
+                SpellsPlugin,
+                SpellRangeIndicatorPlugin,
+                CursorIndicatorPlugin,
+                WardPlugin,
+            ))
+            .add_systems(
+                OnEnter(AppState::InGame),
+                (systems::setup_wizard, systems::prime_starting_spell).chain(),
+            )
             .add_systems(
                 OnEnter(InGameState::Running),
-                systems::setup_wizard.run_if(run_conditions::coming_from_game_over),
+                (systems::setup_wizard, systems::prime_starting_spell)
+                    .chain()
+                    .run_if(run_conditions::coming_from_game_over),
             )
             .add_systems(
                 Update,
                 (
                     systems::regenerate_mana,
-                    systems::handle_prime_spell_messages,
+                    systems::report_finished_channels,
+                    systems::detect_rejected_casts,
+                    systems::record_spell_stats.run_if(not(run_conditions::in_sandbox_mode)),
+                    (
+                        systems::handle_prime_spell_messages,
+                        systems::clear_queued_spell_on_right_click,
+                        systems::activate_queued_spell,
+                    )
+                        .chain(),
+                    (
+                        systems::send_cancel_spell_on_key,
+                        systems::handle_cancel_spell_messages,
+                    )
+                        .chain(),
                 )
                     .run_if(in_state(InGameState::Running)),
             )
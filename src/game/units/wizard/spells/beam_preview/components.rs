@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+/// Marker component for the beam aim preview line.
+#[derive(Component)]
+pub struct BeamPreview;
+
+/// Returns the point a beam from `origin` toward `target` ends at.
+///
+/// Targets beyond `range` are pulled back along the same line, matching how the
+/// beam spells clamp their aim when cast.
+pub fn clamped_beam_end(origin: Vec3, target: Vec3, range: f32) -> Vec3 {
+    let to_target = target - origin;
+    if to_target.length() > range {
+        origin + to_target.normalize() * range
+    } else {
+        target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_beam_end_clamps_to_range() {
+        let origin = Vec3::new(0.0, 100.0, 0.0);
+
+        let near = Vec3::new(30.0, 60.0, 0.0);
+        assert_eq!(clamped_beam_end(origin, near, 500.0), near);
+
+        let far = Vec3::new(1000.0, 100.0, 0.0);
+        let end = clamped_beam_end(origin, far, 500.0);
+        assert!((end.distance(origin) - 500.0).abs() < 1e-3);
+        assert!((end - Vec3::new(500.0, 100.0, 0.0)).length() < 1e-3);
+    }
+}
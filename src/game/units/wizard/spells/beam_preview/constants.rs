@@ -0,0 +1,15 @@
+//! Beam aim preview visual constants.
+
+use bevy::prelude::*;
+
+/// Width of the preview line, thinner than the real beams.
+pub const PREVIEW_WIDTH: f32 = 3.0;
+
+/// Opacity of the preview line.
+pub const PREVIEW_ALPHA: f32 = 0.3;
+
+/// Preview color for Finger of Death (purple).
+pub const FINGER_OF_DEATH_PREVIEW_COLOR: Color = Color::srgb(0.6, 0.0, 0.8);
+
+/// Preview color for Disintegrate (green).
+pub const DISINTEGRATE_PREVIEW_COLOR: Color = Color::srgb(0.0, 1.0, 0.0);
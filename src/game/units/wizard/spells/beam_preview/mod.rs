@@ -0,0 +1,12 @@
+//! Beam aim preview module.
+//!
+//! While a beam spell is primed but not yet being cast, a thin translucent line
+//! runs from the wizard to where the beam would fire. The real beam replaces it
+//! once casting starts.
+
+mod components;
+mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::BeamPreviewPlugin;
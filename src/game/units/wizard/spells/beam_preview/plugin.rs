@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use crate::state::InGameState;
+
+use super::systems;
+
+/// Plugin that previews where a primed beam spell will fire.
+///
+/// Registers systems for:
+/// - Spawning the hidden preview line with the wizard
+/// - Aiming the preview at the clamped cursor target while a beam spell is primed
+pub struct BeamPreviewPlugin;
+
+impl Plugin for BeamPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (systems::setup_beam_preview, systems::update_beam_preview)
+                .chain()
+                .run_if(in_state(InGameState::Running)),
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::*;
+use crate::game::components::OnGameplayScreen;
+use crate::game::constants::WIZARD_POSITION;
+use crate::game::input::components::CursorWorldPosition;
+use crate::game::units::components::Hitbox;
+use crate::game::units::wizard::components::{CastingState, PrimedSpell, Spell, Wizard};
+use crate::game::units::wizard::spells::targeting::{TargetableUnits, cursor_target};
+use crate::game::units::wizard::spells::{disintegrate_constants, finger_of_death_constants};
+
+/// Spawns the hidden preview line when the wizard is created.
+pub fn setup_beam_preview(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    wizard_query: Query<(), Added<Wizard>>,
+    preview_query: Query<Entity, With<BeamPreview>>,
+) {
+    if wizard_query.is_empty() {
+        return;
+    }
+
+    // Replace any preview left over from a previous run
+    for entity in preview_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let material = materials.add(StandardMaterial {
+        base_color: FINGER_OF_DEATH_PREVIEW_COLOR.with_alpha(PREVIEW_ALPHA),
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+
+    commands.spawn((
+        Mesh3d(meshes.add(Rectangle::new(PREVIEW_WIDTH, PREVIEW_WIDTH))),
+        MeshMaterial3d(material),
+        Transform::default(),
+        Visibility::Hidden,
+        BeamPreview,
+        OnGameplayScreen,
+    ));
+}
+
+/// Stretches the preview line from the wizard to the clamped cursor target.
+///
+/// Only shown while Finger of Death or Disintegrate is primed and the wizard
+/// is resting. Once casting starts the spell's own beam takes over.
+pub fn update_beam_preview(
+    cursor: Res<CursorWorldPosition>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    wizard_query: Query<(&Wizard, &CastingState, Option<&PrimedSpell>)>,
+    hitbox_query: TargetableUnits,
+    mut preview_query: Query<
+        (
+            &mut Transform,
+            &mut Visibility,
+            &MeshMaterial3d<StandardMaterial>,
+        ),
+        (With<BeamPreview>, Without<Hitbox>),
+    >,
+) {
+    let Ok((mut transform, mut visibility, material_handle)) = preview_query.single_mut() else {
+        return;
+    };
+
+    let Ok((wizard, casting_state, Some(primed))) = wizard_query.single() else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    let (height_offset, color) = match primed.spell {
+        Spell::FingerOfDeath => (
+            finger_of_death_constants::BEAM_ORIGIN_HEIGHT_OFFSET,
+            FINGER_OF_DEATH_PREVIEW_COLOR,
+        ),
+        Spell::Disintegrate => (
+            disintegrate_constants::BEAM_ORIGIN_HEIGHT_OFFSET,
+            DISINTEGRATE_PREVIEW_COLOR,
+        ),
+        _ => {
+            *visibility = Visibility::Hidden;
+            return;
+        }
+    };
+
+    let Some(target) = cursor_target(&cursor, &hitbox_query).map(|target| target.position()) else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    if !matches!(casting_state, CastingState::Resting) {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    let origin = WIZARD_POSITION + Vec3::new(0.0, height_offset, 0.0);
+    let end = clamped_beam_end(origin, target, wizard.spell_range);
+    let length = origin.distance(end);
+    if length <= f32::EPSILON {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    *visibility = Visibility::Visible;
+    transform.translation = (origin + end) / 2.0;
+    transform.rotation = Quat::from_rotation_arc(Vec3::Y, (end - origin) / length);
+    transform.scale = Vec3::new(1.0, length / PREVIEW_WIDTH, 1.0);
+
+    if let Some(material) = materials.get_mut(material_handle) {
+        material.base_color = color.with_alpha(PREVIEW_ALPHA);
+    }
+}
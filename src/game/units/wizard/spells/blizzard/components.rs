@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+/// Marker component indicating the wizard is actively casting Blizzard.
+///
+/// The circle_entity is None after cast completes but before mouse release.
+#[derive(Component)]
+pub struct BlizzardCaster {
+    /// Entity ID of the visual circle indicator (None if despawned).
+    pub circle_entity: Option<Entity>,
+}
+
+/// Visual indicator for the Blizzard area during casting.
+#[derive(Component)]
+pub struct BlizzardIndicator {
+    /// Position of the circle center.
+    pub position: Vec3,
+}
+
+/// Persistent zone on the ground that damages enemies standing in it.
+///
+/// Damage is dealt every `TICK_INTERVAL` seconds. The effect despawns once
+/// `remaining` runs out.
+#[derive(Component, Debug, Clone)]
+pub struct GroundEffect {
+    /// Center of the zone on the ground.
+    pub center: Vec3,
+    /// Radius of the zone.
+    pub radius: f32,
+    /// Damage per second dealt to each enemy inside.
+    pub dps: f32,
+    /// Seconds left before the zone expires.
+    pub remaining: f32,
+    /// Time since the last damage tick (in seconds).
+    pub time_since_last_tick: f32,
+    /// Mana spent on the cast, for the cast history log.
+    pub mana_cost: f32,
+    /// Distinct units damaged so far, for the cast history log.
+    pub hit_entities: Vec<Entity>,
+}
+
+impl GroundEffect {
+    /// Creates a zone lasting `duration` seconds.
+    pub const fn new(center: Vec3, radius: f32, dps: f32, duration: f32, mana_cost: f32) -> Self {
+        Self {
+            center,
+            radius,
+            dps,
+            remaining: duration,
+            time_since_last_tick: 0.0,
+            mana_cost,
+            hit_entities: Vec::new(),
+        }
+    }
+
+    /// Returns true if a position lies inside the zone (measured on the XZ plane).
+    pub fn contains(&self, position: Vec3) -> bool {
+        let diff_x = position.x - self.center.x;
+        let diff_z = position.z - self.center.z;
+        diff_x * diff_x + diff_z * diff_z <= self.radius * self.radius
+    }
+
+    /// Counts down the zone's lifetime. Returns true once it has expired.
+    pub fn tick(&mut self, delta: f32) -> bool {
+        self.remaining -= delta;
+        self.remaining <= 0.0
+    }
+}
@@ -0,0 +1,31 @@
+use crate::game::units::wizard::components::{PrimedSpell, Spell};
+
+/// PrimedSpell constant for Blizzard.
+pub const PRIMED_BLIZZARD: PrimedSpell = PrimedSpell {
+    spell: Spell::Blizzard,
+    cast_time: CAST_TIME,
+};
+
+/// Cast time for Blizzard in seconds.
+pub const CAST_TIME: f32 = 1.25;
+
+/// Mana cost for casting Blizzard.
+pub const MANA_COST: f32 = 30.0;
+
+/// Radius of the blizzard zone in units.
+pub const ZONE_RADIUS: f32 = 150.0;
+
+/// Damage per second dealt to each enemy standing in the zone.
+pub const DAMAGE_PER_SECOND: f32 = 12.0;
+
+/// Seconds between damage ticks while an enemy stands in the zone.
+pub const TICK_INTERVAL: f32 = 0.5;
+
+/// How long the zone lasts in seconds.
+pub const ZONE_DURATION: f32 = 6.0;
+
+/// Duration of the fade-out at the end of the zone (seconds).
+pub const FADE_DURATION: f32 = 1.0;
+
+/// Y position of the circle indicator and zone disc (slightly above ground).
+pub const CIRCLE_Y_POSITION: f32 = 1.0;
@@ -0,0 +1,11 @@
+//! Blizzard spell module.
+//!
+//! Handles a ground-effect spell that leaves a freezing zone damaging enemies over time.
+
+pub mod components;
+pub mod constants;
+mod plugin;
+mod styles;
+mod systems;
+
+pub use plugin::BlizzardPlugin;
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+use super::super::super::components::Spell;
+use super::super::run_conditions::*;
+use super::systems;
+use crate::state::InGameState;
+
+/// Plugin that handles Blizzard spell casting and behavior.
+///
+/// Registers systems for:
+/// - Casting Blizzard with mouse button and cast time
+/// - Visual circle indicator during cast
+/// - Damaging enemies standing in placed zones
+/// - Pulsing zone visuals and expiry
+/// - Indicator cleanup when the primed spell is cancelled
+pub struct BlizzardPlugin;
+
+impl Plugin for BlizzardPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                systems::handle_blizzard_casting
+                    .run_if(spell_is_primed(Spell::Blizzard))
+                    .run_if(spell_input_not_blocked)
+                    .run_if(mouse_left_not_consumed)
+                    .run_if(mouse_held_or_wizard_casting),
+                systems::update_circle_indicator,
+                systems::cancel_blizzard_on_spell_cancelled,
+                systems::apply_ground_effects,
+                systems::pulse_ground_effects,
+            )
+                .chain()
+                .run_if(in_state(InGameState::Running)),
+        );
+    }
+}
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Color of the circle indicator during casting (pale frost white).
+/// Translucent to show the battlefield underneath.
+pub const CIRCLE_COLOR: Color = Color::srgba(0.8, 0.9, 1.0, 0.3);
+
+/// Color of the blizzard zone once it has been placed.
+pub const ZONE_COLOR: Color = Color::srgba(0.6, 0.8, 1.0, 0.35);
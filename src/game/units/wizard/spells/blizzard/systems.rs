@@ -0,0 +1,406 @@
+use bevy::prelude::*;
+
+use super::super::super::components::{
+    CancelSpellMessage, CastingState, Mana, PrimedSpell, Spell, SpellCastRecord, Wizard,
+};
+use super::super::circle_placement::{CircleIndicator, place_circle_on_ground};
+use super::components::{BlizzardCaster, BlizzardIndicator, GroundEffect};
+use super::constants;
+use super::styles::{CIRCLE_COLOR, ZONE_COLOR};
+use crate::game::input::MouseButtonState;
+use crate::game::input::components::CursorWorldPosition;
+use crate::game::input::events::MouseLeftReleased;
+use crate::game::units::components::{
+    Corpse, DamageDealt, DamageSource, DamageType, Health, Resistances, Team, TemporaryHitPoints,
+    apply_damage_and_report,
+};
+
+/// Handles Blizzard casting with left-click.
+///
+/// Left-click starts cast. Must hold for full cast time.
+/// After cast completes, leaves a freezing zone that damages enemies standing in it.
+/// Only casts when Blizzard is the primed spell.
+///
+/// Note: Spell priming, input blocking, and mouse state checks are handled by run_if conditions.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_blizzard_casting(
+    time: Res<Time>,
+    mut mouse_state: ResMut<MouseButtonState>,
+    mut mouse_left_released: MessageReader<MouseLeftReleased>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut wizard_query: Query<
+        (
+            Entity,
+            &Transform,
+            &Wizard,
+            &mut CastingState,
+            &mut Mana,
+            &PrimedSpell,
+        ),
+        With<Wizard>,
+    >,
+    cursor: Res<CursorWorldPosition>,
+    mut caster_query: Query<&mut BlizzardCaster, With<Wizard>>,
+    mut indicator_query: Query<&mut BlizzardIndicator>,
+) {
+    let Ok((wizard_entity, wizard_transform, wizard, mut casting_state, mut mana, primed_spell)) =
+        wizard_query.single_mut()
+    else {
+        return;
+    };
+
+    // Check for release event - this is spell-specific logic
+    if mouse_left_released.read().next().is_some() {
+        // Cancel cast on release
+        if let Ok(caster) = caster_query.single() {
+            if let Some(circle_entity) = caster.circle_entity {
+                commands.entity(circle_entity).despawn();
+            }
+            commands.entity(wizard_entity).remove::<BlizzardCaster>();
+        }
+        casting_state.cancel();
+        return;
+    }
+
+    let Some(cursor_world_pos) = cursor.ground else {
+        return;
+    };
+
+    // Clamp so the entire zone stays within the ground circle of the wizard's spell range
+    let cursor_world_pos = place_circle_on_ground(
+        cursor_world_pos,
+        wizard_transform.translation,
+        wizard.spell_range,
+        constants::ZONE_RADIUS,
+    );
+
+    match *casting_state {
+        CastingState::Resting => {
+            // The marker persists after cast completion until mouse release
+            if caster_query.single().is_err() && mana.can_afford(constants::MANA_COST) {
+                let circle_entity = CircleIndicator {
+                    radius: constants::ZONE_RADIUS,
+                    color: CIRCLE_COLOR,
+                    alpha_mode: AlphaMode::Blend,
+                }
+                .spawn(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    Transform::from_xyz(
+                        cursor_world_pos.x,
+                        constants::CIRCLE_Y_POSITION,
+                        cursor_world_pos.z,
+                    ),
+                    BlizzardIndicator {
+                        position: cursor_world_pos,
+                    },
+                );
+
+                commands.entity(wizard_entity).insert(BlizzardCaster {
+                    circle_entity: Some(circle_entity),
+                });
+
+                casting_state.start_cast();
+            }
+        }
+        CastingState::Casting { .. } => {
+            casting_state.advance(time.delta_secs());
+
+            // Update circle position to follow cursor
+            if let Ok(caster) = caster_query.single()
+                && let Some(circle_entity) = caster.circle_entity
+                && let Ok(mut indicator) = indicator_query.get_mut(circle_entity)
+            {
+                indicator.position = cursor_world_pos;
+            }
+
+            if !casting_state.is_complete(primed_spell.cast_time) {
+                return;
+            }
+
+            if let Ok(mut caster) = caster_query.single_mut() {
+                if let Some(circle_entity) = caster.circle_entity {
+                    if mana.consume(constants::MANA_COST)
+                        && let Ok(indicator) = indicator_query.get(circle_entity)
+                    {
+                        let effect = GroundEffect::new(
+                            indicator.position,
+                            constants::ZONE_RADIUS,
+                            constants::DAMAGE_PER_SECOND,
+                            constants::ZONE_DURATION,
+                            constants::MANA_COST,
+                        );
+                        spawn_ground_effect(&mut commands, &mut meshes, &mut materials, effect);
+                    }
+                    commands.entity(circle_entity).despawn();
+                }
+
+                // Clear circle entity reference but keep marker to prevent immediate recast
+                caster.circle_entity = None;
+            }
+
+            casting_state.cancel();
+            mouse_state.left_consumed = true; // Require release before next cast
+        }
+        CastingState::Channeling { .. } => {
+            // Blizzard doesn't use channeling, cancel if we somehow get here
+            if let Ok(caster) = caster_query.single() {
+                if let Some(circle_entity) = caster.circle_entity {
+                    commands.entity(circle_entity).despawn();
+                }
+                commands.entity(wizard_entity).remove::<BlizzardCaster>();
+            }
+            casting_state.cancel();
+        }
+    }
+}
+
+/// Despawns the circle indicator when the primed spell is cancelled.
+pub fn cancel_blizzard_on_spell_cancelled(
+    mut cancel_spell: MessageReader<CancelSpellMessage>,
+    mut commands: Commands,
+    caster_query: Query<(Entity, &BlizzardCaster), With<Wizard>>,
+) {
+    if cancel_spell.read().next().is_none() {
+        return;
+    }
+
+    if let Ok((wizard_entity, caster)) = caster_query.single() {
+        if let Some(circle_entity) = caster.circle_entity {
+            commands.entity(circle_entity).despawn();
+        }
+        commands.entity(wizard_entity).remove::<BlizzardCaster>();
+    }
+}
+
+/// Keeps the circle indicator under the cursor during casting.
+pub fn update_circle_indicator(mut indicators: Query<(&BlizzardIndicator, &mut Transform)>) {
+    for (indicator, mut transform) in indicators.iter_mut() {
+        transform.translation.x = indicator.position.x;
+        transform.translation.y = constants::CIRCLE_Y_POSITION;
+        transform.translation.z = indicator.position.z;
+    }
+}
+
+/// Damages enemies (Attackers and Undead) standing in ground effects.
+///
+/// Every `TICK_INTERVAL` each enemy inside takes a tick of damage, reported as
+/// Blizzard damage. Effects despawn once their duration runs out, recording the
+/// cast with every unit they hit along the way.
+#[allow(clippy::type_complexity)]
+pub fn apply_ground_effects(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut effects: Query<(Entity, &mut GroundEffect)>,
+    mut targets: Query<
+        (
+            Entity,
+            &Transform,
+            &Team,
+            &mut Health,
+            Option<&mut TemporaryHitPoints>,
+            Option<&Resistances>,
+        ),
+        (Without<Wizard>, Without<Corpse>),
+    >,
+    mut damage_dealt: MessageWriter<DamageDealt>,
+    mut records: MessageWriter<SpellCastRecord>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut effect) in &mut effects {
+        effect.time_since_last_tick += delta;
+
+        if effect.time_since_last_tick >= constants::TICK_INTERVAL {
+            effect.time_since_last_tick -= constants::TICK_INTERVAL;
+            let damage = effect.dps * constants::TICK_INTERVAL;
+
+            for (target, transform, team, mut health, mut temp_hp, resistances) in &mut targets {
+                if *team == Team::Defenders || !effect.contains(transform.translation) {
+                    continue;
+                }
+
+                apply_damage_and_report(
+                    &mut health,
+                    temp_hp.as_deref_mut(),
+                    resistances,
+                    damage,
+                    DamageType::Magic,
+                    DamageSource::Spell(Spell::Blizzard),
+                    target,
+                    transform.translation,
+                    &mut damage_dealt,
+                );
+                if !effect.hit_entities.contains(&target) {
+                    effect.hit_entities.push(target);
+                }
+            }
+        }
+
+        if effect.tick(delta) {
+            records.write(SpellCastRecord {
+                spell: Spell::Blizzard,
+                mana_cost: effect.mana_cost,
+                affected: effect.hit_entities.len() as u32,
+            });
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Pulses the zone disc and fades it out as the effect runs out.
+pub fn pulse_ground_effects(
+    time: Res<Time>,
+    mut effects: Query<(
+        &GroundEffect,
+        &mut Transform,
+        &MeshMaterial3d<StandardMaterial>,
+    )>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let t = time.elapsed_secs();
+
+    for (effect, mut transform, material_handle) in &mut effects {
+        let pulse = 1.0 + 0.04 * (t * 3.0).sin();
+        transform.scale = Vec3::splat(pulse);
+
+        let Some(material) = materials.get_mut(&material_handle.0) else {
+            continue;
+        };
+
+        let fade = (effect.remaining / constants::FADE_DURATION).clamp(0.0, 1.0);
+        let shimmer = 0.85 + 0.15 * (t * 5.0).sin();
+        material.base_color = ZONE_COLOR.with_alpha(ZONE_COLOR.alpha() * fade * shimmer);
+    }
+}
+
+/// Spawns the zone disc carrying the ground effect.
+fn spawn_ground_effect(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    effect: GroundEffect,
+) {
+    CircleIndicator {
+        radius: effect.radius,
+        color: ZONE_COLOR,
+        alpha_mode: AlphaMode::Blend,
+    }
+    .spawn(
+        commands,
+        meshes,
+        materials,
+        Transform::from_xyz(
+            effect.center.x,
+            constants::CIRCLE_Y_POSITION,
+            effect.center.z,
+        ),
+        effect,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn ground_effect_app(remaining: f32) -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_message::<DamageDealt>()
+            .add_message::<SpellCastRecord>()
+            .add_systems(Update, apply_ground_effects);
+        app.world_mut().spawn(GroundEffect::new(
+            Vec3::ZERO,
+            100.0,
+            10.0,
+            remaining,
+            constants::MANA_COST,
+        ));
+        app
+    }
+
+    fn advance(app: &mut App, millis: u64) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(millis));
+        app.update();
+    }
+
+    fn spawn_target(app: &mut App, team: Team, position: Vec3) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::from_translation(position),
+                team,
+                Health::new(100.0),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_ground_effect_damages_only_enemies_inside() {
+        let mut app = ground_effect_app(10.0);
+
+        let inside = spawn_target(&mut app, Team::Attackers, Vec3::new(50.0, 0.0, 0.0));
+        let outside = spawn_target(&mut app, Team::Attackers, Vec3::new(200.0, 0.0, 0.0));
+        let defender = spawn_target(&mut app, Team::Defenders, Vec3::new(50.0, 0.0, 0.0));
+
+        for _ in 0..4 {
+            advance(&mut app, 250);
+        }
+
+        let health = |entity| app.world().get::<Health>(entity).unwrap().current;
+        assert!((health(inside) - 90.0).abs() < 1e-3);
+        assert_eq!(health(outside), 100.0);
+        assert_eq!(health(defender), 100.0);
+    }
+
+    #[test]
+    fn test_ground_effect_despawns_when_expired() {
+        let mut app = ground_effect_app(0.5);
+        let effect = app
+            .world_mut()
+            .query_filtered::<Entity, With<GroundEffect>>()
+            .single(app.world())
+            .unwrap();
+
+        for _ in 0..6 {
+            advance(&mut app, 100);
+        }
+
+        assert!(app.world().get_entity(effect).is_err());
+    }
+
+    #[test]
+    fn test_ground_effect_reports_each_tick_and_every_unit_hit() {
+        let mut app = ground_effect_app(1.0);
+        spawn_target(&mut app, Team::Attackers, Vec3::new(50.0, 0.0, 0.0));
+
+        advance(&mut app, 500);
+        // Walks into the zone after the cast
+        spawn_target(&mut app, Team::Undead, Vec3::new(-50.0, 0.0, 0.0));
+        advance(&mut app, 500);
+
+        let hits: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Messages<DamageDealt>>()
+            .drain()
+            .collect();
+        assert_eq!(hits.len(), 3);
+        assert!(
+            hits.iter()
+                .all(|hit| hit.source == DamageSource::Spell(Spell::Blizzard))
+        );
+
+        let records: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Messages<SpellCastRecord>>()
+            .drain()
+            .collect();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].affected, 2);
+    }
+}
@@ -1,23 +1,28 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
 
-use super::super::super::components::{CastingState, Mana, PrimedSpell, Wizard};
+use super::super::super::components::{
+    CastingState, Mana, PrimedSpell, Spell, SpellCastRecord, Wizard,
+};
+use super::super::targeting::{CursorTarget, TargetableUnits, cursor_target};
 use super::components::*;
 use super::constants;
 use super::styles::arc_color;
 use crate::game::components::OnGameplayScreen;
 use crate::game::constants::WIZARD_POSITION;
 use crate::game::input::MouseButtonState;
+use crate::game::input::components::CursorWorldPosition;
 use crate::game::input::events::MouseLeftReleased;
 use crate::game::units::components::{
-    Corpse, Health, Team, TemporaryHitPoints, apply_damage_to_unit,
+    Corpse, DamageDealt, DamageSource, DamageType, Health, Resistances, Team, TemporaryHitPoints,
+    apply_damage_and_report, nearest_by_distance,
 };
 use crate::game::units::wizard::spells::wall_of_stone::components::WallOfStone;
 
 /// Handles chain lightning casting with left-click.
 ///
 /// Left-click starts cast. Must hold for full cast time.
-/// After cast completes, finds enemy under cursor and spawns chain lightning bolt.
+/// After cast completes, targets the unit under the cursor (raycast against hitboxes),
+/// or the closest unit near the cursor's ground point, and spawns chain lightning bolt.
 /// Only casts when ChainLightning is the primed spell.
 ///
 /// Note: Spell priming, input blocking, and mouse state checks are handled by run_if conditions.
@@ -30,10 +35,16 @@ pub fn handle_chain_lightning_casting(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut wizard_query: Query<(&mut CastingState, &mut Mana, &PrimedSpell), With<Wizard>>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    cursor: Res<CursorWorldPosition>,
     enemies_query: Query<(Entity, &Transform, &Team), Without<Corpse>>,
-    mut health_query: Query<(&mut Health, Option<&mut TemporaryHitPoints>)>,
+    hitbox_query: TargetableUnits,
+    mut health_query: Query<(
+        &mut Health,
+        Option<&mut TemporaryHitPoints>,
+        Option<&Resistances>,
+    )>,
+    mut damage_dealt: MessageWriter<DamageDealt>,
+    mut records: MessageWriter<SpellCastRecord>,
 ) {
     let Ok((mut casting_state, mut mana, primed_spell)) = wizard_query.single_mut() else {
         return;
@@ -59,23 +70,36 @@ pub fn handle_chain_lightning_casting(
             // Check if cast is complete
             if casting_state.is_complete(primed_spell.cast_time) {
                 // Cast complete - consume mana and find initial target
-                if mana.consume(constants::MANA_COST)
-                    && let Some(cursor_pos) =
-                        get_cursor_world_position(&camera_query, &window_query)
-                {
-                    // Find enemy near cursor
-                    if let Some((target_entity, target_pos)) =
-                        find_target_near_position(cursor_pos, &enemies_query)
-                    {
+                if mana.consume(constants::MANA_COST) {
+                    // Prefer the unit directly under the cursor, otherwise the one nearest the ground point
+                    let target =
+                        cursor_target(&cursor, &hitbox_query).and_then(
+                            |cursor_hit| match cursor_hit {
+                                CursorTarget::Unit(entity, position) => Some((entity, position)),
+                                CursorTarget::Ground(position) => {
+                                    find_target_near_position(position, &enemies_query)
+                                }
+                            },
+                        );
+
+                    if let Some((target_entity, target_pos)) = target {
                         let wizard_pos =
                             WIZARD_POSITION + Vec3::new(0.0, constants::SPAWN_HEIGHT_OFFSET, 0.0);
 
                         // Apply initial damage
-                        if let Ok((mut health, mut temp_hp)) = health_query.get_mut(target_entity) {
-                            apply_damage_to_unit(
+                        if let Ok((mut health, mut temp_hp, resistances)) =
+                            health_query.get_mut(target_entity)
+                        {
+                            apply_damage_and_report(
                                 &mut health,
                                 temp_hp.as_deref_mut(),
+                                resistances,
                                 constants::INITIAL_DAMAGE,
+                                DamageType::Magic,
+                                DamageSource::Spell(Spell::ChainLightning),
+                                target_entity,
+                                target_pos,
+                                &mut damage_dealt,
                             );
                         }
 
@@ -100,6 +124,13 @@ pub fn handle_chain_lightning_casting(
                             },
                             OnGameplayScreen,
                         ));
+                    } else {
+                        // Nothing to strike - the mana is spent anyway
+                        records.write(SpellCastRecord {
+                            spell: Spell::ChainLightning,
+                            mana_cost: constants::MANA_COST,
+                            affected: 0,
+                        });
                     }
                 }
 
@@ -117,30 +148,6 @@ pub fn handle_chain_lightning_casting(
     }
 }
 
-/// Gets the cursor position projected onto the battlefield surface (Y=0 plane).
-fn get_cursor_world_position(
-    camera_query: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: &Query<&Window, With<PrimaryWindow>>,
-) -> Option<Vec3> {
-    let (camera, camera_transform) = camera_query.single().ok()?;
-    let window = window_query.single().ok()?;
-    let cursor_pos = window.cursor_position()?;
-
-    // Create a ray from the camera through the cursor position
-    let ray = camera
-        .viewport_to_world(camera_transform, cursor_pos)
-        .ok()?;
-
-    // Intersect ray with Y=0 plane (battlefield surface)
-    let t = -ray.origin.y / ray.direction.y;
-
-    if t > 0.0 {
-        Some(ray.origin + ray.direction * t)
-    } else {
-        None
-    }
-}
-
 /// Finds the closest enemy near the given position within TARGETING_RADIUS.
 /// Note: position should be at Y=0 (battlefield plane). Uses XZ distance for targeting.
 /// Targets all living units (defenders, attackers, and undead) but excludes corpses.
@@ -151,22 +158,19 @@ fn find_target_near_position(
     // Use XZ distance only (ignore Y difference) for targeting
     let target_pos_2d = Vec3::new(position.x, 0.0, position.z);
 
-    enemies
-        .iter()
-        // No team filter - spell damages ALL units indiscriminately
-        .filter(|(_, transform, _)| {
-            let unit_pos_2d = Vec3::new(transform.translation.x, 0.0, transform.translation.z);
-            let distance = target_pos_2d.distance(unit_pos_2d);
-            distance <= constants::TARGETING_RADIUS
-        })
-        .min_by(|a, b| {
-            let a_pos_2d = Vec3::new(a.1.translation.x, 0.0, a.1.translation.z);
-            let b_pos_2d = Vec3::new(b.1.translation.x, 0.0, b.1.translation.z);
-            let dist_a = target_pos_2d.distance(a_pos_2d);
-            let dist_b = target_pos_2d.distance(b_pos_2d);
-            dist_a.partial_cmp(&dist_b).unwrap()
-        })
-        .map(|(entity, transform, _)| (entity, transform.translation))
+    let distance_2d = |transform: &Transform| {
+        let unit_pos_2d = Vec3::new(transform.translation.x, 0.0, transform.translation.z);
+        target_pos_2d.distance(unit_pos_2d)
+    };
+
+    nearest_by_distance(
+        enemies
+            .iter()
+            // No team filter - spell damages ALL units indiscriminately
+            .filter(|(_, transform, _)| distance_2d(transform) <= constants::TARGETING_RADIUS),
+        |(_, transform, _)| distance_2d(transform),
+    )
+    .map(|(entity, transform, _)| (entity, transform.translation))
 }
 
 /// Spawns a lightning arc visual between two points.
@@ -209,6 +213,8 @@ fn spawn_arc(
 
 /// Processes chain lightning bounces to nearby enemies.
 /// Targets all living units (defenders, attackers, and undead) but excludes corpses.
+/// Reports the cast once the chain ends.
+#[allow(clippy::too_many_arguments)]
 pub fn process_chain_lightning_bounces(
     time: Res<Time>,
     mut commands: Commands,
@@ -222,10 +228,13 @@ pub fn process_chain_lightning_bounces(
             &Team,
             &mut Health,
             Option<&mut TemporaryHitPoints>,
+            Option<&Resistances>,
         ),
         Without<Corpse>,
     >,
     walls: Query<&WallOfStone>,
+    mut damage_dealt: MessageWriter<DamageDealt>,
+    mut records: MessageWriter<SpellCastRecord>,
 ) {
     for (bolt_entity, mut bolt) in &mut bolts {
         // Decrement bounce delay timer
@@ -244,8 +253,20 @@ pub fn process_chain_lightning_bounces(
                     })
             {
                 // Apply damage to target
-                if let Ok((_, _, _, mut health, mut temp_hp)) = enemies.get_mut(target_entity) {
-                    apply_damage_to_unit(&mut health, temp_hp.as_deref_mut(), bolt.current_damage);
+                if let Ok((_, _, _, mut health, mut temp_hp, resistances)) =
+                    enemies.get_mut(target_entity)
+                {
+                    apply_damage_and_report(
+                        &mut health,
+                        temp_hp.as_deref_mut(),
+                        resistances,
+                        bolt.current_damage,
+                        DamageType::Magic,
+                        DamageSource::Spell(Spell::ChainLightning),
+                        target_entity,
+                        target_pos,
+                        &mut damage_dealt,
+                    );
                 }
 
                 // Spawn arc from last position to new target
@@ -271,6 +292,11 @@ pub fn process_chain_lightning_bounces(
 
         // Despawn bolt if no more bounces
         if bolt.bounces_remaining == 0 && bolt.bounce_delay_timer <= 0.0 {
+            records.write(SpellCastRecord {
+                spell: Spell::ChainLightning,
+                mana_cost: constants::MANA_COST,
+                affected: bolt.hit_entities.len() as u32,
+            });
             commands.entity(bolt_entity).despawn();
         }
     }
@@ -288,23 +314,22 @@ fn find_next_bounce_target(
             &Team,
             &mut Health,
             Option<&mut TemporaryHitPoints>,
+            Option<&Resistances>,
         ),
         Without<Corpse>,
     >,
 ) -> Option<(Entity, Vec3)> {
-    enemies
-        .iter()
-        // No team filter - spell damages ALL units indiscriminately
-        .filter(|(entity, _, _, _, _)| !hit_entities.contains(entity))
-        .filter(|(_, transform, _, _, _)| {
-            origin.distance(transform.translation) <= constants::BOUNCE_RANGE
-        })
-        .min_by(|a, b| {
-            let dist_a = origin.distance(a.1.translation);
-            let dist_b = origin.distance(b.1.translation);
-            dist_a.partial_cmp(&dist_b).unwrap()
-        })
-        .map(|(entity, transform, _, _, _)| (entity, transform.translation))
+    nearest_by_distance(
+        enemies
+            .iter()
+            // No team filter - spell damages ALL units indiscriminately
+            .filter(|(entity, _, _, _, _, _)| !hit_entities.contains(entity))
+            .filter(|(_, transform, _, _, _, _)| {
+                origin.distance(transform.translation) <= constants::BOUNCE_RANGE
+            }),
+        |(_, transform, _, _, _, _)| origin.distance(transform.translation),
+    )
+    .map(|(entity, transform, _, _, _, _)| (entity, transform.translation))
 }
 
 /// Updates chain lightning arc visuals with pulsing animation.
@@ -0,0 +1,132 @@
+//! Shared placement for spells that target a circle on the ground.
+//!
+//! Targeted spells project the cursor onto the battlefield, clamp it to the
+//! wizard's spell range, and show a flat circle where the spell will land.
+//! These helpers cover the clamping and the indicator mesh.
+
+use bevy::prelude::*;
+
+use crate::game::components::OnGameplayScreen;
+
+/// Clamps a target so it lies within `range` of the wizard (straight-line distance).
+pub fn place_circle(cursor: Vec3, wizard_pos: Vec3, range: f32) -> Vec3 {
+    let diff = cursor - wizard_pos;
+    let distance = diff.length();
+
+    if distance > range {
+        wizard_pos + diff.normalize() * range
+    } else {
+        cursor
+    }
+}
+
+/// Clamps a circle's center so the whole circle stays inside the spell range on the ground.
+///
+/// The wizard stands above the battlefield, so the spell range reaches the ground
+/// as a circle of radius √(spell_range² - wizard_height²). The center is pulled in
+/// by `circle_radius` so no part of the circle pokes out of it.
+pub fn place_circle_on_ground(
+    cursor: Vec3,
+    wizard_pos: Vec3,
+    spell_range: f32,
+    circle_radius: f32,
+) -> Vec3 {
+    let wizard_height = wizard_pos.y;
+    let max_ground_radius = if wizard_height < spell_range {
+        (spell_range * spell_range - wizard_height * wizard_height).sqrt()
+    } else {
+        0.0
+    };
+    let max_center_distance = (max_ground_radius - circle_radius).max(0.0);
+
+    // Measured on the XZ plane
+    let direction = cursor - wizard_pos;
+    let distance = (direction.x * direction.x + direction.z * direction.z).sqrt();
+    if distance > max_center_distance && distance > 0.001 {
+        wizard_pos + direction / distance * max_center_distance
+    } else {
+        cursor
+    }
+}
+
+/// Look of a flat circle indicator laid on the battlefield.
+#[derive(Debug, Clone, Copy)]
+pub struct CircleIndicator {
+    /// Radius of the circle mesh.
+    pub radius: f32,
+    /// Unlit circle color.
+    pub color: Color,
+    /// Blending for translucent colors.
+    pub alpha_mode: AlphaMode,
+}
+
+impl CircleIndicator {
+    /// Spawns the circle lying flat at `transform`, along with `bundle`.
+    ///
+    /// Returns the spawned entity. It is cleaned up with the rest of the gameplay screen.
+    pub fn spawn(
+        &self,
+        commands: &mut Commands,
+        meshes: &mut Assets<Mesh>,
+        materials: &mut Assets<StandardMaterial>,
+        transform: Transform,
+        bundle: impl Bundle,
+    ) -> Entity {
+        commands
+            .spawn((
+                Mesh3d(meshes.add(Circle::new(self.radius))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: self.color,
+                    unlit: true,
+                    alpha_mode: self.alpha_mode,
+                    ..default()
+                })),
+                transform.with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+                bundle,
+                OnGameplayScreen,
+            ))
+            .id()
+    }
+}
+
+/// Moves a circle indicator to follow a ground position, keeping its height.
+pub fn move_circle_indicator(transform: &mut Transform, position: Vec3) {
+    transform.translation.x = position.x;
+    transform.translation.z = position.z;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIZARD: Vec3 = Vec3::new(0.0, 300.0, 0.0);
+
+    #[test]
+    fn test_place_circle_keeps_targets_in_range() {
+        let target = Vec3::new(100.0, 0.0, 0.0);
+        assert_eq!(place_circle(target, WIZARD, 1000.0), target);
+    }
+
+    #[test]
+    fn test_place_circle_clamps_to_range() {
+        let placed = place_circle(Vec3::new(4000.0, 0.0, 0.0), WIZARD, 500.0);
+        assert!((placed.distance(WIZARD) - 500.0).abs() < 1e-3);
+        assert!(placed.x > 0.0 && placed.z.abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_place_circle_on_ground_keeps_whole_circle_in_range() {
+        // Ground reach is √(500² - 300²) = 400, minus a radius of 100
+        let placed = place_circle_on_ground(Vec3::new(0.0, 0.0, 1000.0), WIZARD, 500.0, 100.0);
+        assert!((placed.x.hypot(placed.z) - 300.0).abs() < 1e-3);
+
+        let target = Vec3::new(0.0, 0.0, 250.0);
+        assert_eq!(place_circle_on_ground(target, WIZARD, 500.0, 100.0), target);
+    }
+
+    #[test]
+    fn test_place_circle_on_ground_out_of_reach_centers_under_wizard() {
+        let placed = place_circle_on_ground(Vec3::new(200.0, 0.0, 0.0), WIZARD, 250.0, 50.0);
+        assert_eq!((placed.x, placed.z), (0.0, 0.0));
+    }
+}
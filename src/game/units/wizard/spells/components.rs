@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::game::units::wizard::components::Spell;
+
 /// Base component for all spell projectiles.
 ///
 /// Represents a spell projectile traveling through the battlefield.
@@ -13,6 +15,8 @@ pub struct Projectile {
     pub damage: f32,
     /// Radius of the projectile for collision detection.
     pub radius: f32,
+    /// Spell that fired the projectile, credited with its damage.
+    pub spell: Spell,
 }
 
 /// Marker component for spell effects (explosions, area effects, etc.).
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+/// Marker component indicating the wizard is actively casting Conjure Guardian.
+///
+/// The circle_entity is None after cast completes but before mouse release.
+#[derive(Component)]
+pub struct ConjureGuardianCaster {
+    /// Entity ID of the visual circle indicator (None if despawned).
+    pub circle_entity: Option<Entity>,
+}
+
+/// Visual indicator for the guardian's arrival point during casting.
+#[derive(Component)]
+pub struct ConjureGuardianIndicator {
+    /// Position of the circle center.
+    pub position: Vec3,
+    /// Time this indicator has been active (for animations).
+    pub time_alive: f32,
+}
+
+impl ConjureGuardianIndicator {
+    /// Creates a new circle indicator.
+    pub const fn new(position: Vec3) -> Self {
+        Self {
+            position,
+            time_alive: 0.0,
+        }
+    }
+
+    /// Returns the current scale factor for pulse animation.
+    ///
+    /// Pulsates between 0.9 and 1.1 during cast time.
+    pub fn pulse_scale(&self) -> f32 {
+        let pulse_freq = 1.5; // Hz
+        let pulse_amplitude = 0.1;
+        1.0 + (self.time_alive * pulse_freq * std::f32::consts::TAU).sin() * pulse_amplitude
+    }
+}
+
+/// Marker for a defender summoned by Conjure Guardian.
+#[derive(Component)]
+pub struct ConjuredGuardian;
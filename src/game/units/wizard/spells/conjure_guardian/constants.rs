@@ -0,0 +1,28 @@
+use crate::game::units::wizard::components::{PrimedSpell, Spell};
+
+/// PrimedSpell constant for Conjure Guardian.
+pub const PRIMED_CONJURE_GUARDIAN: PrimedSpell = PrimedSpell {
+    spell: Spell::ConjureGuardian,
+    cast_time: CAST_TIME,
+};
+
+/// Cast time for Conjure Guardian in seconds.
+pub const CAST_TIME: f32 = 1.5;
+
+/// Mana cost for casting Conjure Guardian.
+pub const MANA_COST: f32 = 45.0;
+
+/// Guardian health (ten regular infantry).
+pub const GUARDIAN_HEALTH: f32 = 500.0;
+
+/// Guardian hitbox radius (twice a regular infantry unit).
+pub const GUARDIAN_RADIUS: f32 = 16.0;
+
+/// Guardian hitbox height.
+pub const GUARDIAN_HEIGHT: f32 = 50.0;
+
+/// Time the guardian fights before vanishing, in seconds.
+pub const GUARDIAN_LIFESPAN: f32 = 20.0;
+
+/// Y position of the circle indicator (slightly above ground).
+pub const CIRCLE_Y_POSITION: f32 = 1.0;
@@ -0,0 +1,11 @@
+//! Conjure Guardian spell module.
+//!
+//! Handles support spell that summons a temporary defender at a target location.
+
+pub mod components;
+pub mod constants;
+mod plugin;
+mod styles;
+mod systems;
+
+pub use plugin::ConjureGuardianPlugin;
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use super::super::super::components::Spell;
+use super::super::run_conditions::*;
+use super::systems;
+use crate::state::InGameState;
+
+/// Plugin that handles Conjure Guardian spell casting.
+///
+/// Registers systems for:
+/// - Casting Conjure Guardian with mouse button and cast time
+/// - Visual circle indicator during cast
+/// - Indicator cleanup when the primed spell is cancelled
+///
+/// The guardian fights as a regular defender and is despawned by the shared
+/// `Temporary` lifespan system.
+pub struct ConjureGuardianPlugin;
+
+impl Plugin for ConjureGuardianPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                systems::handle_conjure_guardian_casting
+                    .run_if(spell_is_primed(Spell::ConjureGuardian))
+                    .run_if(spell_input_not_blocked)
+                    .run_if(mouse_left_not_consumed)
+                    .run_if(mouse_held_or_wizard_casting),
+                systems::update_circle_indicator,
+                systems::cancel_conjure_guardian_on_spell_cancelled,
+            )
+                .chain()
+                .run_if(in_state(InGameState::Running)),
+        );
+    }
+}
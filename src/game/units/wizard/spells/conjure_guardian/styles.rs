@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Color of the circle indicator during casting (pale gold).
+/// Translucent to show the battlefield underneath.
+pub const CIRCLE_COLOR: Color = Color::srgba(1.0, 0.9, 0.5, 0.3);
+
+/// Color of the conjured guardian (bright gold, to stand out from regular defenders).
+pub const GUARDIAN_COLOR: Color = Color::srgb(1.0, 0.75, 0.1);
@@ -0,0 +1,262 @@
+use bevy::prelude::*;
+
+use super::super::super::components::{
+    CancelSpellMessage, CastingState, Mana, PrimedSpell, Spell, SpellCastRecord, Wizard,
+};
+use super::super::circle_placement::{CircleIndicator, place_circle_on_ground};
+use super::components::{ConjureGuardianCaster, ConjureGuardianIndicator, ConjuredGuardian};
+use super::constants;
+use super::styles::{CIRCLE_COLOR, GUARDIAN_COLOR};
+use crate::game::components::{Acceleration, Billboard, OnGameplayScreen, Velocity};
+use crate::game::constants::UNIT_MOVEMENT_SPEED;
+use crate::game::input::MouseButtonState;
+use crate::game::input::components::CursorWorldPosition;
+use crate::game::input::events::MouseLeftReleased;
+use crate::game::units::components::{
+    AttackTiming, Effectiveness, FlockingVelocity, Health, Hitbox, MovementSpeed,
+    TargetingVelocity, Team, Teleportable, Temporary, spawn_attack_offset,
+};
+use crate::game::units::infantry::components::Infantry;
+
+/// Handles Conjure Guardian casting with left-click.
+///
+/// Left-click starts cast. Must hold for full cast time.
+/// After cast completes, summons a guardian at the indicator position.
+/// Only casts when Conjure Guardian is the primed spell.
+///
+/// Note: Spell priming, input blocking, and mouse state checks are handled by run_if conditions.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_conjure_guardian_casting(
+    time: Res<Time>,
+    mut mouse_state: ResMut<MouseButtonState>,
+    mut mouse_left_released: MessageReader<MouseLeftReleased>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut wizard_query: Query<
+        (
+            Entity,
+            &Transform,
+            &Wizard,
+            &mut CastingState,
+            &mut Mana,
+            &PrimedSpell,
+        ),
+        With<Wizard>,
+    >,
+    cursor: Res<CursorWorldPosition>,
+    mut caster_query: Query<&mut ConjureGuardianCaster, With<Wizard>>,
+    mut indicator_query: Query<&mut ConjureGuardianIndicator>,
+    mut records: MessageWriter<SpellCastRecord>,
+) {
+    let Ok((wizard_entity, wizard_transform, wizard, mut casting_state, mut mana, primed_spell)) =
+        wizard_query.single_mut()
+    else {
+        return;
+    };
+
+    // Check for release event - this is spell-specific logic
+    if mouse_left_released.read().next().is_some() {
+        // Cancel cast on release
+        if let Ok(caster) = caster_query.single() {
+            if let Some(circle_entity) = caster.circle_entity {
+                commands.entity(circle_entity).despawn();
+            }
+            commands
+                .entity(wizard_entity)
+                .remove::<ConjureGuardianCaster>();
+        }
+        casting_state.cancel();
+        return;
+    }
+
+    let Some(cursor_world_pos) = cursor.ground else {
+        return;
+    };
+
+    // Clamp so the guardian arrives within the ground circle of the wizard's spell range
+    let cursor_world_pos = place_circle_on_ground(
+        cursor_world_pos,
+        wizard_transform.translation,
+        wizard.spell_range,
+        constants::GUARDIAN_RADIUS,
+    );
+
+    match *casting_state {
+        CastingState::Resting => {
+            // The marker persists after cast completion until mouse release
+            if caster_query.single().is_err() && mana.can_afford(constants::MANA_COST) {
+                let circle_entity = spawn_circle_indicator(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    cursor_world_pos,
+                );
+
+                commands
+                    .entity(wizard_entity)
+                    .insert(ConjureGuardianCaster {
+                        circle_entity: Some(circle_entity),
+                    });
+
+                casting_state.start_cast();
+            }
+        }
+        CastingState::Casting { .. } => {
+            casting_state.advance(time.delta_secs());
+
+            // Update circle position to follow cursor
+            if let Ok(caster) = caster_query.single()
+                && let Some(circle_entity) = caster.circle_entity
+                && let Ok(mut indicator) = indicator_query.get_mut(circle_entity)
+            {
+                indicator.position = cursor_world_pos;
+            }
+
+            if !casting_state.is_complete(primed_spell.cast_time) {
+                return;
+            }
+
+            if let Ok(mut caster) = caster_query.single_mut() {
+                if let Some(circle_entity) = caster.circle_entity {
+                    if mana.consume(constants::MANA_COST)
+                        && let Ok(indicator) = indicator_query.get(circle_entity)
+                    {
+                        spawn_guardian(
+                            &mut commands,
+                            &mut meshes,
+                            &mut materials,
+                            indicator.position,
+                        );
+                        records.write(SpellCastRecord {
+                            spell: Spell::ConjureGuardian,
+                            mana_cost: constants::MANA_COST,
+                            affected: 1,
+                        });
+                    }
+                    commands.entity(circle_entity).despawn();
+                }
+
+                // Clear circle entity reference but keep marker to prevent immediate recast
+                caster.circle_entity = None;
+            }
+
+            casting_state.cancel();
+            mouse_state.left_consumed = true; // Require release before next cast
+        }
+        CastingState::Channeling { .. } => {
+            // Conjure Guardian doesn't use channeling, cancel if we somehow get here
+            if let Ok(caster) = caster_query.single() {
+                if let Some(circle_entity) = caster.circle_entity {
+                    commands.entity(circle_entity).despawn();
+                }
+                commands
+                    .entity(wizard_entity)
+                    .remove::<ConjureGuardianCaster>();
+            }
+            casting_state.cancel();
+        }
+    }
+}
+
+/// Despawns the circle indicator when the primed spell is cancelled.
+pub fn cancel_conjure_guardian_on_spell_cancelled(
+    mut cancel_spell: MessageReader<CancelSpellMessage>,
+    mut commands: Commands,
+    caster_query: Query<(Entity, &ConjureGuardianCaster), With<Wizard>>,
+) {
+    if cancel_spell.read().next().is_none() {
+        return;
+    }
+
+    if let Ok((wizard_entity, caster)) = caster_query.single() {
+        if let Some(circle_entity) = caster.circle_entity {
+            commands.entity(circle_entity).despawn();
+        }
+        commands
+            .entity(wizard_entity)
+            .remove::<ConjureGuardianCaster>();
+    }
+}
+
+/// Updates circle indicator visuals during casting.
+pub fn update_circle_indicator(
+    time: Res<Time>,
+    mut indicators: Query<(&mut ConjureGuardianIndicator, &mut Transform)>,
+) {
+    for (mut indicator, mut transform) in indicators.iter_mut() {
+        indicator.time_alive += time.delta_secs();
+
+        transform.scale = Vec3::splat(indicator.pulse_scale());
+        transform.translation.x = indicator.position.x;
+        transform.translation.y = constants::CIRCLE_Y_POSITION;
+        transform.translation.z = indicator.position.z;
+    }
+}
+
+/// Spawns the translucent circle indicator shown during the cast.
+fn spawn_circle_indicator(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    position: Vec3,
+) -> Entity {
+    CircleIndicator {
+        radius: constants::GUARDIAN_RADIUS * 2.0,
+        color: CIRCLE_COLOR,
+        alpha_mode: AlphaMode::Blend,
+    }
+    .spawn(
+        commands,
+        meshes,
+        materials,
+        Transform::from_xyz(position.x, constants::CIRCLE_Y_POSITION, position.z),
+        ConjureGuardianIndicator::new(position),
+    )
+}
+
+/// Spawns the guardian as a defender infantry unit with a limited lifespan.
+///
+/// It uses the same components as a regular defender, so targeting, flocking,
+/// movement, and combat treat it like any other infantry.
+fn spawn_guardian(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    position: Vec3,
+) {
+    let hitbox = Hitbox::new(constants::GUARDIAN_RADIUS, constants::GUARDIAN_HEIGHT);
+    let circle = Circle::new(hitbox.radius);
+
+    // Position unit so bottom edge is 1 unit above battlefield (Y=0)
+    let spawn_y = hitbox.height / 2.0 + 1.0;
+
+    commands
+        .spawn((
+            Mesh3d(meshes.add(circle)),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: GUARDIAN_COLOR,
+                unlit: true,
+                ..default()
+            })),
+            Transform::from_xyz(position.x, spawn_y, position.z),
+            Velocity::default(),
+            Acceleration::new(),
+            hitbox,
+            Health::new(constants::GUARDIAN_HEALTH),
+            MovementSpeed(UNIT_MOVEMENT_SPEED),
+            AttackTiming::with_offset(spawn_attack_offset(position.x, position.z)),
+            Effectiveness::new(),
+            Team::Defenders,
+            Infantry,
+        ))
+        .insert((
+            TargetingVelocity::default(),
+            FlockingVelocity::default(),
+            Teleportable,
+            Billboard,
+            ConjuredGuardian,
+            Temporary::new(constants::GUARDIAN_LIFESPAN),
+            OnGameplayScreen,
+        ));
+}
@@ -2,6 +2,12 @@ use bevy::prelude::*;
 
 use super::constants;
 
+/// Marker component for disintegrate spell when it's actively being cast/channeled.
+///
+/// This differentiates disintegrate from magic missile casting states.
+#[derive(Component)]
+pub struct DisintegrateCaster;
+
 /// Component for disintegrate beam.
 ///
 /// The beam is a continuous ray that deals damage to entities along its path.
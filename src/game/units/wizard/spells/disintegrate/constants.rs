@@ -25,6 +25,9 @@ pub const BEAM_COLOR: Color = Color::srgb(0.0, 1.0, 0.0);
 /// Mana cost per second while channeling.
 pub const MANA_COST_PER_SECOND: f32 = 20.0;
 
+/// Mana needed to start a cast: a tenth of a second of channeling.
+pub const MIN_MANA_TO_CAST: f32 = MANA_COST_PER_SECOND * 0.1;
+
 /// Beam length (extends through the battlefield).
 pub const BEAM_LENGTH: f32 = 5000.0;
 
@@ -2,7 +2,7 @@
 //!
 //! Handles disintegrate beam spell that damages enemies in a continuous line.
 
-mod components;
+pub mod components;
 pub mod constants;
 mod plugin;
 mod systems;
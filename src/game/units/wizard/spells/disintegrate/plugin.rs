@@ -11,7 +11,7 @@ use crate::state::InGameState;
 /// - Casting disintegrate beam with right-click
 /// - Beam damage application
 /// - Beam visual updates
-/// - Cleanup when casting stops
+/// - Cleanup when casting stops or the primed spell is cancelled
 pub struct DisintegratePlugin;
 
 impl Plugin for DisintegratePlugin {
@@ -26,6 +26,7 @@ impl Plugin for DisintegratePlugin {
                     .run_if(mouse_held_or_wizard_casting),
                 systems::update_beam_visuals,
                 systems::apply_disintegrate_damage,
+                systems::cancel_disintegrate_on_spell_cancelled,
                 systems::cleanup_beams_on_cancel,
             )
                 .chain()
@@ -1,19 +1,18 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
 
-use super::super::super::components::{CastingState, Mana, PrimedSpell, Wizard};
-use super::components::DisintegrateBeam;
+use super::super::super::components::{
+    CancelSpellMessage, CastingState, ChannelTally, Mana, PrimedSpell, Spell, Wizard,
+};
+use super::components::{DisintegrateBeam, DisintegrateCaster};
 use super::constants;
 use crate::game::components::OnGameplayScreen;
 use crate::game::constants::WIZARD_POSITION;
+use crate::game::input::components::CursorWorldPosition;
 use crate::game::input::events::MouseLeftReleased;
-use crate::game::units::components::{Health, TemporaryHitPoints, apply_damage_to_unit};
-
-/// Marker component for disintegrate spell when it's actively being cast/channeled.
-///
-/// This differentiates disintegrate from magic missile casting states.
-#[derive(Component)]
-pub struct DisintegrateCaster;
+use crate::game::units::components::{
+    DamageDealt, DamageSource, DamageType, Health, Resistances, TemporaryHitPoints,
+    apply_damage_and_report,
+};
 
 /// System that handles disintegrate beam casting.
 ///
@@ -27,14 +26,20 @@ pub fn handle_disintegrate_casting(
     time: Res<Time>,
     mut left_released: MessageReader<MouseLeftReleased>,
     mut commands: Commands,
-    mut wizard_query: Query<(Entity, &mut CastingState, &mut Mana, &PrimedSpell, &Wizard)>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut wizard_query: Query<(
+        Entity,
+        &mut CastingState,
+        &mut Mana,
+        &PrimedSpell,
+        &Wizard,
+        Option<&mut ChannelTally>,
+    )>,
+    cursor: Res<CursorWorldPosition>,
     mut beams: Query<(Entity, &mut DisintegrateBeam)>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    let Ok((wizard_entity, mut casting_state, mut mana, primed_spell, wizard)) =
+    let Ok((wizard_entity, mut casting_state, mut mana, primed_spell, wizard, tally)) =
         wizard_query.single_mut()
     else {
         return;
@@ -68,8 +73,12 @@ pub fn handle_disintegrate_casting(
             let mana_cost = constants::MANA_COST_PER_SECOND * time.delta_secs();
 
             if mana.consume(mana_cost) {
+                if let Some(mut tally) = tally {
+                    tally.add(mana_cost, []);
+                }
+
                 // Update beam position based on cursor
-                if let Some(target_pos) = get_cursor_world_position(&camera_query, &window_query) {
+                if let Some(target_pos) = cursor.ground {
                     let beam_origin =
                         WIZARD_POSITION + Vec3::new(0.0, constants::BEAM_ORIGIN_HEIGHT_OFFSET, 0.0);
 
@@ -128,9 +137,12 @@ pub fn handle_disintegrate_casting(
             if casting_state.is_complete(primed_spell.cast_time) {
                 // Cast complete - transition to channeling and spawn first beam
                 casting_state.start_channeling();
+                commands
+                    .entity(wizard_entity)
+                    .insert(ChannelTally::new(Spell::Disintegrate));
 
                 // Spawn initial beam
-                if let Some(target_pos) = get_cursor_world_position(&camera_query, &window_query) {
+                if let Some(target_pos) = cursor.ground {
                     let beam_origin =
                         WIZARD_POSITION + Vec3::new(0.0, constants::BEAM_ORIGIN_HEIGHT_OFFSET, 0.0);
 
@@ -162,7 +174,7 @@ pub fn handle_disintegrate_casting(
         CastingState::Resting => {
             // Not casting or channeling - check mana before starting cast
             // Need enough mana for at least 0.1 seconds of channeling
-            if mana.can_afford(constants::MANA_COST_PER_SECOND * 0.1) {
+            if mana.can_afford(constants::MIN_MANA_TO_CAST) {
                 casting_state.start_cast();
 
                 // Add caster marker to wizard
@@ -172,46 +184,30 @@ pub fn handle_disintegrate_casting(
     }
 }
 
-/// Gets the cursor position projected onto the battlefield surface (Y=0 plane).
-fn get_cursor_world_position(
-    camera_query: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: &Query<&Window, With<PrimaryWindow>>,
-) -> Option<Vec3> {
-    let (camera, camera_transform) = camera_query.single().ok()?;
-    let window = window_query.single().ok()?;
-    let cursor_pos = window.cursor_position()?;
-
-    // Create a ray from the camera through the cursor position
-    let ray = camera
-        .viewport_to_world(camera_transform, cursor_pos)
-        .ok()?;
-
-    // Intersect ray with Y=0 plane (battlefield surface)
-    // Ray equation: origin + direction * t
-    // Plane equation: y = 0
-    // Solve for t: origin.y + direction.y * t = 0
-    let t = -ray.origin.y / ray.direction.y;
-
-    if t > 0.0 {
-        Some(ray.origin + ray.direction * t)
-    } else {
-        None
-    }
-}
-
 /// System that applies damage to all units hit by disintegrate beams.
 ///
 /// This is a high-risk spell that damages both attackers and defenders,
-/// but not the wizard.
+/// but not the wizard. Units hit are added to the wizard's channel tally.
+#[allow(clippy::type_complexity)]
 pub fn apply_disintegrate_damage(
     mut beam_query: Query<&mut DisintegrateBeam>,
     mut target_query: Query<
-        (&Transform, &mut Health, Option<&mut TemporaryHitPoints>),
+        (
+            Entity,
+            &Transform,
+            &mut Health,
+            Option<&mut TemporaryHitPoints>,
+            Option<&Resistances>,
+        ),
         Without<Wizard>,
     >,
     walls: Query<&crate::game::units::wizard::spells::wall_of_stone::components::WallOfStone>,
+    mut tally_query: Query<&mut ChannelTally, With<Wizard>>,
     time: Res<Time>,
+    mut damage_dealt: MessageWriter<DamageDealt>,
 ) {
+    let mut tally = tally_query.single_mut().ok();
+
     for mut beam in beam_query.iter_mut() {
         beam.update_damage_timer(time.delta_secs());
         beam.update_time_alive(time.delta_secs());
@@ -227,17 +223,27 @@ pub fn apply_disintegrate_damage(
         let effective_length = beam.current_length() * max_t;
 
         if beam.should_damage() {
-            for (transform, mut health, mut temp_hp) in target_query.iter_mut() {
+            for (entity, transform, mut health, mut temp_hp, resistances) in target_query.iter_mut()
+            {
                 let position = transform.translation;
                 // Check if point is in beam AND before the wall
                 if beam.contains_point(position) {
                     let proj = (position - beam.origin).dot(beam.direction);
                     if proj <= effective_length {
-                        apply_damage_to_unit(
+                        apply_damage_and_report(
                             &mut health,
                             temp_hp.as_deref_mut(),
+                            resistances,
                             constants::DAMAGE_PER_TICK,
+                            DamageType::Magic,
+                            DamageSource::Spell(Spell::Disintegrate),
+                            entity,
+                            position,
+                            &mut damage_dealt,
                         );
+                        if let Some(tally) = tally.as_mut() {
+                            tally.add(0.0, [entity]);
+                        }
                     }
                 }
             }
@@ -247,6 +253,25 @@ pub fn apply_disintegrate_damage(
     }
 }
 
+/// Drops the caster marker when the primed spell is cancelled.
+///
+/// `cleanup_beams_on_cancel` then despawns the beam.
+pub fn cancel_disintegrate_on_spell_cancelled(
+    mut cancel_spell: MessageReader<CancelSpellMessage>,
+    mut commands: Commands,
+    wizard_query: Query<Entity, (With<Wizard>, With<DisintegrateCaster>)>,
+) {
+    if cancel_spell.read().next().is_none() {
+        return;
+    }
+
+    if let Ok(wizard_entity) = wizard_query.single() {
+        commands
+            .entity(wizard_entity)
+            .remove::<DisintegrateCaster>();
+    }
+}
+
 /// System that despawns beams when wizard is not actively channeling disintegrate.
 pub fn cleanup_beams_on_cancel(
     mut commands: Commands,
@@ -1,19 +1,26 @@
 use bevy::prelude::*;
 use bevy::render::alpha::AlphaMode;
-use bevy::window::PrimaryWindow;
 
-use super::super::super::components::{CastingState, Mana, PrimedSpell, Wizard};
+use super::super::super::components::{
+    CastingState, Mana, PrimedSpell, Spell, SpellCastRecord, Wizard,
+};
+use super::super::targeting::{TargetableUnits, cursor_target};
 use super::components::*;
 use super::constants;
 use crate::game::components::OnGameplayScreen;
 use crate::game::constants::WIZARD_POSITION;
 use crate::game::input::MouseButtonState;
+use crate::game::input::components::CursorWorldPosition;
 use crate::game::input::events::MouseLeftReleased;
-use crate::game::units::components::{Health, TemporaryHitPoints, apply_damage_to_unit};
+use crate::game::units::components::{
+    DamageDealt, DamageSource, DamageType, Health, Resistances, TemporaryHitPoints,
+    apply_damage_and_report,
+};
 
 /// Handles Finger of Death casting with left-click.
 ///
 /// Left-click starts cast (if mana > 0). Beam spawns immediately and grows during cast.
+/// The beam aims at the unit under the cursor if one is hit, otherwise the cursor's ground point.
 /// After 2s cast completes, beam fires instantly dealing massive damage.
 /// Only casts when Finger of Death is the primed spell.
 ///
@@ -27,8 +34,8 @@ pub fn handle_finger_of_death_casting(
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut wizard_query: Query<(Entity, &mut CastingState, &Mana, &PrimedSpell, &Wizard)>,
     awaiting_release_query: Query<(), With<AwaitingFingerOfDeathRelease>>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    cursor: Res<CursorWorldPosition>,
+    hitbox_query: TargetableUnits,
     mut beams: Query<(Entity, &mut FingerOfDeathBeam)>,
 ) {
     let Ok((wizard_entity, mut casting_state, mana, primed_spell, wizard)) =
@@ -66,7 +73,9 @@ pub fn handle_finger_of_death_casting(
             casting_state.advance(time.delta_secs());
 
             // Update beam position/direction to follow cursor
-            if let Some(cursor_pos) = get_cursor_world_position(&camera_query, &window_query) {
+            if let Some(cursor_pos) =
+                cursor_target(&cursor, &hitbox_query).map(|target| target.position())
+            {
                 let beam_origin =
                     WIZARD_POSITION + Vec3::new(0.0, constants::BEAM_ORIGIN_HEIGHT_OFFSET, 0.0);
 
@@ -115,7 +124,9 @@ pub fn handle_finger_of_death_casting(
                 casting_state.start_cast();
 
                 // Spawn initial beam
-                if let Some(cursor_pos) = get_cursor_world_position(&camera_query, &window_query) {
+                if let Some(cursor_pos) =
+                    cursor_target(&cursor, &hitbox_query).map(|target| target.position())
+                {
                     let beam_origin =
                         WIZARD_POSITION + Vec3::new(0.0, constants::BEAM_ORIGIN_HEIGHT_OFFSET, 0.0);
 
@@ -141,30 +152,6 @@ pub fn handle_finger_of_death_casting(
     }
 }
 
-/// Gets the cursor position projected onto the battlefield surface (Y=0 plane).
-fn get_cursor_world_position(
-    camera_query: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: &Query<&Window, With<PrimaryWindow>>,
-) -> Option<Vec3> {
-    let (camera, camera_transform) = camera_query.single().ok()?;
-    let window = window_query.single().ok()?;
-    let cursor_pos = window.cursor_position()?;
-
-    // Create a ray from the camera through the cursor position
-    let ray = camera
-        .viewport_to_world(camera_transform, cursor_pos)
-        .ok()?;
-
-    // Intersect ray with Y=0 plane (battlefield surface)
-    let t = -ray.origin.y / ray.direction.y;
-
-    if t > 0.0 {
-        Some(ray.origin + ray.direction * t)
-    } else {
-        None
-    }
-}
-
 /// Spawns a Finger of Death beam entity with visual mesh and spiral particles.
 fn spawn_beam(
     commands: &mut Commands,
@@ -206,12 +193,24 @@ fn spawn_beam(
 /// Applies 1000 damage instantly to all units along beam (hitscan).
 /// Drains wizard's entire mana bar and cancels casting state.
 /// Adds AwaitingFingerOfDeathRelease component to prevent immediate recast.
+#[allow(clippy::too_many_arguments)]
 pub fn apply_finger_of_death_damage(
     mut mouse_state: ResMut<MouseButtonState>,
     mut beams: Query<&mut FingerOfDeathBeam>,
-    mut targets: Query<(&Transform, &mut Health, Option<&mut TemporaryHitPoints>), Without<Wizard>>,
+    mut targets: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Health,
+            Option<&mut TemporaryHitPoints>,
+            Option<&Resistances>,
+        ),
+        Without<Wizard>,
+    >,
     mut wizard_query: Query<(&mut Mana, &mut CastingState), With<Wizard>>,
     walls: Query<&crate::game::units::wizard::spells::wall_of_stone::components::WallOfStone>,
+    mut damage_dealt: MessageWriter<DamageDealt>,
+    mut records: MessageWriter<SpellCastRecord>,
 ) {
     for mut beam in beams.iter_mut() {
         // Only apply damage if cast is complete and hasn't fired yet
@@ -233,17 +232,35 @@ pub fn apply_finger_of_death_damage(
         let effective_length = beam.length * max_t;
 
         // Apply damage to all units along beam (before wall)
-        for (transform, mut health, mut temp_hp) in targets.iter_mut() {
+        let mut hits = 0;
+        for (entity, transform, mut health, mut temp_hp, resistances) in targets.iter_mut() {
             if beam.contains_point(transform.translation, constants::BEAM_WIDTH) {
                 let proj = (transform.translation - beam.origin).dot(beam.direction);
                 if proj <= effective_length {
-                    apply_damage_to_unit(&mut health, temp_hp.as_deref_mut(), constants::DAMAGE);
+                    // Death magic ignores resistances
+                    apply_damage_and_report(
+                        &mut health,
+                        temp_hp.as_deref_mut(),
+                        resistances,
+                        constants::DAMAGE,
+                        DamageType::True,
+                        DamageSource::Spell(Spell::FingerOfDeath),
+                        entity,
+                        transform.translation,
+                        &mut damage_dealt,
+                    );
+                    hits += 1;
                 }
             }
         }
 
         // Drain entire mana bar, cancel casting state, and add awaiting release marker
         if let Ok((mut mana, mut casting_state)) = wizard_query.single_mut() {
+            records.write(SpellCastRecord {
+                spell: Spell::FingerOfDeath,
+                mana_cost: mana.current,
+                affected: hits,
+            });
             mana.current = 0.0;
             casting_state.cancel(); // Return to Resting immediately
 
@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+use super::constants::{CHARGE_MANA_PER_SECOND, MAX_CHARGE_SCALE, MAX_CHARGE_TIME};
+use crate::game::units::wizard::components::Mana;
+
 /// Fireball projectile component.
 ///
 /// Represents a fireball traveling through the battlefield until it hits a target or the ground.
@@ -13,20 +16,72 @@ pub struct Fireball {
     pub explosion_radius: f32,
     /// Collision radius of the projectile itself.
     pub radius: f32,
+    /// Mana spent on the cast, including any overcharge.
+    pub mana_cost: f32,
 }
 
 impl Fireball {
     /// Creates a new Fireball component.
-    pub const fn new(velocity: Vec3, damage: f32, explosion_radius: f32, radius: f32) -> Self {
+    pub const fn new(
+        velocity: Vec3,
+        damage: f32,
+        explosion_radius: f32,
+        radius: f32,
+        mana_cost: f32,
+    ) -> Self {
         Self {
             velocity,
             damage,
             explosion_radius,
             radius,
+            mana_cost,
         }
     }
 }
 
+/// Overcharge built up by holding a completed fireball cast.
+///
+/// Inserted on the wizard when the cast completes and the base cost is paid.
+/// The fireball is thrown, scaled by the charge, when the button is released.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct FireballCharge {
+    /// Seconds of overcharge held so far.
+    pub seconds: f32,
+    /// Mana spent on the cast so far, including the base cost.
+    pub mana_spent: f32,
+}
+
+impl FireballCharge {
+    /// Starts a charge after paying the base cost.
+    pub const fn new(base_cost: f32) -> Self {
+        Self {
+            seconds: 0.0,
+            mana_spent: base_cost,
+        }
+    }
+
+    /// Holds the charge for `delta` more seconds, paying for it from `mana`.
+    ///
+    /// Stops growing at `MAX_CHARGE_TIME` or once the wizard can't pay.
+    pub fn charge(&mut self, delta: f32, mana: &mut Mana) {
+        let delta = delta.min(MAX_CHARGE_TIME - self.seconds);
+        if delta <= 0.0 {
+            return;
+        }
+
+        let cost = CHARGE_MANA_PER_SECOND * delta;
+        if mana.consume(cost) {
+            self.seconds += delta;
+            self.mana_spent += cost;
+        }
+    }
+
+    /// Returns the multiplier applied to the blast radius and damage.
+    pub fn scale(&self) -> f32 {
+        1.0 + (self.seconds / MAX_CHARGE_TIME) * (MAX_CHARGE_SCALE - 1.0)
+    }
+}
+
 /// Fireball explosion component.
 ///
 /// Represents the expanding sphere explosion after a fireball impacts.
@@ -42,17 +97,23 @@ pub struct FireballExplosion {
     pub time_alive: f32,
     /// Time since last damage tick (in seconds).
     pub time_since_last_tick: f32,
+    /// Mana spent on the fireball that caused this explosion.
+    pub mana_cost: f32,
+    /// Distinct units damaged so far, for the cast history log.
+    pub hit_entities: Vec<Entity>,
 }
 
 impl FireballExplosion {
     /// Creates a new FireballExplosion component.
-    pub fn new(origin: Vec3, max_radius: f32, damage_per_tick: f32) -> Self {
+    pub fn new(origin: Vec3, max_radius: f32, damage_per_tick: f32, mana_cost: f32) -> Self {
         Self {
             origin,
             max_radius,
             damage_per_tick,
+            mana_cost,
             time_alive: 0.0,
             time_since_last_tick: 0.0,
+            hit_entities: Vec::new(),
         }
     }
 
@@ -108,3 +169,40 @@ impl ResidualAreaDamageEffect {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::units::wizard::spells::fireball::constants::{EXPLOSION_RADIUS, MANA_COST};
+
+    fn charged_radius(hold: f32, mana: &mut Mana) -> f32 {
+        let mut charge = FireballCharge::new(MANA_COST);
+        // Charge in frame-sized steps like the casting system does
+        let mut held = 0.0;
+        while held < hold {
+            charge.charge(0.1, mana);
+            held += 0.1;
+        }
+        EXPLOSION_RADIUS * charge.scale()
+    }
+
+    #[test]
+    fn test_longer_hold_grows_fireball_up_to_cap() {
+        let short = charged_radius(0.5, &mut Mana::new(1000.0, 0.0));
+        let long = charged_radius(1.5, &mut Mana::new(1000.0, 0.0));
+        let capped = charged_radius(MAX_CHARGE_TIME * 5.0, &mut Mana::new(1000.0, 0.0));
+
+        assert!(short > EXPLOSION_RADIUS);
+        assert!(long > short);
+        assert!((capped - EXPLOSION_RADIUS * MAX_CHARGE_SCALE).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_charge_stops_growing_without_mana() {
+        let mut mana = Mana::new(CHARGE_MANA_PER_SECOND * 0.5, 0.0);
+        let radius = charged_radius(MAX_CHARGE_TIME, &mut mana);
+
+        assert!(radius < EXPLOSION_RADIUS * MAX_CHARGE_SCALE);
+        assert!(mana.current >= 0.0);
+    }
+}
@@ -19,6 +19,17 @@ pub const CAST_TIME: f32 = 3.0;
 /// Mana cost for casting a fireball.
 pub const MANA_COST: f32 = 30.0;
 
+// ===== Overcharge Constants =====
+
+/// Longest a completed cast can be held to overcharge the fireball (seconds).
+pub const MAX_CHARGE_TIME: f32 = 2.0;
+
+/// Blast radius and damage multiplier at full overcharge.
+pub const MAX_CHARGE_SCALE: f32 = 2.0;
+
+/// Extra mana drained per second while overcharging.
+pub const CHARGE_MANA_PER_SECOND: f32 = 15.0;
+
 /// Speed of the fireball projectile in units per second.
 pub const PROJECTILE_SPEED: f32 = 3000.0;
 
@@ -31,6 +42,9 @@ pub const EXPLOSION_RADIUS: f32 = 100.0;
 /// Duration of the explosion animation in seconds.
 pub const EXPLOSION_DURATION: f32 = 0.4;
 
+/// Knockback at the center of the explosion, falling off to zero at its edge (in units).
+pub const KNOCKBACK_STRENGTH: f32 = 50.0;
+
 /// Interval between damage ticks in seconds.
 pub const DAMAGE_TICK_INTERVAL: f32 = 0.05;
 
@@ -57,3 +71,9 @@ pub const RESIDUAL_DURATION: f32 = 5.0;
 
 /// Duration of the fade-out at the end of the residual fire (seconds).
 pub const RESIDUAL_FADE_DURATION: f32 = 1.0;
+
+/// How long each fireball trail segment lingers (in seconds).
+pub const TRAIL_LIFETIME: f32 = 0.35;
+
+/// Time between fireball trail segments (in seconds).
+pub const TRAIL_SPAWN_INTERVAL: f32 = 0.01;
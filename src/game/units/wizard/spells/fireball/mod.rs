@@ -2,7 +2,7 @@
 //!
 //! Handles fireball projectiles that explode on impact.
 
-mod components;
+pub mod components;
 pub mod constants;
 mod plugin;
 mod styles;
@@ -8,10 +8,10 @@ use crate::state::InGameState;
 /// Plugin that handles fireball spell casting and behavior.
 ///
 /// Registers systems for:
-/// - Casting fireballs with mouse button and cast time
+/// - Casting fireballs with mouse button and cast time, overcharging while held
 /// - Fireball projectile movement
 /// - Collision detection (units and ground)
-/// - Explosion animation and damage
+/// - Explosion animation, sound, and damage
 /// - Cleanup for finished explosions
 pub struct FireballPlugin;
 
@@ -29,6 +29,8 @@ impl Plugin for FireballPlugin {
                 systems::check_fireball_collisions,
                 systems::despawn_distant_fireballs,
                 systems::update_explosions,
+                systems::apply_explosion_knockback,
+                systems::play_explosion_sfx,
                 systems::apply_explosion_damage,
                 systems::cleanup_finished_explosions,
                 systems::apply_residual_area_damage,
@@ -10,6 +10,12 @@ pub const FIREBALL_COLOR: Color = Color::srgb(1.0, 0.5, 0.0);
 /// Radius of the fireball projectile mesh.
 pub const FIREBALL_RADIUS: f32 = 10.0;
 
+/// Color of the fireball trail (translucent orange).
+pub const TRAIL_COLOR: Color = Color::srgba(1.0, 0.5, 0.0, 0.6);
+
+/// Size of each fireball trail segment.
+pub const TRAIL_SIZE: f32 = 14.0;
+
 /// Color of the explosion sphere (red-orange).
 pub const EXPLOSION_COLOR: Color = Color::srgb(1.0, 0.3, 0.0);
 
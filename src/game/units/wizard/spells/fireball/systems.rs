@@ -1,21 +1,31 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
 
-use super::super::super::components::{CastingState, Mana, PrimedSpell, Wizard};
+use super::super::super::components::{
+    CastingState, Mana, PrimedSpell, Spell, SpellCastRecord, Wizard,
+};
 use super::components::*;
 use super::constants;
 use super::styles::*;
-use crate::game::components::OnGameplayScreen;
+use crate::game::components::{OnGameplayScreen, Velocity};
 use crate::game::constants::WIZARD_POSITION;
 use crate::game::input::MouseButtonState;
+use crate::game::input::components::CursorWorldPosition;
 use crate::game::input::events::MouseLeftReleased;
-use crate::game::units::components::{Health, Team, TemporaryHitPoints, apply_damage_to_unit};
+use crate::game::sound_cues::components::{PlaySfx, Sfx};
+use crate::game::units::components::{
+    ComboTriggered, Corpse, DamageDealt, DamageSource, DamageType, Health, Resistances, Snare,
+    StatusEffect, Team, TemporaryHitPoints, apply_combo, apply_damage_and_report, apply_knockback,
+};
+use crate::game::units::wizard::spells::trail::components::Trail;
 use crate::game::units::wizard::spells::wall_of_stone::components::WallOfStone;
 
 /// Handles fireball casting with left-click.
 ///
 /// Left-click starts cast. Must hold for full cast time.
-/// After cast completes, spawns a single fireball projectile toward the cursor.
+/// Once the cast completes the base cost is paid, and holding on overcharges the
+/// fireball (see `FireballCharge`). Releasing throws it toward the cursor;
+/// releasing before the cast completes cancels it, and releasing with no ground
+/// under the cursor refunds the charge.
 /// Only casts when Fireball is the primed spell.
 ///
 /// Note: Spell priming, input blocking, and mouse state checks are handled by run_if conditions.
@@ -27,17 +37,43 @@ pub fn handle_fireball_casting(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    mut wizard_query: Query<(&mut CastingState, &mut Mana, &PrimedSpell), With<Wizard>>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut wizard_query: Query<
+        (
+            Entity,
+            &mut CastingState,
+            &mut Mana,
+            &PrimedSpell,
+            Option<&mut FireballCharge>,
+        ),
+        With<Wizard>,
+    >,
+    cursor: Res<CursorWorldPosition>,
 ) {
-    let Ok((mut casting_state, mut mana, primed_spell)) = wizard_query.single_mut() else {
+    let Ok((wizard_entity, mut casting_state, mut mana, primed_spell, charge)) =
+        wizard_query.single_mut()
+    else {
         return;
     };
 
     // Check for release event - this is spell-specific logic
     if mouse_left_released.read().next().is_some() {
-        // Cancel cast on release
+        // Throw the charged fireball, or cancel a cast that hasn't completed
+        if let Some(charge) = charge {
+            if let Some(target_pos) = cursor.ground {
+                spawn_fireball(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    WIZARD_POSITION + Vec3::new(0.0, constants::SPAWN_HEIGHT_OFFSET, 0.0),
+                    target_pos,
+                    &charge,
+                );
+            } else {
+                // No ground under the cursor - nothing is thrown, so refund the charge
+                mana.regenerate(charge.mana_spent);
+            }
+        }
+        commands.entity(wizard_entity).remove::<FireballCharge>();
         casting_state.cancel();
         return;
     }
@@ -52,22 +88,20 @@ pub fn handle_fireball_casting(
             // Currently casting - advance cast time
             casting_state.advance(time.delta_secs());
 
-            // Check if cast is complete
-            if casting_state.is_complete(primed_spell.cast_time) {
-                // Cast complete - consume mana and spawn fireball
-                if mana.consume(constants::MANA_COST)
-                    && let Some(target_pos) =
-                        get_cursor_world_position(&camera_query, &window_query)
-                {
-                    spawn_fireball(
-                        &mut commands,
-                        &mut meshes,
-                        &mut materials,
-                        WIZARD_POSITION + Vec3::new(0.0, constants::SPAWN_HEIGHT_OFFSET, 0.0),
-                        target_pos,
-                    );
-                }
-                // Return to resting state (no channeling for fireball)
+            if !casting_state.is_complete(primed_spell.cast_time) {
+                return;
+            }
+
+            if let Some(mut charge) = charge {
+                // Still held after completing - keep overcharging
+                charge.charge(time.delta_secs(), &mut mana);
+            } else if mana.consume(constants::MANA_COST) {
+                // Cast just completed - pay the base cost and start charging
+                commands
+                    .entity(wizard_entity)
+                    .insert(FireballCharge::new(constants::MANA_COST));
+            } else {
+                // Can't pay - return to resting state
                 casting_state.cancel();
                 mouse_state.left_consumed = true; // Require release before next cast
             }
@@ -75,46 +109,22 @@ pub fn handle_fireball_casting(
         CastingState::Resting => {
             // Not casting - check mana before starting cast
             if mana.can_afford(constants::MANA_COST) {
+                // Drop any charge left over from a cast that was interrupted
+                commands.entity(wizard_entity).remove::<FireballCharge>();
                 casting_state.start_cast();
             }
         }
     }
 }
 
-/// Gets the cursor position projected onto the battlefield surface (Y=0 plane).
-fn get_cursor_world_position(
-    camera_query: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: &Query<&Window, With<PrimaryWindow>>,
-) -> Option<Vec3> {
-    let (camera, camera_transform) = camera_query.single().ok()?;
-    let window = window_query.single().ok()?;
-    let cursor_pos = window.cursor_position()?;
-
-    // Create a ray from the camera through the cursor position
-    let ray = camera
-        .viewport_to_world(camera_transform, cursor_pos)
-        .ok()?;
-
-    // Intersect ray with Y=0 plane (battlefield surface)
-    // Ray equation: origin + direction * t
-    // Plane equation: y = 0
-    // Solve for t: origin.y + direction.y * t = 0
-    let t = -ray.origin.y / ray.direction.y;
-
-    if t > 0.0 {
-        Some(ray.origin + ray.direction * t)
-    } else {
-        None
-    }
-}
-
-/// Spawns a fireball projectile.
+/// Spawns a fireball projectile, scaled up by its overcharge.
 fn spawn_fireball(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     origin: Vec3,
     target: Vec3,
+    charge: &FireballCharge,
 ) {
     let direction = (target - origin).normalize();
     let velocity = direction * constants::PROJECTILE_SPEED;
@@ -131,9 +141,16 @@ fn spawn_fireball(
         Transform::from_translation(origin),
         Fireball::new(
             velocity,
-            constants::DAMAGE_PER_TICK,
-            constants::EXPLOSION_RADIUS,
+            constants::DAMAGE_PER_TICK * charge.scale(),
+            constants::EXPLOSION_RADIUS * charge.scale(),
             constants::PROJECTILE_COLLISION_RADIUS,
+            charge.mana_spent,
+        ),
+        Trail::new(
+            TRAIL_COLOR,
+            constants::TRAIL_LIFETIME,
+            constants::TRAIL_SPAWN_INTERVAL,
+            TRAIL_SIZE,
         ),
         OnGameplayScreen,
     ));
@@ -173,6 +190,7 @@ pub fn check_fireball_collisions(
                     explosion_pos,
                     fireball.explosion_radius,
                     fireball.damage,
+                    fireball.mana_cost,
                 );
                 commands.entity(fireball_entity).despawn();
                 hit_wall = true;
@@ -194,6 +212,7 @@ pub fn check_fireball_collisions(
                 explosion_pos,
                 fireball.explosion_radius,
                 fireball.damage,
+                fireball.mana_cost,
             );
             commands.entity(fireball_entity).despawn();
             continue;
@@ -212,6 +231,7 @@ pub fn check_fireball_collisions(
                     fireball_pos,
                     fireball.explosion_radius,
                     fireball.damage,
+                    fireball.mana_cost,
                 );
                 commands.entity(fireball_entity).despawn();
                 break;
@@ -228,6 +248,7 @@ fn spawn_explosion(
     position: Vec3,
     max_radius: f32,
     damage: f32,
+    mana_cost: f32,
 ) {
     let sphere = Sphere::new(1.0); // Unit sphere, scaled by transform
 
@@ -239,7 +260,7 @@ fn spawn_explosion(
             ..default()
         })),
         Transform::from_translation(position).with_scale(Vec3::splat(0.1)),
-        FireballExplosion::new(position, max_radius, damage),
+        FireballExplosion::new(position, max_radius, damage, mana_cost),
         OnGameplayScreen,
     ));
 }
@@ -262,12 +283,56 @@ pub fn update_explosions(
     }
 }
 
+/// Plays the explosion sound for each new explosion.
+pub fn play_explosion_sfx(
+    explosions: Query<&FireballExplosion, Added<FireballExplosion>>,
+    mut sfx: MessageWriter<PlaySfx>,
+) {
+    for explosion in &explosions {
+        sfx.write(PlaySfx {
+            sfx: Sfx::FireballExplosion,
+            position: explosion.origin,
+        });
+    }
+}
+
+/// Shoves living units outward from each new explosion.
+///
+/// Applied once when the explosion appears, across its full radius.
+pub fn apply_explosion_knockback(
+    explosions: Query<&FireballExplosion, Added<FireballExplosion>>,
+    mut units: Query<(&mut Transform, &mut Velocity), (With<Team>, Without<Corpse>)>,
+) {
+    for explosion in &explosions {
+        for (mut transform, mut velocity) in &mut units {
+            apply_knockback(
+                explosion.origin,
+                explosion.max_radius,
+                constants::KNOCKBACK_STRENGTH,
+                &mut transform,
+                &mut velocity,
+            );
+        }
+    }
+}
+
 /// Applies damage to units hit by the explosion on a tick interval.
 ///
 /// Targets closer to the center stay in the explosion longer and take more damage.
+/// Fire impacts on snared (frozen) units trigger spell combos.
+#[allow(clippy::type_complexity)]
 pub fn apply_explosion_damage(
     mut explosions: Query<&mut FireballExplosion>,
-    mut targets: Query<(&Transform, &mut Health, Option<&mut TemporaryHitPoints>)>,
+    mut targets: Query<(
+        Entity,
+        &Transform,
+        &mut Health,
+        Option<&mut TemporaryHitPoints>,
+        Option<&Resistances>,
+        Option<&Snare>,
+    )>,
+    mut damage_dealt: MessageWriter<DamageDealt>,
+    mut combo_triggered: MessageWriter<ComboTriggered>,
 ) {
     for mut explosion in &mut explosions {
         // Check if it's time for a damage tick
@@ -277,15 +342,39 @@ pub fn apply_explosion_damage(
             let current_radius = explosion.current_radius(constants::EXPLOSION_DURATION);
 
             // Apply damage to all units within the current explosion radius
-            for (transform, mut health, mut temp_hp) in &mut targets {
+            for (entity, transform, mut health, mut temp_hp, resistances, snare) in &mut targets {
                 let distance = explosion.origin.distance(transform.translation);
 
                 if distance <= current_radius {
-                    apply_damage_to_unit(
+                    let (damage, combo) = apply_combo(
+                        explosion.damage_per_tick,
+                        DamageType::Fire,
+                        &StatusEffect::active(snare),
+                    );
+                    if let Some(combo) = combo
+                        && health.current > 0.0
+                    {
+                        combo_triggered.write(ComboTriggered {
+                            name: combo.name,
+                            position: transform.translation,
+                            bonus_damage: damage - explosion.damage_per_tick,
+                        });
+                    }
+
+                    apply_damage_and_report(
                         &mut health,
                         temp_hp.as_deref_mut(),
-                        explosion.damage_per_tick,
+                        resistances,
+                        damage,
+                        DamageType::Fire,
+                        DamageSource::Spell(Spell::Fireball),
+                        entity,
+                        transform.translation,
+                        &mut damage_dealt,
                     );
+                    if !explosion.hit_entities.contains(&entity) {
+                        explosion.hit_entities.push(entity);
+                    }
                 }
             }
         }
@@ -293,14 +382,23 @@ pub fn apply_explosion_damage(
 }
 
 /// Cleans up explosions that have finished animating and spawns residual fire.
+///
+/// Reports the finished cast with the number of units the explosion hit.
 pub fn cleanup_finished_explosions(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     explosions: Query<(Entity, &FireballExplosion)>,
+    mut records: MessageWriter<SpellCastRecord>,
 ) {
     for (entity, explosion) in &explosions {
         if explosion.time_alive >= constants::EXPLOSION_DURATION {
+            records.write(SpellCastRecord {
+                spell: Spell::Fireball,
+                mana_cost: explosion.mana_cost,
+                affected: explosion.hit_entities.len() as u32,
+            });
+
             // Spawn residual fire at explosion origin
             let circle = Circle::new(constants::RESIDUAL_DAMAGE_RADIUS);
             commands.spawn((
@@ -332,7 +430,14 @@ pub fn cleanup_finished_explosions(
 pub fn apply_residual_area_damage(
     time: Res<Time>,
     mut effects: Query<&mut ResidualAreaDamageEffect>,
-    mut targets: Query<(&Transform, &mut Health, Option<&mut TemporaryHitPoints>)>,
+    mut targets: Query<(
+        Entity,
+        &Transform,
+        &mut Health,
+        Option<&mut TemporaryHitPoints>,
+        Option<&Resistances>,
+    )>,
+    mut damage_dealt: MessageWriter<DamageDealt>,
 ) {
     let delta = time.delta_secs();
 
@@ -343,7 +448,7 @@ pub fn apply_residual_area_damage(
         if effect.time_since_last_tick >= effect.tick_interval {
             effect.time_since_last_tick = 0.0;
 
-            for (transform, mut health, mut temp_hp) in &mut targets {
+            for (entity, transform, mut health, mut temp_hp, resistances) in &mut targets {
                 let distance = Vec3::new(
                     effect.origin.x - transform.translation.x,
                     0.0,
@@ -352,10 +457,16 @@ pub fn apply_residual_area_damage(
                 .length();
 
                 if distance <= effect.radius {
-                    apply_damage_to_unit(
+                    apply_damage_and_report(
                         &mut health,
                         temp_hp.as_deref_mut(),
+                        resistances,
                         effect.damage_per_tick,
+                        DamageType::Fire,
+                        DamageSource::Spell(Spell::Fireball),
+                        entity,
+                        transform.translation,
+                        &mut damage_dealt,
                     );
                 }
             }
@@ -406,10 +517,13 @@ pub fn cleanup_residual_effects(
 }
 
 /// Despawns fireballs that travel beyond the wizard's spell range.
+///
+/// The wasted cast is reported as hitting nothing.
 pub fn despawn_distant_fireballs(
     mut commands: Commands,
-    fireballs: Query<(Entity, &Transform), With<Fireball>>,
+    fireballs: Query<(Entity, &Transform, &Fireball)>,
     wizard_query: Query<(&Transform, &Wizard), Without<Fireball>>,
+    mut records: MessageWriter<SpellCastRecord>,
 ) {
     let Ok((wizard_transform, wizard)) = wizard_query.single() else {
         return;
@@ -418,11 +532,154 @@ pub fn despawn_distant_fireballs(
     let wizard_pos = wizard_transform.translation;
     let spell_range = wizard.spell_range;
 
-    for (entity, transform) in &fireballs {
+    for (entity, transform, fireball) in &fireballs {
         let distance_from_wizard = transform.translation.distance(wizard_pos);
 
         if distance_from_wizard > spell_range {
+            records.write(SpellCastRecord {
+                spell: Spell::Fireball,
+                mana_cost: fireball.mana_cost,
+                affected: 0,
+            });
             commands.entity(entity).despawn();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::config::GameConfig;
+    use crate::game::input::components::{
+        CastGrace, MouseLeftHeldThisFrame, MouseRightHeldThisFrame, SpellInputBlockedThisFrame,
+    };
+    use crate::game::input::events::*;
+    use crate::game::input::{detect_mouse_input, update_input_state_for_run_conditions};
+    use crate::game::units::wizard::spells::fireball_constants::PRIMED_FIREBALL;
+    use crate::game::units::wizard::spells::run_conditions::mouse_held_or_wizard_casting;
+
+    /// Presses, releases, and re-presses the cast button one frame apart with
+    /// the given grace, returning the wizard's casting state afterwards.
+    fn casting_state_after_slip(cast_grace: f32) -> CastingState {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                50,
+            )))
+            .insert_resource(GameConfig {
+                cast_grace,
+                ..default()
+            })
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<MouseButtonState>()
+            .init_resource::<CastGrace>()
+            .init_resource::<MouseLeftHeldThisFrame>()
+            .init_resource::<SpellInputBlockedThisFrame>()
+            .init_resource::<MouseRightHeldThisFrame>()
+            .init_resource::<CursorWorldPosition>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_message::<MouseLeftPressed>()
+            .add_message::<MouseLeftHeld>()
+            .add_message::<MouseLeftReleased>()
+            .add_message::<MouseRightPressed>()
+            .add_message::<MouseRightHeld>()
+            .add_message::<MouseRightReleased>()
+            .add_message::<BlockSpellInput>()
+            .add_systems(
+                Update,
+                (
+                    detect_mouse_input,
+                    update_input_state_for_run_conditions,
+                    handle_fireball_casting.run_if(mouse_held_or_wizard_casting),
+                )
+                    .chain(),
+            );
+        let wizard = app
+            .world_mut()
+            .spawn((
+                Wizard::new(500.0),
+                CastingState::Resting,
+                Mana::new(100.0, 0.0),
+                PRIMED_FIREBALL,
+            ))
+            .id();
+
+        let frame = |app: &mut App, press: Option<bool>| {
+            let mut mouse = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+            mouse.clear();
+            match press {
+                Some(true) => mouse.press(MouseButton::Left),
+                Some(false) => mouse.release(MouseButton::Left),
+                None => {}
+            }
+            app.update();
+        };
+
+        frame(&mut app, Some(true));
+        frame(&mut app, None);
+        frame(&mut app, None);
+        frame(&mut app, Some(false));
+        frame(&mut app, Some(true));
+        frame(&mut app, None);
+
+        *app.world().get::<CastingState>(wizard).unwrap()
+    }
+
+    #[test]
+    fn test_quick_repress_within_grace_resumes_cast() {
+        let CastingState::Casting { elapsed } = casting_state_after_slip(0.2) else {
+            panic!("cast was cancelled by the slip");
+        };
+        // Progress from before the slip is kept
+        assert!(elapsed >= 0.15, "elapsed {elapsed}");
+    }
+
+    #[test]
+    fn test_release_without_grace_cancels_cast() {
+        // The re-press starts a fresh cast
+        let CastingState::Casting { elapsed } = casting_state_after_slip(0.0) else {
+            panic!("re-press should start a new cast");
+        };
+        assert!(elapsed < 0.1, "elapsed {elapsed}");
+    }
+
+    #[test]
+    fn test_release_off_the_ground_refunds_charge() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<MouseButtonState>()
+            .init_resource::<CursorWorldPosition>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_message::<MouseLeftReleased>()
+            .add_systems(Update, handle_fireball_casting);
+        let mut mana = Mana::new(100.0, 0.0);
+        let mut charge = FireballCharge::new(constants::MANA_COST);
+        mana.consume(constants::MANA_COST);
+        charge.charge(0.5, &mut mana);
+        let wizard = app
+            .world_mut()
+            .spawn((
+                Wizard::new(500.0),
+                CastingState::Casting { elapsed: 1.0 },
+                mana,
+                PRIMED_FIREBALL,
+                charge,
+            ))
+            .id();
+
+        // Released with the cursor off the ground
+        app.world_mut().write_message(MouseLeftReleased);
+        app.update();
+
+        let world = app.world_mut();
+        assert_eq!(world.query::<&Fireball>().iter(world).count(), 0);
+        assert!(world.get::<FireballCharge>(wizard).is_none());
+        assert_eq!(world.get::<Mana>(wizard).unwrap().current, 100.0);
+    }
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+/// Marker component indicating the wizard is actively casting Frost Nova.
+///
+/// The circle_entity is None after cast completes but before mouse release.
+#[derive(Component)]
+pub struct FrostNovaCaster {
+    /// Entity ID of the visual circle indicator (None if despawned).
+    pub circle_entity: Option<Entity>,
+}
+
+/// Visual indicator for the Frost Nova area during casting.
+#[derive(Component)]
+pub struct FrostNovaIndicator {
+    /// Position of the circle center.
+    pub position: Vec3,
+    /// Time this indicator has been active (for animations).
+    pub time_alive: f32,
+}
+
+impl FrostNovaIndicator {
+    /// Creates a new circle indicator.
+    pub const fn new(position: Vec3) -> Self {
+        Self {
+            position,
+            time_alive: 0.0,
+        }
+    }
+
+    /// Returns the current scale factor for pulse animation.
+    ///
+    /// Pulsates between 0.95 and 1.05 during cast time.
+    pub fn pulse_scale(&self) -> f32 {
+        let pulse_freq = 2.0; // Hz
+        let pulse_amplitude = 0.05;
+        1.0 + (self.time_alive * pulse_freq * std::f32::consts::TAU).sin() * pulse_amplitude
+    }
+}
+
+/// Expanding frost burst shown when the nova goes off.
+#[derive(Component)]
+pub struct FrostNovaBurst {
+    /// Time since the burst was spawned.
+    pub time_alive: f32,
+}
@@ -0,0 +1,28 @@
+use crate::game::units::wizard::components::{PrimedSpell, Spell};
+
+/// PrimedSpell constant for Frost Nova.
+pub const PRIMED_FROST_NOVA: PrimedSpell = PrimedSpell {
+    spell: Spell::FrostNova,
+    cast_time: CAST_TIME,
+};
+
+/// Cast time for Frost Nova in seconds.
+pub const CAST_TIME: f32 = 1.0;
+
+/// Mana cost for casting Frost Nova.
+pub const MANA_COST: f32 = 20.0;
+
+/// Radius of the nova in units.
+pub const NOVA_RADIUS: f32 = 175.0;
+
+/// Speed multiplier applied to snared enemies (0.4 = 40% speed).
+pub const SLOW_FACTOR: f32 = 0.4;
+
+/// Duration of the snare in seconds.
+pub const SNARE_DURATION: f32 = 4.0;
+
+/// Duration of the burst visual after the nova goes off.
+pub const BURST_DURATION: f32 = 0.4;
+
+/// Y position of the circle indicator (slightly above ground).
+pub const CIRCLE_Y_POSITION: f32 = 1.0;
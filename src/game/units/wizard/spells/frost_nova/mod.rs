@@ -0,0 +1,11 @@
+//! Frost Nova spell module.
+//!
+//! Handles crowd-control spell that snares enemies in an area.
+
+pub mod components;
+pub mod constants;
+mod plugin;
+mod styles;
+mod systems;
+
+pub use plugin::FrostNovaPlugin;
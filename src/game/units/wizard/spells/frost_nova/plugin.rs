@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use super::super::super::components::Spell;
+use super::super::run_conditions::*;
+use super::systems;
+use crate::state::InGameState;
+
+/// Plugin that handles Frost Nova spell casting and behavior.
+///
+/// Registers systems for:
+/// - Casting Frost Nova with mouse button and cast time
+/// - Visual circle indicator during cast
+/// - Snaring enemies in the area
+/// - Burst animation and cleanup
+/// - Indicator cleanup when the primed spell is cancelled
+pub struct FrostNovaPlugin;
+
+impl Plugin for FrostNovaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                systems::handle_frost_nova_casting
+                    .run_if(spell_is_primed(Spell::FrostNova))
+                    .run_if(spell_input_not_blocked)
+                    .run_if(mouse_left_not_consumed)
+                    .run_if(mouse_held_or_wizard_casting),
+                systems::update_circle_indicator,
+                systems::cancel_frost_nova_on_spell_cancelled,
+                systems::update_frost_nova_bursts,
+            )
+                .chain()
+                .run_if(in_state(InGameState::Running)),
+        );
+    }
+}
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Color of the circle indicator during casting (pale ice blue).
+/// Translucent to show the battlefield underneath.
+pub const CIRCLE_COLOR: Color = Color::srgba(0.7, 0.9, 1.0, 0.3);
+
+/// Color of the burst when the nova goes off.
+pub const BURST_COLOR: Color = Color::srgba(0.85, 0.95, 1.0, 0.6);
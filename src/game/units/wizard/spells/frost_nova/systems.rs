@@ -0,0 +1,288 @@
+use bevy::prelude::*;
+
+use super::super::super::components::{
+    CancelSpellMessage, CastingState, Mana, PrimedSpell, Spell, SpellCastRecord, Wizard,
+};
+use super::super::circle_placement::{CircleIndicator, place_circle_on_ground};
+use super::components::{FrostNovaBurst, FrostNovaCaster, FrostNovaIndicator};
+use super::constants;
+use super::styles::{BURST_COLOR, CIRCLE_COLOR};
+use crate::game::components::OnGameplayScreen;
+use crate::game::input::MouseButtonState;
+use crate::game::input::components::CursorWorldPosition;
+use crate::game::input::events::MouseLeftReleased;
+use crate::game::units::components::{Corpse, Snare, Team};
+
+/// Handles Frost Nova casting with left-click.
+///
+/// Left-click starts cast. Must hold for full cast time.
+/// After cast completes, snares all enemies (Attackers and Undead) in radius.
+/// Only casts when Frost Nova is the primed spell.
+///
+/// Note: Spell priming, input blocking, and mouse state checks are handled by run_if conditions.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_frost_nova_casting(
+    time: Res<Time>,
+    mut mouse_state: ResMut<MouseButtonState>,
+    mut mouse_left_released: MessageReader<MouseLeftReleased>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut wizard_query: Query<
+        (
+            Entity,
+            &Transform,
+            &Wizard,
+            &mut CastingState,
+            &mut Mana,
+            &PrimedSpell,
+        ),
+        With<Wizard>,
+    >,
+    cursor: Res<CursorWorldPosition>,
+    mut caster_query: Query<&mut FrostNovaCaster, With<Wizard>>,
+    mut indicator_query: Query<&mut FrostNovaIndicator>,
+    targets_query: Query<(Entity, &Transform, &Team), (Without<Wizard>, Without<Corpse>)>,
+    mut records: MessageWriter<SpellCastRecord>,
+) {
+    let Ok((wizard_entity, wizard_transform, wizard, mut casting_state, mut mana, primed_spell)) =
+        wizard_query.single_mut()
+    else {
+        return;
+    };
+
+    // Check for release event - this is spell-specific logic
+    if mouse_left_released.read().next().is_some() {
+        // Cancel cast on release
+        if let Ok(caster) = caster_query.single() {
+            if let Some(circle_entity) = caster.circle_entity {
+                commands.entity(circle_entity).despawn();
+            }
+            commands.entity(wizard_entity).remove::<FrostNovaCaster>();
+        }
+        casting_state.cancel();
+        return;
+    }
+
+    let Some(cursor_world_pos) = cursor.ground else {
+        return;
+    };
+
+    // Clamp so the entire nova stays within the ground circle of the wizard's spell range
+    let cursor_world_pos = place_circle_on_ground(
+        cursor_world_pos,
+        wizard_transform.translation,
+        wizard.spell_range,
+        constants::NOVA_RADIUS,
+    );
+
+    match *casting_state {
+        CastingState::Resting => {
+            // The marker persists after cast completion until mouse release
+            if caster_query.single().is_err() && mana.can_afford(constants::MANA_COST) {
+                let circle_entity = spawn_circle_indicator(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    cursor_world_pos,
+                );
+
+                commands.entity(wizard_entity).insert(FrostNovaCaster {
+                    circle_entity: Some(circle_entity),
+                });
+
+                casting_state.start_cast();
+            }
+        }
+        CastingState::Casting { .. } => {
+            casting_state.advance(time.delta_secs());
+
+            // Update circle position to follow cursor
+            if let Ok(caster) = caster_query.single()
+                && let Some(circle_entity) = caster.circle_entity
+                && let Ok(mut indicator) = indicator_query.get_mut(circle_entity)
+            {
+                indicator.position = cursor_world_pos;
+            }
+
+            if !casting_state.is_complete(primed_spell.cast_time) {
+                return;
+            }
+
+            if let Ok(mut caster) = caster_query.single_mut() {
+                if let Some(circle_entity) = caster.circle_entity {
+                    if mana.consume(constants::MANA_COST)
+                        && let Ok(indicator) = indicator_query.get(circle_entity)
+                    {
+                        let snared = snare_enemies_in_radius(
+                            &mut commands,
+                            indicator.position,
+                            &targets_query,
+                        );
+                        records.write(SpellCastRecord {
+                            spell: Spell::FrostNova,
+                            mana_cost: constants::MANA_COST,
+                            affected: snared,
+                        });
+                        spawn_burst(
+                            &mut commands,
+                            &mut meshes,
+                            &mut materials,
+                            indicator.position,
+                        );
+                    }
+                    commands.entity(circle_entity).despawn();
+                }
+
+                // Clear circle entity reference but keep marker to prevent immediate recast
+                caster.circle_entity = None;
+            }
+
+            casting_state.cancel();
+            mouse_state.left_consumed = true; // Require release before next cast
+        }
+        CastingState::Channeling { .. } => {
+            // Frost Nova doesn't use channeling, cancel if we somehow get here
+            if let Ok(caster) = caster_query.single() {
+                if let Some(circle_entity) = caster.circle_entity {
+                    commands.entity(circle_entity).despawn();
+                }
+                commands.entity(wizard_entity).remove::<FrostNovaCaster>();
+            }
+            casting_state.cancel();
+        }
+    }
+}
+
+/// Despawns the circle indicator when the primed spell is cancelled.
+pub fn cancel_frost_nova_on_spell_cancelled(
+    mut cancel_spell: MessageReader<CancelSpellMessage>,
+    mut commands: Commands,
+    caster_query: Query<(Entity, &FrostNovaCaster), With<Wizard>>,
+) {
+    if cancel_spell.read().next().is_none() {
+        return;
+    }
+
+    if let Ok((wizard_entity, caster)) = caster_query.single() {
+        if let Some(circle_entity) = caster.circle_entity {
+            commands.entity(circle_entity).despawn();
+        }
+        commands.entity(wizard_entity).remove::<FrostNovaCaster>();
+    }
+}
+
+/// Updates circle indicator visuals during casting.
+pub fn update_circle_indicator(
+    time: Res<Time>,
+    mut indicators: Query<(&mut FrostNovaIndicator, &mut Transform)>,
+) {
+    for (mut indicator, mut transform) in indicators.iter_mut() {
+        indicator.time_alive += time.delta_secs();
+
+        transform.scale = Vec3::splat(indicator.pulse_scale());
+        transform.translation.x = indicator.position.x;
+        transform.translation.y = constants::CIRCLE_Y_POSITION;
+        transform.translation.z = indicator.position.z;
+    }
+}
+
+/// Expands and fades the frost burst, despawning it when finished.
+pub fn update_frost_nova_bursts(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut bursts: Query<(
+        Entity,
+        &mut FrostNovaBurst,
+        &mut Transform,
+        &MeshMaterial3d<StandardMaterial>,
+    )>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, mut burst, mut transform, material_handle) in &mut bursts {
+        burst.time_alive += time.delta_secs();
+
+        let progress = burst.time_alive / constants::BURST_DURATION;
+        if progress >= 1.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // Grow from half size to full radius while fading out
+        transform.scale = Vec3::splat(0.5 + 0.5 * progress);
+        if let Some(material) = materials.get_mut(&material_handle.0) {
+            material.base_color = BURST_COLOR.with_alpha(BURST_COLOR.alpha() * (1.0 - progress));
+        }
+    }
+}
+
+/// Applies a snare to every enemy (Attackers and Undead) within the nova radius.
+///
+/// Re-applying refreshes the snare duration. Returns the number of units snared.
+fn snare_enemies_in_radius(
+    commands: &mut Commands,
+    center: Vec3,
+    targets: &Query<(Entity, &Transform, &Team), (Without<Wizard>, Without<Corpse>)>,
+) -> u32 {
+    let mut snared = 0;
+    for (entity, transform, team) in targets.iter() {
+        if *team == Team::Defenders {
+            continue;
+        }
+
+        let diff_x = transform.translation.x - center.x;
+        let diff_z = transform.translation.z - center.z;
+        if diff_x * diff_x + diff_z * diff_z <= constants::NOVA_RADIUS * constants::NOVA_RADIUS {
+            commands.entity(entity).insert(Snare::new(
+                constants::SLOW_FACTOR,
+                constants::SNARE_DURATION,
+            ));
+            snared += 1;
+        }
+    }
+    snared
+}
+
+/// Spawns the translucent circle indicator shown during the cast.
+fn spawn_circle_indicator(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    position: Vec3,
+) -> Entity {
+    CircleIndicator {
+        radius: constants::NOVA_RADIUS,
+        color: CIRCLE_COLOR,
+        alpha_mode: AlphaMode::Blend,
+    }
+    .spawn(
+        commands,
+        meshes,
+        materials,
+        Transform::from_xyz(position.x, constants::CIRCLE_Y_POSITION, position.z),
+        FrostNovaIndicator::new(position),
+    )
+}
+
+/// Spawns the burst visual where the nova went off.
+fn spawn_burst(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    position: Vec3,
+) {
+    commands.spawn((
+        Mesh3d(meshes.add(Circle::new(constants::NOVA_RADIUS))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: BURST_COLOR,
+            unlit: true,
+            alpha_mode: AlphaMode::Blend,
+            ..default()
+        })),
+        Transform::from_xyz(position.x, constants::CIRCLE_Y_POSITION, position.z)
+            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+            .with_scale(Vec3::splat(0.5)),
+        FrostNovaBurst { time_alive: 0.0 },
+        OnGameplayScreen,
+    ));
+}
@@ -2,7 +2,7 @@
 //!
 //! Handles defensive spell that grants temporary hit points to units in an area.
 
-mod components;
+pub mod components;
 pub mod constants;
 mod plugin;
 mod styles;
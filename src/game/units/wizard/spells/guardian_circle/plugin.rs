@@ -12,6 +12,7 @@ use crate::state::InGameState;
 /// - Visual circle indicator during cast
 /// - Applying temporary HP buff to units in area
 /// - Circle animation and updates
+/// - Indicator cleanup when the primed spell is cancelled
 pub struct GuardianCirclePlugin;
 
 impl Plugin for GuardianCirclePlugin {
@@ -25,6 +26,7 @@ impl Plugin for GuardianCirclePlugin {
                     .run_if(mouse_left_not_consumed)
                     .run_if(mouse_held_or_wizard_casting),
                 systems::update_circle_indicator,
+                systems::cancel_guardian_circle_on_spell_cancelled,
             )
                 .chain()
                 .run_if(in_state(InGameState::Running)),
@@ -1,14 +1,16 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
 
-use super::super::super::components::{CastingState, Mana, PrimedSpell, Wizard};
+use super::super::super::components::{
+    CancelSpellMessage, CastingState, Mana, PrimedSpell, Spell, SpellCastRecord, Wizard,
+};
+use super::super::circle_placement::{CircleIndicator, place_circle_on_ground};
 use super::components::{GuardianCircleCaster, GuardianCircleIndicator};
 use super::constants;
 use super::styles::CIRCLE_COLOR;
-use crate::game::components::OnGameplayScreen;
 use crate::game::input::MouseButtonState;
+use crate::game::input::components::CursorWorldPosition;
 use crate::game::input::events::MouseLeftReleased;
-use crate::game::units::components::TemporaryHitPoints;
+use crate::game::units::components::{Team, TemporaryHitPoints};
 
 /// Handles Guardian Circle casting with left-click.
 ///
@@ -36,11 +38,11 @@ pub fn handle_guardian_circle_casting(
         ),
         With<Wizard>,
     >,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    cursor: Res<CursorWorldPosition>,
     mut caster_query: Query<&mut GuardianCircleCaster, With<Wizard>>,
     mut indicator_query: Query<&mut GuardianCircleIndicator>,
-    mut targets_query: Query<(Entity, &Transform), Without<Wizard>>,
+    mut targets_query: Query<(Entity, &Transform, Has<Team>), Without<Wizard>>,
+    mut records: MessageWriter<SpellCastRecord>,
 ) {
     let Ok((wizard_entity, wizard_transform, wizard, mut casting_state, mut mana, primed_spell)) =
         wizard_query.single_mut()
@@ -65,37 +67,16 @@ pub fn handle_guardian_circle_casting(
         return;
     }
 
-    // Get cursor world position and clamp to wizard's spell range
-    let Some(mut cursor_world_pos) = get_cursor_world_position(&camera_query, &window_query) else {
+    // Keep the entire circle within the wizard's spell range
+    let Some(cursor_world_pos) = cursor.ground else {
         return;
     };
-
-    // Clamp cursor position to be within wizard's spell range
-    // Use the same 3D distance calculation as the spell range indicator
-    let wizard_pos = wizard_transform.translation;
-    let wizard_height = wizard_pos.y;
-
-    // Calculate the actual ground circle radius using Pythagorean theorem
-    // spell_range² = circle_radius² + wizard_height²
-    // Therefore: circle_radius = √(spell_range² - wizard_height²)
-    let max_ground_radius = if wizard_height < wizard.spell_range {
-        (wizard.spell_range * wizard.spell_range - wizard_height * wizard_height).sqrt()
-    } else {
-        0.0
-    };
-
-    // Account for the Guardian Circle's radius so the entire circle stays within range
-    let max_center_distance = (max_ground_radius - constants::CIRCLE_RADIUS).max(0.0);
-
-    // Calculate XZ plane distance from wizard to cursor
-    let direction = cursor_world_pos - wizard_pos;
-    let distance = (direction.x * direction.x + direction.z * direction.z).sqrt();
-
-    if distance > max_center_distance && distance > 0.001 {
-        // Clamp to ensure the entire circle stays within spell range
-        let normalized_direction = direction / distance;
-        cursor_world_pos = wizard_pos + normalized_direction * max_center_distance;
-    }
+    let cursor_world_pos = place_circle_on_ground(
+        cursor_world_pos,
+        wizard_transform.translation,
+        wizard.spell_range,
+        constants::CIRCLE_RADIUS,
+    );
 
     // Mouse is held - handle casting based on state
     match *casting_state {
@@ -140,7 +121,7 @@ pub fn handle_guardian_circle_casting(
                     if let Ok(mut caster) = caster_query.single_mut() {
                         if let Some(circle_entity) = caster.circle_entity {
                             if let Ok(indicator) = indicator_query.get(circle_entity) {
-                                apply_guardian_circle_buff(
+                                let shielded = apply_guardian_circle_buff(
                                     &mut commands,
                                     indicator.position,
                                     constants::CIRCLE_RADIUS,
@@ -148,6 +129,11 @@ pub fn handle_guardian_circle_casting(
                                     constants::TEMP_HP_DURATION,
                                     &mut targets_query,
                                 );
+                                records.write(SpellCastRecord {
+                                    spell: Spell::GuardianCircle,
+                                    mana_cost: constants::MANA_COST,
+                                    affected: shielded,
+                                });
                             }
 
                             // Despawn circle indicator
@@ -190,6 +176,26 @@ pub fn handle_guardian_circle_casting(
     }
 }
 
+/// Despawns the circle indicator when the primed spell is cancelled.
+pub fn cancel_guardian_circle_on_spell_cancelled(
+    mut cancel_spell: MessageReader<CancelSpellMessage>,
+    mut commands: Commands,
+    caster_query: Query<(Entity, &GuardianCircleCaster), With<Wizard>>,
+) {
+    if cancel_spell.read().next().is_none() {
+        return;
+    }
+
+    if let Ok((wizard_entity, caster)) = caster_query.single() {
+        if let Some(circle_entity) = caster.circle_entity {
+            commands.entity(circle_entity).despawn();
+        }
+        commands
+            .entity(wizard_entity)
+            .remove::<GuardianCircleCaster>();
+    }
+}
+
 /// Updates circle indicator visuals during casting.
 ///
 /// Applies pulse animation and updates position/scale.
@@ -215,15 +221,17 @@ pub fn update_circle_indicator(
 /// Helper function to apply Guardian Circle buff to all units in radius.
 ///
 /// Grants temporary HP to units. If a unit already has temp HP, takes the maximum.
+/// Returns the number of units on a team that were shielded.
 fn apply_guardian_circle_buff(
     commands: &mut Commands,
     circle_pos: Vec3,
     radius: f32,
     temp_hp_amount: f32,
     duration: f32,
-    targets: &mut Query<(Entity, &Transform), Without<Wizard>>,
-) {
-    for (entity, transform) in targets.iter() {
+    targets: &mut Query<(Entity, &Transform, Has<Team>), Without<Wizard>>,
+) -> u32 {
+    let mut shielded = 0;
+    for (entity, transform, is_unit) in targets.iter() {
         let distance = transform.translation.distance(circle_pos);
 
         if distance <= radius {
@@ -231,8 +239,12 @@ fn apply_guardian_circle_buff(
             commands
                 .entity(entity)
                 .insert(TemporaryHitPoints::new(temp_hp_amount, duration));
+            if is_unit {
+                shielded += 1;
+            }
         }
     }
+    shielded
 }
 
 /// Helper function to spawn the visual circle indicator.
@@ -244,65 +256,16 @@ fn spawn_circle_indicator(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     position: Vec3,
 ) -> Entity {
-    let circle_mesh = meshes.add(Circle::new(constants::CIRCLE_RADIUS));
-    let circle_material = materials.add(StandardMaterial {
-        base_color: CIRCLE_COLOR,
-        unlit: true,
-        ..default()
-    });
-
-    commands
-        .spawn((
-            Mesh3d(circle_mesh),
-            MeshMaterial3d(circle_material),
-            Transform::from_translation(Vec3::new(
-                position.x,
-                constants::CIRCLE_Y_POSITION,
-                position.z,
-            ))
-            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
-            GuardianCircleIndicator::new(position),
-            OnGameplayScreen,
-        ))
-        .id()
-}
-
-/// Helper function to get cursor world position at Y=0 plane.
-///
-/// Ray casts from camera through cursor to find intersection with ground plane.
-fn get_cursor_world_position(
-    camera_query: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: &Query<&Window, With<PrimaryWindow>>,
-) -> Option<Vec3> {
-    let Ok((camera, camera_transform)) = camera_query.single() else {
-        return None;
-    };
-    let Ok(window) = window_query.single() else {
-        return None;
-    };
-
-    let cursor_position = window.cursor_position()?;
-
-    // Convert cursor position to world ray
-    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_position) else {
-        return None;
-    };
-
-    // Intersect ray with Y=0 plane
-    // Ray equation: P = origin + t * direction
-    // Plane equation: Y = 0
-    // Solve for t: origin.y + t * direction.y = 0
-    // t = -origin.y / direction.y
-
-    if ray.direction.y.abs() < 0.0001 {
-        return None; // Ray is parallel to plane
-    }
-
-    let t = -ray.origin.y / ray.direction.y;
-    if t < 0.0 {
-        return None; // Intersection is behind camera
+    CircleIndicator {
+        radius: constants::CIRCLE_RADIUS,
+        color: CIRCLE_COLOR,
+        alpha_mode: AlphaMode::Opaque,
     }
-
-    let intersection = ray.origin + ray.direction * t;
-    Some(intersection)
+    .spawn(
+        commands,
+        meshes,
+        materials,
+        Transform::from_xyz(position.x, constants::CIRCLE_Y_POSITION, position.z),
+        GuardianCircleIndicator::new(position),
+    )
 }
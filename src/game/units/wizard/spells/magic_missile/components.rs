@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use bevy::prelude::*;
 
 use super::constants;
@@ -72,3 +74,43 @@ impl MagicMissile {
         }
     }
 }
+
+/// Damage committed by magic missiles in flight, keyed by target.
+///
+/// Missiles commit their damage when they lock onto a target and release it when
+/// they hit something or despawn, so new missiles can skip enemies that the
+/// missiles already on their way will kill.
+#[derive(Resource, Default, Debug)]
+pub struct PendingDamage(HashMap<Entity, f32>);
+
+impl PendingDamage {
+    /// Records damage on its way to a target.
+    pub fn commit(&mut self, target: Entity, damage: f32) {
+        *self.0.entry(target).or_default() += damage;
+    }
+
+    /// Removes damage that will no longer reach a target.
+    pub fn release(&mut self, target: Entity, damage: f32) {
+        if let Some(pending) = self.0.get_mut(&target) {
+            *pending -= damage;
+            if *pending <= 0.0 {
+                self.0.remove(&target);
+            }
+        }
+    }
+
+    /// Returns the damage on its way to a target.
+    pub fn get(&self, target: Entity) -> f32 {
+        self.0.get(&target).copied().unwrap_or(0.0)
+    }
+
+    /// Returns true if the damage on its way is enough to kill the target.
+    pub fn is_doomed(&self, target: Entity, remaining_health: f32) -> bool {
+        self.get(target) >= remaining_health
+    }
+
+    /// Forgets all pending damage.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
@@ -90,3 +90,9 @@ pub const MIN_CHANNEL_INTERVAL: f32 = 0.05;
 
 /// Time to ramp from initial to minimum channel interval (in seconds).
 pub const CHANNEL_RAMP_TIME: f32 = 5.0;
+
+/// How long each magic missile trail segment lingers (in seconds).
+pub const TRAIL_LIFETIME: f32 = 0.25;
+
+/// Time between magic missile trail segments (in seconds).
+pub const TRAIL_SPAWN_INTERVAL: f32 = 0.01;
@@ -8,4 +8,5 @@ mod plugin;
 mod styles;
 mod systems;
 
+pub use components::MagicMissile;
 pub use plugin::MagicMissilePlugin;
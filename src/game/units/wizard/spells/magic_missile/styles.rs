@@ -5,3 +5,9 @@ pub const MAGIC_MISSILE_COLOR: Color = Color::srgb(1.0, 0.4, 0.8); // Pink
 
 /// Radius of the magic missile visual.
 pub const MAGIC_MISSILE_RADIUS: f32 = 5.0;
+
+/// Color of the magic missile trail (translucent pink).
+pub const TRAIL_COLOR: Color = Color::srgba(1.0, 0.4, 0.8, 0.6);
+
+/// Size of each magic missile trail segment.
+pub const TRAIL_SIZE: f32 = 6.0;
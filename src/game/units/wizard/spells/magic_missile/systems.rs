@@ -1,23 +1,31 @@
 use bevy::prelude::*;
 use rand::Rng;
 
-use super::super::super::components::{CastingState, Mana, PrimedSpell, Wizard};
+use super::super::super::components::{
+    CastingState, ChannelTally, Mana, PrimedSpell, Spell, Wizard,
+};
 use super::components::*;
 use super::constants;
 use super::styles::*;
+use crate::config::GameConfig;
+use crate::game::batch_commands::despawn_batch;
 use crate::game::components::OnGameplayScreen;
 use crate::game::constants::WIZARD_POSITION;
 use crate::game::input::events::MouseLeftReleased;
+use crate::game::resources::ProjectileCount;
 use crate::game::units::components::{
-    Corpse, Health, Team, TemporaryHitPoints, apply_damage_to_unit,
+    Corpse, DamageDealt, DamageSource, DamageType, Health, Resistances, Team, TemporaryHitPoints,
+    apply_damage_and_report, nearest_by_distance,
 };
+use crate::game::units::wizard::spells::trail::components::Trail;
 use crate::game::units::wizard::spells::wall_of_stone::components::WallOfStone;
 
 /// Handles magic missile casting with left-click.
 ///
 /// Left-click starts cast. Must hold for full cast time.
 /// After cast completes, enters channeling state where missiles spawn continuously.
-/// Only casts when Magic Missile is the primed spell.
+/// Only casts when Magic Missile is the primed spell. While the projectile cap is
+/// reached, missiles are held back (at no mana cost) until others land.
 ///
 /// Note: Spell priming, input blocking, and mouse state checks are handled by run_if conditions.
 #[allow(clippy::too_many_arguments)]
@@ -27,11 +35,35 @@ pub fn handle_magic_missile_casting(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    mut wizard_query: Query<(&mut CastingState, &mut Mana, &PrimedSpell, &Wizard), With<Wizard>>,
+    mut wizard_query: Query<
+        (
+            Entity,
+            &mut CastingState,
+            &mut Mana,
+            &PrimedSpell,
+            &Wizard,
+            Option<&mut ChannelTally>,
+        ),
+        With<Wizard>,
+    >,
     camera_query: Query<&GlobalTransform, With<Camera>>,
-    targets: Query<(Entity, &Transform, &Team), (Without<MagicMissile>, Without<Corpse>)>,
+    targets: Query<
+        (
+            Entity,
+            &Transform,
+            &Team,
+            &Health,
+            Option<&TemporaryHitPoints>,
+        ),
+        (Without<MagicMissile>, Without<Corpse>),
+    >,
+    mut pending_damage: ResMut<PendingDamage>,
+    config: Res<GameConfig>,
+    mut projectiles: ResMut<ProjectileCount>,
 ) {
-    let Ok((mut casting_state, mut mana, primed_spell, wizard)) = wizard_query.single_mut() else {
+    let Ok((wizard_entity, mut casting_state, mut mana, primed_spell, wizard, tally)) =
+        wizard_query.single_mut()
+    else {
         return;
     };
 
@@ -54,16 +86,23 @@ pub fn handle_magic_missile_casting(
                 constants::MIN_CHANNEL_INTERVAL,
                 constants::CHANNEL_RAMP_TIME,
             ) {
-                // Try to spawn missile if we have mana
-                if mana.consume(constants::MANA_COST) {
-                    spawn_magic_missile(
+                // Try to spawn missile if there's room and we have mana
+                if !projectiles.missile_has_room(config.max_projectiles) {
+                    // Too many projectiles in flight - try again next frame
+                } else if mana.consume(constants::MANA_COST) {
+                    let target = spawn_magic_missile(
                         &mut commands,
                         &mut meshes,
                         &mut materials,
                         &camera_query,
-                        &targets,
+                        &enemy_candidates(&targets),
                         wizard.spell_range,
+                        &mut pending_damage,
                     );
+                    projectiles.record_missile();
+                    if let Some(mut tally) = tally {
+                        tally.add(constants::MANA_COST, target);
+                    }
                     casting_state.reset_channel_interval();
                 } else {
                     // Out of mana - cancel channeling
@@ -75,18 +114,25 @@ pub fn handle_magic_missile_casting(
             // Currently casting - advance cast time
             casting_state.advance(time.delta_secs());
 
-            // Check if cast is complete
-            if casting_state.is_complete(primed_spell.cast_time) {
+            // Check if cast is complete (held while the projectile cap is reached)
+            if casting_state.is_complete(primed_spell.cast_time)
+                && projectiles.missile_has_room(config.max_projectiles)
+            {
                 // Cast complete - transition to channeling and spawn first missile
                 if mana.consume(constants::MANA_COST) {
-                    spawn_magic_missile(
+                    let target = spawn_magic_missile(
                         &mut commands,
                         &mut meshes,
                         &mut materials,
                         &camera_query,
-                        &targets,
+                        &enemy_candidates(&targets),
                         wizard.spell_range,
+                        &mut pending_damage,
                     );
+                    projectiles.record_missile();
+                    let mut tally = ChannelTally::new(Spell::MagicMissile);
+                    tally.add(constants::MANA_COST, target);
+                    commands.entity(wizard_entity).insert(tally);
                     casting_state.start_channeling();
                 } else {
                     // Out of mana - cancel cast
@@ -106,47 +152,25 @@ pub fn handle_magic_missile_casting(
 /// Spawns a single magic missile projectile.
 ///
 /// Helper function for spawning missiles with random trajectories that arc towards camera.
-/// Selects a random target within spell range, or falls back to closest target.
+/// Selects a target with `pick_target` and commits the missile's damage to it.
+/// Returns the chosen target, if any.
 fn spawn_magic_missile(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     camera_query: &Query<&GlobalTransform, With<Camera>>,
-    targets: &Query<(Entity, &Transform, &Team), (Without<MagicMissile>, Without<Corpse>)>,
+    candidates: &[(Entity, Vec3, f32)],
     spell_range: f32,
-) {
+    pending_damage: &mut PendingDamage,
+) -> Option<Entity> {
     // Spawn position: above the wizard
     let spawn_pos = WIZARD_POSITION + Vec3::new(0.0, constants::SPAWN_HEIGHT_OFFSET, 0.0);
 
-    // Select target: random enemy (Attacker or Undead) within range, or closest enemy
     let mut rng = rand::thread_rng();
-
-    let enemies_in_range: Vec<Entity> = targets
-        .iter()
-        .filter(|(_, _, team)| **team == Team::Attackers || **team == Team::Undead)
-        .filter(|(_, transform, _)| {
-            let distance = spawn_pos.distance(transform.translation);
-            distance <= spell_range
-        })
-        .map(|(entity, _, _)| entity)
-        .collect();
-
-    let target = if !enemies_in_range.is_empty() {
-        // Pick a random target within range
-        let index = rng.gen_range(0..enemies_in_range.len());
-        Some(enemies_in_range[index])
-    } else {
-        // No targets in range, find the closest enemy anywhere
-        targets
-            .iter()
-            .filter(|(_, _, team)| **team == Team::Attackers || **team == Team::Undead)
-            .min_by(|a, b| {
-                let dist_a = spawn_pos.distance(a.1.translation);
-                let dist_b = spawn_pos.distance(b.1.translation);
-                dist_a.partial_cmp(&dist_b).unwrap()
-            })
-            .map(|(entity, _, _)| entity)
-    };
+    let target = pick_target(&mut rng, spawn_pos, candidates, spell_range, pending_damage);
+    if let Some(target) = target {
+        pending_damage.commit(target, constants::DAMAGE);
+    }
 
     // Random initial velocity: varied launch paths (up and to the sides, never down)
     let horizontal_x = rng.gen_range(constants::HORIZONTAL_VEL_MIN..constants::HORIZONTAL_VEL_MAX);
@@ -179,18 +203,102 @@ fn spawn_magic_missile(
         })),
         Transform::from_translation(spawn_pos),
         MagicMissile::new(initial_velocity, wobble_offset, target),
+        Trail::new(
+            TRAIL_COLOR,
+            constants::TRAIL_LIFETIME,
+            constants::TRAIL_SPAWN_INTERVAL,
+            TRAIL_SIZE,
+        ),
         OnGameplayScreen,
     ));
+
+    target
+}
+
+/// Returns every enemy (Attacker or Undead) as `(entity, position, remaining health)`.
+///
+/// Remaining health includes temporary hit points.
+fn enemy_candidates(
+    targets: &Query<
+        (
+            Entity,
+            &Transform,
+            &Team,
+            &Health,
+            Option<&TemporaryHitPoints>,
+        ),
+        (Without<MagicMissile>, Without<Corpse>),
+    >,
+) -> Vec<(Entity, Vec3, f32)> {
+    targets
+        .iter()
+        .filter(|(_, _, team, _, _)| **team == Team::Attackers || **team == Team::Undead)
+        .map(|(entity, transform, _, health, temp_hp)| {
+            let remaining = health.current + temp_hp.map_or(0.0, |temp_hp| temp_hp.amount);
+            (entity, transform.translation, remaining)
+        })
+        .collect()
+}
+
+/// Picks a target for a missile at `origin` from `(entity, position, remaining health)` candidates.
+///
+/// Prefers a random enemy within spell range, falling back to the closest enemy
+/// anywhere. Enemies in range that the missiles already in flight will kill are
+/// skipped, unless every enemy in range is doomed.
+fn pick_target(
+    rng: &mut impl Rng,
+    origin: Vec3,
+    candidates: &[(Entity, Vec3, f32)],
+    spell_range: f32,
+    pending_damage: &PendingDamage,
+) -> Option<Entity> {
+    let in_range: Vec<Entity> = candidates
+        .iter()
+        .filter(|(_, position, _)| origin.distance(*position) <= spell_range)
+        .map(|(entity, _, _)| *entity)
+        .collect();
+    let viable: Vec<Entity> = candidates
+        .iter()
+        .filter(|(entity, _, remaining)| {
+            in_range.contains(entity) && !pending_damage.is_doomed(*entity, *remaining)
+        })
+        .map(|(entity, _, _)| *entity)
+        .collect();
+
+    let pool = if viable.is_empty() {
+        &in_range
+    } else {
+        &viable
+    };
+    if !pool.is_empty() {
+        // Pick a random target within range
+        return Some(pool[rng.gen_range(0..pool.len())]);
+    }
+
+    // No targets in range, find the closest enemy anywhere
+    nearest_by_distance(candidates, |(_, position, _)| origin.distance(*position))
+        .map(|(entity, _, _)| *entity)
 }
 
 /// Updates magic missile movement with homing and wobble.
 ///
-/// Missiles lock onto their initial target and only retarget if it despawns.
+/// Missiles lock onto their initial target and only retarget if it despawns,
+/// moving their pending damage over to the new target.
 pub fn move_magic_missiles(
     time: Res<Time>,
     mut missiles: Query<(&mut Transform, &mut MagicMissile)>,
-    targets: Query<(Entity, &Transform, &Team), (Without<MagicMissile>, Without<Corpse>)>,
+    targets: Query<
+        (
+            Entity,
+            &Transform,
+            &Team,
+            &Health,
+            Option<&TemporaryHitPoints>,
+        ),
+        (Without<MagicMissile>, Without<Corpse>),
+    >,
     wizard_query: Query<&Wizard>,
+    mut pending_damage: ResMut<PendingDamage>,
 ) {
     let Ok(wizard) = wizard_query.single() else {
         return;
@@ -208,44 +316,28 @@ pub fn move_magic_missiles(
 
         // Retarget if current target despawned
         if !target_exists {
-            // Select new target: random enemy (Attacker or Undead) within range, or closest enemy
-            let mut rng = rand::thread_rng();
+            if let Some(old_target) = missile.target {
+                pending_damage.release(old_target, missile.damage);
+            }
 
-            let enemies_in_range: Vec<Entity> = targets
-                .iter()
-                .filter(|(_, _, team)| **team == Team::Attackers || **team == Team::Undead)
-                .filter(|(_, transform, _)| {
-                    let distance = missile_transform
-                        .translation
-                        .distance(transform.translation);
-                    distance <= spell_range
-                })
-                .map(|(entity, _, _)| entity)
-                .collect();
-
-            missile.target = if !enemies_in_range.is_empty() {
-                // Pick a random target within range
-                let index = rng.gen_range(0..enemies_in_range.len());
-                Some(enemies_in_range[index])
-            } else {
-                // No targets in range, find the closest enemy anywhere
-                targets
-                    .iter()
-                    .filter(|(_, _, team)| **team == Team::Attackers || **team == Team::Undead)
-                    .min_by(|a, b| {
-                        let dist_a = missile_transform.translation.distance(a.1.translation);
-                        let dist_b = missile_transform.translation.distance(b.1.translation);
-                        dist_a.partial_cmp(&dist_b).unwrap()
-                    })
-                    .map(|(entity, _, _)| entity)
-            };
+            let mut rng = rand::thread_rng();
+            missile.target = pick_target(
+                &mut rng,
+                missile_transform.translation,
+                &enemy_candidates(&targets),
+                spell_range,
+                &pending_damage,
+            );
+            if let Some(new_target) = missile.target {
+                pending_damage.commit(new_target, missile.damage);
+            }
         }
 
         // Get current target's transform
         let target_transform = missile
             .target
             .and_then(|target_entity| targets.get(target_entity).ok())
-            .map(|(_, transform, _)| transform);
+            .map(|(_, transform, ..)| transform);
 
         if let Some(target_transform) = target_transform {
             let to_target = target_transform.translation - missile_transform.translation;
@@ -324,15 +416,21 @@ pub fn check_magic_missile_collisions(
     missiles: Query<(Entity, &Transform, &MagicMissile)>,
     mut enemies: Query<
         (
+            Entity,
             &Transform,
             &mut Health,
             Option<&mut TemporaryHitPoints>,
+            Option<&Resistances>,
             &Team,
         ),
         (Without<MagicMissile>, Without<Corpse>),
     >,
     walls: Query<&WallOfStone>,
+    mut damage_dealt: MessageWriter<DamageDealt>,
+    mut pending_damage: ResMut<PendingDamage>,
 ) {
+    let mut spent = Vec::new();
+
     for (missile_entity, missile_transform, missile) in &missiles {
         // Wall collision
         let mut hit_wall = false;
@@ -340,7 +438,8 @@ pub fn check_magic_missile_collisions(
             if wall.contains_point_xz(missile_transform.translation)
                 && missile_transform.translation.y <= wall.height
             {
-                commands.entity(missile_entity).despawn();
+                spent.push(missile_entity);
+                release_pending_damage(&mut pending_damage, missile);
                 hit_wall = true;
                 break;
             }
@@ -349,7 +448,9 @@ pub fn check_magic_missile_collisions(
             continue;
         }
 
-        for (enemy_transform, mut health, mut temp_hp, team) in &mut enemies {
+        for (enemy_entity, enemy_transform, mut health, mut temp_hp, resistances, team) in
+            &mut enemies
+        {
             // Magic Missile targets Attackers and Undead
             if *team != Team::Attackers && *team != Team::Undead {
                 continue;
@@ -361,19 +462,42 @@ pub fn check_magic_missile_collisions(
 
             // Check collision
             if distance < missile.radius {
-                apply_damage_to_unit(&mut health, temp_hp.as_deref_mut(), missile.damage);
-                commands.entity(missile_entity).despawn();
+                apply_damage_and_report(
+                    &mut health,
+                    temp_hp.as_deref_mut(),
+                    resistances,
+                    missile.damage,
+                    DamageType::Magic,
+                    DamageSource::Spell(Spell::MagicMissile),
+                    enemy_entity,
+                    enemy_transform.translation,
+                    &mut damage_dealt,
+                );
+                spent.push(missile_entity);
+                release_pending_damage(&mut pending_damage, missile);
                 break; // Missile destroyed, stop checking
             }
         }
     }
+
+    if !spent.is_empty() {
+        commands.queue(despawn_batch(spent));
+    }
+}
+
+/// Releases a spent missile's damage from its locked target.
+fn release_pending_damage(pending_damage: &mut PendingDamage, missile: &MagicMissile) {
+    if let Some(target) = missile.target {
+        pending_damage.release(target, missile.damage);
+    }
 }
 
 /// Despawns magic missiles that exit the wizard's spell range.
 pub fn despawn_distant_magic_missiles(
     mut commands: Commands,
-    missiles: Query<(Entity, &Transform), With<MagicMissile>>,
+    missiles: Query<(Entity, &Transform, &MagicMissile)>,
     wizard_query: Query<(&Transform, &Wizard), Without<MagicMissile>>,
+    mut pending_damage: ResMut<PendingDamage>,
 ) {
     // Get wizard position and spell range
     let Ok((wizard_transform, wizard)) = wizard_query.single() else {
@@ -383,11 +507,91 @@ pub fn despawn_distant_magic_missiles(
     let wizard_pos = wizard_transform.translation;
     let spell_range = wizard.spell_range;
 
-    for (entity, transform) in &missiles {
+    for (entity, transform, missile) in &missiles {
         let distance_from_wizard = transform.translation.distance(wizard_pos);
 
         if distance_from_wizard > spell_range {
+            release_pending_damage(&mut pending_damage, missile);
             commands.entity(entity).despawn();
         }
     }
 }
+
+/// Clears pending missile damage when a new game starts.
+pub fn reset_pending_damage(mut pending_damage: ResMut<PendingDamage>) {
+    pending_damage.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use crate::game::shared_systems::count_projectiles;
+    use crate::game::units::archer::components::Arrow;
+
+    #[test]
+    fn test_new_missile_skips_doomed_target() {
+        let doomed = Entity::from_raw_u32(1).unwrap();
+        let healthy = Entity::from_raw_u32(2).unwrap();
+        let candidates = [
+            (doomed, Vec3::new(100.0, 0.0, 0.0), constants::DAMAGE - 1.0),
+            (healthy, Vec3::new(-100.0, 0.0, 0.0), 100.0),
+        ];
+
+        // A missile already locked on the nearly-dead enemy will kill it
+        let mut pending_damage = PendingDamage::default();
+        pending_damage.commit(doomed, constants::DAMAGE);
+
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..20 {
+            let target = pick_target(&mut rng, Vec3::ZERO, &candidates, 500.0, &pending_damage);
+            assert_eq!(target, Some(healthy));
+        }
+
+        // Once that missile hits, the enemy is fair game again
+        pending_damage.release(doomed, constants::DAMAGE);
+        assert!(!pending_damage.is_doomed(doomed, constants::DAMAGE - 1.0));
+        assert_eq!(pending_damage.get(doomed), 0.0);
+    }
+
+    #[test]
+    fn test_missile_spawns_while_arrows_fill_the_cap() {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            max_projectiles: Some(1),
+            ..default()
+        })
+        .init_resource::<Time>()
+        .init_resource::<ProjectileCount>()
+        .init_resource::<PendingDamage>()
+        .init_resource::<Assets<Mesh>>()
+        .init_resource::<Assets<StandardMaterial>>()
+        .add_message::<MouseLeftReleased>()
+        .add_systems(
+            Update,
+            (count_projectiles, handle_magic_missile_casting).chain(),
+        );
+
+        app.world_mut().spawn(Arrow {
+            velocity: Vec3::ZERO,
+            damage: 0.0,
+            source_team: Team::Attackers,
+        });
+        app.world_mut().spawn((
+            Wizard::new(500.0),
+            CastingState::Casting { elapsed: 10.0 },
+            Mana::new(100.0, 0.0),
+            constants::PRIMED_MAGIC_MISSILE,
+        ));
+        app.update();
+
+        let missiles = app
+            .world_mut()
+            .query::<&MagicMissile>()
+            .iter(app.world())
+            .count();
+        assert_eq!(missiles, 1);
+    }
+}
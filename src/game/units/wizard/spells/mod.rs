@@ -2,29 +2,42 @@
 //!
 //! Handles wizard spells, projectiles, and spell effects.
 
+mod beam_preview;
+mod blizzard;
 mod chain_lightning;
+mod circle_placement;
 mod components;
+mod conjure_guardian;
 mod disintegrate;
 mod finger_of_death;
 mod fireball;
+mod frost_nova;
 mod guardian_circle;
 mod magic_missile;
 mod plugin;
 mod raise_the_dead;
 pub mod run_conditions;
 mod systems;
+mod targeting;
 mod teleport;
+mod trail;
 pub mod wall_of_stone;
 
 // Re-export constants for wizard setup and spell switching
+pub use blizzard::constants as blizzard_constants;
 pub use chain_lightning::constants as chain_lightning_constants;
+pub use conjure_guardian::constants as conjure_guardian_constants;
 pub use disintegrate::constants as disintegrate_constants;
 pub use finger_of_death::constants as finger_of_death_constants;
 pub use fireball::constants as fireball_constants;
+pub use frost_nova::constants as frost_nova_constants;
 pub use guardian_circle::constants as guardian_circle_constants;
 pub use magic_missile::constants as magic_missile_constants;
 pub use raise_the_dead::constants as raise_the_dead_constants;
 pub use teleport::constants as teleport_constants;
 pub use wall_of_stone::constants as wall_of_stone_constants;
 
+// Re-export projectile components counted toward the projectile cap
+pub use magic_missile::MagicMissile;
+
 pub use plugin::SpellsPlugin;
@@ -2,15 +2,20 @@ use bevy::prelude::*;
 
 use crate::state::InGameState;
 
+use super::beam_preview::BeamPreviewPlugin;
+use super::blizzard::BlizzardPlugin;
 use super::chain_lightning::ChainLightningPlugin;
+use super::conjure_guardian::ConjureGuardianPlugin;
 use super::disintegrate::DisintegratePlugin;
 use super::finger_of_death::FingerOfDeathPlugin;
 use super::fireball::FireballPlugin;
+use super::frost_nova::FrostNovaPlugin;
 use super::guardian_circle::GuardianCirclePlugin;
 use super::magic_missile::MagicMissilePlugin;
 use super::raise_the_dead::RaiseTheDeadPlugin;
 use super::systems;
 use super::teleport::TeleportPlugin;
+use super::trail::TrailPlugin;
 use super::wall_of_stone::plugin::WallOfStonePlugin;
 
 /// Plugin that handles wizard spells and projectiles.
@@ -23,10 +28,16 @@ use super::wall_of_stone::plugin::WallOfStonePlugin;
 /// - Chain Lightning spell (ChainLightningPlugin)
 /// - Finger of Death spell (FingerOfDeathPlugin)
 /// - Raise The Dead spell (RaiseTheDeadPlugin)
+/// - Frost Nova spell (FrostNovaPlugin)
+/// - Conjure Guardian spell (ConjureGuardianPlugin)
+/// - Blizzard spell (BlizzardPlugin)
+/// - Aim preview for beam spells (BeamPreviewPlugin)
+/// - Projectile trails (TrailPlugin)
 /// - Projectile movement
 /// - Projectile collision detection
 /// - Spell effect lifetime management
 /// - Projectile cleanup
+/// - Resetting caster state when gameplay stops running
 pub struct SpellsPlugin;
 
 impl Plugin for SpellsPlugin {
@@ -41,6 +52,11 @@ impl Plugin for SpellsPlugin {
             RaiseTheDeadPlugin,
             TeleportPlugin,
             WallOfStonePlugin,
+            FrostNovaPlugin,
+            ConjureGuardianPlugin,
+            BlizzardPlugin,
+            BeamPreviewPlugin,
+            TrailPlugin,
         ))
         .add_systems(
             Update,
@@ -52,6 +68,7 @@ impl Plugin for SpellsPlugin {
             )
                 .chain()
                 .run_if(in_state(InGameState::Running)),
-        );
+        )
+        .add_systems(OnExit(InGameState::Running), systems::reset_spell_casters);
     }
 }
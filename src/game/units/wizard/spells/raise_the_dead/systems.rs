@@ -1,15 +1,15 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
 
-use super::super::super::components::{CastingState, Mana, PrimedSpell};
+use super::super::super::components::{CastingState, ChannelTally, Mana, PrimedSpell, Spell};
 use super::components::*;
 use super::constants::*;
 use crate::game::components::{Acceleration, Billboard, Velocity};
 use crate::game::constants::{DEFENDER_HITBOX_HEIGHT, UNIT_HEALTH, UNIT_MOVEMENT_SPEED};
+use crate::game::input::components::CursorWorldPosition;
 use crate::game::input::events::MouseLeftReleased;
 use crate::game::units::components::{
-    AttackTiming, Corpse, Effectiveness, Health, Hitbox, MovementSpeed, PermanentCorpse,
-    RoughTerrain, Team, Teleportable,
+    AttackTiming, Corpse, CorpseAge, Effectiveness, Health, Hitbox, MovementSpeed, PermanentCorpse,
+    RoughTerrain, Team, Teleportable, nearest_by_distance, spawn_attack_offset,
 };
 use crate::game::units::infantry::components::Infantry;
 
@@ -28,14 +28,21 @@ pub fn handle_raise_the_dead_casting(
     time: Res<Time>,
     mut mouse_left_released: MessageReader<MouseLeftReleased>,
     mut commands: Commands,
-    mut wizard_query: Query<(&mut CastingState, &mut Mana, &PrimedSpell)>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut wizard_query: Query<(
+        Entity,
+        &mut CastingState,
+        &mut Mana,
+        &PrimedSpell,
+        Option<&mut ChannelTally>,
+    )>,
+    cursor: Res<CursorWorldPosition>,
     corpse_query: Query<(Entity, &Transform, &Team), (With<Corpse>, Without<PermanentCorpse>)>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     material_query: Query<&MeshMaterial3d<StandardMaterial>>,
 ) {
-    let Ok((mut casting_state, mut mana, primed_spell)) = wizard_query.single_mut() else {
+    let Ok((wizard_entity, mut casting_state, mut mana, primed_spell, mut tally)) =
+        wizard_query.single_mut()
+    else {
         return;
     };
 
@@ -61,10 +68,9 @@ pub fn handle_raise_the_dead_casting(
                 // Try to resurrect corpse if we have mana
                 if mana.consume(MANA_COST_PER_CORPSE) {
                     // Find corpse near cursor
-                    if let Some(cursor_pos) =
-                        get_cursor_world_position(&camera_query, &window_query)
-                    {
-                        resurrect_nearest_corpse(
+                    let mut raised = None;
+                    if let Some(cursor_pos) = cursor.ground {
+                        raised = resurrect_nearest_corpse(
                             &mut commands,
                             cursor_pos,
                             &corpse_query,
@@ -73,6 +79,9 @@ pub fn handle_raise_the_dead_casting(
                         );
                         casting_state.reset_channel_interval();
                     }
+                    if let Some(tally) = tally.as_mut() {
+                        tally.add(MANA_COST_PER_CORPSE, raised);
+                    }
                 } else {
                     // Out of mana - cancel channeling
                     casting_state.cancel();
@@ -87,16 +96,17 @@ pub fn handle_raise_the_dead_casting(
             if casting_state.is_complete(primed_spell.cast_time) {
                 // Cast complete - transition to channeling and resurrect first corpse
                 if mana.consume(MANA_COST_PER_CORPSE) {
-                    if let Some(cursor_pos) =
-                        get_cursor_world_position(&camera_query, &window_query)
-                    {
-                        resurrect_nearest_corpse(
+                    if let Some(cursor_pos) = cursor.ground {
+                        let raised = resurrect_nearest_corpse(
                             &mut commands,
                             cursor_pos,
                             &corpse_query,
                             &mut materials,
                             &material_query,
                         );
+                        let mut tally = ChannelTally::new(Spell::RaiseTheDead);
+                        tally.add(MANA_COST_PER_CORPSE, raised);
+                        commands.entity(wizard_entity).insert(tally);
                         casting_state.start_channeling();
                     }
                 } else {
@@ -117,84 +127,62 @@ pub fn handle_raise_the_dead_casting(
 /// Resurrects the nearest corpse to the target position as infantry.
 ///
 /// Searches for corpses within RESURRECTION_RADIUS and resurrects the closest one.
-/// All raised undead are infantry units.
+/// All raised undead are infantry units. Returns the raised unit, if any.
 fn resurrect_nearest_corpse(
     commands: &mut Commands,
     target_pos: Vec3,
     corpse_query: &Query<(Entity, &Transform, &Team), (With<Corpse>, Without<PermanentCorpse>)>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     material_query: &Query<&MeshMaterial3d<StandardMaterial>>,
-) {
+) -> Option<Entity> {
     // Find nearest corpse within radius
-    if let Some((corpse_entity, corpse_transform, _)) = corpse_query
-        .iter()
-        .filter(|(_, transform, _)| {
+    let (corpse_entity, corpse_transform, _) = nearest_by_distance(
+        corpse_query.iter().filter(|(_, transform, _)| {
             target_pos.distance(transform.translation) <= RESURRECTION_RADIUS
-        })
-        .min_by(|a, b| {
-            let dist_a = target_pos.distance(a.1.translation);
-            let dist_b = target_pos.distance(b.1.translation);
-            dist_a.partial_cmp(&dist_b).unwrap()
-        })
-    {
-        // Change sprite color to undead green
-        if let Ok(material_handle) = material_query.get(corpse_entity)
-            && let Some(material) = materials.get_mut(&material_handle.0)
-        {
-            material.base_color = UNDEAD_COLOR;
-        }
-
-        // Calculate upright position: bottom edge 1 unit above battlefield
-        let hitbox = Hitbox::new(UNIT_RADIUS, DEFENDER_HITBOX_HEIGHT);
-        let spawn_y = hitbox.height / 2.0 + 1.0;
-        let upright_transform = Transform::from_xyz(
-            corpse_transform.translation.x,
-            spawn_y,
-            corpse_transform.translation.z,
-        );
+        }),
+        |(_, transform, _)| target_pos.distance(transform.translation),
+    )?;
 
-        // Restore combat components but change team
-        commands
-            .entity(corpse_entity)
-            .remove::<Corpse>()
-            .remove::<RoughTerrain>()
-            .insert(upright_transform) // Stand upright
-            .insert(Team::Undead)
-            .insert(Health::new(UNIT_HEALTH)) // Full health restoration
-            .insert(Velocity::default())
-            .insert(Acceleration::new())
-            .insert(MovementSpeed(UNIT_MOVEMENT_SPEED * 0.5)) // Half speed
-            .insert(AttackTiming::new())
-            .insert(Effectiveness::new())
-            .insert(Billboard)
-            .insert(hitbox) // Restore collision
-            .insert(Teleportable) // Can be teleported
-            .insert(RaisedUndead) // Marker for tracking
-            .insert(Infantry)
-            .insert(crate::game::units::components::TargetingVelocity::default())
-            .insert(crate::game::units::components::FlockingVelocity::default());
+    // Change sprite color to undead green
+    if let Ok(material_handle) = material_query.get(corpse_entity)
+        && let Some(material) = materials.get_mut(&material_handle.0)
+    {
+        material.base_color = UNDEAD_COLOR;
     }
-}
 
-/// Gets cursor position projected onto Y=0 plane (same as other spells).
-///
-/// Returns None if cursor is not in window or ray doesn't intersect Y=0 plane.
-fn get_cursor_world_position(
-    camera_query: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: &Query<&Window, With<PrimaryWindow>>,
-) -> Option<Vec3> {
-    let (camera, camera_transform) = camera_query.single().ok()?;
-    let window = window_query.single().ok()?;
-    let cursor_pos = window.cursor_position()?;
+    // Calculate upright position: bottom edge 1 unit above battlefield
+    let hitbox = Hitbox::new(UNIT_RADIUS, DEFENDER_HITBOX_HEIGHT);
+    let spawn_y = hitbox.height / 2.0 + 1.0;
+    let upright_transform = Transform::from_xyz(
+        corpse_transform.translation.x,
+        spawn_y,
+        corpse_transform.translation.z,
+    );
 
-    let ray = camera
-        .viewport_to_world(camera_transform, cursor_pos)
-        .ok()?;
-    let t = -ray.origin.y / ray.direction.y;
+    // Restore combat components but change team
+    commands
+        .entity(corpse_entity)
+        .remove::<Corpse>()
+        .remove::<RoughTerrain>()
+        .remove::<CorpseAge>()
+        .insert(upright_transform) // Stand upright
+        .insert(Team::Undead)
+        .insert(Health::new(UNIT_HEALTH)) // Full health restoration
+        .insert(Velocity::default())
+        .insert(Acceleration::new())
+        .insert(MovementSpeed(UNIT_MOVEMENT_SPEED * 0.5)) // Half speed
+        .insert(AttackTiming::with_offset(spawn_attack_offset(
+            corpse_transform.translation.x,
+            corpse_transform.translation.z,
+        )))
+        .insert(Effectiveness::new())
+        .insert(Billboard)
+        .insert(hitbox) // Restore collision
+        .insert(Teleportable) // Can be teleported
+        .insert(RaisedUndead) // Marker for tracking
+        .insert(Infantry)
+        .insert(crate::game::units::components::TargetingVelocity::default())
+        .insert(crate::game::units::components::FlockingVelocity::default());
 
-    if t > 0.0 {
-        Some(ray.origin + ray.direction * t)
-    } else {
-        None
-    }
+    Some(corpse_entity)
 }
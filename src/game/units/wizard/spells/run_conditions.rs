@@ -2,7 +2,8 @@ use bevy::prelude::*;
 
 use super::super::components::{CastingState, PrimedSpell, Spell, Wizard};
 use crate::game::input::components::{
-    MouseButtonState, MouseLeftHeldThisFrame, MouseRightHeldThisFrame, SpellInputBlockedThisFrame,
+    CastGrace, MouseButtonState, MouseLeftHeldThisFrame, MouseRightHeldThisFrame,
+    SpellInputBlockedThisFrame,
 };
 
 /// Check if specific spell is primed
@@ -35,12 +36,14 @@ pub fn wizard_is_casting_or_channeling(wizard_query: Query<&CastingState, With<W
 }
 
 /// Check if mouse is held OR wizard is currently casting/channeling
-/// This ensures the system runs both during active casting and when releasing to cancel
+/// This ensures the system runs both during active casting and when releasing to cancel.
+/// A cast whose release is held back by `CastGrace` stays frozen until it resumes or cancels.
 pub fn mouse_held_or_wizard_casting(
     mouse_held: Res<MouseLeftHeldThisFrame>,
+    cast_grace: Res<CastGrace>,
     wizard_query: Query<&CastingState, With<Wizard>>,
 ) -> bool {
-    mouse_held.held || wizard_is_casting_or_channeling(wizard_query)
+    mouse_held.held || (!cast_grace.is_pending() && wizard_is_casting_or_channeling(wizard_query))
 }
 
 /// Check if right mouse button is NOT held
@@ -1,9 +1,22 @@
 use bevy::prelude::*;
 
+use super::blizzard::components::BlizzardCaster;
 use super::components::*;
-use super::wall_of_stone::components::WallOfStone;
-use crate::game::units::components::{Health, Team, TemporaryHitPoints, apply_damage_to_unit};
+use super::conjure_guardian::components::ConjureGuardianCaster;
+use super::disintegrate::components::DisintegrateCaster;
+use super::finger_of_death::components::AwaitingFingerOfDeathRelease;
+use super::fireball::components::FireballCharge;
+use super::frost_nova::components::FrostNovaCaster;
+use super::guardian_circle::components::GuardianCircleCaster;
+use super::teleport::components::TeleportCaster;
+use super::wall_of_stone::components::{WallOfStone, WallOfStoneCaster};
+use crate::game::batch_commands::despawn_batch;
+use crate::game::units::components::{
+    DamageDealt, DamageSource, DamageType, Health, Resistances, Team, TemporaryHitPoints,
+    apply_damage_and_report,
+};
 use crate::game::units::infantry::components::Infantry;
+use crate::game::units::wizard::components::{QueuedSpell, Wizard};
 
 /// Updates all projectile positions based on their direction and speed.
 ///
@@ -25,15 +38,20 @@ pub fn check_projectile_collisions(
     projectiles: Query<(Entity, &Transform, &Projectile), With<Projectile>>,
     mut enemies: Query<
         (
+            Entity,
             &Transform,
             &mut Health,
             Option<&mut TemporaryHitPoints>,
+            Option<&Resistances>,
             &Team,
         ),
         With<Infantry>,
     >,
     walls: Query<&WallOfStone>,
+    mut damage_dealt: MessageWriter<DamageDealt>,
 ) {
+    let mut spent = Vec::new();
+
     for (projectile_entity, proj_transform, projectile) in &projectiles {
         // Check wall collision
         let mut hit_wall = false;
@@ -41,7 +59,7 @@ pub fn check_projectile_collisions(
             if wall.contains_point_xz(proj_transform.translation)
                 && proj_transform.translation.y <= wall.height
             {
-                commands.entity(projectile_entity).despawn();
+                spent.push(projectile_entity);
                 hit_wall = true;
                 break;
             }
@@ -50,7 +68,9 @@ pub fn check_projectile_collisions(
             continue;
         }
 
-        for (enemy_transform, mut health, mut temp_hp, team) in &mut enemies {
+        for (enemy_entity, enemy_transform, mut health, mut temp_hp, resistances, team) in
+            &mut enemies
+        {
             // Only damage attackers (projectiles are from defenders/wizard)
             if *team != Team::Attackers {
                 continue;
@@ -62,12 +82,26 @@ pub fn check_projectile_collisions(
 
             // Check if projectile hit the enemy
             if distance < projectile.radius {
-                apply_damage_to_unit(&mut health, temp_hp.as_deref_mut(), projectile.damage);
-                commands.entity(projectile_entity).despawn();
+                apply_damage_and_report(
+                    &mut health,
+                    temp_hp.as_deref_mut(),
+                    resistances,
+                    projectile.damage,
+                    DamageType::Magic,
+                    DamageSource::Spell(projectile.spell),
+                    enemy_entity,
+                    enemy_transform.translation,
+                    &mut damage_dealt,
+                );
+                spent.push(projectile_entity);
                 break; // Projectile is destroyed, stop checking
             }
         }
     }
+
+    if !spent.is_empty() {
+        commands.queue(despawn_batch(spent));
+    }
 }
 
 /// Updates spell effects and despawns them when their lifetime expires.
@@ -96,11 +130,155 @@ pub fn despawn_distant_projectiles(
 ) {
     const MAX_DISTANCE: f32 = 10000.0;
 
+    let mut spent = Vec::new();
+
     for (entity, transform) in &projectiles {
         let distance_from_origin = transform.translation.length();
 
         if distance_from_origin > MAX_DISTANCE {
-            commands.entity(entity).despawn();
+            spent.push(entity);
         }
     }
+
+    if !spent.is_empty() {
+        commands.queue(despawn_batch(spent));
+    }
+}
+
+/// Resets every spell's caster state on the wizard when gameplay stops running.
+///
+/// A placement interrupted by a pause, the spell book, or game over would
+/// otherwise leave caster components pointing at indicator entities that
+/// `cleanup_game` may despawn. Indicators still alive are despawned, per-cast
+/// markers (including a half-charged fireball and a queued spell) are removed,
+/// and the persistent Teleport and Wall of Stone casters are replaced with
+/// empty ones, so every spell starts fresh on resume or replay.
+#[allow(clippy::type_complexity)]
+pub fn reset_spell_casters(
+    mut commands: Commands,
+    wizard_query: Query<
+        (
+            Entity,
+            Option<&TeleportCaster>,
+            Option<&WallOfStoneCaster>,
+            Option<&GuardianCircleCaster>,
+            Option<&FrostNovaCaster>,
+            Option<&BlizzardCaster>,
+            Option<&ConjureGuardianCaster>,
+        ),
+        With<Wizard>,
+    >,
+) {
+    let Ok((wizard, teleport, wall, guardian_circle, frost_nova, blizzard, conjure_guardian)) =
+        wizard_query.single()
+    else {
+        return;
+    };
+
+    let indicators = teleport
+        .into_iter()
+        .flat_map(|caster| [caster.destination_circle, caster.source_circle])
+        .chain(wall.map(|caster| caster.preview_entity))
+        .chain(guardian_circle.map(|caster| caster.circle_entity))
+        .chain(frost_nova.map(|caster| caster.circle_entity))
+        .chain(blizzard.map(|caster| caster.circle_entity))
+        .chain(conjure_guardian.map(|caster| caster.circle_entity))
+        .flatten();
+    for indicator in indicators {
+        // The indicator may already be gone, e.g. despawned by `cleanup_game`
+        if let Ok(mut entity) = commands.get_entity(indicator) {
+            entity.despawn();
+        }
+    }
+
+    commands
+        .entity(wizard)
+        .remove::<(
+            GuardianCircleCaster,
+            FrostNovaCaster,
+            BlizzardCaster,
+            ConjureGuardianCaster,
+            DisintegrateCaster,
+            AwaitingFingerOfDeathRelease,
+            FireballCharge,
+            QueuedSpell,
+        )>()
+        .insert((TeleportCaster::new(), WallOfStoneCaster::new()));
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+    use crate::game::input::MouseButtonState;
+    use crate::game::units::wizard::components::CastingState;
+    use crate::game::units::wizard::constants::DEFAULT_SPELL_RANGE;
+    use crate::game::units::wizard::spells::fireball_constants::PRIMED_FIREBALL;
+    use crate::game::units::wizard::systems::cancel_active_casts;
+    use crate::state::{AppState, InGameState};
+
+    fn set_in_game_state(app: &mut App, state: InGameState) {
+        app.world_mut()
+            .resource_mut::<NextState<InGameState>>()
+            .set(state);
+        app.update();
+    }
+
+    #[test]
+    fn test_pausing_mid_placement_resets_spell_casters() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_resource::<MouseButtonState>()
+            .insert_state(AppState::InGame)
+            .add_sub_state::<InGameState>()
+            .add_systems(
+                OnExit(InGameState::Running),
+                (cancel_active_casts, reset_spell_casters),
+            );
+        app.update();
+
+        let circle = app.world_mut().spawn_empty().id();
+        let destination = app.world_mut().spawn_empty().id();
+        let mut teleport = TeleportCaster::new();
+        teleport.destination_circle = Some(destination);
+        teleport.destination_position = Some(Vec3::ZERO);
+        let wizard = app
+            .world_mut()
+            .spawn((
+                Wizard::new(DEFAULT_SPELL_RANGE),
+                CastingState::Casting { elapsed: 0.5 },
+                GuardianCircleCaster {
+                    circle_entity: Some(circle),
+                },
+                teleport,
+                AwaitingFingerOfDeathRelease,
+                FireballCharge::new(10.0),
+                QueuedSpell {
+                    spell: PRIMED_FIREBALL,
+                },
+            ))
+            .id();
+
+        set_in_game_state(&mut app, InGameState::Paused);
+        set_in_game_state(&mut app, InGameState::Running);
+
+        let world = app.world();
+        assert!(world.get_entity(circle).is_err());
+        assert!(world.get_entity(destination).is_err());
+        assert!(world.get::<GuardianCircleCaster>(wizard).is_none());
+        assert!(world.get::<AwaitingFingerOfDeathRelease>(wizard).is_none());
+        assert!(world.get::<FireballCharge>(wizard).is_none());
+        assert!(world.get::<QueuedSpell>(wizard).is_none());
+        assert!(
+            !world
+                .get::<TeleportCaster>(wizard)
+                .unwrap()
+                .has_destination()
+        );
+        assert!(matches!(
+            world.get::<CastingState>(wizard),
+            Some(CastingState::Resting)
+        ));
+    }
 }
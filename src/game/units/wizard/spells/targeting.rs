@@ -0,0 +1,238 @@
+//! Shared cursor targeting for click-to-target spells.
+//!
+//! Units are billboards standing above the battlefield, so projecting the cursor
+//! onto the Y=0 plane can land behind a tall unit the player clicked on. These
+//! helpers raycast the shared `CursorWorldPosition` ray against unit `Hitbox`
+//! cylinders first and fall back to the ground point.
+
+use bevy::prelude::*;
+
+use crate::game::input::components::CursorWorldPosition;
+use crate::game::units::components::{Corpse, Hitbox, Team};
+
+/// Living units a spell can target.
+///
+/// Filters on `Team` so the wizard, which has a hitbox but no team, is never
+/// picked when the cursor ray passes through it.
+pub type TargetableUnits<'w, 's> =
+    Query<'w, 's, (Entity, &'static Transform, &'static Hitbox), (With<Team>, Without<Corpse>)>;
+
+/// What the cursor is pointing at.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CursorTarget {
+    /// A unit whose hitbox the cursor ray hit, with its center position.
+    Unit(Entity, Vec3),
+    /// The point where the cursor ray meets the battlefield (Y=0 plane).
+    Ground(Vec3),
+}
+
+impl CursorTarget {
+    /// Returns the world position being targeted.
+    pub fn position(&self) -> Vec3 {
+        match self {
+            Self::Unit(_, position) | Self::Ground(position) => *position,
+        }
+    }
+}
+
+/// Resolves what the cursor is pointing at.
+///
+/// Returns the nearest targetable unit hit by the cursor ray, or the ground
+/// point if no unit is hit. Returns None if the cursor is outside the window or the ray
+/// never reaches the ground.
+pub fn cursor_target(
+    cursor: &CursorWorldPosition,
+    units: &TargetableUnits,
+) -> Option<CursorTarget> {
+    let ray = cursor.ray?;
+
+    if let Some((entity, position)) = raycast_units(ray, units.iter()) {
+        return Some(CursorTarget::Unit(entity, position));
+    }
+
+    cursor.ground.map(CursorTarget::Ground)
+}
+
+/// Finds the unit whose hitbox is hit first along the ray.
+fn raycast_units<'a>(
+    ray: Ray3d,
+    units: impl Iterator<Item = (Entity, &'a Transform, &'a Hitbox)>,
+) -> Option<(Entity, Vec3)> {
+    units
+        .filter_map(|(entity, transform, hitbox)| {
+            ray_cylinder_intersection(ray.origin, *ray.direction, transform.translation, hitbox)
+                .map(|t| (t, entity, transform.translation))
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, entity, position)| (entity, position))
+}
+
+/// Intersects a ray with an upright hitbox cylinder centered at `center`.
+///
+/// Returns the distance along the ray to the first hit in front of the origin,
+/// checking both the curved side and the top/bottom caps.
+fn ray_cylinder_intersection(
+    origin: Vec3,
+    direction: Vec3,
+    center: Vec3,
+    hitbox: &Hitbox,
+) -> Option<f32> {
+    let half_height = hitbox.height / 2.0;
+    let bottom = center.y - half_height;
+    let top = center.y + half_height;
+    let radius_squared = hitbox.radius * hitbox.radius;
+
+    let within_height = |t: f32| {
+        let y = origin.y + direction.y * t;
+        y >= bottom && y <= top
+    };
+    let within_radius = |t: f32| {
+        let x = origin.x + direction.x * t - center.x;
+        let z = origin.z + direction.z * t - center.z;
+        x * x + z * z <= radius_squared
+    };
+
+    let mut nearest: Option<f32> = None;
+    let mut consider = |t: f32| {
+        if t >= 0.0 && nearest.is_none_or(|best| t < best) {
+            nearest = Some(t);
+        }
+    };
+
+    // Curved side: solve |(origin + t * direction - center).xz|^2 = radius^2
+    let offset_x = origin.x - center.x;
+    let offset_z = origin.z - center.z;
+    let a = direction.x * direction.x + direction.z * direction.z;
+    if a > f32::EPSILON {
+        let b = 2.0 * (offset_x * direction.x + offset_z * direction.z);
+        let c = offset_x * offset_x + offset_z * offset_z - radius_squared;
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant >= 0.0 {
+            let sqrt_discriminant = discriminant.sqrt();
+            for t in [
+                (-b - sqrt_discriminant) / (2.0 * a),
+                (-b + sqrt_discriminant) / (2.0 * a),
+            ] {
+                if within_height(t) {
+                    consider(t);
+                }
+            }
+        }
+    }
+
+    // Caps: hit the top or bottom disc
+    if direction.y.abs() > f32::EPSILON {
+        for cap_y in [top, bottom] {
+            let t = (cap_y - origin.y) / direction.y;
+            if within_radius(t) {
+                consider(t);
+            }
+        }
+    }
+
+    nearest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::units::wizard::components::Wizard;
+
+    const HITBOX: Hitbox = Hitbox::new(10.0, 40.0);
+    const CENTER: Vec3 = Vec3::new(0.0, 21.0, 0.0);
+
+    #[test]
+    fn test_ray_hits_cylinder_side() {
+        let origin = Vec3::new(-100.0, 21.0, 0.0);
+        let t = ray_cylinder_intersection(origin, Vec3::X, CENTER, &HITBOX);
+        assert_eq!(t, Some(90.0));
+    }
+
+    #[test]
+    fn test_ray_hits_cylinder_top_cap() {
+        let origin = Vec3::new(0.0, 200.0, 0.0);
+        let t = ray_cylinder_intersection(origin, Vec3::NEG_Y, CENTER, &HITBOX);
+        assert_eq!(t, Some(159.0));
+    }
+
+    #[test]
+    fn test_ray_passes_over_cylinder() {
+        let origin = Vec3::new(-100.0, 100.0, 0.0);
+        let t = ray_cylinder_intersection(origin, Vec3::X, CENTER, &HITBOX);
+        assert_eq!(t, None);
+    }
+
+    #[test]
+    fn test_ray_misses_cylinder_to_the_side() {
+        let origin = Vec3::new(-100.0, 21.0, 20.0);
+        let t = ray_cylinder_intersection(origin, Vec3::X, CENTER, &HITBOX);
+        assert_eq!(t, None);
+    }
+
+    #[test]
+    fn test_ray_behind_origin_is_ignored() {
+        let origin = Vec3::new(100.0, 21.0, 0.0);
+        let t = ray_cylinder_intersection(origin, Vec3::X, CENTER, &HITBOX);
+        assert_eq!(t, None);
+    }
+
+    #[test]
+    fn test_downward_ray_hits_tall_unit_before_ground() {
+        // A camera-like ray that would reach the ground behind the unit
+        let origin = Vec3::new(-200.0, 200.0, 0.0);
+        let direction = Vec3::new(1.0, -0.9, 0.0).normalize();
+        let ground_t = -origin.y / direction.y;
+        let ground_point = origin + direction * ground_t;
+        assert!(ground_point.x > HITBOX.radius);
+
+        let t = ray_cylinder_intersection(origin, direction, CENTER, &HITBOX)
+            .expect("ray should hit the unit");
+        assert!(t < ground_t);
+        let hit = origin + direction * t;
+        assert!(hit.y >= CENTER.y - HITBOX.height / 2.0 - 1e-3);
+        assert!(hit.y <= CENTER.y + HITBOX.height / 2.0 + 1e-3);
+    }
+
+    #[derive(Resource, Default)]
+    struct Resolved(Option<CursorTarget>);
+
+    fn resolve_target(
+        cursor: Res<CursorWorldPosition>,
+        units: TargetableUnits,
+        mut resolved: ResMut<Resolved>,
+    ) {
+        resolved.0 = cursor_target(&cursor, &units);
+    }
+
+    #[test]
+    fn test_cursor_ray_through_wizard_targets_unit_behind() {
+        let mut app = App::new();
+        app.init_resource::<Resolved>()
+            .insert_resource(CursorWorldPosition::from_ray(Some(Ray3d::new(
+                Vec3::new(-100.0, 21.0, 0.0),
+                Dir3::X,
+            ))))
+            .add_systems(Update, resolve_target);
+
+        app.world_mut().spawn((
+            Wizard::new(100.0),
+            Transform::from_translation(CENTER),
+            HITBOX,
+        ));
+        let enemy = app
+            .world_mut()
+            .spawn((
+                Team::Attackers,
+                Transform::from_xyz(50.0, 21.0, 0.0),
+                HITBOX,
+            ))
+            .id();
+
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Resolved>().0,
+            Some(CursorTarget::Unit(enemy, Vec3::new(50.0, 21.0, 0.0)))
+        );
+    }
+}
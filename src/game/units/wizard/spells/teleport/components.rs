@@ -29,6 +29,16 @@ impl TeleportCaster {
     pub const fn has_destination(&self) -> bool {
         self.destination_position.is_some()
     }
+
+    /// Resets placement and returns the circle entities that need despawning.
+    pub fn clear(&mut self) -> Vec<Entity> {
+        self.destination_position = None;
+        self.destination_circle
+            .take()
+            .into_iter()
+            .chain(self.source_circle.take())
+            .collect()
+    }
 }
 
 /// Visual indicator for the destination circle (persists between casts).
@@ -79,3 +89,30 @@ impl TeleportSourceCircle {
         1.0 + (self.time_alive * pulse_freq * std::f32::consts::TAU).sin() * pulse_amplitude
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clear_mid_placement_returns_both_circles() {
+        let destination = Entity::from_raw_u32(1).unwrap();
+        let source = Entity::from_raw_u32(2).unwrap();
+        let mut caster = TeleportCaster {
+            destination_circle: Some(destination),
+            destination_position: Some(Vec3::new(100.0, 0.0, 50.0)),
+            source_circle: Some(source),
+        };
+
+        assert_eq!(caster.clear(), vec![destination, source]);
+        assert!(!caster.has_destination());
+        assert!(caster.destination_circle.is_none());
+        assert!(caster.source_circle.is_none());
+    }
+
+    #[test]
+    fn test_clear_without_placement_returns_nothing() {
+        let mut caster = TeleportCaster::new();
+        assert!(caster.clear().is_empty());
+    }
+}
@@ -20,6 +20,16 @@ pub const MANA_COST: f32 = 20.0;
 /// Radius of source circle (grows during channeling).
 pub const CIRCLE_RADIUS: f32 = 150.0;
 
+/// Random landing spots tried per unit before pushing it out of a wall instead.
+pub const MAX_DESTINATION_ATTEMPTS: usize = 5;
+
+/// Passes over the walls when pushing a landing spot out of them, so a push
+/// out of one wall into a neighboring wall is resolved as well.
+pub const MAX_WALL_PUSH_PASSES: usize = 8;
+
+/// Damage dealt to an attacker teleported into a Wall of Stone (kills standard units).
+pub const WALL_CRUSH_DAMAGE: f32 = 100.0;
+
 /// Radius of the destination crosshair (small marker).
 pub const CROSSHAIR_RADIUS: f32 = 30.0;
 
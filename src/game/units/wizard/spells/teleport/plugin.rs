@@ -12,6 +12,7 @@ use crate::state::InGameState;
 /// Registers systems for:
 /// - Two-phase casting (destination placement, then source placement)
 /// - Circle animations (pulsing effects)
+/// - Circle cleanup when the primed spell is cancelled
 /// - Unit teleportation
 pub struct TeleportPlugin;
 
@@ -28,6 +29,7 @@ impl Plugin for TeleportPlugin {
                     .run_if(mouse_right_not_held)
                     .run_if(mouse_held_or_wizard_casting),
                 systems::update_circle_animations,
+                systems::cancel_teleport_on_spell_cancelled,
             )
                 .run_if(in_state(InGameState::Running)),
         );
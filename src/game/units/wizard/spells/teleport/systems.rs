@@ -1,17 +1,43 @@
 //! Systems for the Teleport spell.
 
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
 use rand::Rng;
 
-use super::super::super::components::{CastingState, Mana, PrimedSpell, Wizard};
+use super::super::super::components::{
+    CancelSpellMessage, CastingState, Mana, PrimedSpell, Spell, SpellCastRecord, Wizard,
+};
+use super::super::circle_placement::{CircleIndicator, move_circle_indicator, place_circle};
 use super::components::{TeleportCaster, TeleportDestinationCircle, TeleportSourceCircle};
 use super::constants::*;
-use crate::game::components::OnGameplayScreen;
-use crate::game::constants::BATTLEFIELD_SIZE;
+use crate::game::battlefield::components::BattlefieldConfig;
 use crate::game::input::MouseButtonState;
+use crate::game::input::components::CursorWorldPosition;
 use crate::game::input::events::{MouseLeftReleased, MouseRightPressed};
-use crate::game::units::components::Teleportable;
+use crate::game::units::components::{
+    DamageDealt, DamageSource, DamageType, Health, Hitbox, Resistances, Team, Teleportable,
+    TemporaryHitPoints, apply_damage_and_report,
+};
+use crate::game::units::wizard::spells::wall_of_stone::components::WallOfStone;
+
+/// Units a teleport can move, with the health needed to crush them into walls.
+type TeleportUnits<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        Option<&'static Hitbox>,
+        Option<&'static Team>,
+        Option<&'static mut Health>,
+        Option<&'static mut TemporaryHitPoints>,
+        Option<&'static Resistances>,
+    ),
+    (
+        With<Teleportable>,
+        Without<TeleportDestinationCircle>,
+        Without<TeleportSourceCircle>,
+    ),
+>;
 
 /// Handles right-click to cancel/reset the teleport spell.
 ///
@@ -41,22 +67,31 @@ pub fn handle_teleport_cancel(
         return;
     };
 
-    // Despawn any active circles
-    if let Some(dest_entity) = caster.destination_circle {
-        commands.entity(dest_entity).despawn();
-    }
-    if let Some(source_entity) = caster.source_circle {
-        commands.entity(source_entity).despawn();
+    // Despawn any active circles and reset all state
+    for circle in caster.clear() {
+        commands.entity(circle).despawn();
     }
-
-    // Reset all state
-    caster.destination_circle = None;
-    caster.destination_position = None;
-    caster.source_circle = None;
     casting_state.cancel();
     mouse_state.left_consumed = true; // Prevent immediate restart if left button still held
 }
 
+/// Despawns both placement circles when the primed spell is cancelled.
+pub fn cancel_teleport_on_spell_cancelled(
+    mut cancel_spell: MessageReader<CancelSpellMessage>,
+    mut commands: Commands,
+    mut caster_query: Query<&mut TeleportCaster, With<Wizard>>,
+) {
+    if cancel_spell.read().next().is_none() {
+        return;
+    }
+
+    if let Ok(mut caster) = caster_query.single_mut() {
+        for circle in caster.clear() {
+            commands.entity(circle).despawn();
+        }
+    }
+}
+
 /// Handles Teleport spell casting with two phases.
 ///
 /// Phase 1: Place destination circle (1 second cast)
@@ -66,6 +101,7 @@ pub fn handle_teleport_cancel(
 #[allow(clippy::too_many_arguments)]
 pub fn handle_teleport_casting(
     time: Res<Time>,
+    battlefield: Res<BattlefieldConfig>,
     mut mouse_state: ResMut<MouseButtonState>,
     mut mouse_left_released: MessageReader<MouseLeftReleased>,
     mut commands: Commands,
@@ -86,8 +122,7 @@ pub fn handle_teleport_casting(
             Without<TeleportSourceCircle>,
         ),
     >,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    cursor: Res<CursorWorldPosition>,
     mut caster_query: Query<&mut TeleportCaster, With<Wizard>>,
     mut destination_query: Query<
         (&mut Transform, &mut TeleportDestinationCircle),
@@ -103,14 +138,10 @@ pub fn handle_teleport_casting(
             Without<TeleportDestinationCircle>,
         ),
     >,
-    units_query: Query<
-        (Entity, &Transform),
-        (
-            With<Teleportable>,
-            Without<TeleportDestinationCircle>,
-            Without<TeleportSourceCircle>,
-        ),
-    >,
+    mut units_query: TeleportUnits,
+    walls: Query<&WallOfStone>,
+    mut records: MessageWriter<SpellCastRecord>,
+    mut damage_dealt: MessageWriter<DamageDealt>,
 ) {
     let Ok((wizard_entity, wizard_transform, wizard, mut casting_state, mut mana, _)) =
         wizard_query.single_mut()
@@ -140,10 +171,9 @@ pub fn handle_teleport_casting(
         && !caster.has_destination()
         && matches!(*casting_state, CastingState::Casting { .. })
     {
-        if let Some(cursor_world_pos) = get_cursor_world_position(&camera_query, &window_query) {
+        if let Some(cursor_world_pos) = cursor.ground {
             let wizard_pos = wizard_transform.translation;
-            let clamped_pos =
-                clamp_to_spell_range(cursor_world_pos, wizard_pos, wizard.spell_range);
+            let clamped_pos = place_circle(cursor_world_pos, wizard_pos, wizard.spell_range);
 
             caster.destination_position = Some(clamped_pos);
             casting_state.cancel(); // Return to resting for phase 2
@@ -170,13 +200,21 @@ pub fn handle_teleport_casting(
                     mana.consume(MANA_COST);
 
                     if let Some(dest_pos) = caster.destination_position {
-                        teleport_units_with_radius(
+                        let teleported = teleport_units_with_radius(
                             source_pos,
                             dest_pos,
                             current_radius,
-                            &units_query,
+                            &battlefield,
+                            &mut units_query,
+                            &walls,
                             &mut commands,
+                            &mut damage_dealt,
                         );
+                        records.write(SpellCastRecord {
+                            spell: Spell::Teleport,
+                            mana_cost: MANA_COST,
+                            affected: teleported,
+                        });
                     }
 
                     // Cleanup
@@ -198,13 +236,13 @@ pub fn handle_teleport_casting(
     }
 
     // Get cursor world position
-    let Some(cursor_world_pos) = get_cursor_world_position(&camera_query, &window_query) else {
+    let Some(cursor_world_pos) = cursor.ground else {
         return;
     };
 
     // Clamp to spell range
     let wizard_pos = wizard_transform.translation;
-    let clamped_pos = clamp_to_spell_range(cursor_world_pos, wizard_pos, wizard.spell_range);
+    let clamped_pos = place_circle(cursor_world_pos, wizard_pos, wizard.spell_range);
 
     // State machine based on whether destination exists
     if !caster.has_destination() {
@@ -222,6 +260,7 @@ pub fn handle_teleport_casting(
         // PHASE 2: Placing source circle and teleporting
         handle_second_cast(
             &time,
+            &battlefield,
             &mut casting_state,
             &mut mouse_state,
             &mut mana,
@@ -231,7 +270,10 @@ pub fn handle_teleport_casting(
             &mut materials,
             &mut source_query,
             clamped_pos,
-            &units_query,
+            &mut units_query,
+            &walls,
+            &mut records,
+            &mut damage_dealt,
         );
     }
 }
@@ -255,23 +297,18 @@ fn handle_first_cast(
     match *casting_state {
         CastingState::Resting => {
             // Start showing crosshair on mouse down
-            let crosshair_mesh = meshes.add(Circle::new(CROSSHAIR_RADIUS));
-            let crosshair_material = materials.add(StandardMaterial {
-                base_color: DESTINATION_COLOR,
-                unlit: true,
-                ..default()
-            });
-
-            let crosshair_entity = commands
-                .spawn((
-                    Mesh3d(crosshair_mesh),
-                    MeshMaterial3d(crosshair_material),
-                    Transform::from_xyz(position.x, 1.0, position.z)
-                        .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
-                    TeleportDestinationCircle::new(),
-                    OnGameplayScreen,
-                ))
-                .id();
+            let crosshair_entity = CircleIndicator {
+                radius: CROSSHAIR_RADIUS,
+                color: DESTINATION_COLOR,
+                alpha_mode: AlphaMode::Opaque,
+            }
+            .spawn(
+                commands,
+                meshes,
+                materials,
+                Transform::from_xyz(position.x, 1.0, position.z),
+                TeleportDestinationCircle::new(),
+            );
 
             caster.destination_circle = Some(crosshair_entity);
             casting_state.start_cast(); // Enter casting state to track mouse movement
@@ -281,8 +318,7 @@ fn handle_first_cast(
             if let Some(circle_entity) = caster.destination_circle
                 && let Ok((mut transform, _)) = destination_query.get_mut(circle_entity)
             {
-                transform.translation.x = position.x;
-                transform.translation.z = position.z;
+                move_circle_indicator(&mut transform, position);
             }
         }
         CastingState::Channeling { .. } => {
@@ -295,6 +331,7 @@ fn handle_first_cast(
 #[allow(clippy::too_many_arguments)]
 fn handle_second_cast(
     time: &Res<Time>,
+    battlefield: &BattlefieldConfig,
     casting_state: &mut CastingState,
     mouse_state: &mut ResMut<MouseButtonState>,
     mana: &mut Mana,
@@ -310,14 +347,10 @@ fn handle_second_cast(
         ),
     >,
     position: Vec3,
-    units_query: &Query<
-        (Entity, &Transform),
-        (
-            With<Teleportable>,
-            Without<TeleportDestinationCircle>,
-            Without<TeleportSourceCircle>,
-        ),
-    >,
+    units_query: &mut TeleportUnits,
+    walls: &Query<&WallOfStone>,
+    records: &mut MessageWriter<SpellCastRecord>,
+    damage_dealt: &mut MessageWriter<DamageDealt>,
 ) {
     match *casting_state {
         CastingState::Resting => {
@@ -330,24 +363,19 @@ fn handle_second_cast(
             casting_state.start_cast();
 
             // Spawn source circle
-            let circle_mesh = meshes.add(Circle::new(CIRCLE_RADIUS));
-            let circle_material = materials.add(StandardMaterial {
-                base_color: SOURCE_COLOR,
-                unlit: true,
-                ..default()
-            });
-
-            let circle_entity = commands
-                .spawn((
-                    Mesh3d(circle_mesh),
-                    MeshMaterial3d(circle_material),
-                    Transform::from_xyz(position.x, 1.0, position.z)
-                        .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
-                        .with_scale(Vec3::ZERO), // Start at zero size
-                    TeleportSourceCircle::new(position),
-                    OnGameplayScreen,
-                ))
-                .id();
+            let circle_entity = CircleIndicator {
+                radius: CIRCLE_RADIUS,
+                color: SOURCE_COLOR,
+                alpha_mode: AlphaMode::Opaque,
+            }
+            .spawn(
+                commands,
+                meshes,
+                materials,
+                // Start at zero size
+                Transform::from_xyz(position.x, 1.0, position.z).with_scale(Vec3::ZERO),
+                TeleportSourceCircle::new(position),
+            );
 
             caster.source_circle = Some(circle_entity);
         }
@@ -359,8 +387,7 @@ fn handle_second_cast(
             if let Some(circle_entity) = caster.source_circle
                 && let Ok((mut transform, mut indicator)) = source_query.get_mut(circle_entity)
             {
-                transform.translation.x = position.x;
-                transform.translation.z = position.z;
+                move_circle_indicator(&mut transform, position);
 
                 // Grow circle from 0 to full radius
                 let growth = (*elapsed / SECOND_CAST_TIME).min(1.0);
@@ -377,7 +404,20 @@ fn handle_second_cast(
 
                 // Execute teleportation
                 if let Some(dest_pos) = caster.destination_position {
-                    teleport_units(position, dest_pos, units_query, commands);
+                    let teleported = teleport_units(
+                        position,
+                        dest_pos,
+                        battlefield,
+                        units_query,
+                        walls,
+                        commands,
+                        damage_dealt,
+                    );
+                    records.write(SpellCastRecord {
+                        spell: Spell::Teleport,
+                        mana_cost: MANA_COST,
+                        affected: teleported,
+                    });
                 }
 
                 // Despawn both circles
@@ -402,76 +442,202 @@ fn handle_second_cast(
 }
 
 /// Teleports all units within the source circle to random positions within the destination circle.
+///
+/// Returns the number of units moved.
+#[allow(clippy::too_many_arguments)]
 fn teleport_units(
     source_center: Vec3,
     dest_center: Vec3,
-    units_query: &Query<
-        (Entity, &Transform),
-        (
-            With<Teleportable>,
-            Without<TeleportDestinationCircle>,
-            Without<TeleportSourceCircle>,
-        ),
-    >,
+    battlefield: &BattlefieldConfig,
+    units_query: &mut TeleportUnits,
+    walls: &Query<&WallOfStone>,
     commands: &mut Commands,
-) {
+    damage_dealt: &mut MessageWriter<DamageDealt>,
+) -> u32 {
     teleport_units_with_radius(
         source_center,
         dest_center,
         CIRCLE_RADIUS,
+        battlefield,
         units_query,
+        walls,
         commands,
-    );
+        damage_dealt,
+    )
 }
 
 /// Teleports all units within a specified radius of the source center to random positions
-/// within the same radius of the destination center.
+/// within the same radius of the destination center. Returns the number of units moved.
+///
+/// Friendly landing spots avoid walls (see `pick_destination`). Attackers and
+/// undead, who are hostile to everyone, land where they roll, and any that
+/// materialize inside a wall are pushed out and take `WALL_CRUSH_DAMAGE` (see
+/// `pick_enemy_destination`).
+#[allow(clippy::too_many_arguments)]
 fn teleport_units_with_radius(
     source_center: Vec3,
     dest_center: Vec3,
     radius: f32,
-    units_query: &Query<
-        (Entity, &Transform),
-        (
-            With<Teleportable>,
-            Without<TeleportDestinationCircle>,
-            Without<TeleportSourceCircle>,
-        ),
-    >,
+    battlefield: &BattlefieldConfig,
+    units_query: &mut TeleportUnits,
+    walls: &Query<&WallOfStone>,
     commands: &mut Commands,
-) {
+    damage_dealt: &mut MessageWriter<DamageDealt>,
+) -> u32 {
     let mut rng = rand::thread_rng();
+    let walls: Vec<&WallOfStone> = walls.iter().collect();
+    let mut teleported = 0;
 
-    for (entity, transform) in units_query.iter() {
+    for (entity, transform, hitbox, team, health, mut temp_hp, resistances) in
+        units_query.iter_mut()
+    {
         // Check if unit is within source circle (XZ distance only)
         let diff_x = transform.translation.x - source_center.x;
         let diff_z = transform.translation.z - source_center.z;
         let distance = (diff_x * diff_x + diff_z * diff_z).sqrt();
 
         if distance <= radius {
-            // Generate random position within destination circle
-            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-            let random_radius = rng.gen_range(0.0..radius);
-
-            let offset_x = angle.cos() * random_radius;
-            let offset_z = angle.sin() * random_radius;
-
-            let new_x = dest_center.x + offset_x;
-            let new_z = dest_center.z + offset_z;
-
-            // Clamp to battlefield bounds
-            let clamped_x = new_x.clamp(-BATTLEFIELD_SIZE / 2.0, BATTLEFIELD_SIZE / 2.0);
-            let clamped_z = new_z.clamp(-BATTLEFIELD_SIZE / 2.0, BATTLEFIELD_SIZE / 2.0);
+            let unit_radius = hitbox.map_or(0.0, |hitbox| hitbox.radius);
+            let ((new_x, new_z), crushed) = if matches!(team, Some(Team::Attackers | Team::Undead))
+            {
+                pick_enemy_destination(
+                    &mut rng,
+                    dest_center,
+                    radius,
+                    unit_radius,
+                    battlefield,
+                    &walls,
+                )
+            } else {
+                let destination = pick_destination(
+                    &mut rng,
+                    dest_center,
+                    radius,
+                    unit_radius,
+                    battlefield,
+                    &walls,
+                );
+                (destination, false)
+            };
 
             // Keep original Y position and rotation
-            let new_position = Vec3::new(clamped_x, transform.translation.y, clamped_z);
+            let new_position = Vec3::new(new_x, transform.translation.y, new_z);
 
             let mut new_transform = *transform;
             new_transform.translation = new_position;
 
             commands.entity(entity).insert(new_transform);
+            teleported += 1;
+
+            if crushed && let Some(mut health) = health {
+                apply_damage_and_report(
+                    &mut health,
+                    temp_hp.as_deref_mut(),
+                    resistances,
+                    WALL_CRUSH_DAMAGE,
+                    DamageType::Physical,
+                    DamageSource::Spell(Spell::Teleport),
+                    entity,
+                    new_position,
+                    damage_dealt,
+                );
+            }
         }
     }
+    teleported
+}
+
+/// Picks a random landing spot within `radius` of `dest_center`, clamped to the battlefield.
+///
+/// Spots that would overlap a wall are resampled up to `MAX_DESTINATION_ATTEMPTS`
+/// times. If every sample hits a wall, the last one is pushed out to the nearest
+/// wall edge so wall collision doesn't shove the unit out on the next frame.
+fn pick_destination(
+    rng: &mut impl Rng,
+    dest_center: Vec3,
+    radius: f32,
+    unit_radius: f32,
+    battlefield: &BattlefieldConfig,
+    walls: &[&WallOfStone],
+) -> (f32, f32) {
+    let overlaps_wall = |x: f32, z: f32| {
+        walls
+            .iter()
+            .any(|wall| wall.push_out(Vec3::new(x, 0.0, z), unit_radius).is_some())
+    };
+
+    let mut sample = (dest_center.x, dest_center.z);
+    for _ in 0..MAX_DESTINATION_ATTEMPTS {
+        sample = random_spot(rng, dest_center, radius, battlefield);
+        if !overlaps_wall(sample.0, sample.1) {
+            return sample;
+        }
+    }
+
+    let (position, _) = push_out_of_walls(sample, unit_radius, walls);
+    battlefield.clamp(position.x, position.z)
+}
+
+/// Picks a landing spot for a hostile unit, without the care taken for friendly units.
+///
+/// The first random spot is used as rolled. If it overlaps a wall, the unit is
+/// pushed out to the nearest wall edge and `true` is returned so the caller
+/// can crush it for materializing inside solid stone.
+fn pick_enemy_destination(
+    rng: &mut impl Rng,
+    dest_center: Vec3,
+    radius: f32,
+    unit_radius: f32,
+    battlefield: &BattlefieldConfig,
+    walls: &[&WallOfStone],
+) -> ((f32, f32), bool) {
+    let sample = random_spot(rng, dest_center, radius, battlefield);
+    let (position, crushed) = push_out_of_walls(sample, unit_radius, walls);
+    (battlefield.clamp(position.x, position.z), crushed)
+}
+
+/// Returns a random spot within `radius` of `dest_center`, clamped to the battlefield.
+fn random_spot(
+    rng: &mut impl Rng,
+    dest_center: Vec3,
+    radius: f32,
+    battlefield: &BattlefieldConfig,
+) -> (f32, f32) {
+    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+    let random_radius = if radius > 0.0 {
+        rng.gen_range(0.0..radius)
+    } else {
+        0.0
+    };
+
+    battlefield.clamp(
+        dest_center.x + angle.cos() * random_radius,
+        dest_center.z + angle.sin() * random_radius,
+    )
+}
+
+/// Pushes a ground spot out of every wall it overlaps.
+///
+/// Pushing out of one wall can land the spot in a wall next to it, so the
+/// walls are passed over until none overlaps (up to `MAX_WALL_PUSH_PASSES`).
+/// Returns the new position and whether any wall had to push it.
+fn push_out_of_walls((x, z): (f32, f32), unit_radius: f32, walls: &[&WallOfStone]) -> (Vec3, bool) {
+    let mut position = Vec3::new(x, 0.0, z);
+    let mut pushed_out = false;
+    for _ in 0..MAX_WALL_PUSH_PASSES {
+        let mut pushed_this_pass = false;
+        for wall in walls {
+            if let Some(pushed) = wall.push_out(position, unit_radius) {
+                position = pushed;
+                pushed_this_pass = true;
+            }
+        }
+        if !pushed_this_pass {
+            break;
+        }
+        pushed_out = true;
+    }
+    (position, pushed_out)
 }
 
 /// Updates pulse animations for both destination and source circles.
@@ -506,35 +672,203 @@ pub fn update_circle_animations(
     }
 }
 
-/// Gets cursor position projected onto Y=0 plane.
-fn get_cursor_world_position(
-    camera_query: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: &Query<&Window, With<PrimaryWindow>>,
-) -> Option<Vec3> {
-    let (camera, camera_transform) = camera_query.single().ok()?;
-    let window = window_query.single().ok()?;
-    let cursor_pos = window.cursor_position()?;
-
-    let ray = camera
-        .viewport_to_world(camera_transform, cursor_pos)
-        .ok()?;
-    let t = -ray.origin.y / ray.direction.y;
-
-    if t > 0.0 {
-        Some(ray.origin + ray.direction * t)
-    } else {
-        None
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    fn wall(center: Vec3, half_length: f32, half_width: f32) -> WallOfStone {
+        WallOfStone {
+            center,
+            half_length,
+            half_width,
+            forward: Vec3::X,
+            right: Vec3::Z,
+            height: 100.0,
+            time_alive: 0.0,
+            duration: 10.0,
+            sinking: false,
+        }
     }
-}
 
-/// Clamps a position to be within the wizard's spell range.
-fn clamp_to_spell_range(target: Vec3, wizard_pos: Vec3, spell_range: f32) -> Vec3 {
-    let diff = target - wizard_pos;
-    let distance = diff.length();
+    #[test]
+    fn test_teleported_units_never_land_inside_walls() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let battlefield = BattlefieldConfig::default();
+        let dest_center = battlefield.center();
+        let unit_radius = 10.0;
+
+        // One wall across the middle of the circle, one covering all of it
+        let partial = wall(dest_center, CIRCLE_RADIUS, 40.0);
+        let covering = wall(dest_center, CIRCLE_RADIUS * 2.0, CIRCLE_RADIUS * 2.0);
+
+        for walls in [vec![&partial], vec![&covering]] {
+            for _ in 0..200 {
+                let (x, z) = pick_destination(
+                    &mut rng,
+                    dest_center,
+                    CIRCLE_RADIUS,
+                    unit_radius,
+                    &battlefield,
+                    &walls,
+                );
+                let landing = Vec3::new(x, 0.0, z);
+                for wall in &walls {
+                    assert!(
+                        wall.push_out(landing, unit_radius).is_none(),
+                        "unit landed inside a wall at {landing}"
+                    );
+                }
+            }
+        }
+    }
 
-    if distance > spell_range {
-        wizard_pos + diff.normalize() * spell_range
-    } else {
-        target
+    #[test]
+    fn test_push_out_clears_both_walls_at_a_corner() {
+        // The first wall's top edge is the second wall's bottom edge
+        let first = wall(Vec3::new(0.0, 0.0, -10.0), 50.0, 20.0);
+        let second = wall(Vec3::new(45.0, 0.0, 20.0), 20.0, 10.0);
+        let walls = [&first, &second];
+        let unit_radius = 5.0;
+
+        // Out of the first wall into the second, then back into the first
+        let (position, pushed_out) = push_out_of_walls((52.0, 12.0), unit_radius, &walls);
+
+        assert!(pushed_out);
+        for wall in walls {
+            assert!(
+                wall.push_out(position, unit_radius).is_none(),
+                "unit left inside a wall at {position}"
+            );
+        }
+    }
+
+    /// Offset of the source circle from the battlefield center, where units are teleported.
+    const SOURCE_OFFSET: Vec3 = Vec3::new(600.0, 0.0, 0.0);
+
+    /// Teleports everything in the source circle to the battlefield center.
+    fn teleport_to_center(
+        mut commands: Commands,
+        battlefield: Res<BattlefieldConfig>,
+        mut units: TeleportUnits,
+        walls: Query<&WallOfStone>,
+        mut damage_dealt: MessageWriter<DamageDealt>,
+    ) {
+        teleport_units_with_radius(
+            battlefield.center() + SOURCE_OFFSET,
+            battlefield.center(),
+            CIRCLE_RADIUS,
+            &battlefield,
+            &mut units,
+            &walls,
+            &mut commands,
+            &mut damage_dealt,
+        );
+    }
+
+    #[test]
+    fn test_hostile_units_teleported_into_wall_are_crushed() {
+        let battlefield = BattlefieldConfig::default();
+        let source = battlefield.center() + SOURCE_OFFSET;
+        let dest = battlefield.center();
+
+        let mut app = App::new();
+        app.insert_resource(battlefield)
+            .add_message::<DamageDealt>()
+            .add_systems(Update, teleport_to_center);
+
+        // The wall covers the whole destination circle
+        app.world_mut()
+            .spawn(wall(dest, CIRCLE_RADIUS * 2.0, CIRCLE_RADIUS * 2.0));
+        let spawn_unit = |app: &mut App, team: Team| {
+            app.world_mut()
+                .spawn((
+                    team,
+                    Transform::from_translation(source),
+                    Hitbox::new(8.0, 20.0),
+                    Health::new(500.0),
+                    Teleportable,
+                ))
+                .id()
+        };
+        let attacker = spawn_unit(&mut app, Team::Attackers);
+        let undead = spawn_unit(&mut app, Team::Undead);
+        let defender = spawn_unit(&mut app, Team::Defenders);
+
+        app.update();
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<Health>(attacker).unwrap().current,
+            500.0 - WALL_CRUSH_DAMAGE
+        );
+        // Undead are hostile to everyone, so they're crushed too
+        assert_eq!(
+            world.get::<Health>(undead).unwrap().current,
+            500.0 - WALL_CRUSH_DAMAGE
+        );
+        // Friendly units are still steered clear of the wall
+        assert_eq!(world.get::<Health>(defender).unwrap().current, 500.0);
+        assert!(world.get::<Transform>(attacker).unwrap().translation != source);
+    }
+
+    #[test]
+    fn test_cancel_mid_teleport_clears_circles_without_spending_mana() {
+        use crate::game::units::wizard::systems::handle_cancel_spell_messages;
+
+        let mut app = App::new();
+        app.init_resource::<MouseButtonState>()
+            .add_message::<CancelSpellMessage>()
+            .add_systems(
+                Update,
+                (
+                    handle_cancel_spell_messages,
+                    cancel_teleport_on_spell_cancelled,
+                ),
+            );
+
+        // Destination placed, source circle growing in the second cast
+        let world = app.world_mut();
+        let destination = world
+            .spawn(TeleportDestinationCircle { time_alive: 1.0 })
+            .id();
+        let source = world
+            .spawn(TeleportSourceCircle {
+                position: Vec3::ZERO,
+                time_alive: 0.5,
+            })
+            .id();
+        let wizard = world
+            .spawn((
+                Wizard::new(1000.0),
+                CastingState::Casting { elapsed: 0.5 },
+                Mana::new(100.0, 0.0),
+                TeleportCaster {
+                    destination_circle: Some(destination),
+                    destination_position: Some(Vec3::new(500.0, 0.0, 0.0)),
+                    source_circle: Some(source),
+                },
+            ))
+            .id();
+
+        world.write_message(CancelSpellMessage);
+        app.update();
+
+        let world = app.world();
+        assert!(world.get_entity(destination).is_err());
+        assert!(world.get_entity(source).is_err());
+        assert!(
+            !world
+                .get::<TeleportCaster>(wizard)
+                .unwrap()
+                .has_destination()
+        );
+        assert_eq!(
+            *world.get::<CastingState>(wizard).unwrap(),
+            CastingState::Resting
+        );
+        assert_eq!(world.get::<Mana>(wizard).unwrap().current, 100.0);
     }
 }
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+
+/// Leaves a trail of fading quads behind a moving entity.
+///
+/// Every `spawn_interval` seconds a `TrailSegment` is spawned at the entity's
+/// position. Segments live on their own, so the trail outlasts the projectile.
+#[derive(Component)]
+pub struct Trail {
+    /// Color of the trail segments (faded out over their lifetime).
+    pub color: Color,
+    /// How long each segment lives (in seconds).
+    pub lifetime: f32,
+    /// Time between segment spawns (in seconds).
+    pub spawn_interval: f32,
+    /// Width and height of each segment quad.
+    pub size: f32,
+    /// Time accumulated since the last segment was spawned.
+    pub timer: f32,
+}
+
+impl Trail {
+    /// Creates a new trail.
+    pub const fn new(color: Color, lifetime: f32, spawn_interval: f32, size: f32) -> Self {
+        Self {
+            color,
+            lifetime,
+            spawn_interval,
+            size,
+            timer: 0.0,
+        }
+    }
+
+    /// Advances the spawn timer. Returns true when a segment should be spawned.
+    ///
+    /// At most one segment is spawned per frame, so a long frame doesn't dump a
+    /// burst of segments at the same position.
+    pub fn tick(&mut self, delta: f32) -> bool {
+        self.timer += delta;
+        if self.timer >= self.spawn_interval {
+            self.timer = 0.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A single fading quad left behind by a `Trail`.
+#[derive(Component)]
+pub struct TrailSegment {
+    /// Color at full opacity.
+    pub color: Color,
+    /// Total lifetime of the segment (in seconds).
+    pub lifetime: f32,
+    /// Time since the segment was spawned (in seconds).
+    pub age: f32,
+}
+
+impl TrailSegment {
+    /// Creates a new segment.
+    pub const fn new(color: Color, lifetime: f32) -> Self {
+        Self {
+            color,
+            lifetime,
+            age: 0.0,
+        }
+    }
+
+    /// Returns the segment's current opacity multiplier (1.0 when new, 0.0 when expired).
+    pub fn opacity(&self) -> f32 {
+        (1.0 - self.age / self.lifetime).clamp(0.0, 1.0)
+    }
+
+    /// Returns true once the segment has outlived its lifetime.
+    pub fn is_expired(&self) -> bool {
+        self.age >= self.lifetime
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trail_spawns_each_interval() {
+        let mut trail = Trail::new(Color::WHITE, 0.3, 0.05, 4.0);
+        assert!(!trail.tick(0.03));
+        assert!(trail.tick(0.03));
+        assert!(!trail.tick(0.03));
+    }
+
+    #[test]
+    fn test_trail_spawns_once_per_long_frame() {
+        let mut trail = Trail::new(Color::WHITE, 0.3, 0.05, 4.0);
+        assert!(trail.tick(0.5));
+        assert!(!trail.tick(0.01));
+    }
+
+    #[test]
+    fn test_segment_fades_over_lifetime() {
+        let mut segment = TrailSegment::new(Color::WHITE, 0.4);
+        assert_eq!(segment.opacity(), 1.0);
+
+        segment.age = 0.2;
+        assert!((segment.opacity() - 0.5).abs() < 1e-6);
+        assert!(!segment.is_expired());
+
+        segment.age = 0.5;
+        assert_eq!(segment.opacity(), 0.0);
+        assert!(segment.is_expired());
+    }
+}
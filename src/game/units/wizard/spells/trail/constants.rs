@@ -0,0 +1,4 @@
+//! Spell trail constants.
+
+/// Maximum number of trail segments alive at once across all projectiles.
+pub const MAX_TRAIL_SEGMENTS: usize = 300;
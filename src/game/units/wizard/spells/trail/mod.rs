@@ -0,0 +1,11 @@
+//! Spell trail module.
+//!
+//! Projectiles with a `Trail` leave short-lived fading quads behind them.
+//! Segments are separate entities, so a trail lingers briefly after impact.
+
+pub mod components;
+mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::TrailPlugin;
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use crate::state::InGameState;
+
+use super::systems;
+
+/// Plugin that draws fading trails behind projectiles.
+///
+/// Registers systems for:
+/// - Spawning trail segments behind entities with a `Trail`
+/// - Fading and despawning trail segments
+pub struct TrailPlugin;
+
+impl Plugin for TrailPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                systems::spawn_trail_segments,
+                systems::update_trail_segments,
+            )
+                .chain()
+                .run_if(in_state(InGameState::Running)),
+        );
+    }
+}
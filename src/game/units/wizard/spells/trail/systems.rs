@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::MAX_TRAIL_SEGMENTS;
+use crate::config::GameConfig;
+use crate::game::components::{Billboard, OnGameplayScreen};
+
+/// Spawns trail segments behind every entity with a `Trail`.
+///
+/// Skipped entirely when spell trails are turned off in the settings, and
+/// capped at `MAX_TRAIL_SEGMENTS` (scaled down by the graphics quality's
+/// effects density) so heavy spell spam stays cheap.
+pub fn spawn_trail_segments(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut trails: Query<(&Transform, &mut Trail)>,
+    segments: Query<(), With<TrailSegment>>,
+) {
+    if !config.show_spell_trails {
+        return;
+    }
+
+    let max_segments =
+        (MAX_TRAIL_SEGMENTS as f32 * config.graphics_quality.effects_density()) as usize;
+    let mut segment_count = segments.iter().count();
+
+    for (transform, mut trail) in &mut trails {
+        if !trail.tick(time.delta_secs()) || segment_count >= max_segments {
+            continue;
+        }
+
+        commands.spawn((
+            Mesh3d(meshes.add(Rectangle::new(trail.size, trail.size))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: trail.color,
+                unlit: true,
+                alpha_mode: AlphaMode::Blend,
+                ..default()
+            })),
+            Transform::from_translation(transform.translation),
+            TrailSegment::new(trail.color, trail.lifetime),
+            Billboard,
+            OnGameplayScreen,
+        ));
+        segment_count += 1;
+    }
+}
+
+/// Fades trail segments out over their lifetime and despawns expired ones.
+pub fn update_trail_segments(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut segments: Query<(Entity, &mut TrailSegment, &MeshMaterial3d<StandardMaterial>)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for (entity, mut segment, material_handle) in &mut segments {
+        segment.age += time.delta_secs();
+
+        if segment.is_expired() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if let Some(material) = materials.get_mut(&material_handle.0) {
+            material.base_color = segment
+                .color
+                .with_alpha(segment.color.alpha() * segment.opacity());
+        }
+    }
+}
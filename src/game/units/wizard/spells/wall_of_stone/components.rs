@@ -1,5 +1,10 @@
+use std::collections::VecDeque;
+
 use bevy::prelude::*;
 
+use super::constants::MAX_ACTIVE_WALLS;
+use crate::game::units::components::push_out_of_rectangle;
+
 /// Active wall entity that blocks movement and projectiles.
 #[derive(Component)]
 pub struct WallOfStone {
@@ -66,33 +71,15 @@ impl WallOfStone {
     /// Pushes a point outside the wall along the nearest edge normal.
     /// Returns the corrected position if the point was inside.
     pub fn push_out(&self, point: Vec3, radius: f32) -> Option<Vec3> {
-        let diff = Vec3::new(point.x - self.center.x, 0.0, point.z - self.center.z);
-        let forward_proj = diff.dot(self.forward);
-        let right_proj = diff.dot(self.right);
-
-        let forward_pen = self.half_length + radius - forward_proj.abs();
-        let right_pen = self.half_width + radius - right_proj.abs();
-
-        if forward_pen <= 0.0 || right_pen <= 0.0 {
-            return None; // Not overlapping
-        }
-
-        // Push along axis with least penetration
-        if forward_pen < right_pen {
-            let sign = forward_proj.signum();
-            Some(Vec3::new(
-                point.x + self.forward.x * forward_pen * sign,
-                point.y,
-                point.z + self.forward.z * forward_pen * sign,
-            ))
-        } else {
-            let sign = right_proj.signum();
-            Some(Vec3::new(
-                point.x + self.right.x * right_pen * sign,
-                point.y,
-                point.z + self.right.z * right_pen * sign,
-            ))
-        }
+        push_out_of_rectangle(
+            point,
+            radius,
+            self.center,
+            self.forward,
+            self.right,
+            self.half_length,
+            self.half_width,
+        )
     }
 
     fn slab_intersect(origin: f32, dir: f32, half_extent: f32) -> Option<(f32, f32)> {
@@ -130,3 +117,102 @@ impl WallOfStoneCaster {
 /// Marker component for the wall preview mesh shown during drag.
 #[derive(Component)]
 pub struct WallOfStonePreview;
+
+/// Tracks placed walls in the order they were cast.
+///
+/// Wall collision and avoidance loop over every wall for every unit, so the
+/// number of standing walls is capped. Casting past the cap removes the oldest.
+#[derive(Resource)]
+pub struct WallRegistry {
+    /// Wall entities, oldest first.
+    pub walls: VecDeque<Entity>,
+    /// Maximum number of walls standing at once.
+    pub max_active_walls: usize,
+}
+
+impl Default for WallRegistry {
+    fn default() -> Self {
+        Self {
+            walls: VecDeque::new(),
+            max_active_walls: MAX_ACTIVE_WALLS,
+        }
+    }
+}
+
+impl WallRegistry {
+    /// Records a newly placed wall.
+    ///
+    /// Returns the oldest walls that no longer fit under the limit; the caller
+    /// is responsible for despawning them.
+    pub fn register(&mut self, wall: Entity) -> Vec<Entity> {
+        self.walls.push_back(wall);
+
+        let excess = self.walls.len().saturating_sub(self.max_active_walls);
+        self.walls.drain(..excess).collect()
+    }
+
+    /// Forgets a wall that was removed (expired, evicted, or cleaned up).
+    pub fn remove(&mut self, wall: Entity) {
+        self.walls.retain(|&entity| entity != wall);
+    }
+
+    /// Forgets every wall.
+    pub fn clear(&mut self) {
+        self.walls.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wall(index: u32) -> Entity {
+        Entity::from_raw_u32(index).unwrap()
+    }
+
+    fn registry(max_active_walls: usize) -> WallRegistry {
+        WallRegistry {
+            walls: VecDeque::new(),
+            max_active_walls,
+        }
+    }
+
+    #[test]
+    fn test_walls_under_limit_are_kept() {
+        let mut registry = registry(3);
+        for index in 0..3 {
+            assert!(registry.register(wall(index)).is_empty());
+        }
+        assert_eq!(registry.walls.len(), 3);
+    }
+
+    #[test]
+    fn test_wall_over_limit_evicts_oldest() {
+        let mut registry = registry(3);
+        for index in 0..3 {
+            registry.register(wall(index));
+        }
+
+        assert_eq!(registry.register(wall(3)), vec![wall(0)]);
+        assert_eq!(registry.walls, [wall(1), wall(2), wall(3)]);
+    }
+
+    #[test]
+    fn test_removed_wall_frees_a_slot() {
+        let mut registry = registry(3);
+        for index in 0..3 {
+            registry.register(wall(index));
+        }
+
+        // The middle wall expires on its own
+        registry.remove(wall(1));
+        assert_eq!(registry.walls, [wall(0), wall(2)]);
+
+        // The next wall fits without evicting anything
+        assert!(registry.register(wall(3)).is_empty());
+
+        // And the one after that evicts the true oldest
+        assert_eq!(registry.register(wall(4)), vec![wall(0)]);
+        assert_eq!(registry.walls, [wall(2), wall(3), wall(4)]);
+    }
+}
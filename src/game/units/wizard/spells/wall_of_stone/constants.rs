@@ -23,6 +23,9 @@ pub const WALL_DURATION: f32 = 20.0;
 /// Duration of the sinking animation at end of life.
 pub const WALL_SINK_DURATION: f32 = 2.0;
 
+/// Maximum number of walls standing at once (casting another removes the oldest).
+pub const MAX_ACTIVE_WALLS: usize = 3;
+
 /// Minimum drag distance required to place a wall.
 pub const MIN_WALL_LENGTH: f32 = 20.0;
 
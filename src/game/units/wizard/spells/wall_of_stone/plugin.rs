@@ -2,28 +2,38 @@ use bevy::prelude::*;
 
 use super::super::super::components::Spell;
 use super::super::run_conditions::*;
+use super::components::WallRegistry;
 use super::systems;
-use crate::state::InGameState;
+use crate::state::{AppState, InGameState};
 
 /// Plugin that handles the Wall of Stone spell.
+///
+/// Tracks placed walls in a `WallRegistry` so only a limited number stand at once,
+/// and clears the placement preview when the primed spell is cancelled.
 pub struct WallOfStonePlugin;
 
 impl Plugin for WallOfStonePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                systems::handle_wall_of_stone_cancel.run_if(spell_is_primed(Spell::WallOfStone)),
-                systems::handle_wall_of_stone_casting
-                    .run_if(spell_is_primed(Spell::WallOfStone))
-                    .run_if(spell_input_not_blocked)
-                    .run_if(mouse_left_not_consumed)
-                    .run_if(mouse_held_or_wizard_casting),
-                systems::tick_wall_lifetime,
-                systems::animate_sinking_walls,
-                systems::cleanup_expired_walls,
-            )
-                .run_if(in_state(InGameState::Running)),
-        );
+        app.init_resource::<WallRegistry>()
+            .add_systems(OnEnter(AppState::InGame), systems::reset_wall_registry)
+            .add_systems(OnExit(InGameState::GameOver), systems::reset_wall_registry)
+            .add_systems(
+                Update,
+                (
+                    systems::handle_wall_of_stone_cancel
+                        .run_if(spell_is_primed(Spell::WallOfStone)),
+                    systems::handle_wall_of_stone_casting
+                        .run_if(spell_is_primed(Spell::WallOfStone))
+                        .run_if(spell_input_not_blocked)
+                        .run_if(mouse_left_not_consumed)
+                        .run_if(mouse_held_or_wizard_casting),
+                    systems::tick_wall_lifetime,
+                    systems::animate_sinking_walls,
+                    systems::cleanup_expired_walls,
+                    systems::sync_wall_registry,
+                    systems::cancel_wall_of_stone_on_spell_cancelled,
+                )
+                    .run_if(in_state(InGameState::Running)),
+            );
     }
 }
@@ -1,11 +1,14 @@
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
 
-use super::super::super::components::{CastingState, Mana, Wizard};
-use super::components::{WallOfStone, WallOfStoneCaster, WallOfStonePreview};
+use super::super::super::components::{
+    CancelSpellMessage, CastingState, Mana, Spell, SpellCastRecord, Wizard,
+};
+use super::super::circle_placement::place_circle;
+use super::components::{WallOfStone, WallOfStoneCaster, WallOfStonePreview, WallRegistry};
 use super::constants::*;
 use crate::game::components::OnGameplayScreen;
 use crate::game::input::MouseButtonState;
+use crate::game::input::components::CursorWorldPosition;
 use crate::game::input::events::MouseLeftReleased;
 
 /// Handles Wall of Stone casting — click to anchor, drag to extend, release to place.
@@ -20,10 +23,11 @@ pub fn handle_wall_of_stone_casting(
         (Entity, &Transform, &Wizard, &mut CastingState, &mut Mana),
         With<Wizard>,
     >,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
+    cursor: Res<CursorWorldPosition>,
     mut caster_query: Query<&mut WallOfStoneCaster, With<Wizard>>,
     mut preview_query: Query<&mut Transform, (With<WallOfStonePreview>, Without<Wizard>)>,
+    mut registry: ResMut<WallRegistry>,
+    mut records: MessageWriter<SpellCastRecord>,
 ) {
     let Ok((wizard_entity, wizard_transform, wizard, mut casting_state, mut mana)) =
         wizard_query.single_mut()
@@ -43,11 +47,10 @@ pub fn handle_wall_of_stone_casting(
     let mouse_released = mouse_left_released.read().next().is_some();
 
     // Get cursor world position
-    let Some(cursor_pos) = get_cursor_world_position(&camera_query, &window_query) else {
+    let Some(cursor_pos) = cursor.ground else {
         return;
     };
-    let clamped_pos =
-        clamp_to_spell_range(cursor_pos, wizard_transform.translation, wizard.spell_range);
+    let clamped_pos = place_circle(cursor_pos, wizard_transform.translation, wizard.spell_range);
 
     // Handle release — place wall or cancel
     if mouse_released {
@@ -62,32 +65,44 @@ pub fn handle_wall_of_stone_casting(
                 let center = anchor + forward * (clamped_length / 2.0);
 
                 mana.consume(MANA_COST);
+                records.write(SpellCastRecord {
+                    spell: Spell::WallOfStone,
+                    mana_cost: MANA_COST,
+                    affected: 0,
+                });
 
                 // Spawn the actual wall
                 let wall_mesh = Cuboid::new(clamped_length, WALL_HEIGHT, WALL_WIDTH);
                 let rotation = Quat::from_rotation_arc(Vec3::X, forward);
 
-                commands.spawn((
-                    Mesh3d(meshes.add(wall_mesh)),
-                    MeshMaterial3d(materials.add(StandardMaterial {
-                        base_color: WALL_COLOR,
-                        ..default()
-                    })),
-                    Transform::from_xyz(center.x, WALL_HEIGHT / 2.0, center.z)
-                        .with_rotation(rotation),
-                    WallOfStone {
-                        center,
-                        half_length: clamped_length / 2.0,
-                        half_width: WALL_WIDTH / 2.0,
-                        forward,
-                        right,
-                        height: WALL_HEIGHT,
-                        time_alive: 0.0,
-                        duration: WALL_DURATION,
-                        sinking: false,
-                    },
-                    OnGameplayScreen,
-                ));
+                let wall_entity = commands
+                    .spawn((
+                        Mesh3d(meshes.add(wall_mesh)),
+                        MeshMaterial3d(materials.add(StandardMaterial {
+                            base_color: WALL_COLOR,
+                            ..default()
+                        })),
+                        Transform::from_xyz(center.x, WALL_HEIGHT / 2.0, center.z)
+                            .with_rotation(rotation),
+                        WallOfStone {
+                            center,
+                            half_length: clamped_length / 2.0,
+                            half_width: WALL_WIDTH / 2.0,
+                            forward,
+                            right,
+                            height: WALL_HEIGHT,
+                            time_alive: 0.0,
+                            duration: WALL_DURATION,
+                            sinking: false,
+                        },
+                        OnGameplayScreen,
+                    ))
+                    .id();
+
+                // Keep the number of standing walls under the limit
+                for oldest in registry.register(wall_entity) {
+                    commands.entity(oldest).despawn();
+                }
             }
 
             // Despawn preview
@@ -187,6 +202,26 @@ pub fn handle_wall_of_stone_cancel(
     mouse_state.left_consumed = true;
 }
 
+/// Despawns the wall preview and drops the anchor when the primed spell is cancelled.
+pub fn cancel_wall_of_stone_on_spell_cancelled(
+    mut cancel_spell: MessageReader<CancelSpellMessage>,
+    mut commands: Commands,
+    mut caster_query: Query<&mut WallOfStoneCaster, With<Wizard>>,
+) {
+    if cancel_spell.read().next().is_none() {
+        return;
+    }
+
+    let Ok(mut caster) = caster_query.single_mut() else {
+        return;
+    };
+
+    if let Some(preview_entity) = caster.preview_entity.take() {
+        commands.entity(preview_entity).despawn();
+    }
+    caster.anchor = None;
+}
+
 /// Advances wall lifetime and triggers sinking phase.
 pub fn tick_wall_lifetime(time: Res<Time>, mut walls: Query<&mut WallOfStone>) {
     let delta = time.delta_secs();
@@ -219,35 +254,96 @@ pub fn cleanup_expired_walls(mut commands: Commands, walls: Query<(Entity, &Wall
     }
 }
 
-/// Gets cursor position projected onto Y=0 plane.
-fn get_cursor_world_position(
-    camera_query: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    window_query: &Query<&Window, With<PrimaryWindow>>,
-) -> Option<Vec3> {
-    let (camera, camera_transform) = camera_query.single().ok()?;
-    let window = window_query.single().ok()?;
-    let cursor_pos = window.cursor_position()?;
-
-    let ray = camera
-        .viewport_to_world(camera_transform, cursor_pos)
-        .ok()?;
-    let t = -ray.origin.y / ray.direction.y;
-
-    if t > 0.0 {
-        Some(ray.origin + ray.direction * t)
-    } else {
-        None
+/// Removes walls from the registry once they are gone, however they were removed.
+pub fn sync_wall_registry(
+    mut registry: ResMut<WallRegistry>,
+    mut removed_walls: RemovedComponents<WallOfStone>,
+) {
+    for entity in removed_walls.read() {
+        registry.remove(entity);
     }
 }
 
-/// Clamps a position to be within the wizard's spell range.
-fn clamp_to_spell_range(target: Vec3, wizard_pos: Vec3, spell_range: f32) -> Vec3 {
-    let diff = target - wizard_pos;
-    let distance = diff.length();
+/// Clears the registry when a new game starts.
+pub fn reset_wall_registry(mut registry: ResMut<WallRegistry>) {
+    registry.clear();
+}
 
-    if distance > spell_range {
-        wizard_pos + diff.normalize() * spell_range
-    } else {
-        target
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wall_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<WallRegistry>()
+            .add_systems(Update, (cleanup_expired_walls, sync_wall_registry).chain());
+        app
+    }
+
+    /// Spawns a wall and registers it the way casting does, despawning evictions.
+    fn place_wall(app: &mut App) -> Entity {
+        let wall = app
+            .world_mut()
+            .spawn(WallOfStone {
+                center: Vec3::ZERO,
+                half_length: MIN_WALL_LENGTH,
+                half_width: WALL_WIDTH / 2.0,
+                forward: Vec3::Z,
+                right: Vec3::X,
+                height: WALL_HEIGHT,
+                time_alive: 0.0,
+                duration: WALL_DURATION,
+                sinking: false,
+            })
+            .id();
+        let evicted = app
+            .world_mut()
+            .resource_mut::<WallRegistry>()
+            .register(wall);
+        for oldest in evicted {
+            app.world_mut().entity_mut(oldest).despawn();
+        }
+        app.update();
+        wall
+    }
+
+    #[test]
+    fn test_expired_wall_frees_its_registry_slot() {
+        let mut app = wall_app();
+        let walls: Vec<Entity> = (0..MAX_ACTIVE_WALLS)
+            .map(|_| place_wall(&mut app))
+            .collect();
+
+        // The middle wall runs out its lifetime and is cleaned up
+        app.world_mut()
+            .get_mut::<WallOfStone>(walls[1])
+            .unwrap()
+            .time_alive = WALL_DURATION;
+        app.update();
+
+        assert!(app.world().get_entity(walls[1]).is_err());
+        assert!(
+            !app.world()
+                .resource::<WallRegistry>()
+                .walls
+                .contains(&walls[1])
+        );
+        assert_eq!(
+            app.world().resource::<WallRegistry>().walls.len(),
+            MAX_ACTIVE_WALLS - 1
+        );
+
+        // The freed slot takes the next wall without evicting anything
+        let refill = place_wall(&mut app);
+        assert!(app.world().get_entity(walls[0]).is_ok());
+
+        // One more at the limit evicts the oldest wall still standing
+        let newest = place_wall(&mut app);
+        assert!(app.world().get_entity(walls[0]).is_err());
+        let registry = app.world().resource::<WallRegistry>();
+        assert_eq!(registry.walls.len(), MAX_ACTIVE_WALLS);
+        assert!(!registry.walls.contains(&walls[0]));
+        assert!(registry.walls.contains(&refill));
+        assert_eq!(registry.walls.back(), Some(&newest));
     }
 }
@@ -4,14 +4,18 @@ use super::components::*;
 use super::constants;
 use super::spells::magic_missile_constants;
 use super::styles::*;
+use crate::config::{GameConfig, SpellStats};
 use crate::game::components::{Billboard, OnGameplayScreen};
 use crate::game::constants::WIZARD_POSITION;
 use crate::game::input::MouseButtonState;
-use crate::game::units::components::{Health, Hitbox, MovementSpeed};
+use crate::game::input::components::{CursorWorldPosition, SpellInputBlockedThisFrame};
+use crate::game::input::events::{MouseLeftPressed, MouseRightPressed};
+use crate::game::units::components::{DamageDealt, DamageSource, Health, Hitbox, MovementSpeed};
 
 /// Sets up the wizard when entering the InGame state.
 ///
 /// Spawns the wizard entity as a triangle on the castle platform in 3D space.
+/// The starting spell is primed separately by `prime_starting_spell`.
 pub fn setup_wizard(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -40,32 +44,199 @@ pub fn setup_wizard(
         hitbox,
         Health::new(constants::HEALTH),
         MovementSpeed(0.0), // Wizard doesn't move
-        Mana::new(constants::MANA),
-        ManaRegen::new(constants::MANA_REGEN),
+        Mana::new(constants::MANA, constants::MANA_REGEN),
         CastingState::new(),
         Wizard::new(constants::DEFAULT_SPELL_RANGE),
-        magic_missile_constants::PRIMED_MAGIC_MISSILE,
         Billboard,
         OnGameplayScreen,
     ));
 }
 
-/// Regenerates wizard mana over time.
-pub fn regenerate_mana(time: Res<Time>, mut wizards: Query<(&mut Mana, &ManaRegen), With<Wizard>>) {
-    for (mut mana, regen) in &mut wizards {
-        mana.regenerate(regen.rate * time.delta_secs());
+/// Primes the player's starting spell when a battle starts.
+///
+/// Uses the configured default spell if it is equipped, otherwise the first
+/// equipped spell (Magic Missile if the loadout is somehow empty).
+pub fn prime_starting_spell(
+    config: Res<GameConfig>,
+    mut prime_spell: MessageWriter<PrimeSpellMessage>,
+) {
+    prime_spell.write(PrimeSpellMessage {
+        spell: config.starting_spell().map_or(
+            magic_missile_constants::PRIMED_MAGIC_MISSILE,
+            Spell::primed_config,
+        ),
+    });
+}
+
+/// Regenerates wizard mana over time, scaled by the difficulty setting.
+pub fn regenerate_mana(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut wizards: Query<&mut Mana, With<Wizard>>,
+) {
+    let multiplier = config.difficulty.mana_regen_multiplier();
+    for mut mana in &mut wizards {
+        mana.tick(time.delta_secs(), multiplier);
     }
 }
 
 /// Handles PrimeSpellMessage to update the wizard's primed spell.
 /// This allows UI systems to request spell changes without directly accessing components.
+///
+/// Inserts the component so a spell can be primed again after being cancelled.
+/// While a cast or channel is in progress the spell is queued instead, and
+/// primed by `activate_queued_spell` once the wizard is resting again.
 pub fn handle_prime_spell_messages(
+    mut commands: Commands,
     mut messages: MessageReader<PrimeSpellMessage>,
-    mut wizard_query: Query<&mut PrimedSpell, With<Wizard>>,
+    wizard_query: Query<(Entity, &CastingState), With<Wizard>>,
 ) {
     for message in messages.read() {
-        if let Ok(mut primed_spell) = wizard_query.single_mut() {
-            *primed_spell = message.spell;
+        if let Ok((wizard_entity, casting_state)) = wizard_query.single() {
+            if matches!(casting_state, CastingState::Resting) {
+                commands
+                    .entity(wizard_entity)
+                    .insert(message.spell)
+                    .remove::<QueuedSpell>();
+            } else {
+                commands.entity(wizard_entity).insert(QueuedSpell {
+                    spell: message.spell,
+                });
+            }
+        }
+    }
+}
+
+/// Primes the queued spell once the current cast or channel has finished.
+pub fn activate_queued_spell(
+    mut commands: Commands,
+    wizard_query: Query<(Entity, &CastingState, &QueuedSpell), With<Wizard>>,
+) {
+    if let Ok((wizard_entity, casting_state, queued)) = wizard_query.single()
+        && matches!(casting_state, CastingState::Resting)
+    {
+        commands
+            .entity(wizard_entity)
+            .insert(queued.spell)
+            .remove::<QueuedSpell>();
+    }
+}
+
+/// Clears the queued spell on right-click.
+pub fn clear_queued_spell_on_right_click(
+    mut commands: Commands,
+    mut mouse_right_pressed: MessageReader<MouseRightPressed>,
+    wizard_query: Query<Entity, (With<Wizard>, With<QueuedSpell>)>,
+) {
+    if mouse_right_pressed.read().next().is_none() {
+        return;
+    }
+
+    if let Ok(wizard_entity) = wizard_query.single() {
+        commands.entity(wizard_entity).remove::<QueuedSpell>();
+    }
+}
+
+/// Reports a channel to the cast history once the wizard stops channeling.
+///
+/// Covers every way a channel ends: release, running out of mana, or cancelling.
+pub fn report_finished_channels(
+    mut commands: Commands,
+    wizard_query: Query<(Entity, &CastingState, &ChannelTally), With<Wizard>>,
+    mut records: MessageWriter<SpellCastRecord>,
+) {
+    if let Ok((wizard_entity, casting_state, tally)) = wizard_query.single()
+        && !matches!(casting_state, CastingState::Channeling { .. })
+    {
+        records.write(tally.record());
+        commands.entity(wizard_entity).remove::<ChannelTally>();
+    }
+}
+
+/// Sends a CancelSpellMessage when the cancel key is pressed.
+pub fn send_cancel_spell_on_key(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut cancel_spell: MessageWriter<CancelSpellMessage>,
+) {
+    if keyboard.just_pressed(constants::CANCEL_SPELL_KEY) {
+        cancel_spell.write(CancelSpellMessage);
+    }
+}
+
+/// Handles CancelSpellMessage by unpriming the wizard's spell and clearing the queue.
+///
+/// Cancels any cast in progress and consumes the left mouse button so a held
+/// click doesn't start a new cast. Spell-specific indicators are cleaned up by
+/// each spell's own cancel system.
+pub fn handle_cancel_spell_messages(
+    mut commands: Commands,
+    mut messages: MessageReader<CancelSpellMessage>,
+    mut wizard_query: Query<(Entity, &mut CastingState), With<Wizard>>,
+    mut mouse_state: ResMut<MouseButtonState>,
+) {
+    if messages.read().next().is_none() {
+        return;
+    }
+
+    if let Ok((wizard_entity, mut casting_state)) = wizard_query.single_mut() {
+        casting_state.cancel();
+        commands
+            .entity(wizard_entity)
+            .remove::<(PrimedSpell, QueuedSpell)>();
+    }
+    mouse_state.left_consumed = true;
+}
+
+/// Sends `CastRejected` when a cast press can't start the primed spell.
+///
+/// Only presses that would start a new cast count, and presses on UI buttons
+/// or off the battlefield are ignored. Uses the same mana and range rules as
+/// the cursor indicator.
+pub fn detect_rejected_casts(
+    mut pressed: MessageReader<MouseLeftPressed>,
+    input_blocked: Res<SpellInputBlockedThisFrame>,
+    cursor: Res<CursorWorldPosition>,
+    wizard_query: Query<(&Transform, &Wizard, &Mana, &CastingState, &PrimedSpell)>,
+    mut rejected: MessageWriter<CastRejected>,
+) {
+    if pressed.read().count() == 0 || input_blocked.blocked {
+        return;
+    }
+    let Ok((transform, wizard, mana, casting_state, primed)) = wizard_query.single() else {
+        return;
+    };
+    let Some(target) = cursor.ground else {
+        return;
+    };
+
+    if matches!(casting_state, CastingState::Resting)
+        && let Some(reason) = cast_rejection(
+            primed.spell,
+            mana,
+            transform.translation,
+            wizard.spell_range,
+            target,
+        )
+    {
+        rejected.write(CastRejected {
+            spell: primed.spell,
+            reason,
+        });
+    }
+}
+
+/// Adds resolved casts and spell damage to the lifetime `SpellStats`.
+pub fn record_spell_stats(
+    mut records: MessageReader<SpellCastRecord>,
+    mut damage_dealt: MessageReader<DamageDealt>,
+    mut stats: ResMut<SpellStats>,
+) {
+    for record in records.read() {
+        stats.record_cast(record.spell);
+    }
+    for hit in damage_dealt.read() {
+        if let DamageSource::Spell(spell) = hit.source {
+            stats.record_damage(spell, hit.amount);
         }
     }
 }
@@ -86,3 +257,229 @@ pub fn cancel_active_casts(
     // Reset mouse state when exiting running state
     mouse_state.left_consumed = false;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::spell_stats::{parse_spell_stats, serialize_spell_stats};
+    use crate::game::units::components::DamageType;
+    use crate::game::units::wizard::spells::{fireball_constants, frost_nova_constants};
+
+    #[test]
+    fn test_spell_primed_during_cast_activates_when_cast_finishes() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<PrimeSpellMessage>()
+            .add_systems(
+                Update,
+                (handle_prime_spell_messages, activate_queued_spell).chain(),
+            );
+
+        let wizard = app
+            .world_mut()
+            .spawn((
+                Wizard::new(constants::DEFAULT_SPELL_RANGE),
+                CastingState::Casting { elapsed: 0.0 },
+                fireball_constants::PRIMED_FIREBALL,
+            ))
+            .id();
+
+        app.world_mut().write_message(PrimeSpellMessage {
+            spell: frost_nova_constants::PRIMED_FROST_NOVA,
+        });
+        app.update();
+
+        // Still casting, so the fireball stays primed and frost nova waits
+        let primed = |app: &App| app.world().get::<PrimedSpell>(wizard).copied();
+        assert_eq!(primed(&app), Some(fireball_constants::PRIMED_FIREBALL));
+        assert!(app.world().get::<QueuedSpell>(wizard).is_some());
+
+        app.world_mut()
+            .get_mut::<CastingState>(wizard)
+            .unwrap()
+            .cancel();
+        app.update();
+
+        assert_eq!(primed(&app), Some(frost_nova_constants::PRIMED_FROST_NOVA));
+        assert!(app.world().get::<QueuedSpell>(wizard).is_none());
+    }
+
+    #[test]
+    fn test_battle_starts_with_default_spell_primed() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<PrimeSpellMessage>()
+            .insert_resource(GameConfig {
+                spell_loadout: vec![Spell::MagicMissile, Spell::FrostNova],
+                default_spell: Spell::FrostNova,
+                ..Default::default()
+            })
+            .add_systems(
+                Update,
+                (prime_starting_spell, handle_prime_spell_messages).chain(),
+            );
+
+        let wizard = app
+            .world_mut()
+            .spawn((
+                Wizard::new(constants::DEFAULT_SPELL_RANGE),
+                CastingState::new(),
+            ))
+            .id();
+        app.update();
+
+        assert_eq!(
+            app.world().get::<PrimedSpell>(wizard).copied(),
+            Some(frost_nova_constants::PRIMED_FROST_NOVA)
+        );
+    }
+
+    #[test]
+    fn test_channel_is_reported_once_when_it_ends() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<SpellCastRecord>()
+            .add_systems(Update, report_finished_channels);
+
+        let units = [
+            Entity::from_raw_u32(1).unwrap(),
+            Entity::from_raw_u32(2).unwrap(),
+        ];
+        let mut tally = ChannelTally::new(Spell::MagicMissile);
+        tally.add(1.0, [units[0]]);
+        tally.add(1.0, [units[0], units[1]]);
+
+        let mut casting_state = CastingState::new();
+        casting_state.start_channeling();
+        let wizard = app
+            .world_mut()
+            .spawn((
+                Wizard::new(constants::DEFAULT_SPELL_RANGE),
+                casting_state,
+                tally,
+            ))
+            .id();
+
+        // Still channeling, nothing reported yet
+        app.update();
+        assert!(app.world().get::<ChannelTally>(wizard).is_some());
+
+        app.world_mut()
+            .get_mut::<CastingState>(wizard)
+            .unwrap()
+            .cancel();
+        app.update();
+
+        let records: Vec<SpellCastRecord> = app
+            .world_mut()
+            .resource_mut::<Messages<SpellCastRecord>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            records,
+            vec![SpellCastRecord {
+                spell: Spell::MagicMissile,
+                mana_cost: 2.0,
+                affected: 2,
+            }]
+        );
+        assert!(app.world().get::<ChannelTally>(wizard).is_none());
+    }
+
+    fn rejected_cast_app(mana: f32, target: Vec3) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<MouseLeftPressed>()
+            .add_message::<CastRejected>()
+            .init_resource::<SpellInputBlockedThisFrame>()
+            .insert_resource(CursorWorldPosition {
+                ray: None,
+                ground: Some(target),
+            })
+            .add_systems(Update, detect_rejected_casts);
+
+        let mut wizard_mana = Mana::new(constants::MANA, constants::MANA_REGEN);
+        wizard_mana.current = mana;
+        app.world_mut().spawn((
+            Transform::from_translation(WIZARD_POSITION),
+            Wizard::new(constants::DEFAULT_SPELL_RANGE),
+            wizard_mana,
+            CastingState::new(),
+            fireball_constants::PRIMED_FIREBALL,
+        ));
+
+        app.world_mut().write_message(MouseLeftPressed {
+            cursor_position: None,
+        });
+        app.update();
+        app
+    }
+
+    fn rejections(app: &mut App) -> Vec<CastRejected> {
+        app.world_mut()
+            .resource_mut::<Messages<CastRejected>>()
+            .drain()
+            .collect()
+    }
+
+    #[test]
+    fn test_cast_without_enough_mana_is_rejected() {
+        let in_range = Vec3::new(WIZARD_POSITION.x, 0.0, WIZARD_POSITION.z);
+        let mut app = rejected_cast_app(fireball_constants::MANA_COST - 1.0, in_range);
+
+        assert_eq!(
+            rejections(&mut app),
+            vec![CastRejected {
+                spell: Spell::Fireball,
+                reason: CastRejectReason::NotEnoughMana,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_affordable_cast_in_range_is_not_rejected() {
+        let in_range = Vec3::new(WIZARD_POSITION.x, 0.0, WIZARD_POSITION.z);
+        let mut app = rejected_cast_app(constants::MANA, in_range);
+        assert!(rejections(&mut app).is_empty());
+
+        let far = in_range + Vec3::X * (constants::DEFAULT_SPELL_RANGE * 2.0);
+        let mut app = rejected_cast_app(constants::MANA, far);
+        assert_eq!(rejections(&mut app)[0].reason, CastRejectReason::OutOfRange);
+    }
+
+    #[test]
+    fn test_cast_spell_count_persists_after_save() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<SpellStats>()
+            .add_message::<SpellCastRecord>()
+            .add_message::<DamageDealt>()
+            .add_systems(Update, record_spell_stats);
+
+        app.world_mut().write_message(SpellCastRecord {
+            spell: Spell::Fireball,
+            mana_cost: 30.0,
+            affected: 2,
+        });
+        for (source, amount) in [
+            (DamageSource::Spell(Spell::Fireball), 40.0),
+            (DamageSource::Spell(Spell::Fireball), 25.0),
+            (DamageSource::Unit, 10.0),
+        ] {
+            app.world_mut().write_message(DamageDealt {
+                target: Entity::PLACEHOLDER,
+                position: Vec3::ZERO,
+                amount,
+                damage_type: DamageType::Magic,
+                source,
+                lethal: false,
+            });
+        }
+        app.update();
+
+        let saved = serialize_spell_stats(app.world().resource::<SpellStats>()).unwrap();
+        let fireball = parse_spell_stats(&saved).unwrap().usage(Spell::Fireball);
+        assert_eq!(fireball.casts, 1);
+        assert_eq!(fireball.damage, 65.0);
+    }
+}
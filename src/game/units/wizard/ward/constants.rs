@@ -0,0 +1,10 @@
+//! Personal ward constants.
+
+/// Radius of the ward around the wizard on the ground plane (in units).
+pub const WARD_RADIUS: f32 = 300.0;
+
+/// Outward push at the wizard, falling off to zero at the ward's edge.
+pub const WARD_FORCE: f32 = 900.0;
+
+/// Braking applied per unit of speed toward the wizard, scaled like the push.
+pub const WARD_BRAKING: f32 = 3.0;
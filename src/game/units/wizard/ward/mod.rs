@@ -0,0 +1,9 @@
+//! Personal ward module.
+//!
+//! Passively pushes enemies away from the wizard and slows their approach.
+
+mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::WardPlugin;
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use crate::game::run_conditions;
+
+use super::systems;
+
+/// Plugin that handles the wizard's personal ward.
+///
+/// Registers systems for:
+/// - Pushing attackers and undead out of the ward and slowing their approach
+///   (only while the ward is enabled in the settings)
+pub struct WardPlugin;
+
+impl Plugin for WardPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            systems::repel_enemies_from_wizard
+                .run_if(run_conditions::personal_ward_enabled)
+                .in_set(crate::game::plugin::VelocitySystemSet),
+        );
+    }
+}
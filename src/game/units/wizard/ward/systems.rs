@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+
+use super::super::components::Wizard;
+use super::constants::*;
+use crate::game::components::{Acceleration, Velocity};
+use crate::game::units::components::{Corpse, Team};
+
+/// Applies a steering force to enemies inside the ward, pushing them away from the wizard.
+///
+/// Both the push and the braking on their approach grow stronger closer to the wizard.
+/// Distance is measured on the ground plane, since the wizard stands on the castle.
+pub fn repel_enemies_from_wizard(
+    wizard_query: Query<&Transform, With<Wizard>>,
+    mut units: Query<
+        (&Transform, &Team, &Velocity, &mut Acceleration),
+        (Without<Corpse>, Without<Wizard>),
+    >,
+) {
+    let Ok(wizard_transform) = wizard_query.single() else {
+        return;
+    };
+    let wizard_pos = wizard_transform.translation;
+
+    for (transform, team, velocity, mut acceleration) in &mut units {
+        if *team == Team::Defenders {
+            continue;
+        }
+
+        let offset = Vec3::new(
+            transform.translation.x - wizard_pos.x,
+            0.0,
+            transform.translation.z - wizard_pos.z,
+        );
+        let distance = offset.length();
+        if distance >= WARD_RADIUS {
+            continue;
+        }
+
+        let outward = offset.normalize_or(Vec3::X);
+        let proximity = 1.0 - distance / WARD_RADIUS;
+
+        // Brake only the part of the velocity heading toward the wizard
+        let approach_speed = (-Vec3::new(velocity.x, 0.0, velocity.z).dot(outward)).max(0.0);
+
+        acceleration.add_force(outward * (WARD_FORCE + approach_speed * WARD_BRAKING) * proximity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constants::WIZARD_POSITION;
+
+    fn spawn_unit(app: &mut App, team: Team, offset: Vec3) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::from_translation(WIZARD_POSITION.with_y(0.0) + offset),
+                team,
+                Velocity::default(),
+                Acceleration::new(),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_attacker_inside_ward_is_pushed_outward() {
+        let mut app = App::new();
+        app.add_systems(Update, repel_enemies_from_wizard);
+        app.world_mut().spawn((
+            Transform::from_translation(WIZARD_POSITION),
+            Wizard::new(1000.0),
+        ));
+
+        let direction = Vec3::new(1.0, 0.0, 1.0).normalize();
+        let inside = spawn_unit(&mut app, Team::Attackers, direction * WARD_RADIUS * 0.5);
+        let outside = spawn_unit(&mut app, Team::Attackers, direction * WARD_RADIUS * 2.0);
+        let defender = spawn_unit(&mut app, Team::Defenders, direction * WARD_RADIUS * 0.5);
+
+        app.update();
+
+        let push = |entity| {
+            let acceleration = app.world().get::<Acceleration>(entity).unwrap();
+            Vec3::new(acceleration.x, 0.0, acceleration.z)
+        };
+        assert!(push(inside).dot(direction) > 0.0);
+        assert_eq!(push(outside), Vec3::ZERO);
+        assert_eq!(push(defender), Vec3::ZERO);
+    }
+}
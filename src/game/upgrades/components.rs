@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+
+use super::constants::*;
+use crate::game::units::wizard::components::{Mana, Wizard};
+use crate::game::units::wizard::constants::{DEFAULT_SPELL_RANGE, MANA, MANA_REGEN};
+
+/// Wizard stats that can be upgraded between waves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Upgrade {
+    SpellRange,
+    MaxMana,
+    ManaRegen,
+}
+
+impl Upgrade {
+    /// Returns every upgrade in display order.
+    pub const fn all() -> &'static [Upgrade] {
+        &[Upgrade::SpellRange, Upgrade::MaxMana, Upgrade::ManaRegen]
+    }
+
+    /// Returns the display name for this upgrade.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Upgrade::SpellRange => "Spell Range",
+            Upgrade::MaxMana => "Max Mana",
+            Upgrade::ManaRegen => "Mana Regen",
+        }
+    }
+}
+
+/// Message sent by the intermission screen to buy an upgrade.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct PurchaseUpgrade(pub Upgrade);
+
+/// Upgrade points and purchased levels for the current run.
+///
+/// Kept across waves and reset when a new game starts.
+#[derive(Resource, Debug, Default)]
+pub struct WizardUpgrades {
+    /// Points available to spend.
+    pub points: u32,
+    /// Levels bought of each upgrade.
+    pub spell_range: u32,
+    pub max_mana: u32,
+    pub mana_regen: u32,
+    /// Enemy kills already converted into points.
+    kills_credited: u32,
+}
+
+impl WizardUpgrades {
+    /// Converts enemy kills since the last call into points.
+    ///
+    /// `total_enemy_kills` is the run-wide count of attackers and undead killed.
+    pub fn credit_kills(&mut self, total_enemy_kills: u32) {
+        let new_kills = total_enemy_kills.saturating_sub(self.kills_credited);
+        self.points += new_kills * POINTS_PER_KILL;
+        self.kills_credited = total_enemy_kills;
+    }
+
+    /// Returns the levels bought of an upgrade.
+    pub const fn level(&self, upgrade: Upgrade) -> u32 {
+        match upgrade {
+            Upgrade::SpellRange => self.spell_range,
+            Upgrade::MaxMana => self.max_mana,
+            Upgrade::ManaRegen => self.mana_regen,
+        }
+    }
+
+    /// Returns the cost of the next level of an upgrade.
+    pub const fn cost(&self, upgrade: Upgrade) -> u32 {
+        BASE_UPGRADE_COST + self.level(upgrade) * UPGRADE_COST_STEP
+    }
+
+    /// Buys the next level of an upgrade. Returns false if it can't be afforded.
+    pub fn purchase(&mut self, upgrade: Upgrade) -> bool {
+        let cost = self.cost(upgrade);
+        if self.points < cost {
+            return false;
+        }
+
+        self.points -= cost;
+        match upgrade {
+            Upgrade::SpellRange => self.spell_range += 1,
+            Upgrade::MaxMana => self.max_mana += 1,
+            Upgrade::ManaRegen => self.mana_regen += 1,
+        }
+        true
+    }
+
+    /// Sets the wizard's stats to their base values plus the bought levels.
+    ///
+    /// Raising maximum mana also grants the extra mana right away.
+    pub fn apply(&self, wizard: &mut Wizard, mana: &mut Mana) {
+        wizard.spell_range = DEFAULT_SPELL_RANGE + self.spell_range as f32 * SPELL_RANGE_PER_LEVEL;
+        mana.set_max(MANA + self.max_mana as f32 * MAX_MANA_PER_LEVEL);
+        mana.regen_per_sec = MANA_REGEN + self.mana_regen as f32 * MANA_REGEN_PER_LEVEL;
+    }
+
+    /// Clears points and upgrades for a new game.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kills_are_credited_once() {
+        let mut upgrades = WizardUpgrades::default();
+        upgrades.credit_kills(12);
+        upgrades.credit_kills(12);
+        upgrades.credit_kills(15);
+
+        assert_eq!(upgrades.points, 15 * POINTS_PER_KILL);
+    }
+
+    #[test]
+    fn test_purchase_spends_points_and_raises_cost() {
+        let mut upgrades = WizardUpgrades {
+            points: BASE_UPGRADE_COST * 2,
+            ..default()
+        };
+
+        assert!(upgrades.purchase(Upgrade::MaxMana));
+        assert_eq!(upgrades.points, BASE_UPGRADE_COST);
+        assert_eq!(upgrades.max_mana, 1);
+        assert_eq!(
+            upgrades.cost(Upgrade::MaxMana),
+            BASE_UPGRADE_COST + UPGRADE_COST_STEP
+        );
+
+        // The second level now costs more than what is left
+        assert!(!upgrades.purchase(Upgrade::MaxMana));
+        assert_eq!(upgrades.max_mana, 1);
+    }
+}
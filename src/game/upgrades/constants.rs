@@ -0,0 +1,17 @@
+/// Upgrade points earned per attacker or undead killed.
+pub const POINTS_PER_KILL: u32 = 1;
+
+/// Cost of the first level of any upgrade.
+pub const BASE_UPGRADE_COST: u32 = 20;
+
+/// Extra cost for each level already bought of the same upgrade.
+pub const UPGRADE_COST_STEP: u32 = 10;
+
+/// Spell range gained per level (units).
+pub const SPELL_RANGE_PER_LEVEL: f32 = 300.0;
+
+/// Maximum mana gained per level.
+pub const MAX_MANA_PER_LEVEL: f32 = 20.0;
+
+/// Mana regeneration gained per level (per second).
+pub const MANA_REGEN_PER_LEVEL: f32 = 1.0;
@@ -0,0 +1,12 @@
+//! Wizard upgrades module.
+//!
+//! Enemy kills earn upgrade points. Between campaign waves the player spends
+//! them on a longer spell range, more maximum mana, or faster mana regeneration.
+//! Upgrades last for the rest of the run.
+
+pub mod components;
+mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::UpgradesPlugin;
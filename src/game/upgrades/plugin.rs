@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::state::{AppState, InGameState};
+
+use super::components::{PurchaseUpgrade, WizardUpgrades};
+use super::systems;
+
+/// Plugin that handles wizard upgrades bought between waves.
+///
+/// Registers systems for:
+/// - Earning upgrade points from enemy kills
+/// - Buying upgrades during the campaign intermission
+/// - Applying upgrades to the wizard when bought and when it spawns
+/// - Resetting points and upgrades for each new game
+pub struct UpgradesPlugin;
+
+impl Plugin for UpgradesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WizardUpgrades>()
+            .add_message::<PurchaseUpgrade>()
+            .add_systems(
+                Update,
+                (
+                    systems::earn_upgrade_points,
+                    systems::purchase_upgrades,
+                    systems::apply_wizard_upgrades,
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running)),
+            )
+            .add_systems(OnEnter(AppState::InGame), systems::reset_upgrades)
+            .add_systems(OnExit(InGameState::GameOver), systems::reset_upgrades);
+    }
+}
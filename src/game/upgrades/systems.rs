@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+
+use super::components::{PurchaseUpgrade, WizardUpgrades};
+use crate::game::campaign::components::CampaignState;
+use crate::game::resources::KillStats;
+use crate::game::units::wizard::components::{Mana, Wizard};
+
+/// Converts new attacker and undead kills into upgrade points.
+pub fn earn_upgrade_points(kill_stats: Res<KillStats>, mut upgrades: ResMut<WizardUpgrades>) {
+    if kill_stats.is_changed() {
+        upgrades.credit_kills(kill_stats.attackers_killed + kill_stats.undead_killed);
+    }
+}
+
+/// Buys upgrades requested by the intermission screen.
+///
+/// Purchases are only honored between waves.
+pub fn purchase_upgrades(
+    mut purchases: MessageReader<PurchaseUpgrade>,
+    campaign: Res<CampaignState>,
+    mut upgrades: ResMut<WizardUpgrades>,
+) {
+    for PurchaseUpgrade(upgrade) in purchases.read() {
+        if campaign.in_intermission() {
+            upgrades.purchase(*upgrade);
+        }
+    }
+}
+
+/// Applies bought upgrades to the wizard when they change or the wizard spawns.
+pub fn apply_wizard_upgrades(
+    upgrades: Res<WizardUpgrades>,
+    mut wizards: Query<(&mut Wizard, &mut Mana)>,
+) {
+    for (mut wizard, mut mana) in &mut wizards {
+        if upgrades.is_changed() || wizard.is_added() {
+            upgrades.apply(&mut wizard, &mut mana);
+        }
+    }
+}
+
+/// Clears points and upgrades when a new game starts.
+pub fn reset_upgrades(mut upgrades: ResMut<WizardUpgrades>) {
+    upgrades.reset();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::campaign::components::WavePhase;
+    use crate::game::units::wizard::constants::{DEFAULT_SPELL_RANGE, MANA, MANA_REGEN};
+    use crate::game::upgrades::components::Upgrade;
+    use crate::game::upgrades::constants::{BASE_UPGRADE_COST, SPELL_RANGE_PER_LEVEL};
+
+    #[test]
+    fn test_range_upgrade_increases_wizard_spell_range() {
+        let mut campaign = CampaignState::default();
+        campaign.phase = WavePhase::Intermission { remaining: 5.0 };
+
+        let mut app = App::new();
+        app.insert_resource(KillStats {
+            attackers_killed: BASE_UPGRADE_COST,
+            ..default()
+        })
+        .insert_resource(campaign)
+        .init_resource::<WizardUpgrades>()
+        .add_message::<PurchaseUpgrade>()
+        .add_systems(
+            Update,
+            (
+                earn_upgrade_points,
+                purchase_upgrades,
+                apply_wizard_upgrades,
+            )
+                .chain(),
+        );
+        let wizard = app
+            .world_mut()
+            .spawn((
+                Wizard::new(DEFAULT_SPELL_RANGE),
+                Mana::new(MANA, MANA_REGEN),
+            ))
+            .id();
+
+        app.update();
+        app.world_mut()
+            .write_message(PurchaseUpgrade(Upgrade::SpellRange));
+        app.update();
+
+        let range = app.world().get::<Wizard>(wizard).unwrap().spell_range;
+        assert_eq!(range, DEFAULT_SPELL_RANGE + SPELL_RANGE_PER_LEVEL);
+        assert_eq!(app.world().resource::<WizardUpgrades>().points, 0);
+    }
+}
@@ -0,0 +1,112 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::config::ConfigError;
+
+/// Attacker unit types a designed wave can spawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum WaveUnit {
+    Infantry,
+    Archer,
+    Healer,
+}
+
+/// A group of identical attackers spawned together.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WaveGroup {
+    /// Type of unit in the group.
+    pub unit: WaveUnit,
+    /// Number of units in the group.
+    pub count: u32,
+    /// Ground position (x, z) the group gathers around.
+    pub position: (f32, f32),
+}
+
+/// One designed campaign wave.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WaveDefinition {
+    /// Groups of attackers making up the wave.
+    pub groups: Vec<WaveGroup>,
+}
+
+/// Designed waves loaded from `assets/waves.ron`.
+///
+/// The first wave is used for level 1, the second for level 2, and so on.
+/// Empty when no file is found, so every level uses the procedural formula.
+/// The file itself is loaded as an asset of the same type and copied into the
+/// resource once it's ready.
+#[derive(Resource, Asset, TypePath, Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct WaveDefinitions {
+    pub waves: Vec<WaveDefinition>,
+}
+
+impl WaveDefinitions {
+    /// Parses wave definitions from RON text.
+    pub fn from_ron(contents: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(contents)
+    }
+
+    /// Returns the designed wave for a level, if there is one.
+    pub fn for_level(&self, level: u32) -> Option<&WaveDefinition> {
+        let index = level.checked_sub(1)?;
+        self.waves.get(index as usize)
+    }
+}
+
+/// Keeps the `waves.ron` asset loaded so edits are picked up on hot reload.
+#[derive(Resource)]
+pub struct WaveDefinitionsHandle(pub Handle<WaveDefinitions>);
+
+/// Loads `WaveDefinitions` assets from RON files.
+#[derive(Default)]
+pub struct WaveDefinitionsLoader;
+
+impl AssetLoader for WaveDefinitionsLoader {
+    type Asset = WaveDefinitions;
+    type Settings = ();
+    type Error = ConfigError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let contents =
+            std::str::from_utf8(&bytes).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        Ok(WaveDefinitions::from_ron(contents)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_waves_map_to_levels_from_one() {
+        let definitions = WaveDefinitions {
+            waves: vec![WaveDefinition { groups: vec![] }],
+        };
+
+        assert!(definitions.for_level(0).is_none());
+        assert!(definitions.for_level(1).is_some());
+        assert!(definitions.for_level(2).is_none());
+    }
+
+    #[test]
+    fn test_example_file_parses() {
+        let definitions =
+            WaveDefinitions::from_ron(include_str!("../../../assets/waves.example.ron")).unwrap();
+
+        assert_eq!(definitions.waves.len(), 2);
+        assert_eq!(definitions.for_level(2).unwrap().groups.len(), 4);
+    }
+}
@@ -0,0 +1,4 @@
+//! Designed wave constants.
+
+/// Asset path (relative to `assets/`) of the optional wave definition file.
+pub const WAVES_PATH: &str = "waves.ron";
@@ -0,0 +1,12 @@
+//! Designed wave module.
+//!
+//! Loads hand-made campaign waves from `assets/waves.ron`. A wave listed there
+//! replaces the procedural spawn formula for its level; levels past the end of
+//! the list, or every level when the file is missing, stay procedural.
+
+pub mod components;
+pub mod constants;
+mod plugin;
+pub mod systems;
+
+pub use plugin::WavesPlugin;
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use crate::game::run_conditions;
+use crate::state::{AppState, InGameState};
+
+use super::components::{WaveDefinitions, WaveDefinitionsLoader};
+use super::systems;
+
+/// Plugin that handles designed campaign waves.
+///
+/// Registers systems for:
+/// - Loading wave definitions from `assets/waves.ron` through the asset server
+/// - Applying them once the asset is ready (and again on hot reload)
+/// - Spawning the designed wave on game start when the level has one
+///   (campaign mode only)
+/// - Re-spawn when entering Running state from GameOver (for replay)
+///
+/// Later waves are spawned by `advance_intermission`.
+pub struct WavesPlugin;
+
+impl Plugin for WavesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WaveDefinitions>()
+            .init_asset::<WaveDefinitions>()
+            .register_asset_loader(WaveDefinitionsLoader)
+            .add_systems(Startup, systems::load_wave_definitions)
+            .add_systems(Update, systems::apply_wave_definitions)
+            .add_systems(
+                OnEnter(AppState::InGame),
+                systems::spawn_defined_wave
+                    .run_if(run_conditions::in_campaign_mode)
+                    .run_if(run_conditions::has_defined_wave),
+            )
+            .add_systems(
+                OnEnter(InGameState::Running),
+                systems::spawn_defined_wave
+                    .run_if(run_conditions::in_campaign_mode)
+                    .run_if(run_conditions::has_defined_wave)
+                    .run_if(run_conditions::coming_from_game_over),
+            );
+    }
+}
@@ -0,0 +1,207 @@
+use bevy::asset::io::AssetReaderError;
+use bevy::asset::{AssetLoadError, AssetLoadFailedEvent};
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::WAVES_PATH;
+use crate::config::{ConfigError, LoadFailures};
+use crate::game::battlefield::components::BattlefieldConfig;
+use crate::game::constants::spawn_offset;
+use crate::game::resources::CurrentLevel;
+use crate::game::units::archer::systems::spawn_attacker_archer;
+use crate::game::units::healer::systems::spawn_attacker_healer;
+use crate::game::units::infantry::systems::spawn_attacker_infantry;
+
+/// Starts loading designed waves from `assets/waves.ron`.
+///
+/// The handle is kept in `WaveDefinitionsHandle`; `apply_wave_definitions`
+/// copies the waves into the resource once the asset is ready.
+pub fn load_wave_definitions(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(WaveDefinitionsHandle(asset_server.load(WAVES_PATH)));
+}
+
+/// Applies the `waves.ron` asset once it has loaded (or been edited).
+///
+/// Keeps the empty default when the file is missing or cannot be parsed, so
+/// the campaign stays procedural. A file that can't be parsed is reported to
+/// `LoadFailures` and shown on the main menu.
+pub fn apply_wave_definitions(
+    mut asset_events: MessageReader<AssetEvent<WaveDefinitions>>,
+    mut failed_events: MessageReader<AssetLoadFailedEvent<WaveDefinitions>>,
+    handle: Option<Res<WaveDefinitionsHandle>>,
+    assets: Res<Assets<WaveDefinitions>>,
+    mut definitions: ResMut<WaveDefinitions>,
+    mut load_failures: ResMut<LoadFailures>,
+) {
+    let Some(handle) = handle else {
+        return;
+    };
+
+    for event in asset_events.read() {
+        if let AssetEvent::Added { id } | AssetEvent::Modified { id } = *event
+            && id == handle.0.id()
+            && let Some(loaded) = assets.get(id)
+        {
+            info!("Loaded {} designed waves", loaded.waves.len());
+            *definitions = loaded.clone();
+        }
+    }
+
+    for event in failed_events.read() {
+        if event.id != handle.0.id() {
+            continue;
+        }
+        load_failures.report(
+            "designed waves",
+            &config_error(&event.path.to_string(), &event.error),
+        );
+    }
+}
+
+/// Maps a failed asset load of `path` onto the matching `ConfigError`.
+///
+/// A missing file is `NotFound`, a file the loader rejected is `Parse`, and
+/// anything the asset reader couldn't do is `Io`.
+fn config_error(path: &str, error: &AssetLoadError) -> ConfigError {
+    match error {
+        AssetLoadError::AssetReaderError(AssetReaderError::NotFound(_)) => {
+            ConfigError::NotFound(path.to_string())
+        }
+        AssetLoadError::AssetLoaderError(e) => ConfigError::Parse(e.to_string()),
+        e => ConfigError::Io(std::io::Error::other(e.to_string())),
+    }
+}
+
+/// Spawns the designed wave for the current level.
+///
+/// Each group gathers around its position, clamped to the battlefield.
+pub fn spawn_defined_wave(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    definitions: Res<WaveDefinitions>,
+    current_level: Res<CurrentLevel>,
+    battlefield: Res<BattlefieldConfig>,
+) {
+    let Some(wave) = definitions.for_level(current_level.0) else {
+        return;
+    };
+
+    for group in &wave.groups {
+        let (spawn_x, spawn_z) = battlefield.clamp(group.position.0, group.position.1);
+        let spawn = match group.unit {
+            WaveUnit::Infantry => spawn_attacker_infantry,
+            WaveUnit::Archer => spawn_attacker_archer,
+            WaveUnit::Healer => spawn_attacker_healer,
+        };
+
+        // Spread spawns on a spiral around the group position
+        for i in 0..group.count {
+            let offset = spawn_offset(i);
+            spawn(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                spawn_x + offset.x,
+                spawn_z + offset.y,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::units::archer::components::Archer;
+    use crate::game::units::components::Team;
+    use crate::game::units::healer::components::Healer;
+    use crate::game::units::infantry::components::Infantry;
+
+    const SAMPLE_WAVES: &str = r#"(
+        waves: [
+            (
+                groups: [
+                    (unit: Infantry, count: 6, position: (1200.0, -200.0)),
+                    (unit: Infantry, count: 4, position: (1200.0, 200.0)),
+                    (unit: Archer, count: 3, position: (1500.0, 0.0)),
+                    (unit: Healer, count: 1, position: (1600.0, 0.0)),
+                ],
+            ),
+        ],
+    )"#;
+
+    #[test]
+    fn test_sample_wave_spawns_defined_unit_counts() {
+        let definitions = WaveDefinitions::from_ron(SAMPLE_WAVES).unwrap();
+        assert_eq!(definitions.for_level(1).unwrap().groups.len(), 4);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(definitions)
+            .insert_resource(CurrentLevel(1))
+            .init_resource::<BattlefieldConfig>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .add_systems(Update, spawn_defined_wave);
+        app.update();
+
+        let world = app.world_mut();
+        let infantry = world.query::<&Infantry>().iter(world).count();
+        let archers = world.query::<&Archer>().iter(world).count();
+        let healers = world.query::<&Healer>().iter(world).count();
+        let attackers = world
+            .query::<&Team>()
+            .iter(world)
+            .filter(|team| **team == Team::Attackers)
+            .count();
+
+        assert_eq!((infantry, archers, healers), (10, 3, 1));
+        assert_eq!(attackers, 14);
+    }
+
+    #[test]
+    fn test_loaded_asset_replaces_definitions() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<WaveDefinitions>()
+            .init_resource::<WaveDefinitions>()
+            .init_resource::<LoadFailures>()
+            .add_systems(Update, apply_wave_definitions);
+
+        let loaded = WaveDefinitions::from_ron(SAMPLE_WAVES).unwrap();
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<WaveDefinitions>>()
+            .add(loaded.clone());
+        app.insert_resource(WaveDefinitionsHandle(handle));
+
+        // Asset events are flushed at the end of the frame
+        app.update();
+        app.update();
+
+        assert_eq!(*app.world().resource::<WaveDefinitions>(), loaded);
+    }
+
+    #[test]
+    fn test_unreadable_waves_file_is_an_io_error() {
+        let missing =
+            AssetLoadError::AssetReaderError(AssetReaderError::NotFound(WAVES_PATH.into()));
+        assert!(matches!(
+            config_error(WAVES_PATH, &missing),
+            ConfigError::NotFound(_)
+        ));
+
+        let unreadable = AssetLoadError::AssetReaderError(
+            std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied").into(),
+        );
+        let error = config_error(WAVES_PATH, &unreadable);
+        assert!(matches!(error, ConfigError::Io(_)));
+        assert!(!error.discards_saved_data());
+
+        // Neither is shown on the main menu, since no saved data was thrown away
+        let mut failures = LoadFailures::default();
+        failures.report("designed waves", &config_error(WAVES_PATH, &missing));
+        failures.report("designed waves", &error);
+        assert!(failures.messages.is_empty());
+    }
+}
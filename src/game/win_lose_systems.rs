@@ -1,21 +1,36 @@
 use bevy::prelude::*;
 
+use crate::config::{GameConfig, GameMode};
 use crate::state::InGameState;
 
+use super::campaign::components::{CampaignState, LastClearedWave, WaveCleared};
+use super::castle::components::CastleHealth;
+use super::endless::components::EndlessState;
 use super::resources::GameOutcome;
 use super::units::components::{Corpse, Team};
 use super::units::king::components::{King, KingSpawned};
 
-/// Checks win/lose conditions every frame and transitions to GameOver state.
+/// Checks win/lose conditions every frame.
 ///
-/// Win: All Attackers AND Undead are dead (only Defenders remain)
-/// Lose: All Defenders are dead OR King is dead
+/// Wave cleared: All Attackers AND Undead are dead (only Defenders remain).
+/// Sends `WaveCleared` once per wave so the campaign can start the next wave
+/// (or endless mode can bring its next wave forward).
+/// Lose: All Defenders are dead OR King is dead OR the castle has fallen.
+/// Transitions to GameOver state.
+/// This is also the only way an endless game ends.
+#[allow(clippy::too_many_arguments)]
 pub fn check_win_lose_conditions(
     mut next_state: ResMut<NextState<InGameState>>,
     mut game_outcome: ResMut<GameOutcome>,
+    mut wave_cleared: MessageWriter<WaveCleared>,
+    mut last_cleared: ResMut<LastClearedWave>,
+    config: Res<GameConfig>,
+    campaign: Res<CampaignState>,
+    endless: Res<EndlessState>,
     units: Query<&Team, Without<Corpse>>,
     king_spawned: Res<KingSpawned>,
     kings: Query<&King, Without<Corpse>>,
+    castle_health: Res<CastleHealth>,
 ) {
     // Check King death first (highest priority lose condition)
     if king_spawned.0 && kings.iter().next().is_none() {
@@ -24,6 +39,13 @@ pub fn check_win_lose_conditions(
         return;
     }
 
+    // The castle falling loses the battle even with defenders left
+    if castle_health.0.is_dead() {
+        *game_outcome = GameOutcome::DefeatCastleDestroyed;
+        next_state.set(InGameState::GameOver);
+        return;
+    }
+
     let mut defenders_alive = 0;
     let mut attackers_alive = 0;
     let mut undead_alive = 0;
@@ -43,9 +65,61 @@ pub fn check_win_lose_conditions(
         return;
     }
 
-    // Check win condition: no attackers AND no undead left
-    if attackers_alive == 0 && undead_alive == 0 {
-        *game_outcome = GameOutcome::Victory;
-        next_state.set(InGameState::GameOver);
+    // Check wave cleared: no attackers AND no undead left
+    // (the field stays empty until the next wave spawns, so only report it once)
+    let wave = if config.game_mode == GameMode::Endless {
+        endless.waves_spawned
+    } else {
+        campaign.waves_survived
+    };
+    if attackers_alive == 0
+        && undead_alive == 0
+        && !campaign.in_intermission()
+        && last_cleared.0 != Some(wave)
+    {
+        last_cleared.0 = Some(wave);
+        wave_cleared.write(WaveCleared {
+            surviving_defenders: defenders_alive,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+    use crate::state::AppState;
+
+    #[test]
+    fn test_empty_field_reports_wave_cleared_once() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .insert_state(AppState::InGame)
+            .add_sub_state::<InGameState>()
+            .add_message::<WaveCleared>()
+            .insert_resource(GameConfig {
+                game_mode: GameMode::Endless,
+                ..default()
+            })
+            .insert_resource(GameOutcome::Victory)
+            .insert_resource(KingSpawned(false))
+            .insert_resource(CastleHealth::default())
+            .init_resource::<CampaignState>()
+            .init_resource::<LastClearedWave>()
+            .init_resource::<EndlessState>()
+            .add_systems(Update, check_win_lose_conditions);
+
+        // Only a defender left on the field
+        app.world_mut().spawn(Team::Defenders);
+        app.update();
+        app.update();
+
+        let cleared = app
+            .world_mut()
+            .resource_mut::<Messages<WaveCleared>>()
+            .drain()
+            .count();
+        assert_eq!(cleared, 1);
     }
 }
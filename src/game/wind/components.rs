@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+/// Wind blowing across the battlefield.
+///
+/// Only present while wind is enabled in the settings.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct Wind {
+    /// Direction the wind blows toward, as an angle in the XZ plane (radians).
+    pub angle: f32,
+    /// Sideways acceleration applied to arrows (units per second squared).
+    pub strength: f32,
+}
+
+impl Wind {
+    /// Creates wind blowing toward `angle` with the given strength.
+    pub const fn new(angle: f32, strength: f32) -> Self {
+        Self { angle, strength }
+    }
+
+    /// Horizontal unit vector the wind blows toward.
+    pub fn direction(&self) -> Vec3 {
+        Vec3::new(self.angle.cos(), 0.0, self.angle.sin())
+    }
+
+    /// Acceleration the wind applies to a projectile.
+    pub fn acceleration(&self) -> Vec3 {
+        self.direction() * self.strength
+    }
+}
+
+/// A faint mote carried by the wind to show its direction.
+#[derive(Component)]
+pub struct WindMote {
+    /// Seconds until the mote disappears.
+    pub lifetime: f32,
+}
@@ -0,0 +1,28 @@
+//! Wind constants.
+
+use bevy::prelude::*;
+
+/// Sideways acceleration wind applies to arrows (units per second squared).
+pub const WIND_STRENGTH: f32 = 60.0;
+
+/// How fast the wind can veer (radians per second).
+pub const WIND_VEER_RATE: f32 = 0.3;
+
+/// Wind motes spawned per second.
+pub const MOTE_SPAWN_RATE: f32 = 6.0;
+
+/// How long a wind mote drifts before disappearing (in seconds).
+pub const MOTE_LIFETIME: f32 = 3.0;
+
+/// Speed of wind motes per unit of wind strength.
+pub const MOTE_SPEED_PER_STRENGTH: f32 = 2.0;
+
+/// Height range wind motes drift at.
+pub const MOTE_MIN_HEIGHT: f32 = 20.0;
+pub const MOTE_MAX_HEIGHT: f32 = 120.0;
+
+/// Radius of a wind mote.
+pub const MOTE_RADIUS: f32 = 1.5;
+
+/// Wind mote color (faint white).
+pub const MOTE_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.35);
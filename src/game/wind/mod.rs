@@ -0,0 +1,12 @@
+//! Wind module.
+//!
+//! Optional environmental wind that pushes arrows sideways in flight, so
+//! volleys drift off their mark. Enabled from the settings menu and shown
+//! with faint motes drifting across the battlefield.
+
+pub mod components;
+pub mod constants;
+mod plugin;
+mod systems;
+
+pub use plugin::WindPlugin;
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::state::{AppState, InGameState};
+
+use super::components::Wind;
+use super::systems;
+
+/// Plugin that handles environmental wind.
+///
+/// Registers systems for:
+/// - Setting up the wind for each new game when enabled in the settings
+/// - Slowly veering the wind direction
+/// - Wind motes drifting across the battlefield
+///
+/// Arrows read the `Wind` resource in `move_arrows`.
+pub struct WindPlugin;
+
+impl Plugin for WindPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::InGame), systems::setup_wind)
+            .add_systems(OnExit(InGameState::GameOver), systems::setup_wind)
+            .add_systems(
+                Update,
+                (
+                    systems::veer_wind,
+                    systems::spawn_wind_motes,
+                    systems::drift_wind_motes,
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running))
+                    .run_if(resource_exists::<Wind>),
+            );
+    }
+}
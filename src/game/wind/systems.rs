@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::components::*;
+use super::constants::*;
+use crate::config::GameConfig;
+use crate::game::battlefield::components::BattlefieldConfig;
+use crate::game::components::{Billboard, OnGameplayScreen};
+
+/// Sets up the wind for a new game.
+///
+/// Blows from a random direction when enabled in the settings, otherwise
+/// removes the resource so arrows fly true.
+pub fn setup_wind(mut commands: Commands, config: Res<GameConfig>) {
+    if config.wind {
+        let angle = rand::thread_rng().gen_range(0.0..std::f32::consts::TAU);
+        commands.insert_resource(Wind::new(angle, WIND_STRENGTH));
+    } else {
+        commands.remove_resource::<Wind>();
+    }
+}
+
+/// Slowly veers the wind direction with a random walk.
+pub fn veer_wind(time: Res<Time>, mut wind: ResMut<Wind>) {
+    let veer = rand::thread_rng().gen_range(-1.0..1.0);
+    wind.angle += veer * WIND_VEER_RATE * time.delta_secs();
+}
+
+/// Spawns faint motes at random spots over the battlefield.
+pub fn spawn_wind_motes(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    time: Res<Time>,
+    battlefield: Res<BattlefieldConfig>,
+    mut spawn_timer: Local<f32>,
+) {
+    *spawn_timer += time.delta_secs() * MOTE_SPAWN_RATE;
+    if *spawn_timer < 1.0 {
+        return;
+    }
+
+    let mut rng = rand::thread_rng();
+    let center = battlefield.center();
+    let half_size = battlefield.size / 2.0;
+    let mesh = meshes.add(Circle::new(MOTE_RADIUS));
+
+    while *spawn_timer >= 1.0 {
+        *spawn_timer -= 1.0;
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: MOTE_COLOR,
+                unlit: true,
+                alpha_mode: AlphaMode::Blend,
+                ..default()
+            })),
+            Transform::from_xyz(
+                center.x + rng.gen_range(-half_size..half_size),
+                rng.gen_range(MOTE_MIN_HEIGHT..MOTE_MAX_HEIGHT),
+                center.z + rng.gen_range(-half_size..half_size),
+            ),
+            WindMote {
+                lifetime: MOTE_LIFETIME,
+            },
+            Billboard,
+            OnGameplayScreen,
+        ));
+    }
+}
+
+/// Carries motes along the wind and fades them out before despawning.
+pub fn drift_wind_motes(
+    mut commands: Commands,
+    time: Res<Time>,
+    wind: Res<Wind>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut motes: Query<(
+        Entity,
+        &mut WindMote,
+        &mut Transform,
+        &MeshMaterial3d<StandardMaterial>,
+    )>,
+) {
+    let delta = time.delta_secs();
+    let drift = wind.direction() * wind.strength * MOTE_SPEED_PER_STRENGTH * delta;
+
+    for (entity, mut mote, mut transform, material_handle) in &mut motes {
+        mote.lifetime -= delta;
+        if mote.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation += drift;
+        if let Some(material) = materials.get_mut(material_handle) {
+            let fade = mote.lifetime / MOTE_LIFETIME;
+            material.base_color = MOTE_COLOR.with_alpha(MOTE_COLOR.alpha() * fade);
+        }
+    }
+}
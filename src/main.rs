@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy::render::view::ColorGrading;
 use bevy::window::{Window, WindowPlugin, WindowResolution};
 
 mod config;
@@ -8,6 +9,7 @@ mod ui;
 
 use config::{ConfigPlugin, GameConfig};
 use game::GamePlugin;
+use game::battlefield::components::TimeOfDay;
 use state::StatePlugin;
 use ui::UiPlugin;
 
@@ -37,14 +39,11 @@ fn main() {
         .run();
 }
 
-/// Marker component for the brightness overlay.
-#[derive(Component)]
-struct BrightnessOverlay;
-
 /// Sets up the initial game scene.
 ///
 /// Spawns the primary 3D perspective camera positioned above the castle
-/// looking toward the horizon, and brightness overlay.
+/// looking toward the horizon. The camera's color grading carries the
+/// brightness setting.
 ///
 /// # Arguments
 ///
@@ -54,58 +53,41 @@ fn setup(mut commands: Commands) {
     // We can adjust this later once everything is positioned correctly
     commands.spawn((
         Camera3d::default(),
+        ColorGrading::default(),
         Transform::from_xyz(-1000.0, 2500.0, 2500.0) // Zoomed out further back and higher up, shifted left
             .looking_at(Vec3::new(0.0, 0.0, 0.0), Vec3::Y), // Looking at origin
     ));
-
-    // Spawn brightness overlay (a fullscreen node that adjusts screen brightness)
-    commands.spawn((
-        BrightnessOverlay,
-        Node {
-            position_type: PositionType::Absolute,
-            width: Val::Percent(100.0),
-            height: Val::Percent(100.0),
-            ..default()
-        },
-        BackgroundColor(Color::BLACK.with_alpha(0.0)),
-        GlobalZIndex(1000), // On top of everything
-        Pickable::IGNORE,   // Don't block pointer events
-    ));
 }
 
-/// Applies global brightness setting via overlay opacity.
+/// Lowest camera exposure once the time of day is added, so night at minimum
+/// brightness stays playable.
+const MIN_EXPOSURE: f32 = -4.0;
+
+/// Applies global brightness setting via camera exposure.
 ///
-/// This system updates the brightness overlay when the brightness setting changes.
-/// Uses a black overlay with varying opacity to darken the screen, or a white overlay
-/// to brighten it.
+/// This system updates the 3D camera's color grading exposure whenever the
+/// brightness setting or the battlefield time of day changes, so the change
+/// shows up live. Exposure is measured in stops, so brightness maps through log2,
+/// and the time of day adds its own offset (darker at night) and white balance.
 ///
 /// Brightness mapping:
-/// - brightness 0.1 → black overlay at 90% opacity (darkest, minimum to prevent soft-lock)
-/// - brightness 1.0 → no overlay (normal)
-/// - brightness 2.0 → white overlay at 50% opacity (brightest)
+/// - brightness 0.1 → about -3.3 EV (darkest, minimum to prevent soft-lock)
+/// - brightness 1.0 → 0 EV (normal)
+/// - brightness 2.0 → +1 EV (brightest)
 fn apply_global_brightness(
     config: Res<GameConfig>,
-    mut overlay: Query<&mut BackgroundColor, With<BrightnessOverlay>>,
+    time_of_day: Res<TimeOfDay>,
+    mut cameras: Query<&mut ColorGrading, With<Camera3d>>,
 ) {
-    if !config.is_changed() {
+    if !config.is_changed() && !time_of_day.is_changed() {
         return;
     }
 
     let brightness = config.brightness.clamp(0.1, 2.0);
+    let lighting = time_of_day.lighting();
 
-    if let Ok(mut bg) = overlay.single_mut() {
-        if brightness < 1.0 {
-            // Darken: black overlay with alpha based on how far below 1.0
-            // At 0.1 brightness, alpha = 0.9 (90% dark)
-            let alpha = 1.0 - brightness;
-            *bg = BackgroundColor(Color::BLACK.with_alpha(alpha));
-        } else if brightness > 1.0 {
-            // Brighten: white overlay with alpha based on how far above 1.0
-            let alpha = (brightness - 1.0) * 0.5; // Max 50% white overlay at brightness 2.0
-            *bg = BackgroundColor(Color::WHITE.with_alpha(alpha));
-        } else {
-            // Normal brightness: transparent overlay
-            *bg = BackgroundColor(Color::BLACK.with_alpha(0.0));
-        }
+    for mut color_grading in &mut cameras {
+        color_grading.global.exposure = (brightness.log2() + lighting.exposure).max(MIN_EXPOSURE);
+        color_grading.global.temperature = lighting.temperature;
     }
 }
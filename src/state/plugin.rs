@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::states::{AppState, InGameState, MenuState, PauseMenuState};
+use super::states::{AppState, InGameState, MenuBackground, MenuState, PauseMenuState};
 
 /// Manages all game states.
 ///
@@ -72,6 +72,7 @@ impl Plugin for StatePlugin {
         app.add_sub_state::<MenuState>();
         app.add_sub_state::<InGameState>();
         app.add_sub_state::<PauseMenuState>();
+        app.add_sub_state::<MenuBackground>();
 
         // Optional: Add state transition logging for debugging
         #[cfg(debug_assertions)]
@@ -7,7 +7,7 @@ use bevy::prelude::*;
 ///
 /// # State Transitions
 ///
-/// - `MainMenu` → `InGame`: Player starts a new game
+/// - `MainMenu` → `InGame`: Player starts a battle from the loadout screen
 /// - `InGame` → `MainMenu`: Player quits to main menu from pause or game over
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
 #[allow(dead_code)] // Variants will be used as game features are implemented
@@ -41,13 +41,40 @@ pub enum MenuState {
     /// Settings submenu.
     Settings,
 
+    /// Pre-battle spell loadout screen, shown after Start Game.
+    Loadout,
+
     /// Changelog screen.
     Changelog,
 
+    /// Lifetime per-spell usage statistics.
+    Statistics,
+
     /// Credits screen.
     Credits,
 }
 
+/// Main menu background sub-state.
+///
+/// This is a SubState that only exists when AppState::MainMenu is active, so
+/// leaving the main menu always exits `Playing` and cleans up the idle battle
+/// before gameplay starts.
+///
+/// # State Transitions
+///
+/// - `Hidden` → `Playing`: The menu battle setting is on
+/// - `Playing` → `Hidden`: The menu battle setting is turned off
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, SubStates)]
+#[source(AppState = AppState::MainMenu)]
+pub enum MenuBackground {
+    /// No battle behind the menu.
+    #[default]
+    Hidden,
+
+    /// A small ambient battle plays behind the menu.
+    Playing,
+}
+
 /// InGame sub-state.
 ///
 /// This is a SubState that only exists when AppState::InGame is active.
@@ -59,7 +86,8 @@ pub enum MenuState {
 /// - `Paused` → `Running`: Player selects Continue from pause menu
 /// - `Running` → `SpellBook`: Player clicks Spells button
 /// - `SpellBook` → `Running`: Player selects a spell or closes spell book
-/// - `Running` → `GameOver`: Game ends (win or lose)
+/// - `Running` → `GameOver`: Game ends (defenders or King lost; cleared waves
+///   continue in `Running` via the campaign intermission)
 /// - `GameOver` → `Running`: Player clicks Play Again
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, SubStates)]
 #[source(AppState = AppState::InGame)]
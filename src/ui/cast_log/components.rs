@@ -0,0 +1,85 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::game::units::wizard::components::Spell;
+
+/// A single resolved spell cast.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CastLogEntry {
+    /// Battle time of the cast in seconds.
+    pub time: f32,
+    pub spell: Spell,
+    pub mana_cost: f32,
+    /// Number of units hit or otherwise affected.
+    pub affected: u32,
+}
+
+impl CastLogEntry {
+    /// Returns the line shown in the log (e.g. "12.3s Fireball — 8 hit (30 mana)").
+    pub fn label(&self) -> String {
+        format!(
+            "{:.1}s {} — {} hit ({:.0} mana)",
+            self.time,
+            self.spell.name(),
+            self.affected,
+            self.mana_cost
+        )
+    }
+}
+
+/// Recent spell casts for the current battle, oldest first.
+#[derive(Resource, Default)]
+pub struct SpellCastLog {
+    pub entries: VecDeque<CastLogEntry>,
+    /// Whether the log panel is shown.
+    pub visible: bool,
+}
+
+impl SpellCastLog {
+    /// Appends an entry, dropping the oldest once `capacity` is reached.
+    pub fn push(&mut self, entry: CastLogEntry, capacity: usize) {
+        if self.entries.len() >= capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+}
+
+/// Marker component for the cast log panel.
+#[derive(Component)]
+pub struct CastLogPanel;
+
+/// Marker component for the scrollable list of log entries.
+#[derive(Component)]
+pub struct ScrollableCastLog;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(time: f32) -> CastLogEntry {
+        CastLogEntry {
+            time,
+            spell: Spell::Fireball,
+            mana_cost: 30.0,
+            affected: 8,
+        }
+    }
+
+    #[test]
+    fn test_log_drops_oldest_entry_at_capacity() {
+        let mut log = SpellCastLog::default();
+        for time in [1.0, 2.0, 3.0] {
+            log.push(entry(time), 2);
+        }
+
+        let times: Vec<f32> = log.entries.iter().map(|entry| entry.time).collect();
+        assert_eq!(times, vec![2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_entry_label() {
+        assert_eq!(entry(12.34).label(), "12.3s Fireball — 8 hit (30 mana)");
+    }
+}
@@ -0,0 +1,36 @@
+//! Spell cast log constants.
+
+use bevy::prelude::*;
+
+/// Key that shows or hides the cast log.
+pub const TOGGLE_KEY: KeyCode = KeyCode::KeyL;
+
+/// Maximum entries kept in the log (oldest are dropped first).
+pub const MAX_LOG_ENTRIES: usize = 50;
+
+/// Distance of the panel from the left edge of the screen.
+pub const PANEL_LEFT: Val = Val::Px(12.0);
+
+/// Distance of the panel from the top of the screen.
+pub const PANEL_TOP: Val = Val::Percent(30.0);
+
+/// Width of the panel.
+pub const PANEL_WIDTH: Val = Val::Px(300.0);
+
+/// Height of the panel (entries beyond this scroll).
+pub const PANEL_HEIGHT: Val = Val::Px(220.0);
+
+/// Padding inside the panel.
+pub const PANEL_PADDING: Val = Val::Px(8.0);
+
+/// Space between entries.
+pub const ENTRY_GAP: Val = Val::Px(2.0);
+
+/// Font size for log entries.
+pub const ENTRY_FONT_SIZE: f32 = 14.0;
+
+/// Background color of the panel.
+pub const PANEL_BACKGROUND_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+
+/// Text color of log entries.
+pub const ENTRY_TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
@@ -0,0 +1,9 @@
+//! In-battle spell cast history.
+//!
+//! Keeps a short scrolling log of recent casts with their timestamp, mana cost,
+//! and how many units they affected.
+
+mod components;
+mod constants;
+pub(super) mod plugin;
+mod systems;
@@ -0,0 +1,37 @@
+//! Spell cast log plugin.
+
+use bevy::prelude::*;
+
+use crate::state::{AppState, InGameState};
+
+use super::components::SpellCastLog;
+use super::systems;
+
+/// Plugin that records spell casts and shows them in a toggleable log.
+///
+/// Registers systems for:
+/// - Clearing the log on game start and replay
+/// - Recording each resolved cast with the battle time
+/// - Toggling the panel and keeping its entries up to date
+/// - Scrolling the panel with the mouse wheel
+#[derive(Default)]
+pub struct CastLogPlugin;
+
+impl Plugin for CastLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpellCastLog>()
+            .add_systems(OnEnter(AppState::InGame), systems::reset_cast_log)
+            .add_systems(OnExit(InGameState::GameOver), systems::reset_cast_log)
+            .add_systems(
+                Update,
+                (
+                    systems::record_spell_casts,
+                    systems::toggle_cast_log,
+                    systems::update_cast_log_panel,
+                    systems::handle_cast_log_scroll,
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running)),
+            );
+    }
+}
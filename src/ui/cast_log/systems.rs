@@ -0,0 +1,167 @@
+use bevy::ecs::relationship::Relationship;
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::*;
+use crate::config::GameConfig;
+use crate::game::components::OnGameplayScreen;
+use crate::game::resources::BattleTimer;
+use crate::game::units::wizard::components::SpellCastRecord;
+use crate::ui::systems::wheel_scroll_delta;
+
+/// Clears the log at the start of each battle.
+pub fn reset_cast_log(mut log: ResMut<SpellCastLog>) {
+    log.entries.clear();
+}
+
+/// Toggles the cast log panel with its hotkey.
+pub fn toggle_cast_log(keys: Res<ButtonInput<KeyCode>>, mut log: ResMut<SpellCastLog>) {
+    if keys.just_pressed(TOGGLE_KEY) {
+        log.visible = !log.visible;
+    }
+}
+
+/// Adds each resolved spell cast to the log, stamped with the battle time.
+pub fn record_spell_casts(
+    mut records: MessageReader<SpellCastRecord>,
+    battle_timer: Res<BattleTimer>,
+    mut log: ResMut<SpellCastLog>,
+) {
+    for record in records.read() {
+        log.push(
+            CastLogEntry {
+                time: battle_timer.elapsed,
+                spell: record.spell,
+                mana_cost: record.mana_cost,
+                affected: record.affected,
+            },
+            MAX_LOG_ENTRIES,
+        );
+    }
+}
+
+/// Shows or hides the panel and rebuilds its entries when the log changes.
+///
+/// Also respawns the panel after the gameplay screen is cleaned up for a replay.
+pub fn update_cast_log_panel(
+    mut commands: Commands,
+    log: Res<SpellCastLog>,
+    mut panel_query: Query<&mut Node, With<CastLogPanel>>,
+    list_query: Query<Entity, With<ScrollableCastLog>>,
+) {
+    let Ok(mut panel_node) = panel_query.single_mut() else {
+        spawn_cast_log_panel(&mut commands, &log);
+        return;
+    };
+
+    if !log.is_changed() {
+        return;
+    }
+
+    panel_node.display = if log.visible {
+        Display::Flex
+    } else {
+        Display::None
+    };
+
+    if let Ok(list) = list_query.single() {
+        commands.entity(list).despawn_related::<Children>();
+        commands.entity(list).with_children(|list| {
+            spawn_entries(list, &log);
+        });
+    }
+}
+
+/// Handles mouse wheel scrolling for the cast log.
+pub fn handle_cast_log_scroll(
+    mut mouse_wheel_events: MessageReader<MouseWheel>,
+    config: Res<GameConfig>,
+    hover_map: Res<bevy::picking::hover::HoverMap>,
+    mut scrollable_query: Query<(&mut ScrollPosition, &ComputedNode), With<ScrollableCastLog>>,
+    parent_query: Query<&ChildOf>,
+) {
+    for event in mouse_wheel_events.read() {
+        let dy = wheel_scroll_delta(event, &config);
+
+        for pointer_map in hover_map.values() {
+            for (hovered_entity, _) in pointer_map.iter() {
+                let mut current_entity = *hovered_entity;
+                loop {
+                    if let Ok((mut scroll_position, computed)) =
+                        scrollable_query.get_mut(current_entity)
+                    {
+                        let visible_size = computed.size();
+                        let content_size = computed.content_size();
+                        let max_scroll = (content_size.y - visible_size.y).max(0.0)
+                            * computed.inverse_scale_factor();
+
+                        scroll_position.y = (scroll_position.y + dy).clamp(0.0, max_scroll);
+                        break;
+                    }
+
+                    if let Ok(parent) = parent_query.get(current_entity) {
+                        current_entity = parent.get();
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Spawns the log panel on the left side of the screen.
+fn spawn_cast_log_panel(commands: &mut Commands, log: &SpellCastLog) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: PANEL_LEFT,
+                top: PANEL_TOP,
+                width: PANEL_WIDTH,
+                height: PANEL_HEIGHT,
+                padding: UiRect::all(PANEL_PADDING),
+                display: if log.visible {
+                    Display::Flex
+                } else {
+                    Display::None
+                },
+                ..default()
+            },
+            BackgroundColor(PANEL_BACKGROUND_COLOR),
+            CastLogPanel,
+            OnGameplayScreen,
+        ))
+        .with_children(|panel| {
+            panel
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        row_gap: ENTRY_GAP,
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                    ScrollPosition::default(),
+                    ScrollableCastLog,
+                ))
+                .with_children(|list| {
+                    spawn_entries(list, log);
+                });
+        });
+}
+
+/// Spawns one text line per entry, newest first.
+fn spawn_entries(list: &mut ChildSpawnerCommands, log: &SpellCastLog) {
+    for entry in log.entries.iter().rev() {
+        list.spawn((
+            Text::new(entry.label()),
+            TextFont {
+                font_size: ENTRY_FONT_SIZE,
+                ..default()
+            },
+            TextColor(ENTRY_TEXT_COLOR),
+        ));
+    }
+}
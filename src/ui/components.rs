@@ -14,10 +14,10 @@ pub struct ButtonColors {
     pub border: Color,
 }
 
-/// Configuration for button dimensions and styling.
+/// Configuration for button dimensions and font size.
 ///
-/// Pass this to `spawn_button` to control button size, font, and colors.
-/// Each screen can define its own `ButtonStyle` constant.
+/// Pass this to `spawn_button` to control button size and font. Colors come
+/// from the active `UiPalette`. Each screen can define its own `ButtonStyle` constant.
 pub struct ButtonStyle {
     /// Button width in pixels.
     pub width: f32,
@@ -27,10 +27,4 @@ pub struct ButtonStyle {
     pub border_width: f32,
     /// Font size for button text.
     pub font_size: f32,
-    /// Background color.
-    pub background: Color,
-    /// Border color.
-    pub border: Color,
-    /// Text color.
-    pub text_color: Color,
 }
@@ -0,0 +1,12 @@
+//! Floating damage number components.
+
+use bevy::prelude::*;
+
+/// A floating damage number that rises from the unit that was hit and fades out.
+#[derive(Component)]
+pub struct DamageNumber {
+    /// World position the text is anchored to (rises over time).
+    pub world_position: Vec3,
+    /// Seconds since the number was spawned.
+    pub age: f32,
+}
@@ -0,0 +1,53 @@
+//! Floating damage number constants.
+
+use bevy::prelude::*;
+
+/// How long each damage number stays on screen in seconds.
+pub const LIFETIME: f32 = 1.0;
+
+/// How fast damage numbers rise in world units per second.
+pub const RISE_SPEED: f32 = 60.0;
+
+/// Height above the unit's center where damage numbers appear.
+pub const SPAWN_HEIGHT_OFFSET: f32 = 30.0;
+
+/// Maximum damage numbers on screen at once (extra hits are not shown).
+pub const MAX_DAMAGE_NUMBERS: usize = 150;
+
+/// Font size for damage numbers.
+pub const FONT_SIZE: f32 = 16.0;
+
+/// Damage at or above this is shown as a medium hit.
+pub const MEDIUM_HIT_THRESHOLD: f32 = 20.0;
+
+/// Damage at or above this is shown as a big hit.
+pub const BIG_HIT_THRESHOLD: f32 = 100.0;
+
+/// Color for small hits (melee, arrows, missiles).
+pub const SMALL_HIT_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+
+/// Color for medium hits.
+pub const MEDIUM_HIT_COLOR: Color = Color::srgb(1.0, 0.6, 0.1);
+
+/// Color for big spell hits.
+pub const BIG_HIT_COLOR: Color = Color::srgb(1.0, 0.2, 0.15);
+
+/// Font size for spell combo callouts.
+pub const COMBO_FONT_SIZE: f32 = 20.0;
+
+/// Color for spell combo callouts.
+pub const COMBO_COLOR: Color = Color::srgb(0.5, 0.9, 1.0);
+
+/// Height above the unit's center where combo callouts appear (above the damage number).
+pub const COMBO_HEIGHT_OFFSET: f32 = 55.0;
+
+/// Returns the text color for a hit of the given size.
+pub fn damage_color(amount: f32) -> Color {
+    if amount >= BIG_HIT_THRESHOLD {
+        BIG_HIT_COLOR
+    } else if amount >= MEDIUM_HIT_THRESHOLD {
+        MEDIUM_HIT_COLOR
+    } else {
+        SMALL_HIT_COLOR
+    }
+}
@@ -0,0 +1,8 @@
+//! Floating combat text.
+//!
+//! Shows the damage dealt above units when they are hit.
+
+mod components;
+mod constants;
+pub(super) mod plugin;
+mod systems;
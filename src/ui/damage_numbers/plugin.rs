@@ -0,0 +1,31 @@
+//! Floating damage numbers plugin.
+
+use bevy::prelude::*;
+
+use crate::state::InGameState;
+
+use super::systems;
+
+/// Plugin that shows floating damage numbers above units that take damage.
+///
+/// Registers systems for:
+/// - Spawning a number for each `DamageDealt` message (if enabled in settings)
+/// - Spawning a callout for each `ComboTriggered` message
+/// - Rising, fading, and despawning numbers during gameplay
+#[derive(Default)]
+pub struct DamageNumbersPlugin;
+
+impl Plugin for DamageNumbersPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                systems::spawn_damage_numbers,
+                systems::spawn_combo_callouts,
+                systems::animate_damage_numbers,
+            )
+                .chain()
+                .run_if(in_state(InGameState::Running)),
+        );
+    }
+}
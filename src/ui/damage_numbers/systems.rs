@@ -0,0 +1,149 @@
+//! Floating damage number systems.
+
+use bevy::prelude::*;
+
+use super::components::DamageNumber;
+use super::constants::*;
+use crate::config::GameConfig;
+use crate::game::components::OnGameplayScreen;
+use crate::game::units::components::{ComboTriggered, DamageDealt};
+
+/// Spawns a damage number for each hit reported this frame.
+///
+/// Does nothing (and drops the messages) when damage numbers are disabled.
+pub fn spawn_damage_numbers(
+    mut commands: Commands,
+    mut damage_dealt: MessageReader<DamageDealt>,
+    config: Res<GameConfig>,
+    existing: Query<(), With<DamageNumber>>,
+) {
+    if !config.show_damage_numbers {
+        damage_dealt.clear();
+        return;
+    }
+
+    let mut count = existing.iter().count();
+
+    for hit in damage_dealt.read() {
+        if count >= MAX_DAMAGE_NUMBERS {
+            continue;
+        }
+        count += 1;
+
+        commands.spawn((
+            Text::new(format!("{}", hit.amount.round().max(1.0))),
+            TextFont {
+                font_size: FONT_SIZE,
+                ..default()
+            },
+            TextColor(damage_color(hit.amount)),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            // Hidden until positioned on screen by animate_damage_numbers
+            Visibility::Hidden,
+            Pickable::IGNORE,
+            DamageNumber {
+                world_position: hit.position + Vec3::Y * SPAWN_HEIGHT_OFFSET,
+                age: 0.0,
+            },
+            OnGameplayScreen,
+        ));
+    }
+}
+
+/// Spawns a floating callout (e.g. "Shatter! +5") for each spell combo this frame.
+///
+/// Shares the damage number setting, cap, and animation.
+pub fn spawn_combo_callouts(
+    mut commands: Commands,
+    mut combo_triggered: MessageReader<ComboTriggered>,
+    config: Res<GameConfig>,
+    existing: Query<(), With<DamageNumber>>,
+) {
+    if !config.show_damage_numbers {
+        combo_triggered.clear();
+        return;
+    }
+
+    let mut count = existing.iter().count();
+
+    for combo in combo_triggered.read() {
+        if count >= MAX_DAMAGE_NUMBERS {
+            continue;
+        }
+        count += 1;
+
+        commands.spawn((
+            Text::new(format!(
+                "{}! +{}",
+                combo.name,
+                combo.bonus_damage.round().max(1.0)
+            )),
+            TextFont {
+                font_size: COMBO_FONT_SIZE,
+                ..default()
+            },
+            TextColor(COMBO_COLOR),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            // Hidden until positioned on screen by animate_damage_numbers
+            Visibility::Hidden,
+            Pickable::IGNORE,
+            DamageNumber {
+                world_position: combo.position + Vec3::Y * COMBO_HEIGHT_OFFSET,
+                age: 0.0,
+            },
+            OnGameplayScreen,
+        ));
+    }
+}
+
+/// Moves damage numbers upward, fades them out, and despawns them after their lifetime.
+///
+/// Numbers are anchored in world space and projected to the screen each frame.
+pub fn animate_damage_numbers(
+    mut commands: Commands,
+    time: Res<Time>,
+    ui_scale: Res<UiScale>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut numbers: Query<(
+        Entity,
+        &mut DamageNumber,
+        &mut Node,
+        &mut TextColor,
+        &mut Visibility,
+    )>,
+) {
+    let Ok((camera, camera_transform)) = camera_query.single() else {
+        return;
+    };
+
+    for (entity, mut number, mut node, mut color, mut visibility) in &mut numbers {
+        number.age += time.delta_secs();
+        if number.age >= LIFETIME {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        number.world_position.y += RISE_SPEED * time.delta_secs();
+
+        let Ok(screen_position) = camera.world_to_viewport(camera_transform, number.world_position)
+        else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        // Viewport coordinates are logical pixels; UI Px values are scaled by UiScale
+        node.left = Val::Px(screen_position.x / ui_scale.0);
+        node.top = Val::Px(screen_position.y / ui_scale.0);
+        *visibility = Visibility::Inherited;
+
+        // Fade out over the lifetime, staying readable for the first half
+        let progress = number.age / LIFETIME;
+        color.0 = color.0.with_alpha(1.0 - progress * progress);
+    }
+}
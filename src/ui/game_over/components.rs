@@ -9,4 +9,20 @@ pub struct OnGameOverScreen;
 pub enum GameOverButtonAction {
     PlayAgain,
     ReturnToMenu,
+    CopySummary,
+    ExportScreenshot,
 }
+
+/// Plain-text summary of the finished battle, built when the screen opens.
+///
+/// Captured up front because the level moves on right after the screen is shown.
+#[derive(Resource)]
+pub struct BattleSummary {
+    pub text: String,
+    /// The level that was just played.
+    pub level: u32,
+}
+
+/// Marker for the line reporting whether the summary was copied or the screenshot saved.
+#[derive(Component)]
+pub struct ShareStatusText;
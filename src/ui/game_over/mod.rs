@@ -1,5 +1,6 @@
 pub mod components;
 pub mod plugin;
+mod share;
 pub mod styles;
 pub mod systems;
 
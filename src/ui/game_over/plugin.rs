@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
-use crate::state::InGameState;
+use crate::game::run_conditions;
+use crate::state::{AppState, InGameState};
 
 use super::systems::*;
 
@@ -11,13 +12,23 @@ impl Plugin for GameOverPlugin {
         app.add_systems(
             OnEnter(InGameState::GameOver),
             (
-                save_efficiency_to_config,
+                // Levels, efficiency, and high scores only apply to the campaign
+                save_efficiency_to_config.run_if(run_conditions::in_campaign_mode),
+                record_high_scores.run_if(run_conditions::in_campaign_mode),
                 setup_game_over_screen,
-                update_level_after_display,
+                update_level_after_display.run_if(run_conditions::in_campaign_mode),
             )
                 .chain(),
         )
-        .add_systems(OnExit(InGameState::GameOver), cleanup_game_over_screen)
+        .add_systems(
+            OnExit(InGameState::GameOver),
+            (cleanup_game_over_screen, start_high_score_game),
+        )
+        .add_systems(OnEnter(AppState::InGame), start_high_score_game)
+        .add_systems(
+            OnExit(AppState::InGame),
+            record_high_scores.run_if(run_conditions::in_campaign_mode),
+        )
         .add_systems(
             Update,
             handle_button_actions.run_if(in_state(InGameState::GameOver)),
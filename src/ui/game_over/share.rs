@@ -0,0 +1,52 @@
+//! Sharing the game over summary: clipboard text and screenshots.
+
+use std::path::PathBuf;
+
+/// Folder screenshots are saved to (a download in the browser).
+pub const SCREENSHOT_DIR: &str = "screenshots";
+
+/// Copies `text` to the system clipboard.
+///
+/// The browser write finishes asynchronously, so success only means the copy
+/// was requested. Fails when the page has no clipboard access (it needs a
+/// secure context).
+#[cfg(target_arch = "wasm32")]
+pub fn copy_to_clipboard(text: &str) -> Result<(), &'static str> {
+    let window = web_sys::window().ok_or("No window object")?;
+    let clipboard = window.navigator().clipboard();
+    // `navigator.clipboard` is missing on pages served over plain http
+    if clipboard.is_undefined() {
+        return Err("Clipboard not available");
+    }
+    let _ = clipboard.write_text(text);
+    Ok(())
+}
+
+/// Copies `text` to the system clipboard.
+///
+/// Desktop builds have no clipboard backend, so this always fails and the
+/// caller falls back to logging the text.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn copy_to_clipboard(_text: &str) -> Result<(), &'static str> {
+    Err("Clipboard not available in desktop builds")
+}
+
+/// Returns where to save a screenshot of the game over screen for `level`.
+///
+/// Desktop builds stamp the file with the current time so earlier screenshots
+/// aren't overwritten, creating the folder if needed; the browser renames
+/// repeated downloads itself.
+pub fn screenshot_path(level: u32) -> PathBuf {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = std::fs::create_dir_all(SCREENSHOT_DIR);
+        let stamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        PathBuf::from(SCREENSHOT_DIR).join(format!("court_wizard_level_{level}_{stamp}.png"))
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        PathBuf::from(SCREENSHOT_DIR).join(format!("court_wizard_level_{level}.png"))
+    }
+}
@@ -1,13 +1,18 @@
 use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured, save_to_disk};
 
-use crate::config::{ConfigChanged, GameConfig};
+use crate::config::{ConfigChanged, GameConfig, GameMode, Scores};
+use crate::game::campaign::components::CampaignState;
 use crate::game::constants::INITIAL_DEFENDER_COUNT;
-use crate::game::resources::{CurrentLevel, GameOutcome, KillStats};
+use crate::game::endless::components::{EndlessState, endless_score};
+use crate::game::resources::{BattleTimer, CurrentLevel, GameOutcome, KillStats};
 use crate::game::units::archer::constants::INITIAL_ARCHER_DEFENDER_COUNT;
 use crate::state::{AppState, InGameState};
 use crate::ui::systems::spawn_button;
+use crate::ui::theme::{UiPalette, palette};
 
 use super::components::*;
+use super::share::{copy_to_clipboard, screenshot_path};
 use super::styles::*;
 
 /// Saves efficiency for current level to config when entering game over screen.
@@ -34,6 +39,28 @@ pub fn save_efficiency_to_config(
     config_events.write(ConfigChanged);
 }
 
+/// Records the current game in the persistent scores.
+///
+/// Runs when entering the game over screen, BEFORE setup_game_over_screen so
+/// the screen can highlight a new best, and again when leaving the battle so a
+/// game the player quits is recorded too.
+pub fn record_high_scores(
+    current_level: Res<CurrentLevel>,
+    kill_stats: Res<KillStats>,
+    mut scores: ResMut<Scores>,
+) {
+    scores.record(
+        current_level.0,
+        kill_stats.attackers_killed,
+        kill_stats.undead_killed,
+    );
+}
+
+/// Clears the previous game's new-best flag when a game starts.
+pub fn start_high_score_game(mut scores: ResMut<Scores>) {
+    scores.start_game();
+}
+
 /// Updates level and saves to config after game over screen is displayed.
 ///
 /// This system runs AFTER setup_game_over_screen so the UI shows the correct
@@ -53,7 +80,7 @@ pub fn update_level_after_display(
                 config.highest_level_achieved = current_level.0;
             }
         }
-        GameOutcome::Defeat | GameOutcome::DefeatKingDied => {
+        GameOutcome::Defeat | GameOutcome::DefeatKingDied | GameOutcome::DefeatCastleDestroyed => {
             // Drop one level, minimum 1
             current_level.0 = current_level.0.saturating_sub(1).max(1);
         }
@@ -66,18 +93,51 @@ pub fn update_level_after_display(
     config_events.write(ConfigChanged);
 }
 
+/// Spawns the game over screen.
+///
+/// The summary matches the game mode that was played: the campaign reports
+/// level progress and efficiency, endless mode reports time survived and score.
+#[allow(clippy::too_many_arguments)]
 pub fn setup_game_over_screen(
     mut commands: Commands,
     game_outcome: Res<GameOutcome>,
     kill_stats: Res<KillStats>,
     current_level: Res<CurrentLevel>,
     config: Res<GameConfig>,
+    scores: Res<Scores>,
+    campaign: Res<CampaignState>,
+    endless: Res<EndlessState>,
+    battle_timer: Res<BattleTimer>,
 ) {
+    let endless_mode = config.game_mode == GameMode::Endless;
+    let palette = palette(config.ui_theme);
+
     // Calculate current efficiency
     let total_defenders = (INITIAL_DEFENDER_COUNT + INITIAL_ARCHER_DEFENDER_COUNT) as f32;
     let defenders_lost = kill_stats.defenders_killed as f32;
     let current_efficiency = (1.0 - (defenders_lost / total_defenders)) * 100.0;
 
+    let enemy_kills = kill_stats.attackers_killed + kill_stats.undead_killed;
+    commands.insert_resource(BattleSummary {
+        level: current_level.0,
+        text: battle_summary(
+            *game_outcome,
+            endless_mode,
+            current_level.0,
+            &kill_stats,
+            &battle_timer,
+            if endless_mode {
+                (
+                    endless.waves_spawned,
+                    endless_score(battle_timer.elapsed, enemy_kills),
+                )
+            } else {
+                (campaign.waves_survived, campaign.score)
+            },
+            !endless_mode && scores.new_best,
+        ),
+    });
+
     // Root container (fullscreen, horizontal layout)
     commands
         .spawn((
@@ -107,8 +167,11 @@ pub fn setup_game_over_screen(
                 .with_children(|buttons| {
                     // Victory/Defeat title
                     let title_text = match *game_outcome {
+                        _ if endless_mode => "OVERRUN",
                         GameOutcome::Victory => "VICTORY",
-                        GameOutcome::Defeat | GameOutcome::DefeatKingDied => "DEFEAT",
+                        GameOutcome::Defeat
+                        | GameOutcome::DefeatKingDied
+                        | GameOutcome::DefeatCastleDestroyed => "DEFEAT",
                     };
 
                     buttons.spawn((
@@ -120,24 +183,45 @@ pub fn setup_game_over_screen(
                         TextColor(TITLE_COLOR),
                     ));
 
-                    // Subtext for King death
-                    if *game_outcome == GameOutcome::DefeatKingDied {
+                    // Subtext for King death or the castle falling
+                    let defeat_reason = match *game_outcome {
+                        GameOutcome::DefeatKingDied => Some("The King died!"),
+                        GameOutcome::DefeatCastleDestroyed => Some("The castle has fallen!"),
+                        GameOutcome::Victory | GameOutcome::Defeat => None,
+                    };
+                    if let Some(reason) = defeat_reason {
                         buttons.spawn((
-                            Text::new("The King died!"),
+                            Text::new(reason),
                             TextFont {
                                 font_size: 24.0,
                                 ..default()
                             },
-                            TextColor(TEXT_COLOR),
+                            TextColor(palette.text),
+                        ));
+                    }
+
+                    // Highlight when a personal best was beaten this game
+                    // (scores are only recorded for the campaign)
+                    if !endless_mode && scores.new_best {
+                        buttons.spawn((
+                            Text::new("New Best!"),
+                            TextFont {
+                                font_size: 32.0,
+                                ..default()
+                            },
+                            TextColor(NEW_BEST_COLOR),
                         ));
                     }
 
                     // Play Again button with level progression indicator
                     let button_text = match *game_outcome {
+                        _ if endless_mode => "Play Again".to_string(),
                         GameOutcome::Victory => {
                             format!("Advance to Level {}", current_level.0 + 1)
                         }
-                        GameOutcome::Defeat | GameOutcome::DefeatKingDied => {
+                        GameOutcome::Defeat
+                        | GameOutcome::DefeatKingDied
+                        | GameOutcome::DefeatCastleDestroyed => {
                             let next_level = current_level.0.saturating_sub(1).max(1);
                             if next_level < current_level.0 {
                                 format!("Drop to Level {}", next_level)
@@ -152,6 +236,7 @@ pub fn setup_game_over_screen(
                         &button_text,
                         GameOverButtonAction::PlayAgain,
                         &BUTTON_STYLE,
+                        &palette.menu_buttons(),
                     );
 
                     // Return to Menu button
@@ -160,7 +245,33 @@ pub fn setup_game_over_screen(
                         "Return to Menu",
                         GameOverButtonAction::ReturnToMenu,
                         &BUTTON_STYLE,
+                        &palette.menu_buttons(),
                     );
+
+                    // Sharing
+                    spawn_button(
+                        buttons,
+                        "Copy Summary",
+                        GameOverButtonAction::CopySummary,
+                        &BUTTON_STYLE,
+                        &palette.menu_buttons(),
+                    );
+                    spawn_button(
+                        buttons,
+                        "Export Screenshot",
+                        GameOverButtonAction::ExportScreenshot,
+                        &BUTTON_STYLE,
+                        &palette.menu_buttons(),
+                    );
+                    buttons.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(palette.text),
+                        ShareStatusText,
+                    ));
                 });
 
             // Right column - Statistics
@@ -173,15 +284,32 @@ pub fn setup_game_over_screen(
                     ..default()
                 })
                 .with_children(|stats| {
-                    // Current Level
-                    stats.spawn((
-                        Text::new(format!("Current Level: {}", current_level.0)),
-                        TextFont {
-                            font_size: 28.0,
-                            ..default()
-                        },
-                        TextColor(TITLE_COLOR),
-                    ));
+                    if endless_mode {
+                        spawn_endless_summary(stats, &endless, &battle_timer, &kill_stats, palette);
+                    } else {
+                        // Current Level
+                        stats.spawn((
+                            Text::new(format!("Current Level: {}", current_level.0)),
+                            TextFont {
+                                font_size: 28.0,
+                                ..default()
+                            },
+                            TextColor(TITLE_COLOR),
+                        ));
+
+                        // Campaign progress
+                        stats.spawn((
+                            Text::new(format!(
+                                "Waves Survived: {}  Score: {}",
+                                campaign.waves_survived, campaign.score
+                            )),
+                            TextFont {
+                                font_size: 24.0,
+                                ..default()
+                            },
+                            TextColor(palette.text),
+                        ));
+                    }
 
                     // Kill Statistics header
                     stats.spawn((
@@ -190,7 +318,7 @@ pub fn setup_game_over_screen(
                             font_size: 24.0,
                             ..default()
                         },
-                        TextColor(TEXT_COLOR),
+                        TextColor(palette.text),
                     ));
 
                     stats.spawn((
@@ -199,7 +327,7 @@ pub fn setup_game_over_screen(
                             font_size: 20.0,
                             ..default()
                         },
-                        TextColor(TEXT_COLOR),
+                        TextColor(palette.text),
                     ));
 
                     stats.spawn((
@@ -211,7 +339,7 @@ pub fn setup_game_over_screen(
                             font_size: 20.0,
                             ..default()
                         },
-                        TextColor(TEXT_COLOR),
+                        TextColor(palette.text),
                     ));
 
                     stats.spawn((
@@ -220,9 +348,14 @@ pub fn setup_game_over_screen(
                             font_size: 20.0,
                             ..default()
                         },
-                        TextColor(TEXT_COLOR),
+                        TextColor(palette.text),
                     ));
 
+                    // Efficiency and past victories only apply to campaign levels
+                    if endless_mode {
+                        return;
+                    }
+
                     // Current efficiency
                     stats.spawn((
                         Text::new(format!("  Efficiency: {:.1}%", current_efficiency)),
@@ -230,7 +363,7 @@ pub fn setup_game_over_screen(
                             font_size: 20.0,
                             ..default()
                         },
-                        TextColor(TEXT_COLOR),
+                        TextColor(palette.text),
                     ));
 
                     // Past victory efficiency for current level (if exists)
@@ -243,7 +376,7 @@ pub fn setup_game_over_screen(
                                 font_size: 24.0,
                                 ..default()
                             },
-                            TextColor(TEXT_COLOR),
+                            TextColor(palette.text),
                         ));
 
                         stats.spawn((
@@ -256,17 +389,97 @@ pub fn setup_game_over_screen(
                                 font_size: 18.0,
                                 ..default()
                             },
-                            TextColor(TEXT_COLOR),
+                            TextColor(palette.text),
                         ));
                     }
                 });
         });
 }
 
+/// Formats the battle result as plain text for sharing.
+///
+/// `waves_and_score` is waves survived and campaign score, or waves reached and
+/// endless score in endless mode.
+pub fn battle_summary(
+    outcome: GameOutcome,
+    endless_mode: bool,
+    level: u32,
+    kill_stats: &KillStats,
+    battle_timer: &BattleTimer,
+    waves_and_score: (u32, u32),
+    new_best: bool,
+) -> String {
+    let (waves, score) = waves_and_score;
+    let mut lines = Vec::new();
+
+    if endless_mode {
+        lines.push("Court Wizard - Endless Horde - OVERRUN".to_string());
+        lines.push(format!("Time Survived: {}", battle_timer.display()));
+        lines.push(format!("Waves Reached: {waves}  Score: {score}"));
+    } else {
+        let result = match outcome {
+            GameOutcome::Victory => "VICTORY",
+            GameOutcome::Defeat => "DEFEAT",
+            GameOutcome::DefeatKingDied => "DEFEAT (the King died)",
+            GameOutcome::DefeatCastleDestroyed => "DEFEAT (the castle fell)",
+        };
+        lines.push(format!("Court Wizard - Level {level} - {result}"));
+        if new_best {
+            lines.push("New Best!".to_string());
+        }
+        lines.push(format!("Time: {}", battle_timer.display()));
+        lines.push(format!("Waves Survived: {waves}  Score: {score}"));
+    }
+
+    lines.push(format!(
+        "Attackers Killed: {}  Undead Killed: {}  Defenders Lost: {}",
+        kill_stats.attackers_killed, kill_stats.undead_killed, kill_stats.defenders_killed
+    ));
+    lines.join("\n")
+}
+
+/// Spawns the endless horde summary: time survived, waves reached, and score.
+fn spawn_endless_summary(
+    stats: &mut ChildSpawnerCommands,
+    endless: &EndlessState,
+    battle_timer: &BattleTimer,
+    kill_stats: &KillStats,
+    palette: &UiPalette,
+) {
+    let enemy_kills = kill_stats.attackers_killed + kill_stats.undead_killed;
+
+    stats.spawn((
+        Text::new(format!("Time Survived: {}", battle_timer.display())),
+        TextFont {
+            font_size: 28.0,
+            ..default()
+        },
+        TextColor(TITLE_COLOR),
+    ));
+
+    stats.spawn((
+        Text::new(format!(
+            "Waves Reached: {}  Score: {}",
+            endless.waves_spawned,
+            endless_score(battle_timer.elapsed, enemy_kills)
+        )),
+        TextFont {
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(palette.text),
+    ));
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn handle_button_actions(
+    mut commands: Commands,
+    summary: Option<Res<BattleSummary>>,
+    mut status_text: Query<&mut Text, With<ShareStatusText>>,
     mut next_app_state: ResMut<NextState<AppState>>,
     mut next_in_game_state: ResMut<NextState<InGameState>>,
     mut kill_stats: ResMut<KillStats>,
+    mut battle_timer: ResMut<BattleTimer>,
     interaction_query: Query<
         (&Interaction, &GameOverButtonAction),
         (Changed<Interaction>, With<Button>),
@@ -279,13 +492,50 @@ pub fn handle_button_actions(
                     // Reset stats and return to Running state
                     // (level was already updated and saved when entering GameOver state)
                     kill_stats.reset();
+                    battle_timer.reset();
                     next_in_game_state.set(InGameState::Running);
                 }
                 GameOverButtonAction::ReturnToMenu => {
                     // Reset stats and go to main menu (exits InGame state)
                     kill_stats.reset();
+                    battle_timer.reset();
                     next_app_state.set(AppState::MainMenu);
                 }
+                GameOverButtonAction::CopySummary => {
+                    let Some(summary) = summary.as_deref() else {
+                        continue;
+                    };
+                    let status = match copy_to_clipboard(&summary.text) {
+                        Ok(()) => "Summary copied to clipboard".to_string(),
+                        Err(reason) => {
+                            // Still leave the summary somewhere it can be copied from
+                            info!("Battle summary:\n{}", summary.text);
+                            format!("{reason} - summary written to the log")
+                        }
+                    };
+                    for mut text in &mut status_text {
+                        text.0.clone_from(&status);
+                    }
+                }
+                GameOverButtonAction::ExportScreenshot => {
+                    let Some(summary) = summary.as_deref() else {
+                        continue;
+                    };
+                    let path = screenshot_path(summary.level);
+                    let status = format!("Screenshot saved to {}", path.display());
+                    // Report only once captured, so the message isn't in the picture
+                    commands
+                        .spawn(Screenshot::primary_window())
+                        .observe(save_to_disk(path))
+                        .observe(
+                            move |_: On<ScreenshotCaptured>,
+                                  mut status_text: Query<&mut Text, With<ShareStatusText>>| {
+                                for mut text in &mut status_text {
+                                    text.0.clone_from(&status);
+                                }
+                            },
+                        );
+                }
             }
         }
     }
@@ -295,7 +545,43 @@ pub fn cleanup_game_over_screen(
     mut commands: Commands,
     query: Query<Entity, With<OnGameOverScreen>>,
 ) {
+    commands.remove_resource::<BattleSummary>();
+
     for entity in &query {
         commands.entity(entity).despawn();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_campaign_summary_lists_result_and_kills() {
+        let kill_stats = KillStats {
+            defenders_killed: 12,
+            attackers_killed: 140,
+            undead_killed: 3,
+        };
+        let battle_timer = BattleTimer { elapsed: 247.0 };
+
+        let summary = battle_summary(
+            GameOutcome::DefeatCastleDestroyed,
+            false,
+            7,
+            &kill_stats,
+            &battle_timer,
+            (2, 900),
+            true,
+        );
+
+        assert_eq!(
+            summary,
+            "Court Wizard - Level 7 - DEFEAT (the castle fell)\n\
+             New Best!\n\
+             Time: 4:07\n\
+             Waves Survived: 2  Score: 900\n\
+             Attackers Killed: 140  Undead Killed: 3  Defenders Lost: 12"
+        );
+    }
+}
@@ -1,21 +1,64 @@
 use bevy::prelude::*;
 
+use crate::game::sandbox::components::SandboxCommand;
+use crate::game::upgrades::components::Upgrade;
+
 /// Marker component for the HUD root container.
 #[derive(Component)]
 pub struct HudRoot;
 
-/// Marker component for the mana bar fill element.
+/// Mana bar fill element.
+#[derive(Component)]
+pub struct ManaBarFill {
+    /// Fill fraction currently shown (0.0 to 1.0), eased toward the wizard's mana.
+    pub displayed: f32,
+}
+
+/// Mana bar background, flashed red when a cast fails for lack of mana.
+#[derive(Component, Default)]
+pub struct ManaBarFlash {
+    /// Seconds left in the current flash (0.0 when not flashing).
+    pub remaining: f32,
+}
+
+/// Castle health bar fill element.
+#[derive(Component)]
+pub struct CastleHealthFill {
+    /// Fill fraction currently shown (0.0 to 1.0), eased toward the castle's health.
+    pub displayed: f32,
+}
+
+/// Cast bar fill element.
+#[derive(Component)]
+pub struct CastBarFill {
+    /// Fill fraction currently shown (0.0 to 1.0), eased toward the cast progress.
+    pub displayed: f32,
+}
+
+/// Ultimate charge bar fill element.
+#[derive(Component)]
+pub struct UltimateChargeFill {
+    /// Fill fraction currently shown (0.0 to 1.0), eased toward the ultimate charge.
+    pub displayed: f32,
+}
+
+/// Battle balance hint container (hidden unless enabled in the settings).
 #[derive(Component)]
-pub struct ManaBarFill;
+pub struct BattleBalanceDisplay;
 
-/// Marker component for the cast bar fill element.
+/// Battle balance bar fill element (the defenders' share of the bar).
 #[derive(Component)]
-pub struct CastBarFill;
+pub struct BattleBalanceFill {
+    /// Fill fraction currently shown (0.0 to 1.0), eased toward the balance estimate.
+    pub displayed: f32,
+}
 
 /// Actions that can be triggered by HUD buttons.
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HudButtonAction {
     OpenSpellBook,
+    BuyUpgrade(Upgrade),
+    Sandbox(SandboxCommand),
 }
 
 /// Marker component for the level display text.
@@ -25,3 +68,23 @@ pub struct LevelDisplay;
 /// Marker component for the past victory display text.
 #[derive(Component)]
 pub struct PastVictoryDisplay;
+
+/// Marker component for the wave and score display text.
+#[derive(Component)]
+pub struct WaveDisplay;
+
+/// Marker component for the preparation and between-waves countdown banner.
+#[derive(Component)]
+pub struct IntermissionBanner;
+
+/// Between-waves upgrade choices (hidden outside the intermission).
+#[derive(Component)]
+pub struct UpgradePanel;
+
+/// Marker component for the available upgrade points text.
+#[derive(Component)]
+pub struct UpgradePointsText;
+
+/// Practice sandbox controls (hidden outside practice games).
+#[derive(Component)]
+pub struct SandboxPanel;
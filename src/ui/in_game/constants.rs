@@ -15,6 +15,18 @@ pub const MANA_BAR_HEIGHT: Val = Val::Px(20.0);
 /// Mana bar colors.
 pub const MANA_BAR_BG_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.5); // 50% translucent black background
 pub const MANA_BAR_FILL_COLOR: Color = Color::srgba(0.2, 0.4, 1.0, 0.7); // 70% translucent blue
+pub const MANA_BAR_FLASH_COLOR: Color = Color::srgba(0.9, 0.1, 0.1, 0.8); // 80% translucent red on a rejected cast
+
+/// Seconds the mana bar stays flashed after a cast fails for lack of mana.
+pub const MANA_BAR_FLASH_DURATION: f32 = 0.35;
+
+/// Castle health bar dimensions.
+pub const CASTLE_BAR_WIDTH: Val = Val::Vw(33.33); // Matches the mana bar
+pub const CASTLE_BAR_HEIGHT: Val = Val::Px(12.0);
+
+/// Castle health bar colors.
+pub const CASTLE_BAR_BG_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.5); // 50% translucent black background
+pub const CASTLE_BAR_FILL_COLOR: Color = Color::srgba(0.75, 0.7, 0.6, 0.8); // 80% translucent stone grey
 
 /// Cast bar dimensions.
 pub const CAST_BAR_WIDTH: Val = Val::Vw(33.33); // 1/3 of screen width
@@ -23,16 +35,33 @@ pub const CAST_BAR_HEIGHT: Val = Val::Px(15.0);
 /// Cast bar colors.
 pub const CAST_BAR_BG_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.5); // 50% translucent black background
 pub const CAST_BAR_FILL_COLOR: Color = Color::srgba(1.0, 0.8, 0.0, 0.7); // 70% translucent yellow/gold
+pub const CAST_BAR_CHANNEL_COLOR: Color = Color::srgba(0.6, 0.3, 1.0, 0.8); // 80% translucent violet while channeling
+
+/// Ultimate charge bar dimensions.
+pub const ULTIMATE_BAR_WIDTH: Val = Val::Vw(33.33); // Matches the mana bar
+pub const ULTIMATE_BAR_HEIGHT: Val = Val::Px(10.0);
+
+/// Ultimate charge bar colors.
+pub const ULTIMATE_BAR_BG_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.5); // 50% translucent black background
+pub const ULTIMATE_BAR_FILL_COLOR: Color = Color::srgba(0.55, 0.75, 1.0, 0.6); // 60% translucent pale blue
+pub const ULTIMATE_BAR_READY_COLOR: Color = Color::srgba(0.85, 0.9, 1.0, 0.9); // 90% translucent white-blue when full
+
+/// Battle balance bar dimensions.
+pub const BALANCE_BAR_WIDTH: Val = Val::Vw(25.0);
+pub const BALANCE_BAR_HEIGHT: Val = Val::Px(12.0);
+
+/// Battle balance bar colors (defenders fill from the left, attackers fill the rest).
+pub const BALANCE_BAR_ATTACKERS_COLOR: Color = Color::srgba(0.9, 0.2, 0.2, 0.7); // 70% translucent red
+pub const BALANCE_BAR_DEFENDERS_COLOR: Color = Color::srgba(0.2, 0.5, 1.0, 0.7); // 70% translucent blue
+
+/// How quickly the bars ease toward their target value (higher = snappier).
+pub const BAR_SMOOTHING_RATE: f32 = 12.0;
 
 /// Button dimensions.
 pub const BUTTON_WIDTH: f32 = 120.0;
 pub const BUTTON_HEIGHT: f32 = 50.0;
 pub const BUTTON_BORDER_WIDTH: f32 = 2.0;
 
-/// Button colors.
-pub const BUTTON_BACKGROUND: Color = Color::srgb(0.15, 0.15, 0.15);
-pub const BUTTON_BORDER: Color = Color::srgb(0.4, 0.4, 0.4);
-pub const BUTTON_TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
 pub const BUTTON_FONT_SIZE: f32 = 24.0;
 
 /// Button style configuration for the in-game HUD.
@@ -41,7 +70,24 @@ pub const BUTTON_STYLE: ButtonStyle = ButtonStyle {
     height: BUTTON_HEIGHT,
     border_width: BUTTON_BORDER_WIDTH,
     font_size: BUTTON_FONT_SIZE,
-    background: BUTTON_BACKGROUND,
-    border: BUTTON_BORDER,
-    text_color: BUTTON_TEXT_COLOR,
 };
+
+/// Button style for the between-waves upgrade choices.
+pub const UPGRADE_BUTTON_STYLE: ButtonStyle = ButtonStyle {
+    width: 240.0,
+    height: 44.0,
+    border_width: BUTTON_BORDER_WIDTH,
+    font_size: 18.0,
+};
+
+/// Button style for the practice sandbox controls.
+pub const SANDBOX_BUTTON_STYLE: ButtonStyle = ButtonStyle {
+    width: 200.0,
+    height: 36.0,
+    border_width: BUTTON_BORDER_WIDTH,
+    font_size: 16.0,
+};
+
+/// Wheel scroll distance (see `wheel_scroll_delta`) that cycles the primed spell one step.
+/// One wheel notch at the default scroll speed.
+pub const SPELL_CYCLE_SCROLL_STEP: f32 = 10.0;
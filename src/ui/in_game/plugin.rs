@@ -10,9 +10,15 @@ use super::systems;
 /// Plugin that manages in-game UI and input handling.
 ///
 /// Registers systems for:
-/// - HUD spawning and updates
+/// - HUD spawning and updates (including the optional battle balance hint)
+/// - Recoloring the HUD buttons when the UI theme changes mid-battle
+/// - Flashing the mana bar when a cast fails for lack of mana
 /// - Re-spawning HUD when entering Running from GameOver (for replay)
 /// - Keyboard input during active gameplay (e.g., pause on Escape)
+/// - Cycling the primed spell with the mouse wheel
+/// - Pausing when the window loses focus
+/// - Between-waves upgrade choices
+/// - Practice sandbox controls
 #[derive(Default)]
 pub struct InGamePlugin;
 
@@ -23,16 +29,29 @@ impl Plugin for InGamePlugin {
                 OnEnter(InGameState::Running),
                 systems::spawn_hud.run_if(run_conditions::coming_from_game_over),
             )
+            .add_systems(
+                Update,
+                systems::retint_hud_buttons.run_if(in_state(AppState::InGame)),
+            )
             .add_systems(
                 Update,
                 (
                     systems::block_spell_input_on_button_interaction,
                     systems::keyboard_input,
+                    systems::cycle_spell_on_scroll,
+                    systems::pause_on_focus_loss,
                     systems::hud_button_action,
+                    systems::update_castle_health_bar,
                     systems::update_mana_bar,
+                    systems::flash_mana_bar,
                     systems::update_cast_bar,
+                    systems::update_ultimate_bar,
+                    systems::update_battle_balance_bar,
                     systems::update_level_display,
                     systems::update_past_victory_display,
+                    systems::update_campaign_display,
+                    systems::update_upgrade_panel,
+                    systems::update_sandbox_panel,
                 )
                     .run_if(in_state(InGameState::Running)),
             );
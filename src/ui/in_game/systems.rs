@@ -1,17 +1,31 @@
 //! In-game systems for input handling and HUD management.
 
 use bevy::input::keyboard::KeyCode;
+use bevy::input::mouse::MouseWheel;
+use bevy::picking::hover::HoverMap;
 use bevy::prelude::*;
+use bevy::window::WindowFocused;
 
 use super::components::*;
 use super::constants::*;
-use crate::config::GameConfig;
+use crate::config::{GameConfig, GameMode, UiTheme};
+use crate::game::battle_balance::components::BattleBalance;
+use crate::game::campaign::components::{CampaignState, WavePhase};
+use crate::game::castle::components::CastleHealth;
 use crate::game::components::OnGameplayScreen;
 use crate::game::input::events::BlockSpellInput;
 use crate::game::resources::CurrentLevel;
-use crate::game::units::wizard::components::{CastingState, Mana, PrimedSpell, Wizard};
+use crate::game::sandbox::components::{SandboxCommand, SandboxConfig};
+use crate::game::ultimate::components::UltimateCharge;
+use crate::game::units::wizard::components::{
+    CastRejectReason, CastRejected, CastingState, Mana, PrimeSpellMessage, PrimedSpell,
+    QueuedSpell, Wizard,
+};
+use crate::game::upgrades::components::{PurchaseUpgrade, Upgrade, WizardUpgrades};
 use crate::state::InGameState;
-use crate::ui::systems::spawn_button;
+use crate::ui::components::ButtonColors;
+use crate::ui::systems::{spawn_button, wheel_scroll_delta};
+use crate::ui::theme::palette;
 
 /// Marker component to track that a button was pressed down.
 #[derive(Component)]
@@ -33,15 +47,135 @@ pub fn block_spell_input_on_button_interaction(
     }
 }
 
+/// Recolors the HUD buttons when the UI theme is changed from the pause menu.
+///
+/// The HUD stays spawned through the pause menu, so unlike the menu screens
+/// it isn't rebuilt with the new palette.
+#[allow(clippy::type_complexity)]
+pub fn retint_hud_buttons(
+    config: Res<GameConfig>,
+    mut applied_theme: Local<Option<UiTheme>>,
+    mut buttons: Query<
+        (
+            &mut ButtonColors,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &Children,
+        ),
+        With<HudButtonAction>,
+    >,
+    mut texts: Query<&mut TextColor>,
+) {
+    if *applied_theme == Some(config.ui_theme) {
+        return;
+    }
+    *applied_theme = Some(config.ui_theme);
+
+    let palette = palette(config.ui_theme);
+    for (mut colors, mut background, mut border, children) in &mut buttons {
+        colors.background = palette.button_background;
+        colors.border = palette.button_border;
+        *background = palette.button_background.into();
+        *border = BorderColor::all(palette.button_border);
+        for child in children {
+            if let Ok(mut text_color) = texts.get_mut(*child) {
+                text_color.0 = palette.text;
+            }
+        }
+    }
+}
+
 /// Handles keyboard input during active gameplay.
 ///
 /// - Escape: Pause the game, transitioning to `InGameState::Paused`
+/// - B: Open the spell book, transitioning to `InGameState::SpellBook`
 pub fn keyboard_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut next_in_game_state: ResMut<NextState<InGameState>>,
 ) {
     if keyboard.just_pressed(KeyCode::Escape) {
         next_in_game_state.set(InGameState::Paused);
+    } else if keyboard.just_pressed(KeyCode::KeyB) {
+        next_in_game_state.set(InGameState::SpellBook);
+    }
+}
+
+/// Cycles the primed spell through the equipped loadout with the mouse wheel.
+///
+/// Scrolling forward primes the next equipped spell and backward the previous
+/// one, wrapping around at the ends. Follows the scroll direction and speed
+/// settings, with every `SPELL_CYCLE_SCROLL_STEP` of scrolling one step. The
+/// wheel is left to the UI while the cursor is over a button or a scrollable
+/// panel such as the cast log.
+#[allow(clippy::too_many_arguments)]
+pub fn cycle_spell_on_scroll(
+    mut mouse_wheel_events: MessageReader<MouseWheel>,
+    config: Res<GameConfig>,
+    hover_map: Res<HoverMap>,
+    nodes: Query<(&Node, Has<Button>)>,
+    parent_query: Query<&ChildOf>,
+    wizard_query: Query<(Option<&PrimedSpell>, Option<&QueuedSpell>), With<Wizard>>,
+    mut scrolled: Local<f32>,
+    mut prime_spell: MessageWriter<PrimeSpellMessage>,
+) {
+    let delta: f32 = mouse_wheel_events
+        .read()
+        .map(|event| wheel_scroll_delta(event, &config))
+        .sum();
+    if delta == 0.0 {
+        return;
+    }
+
+    let over_ui = hover_map
+        .values()
+        .flat_map(|pointer_map| pointer_map.keys())
+        .any(|&hovered| {
+            std::iter::once(hovered)
+                .chain(parent_query.iter_ancestors(hovered))
+                .any(|entity| {
+                    nodes.get(entity).is_ok_and(|(node, is_button)| {
+                        is_button || node.overflow.y == OverflowAxis::Scroll
+                    })
+                })
+        });
+    if over_ui {
+        *scrolled = 0.0;
+        return;
+    }
+
+    *scrolled += delta;
+    let steps = (*scrolled / SPELL_CYCLE_SCROLL_STEP).trunc();
+    if steps == 0.0 {
+        return;
+    }
+    *scrolled -= steps * SPELL_CYCLE_SCROLL_STEP;
+
+    let Ok((primed, queued)) = wizard_query.single() else {
+        return;
+    };
+    // A spell queued behind the current cast is the one the player picked last
+    let current = queued
+        .map(|queued| queued.spell.spell)
+        .or(primed.map(|primed| primed.spell));
+    if let Some(spell) = config.step_equipped_spell(current, steps as i32) {
+        prime_spell.write(PrimeSpellMessage {
+            spell: spell.primed_config(),
+        });
+    }
+}
+
+/// Pauses the game when the window loses focus, if enabled in the settings.
+///
+/// Regaining focus leaves the game paused until the player resumes it.
+pub fn pause_on_focus_loss(
+    config: Res<GameConfig>,
+    mut window_focused: MessageReader<WindowFocused>,
+    mut next_in_game_state: ResMut<NextState<InGameState>>,
+) {
+    // Drain every focus change so stale ones don't pause later
+    let lost_focus = window_focused.read().filter(|event| !event.focused).count() > 0;
+    if lost_focus && config.pause_on_focus_loss {
+        next_in_game_state.set(InGameState::Paused);
     }
 }
 
@@ -49,13 +183,18 @@ pub fn keyboard_input(
 ///
 /// Creates a HUD with margins around screen edges containing:
 /// - Spell book button in top left corner
-/// - Level indicator and past victory in top right corner
-/// - Mana bar in bottom right corner
+/// - Level indicator, past victory, and campaign wave/score in top right corner
+/// - Between-waves countdown banner in the center
+/// - Practice sandbox controls on the right (practice games only)
+/// - Castle health bar in bottom left corner, below the wizard's tower
+/// - Mana bar below castle health bar
 /// - Cast bar below mana bar
+/// - Ultimate charge bar below cast bar
 pub fn spawn_hud(
     mut commands: Commands,
     current_level: Res<CurrentLevel>,
     config: Res<GameConfig>,
+    sandbox: Res<SandboxConfig>,
 ) {
     // Root HUD container (fullscreen with margins)
     commands
@@ -83,7 +222,59 @@ pub fn spawn_hud(
                 })
                 .with_children(|row| {
                     // Spell book button (top-left)
-                    spawn_button(row, "Spells", HudButtonAction::OpenSpellBook, &BUTTON_STYLE);
+                    spawn_button(
+                        row,
+                        "Spells",
+                        HudButtonAction::OpenSpellBook,
+                        &BUTTON_STYLE,
+                        palette(config.ui_theme),
+                    );
+
+                    // Battle balance hint (top-center)
+                    row.spawn((
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            row_gap: Val::Px(5.0),
+                            display: if config.show_battle_hint {
+                                Display::Flex
+                            } else {
+                                Display::None
+                            },
+                            ..default()
+                        },
+                        BattleBalanceDisplay,
+                    ))
+                    .with_children(|hint| {
+                        hint.spawn((
+                            Text::new("Battle Outlook"),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgba(0.8, 0.8, 0.8, 0.9)),
+                        ));
+                        hint.spawn((
+                            Node {
+                                width: BALANCE_BAR_WIDTH,
+                                height: BALANCE_BAR_HEIGHT,
+                                justify_content: JustifyContent::FlexStart, // Defenders fill from left
+                                ..default()
+                            },
+                            BackgroundColor(BALANCE_BAR_ATTACKERS_COLOR),
+                        ))
+                        .with_children(|bar| {
+                            bar.spawn((
+                                Node {
+                                    width: Val::Percent(50.0),
+                                    height: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                BackgroundColor(BALANCE_BAR_DEFENDERS_COLOR),
+                                BattleBalanceFill { displayed: 0.5 },
+                            ));
+                        });
+                    });
 
                     // Level and past victory display (top-right)
                     row.spawn(Node {
@@ -129,31 +320,161 @@ pub fn spawn_hud(
                                 PastVictoryDisplay,
                             ));
                         }
+
+                        // Campaign wave and score display
+                        level_container.spawn((
+                            Text::new(""),
+                            TextFont {
+                                font_size: 20.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgba(0.8, 0.8, 0.8, 0.9)),
+                            WaveDisplay,
+                        ));
                     });
                 });
 
-            // Bottom-right bars container
+            // Preparation and between-waves banner (empty while fighting)
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    align_self: AlignSelf::Center,
+                    ..default()
+                },
+                IntermissionBanner,
+            ));
+
+            // Between-waves upgrade choices
+            parent
+                .spawn((
+                    Node {
+                        display: Display::None,
+                        flex_direction: FlexDirection::Column,
+                        align_self: AlignSelf::Center,
+                        align_items: AlignItems::Center,
+                        row_gap: HUD_ELEMENT_GAP,
+                        ..default()
+                    },
+                    UpgradePanel,
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        UpgradePointsText,
+                    ));
+                    panel
+                        .spawn(Node {
+                            column_gap: HUD_ELEMENT_GAP,
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            for upgrade in Upgrade::all() {
+                                spawn_button(
+                                    row,
+                                    upgrade.name(),
+                                    HudButtonAction::BuyUpgrade(*upgrade),
+                                    &UPGRADE_BUTTON_STYLE,
+                                    palette(config.ui_theme),
+                                );
+                            }
+                        });
+                });
+
+            // Practice sandbox controls (right side)
+            parent
+                .spawn((
+                    Node {
+                        display: if config.game_mode == GameMode::Sandbox {
+                            Display::Flex
+                        } else {
+                            Display::None
+                        },
+                        flex_direction: FlexDirection::Column,
+                        align_self: AlignSelf::FlexEnd,
+                        align_items: AlignItems::Center,
+                        row_gap: HUD_ELEMENT_GAP,
+                        ..default()
+                    },
+                    SandboxPanel,
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("Practice"),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                    for command in SandboxCommand::all() {
+                        spawn_button(
+                            panel,
+                            &command.label(&sandbox),
+                            HudButtonAction::Sandbox(*command),
+                            &SANDBOX_BUTTON_STYLE,
+                            palette(config.ui_theme),
+                        );
+                    }
+                });
+
+            // Bottom-left bars container (the wizard's tower is on the left side of the screen)
             parent
                 .spawn(Node {
                     flex_direction: FlexDirection::Column,
-                    align_items: AlignItems::FlexEnd,
+                    align_self: AlignSelf::FlexStart,
+                    align_items: AlignItems::FlexStart,
                     row_gap: HUD_ELEMENT_GAP,
                     ..default()
                 })
                 .with_children(|bars| {
+                    // Castle health bar container (background)
+                    bars.spawn((
+                        Node {
+                            width: CASTLE_BAR_WIDTH,
+                            height: CASTLE_BAR_HEIGHT,
+                            border: UiRect::all(Val::Px(2.0)),
+                            justify_content: JustifyContent::FlexStart, // Fill from left, empties from right
+                            ..default()
+                        },
+                        BackgroundColor(CASTLE_BAR_BG_COLOR),
+                    ))
+                    .with_children(|castle_bar| {
+                        // Castle health bar fill (starts at 100%, reduces from right)
+                        castle_bar.spawn((
+                            Node {
+                                width: Val::Percent(100.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            BackgroundColor(CASTLE_BAR_FILL_COLOR),
+                            CastleHealthFill { displayed: 1.0 },
+                        ));
+                    });
+
                     // Mana bar container (background)
                     bars.spawn((
                         Node {
                             width: MANA_BAR_WIDTH,
                             height: MANA_BAR_HEIGHT,
                             border: UiRect::all(Val::Px(2.0)),
-                            justify_content: JustifyContent::FlexEnd, // Fill from right, empties from left
+                            justify_content: JustifyContent::FlexStart, // Fill from left, empties from right
                             ..default()
                         },
                         BackgroundColor(MANA_BAR_BG_COLOR),
+                        ManaBarFlash::default(),
                     ))
                     .with_children(|parent| {
-                        // Mana bar fill (starts at 100%, reduces from left)
+                        // Mana bar fill (starts at 100%, reduces from right)
                         parent.spawn((
                             Node {
                                 width: Val::Percent(100.0),
@@ -161,7 +482,7 @@ pub fn spawn_hud(
                                 ..default()
                             },
                             BackgroundColor(MANA_BAR_FILL_COLOR),
-                            ManaBarFill,
+                            ManaBarFill { displayed: 1.0 },
                         ));
                     });
 
@@ -171,7 +492,7 @@ pub fn spawn_hud(
                             width: CAST_BAR_WIDTH,
                             height: CAST_BAR_HEIGHT,
                             border: UiRect::all(Val::Px(2.0)),
-                            justify_content: JustifyContent::FlexEnd, // Fill from right
+                            justify_content: JustifyContent::FlexStart, // Fill from left
                             ..default()
                         },
                         BackgroundColor(CAST_BAR_BG_COLOR),
@@ -185,7 +506,31 @@ pub fn spawn_hud(
                                 ..default()
                             },
                             BackgroundColor(CAST_BAR_FILL_COLOR),
-                            CastBarFill,
+                            CastBarFill { displayed: 0.0 },
+                        ));
+                    });
+
+                    // Ultimate charge bar container (background)
+                    bars.spawn((
+                        Node {
+                            width: ULTIMATE_BAR_WIDTH,
+                            height: ULTIMATE_BAR_HEIGHT,
+                            border: UiRect::all(Val::Px(2.0)),
+                            justify_content: JustifyContent::FlexStart, // Fill from left
+                            ..default()
+                        },
+                        BackgroundColor(ULTIMATE_BAR_BG_COLOR),
+                    ))
+                    .with_children(|ultimate_bar| {
+                        // Ultimate charge bar fill (starts at 0%)
+                        ultimate_bar.spawn((
+                            Node {
+                                width: Val::Percent(0.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            BackgroundColor(ULTIMATE_BAR_FILL_COLOR),
+                            UltimateChargeFill { displayed: 0.0 },
                         ));
                     });
                 });
@@ -207,6 +552,8 @@ pub fn hud_button_action(
         (Changed<Interaction>, With<Button>),
     >,
     mut next_in_game_state: ResMut<NextState<InGameState>>,
+    mut purchases: MessageWriter<PurchaseUpgrade>,
+    mut sandbox_commands: MessageWriter<SandboxCommand>,
 ) {
     for (entity, interaction, action, pressed_down) in &interaction_query {
         match *interaction {
@@ -223,6 +570,12 @@ pub fn hud_button_action(
                         HudButtonAction::OpenSpellBook => {
                             next_in_game_state.set(InGameState::SpellBook);
                         }
+                        HudButtonAction::BuyUpgrade(upgrade) => {
+                            purchases.write(PurchaseUpgrade(*upgrade));
+                        }
+                        HudButtonAction::Sandbox(command) => {
+                            sandbox_commands.write(*command);
+                        }
                     }
                 }
             }
@@ -236,31 +589,248 @@ pub fn hud_button_action(
     }
 }
 
-/// Updates the mana bar width based on current wizard mana.
+/// Eases the castle health bar width toward the castle's current health.
+pub fn update_castle_health_bar(
+    time: Res<Time>,
+    castle_health: Res<CastleHealth>,
+    mut castle_bar_query: Query<(&mut Node, &mut CastleHealthFill)>,
+) {
+    if let Ok((mut node, mut fill)) = castle_bar_query.single_mut() {
+        let health = &castle_health.0;
+        let target = (health.current / health.max).clamp(0.0, 1.0);
+        fill.displayed = ease_toward(fill.displayed, target, time.delta_secs());
+        node.width = Val::Percent(fill.displayed * 100.0);
+    }
+}
+
+/// Eases the mana bar width toward the wizard's current mana.
 pub fn update_mana_bar(
+    time: Res<Time>,
     wizard_query: Query<&Mana, With<Wizard>>,
-    mut mana_bar_query: Query<&mut Node, With<ManaBarFill>>,
+    mut mana_bar_query: Query<(&mut Node, &mut ManaBarFill)>,
 ) {
     if let Ok(mana) = wizard_query.single()
-        && let Ok(mut node) = mana_bar_query.single_mut()
+        && let Ok((mut node, mut fill)) = mana_bar_query.single_mut()
     {
-        let mana_percent = mana.percentage() * 100.0;
-        node.width = Val::Percent(mana_percent);
+        fill.displayed = ease_toward(fill.displayed, mana.percentage(), time.delta_secs());
+        node.width = Val::Percent(fill.displayed * 100.0);
     }
 }
 
-/// Updates the cast bar width based on current wizard casting progress.
+/// Flashes the mana bar background red when a cast fails for lack of mana.
 ///
-/// Uses the cast time from the currently primed spell.
+/// The flash fades back to the normal background over `MANA_BAR_FLASH_DURATION`.
+pub fn flash_mana_bar(
+    time: Res<Time>,
+    mut rejected: MessageReader<CastRejected>,
+    mut mana_bar_query: Query<(&mut BackgroundColor, &mut ManaBarFlash)>,
+) {
+    let out_of_mana = rejected
+        .read()
+        .any(|rejection| rejection.reason == CastRejectReason::NotEnoughMana);
+
+    for (mut background, mut flash) in &mut mana_bar_query {
+        if out_of_mana {
+            flash.remaining = MANA_BAR_FLASH_DURATION;
+        } else if flash.remaining <= 0.0 {
+            continue;
+        } else {
+            flash.remaining = (flash.remaining - time.delta_secs()).max(0.0);
+        }
+
+        let t = flash.remaining / MANA_BAR_FLASH_DURATION;
+        background.0 = MANA_BAR_BG_COLOR.mix(&MANA_BAR_FLASH_COLOR, t);
+    }
+}
+
+/// Eases the cast bar width toward the wizard's casting progress.
+///
+/// Uses the cast time from the currently primed spell, and stays empty while no
+/// spell is primed. The bar switches to the channel color while channeling.
 pub fn update_cast_bar(
-    wizard_query: Query<(&CastingState, &PrimedSpell), With<Wizard>>,
-    mut cast_bar_query: Query<&mut Node, With<CastBarFill>>,
+    time: Res<Time>,
+    wizard_query: Query<(&CastingState, Option<&PrimedSpell>), With<Wizard>>,
+    mut cast_bar_query: Query<(&mut Node, &mut BackgroundColor, &mut CastBarFill)>,
 ) {
     if let Ok((casting_state, primed_spell)) = wizard_query.single()
-        && let Ok(mut node) = cast_bar_query.single_mut()
+        && let Ok((mut node, mut background, mut fill)) = cast_bar_query.single_mut()
+    {
+        let progress = primed_spell.map_or(0.0, |primed| casting_state.progress(primed.cast_time));
+        fill.displayed = ease_toward(fill.displayed, progress, time.delta_secs());
+        node.width = Val::Percent(fill.displayed * 100.0);
+
+        let color = match casting_state {
+            CastingState::Channeling { .. } => CAST_BAR_CHANNEL_COLOR,
+            CastingState::Resting | CastingState::Casting { .. } => CAST_BAR_FILL_COLOR,
+        };
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+/// Eases the ultimate charge bar toward the current charge.
+///
+/// The bar brightens once the charge is full and the storm is ready.
+pub fn update_ultimate_bar(
+    time: Res<Time>,
+    charge: Res<UltimateCharge>,
+    mut ultimate_bar_query: Query<(&mut Node, &mut BackgroundColor, &mut UltimateChargeFill)>,
+) {
+    if let Ok((mut node, mut background, mut fill)) = ultimate_bar_query.single_mut() {
+        fill.displayed = ease_toward(fill.displayed, charge.fraction(), time.delta_secs());
+        node.width = Val::Percent(fill.displayed * 100.0);
+
+        let color = if charge.is_full() {
+            ULTIMATE_BAR_READY_COLOR
+        } else {
+            ULTIMATE_BAR_FILL_COLOR
+        };
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+/// Eases the battle balance bar toward the current estimate.
+///
+/// Also shows or hides the hint when the setting changes.
+pub fn update_battle_balance_bar(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    balance: Res<BattleBalance>,
+    mut display_query: Query<&mut Node, (With<BattleBalanceDisplay>, Without<BattleBalanceFill>)>,
+    mut fill_query: Query<(&mut Node, &mut BattleBalanceFill), Without<BattleBalanceDisplay>>,
+) {
+    if config.is_changed()
+        && let Ok(mut node) = display_query.single_mut()
     {
-        let progress_percent = casting_state.progress(primed_spell.cast_time) * 100.0;
-        node.width = Val::Percent(progress_percent);
+        node.display = if config.show_battle_hint {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+
+    if let Ok((mut node, mut fill)) = fill_query.single_mut() {
+        // Map -1.0 (attackers favored) .. 1.0 (defenders favored) to the defenders' share
+        let target = (balance.0 + 1.0) / 2.0;
+        fill.displayed = ease_toward(fill.displayed, target, time.delta_secs());
+        node.width = Val::Percent(fill.displayed * 100.0);
+    }
+}
+
+/// Moves a displayed bar value toward its target with frame-rate independent easing.
+fn ease_toward(current: f32, target: f32, delta: f32) -> f32 {
+    let t = 1.0 - (-BAR_SMOOTHING_RATE * delta).exp();
+    current + (target - current) * t
+}
+
+/// Updates the wave/score display and the preparation and between-waves countdown banner.
+pub fn update_campaign_display(
+    campaign: Res<CampaignState>,
+    mut wave_display_query: Query<&mut Text, (With<WaveDisplay>, Without<IntermissionBanner>)>,
+    mut banner_query: Query<&mut Text, (With<IntermissionBanner>, Without<WaveDisplay>)>,
+    new_hud_query: Query<(), Added<WaveDisplay>>,
+) {
+    // Refresh on campaign changes and when the HUD is (re)spawned
+    if !campaign.is_changed() && new_hud_query.is_empty() {
+        return;
+    }
+
+    if let Ok(mut text) = wave_display_query.single_mut() {
+        **text = format!(
+            "Wave: {}  Score: {}",
+            campaign.waves_survived + 1,
+            campaign.score
+        );
+    }
+
+    if let Ok(mut text) = banner_query.single_mut() {
+        **text = match campaign.phase {
+            WavePhase::Intermission { remaining } => format!(
+                "Wave {} cleared! {} defenders remain. Next wave in {}s",
+                campaign.waves_survived,
+                campaign.carried_over_defenders,
+                remaining.ceil() as u32
+            ),
+            WavePhase::Preparation { remaining } => format!(
+                "Wave {} begins in {}s. Prepare your defenses!",
+                campaign.waves_survived + 1,
+                remaining.ceil() as u32
+            ),
+            WavePhase::Fighting => String::new(),
+        };
+    }
+}
+
+/// Shows the upgrade choices between waves with the points available and each cost.
+pub fn update_upgrade_panel(
+    campaign: Res<CampaignState>,
+    upgrades: Res<WizardUpgrades>,
+    mut panel_query: Query<&mut Node, With<UpgradePanel>>,
+    mut points_query: Query<&mut Text, With<UpgradePointsText>>,
+    buttons: Query<(&HudButtonAction, &Children)>,
+    mut labels: Query<&mut Text, Without<UpgradePointsText>>,
+    new_hud_query: Query<(), Added<UpgradePanel>>,
+) {
+    // Refresh on campaign or upgrade changes and when the HUD is (re)spawned
+    if !campaign.is_changed() && !upgrades.is_changed() && new_hud_query.is_empty() {
+        return;
+    }
+
+    if let Ok(mut node) = panel_query.single_mut() {
+        node.display = if campaign.in_intermission() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+
+    if let Ok(mut text) = points_query.single_mut() {
+        **text = format!("Upgrade points: {}", upgrades.points);
+    }
+
+    for (action, children) in &buttons {
+        let HudButtonAction::BuyUpgrade(upgrade) = action else {
+            continue;
+        };
+        // The label is the button's only text child
+        let Some(label_entity) = children.iter().find(|child| labels.contains(*child)) else {
+            continue;
+        };
+        if let Ok(mut label) = labels.get_mut(label_entity) {
+            **label = format!(
+                "{} Lv{} ({} pts)",
+                upgrade.name(),
+                upgrades.level(*upgrade) + 1,
+                upgrades.cost(*upgrade)
+            );
+        }
+    }
+}
+
+/// Relabels the practice sandbox buttons when the sandbox settings change.
+pub fn update_sandbox_panel(
+    sandbox: Res<SandboxConfig>,
+    buttons: Query<(&HudButtonAction, &Children)>,
+    mut labels: Query<&mut Text>,
+) {
+    if !sandbox.is_changed() {
+        return;
+    }
+
+    for (action, children) in &buttons {
+        let HudButtonAction::Sandbox(command) = action else {
+            continue;
+        };
+        // The label is the button's only text child
+        let Some(label_entity) = children.iter().find(|child| labels.contains(*child)) else {
+            continue;
+        };
+        if let Ok(mut label) = labels.get_mut(label_entity) {
+            **label = command.label(&sandbox);
+        }
     }
 }
 
@@ -292,3 +862,100 @@ pub fn update_past_victory_display(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::mouse::MouseScrollUnit;
+
+    use super::*;
+    use crate::game::units::wizard::components::Spell;
+    use crate::game::units::wizard::constants::DEFAULT_SPELL_RANGE;
+    use crate::game::units::wizard::systems::handle_prime_spell_messages;
+
+    #[test]
+    fn test_hud_buttons_follow_theme_change() {
+        use crate::ui::theme::HIGH_CONTRAST_PALETTE;
+
+        let mut app = App::new();
+        app.init_resource::<GameConfig>()
+            .add_systems(Update, retint_hud_buttons);
+
+        let default_palette = palette(UiTheme::Default);
+        let button = app
+            .world_mut()
+            .spawn((
+                HudButtonAction::OpenSpellBook,
+                ButtonColors {
+                    background: default_palette.button_background,
+                    border: default_palette.button_border,
+                },
+                BackgroundColor(default_palette.button_background),
+                BorderColor::all(default_palette.button_border),
+            ))
+            .with_child(TextColor(default_palette.text))
+            .id();
+        app.update();
+
+        app.world_mut().resource_mut::<GameConfig>().ui_theme = UiTheme::HighContrast;
+        app.update();
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<BackgroundColor>(button).unwrap().0,
+            HIGH_CONTRAST_PALETTE.button_background
+        );
+        assert_eq!(
+            world.get::<ButtonColors>(button).unwrap().border,
+            HIGH_CONTRAST_PALETTE.button_border
+        );
+        let label = world.get::<Children>(button).unwrap()[0];
+        assert_eq!(
+            world.get::<TextColor>(label).unwrap().0,
+            HIGH_CONTRAST_PALETTE.text
+        );
+    }
+
+    #[test]
+    fn test_scrolling_cycles_primed_spell_and_wraps() {
+        let mut app = App::new();
+        app.insert_resource(GameConfig {
+            spell_loadout: vec![Spell::MagicMissile, Spell::Fireball, Spell::FrostNova],
+            ..default()
+        })
+        .init_resource::<HoverMap>()
+        .add_message::<MouseWheel>()
+        .add_message::<PrimeSpellMessage>()
+        .add_systems(
+            Update,
+            (cycle_spell_on_scroll, handle_prime_spell_messages).chain(),
+        );
+
+        let wizard = app
+            .world_mut()
+            .spawn((
+                Wizard::new(DEFAULT_SPELL_RANGE),
+                CastingState::new(),
+                Spell::FrostNova.primed_config(),
+            ))
+            .id();
+
+        // Wheel down is forward at the default scroll settings
+        let scroll = |app: &mut App, notches: f32| {
+            app.world_mut().write_message(MouseWheel {
+                unit: MouseScrollUnit::Line,
+                x: 0.0,
+                y: -notches,
+                window: Entity::PLACEHOLDER,
+            });
+            app.update();
+            app.world().get::<PrimedSpell>(wizard).unwrap().spell
+        };
+
+        // Forward past the last equipped spell wraps to the first
+        assert_eq!(scroll(&mut app, 1.0), Spell::MagicMissile);
+        assert_eq!(scroll(&mut app, 1.0), Spell::Fireball);
+        // Backward past the first wraps to the last
+        assert_eq!(scroll(&mut app, -1.0), Spell::MagicMissile);
+        assert_eq!(scroll(&mut app, -1.0), Spell::FrostNova);
+    }
+}
@@ -13,11 +13,7 @@ impl Plugin for ChangelogPlugin {
         app.add_systems(OnEnter(MenuState::Changelog), systems::setup)
             .add_systems(
                 Update,
-                (
-                    systems::handle_back_button,
-                    systems::update_button_colors,
-                    systems::handle_scroll,
-                )
+                (systems::handle_back_button, systems::handle_scroll)
                     .run_if(in_state(MenuState::Changelog)),
             )
             .add_systems(OnExit(MenuState::Changelog), systems::cleanup);
@@ -6,17 +6,18 @@ use bevy::prelude::*;
 use bevy::ui::ComputedNode;
 
 use super::components::{BackButton, OnChangelogScreen, ScrollableChangelogContainer};
+use crate::config::GameConfig;
 use crate::state::MenuState;
-use crate::ui::main_menu::landing::constants::TEXT_COLOR;
-
-// Button colors for changelog screen
-const BUTTON_COLOR: Color = Color::hsla(0.0, 0.0, 0.15, 1.0);
-const BUTTON_HOVER_COLOR: Color = Color::hsla(0.0, 0.0, 0.25, 1.0);
+use crate::ui::components::ButtonColors;
+use crate::ui::systems::wheel_scroll_delta;
+use crate::ui::theme::palette;
 
 const CHANGELOG_TEXT: &str = include_str!("../../../../CHANGELOG.md");
 
 /// Spawns the changelog screen UI.
-pub fn setup(mut commands: Commands) {
+pub fn setup(mut commands: Commands, config: Res<GameConfig>) {
+    let palette = palette(config.ui_theme);
+
     commands
         .spawn((
             Node {
@@ -39,7 +40,7 @@ pub fn setup(mut commands: Commands) {
                     font_size: 48.0,
                     ..default()
                 },
-                TextColor(TEXT_COLOR),
+                TextColor(palette.text),
                 Node {
                     margin: UiRect::bottom(Val::Px(20.0)),
                     ..default()
@@ -75,7 +76,7 @@ pub fn setup(mut commands: Commands) {
                                     font_size: 16.0,
                                     ..default()
                                 },
-                                TextColor(TEXT_COLOR),
+                                TextColor(palette.text),
                             ));
                         });
                 });
@@ -93,9 +94,13 @@ pub fn setup(mut commands: Commands) {
                         margin: UiRect::top(Val::Px(20.0)),
                         ..default()
                     },
-                    BorderColor::all(Color::hsla(0.0, 0.0, 0.3, 1.0)),
+                    BorderColor::all(palette.menu_button_border),
                     BorderRadius::all(Val::Px(8.0)),
-                    BackgroundColor(BUTTON_COLOR),
+                    BackgroundColor(palette.button_background),
+                    ButtonColors {
+                        background: palette.button_background,
+                        border: palette.menu_button_border,
+                    },
                     BackButton,
                 ))
                 .with_children(|parent| {
@@ -105,7 +110,7 @@ pub fn setup(mut commands: Commands) {
                             font_size: 32.0,
                             ..default()
                         },
-                        TextColor(TEXT_COLOR),
+                        TextColor(palette.text),
                     ));
                 });
         });
@@ -123,35 +128,6 @@ pub fn handle_back_button(
     }
 }
 
-/// Updates button colors on hover.
-pub fn update_button_colors(
-    mut button_query: Query<
-        (&Interaction, &mut BackgroundColor, &mut BorderColor),
-        (Changed<Interaction>, With<Button>),
-    >,
-) {
-    const NORMAL_BORDER: Color = Color::hsla(0.0, 0.0, 0.3, 1.0);
-    const HOVER_BORDER: Color = Color::hsla(0.0, 0.0, 0.4, 1.0);
-    const PRESSED_BORDER: Color = Color::hsla(0.0, 0.0, 0.5, 1.0);
-
-    for (interaction, mut bg_color, mut border_color) in &mut button_query {
-        match *interaction {
-            Interaction::Pressed => {
-                *bg_color = Color::hsla(0.0, 0.0, 0.35, 1.0).into();
-                *border_color = BorderColor::all(PRESSED_BORDER);
-            }
-            Interaction::Hovered => {
-                *bg_color = BUTTON_HOVER_COLOR.into();
-                *border_color = BorderColor::all(HOVER_BORDER);
-            }
-            Interaction::None => {
-                *bg_color = BUTTON_COLOR.into();
-                *border_color = BorderColor::all(NORMAL_BORDER);
-            }
-        }
-    }
-}
-
 /// Despawns all changelog screen entities.
 pub fn cleanup(mut commands: Commands, query: Query<Entity, With<OnChangelogScreen>>) {
     for entity in &query {
@@ -162,6 +138,7 @@ pub fn cleanup(mut commands: Commands, query: Query<Entity, With<OnChangelogScre
 /// Handles mouse wheel scrolling for the changelog container.
 pub fn handle_scroll(
     mut mouse_wheel_events: MessageReader<MouseWheel>,
+    config: Res<GameConfig>,
     hover_map: Res<bevy::picking::hover::HoverMap>,
     mut scrollable_query: Query<
         (&mut ScrollPosition, &ComputedNode),
@@ -169,14 +146,8 @@ pub fn handle_scroll(
     >,
     parent_query: Query<&ChildOf>,
 ) {
-    const LINE_HEIGHT: f32 = 10.0;
-    const PIXEL_SCROLL_MULTIPLIER: f32 = 0.3;
-
     for event in mouse_wheel_events.read() {
-        let dy = match event.unit {
-            bevy::input::mouse::MouseScrollUnit::Line => -event.y * LINE_HEIGHT,
-            bevy::input::mouse::MouseScrollUnit::Pixel => -event.y * PIXEL_SCROLL_MULTIPLIER,
-        };
+        let dy = wheel_scroll_delta(event, &config);
 
         // Check if we're hovering over the scrollable container or any of its children
         for pointer_map in hover_map.values() {
@@ -14,7 +14,7 @@ pub struct OnLandingScreen;
 /// a button is pressed.
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MenuButtonAction {
-    /// Start a new game, transitioning to `AppState::InGame`.
+    /// Start a new game, transitioning to the `MenuState::Loadout` screen.
     StartGame,
 
     /// Open the settings menu, transitioning to `MenuState::Settings`.
@@ -22,4 +22,7 @@ pub enum MenuButtonAction {
 
     /// Open the changelog screen, transitioning to `MenuState::Changelog`.
     Changelog,
+
+    /// Open the spell statistics screen, transitioning to `MenuState::Statistics`.
+    Statistics,
 }
@@ -3,11 +3,13 @@
 use bevy::input::keyboard::KeyCode;
 use bevy::prelude::*;
 
-use crate::state::{AppState, MenuState};
+use crate::config::{GameConfig, LoadFailures, Scores};
+use crate::state::MenuState;
 use crate::ui::systems::spawn_button;
+use crate::ui::theme::palette;
 
 use super::components::{MenuButtonAction, OnLandingScreen};
-use super::constants::{BUTTON_STYLE, MARGIN, TEXT_COLOR, TITLE_FONT_SIZE};
+use super::constants::{BUTTON_STYLE, MARGIN, SCORES_FONT_SIZE, TITLE_FONT_SIZE};
 
 /// Marker component to track that a button was pressed down.
 #[derive(Component)]
@@ -15,9 +17,17 @@ pub(super) struct ButtonPressedDown;
 
 /// Sets up the landing screen UI.
 ///
-/// Spawns the root UI node containing the title and menu buttons.
+/// Spawns the root UI node containing the title, personal bests, any saved
+/// data that failed to load, and menu buttons.
 /// All spawned entities are marked with `OnLandingScreen` for cleanup.
-pub fn setup(mut commands: Commands) {
+pub fn setup(
+    mut commands: Commands,
+    scores: Res<Scores>,
+    config: Res<GameConfig>,
+    load_failures: Res<LoadFailures>,
+) {
+    let palette = palette(config.ui_theme);
+
     // Root container - full screen, centered content in a column
     commands
         .spawn((
@@ -40,19 +50,51 @@ pub fn setup(mut commands: Commands) {
                     font_size: TITLE_FONT_SIZE,
                     ..default()
                 },
-                TextColor(TEXT_COLOR),
+                TextColor(palette.text),
                 Node {
                     margin: UiRect::bottom(Val::Px(MARGIN * 2.0)),
                     ..default()
                 },
             ));
 
+            // Personal bests (hidden until the first game is finished)
+            if scores.best_level > 0 {
+                parent.spawn((
+                    Text::new(format!(
+                        "Best Level: {}  |  Most Attackers Killed: {}  |  Most Undead Killed: {}",
+                        scores.best_level, scores.most_attackers_killed, scores.most_undead_killed
+                    )),
+                    TextFont {
+                        font_size: SCORES_FONT_SIZE,
+                        ..default()
+                    },
+                    TextColor(palette.text),
+                    Node {
+                        margin: UiRect::bottom(Val::Px(MARGIN)),
+                        ..default()
+                    },
+                ));
+            }
+
+            // Saved data that was reset this session
+            for message in &load_failures.messages {
+                parent.spawn((
+                    Text::new(message.clone()),
+                    TextFont {
+                        font_size: SCORES_FONT_SIZE,
+                        ..default()
+                    },
+                    TextColor(palette.warning),
+                ));
+            }
+
             // Start Game button
             spawn_button(
                 parent,
                 "Start Game",
                 MenuButtonAction::StartGame,
                 &BUTTON_STYLE,
+                &palette.menu_buttons(),
             );
 
             // Settings button
@@ -61,6 +103,7 @@ pub fn setup(mut commands: Commands) {
                 "Settings",
                 MenuButtonAction::Settings,
                 &BUTTON_STYLE,
+                &palette.menu_buttons(),
             );
 
             // Changelog button
@@ -69,17 +112,33 @@ pub fn setup(mut commands: Commands) {
                 "Changelog",
                 MenuButtonAction::Changelog,
                 &BUTTON_STYLE,
+                &palette.menu_buttons(),
+            );
+
+            // Spell statistics button
+            spawn_button(
+                parent,
+                "Spell Stats",
+                MenuButtonAction::Statistics,
+                &BUTTON_STYLE,
+                &palette.menu_buttons(),
             );
         });
 }
 
 /// Cleans up the landing screen UI when exiting the state.
 ///
-/// Despawns all entities marked with `OnLandingScreen`.
-pub fn cleanup(mut commands: Commands, landing_items: Query<Entity, With<OnLandingScreen>>) {
+/// Despawns all entities marked with `OnLandingScreen`. Load failures have
+/// been shown by then, so they are cleared.
+pub fn cleanup(
+    mut commands: Commands,
+    landing_items: Query<Entity, With<OnLandingScreen>>,
+    mut load_failures: ResMut<LoadFailures>,
+) {
     for entity in &landing_items {
         commands.entity(entity).despawn();
     }
+    load_failures.messages.clear();
 }
 
 /// Handles menu button actions.
@@ -97,7 +156,6 @@ pub fn button_action(
         ),
         (Changed<Interaction>, With<Button>),
     >,
-    mut next_app_state: ResMut<NextState<AppState>>,
     mut next_menu_state: ResMut<NextState<MenuState>>,
 ) {
     for (entity, interaction, action, pressed_down) in &interaction_query {
@@ -113,7 +171,7 @@ pub fn button_action(
 
                     match action {
                         MenuButtonAction::StartGame => {
-                            next_app_state.set(AppState::InGame);
+                            next_menu_state.set(MenuState::Loadout);
                         }
                         MenuButtonAction::Settings => {
                             next_menu_state.set(MenuState::Settings);
@@ -121,6 +179,9 @@ pub fn button_action(
                         MenuButtonAction::Changelog => {
                             next_menu_state.set(MenuState::Changelog);
                         }
+                        MenuButtonAction::Statistics => {
+                            next_menu_state.set(MenuState::Statistics);
+                        }
                     }
                 }
             }
@@ -131,7 +192,7 @@ pub fn button_action(
 
                     match action {
                         MenuButtonAction::StartGame => {
-                            next_app_state.set(AppState::InGame);
+                            next_menu_state.set(MenuState::Loadout);
                         }
                         MenuButtonAction::Settings => {
                             next_menu_state.set(MenuState::Settings);
@@ -139,6 +200,9 @@ pub fn button_action(
                         MenuButtonAction::Changelog => {
                             next_menu_state.set(MenuState::Changelog);
                         }
+                        MenuButtonAction::Statistics => {
+                            next_menu_state.set(MenuState::Statistics);
+                        }
                     }
                 }
             }
@@ -0,0 +1,35 @@
+//! Loadout screen specific components.
+
+use bevy::prelude::*;
+
+use crate::config::GameMode;
+use crate::game::units::wizard::components::Spell;
+
+/// Marker component for entities that belong to the loadout screen.
+///
+/// Used for cleanup when exiting the loadout state.
+#[derive(Component)]
+pub struct OnLoadoutScreen;
+
+/// Actions that can be triggered by loadout screen buttons.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadoutButtonAction {
+    /// Equip or unequip a spell.
+    ToggleSpell(Spell),
+    /// Choose the game mode for the next battle.
+    SelectMode(GameMode),
+    /// Make the next equipped spell the one primed at battle start.
+    CycleStartingSpell,
+    /// Start the battle with the current loadout, transitioning to `AppState::InGame`.
+    StartBattle,
+    /// Return to the landing screen.
+    Back,
+}
+
+/// Marker component for the "Equipped: N / M" status text.
+#[derive(Component)]
+pub struct LoadoutStatusText;
+
+/// Marker component for the "Starting Spell: X" text.
+#[derive(Component)]
+pub struct StartingSpellText;
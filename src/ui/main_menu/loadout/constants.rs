@@ -0,0 +1,34 @@
+//! Loadout screen styling constants.
+
+use crate::ui::components::ButtonStyle;
+
+/// Font size for the loadout title text.
+pub const TITLE_FONT_SIZE: f32 = 48.0;
+
+/// Font size for the equipped count and warnings.
+pub const STATUS_FONT_SIZE: f32 = 22.0;
+
+/// Margin between loadout UI elements in pixels.
+pub const MARGIN: f32 = 20.0;
+
+/// Gap between spell buttons in pixels.
+pub const SPELL_GRID_GAP: f32 = 12.0;
+
+/// Maximum width of the spell button grid in pixels.
+pub const SPELL_GRID_WIDTH: f32 = 1000.0;
+
+/// Button style for spell toggle buttons.
+pub const SPELL_BUTTON_STYLE: ButtonStyle = ButtonStyle {
+    width: 220.0,
+    height: 60.0,
+    border_width: 2.0,
+    font_size: 22.0,
+};
+
+/// Button style for the Start Battle and Back buttons.
+pub const NAV_BUTTON_STYLE: ButtonStyle = ButtonStyle {
+    width: 250.0,
+    height: 65.0,
+    border_width: 3.0,
+    font_size: 28.0,
+};
@@ -0,0 +1,8 @@
+//! Spell loadout screen module.
+//!
+//! Pre-battle screen where the player chooses which spells to equip.
+
+mod components;
+mod constants;
+pub(super) mod plugin;
+mod systems;
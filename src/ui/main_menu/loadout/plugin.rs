@@ -0,0 +1,29 @@
+//! Loadout screen plugin.
+
+use bevy::prelude::*;
+
+use crate::state::MenuState;
+
+use super::systems::{button_action, cleanup, keyboard_input, setup, update_loadout_display};
+
+/// Plugin that manages the pre-battle spell loadout screen.
+///
+/// Registers systems for:
+/// - Loadout screen setup and cleanup
+/// - Equipping/unequipping spells and refreshing the display
+/// - Starting the battle once at least one spell is equipped
+#[derive(Default)]
+pub struct LoadoutPlugin;
+
+impl Plugin for LoadoutPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(MenuState::Loadout), setup)
+            .add_systems(OnExit(MenuState::Loadout), cleanup)
+            .add_systems(
+                Update,
+                (button_action, keyboard_input, update_loadout_display)
+                    .chain()
+                    .run_if(in_state(MenuState::Loadout)),
+            );
+    }
+}
@@ -0,0 +1,359 @@
+//! Loadout screen systems.
+
+use bevy::input::keyboard::KeyCode;
+use bevy::prelude::*;
+
+use crate::config::{GameConfig, GameMode};
+use crate::game::units::wizard::components::Spell;
+use crate::state::{AppState, MenuState};
+use crate::ui::components::ButtonColors;
+use crate::ui::systems::spawn_button;
+use crate::ui::theme::{UiPalette, palette};
+
+use super::components::{
+    LoadoutButtonAction, LoadoutStatusText, OnLoadoutScreen, StartingSpellText,
+};
+use super::constants::*;
+
+/// Marker component to track that a button was pressed down.
+#[derive(Component)]
+pub(super) struct ButtonPressedDown;
+
+/// Sets up the loadout screen UI.
+///
+/// Spawns the title, game mode selector, equipped count, one toggle button per
+/// spell, the starting spell selector, and the Start Battle / Back buttons. All spawned entities are marked with
+/// `OnLoadoutScreen` for cleanup.
+pub fn setup(mut commands: Commands, config: Res<GameConfig>) {
+    let palette = palette(config.ui_theme);
+    let (status, status_color) = loadout_status(&config, palette);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(MARGIN),
+                ..default()
+            },
+            OnLoadoutScreen,
+        ))
+        .with_children(|parent| {
+            // Title text
+            parent.spawn((
+                Text::new("Choose Your Spells"),
+                TextFont {
+                    font_size: TITLE_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(palette.text),
+            ));
+
+            // Game mode selector
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(SPELL_GRID_GAP),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for mode in GameMode::all() {
+                        spawn_toggle(
+                            row,
+                            mode.name(),
+                            LoadoutButtonAction::SelectMode(*mode),
+                            config.game_mode == *mode,
+                            palette,
+                        );
+                    }
+                });
+
+            // Equipped count / validation message
+            parent.spawn((
+                Text::new(status),
+                TextFont {
+                    font_size: STATUS_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(status_color),
+                LoadoutStatusText,
+            ));
+
+            // Spell toggle buttons, wrapping onto multiple rows
+            parent
+                .spawn(Node {
+                    max_width: Val::Px(SPELL_GRID_WIDTH),
+                    flex_direction: FlexDirection::Row,
+                    flex_wrap: FlexWrap::Wrap,
+                    justify_content: JustifyContent::Center,
+                    column_gap: Val::Px(SPELL_GRID_GAP),
+                    row_gap: Val::Px(SPELL_GRID_GAP),
+                    ..default()
+                })
+                .with_children(|grid| {
+                    for spell in Spell::all() {
+                        spawn_toggle(
+                            grid,
+                            spell.name(),
+                            LoadoutButtonAction::ToggleSpell(*spell),
+                            config.is_equipped(*spell),
+                            palette,
+                        );
+                    }
+                });
+
+            // Starting spell selector
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(SPELL_GRID_GAP),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(starting_spell_label(&config)),
+                        TextFont {
+                            font_size: STATUS_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(palette.text),
+                        StartingSpellText,
+                    ));
+                    spawn_button(
+                        row,
+                        "Change",
+                        LoadoutButtonAction::CycleStartingSpell,
+                        &SPELL_BUTTON_STYLE,
+                        palette,
+                    );
+                });
+
+            // Navigation buttons
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(MARGIN),
+                    margin: UiRect::top(Val::Px(MARGIN)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    spawn_button(
+                        row,
+                        "Back",
+                        LoadoutButtonAction::Back,
+                        &NAV_BUTTON_STYLE,
+                        &palette.menu_buttons(),
+                    );
+                    spawn_button(
+                        row,
+                        "Start Battle",
+                        LoadoutButtonAction::StartBattle,
+                        &NAV_BUTTON_STYLE,
+                        &palette.menu_buttons(),
+                    );
+                });
+        });
+}
+
+/// Spawns a toggle button highlighted according to whether it is selected.
+fn spawn_toggle(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    action: LoadoutButtonAction,
+    selected: bool,
+    palette: &UiPalette,
+) {
+    let (background, border) = toggle_button_colors(selected, palette);
+
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(SPELL_BUTTON_STYLE.width),
+                height: Val::Px(SPELL_BUTTON_STYLE.height),
+                border: UiRect::all(Val::Px(SPELL_BUTTON_STYLE.border_width)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BorderColor::all(border),
+            BorderRadius::all(Val::Px(8.0)),
+            BackgroundColor(background),
+            ButtonColors { background, border },
+            action,
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: SPELL_BUTTON_STYLE.font_size,
+                    ..default()
+                },
+                TextColor(palette.text),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+        });
+}
+
+/// Returns the (background, border) colors for a spell or game mode button.
+fn toggle_button_colors(selected: bool, palette: &UiPalette) -> (Color, Color) {
+    if selected {
+        (palette.selected_background, palette.selected_border)
+    } else {
+        (palette.button_background, palette.button_border)
+    }
+}
+
+/// Returns the status line text and color for the current loadout.
+fn loadout_status(config: &GameConfig, palette: &UiPalette) -> (String, Color) {
+    if config.has_valid_loadout() {
+        (
+            format!(
+                "Equipped: {} / {}",
+                config.equipped_spells().count(),
+                config.loadout_size
+            ),
+            palette.text,
+        )
+    } else {
+        (
+            "Equip at least one spell to start the battle".to_string(),
+            palette.warning,
+        )
+    }
+}
+
+/// Returns the label naming the spell primed at battle start.
+fn starting_spell_label(config: &GameConfig) -> String {
+    match config.starting_spell() {
+        Some(spell) => format!("Starting Spell: {}", spell.name()),
+        None => "Starting Spell: None".to_string(),
+    }
+}
+
+/// Cleans up the loadout screen UI when exiting the state.
+///
+/// Despawns all entities marked with `OnLoadoutScreen`.
+pub fn cleanup(mut commands: Commands, loadout_items: Query<Entity, With<OnLoadoutScreen>>) {
+    for entity in &loadout_items {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Handles loadout button actions.
+///
+/// Uses a marker component to ensure buttons only trigger on release after being pressed.
+pub fn button_action(
+    mut commands: Commands,
+    interaction_query: Query<
+        (
+            Entity,
+            &Interaction,
+            &LoadoutButtonAction,
+            Option<&ButtonPressedDown>,
+        ),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut config: ResMut<GameConfig>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut next_menu_state: ResMut<NextState<MenuState>>,
+) {
+    for (entity, interaction, action, pressed_down) in &interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                // Mark button as pressed down
+                commands.entity(entity).insert(ButtonPressedDown);
+            }
+            // Trigger action on release (touch goes Pressed → None, skipping Hovered)
+            Interaction::Hovered | Interaction::None => {
+                if pressed_down.is_none() {
+                    continue;
+                }
+                commands.entity(entity).remove::<ButtonPressedDown>();
+
+                match action {
+                    LoadoutButtonAction::ToggleSpell(spell) => {
+                        // A full loadout ignores the click; the player unequips first
+                        config.toggle_spell(*spell);
+                    }
+                    LoadoutButtonAction::SelectMode(mode) => {
+                        config.game_mode = *mode;
+                    }
+                    LoadoutButtonAction::CycleStartingSpell => {
+                        config.cycle_starting_spell();
+                    }
+                    LoadoutButtonAction::StartBattle => {
+                        if config.has_valid_loadout() {
+                            next_app_state.set(AppState::InGame);
+                        }
+                    }
+                    LoadoutButtonAction::Back => {
+                        next_menu_state.set(MenuState::Landing);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Handles keyboard input in the loadout screen (ESC to go back).
+pub fn keyboard_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_menu_state: ResMut<NextState<MenuState>>,
+) {
+    if keyboard.just_pressed(KeyCode::Escape) {
+        next_menu_state.set(MenuState::Landing);
+    }
+}
+
+/// Refreshes spell and game mode highlights, the status line, and the starting
+/// spell when the loadout changes.
+pub fn update_loadout_display(
+    config: Res<GameConfig>,
+    mut buttons: Query<(
+        &LoadoutButtonAction,
+        &mut ButtonColors,
+        &mut BackgroundColor,
+        &mut BorderColor,
+    )>,
+    mut status_query: Query<
+        (&mut Text, &mut TextColor),
+        (With<LoadoutStatusText>, Without<StartingSpellText>),
+    >,
+    mut starting_spell_query: Query<&mut Text, With<StartingSpellText>>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+
+    let palette = palette(config.ui_theme);
+    for (action, mut colors, mut background, mut border) in &mut buttons {
+        let selected = match action {
+            LoadoutButtonAction::ToggleSpell(spell) => config.is_equipped(*spell),
+            LoadoutButtonAction::SelectMode(mode) => config.game_mode == *mode,
+            LoadoutButtonAction::CycleStartingSpell
+            | LoadoutButtonAction::StartBattle
+            | LoadoutButtonAction::Back => continue,
+        };
+
+        let (new_background, new_border) = toggle_button_colors(selected, palette);
+        colors.background = new_background;
+        colors.border = new_border;
+        *background = new_background.into();
+        *border = BorderColor::all(new_border);
+    }
+
+    let (status, status_color) = loadout_status(&config, palette);
+    for (mut text, mut color) in &mut status_query {
+        **text = status.clone();
+        color.0 = status_color;
+    }
+
+    for mut text in &mut starting_spell_query {
+        **text = starting_spell_label(&config);
+    }
+}
@@ -1,10 +1,13 @@
 //! Main menu module.
 //!
-//! Contains the MainMenuPlugin which aggregates Landing, Settings, and Changelog screens.
+//! Contains the MainMenuPlugin which aggregates Landing, Loadout, Settings, Changelog, and
+//! Statistics screens.
 
 mod changelog;
 mod landing;
+mod loadout;
 mod plugin;
 pub mod settings;
+mod statistics;
 
 pub use plugin::MainMenuPlugin;
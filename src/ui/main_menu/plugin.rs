@@ -1,24 +1,34 @@
 //! Main menu plugin.
 //!
-//! Aggregates the Landing, Settings, and Changelog plugins for the main menu flow.
+//! Aggregates the Landing, Loadout, Settings, Changelog, and Statistics plugins for the main menu flow.
 
 use bevy::prelude::*;
 
 use super::changelog::ChangelogPlugin;
 use super::landing::plugin::LandingPlugin;
+use super::loadout::plugin::LoadoutPlugin;
 use super::settings::plugin::SettingsPlugin;
+use super::statistics::StatisticsPlugin;
 
 /// Main menu plugin that aggregates all main menu sub-screens.
 ///
 /// This plugin contains:
 /// - LandingPlugin (MenuState::Landing) - Start Game, Settings, and Changelog buttons
+/// - LoadoutPlugin (MenuState::Loadout) - Pre-battle spell loadout screen
 /// - SettingsPlugin (MenuState::Settings) - Settings screen
 /// - ChangelogPlugin (MenuState::Changelog) - Changelog screen
+/// - StatisticsPlugin (MenuState::Statistics) - Lifetime spell statistics screen
 #[derive(Default)]
 pub struct MainMenuPlugin;
 
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((LandingPlugin, SettingsPlugin, ChangelogPlugin));
+        app.add_plugins((
+            LandingPlugin,
+            LoadoutPlugin,
+            SettingsPlugin,
+            ChangelogPlugin,
+            StatisticsPlugin,
+        ));
     }
 }
@@ -2,7 +2,11 @@
 
 use bevy::prelude::*;
 
-use crate::config::{Difficulty, VsyncMode};
+use crate::config::{
+    CorpseMode, Difficulty, GraphicsQuality, MAX_UI_SCALE, MAX_UNIT_RENDER_SCALE, MIN_SCROLL_SPEED,
+    MIN_UI_SCALE, MIN_UNIT_RENDER_SCALE, MapSize, MouseBinding, RenderStyle, TimeOfDayMode,
+    UiTheme, VsyncMode,
+};
 
 /// Marker component for entities that belong to the settings screen.
 ///
@@ -20,8 +24,56 @@ pub struct ScrollableContainer;
 pub enum OptionButtonValue {
     /// VSync mode option
     VsyncMode(VsyncMode),
+    /// Frame rate cap option while VSync is off (None = uncapped)
+    FrameCap(Option<u32>),
+    /// Graphics quality preset option
+    GraphicsQuality(GraphicsQuality),
     /// Difficulty option
     Difficulty(Difficulty),
+    /// Battlefield size option
+    MapSize(MapSize),
+    /// Floating damage numbers option
+    DamageNumbers(bool),
+    /// Projectile trail effects option
+    SpellTrails(bool),
+    /// Battle balance HUD hint option
+    BattleHint(bool),
+    /// Wind affecting arrows option
+    Wind(bool),
+    /// Personal ward around the wizard option
+    PersonalWard(bool),
+    /// Berserk units near death option
+    Berserk(bool),
+    /// Attacker shield wall formations option
+    ShieldWalls(bool),
+    /// Pause when the window loses focus option
+    PauseOnFocusLoss(bool),
+    /// Mouse button used for casting option
+    MouseBinding(MouseBinding),
+    /// Cast release grace option (in milliseconds)
+    CastGrace(u32),
+    /// Idle battle behind the main menu option
+    MenuBackground(bool),
+    /// Inverted menu scroll direction option
+    ScrollInvert(bool),
+    /// Tutorial option (false replays the tutorial next game)
+    TutorialCompleted(bool),
+    /// Wave preparation countdown option (in whole seconds)
+    PreparationDuration(u32),
+    /// Fixed simulation rate option (steps per second)
+    SimulationRate(u32),
+    /// What units leave behind on death option
+    CorpseMode(CorpseMode),
+    /// Corpse decay time option (in whole seconds)
+    CorpseDecayTime(u32),
+    /// Battlefield time of day option
+    TimeOfDay(TimeOfDayMode),
+    /// Projectile cap option (None = unlimited)
+    MaxProjectiles(Option<u32>),
+    /// UI color theme option
+    UiTheme(UiTheme),
+    /// Unit rendering style option
+    RenderStyle(RenderStyle),
 }
 
 impl OptionButtonValue {
@@ -29,7 +81,39 @@ impl OptionButtonValue {
     pub fn is_selected(&self, config: &crate::config::GameConfig) -> bool {
         match self {
             OptionButtonValue::VsyncMode(mode) => config.vsync == *mode,
+            OptionButtonValue::FrameCap(cap) => config.frame_cap == *cap,
+            OptionButtonValue::GraphicsQuality(quality) => config.graphics_quality == *quality,
             OptionButtonValue::Difficulty(difficulty) => config.difficulty == *difficulty,
+            OptionButtonValue::MapSize(map_size) => config.map_size == *map_size,
+            OptionButtonValue::DamageNumbers(show) => config.show_damage_numbers == *show,
+            OptionButtonValue::SpellTrails(show) => config.show_spell_trails == *show,
+            OptionButtonValue::BattleHint(show) => config.show_battle_hint == *show,
+            OptionButtonValue::Wind(enabled) => config.wind == *enabled,
+            OptionButtonValue::PersonalWard(enabled) => config.personal_ward == *enabled,
+            OptionButtonValue::Berserk(enabled) => config.berserk == *enabled,
+            OptionButtonValue::ShieldWalls(enabled) => config.shield_walls == *enabled,
+            OptionButtonValue::PauseOnFocusLoss(enabled) => config.pause_on_focus_loss == *enabled,
+            OptionButtonValue::MouseBinding(binding) => config.mouse_binding == *binding,
+            OptionButtonValue::CastGrace(millis) => {
+                (config.cast_grace * 1000.0).round() as u32 == *millis
+            }
+            OptionButtonValue::MenuBackground(enabled) => config.menu_background == *enabled,
+            OptionButtonValue::ScrollInvert(invert) => config.scroll_invert == *invert,
+            OptionButtonValue::TutorialCompleted(completed) => {
+                config.tutorial_completed == *completed
+            }
+            OptionButtonValue::PreparationDuration(seconds) => {
+                config.preparation_duration == *seconds as f32
+            }
+            OptionButtonValue::SimulationRate(rate) => config.simulation_rate == *rate,
+            OptionButtonValue::CorpseMode(mode) => config.corpse_mode == *mode,
+            OptionButtonValue::CorpseDecayTime(seconds) => {
+                config.corpse_decay_time == *seconds as f32
+            }
+            OptionButtonValue::TimeOfDay(mode) => config.time_of_day == *mode,
+            OptionButtonValue::MaxProjectiles(cap) => config.max_projectiles == *cap,
+            OptionButtonValue::UiTheme(theme) => config.ui_theme == *theme,
+            OptionButtonValue::RenderStyle(style) => config.render_style == *style,
         }
     }
 
@@ -37,7 +121,37 @@ impl OptionButtonValue {
     pub fn apply(&self, config: &mut crate::config::GameConfig) {
         match self {
             OptionButtonValue::VsyncMode(mode) => config.vsync = *mode,
+            OptionButtonValue::FrameCap(cap) => config.frame_cap = *cap,
+            OptionButtonValue::GraphicsQuality(quality) => config.graphics_quality = *quality,
             OptionButtonValue::Difficulty(difficulty) => config.difficulty = *difficulty,
+            OptionButtonValue::MapSize(map_size) => config.map_size = *map_size,
+            OptionButtonValue::DamageNumbers(show) => config.show_damage_numbers = *show,
+            OptionButtonValue::SpellTrails(show) => config.show_spell_trails = *show,
+            OptionButtonValue::BattleHint(show) => config.show_battle_hint = *show,
+            OptionButtonValue::Wind(enabled) => config.wind = *enabled,
+            OptionButtonValue::PersonalWard(enabled) => config.personal_ward = *enabled,
+            OptionButtonValue::Berserk(enabled) => config.berserk = *enabled,
+            OptionButtonValue::ShieldWalls(enabled) => config.shield_walls = *enabled,
+            OptionButtonValue::PauseOnFocusLoss(enabled) => config.pause_on_focus_loss = *enabled,
+            OptionButtonValue::MouseBinding(binding) => config.mouse_binding = *binding,
+            OptionButtonValue::CastGrace(millis) => config.cast_grace = *millis as f32 / 1000.0,
+            OptionButtonValue::MenuBackground(enabled) => config.menu_background = *enabled,
+            OptionButtonValue::ScrollInvert(invert) => config.scroll_invert = *invert,
+            OptionButtonValue::TutorialCompleted(completed) => {
+                config.tutorial_completed = *completed
+            }
+            OptionButtonValue::PreparationDuration(seconds) => {
+                config.preparation_duration = *seconds as f32
+            }
+            OptionButtonValue::SimulationRate(rate) => config.simulation_rate = *rate,
+            OptionButtonValue::CorpseMode(mode) => config.corpse_mode = *mode,
+            OptionButtonValue::CorpseDecayTime(seconds) => {
+                config.corpse_decay_time = *seconds as f32
+            }
+            OptionButtonValue::TimeOfDay(mode) => config.time_of_day = *mode,
+            OptionButtonValue::MaxProjectiles(cap) => config.max_projectiles = *cap,
+            OptionButtonValue::UiTheme(theme) => config.ui_theme = *theme,
+            OptionButtonValue::RenderStyle(style) => config.render_style = *style,
         }
     }
 }
@@ -72,8 +186,14 @@ pub enum SliderValue {
     MusicVolume,
     /// SFX volume (0.0-1.0)
     SfxVolume,
-    /// UI brightness (0.1-2.0, minimum 10% to prevent soft-lock)
+    /// Scene brightness (0.1-2.0, minimum 10% to prevent soft-lock)
     UiBrightness,
+    /// Menu scroll speed (0.25-2.5, never zero so scrolling keeps working)
+    ScrollSpeed,
+    /// Unit sprite size (0.5-2.0, visual only)
+    UnitRenderScale,
+    /// Menu and HUD size (0.75-1.5, leaves the battlefield view alone)
+    UiScale,
 }
 
 impl SliderValue {
@@ -84,6 +204,9 @@ impl SliderValue {
             SliderValue::MusicVolume => config.music_volume,
             SliderValue::SfxVolume => config.sfx_volume,
             SliderValue::UiBrightness => config.brightness,
+            SliderValue::ScrollSpeed => config.scroll_speed,
+            SliderValue::UnitRenderScale => config.unit_render_scale,
+            SliderValue::UiScale => config.ui_scale,
         }
     }
 
@@ -94,6 +217,9 @@ impl SliderValue {
             SliderValue::MusicVolume => config.music_volume = value,
             SliderValue::SfxVolume => config.sfx_volume = value,
             SliderValue::UiBrightness => config.brightness = value,
+            SliderValue::ScrollSpeed => config.scroll_speed = value,
+            SliderValue::UnitRenderScale => config.unit_render_scale = value,
+            SliderValue::UiScale => config.ui_scale = value,
         }
     }
 
@@ -102,6 +228,9 @@ impl SliderValue {
         match self {
             SliderValue::MasterVolume | SliderValue::MusicVolume | SliderValue::SfxVolume => 0.0,
             SliderValue::UiBrightness => 0.1, // 10% minimum to prevent soft-lock
+            SliderValue::ScrollSpeed => MIN_SCROLL_SPEED,
+            SliderValue::UnitRenderScale => MIN_UNIT_RENDER_SCALE,
+            SliderValue::UiScale => MIN_UI_SCALE,
         }
     }
 
@@ -110,6 +239,9 @@ impl SliderValue {
         match self {
             SliderValue::MasterVolume | SliderValue::MusicVolume | SliderValue::SfxVolume => 1.0,
             SliderValue::UiBrightness => 2.0,
+            SliderValue::ScrollSpeed => 2.5,
+            SliderValue::UnitRenderScale => MAX_UNIT_RENDER_SCALE,
+            SliderValue::UiScale => MAX_UI_SCALE,
         }
     }
 
@@ -118,6 +250,9 @@ impl SliderValue {
         match self {
             SliderValue::MasterVolume | SliderValue::MusicVolume | SliderValue::SfxVolume => 0.01,
             SliderValue::UiBrightness => 0.1,
+            SliderValue::ScrollSpeed => 0.25,
+            SliderValue::UnitRenderScale => 0.1,
+            SliderValue::UiScale => 0.05,
         }
     }
 }
@@ -1,10 +1,5 @@
 //! Settings menu styling constants.
 
-use bevy::prelude::*;
-
-/// Text color for settings menu UI elements.
-pub const TEXT_COLOR: Color = Color::hsla(0.0, 0.0, 0.9, 1.0);
-
 /// Font size for settings title text.
 pub const TITLE_FONT_SIZE: f32 = 48.0;
 
@@ -40,15 +35,3 @@ pub const BACK_BUTTON_HEIGHT: f32 = 50.0;
 
 /// Border width for buttons in pixels.
 pub const BUTTON_BORDER_WIDTH: f32 = 2.0;
-
-/// Normal button background color.
-pub const BUTTON_BACKGROUND: Color = Color::hsla(0.0, 0.0, 0.15, 1.0);
-
-/// Selected option button background color.
-pub const SELECTED_BACKGROUND: Color = Color::hsla(210.0, 0.7, 0.4, 1.0);
-
-/// Button border color.
-pub const BUTTON_BORDER: Color = Color::hsla(0.0, 0.0, 0.4, 1.0);
-
-/// Selected option button border color.
-pub const SELECTED_BORDER: Color = Color::hsla(210.0, 0.8, 0.6, 1.0);
@@ -6,8 +6,8 @@ use crate::state::MenuState;
 
 use super::systems::{
     button_hover, button_press, cleanup, handle_scroll, keyboard_input, option_button_action,
-    settings_button_action, setup, slider_button_action, slider_interaction,
-    update_selected_options, update_slider_text, update_sliders,
+    respawn_on_theme_change, settings_button_action, setup, slider_button_action,
+    slider_interaction, update_selected_options, update_slider_text, update_sliders,
 };
 
 /// Plugin that manages the settings menu UI.
@@ -18,6 +18,7 @@ use super::systems::{
 /// - Button interaction and actions
 /// - Unified slider controls for all config values
 /// - Selected option highlighting
+/// - Rebuilding the screen when the UI theme changes
 #[derive(Default)]
 pub struct SettingsPlugin;
 
@@ -39,6 +40,7 @@ impl Plugin for SettingsPlugin {
                     update_slider_text,
                     update_sliders,
                     update_selected_options,
+                    respawn_on_theme_change,
                 )
                     .run_if(in_state(MenuState::Settings)),
             );
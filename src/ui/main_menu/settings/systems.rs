@@ -6,9 +6,14 @@ use bevy::input::mouse::{MouseMotion, MouseWheel};
 use bevy::prelude::*;
 use bevy::ui::RelativeCursorPosition;
 
-use crate::config::{Difficulty, GameConfig, VsyncMode};
+use crate::config::{
+    CorpseMode, Difficulty, GameConfig, GraphicsQuality, MapSize, MouseBinding, RenderStyle,
+    TimeOfDayMode, UiTheme, VsyncMode,
+};
 use crate::state::{MenuState, PauseMenuState};
 use crate::ui::styles::{item_hovered, item_pressed};
+use crate::ui::systems::wheel_scroll_delta;
+use crate::ui::theme::{UiPalette, palette};
 
 /// Marker component to track that a button was pressed down.
 #[derive(Component)]
@@ -20,9 +25,8 @@ use super::components::{
     SliderUpButton, SliderValue,
 };
 use super::constants::{
-    BACK_BUTTON_HEIGHT, BACK_BUTTON_WIDTH, BUTTON_BACKGROUND, BUTTON_BORDER, BUTTON_BORDER_WIDTH,
-    BUTTON_FONT_SIZE, LABEL_FONT_SIZE, MARGIN, MARGIN_SMALL, OPTION_BUTTON_HEIGHT,
-    OPTION_BUTTON_WIDTH, SECTION_FONT_SIZE, SELECTED_BACKGROUND, SELECTED_BORDER, TEXT_COLOR,
+    BACK_BUTTON_HEIGHT, BACK_BUTTON_WIDTH, BUTTON_BORDER_WIDTH, BUTTON_FONT_SIZE, LABEL_FONT_SIZE,
+    MARGIN, MARGIN_SMALL, OPTION_BUTTON_HEIGHT, OPTION_BUTTON_WIDTH, SECTION_FONT_SIZE,
     TITLE_FONT_SIZE, VOLUME_BUTTON_SIZE,
 };
 
@@ -40,6 +44,8 @@ use super::constants::{
 /// * `commands` - Bevy command buffer for spawning entities
 /// * `game_config` - Current game configuration
 pub fn setup(mut commands: Commands, game_config: Res<GameConfig>) {
+    let palette = palette(game_config.ui_theme);
+
     commands
         .spawn((
             Node {
@@ -72,7 +78,7 @@ pub fn setup(mut commands: Commands, game_config: Res<GameConfig>) {
                             font_size: TITLE_FONT_SIZE,
                             ..default()
                         },
-                        TextColor(TEXT_COLOR),
+                        TextColor(palette.text),
                         Node {
                             margin: UiRect::bottom(Val::Px(MARGIN)),
                             ..default()
@@ -80,84 +86,493 @@ pub fn setup(mut commands: Commands, game_config: Res<GameConfig>) {
                     ));
 
                     // Graphics Settings Section
-                    spawn_section(parent, "Graphics", |section| {
+                    spawn_section(parent, "Graphics", palette, |section| {
                         // VSync Mode
-                        spawn_option_row(section, "VSync:", |buttons| {
+                        spawn_option_row(section, "VSync:", palette, |buttons| {
                             spawn_option_button(
                                 buttons,
                                 "On",
                                 OptionButtonValue::VsyncMode(VsyncMode::On),
                                 game_config.vsync == VsyncMode::On,
+                                palette,
                             );
                             spawn_option_button(
                                 buttons,
                                 "Off",
                                 OptionButtonValue::VsyncMode(VsyncMode::Off),
                                 game_config.vsync == VsyncMode::Off,
+                                palette,
                             );
                             spawn_option_button(
                                 buttons,
                                 "Adaptive",
                                 OptionButtonValue::VsyncMode(VsyncMode::Adaptive),
                                 game_config.vsync == VsyncMode::Adaptive,
+                                palette,
+                            );
+                        });
+                        // Frame rate cap (only applies while VSync is off)
+                        spawn_option_row(section, "Frame Cap:", palette, |buttons| {
+                            for (label, cap) in [
+                                ("30", Some(30)),
+                                ("60", Some(60)),
+                                ("120", Some(120)),
+                                ("Uncapped", None),
+                            ] {
+                                let value = OptionButtonValue::FrameCap(cap);
+                                spawn_option_button(
+                                    buttons,
+                                    label,
+                                    value,
+                                    value.is_selected(&game_config),
+                                    palette,
+                                );
+                            }
+                        });
+                        // Anti-aliasing, shadows, and effect density
+                        spawn_option_row(section, "Quality:", palette, |buttons| {
+                            for (label, quality) in [
+                                ("Low", GraphicsQuality::Low),
+                                ("Medium", GraphicsQuality::Medium),
+                                ("High", GraphicsQuality::High),
+                            ] {
+                                let value = OptionButtonValue::GraphicsQuality(quality);
+                                spawn_option_button(
+                                    buttons,
+                                    label,
+                                    value,
+                                    value.is_selected(&game_config),
+                                    palette,
+                                );
+                            }
+                        });
+                        // Idle battle behind the main menu
+                        spawn_option_row(section, "Menu Battle:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "On",
+                                OptionButtonValue::MenuBackground(true),
+                                game_config.menu_background,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Off",
+                                OptionButtonValue::MenuBackground(false),
+                                !game_config.menu_background,
+                                palette,
                             );
                         });
                     });
 
                     // Audio Settings Section
-                    spawn_section(parent, "Audio", |section| {
+                    spawn_section(parent, "Audio", palette, |section| {
                         spawn_slider_control(
                             section,
                             "Master Volume:",
                             SliderValue::MasterVolume,
                             &game_config,
+                            palette,
                         );
                         spawn_slider_control(
                             section,
                             "Music Volume:",
                             SliderValue::MusicVolume,
                             &game_config,
+                            palette,
                         );
                         spawn_slider_control(
                             section,
                             "SFX Volume:",
                             SliderValue::SfxVolume,
                             &game_config,
+                            palette,
                         );
                     });
 
                     // Display Settings Section
-                    spawn_section(parent, "Display", |section| {
+                    spawn_section(parent, "Display", palette, |section| {
                         spawn_slider_control(
                             section,
                             "Brightness:",
                             SliderValue::UiBrightness,
                             &game_config,
+                            palette,
+                        );
+                        spawn_slider_control(
+                            section,
+                            "Scroll Speed:",
+                            SliderValue::ScrollSpeed,
+                            &game_config,
+                            palette,
+                        );
+                        spawn_option_row(section, "Invert Scroll:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "On",
+                                OptionButtonValue::ScrollInvert(true),
+                                game_config.scroll_invert,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Off",
+                                OptionButtonValue::ScrollInvert(false),
+                                !game_config.scroll_invert,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Theme:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "Default",
+                                OptionButtonValue::UiTheme(UiTheme::Default),
+                                game_config.ui_theme == UiTheme::Default,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "High Contrast",
+                                OptionButtonValue::UiTheme(UiTheme::HighContrast),
+                                game_config.ui_theme == UiTheme::HighContrast,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Units:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "Billboard",
+                                OptionButtonValue::RenderStyle(RenderStyle::Billboard),
+                                game_config.render_style == RenderStyle::Billboard,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Top-Down",
+                                OptionButtonValue::RenderStyle(RenderStyle::TopDown),
+                                game_config.render_style == RenderStyle::TopDown,
+                                palette,
+                            );
+                        });
+                        spawn_slider_control(
+                            section,
+                            "Unit Size:",
+                            SliderValue::UnitRenderScale,
+                            &game_config,
+                            palette,
+                        );
+                        spawn_slider_control(
+                            section,
+                            "UI Scale:",
+                            SliderValue::UiScale,
+                            &game_config,
+                            palette,
                         );
                     });
 
                     // Game Settings Section
-                    spawn_section(parent, "Game", |section| {
-                        spawn_option_row(section, "Difficulty:", |buttons| {
+                    spawn_section(parent, "Game", palette, |section| {
+                        spawn_option_row(section, "Difficulty:", palette, |buttons| {
                             spawn_option_button(
                                 buttons,
                                 "Easy",
                                 OptionButtonValue::Difficulty(Difficulty::Easy),
                                 game_config.difficulty == Difficulty::Easy,
+                                palette,
                             );
                             spawn_option_button(
                                 buttons,
                                 "Normal",
                                 OptionButtonValue::Difficulty(Difficulty::Normal),
                                 game_config.difficulty == Difficulty::Normal,
+                                palette,
                             );
                             spawn_option_button(
                                 buttons,
                                 "Hard",
                                 OptionButtonValue::Difficulty(Difficulty::Hard),
                                 game_config.difficulty == Difficulty::Hard,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Map Size:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "Standard",
+                                OptionButtonValue::MapSize(MapSize::Standard),
+                                game_config.map_size == MapSize::Standard,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Large",
+                                OptionButtonValue::MapSize(MapSize::Large),
+                                game_config.map_size == MapSize::Large,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Damage Numbers:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "On",
+                                OptionButtonValue::DamageNumbers(true),
+                                game_config.show_damage_numbers,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Off",
+                                OptionButtonValue::DamageNumbers(false),
+                                !game_config.show_damage_numbers,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Spell Trails:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "On",
+                                OptionButtonValue::SpellTrails(true),
+                                game_config.show_spell_trails,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Off",
+                                OptionButtonValue::SpellTrails(false),
+                                !game_config.show_spell_trails,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Battle Hint:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "On",
+                                OptionButtonValue::BattleHint(true),
+                                game_config.show_battle_hint,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Off",
+                                OptionButtonValue::BattleHint(false),
+                                !game_config.show_battle_hint,
+                                palette,
                             );
                         });
+                        spawn_option_row(section, "Wind:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "On",
+                                OptionButtonValue::Wind(true),
+                                game_config.wind,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Off",
+                                OptionButtonValue::Wind(false),
+                                !game_config.wind,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Personal Ward:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "On",
+                                OptionButtonValue::PersonalWard(true),
+                                game_config.personal_ward,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Off",
+                                OptionButtonValue::PersonalWard(false),
+                                !game_config.personal_ward,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Berserk:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "On",
+                                OptionButtonValue::Berserk(true),
+                                game_config.berserk,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Off",
+                                OptionButtonValue::Berserk(false),
+                                !game_config.berserk,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Shield Walls:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "On",
+                                OptionButtonValue::ShieldWalls(true),
+                                game_config.shield_walls,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Off",
+                                OptionButtonValue::ShieldWalls(false),
+                                !game_config.shield_walls,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Pause on Focus Loss:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "On",
+                                OptionButtonValue::PauseOnFocusLoss(true),
+                                game_config.pause_on_focus_loss,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Off",
+                                OptionButtonValue::PauseOnFocusLoss(false),
+                                !game_config.pause_on_focus_loss,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Cast Button:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "Left",
+                                OptionButtonValue::MouseBinding(MouseBinding::LeftCast),
+                                game_config.mouse_binding == MouseBinding::LeftCast,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Right",
+                                OptionButtonValue::MouseBinding(MouseBinding::RightCast),
+                                game_config.mouse_binding == MouseBinding::RightCast,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Cast Grace:", palette, |buttons| {
+                            for (label, millis) in
+                                [("Off", 0), ("100ms", 100), ("200ms", 200), ("300ms", 300)]
+                            {
+                                let value = OptionButtonValue::CastGrace(millis);
+                                spawn_option_button(
+                                    buttons,
+                                    label,
+                                    value,
+                                    value.is_selected(&game_config),
+                                    palette,
+                                );
+                            }
+                        });
+                        spawn_option_row(section, "Tutorial:", palette, |buttons| {
+                            spawn_option_button(
+                                buttons,
+                                "Replay",
+                                OptionButtonValue::TutorialCompleted(false),
+                                !game_config.tutorial_completed,
+                                palette,
+                            );
+                            spawn_option_button(
+                                buttons,
+                                "Skip",
+                                OptionButtonValue::TutorialCompleted(true),
+                                game_config.tutorial_completed,
+                                palette,
+                            );
+                        });
+                        spawn_option_row(section, "Prep Time:", palette, |buttons| {
+                            for (label, seconds) in [("Off", 0), ("3s", 3), ("5s", 5), ("10s", 10)]
+                            {
+                                let value = OptionButtonValue::PreparationDuration(seconds);
+                                spawn_option_button(
+                                    buttons,
+                                    label,
+                                    value,
+                                    value.is_selected(&game_config),
+                                    palette,
+                                );
+                            }
+                        });
+                        spawn_option_row(section, "Sim Rate:", palette, |buttons| {
+                            for (label, rate) in [("30 Hz", 30), ("60 Hz", 60), ("120 Hz", 120)] {
+                                let value = OptionButtonValue::SimulationRate(rate);
+                                spawn_option_button(
+                                    buttons,
+                                    label,
+                                    value,
+                                    value.is_selected(&game_config),
+                                    palette,
+                                );
+                            }
+                        });
+                        spawn_option_row(section, "Max Projectiles:", palette, |buttons| {
+                            for (label, cap) in [
+                                ("200", Some(200)),
+                                ("400", Some(400)),
+                                ("800", Some(800)),
+                                ("Unlimited", None),
+                            ] {
+                                let value = OptionButtonValue::MaxProjectiles(cap);
+                                spawn_option_button(
+                                    buttons,
+                                    label,
+                                    value,
+                                    value.is_selected(&game_config),
+                                    palette,
+                                );
+                            }
+                        });
+                        spawn_option_row(section, "Corpses:", palette, |buttons| {
+                            for (label, mode) in [
+                                ("Persistent", CorpseMode::Persistent),
+                                ("Decay", CorpseMode::Decay),
+                                ("None", CorpseMode::None),
+                            ] {
+                                let value = OptionButtonValue::CorpseMode(mode);
+                                spawn_option_button(
+                                    buttons,
+                                    label,
+                                    value,
+                                    value.is_selected(&game_config),
+                                    palette,
+                                );
+                            }
+                        });
+                        spawn_option_row(section, "Corpse Decay:", palette, |buttons| {
+                            for (label, seconds) in [("10s", 10), ("30s", 30), ("60s", 60)] {
+                                let value = OptionButtonValue::CorpseDecayTime(seconds);
+                                spawn_option_button(
+                                    buttons,
+                                    label,
+                                    value,
+                                    value.is_selected(&game_config),
+                                    palette,
+                                );
+                            }
+                        });
+                        spawn_option_row(section, "Time of Day:", palette, |buttons| {
+                            for (label, mode) in [
+                                ("Dawn", TimeOfDayMode::Dawn),
+                                ("Noon", TimeOfDayMode::Noon),
+                                ("Dusk", TimeOfDayMode::Dusk),
+                                ("Night", TimeOfDayMode::Night),
+                                ("Cycle", TimeOfDayMode::Cycle),
+                            ] {
+                                let value = OptionButtonValue::TimeOfDay(mode);
+                                spawn_option_button(
+                                    buttons,
+                                    label,
+                                    value,
+                                    value.is_selected(&game_config),
+                                    palette,
+                                );
+                            }
+                        });
                     });
 
                     // Back button
@@ -173,11 +588,11 @@ pub fn setup(mut commands: Commands, game_config: Res<GameConfig>) {
                                 margin: UiRect::top(Val::Px(MARGIN)),
                                 ..default()
                             },
-                            BorderColor::all(BUTTON_BORDER),
+                            BorderColor::all(palette.button_border),
                             BorderRadius::all(Val::Px(8.0)),
-                            BackgroundColor(BUTTON_BACKGROUND),
+                            BackgroundColor(palette.button_background),
                             ButtonColors {
-                                background: BUTTON_BACKGROUND,
+                                background: palette.button_background,
                             },
                             SettingsButtonAction::Back,
                         ))
@@ -188,7 +603,7 @@ pub fn setup(mut commands: Commands, game_config: Res<GameConfig>) {
                                     font_size: BUTTON_FONT_SIZE,
                                     ..default()
                                 },
-                                TextColor(TEXT_COLOR),
+                                TextColor(palette.text),
                             ));
                         });
                 });
@@ -199,6 +614,7 @@ pub fn setup(mut commands: Commands, game_config: Res<GameConfig>) {
 fn spawn_section(
     parent: &mut ChildSpawnerCommands,
     title: &str,
+    palette: &UiPalette,
     spawn_content: impl FnOnce(&mut ChildSpawnerCommands),
 ) {
     parent
@@ -217,7 +633,7 @@ fn spawn_section(
                     font_size: SECTION_FONT_SIZE,
                     ..default()
                 },
-                TextColor(TEXT_COLOR),
+                TextColor(palette.text),
                 Node {
                     margin: UiRect::bottom(Val::Px(MARGIN_SMALL)),
                     ..default()
@@ -232,6 +648,7 @@ fn spawn_section(
 fn spawn_option_row(
     parent: &mut ChildSpawnerCommands,
     label: &str,
+    palette: &UiPalette,
     spawn_buttons: impl FnOnce(&mut ChildSpawnerCommands),
 ) {
     parent
@@ -250,7 +667,7 @@ fn spawn_option_row(
                     font_size: LABEL_FONT_SIZE,
                     ..default()
                 },
-                TextColor(TEXT_COLOR),
+                TextColor(palette.text),
                 Node {
                     width: Val::Px(200.0),
                     ..default()
@@ -273,12 +690,9 @@ fn spawn_option_button(
     text: &str,
     value: OptionButtonValue,
     is_selected: bool,
+    palette: &UiPalette,
 ) {
-    let (bg_color, border_color) = if is_selected {
-        (SELECTED_BACKGROUND, SELECTED_BORDER)
-    } else {
-        (BUTTON_BACKGROUND, BUTTON_BORDER)
-    };
+    let (bg_color, border_color) = option_button_colors(is_selected, palette);
 
     let mut entity = parent.spawn((
         Button,
@@ -310,15 +724,25 @@ fn spawn_option_button(
                 font_size: BUTTON_FONT_SIZE,
                 ..default()
             },
-            TextColor(TEXT_COLOR),
+            TextColor(palette.text),
         ));
     });
 }
 
+/// Returns the (background, border) colors for an option button.
+fn option_button_colors(is_selected: bool, palette: &UiPalette) -> (Color, Color) {
+    if is_selected {
+        (palette.selected_background, palette.selected_border)
+    } else {
+        (palette.button_background, palette.button_border)
+    }
+}
+
 /// Configuration for spawning a slider row.
 struct SliderRowConfig<'a, TText, TDownButton, TUpButton, TSliderTrack, TSliderFill, TSliderHandle>
 {
     label: &'a str,
+    palette: &'a UiPalette,
     current_value: f32,
     max_value: f32,
     text_component: TText,
@@ -351,6 +775,7 @@ fn spawn_slider_row<
 ) {
     let SliderRowConfig {
         label,
+        palette,
         current_value,
         max_value,
         text_component,
@@ -376,7 +801,7 @@ fn spawn_slider_row<
                     font_size: LABEL_FONT_SIZE,
                     ..default()
                 },
-                TextColor(TEXT_COLOR),
+                TextColor(palette.text),
                 Node {
                     width: Val::Px(200.0),
                     ..default()
@@ -403,11 +828,11 @@ fn spawn_slider_row<
                             align_items: AlignItems::Center,
                             ..default()
                         },
-                        BorderColor::all(BUTTON_BORDER),
+                        BorderColor::all(palette.button_border),
                         BorderRadius::all(Val::Px(4.0)),
-                        BackgroundColor(BUTTON_BACKGROUND),
+                        BackgroundColor(palette.button_background),
                         ButtonColors {
-                            background: BUTTON_BACKGROUND,
+                            background: palette.button_background,
                         },
                         down_button,
                     ))
@@ -418,7 +843,7 @@ fn spawn_slider_row<
                                 font_size: BUTTON_FONT_SIZE,
                                 ..default()
                             },
-                            TextColor(TEXT_COLOR),
+                            TextColor(palette.text),
                         ));
                     });
 
@@ -434,7 +859,7 @@ fn spawn_slider_row<
                             position_type: PositionType::Relative,
                             ..default()
                         },
-                        BorderColor::all(BUTTON_BORDER),
+                        BorderColor::all(palette.button_border),
                         BorderRadius::all(Val::Px(6.0)),
                         BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
                         Interaction::default(),
@@ -456,7 +881,7 @@ fn spawn_slider_row<
                                 top_right: Val::Px(0.0),
                                 bottom_right: Val::Px(0.0),
                             },
-                            BackgroundColor(BUTTON_BORDER),
+                            BackgroundColor(palette.button_border),
                             slider_fill,
                         ));
 
@@ -472,7 +897,7 @@ fn spawn_slider_row<
                             },
                             BorderRadius::all(Val::Px(2.0)),
                             BackgroundColor(Color::WHITE),
-                            BorderColor::all(BUTTON_BORDER),
+                            BorderColor::all(palette.button_border),
                             Interaction::default(),
                             RelativeCursorPosition::default(),
                             slider_handle,
@@ -491,11 +916,11 @@ fn spawn_slider_row<
                             align_items: AlignItems::Center,
                             ..default()
                         },
-                        BorderColor::all(BUTTON_BORDER),
+                        BorderColor::all(palette.button_border),
                         BorderRadius::all(Val::Px(4.0)),
-                        BackgroundColor(BUTTON_BACKGROUND),
+                        BackgroundColor(palette.button_background),
                         ButtonColors {
-                            background: BUTTON_BACKGROUND,
+                            background: palette.button_background,
                         },
                         up_button,
                     ))
@@ -506,7 +931,7 @@ fn spawn_slider_row<
                                 font_size: BUTTON_FONT_SIZE,
                                 ..default()
                             },
-                            TextColor(TEXT_COLOR),
+                            TextColor(palette.text),
                         ));
                     });
 
@@ -517,7 +942,7 @@ fn spawn_slider_row<
                         font_size: LABEL_FONT_SIZE,
                         ..default()
                     },
-                    TextColor(TEXT_COLOR),
+                    TextColor(palette.text),
                     Node {
                         width: Val::Px(60.0),
                         justify_content: JustifyContent::Center,
@@ -535,6 +960,7 @@ fn spawn_slider_control(
     label: &str,
     slider_value: SliderValue,
     game_config: &GameConfig,
+    palette: &UiPalette,
 ) {
     let current_value = slider_value.get(game_config);
     let max_value = slider_value.max_value();
@@ -543,6 +969,7 @@ fn spawn_slider_control(
         parent,
         SliderRowConfig {
             label,
+            palette,
             current_value,
             max_value,
             text_component: SliderText {
@@ -582,6 +1009,24 @@ pub fn cleanup(mut commands: Commands, settings_items: Query<Entity, With<OnSett
     }
 }
 
+/// Rebuilds the settings screen in the new colors when the UI theme changes.
+///
+/// Every other screen picks up the theme the next time it is spawned.
+pub fn respawn_on_theme_change(
+    mut commands: Commands,
+    game_config: Res<GameConfig>,
+    mut spawned_theme: Local<Option<UiTheme>>,
+) {
+    let theme = game_config.ui_theme;
+    if spawned_theme
+        .replace(theme)
+        .is_some_and(|previous| previous != theme)
+    {
+        commands.run_system_cached(cleanup);
+        commands.run_system_cached(setup);
+    }
+}
+
 /// Handles keyboard input in the settings menu from main menu.
 ///
 /// - Escape: Returns to Landing screen
@@ -790,23 +1235,19 @@ pub fn option_button_action(
 /// # Arguments
 ///
 /// * `mouse_wheel_events` - Event reader for mouse wheel events
+/// * `config` - Scroll direction and speed settings
 /// * `hover_map` - Map of hovered UI entities
 /// * `scrollable_query` - Query for scrollable nodes with ScrollPosition
 /// * `parent_query` - Query for parent entities to walk up the hierarchy
 pub fn handle_scroll(
     mut mouse_wheel_events: MessageReader<MouseWheel>,
+    config: Res<GameConfig>,
     hover_map: Res<bevy::picking::hover::HoverMap>,
     mut scrollable_query: Query<(&mut ScrollPosition, &ComputedNode), With<ScrollableContainer>>,
     parent_query: Query<&ChildOf>,
 ) {
-    const LINE_HEIGHT: f32 = 10.0;
-    const PIXEL_SCROLL_MULTIPLIER: f32 = 0.3;
-
     for event in mouse_wheel_events.read() {
-        let dy = match event.unit {
-            bevy::input::mouse::MouseScrollUnit::Line => -event.y * LINE_HEIGHT,
-            bevy::input::mouse::MouseScrollUnit::Pixel => -event.y * PIXEL_SCROLL_MULTIPLIER,
-        };
+        let dy = wheel_scroll_delta(event, &config);
 
         // Check if we're hovering over the scrollable container or any of its children
         for pointer_map in hover_map.values() {
@@ -1050,16 +1491,18 @@ pub fn update_selected_options(
     >,
 ) {
     if game_config.is_changed() {
+        let palette = palette(game_config.ui_theme);
         for (entity, value, mut bg, mut border) in &mut option_buttons {
-            if value.is_selected(&game_config) {
+            let is_selected = value.is_selected(&game_config);
+            if is_selected {
                 commands.entity(entity).insert(SelectedOption);
-                *bg = BackgroundColor(SELECTED_BACKGROUND);
-                *border = BorderColor::all(SELECTED_BORDER);
             } else {
                 commands.entity(entity).remove::<SelectedOption>();
-                *bg = BackgroundColor(BUTTON_BACKGROUND);
-                *border = BorderColor::all(BUTTON_BORDER);
             }
+
+            let (bg_color, border_color) = option_button_colors(is_selected, palette);
+            *bg = BackgroundColor(bg_color);
+            *border = BorderColor::all(border_color);
         }
     }
 }
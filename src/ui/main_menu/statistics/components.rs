@@ -0,0 +1,11 @@
+//! Components for the spell statistics screen.
+
+use bevy::prelude::*;
+
+/// Marker component for entities that should be despawned when leaving the statistics screen.
+#[derive(Component)]
+pub struct OnStatisticsScreen;
+
+/// Marker component for the back button.
+#[derive(Component)]
+pub struct BackButton;
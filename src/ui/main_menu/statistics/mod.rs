@@ -0,0 +1,7 @@
+//! Spell statistics screen module.
+
+mod components;
+mod plugin;
+mod systems;
+
+pub use plugin::StatisticsPlugin;
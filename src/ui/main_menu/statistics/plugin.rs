@@ -0,0 +1,20 @@
+//! Plugin for the spell statistics screen.
+
+use bevy::prelude::*;
+
+use super::systems;
+use crate::state::MenuState;
+
+/// Plugin that handles the spell statistics screen.
+pub struct StatisticsPlugin;
+
+impl Plugin for StatisticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(MenuState::Statistics), systems::setup)
+            .add_systems(
+                Update,
+                systems::handle_back_button.run_if(in_state(MenuState::Statistics)),
+            )
+            .add_systems(OnExit(MenuState::Statistics), systems::cleanup);
+    }
+}
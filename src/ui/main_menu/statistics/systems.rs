@@ -0,0 +1,162 @@
+//! Systems for the spell statistics screen.
+
+use bevy::prelude::*;
+
+use super::components::{BackButton, OnStatisticsScreen};
+use crate::config::{GameConfig, SpellStats};
+use crate::game::units::wizard::components::Spell;
+use crate::state::MenuState;
+use crate::ui::components::ButtonColors;
+use crate::ui::theme::palette;
+
+/// Width of each column in the statistics table in pixels.
+const COLUMN_WIDTH: f32 = 200.0;
+
+/// Font size for the statistics table.
+const TABLE_FONT_SIZE: f32 = 22.0;
+
+/// Spawns the statistics screen UI: one row per spell with its lifetime
+/// casts, total damage, and average damage per cast.
+pub fn setup(mut commands: Commands, config: Res<GameConfig>, stats: Res<SpellStats>) {
+    let palette = palette(config.ui_theme);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(20.0)),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK),
+            OnStatisticsScreen,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new("Spell Statistics"),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(palette.text),
+                Node {
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            // Table
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(20.0)),
+                        row_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.8)),
+                ))
+                .with_children(|parent| {
+                    spawn_row(
+                        parent,
+                        ["Spell", "Casts", "Damage", "Damage / Cast"].map(String::from),
+                        palette.text,
+                    );
+
+                    for &spell in Spell::all() {
+                        let usage = stats.usage(spell);
+                        spawn_row(
+                            parent,
+                            [
+                                spell.name().to_string(),
+                                usage.casts.to_string(),
+                                format!("{:.0}", usage.damage),
+                                format!("{:.1}", usage.damage_per_cast()),
+                            ],
+                            palette.text,
+                        );
+                    }
+                });
+
+            // Back button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(60.0),
+                        border: UiRect::all(Val::Px(3.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::top(Val::Px(20.0)),
+                        ..default()
+                    },
+                    BorderColor::all(palette.menu_button_border),
+                    BorderRadius::all(Val::Px(8.0)),
+                    BackgroundColor(palette.button_background),
+                    ButtonColors {
+                        background: palette.button_background,
+                        border: palette.menu_button_border,
+                    },
+                    BackButton,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Back"),
+                        TextFont {
+                            font_size: 32.0,
+                            ..default()
+                        },
+                        TextColor(palette.text),
+                    ));
+                });
+        });
+}
+
+/// Spawns one table row with a fixed-width cell per column.
+fn spawn_row(parent: &mut ChildSpawnerCommands, cells: [String; 4], color: Color) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            ..default()
+        })
+        .with_children(|row| {
+            for cell in cells {
+                row.spawn((
+                    Text::new(cell),
+                    TextFont {
+                        font_size: TABLE_FONT_SIZE,
+                        ..default()
+                    },
+                    TextColor(color),
+                    Node {
+                        width: Val::Px(COLUMN_WIDTH),
+                        ..default()
+                    },
+                ));
+            }
+        });
+}
+
+/// Handles back button interactions.
+pub fn handle_back_button(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<BackButton>)>,
+    mut next_state: ResMut<NextState<MenuState>>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            next_state.set(MenuState::Landing);
+        }
+    }
+}
+
+/// Despawns all statistics screen entities.
+pub fn cleanup(mut commands: Commands, query: Query<Entity, With<OnStatisticsScreen>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
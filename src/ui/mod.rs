@@ -3,7 +3,9 @@
 //! This module provides the user interface systems and components,
 //! organized by menu/screen type.
 
+mod cast_log;
 mod components;
+mod damage_numbers;
 mod game_over;
 mod in_game;
 mod main_menu;
@@ -12,6 +14,9 @@ mod plugin;
 mod spell_book;
 mod styles;
 mod systems;
+mod theme;
+mod threat_indicators;
+mod tutorial;
 mod version;
 
 pub use plugin::UiPlugin;
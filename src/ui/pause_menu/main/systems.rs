@@ -3,11 +3,13 @@
 use bevy::input::keyboard::KeyCode;
 use bevy::prelude::*;
 
+use crate::config::GameConfig;
 use crate::state::{AppState, InGameState, PauseMenuState};
 use crate::ui::systems::spawn_button;
+use crate::ui::theme::palette;
 
 use super::components::{OnPauseMainScreen, PauseMenuButtonAction};
-use super::constants::{BUTTON_STYLE, MARGIN, TEXT_COLOR, TITLE_FONT_SIZE};
+use super::constants::{BUTTON_STYLE, MARGIN, TITLE_FONT_SIZE};
 
 /// Marker component to track that a button was pressed down.
 #[derive(Component)]
@@ -17,7 +19,9 @@ pub(super) struct ButtonPressedDown;
 ///
 /// Spawns the root UI node containing the title and menu buttons.
 /// All spawned entities are marked with `OnPauseMainScreen` for cleanup.
-pub fn setup(mut commands: Commands) {
+pub fn setup(mut commands: Commands, config: Res<GameConfig>) {
+    let palette = palette(config.ui_theme);
+
     // Root container - full screen, centered content in a column
     commands
         .spawn((
@@ -33,7 +37,7 @@ pub fn setup(mut commands: Commands) {
             OnPauseMainScreen,
             // Semi-transparent dark background to dim the game behind
             BackgroundColor(Color::BLACK.with_alpha(0.7)),
-            GlobalZIndex(500), // Above game, below brightness overlay
+            GlobalZIndex(500), // Above game
         ))
         .with_children(|parent| {
             // Title text
@@ -43,7 +47,7 @@ pub fn setup(mut commands: Commands) {
                     font_size: TITLE_FONT_SIZE,
                     ..default()
                 },
-                TextColor(TEXT_COLOR),
+                TextColor(palette.text),
                 Node {
                     margin: UiRect::bottom(Val::Px(MARGIN * 2.0)),
                     ..default()
@@ -56,6 +60,7 @@ pub fn setup(mut commands: Commands) {
                 "Continue",
                 PauseMenuButtonAction::Continue,
                 &BUTTON_STYLE,
+                palette,
             );
 
             // Settings button
@@ -64,6 +69,7 @@ pub fn setup(mut commands: Commands) {
                 "Settings",
                 PauseMenuButtonAction::Settings,
                 &BUTTON_STYLE,
+                palette,
             );
 
             // Exit button
@@ -72,6 +78,7 @@ pub fn setup(mut commands: Commands) {
                 "Exit to Menu",
                 PauseMenuButtonAction::Exit,
                 &BUTTON_STYLE,
+                palette,
             );
         });
 }
@@ -8,8 +8,8 @@ use bevy::prelude::*;
 use crate::state::PauseMenuState;
 use crate::ui::main_menu::settings::systems::{
     button_hover, button_press, cleanup, handle_scroll, option_button_action, pause_keyboard_input,
-    pause_settings_button_action, setup, slider_button_action, slider_interaction,
-    update_selected_options, update_slider_text, update_sliders,
+    pause_settings_button_action, respawn_on_theme_change, setup, slider_button_action,
+    slider_interaction, update_selected_options, update_slider_text, update_sliders,
 };
 
 /// Plugin that manages the pause menu settings UI.
@@ -37,6 +37,7 @@ impl Plugin for PauseSettingsPlugin {
                     update_slider_text,
                     update_sliders,
                     update_selected_options,
+                    respawn_on_theme_change,
                 )
                     .run_if(in_state(PauseMenuState::Settings)),
             );
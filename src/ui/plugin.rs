@@ -6,13 +6,18 @@ use bevy::prelude::*;
 use bevy::ui::UiScale as BevyUiScale;
 use bevy::window::PrimaryWindow;
 
+use super::cast_log::plugin::CastLogPlugin;
+use super::damage_numbers::plugin::DamageNumbersPlugin;
 use super::game_over::GameOverPlugin;
 use super::in_game::plugin::InGamePlugin;
 use super::main_menu::MainMenuPlugin;
 use super::pause_menu::plugin::PauseMenuPlugin;
 use super::spell_book::SpellBookPlugin;
 use super::systems;
+use super::threat_indicators::plugin::ThreatIndicatorsPlugin;
+use super::tutorial::plugin::TutorialPlugin;
 use super::version::VersionPlugin;
+use crate::config::GameConfig;
 
 /// Top-level UI plugin that manages all UI systems.
 ///
@@ -29,29 +34,74 @@ impl Plugin for UiPlugin {
             PauseMenuPlugin,
             SpellBookPlugin,
             GameOverPlugin,
+            DamageNumbersPlugin,
+            ThreatIndicatorsPlugin,
+            TutorialPlugin,
+            CastLogPlugin,
             VersionPlugin,
         ))
         .add_systems(Update, (update_ui_scale, systems::button_interaction));
     }
 }
 
-/// Updates the global UI scale based on window width.
+/// Updates the global UI scale based on window width and the UI Scale setting.
 ///
 /// Uses Bevy's built-in UiScale resource to scale all UI elements.
 /// Calculates scale factor relative to a base width of 1920px, then applies
-/// a 1.5x multiplier to make everything larger.
-/// This ensures UI elements shrink/grow proportionally with window size.
+/// a 1.5x multiplier to make everything larger, and the player's `ui_scale`
+/// on top. This ensures UI elements shrink/grow proportionally with window size.
+/// UiScale only affects UI nodes and text, so the 3D camera and battlefield
+/// keep their zoom.
 fn update_ui_scale(
+    config: Res<GameConfig>,
     mut ui_scale: ResMut<BevyUiScale>,
     window_query: Query<&Window, With<PrimaryWindow>>,
 ) {
     if let Ok(window) = window_query.single() {
         const BASE_WIDTH: f32 = 1920.0;
         const SCALE_MULTIPLIER: f32 = 1.5;
-        let new_scale = (window.width() / BASE_WIDTH) * SCALE_MULTIPLIER;
+        let new_scale = (window.width() / BASE_WIDTH) * SCALE_MULTIPLIER * config.ui_scale;
 
         if (ui_scale.0 - new_scale).abs() > 0.001 {
             ui_scale.0 = new_scale;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ui_scale_setting_multiplies_window_scale() {
+        let mut app = App::new();
+        app.init_resource::<GameConfig>()
+            .init_resource::<BevyUiScale>()
+            .add_systems(Update, update_ui_scale);
+
+        let window = Window::default();
+        let window_scale = window.width() / 1920.0 * 1.5;
+        app.world_mut().spawn((window, PrimaryWindow));
+
+        app.update();
+        let ui_scale = app.world().resource::<BevyUiScale>().0;
+        assert!((ui_scale - window_scale).abs() < 1e-5);
+
+        // Raising the setting mid-session grows the UI on the next frame
+        app.world_mut().resource_mut::<GameConfig>().ui_scale = 1.5;
+        app.update();
+        let ui_scale = app.world().resource::<BevyUiScale>().0;
+        assert!((ui_scale - window_scale * 1.5).abs() < 1e-5);
+
+        // And it still follows the window width on top of the setting
+        let mut windows = app
+            .world_mut()
+            .query_filtered::<&mut Window, With<PrimaryWindow>>();
+        let mut window = windows.single_mut(app.world_mut()).unwrap();
+        let (width, height) = (window.width(), window.height());
+        window.resolution.set(width / 2.0, height);
+        app.update();
+        let ui_scale = app.world().resource::<BevyUiScale>().0;
+        assert!((ui_scale - window_scale * 1.5 / 2.0).abs() < 1e-5);
+    }
+}
@@ -6,6 +6,12 @@ use crate::game::units::wizard::components::Spell;
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SpellBookButtonAction {
     SelectSpell(Spell),
+    /// Stars or unstars the spell as a favorite.
+    ToggleFavorite(Spell),
+    /// Moves the spell one place earlier in the book.
+    MoveEarlier(Spell),
+    /// Moves the spell one place later in the book.
+    MoveLater(Spell),
     Close,
 }
 
@@ -5,10 +5,12 @@ use bevy::ui::ComputedNode;
 
 use super::components::*;
 use super::constants::*;
+use crate::config::GameConfig;
 use crate::game::units::wizard::components::{PrimeSpellMessage, Spell};
 use crate::state::InGameState;
 use crate::ui::components::{ButtonColors, ButtonStyle};
-use crate::ui::systems::spawn_button;
+use crate::ui::systems::{spawn_button, wheel_scroll_delta};
+use crate::ui::theme::{UiPalette, palette};
 
 /// Resource to track when we just entered the spell book.
 /// Prevents spell casting on the same frame as opening the spell book.
@@ -20,7 +22,17 @@ pub struct JustEnteredSpellBook(pub bool);
 pub(super) struct ButtonPressedDown;
 
 /// Spawns the spell book UI when entering the SpellBook state.
-pub fn spawn_spell_book_ui(mut commands: Commands) {
+///
+/// Only spells in the player's loadout are listed, favorites first and
+/// otherwise in the player's chosen order.
+pub fn spawn_spell_book_ui(mut commands: Commands, config: Res<GameConfig>) {
+    spawn_spell_book(&mut commands, &config, ScrollPosition::default());
+}
+
+/// Builds the spell book, starting the spell list at the given scroll position.
+fn spawn_spell_book(commands: &mut Commands, config: &GameConfig, scroll: ScrollPosition) {
+    let palette = palette(config.ui_theme);
+
     commands
         .spawn((
             Node {
@@ -43,7 +55,7 @@ pub fn spawn_spell_book_ui(mut commands: Commands) {
                     font_size: TITLE_FONT_SIZE,
                     ..default()
                 },
-                TextColor(TEXT_COLOR),
+                TextColor(palette.text),
             ));
 
             // Scrollable horizontal container
@@ -60,7 +72,7 @@ pub fn spawn_spell_book_ui(mut commands: Commands) {
                     BorderColor::all(FRAME_BORDER_COLOR),
                     BorderRadius::all(Val::Px(8.0)),
                     BackgroundColor(FRAME_BACKGROUND),
-                    ScrollPosition::default(),
+                    scroll,
                     ScrollableSpellContainer,
                 ))
                 .with_children(|scroll| {
@@ -72,7 +84,7 @@ pub fn spawn_spell_book_ui(mut commands: Commands) {
                             ..default()
                         })
                         .with_children(|col| {
-                            let spells = Spell::all();
+                            let spells: Vec<Spell> = config.equipped_spells().collect();
 
                             // Buttons row
                             col.spawn(Node {
@@ -81,8 +93,12 @@ pub fn spawn_spell_book_ui(mut commands: Commands) {
                                 ..default()
                             })
                             .with_children(|row| {
-                                for spell in spells {
-                                    let name = spell.name();
+                                for spell in &spells {
+                                    let name = if config.is_favorite(*spell) {
+                                        format!("{FAVORITE_MARKER}{}", spell.name())
+                                    } else {
+                                        spell.name().to_string()
+                                    };
                                     let min_chars = 6.0;
                                     let max_chars = 16.0;
                                     let min_scale = 0.7;
@@ -93,14 +109,61 @@ pub fn spawn_spell_book_ui(mut commands: Commands) {
                                         BUTTON_FONT_SIZE * (1.0 - t * (1.0 - min_scale));
                                     spawn_spell_button(
                                         row,
-                                        name,
+                                        &name,
                                         SpellBookButtonAction::SelectSpell(*spell),
                                         &BUTTON_STYLE,
+                                        palette,
                                         font_size,
                                     );
                                 }
                             });
 
+                            // Favorite and reorder controls row
+                            col.spawn(Node {
+                                flex_direction: FlexDirection::Row,
+                                column_gap: Val::Px(SPELL_COLUMN_GAP),
+                                ..default()
+                            })
+                            .with_children(|row| {
+                                for spell in &spells {
+                                    row.spawn(Node {
+                                        width: Val::Px(SPELL_COLUMN_WIDTH),
+                                        justify_content: JustifyContent::Center,
+                                        column_gap: Val::Px(ORDER_CONTROLS_GAP),
+                                        ..default()
+                                    })
+                                    .with_children(
+                                        |controls| {
+                                            spawn_button(
+                                                controls,
+                                                "<",
+                                                SpellBookButtonAction::MoveEarlier(*spell),
+                                                &ORDER_BUTTON_STYLE,
+                                                palette,
+                                            );
+                                            spawn_button(
+                                                controls,
+                                                if config.is_favorite(*spell) {
+                                                    "Unstar"
+                                                } else {
+                                                    "Star"
+                                                },
+                                                SpellBookButtonAction::ToggleFavorite(*spell),
+                                                &FAVORITE_BUTTON_STYLE,
+                                                palette,
+                                            );
+                                            spawn_button(
+                                                controls,
+                                                ">",
+                                                SpellBookButtonAction::MoveLater(*spell),
+                                                &ORDER_BUTTON_STYLE,
+                                                palette,
+                                            );
+                                        },
+                                    );
+                                }
+                            });
+
                             // Instructions row
                             col.spawn(Node {
                                 flex_direction: FlexDirection::Row,
@@ -108,7 +171,7 @@ pub fn spawn_spell_book_ui(mut commands: Commands) {
                                 ..default()
                             })
                             .with_children(|row| {
-                                for spell in spells {
+                                for spell in &spells {
                                     row.spawn(Node {
                                         width: Val::Px(SPELL_COLUMN_WIDTH),
                                         height: Val::Percent(100.0),
@@ -138,14 +201,14 @@ pub fn spawn_spell_book_ui(mut commands: Commands) {
                                 ..default()
                             })
                             .with_children(|row| {
-                                for spell in spells {
+                                for spell in &spells {
                                     row.spawn((
                                         Text::new(spell.description()),
                                         TextFont {
                                             font_size: DESCRIPTION_FONT_SIZE,
                                             ..default()
                                         },
-                                        TextColor(TEXT_COLOR),
+                                        TextColor(palette.text),
                                         TextLayout::new_with_justify(Justify::Center),
                                         Node {
                                             width: Val::Px(SPELL_COLUMN_WIDTH),
@@ -164,6 +227,7 @@ pub fn spawn_spell_book_ui(mut commands: Commands) {
                 "Close",
                 SpellBookButtonAction::Close,
                 &CLOSE_BUTTON_STYLE,
+                palette,
             );
         });
 }
@@ -174,6 +238,7 @@ fn spawn_spell_button(
     text: &str,
     action: impl Component,
     style: &ButtonStyle,
+    palette: &UiPalette,
     font_size: f32,
 ) {
     parent
@@ -187,12 +252,12 @@ fn spawn_spell_button(
                 align_items: AlignItems::Center,
                 ..default()
             },
-            BorderColor::all(style.border),
+            BorderColor::all(palette.button_border),
             BorderRadius::all(Val::Px(8.0)),
-            BackgroundColor(style.background),
+            BackgroundColor(palette.button_background),
             ButtonColors {
-                background: style.background,
-                border: style.border,
+                background: palette.button_background,
+                border: palette.button_border,
             },
             action,
         ))
@@ -203,7 +268,7 @@ fn spawn_spell_button(
                     font_size,
                     ..default()
                 },
-                TextColor(style.text_color),
+                TextColor(palette.text),
                 TextLayout::new_with_justify(Justify::Center),
             ));
         });
@@ -212,6 +277,7 @@ fn spawn_spell_button(
 /// Handles mouse wheel scrolling for the spell book container.
 pub fn handle_spell_scroll(
     mut mouse_wheel_events: MessageReader<MouseWheel>,
+    config: Res<GameConfig>,
     hover_map: Res<bevy::picking::hover::HoverMap>,
     mut scrollable_query: Query<
         (&mut ScrollPosition, &ComputedNode),
@@ -219,14 +285,8 @@ pub fn handle_spell_scroll(
     >,
     parent_query: Query<&ChildOf>,
 ) {
-    const LINE_HEIGHT: f32 = 10.0;
-    const PIXEL_SCROLL_MULTIPLIER: f32 = 0.3;
-
     for event in mouse_wheel_events.read() {
-        let dx = match event.unit {
-            bevy::input::mouse::MouseScrollUnit::Line => -event.y * LINE_HEIGHT,
-            bevy::input::mouse::MouseScrollUnit::Pixel => -event.y * PIXEL_SCROLL_MULTIPLIER,
-        };
+        let dx = wheel_scroll_delta(event, &config);
 
         for pointer_map in hover_map.values() {
             for (hovered_entity, _) in pointer_map.iter() {
@@ -257,6 +317,9 @@ pub fn handle_spell_scroll(
 
 /// Handles button click actions and sends prime spell messages.
 /// Uses a marker component to ensure buttons only trigger on release after being pressed.
+///
+/// Starring or reordering a spell updates the saved order and rebuilds the book,
+/// keeping the current scroll position.
 pub fn button_action(
     mut commands: Commands,
     interaction_query: Query<
@@ -270,59 +333,69 @@ pub fn button_action(
     >,
     mut prime_spell: MessageWriter<PrimeSpellMessage>,
     mut next_in_game_state: ResMut<NextState<InGameState>>,
+    mut config: ResMut<GameConfig>,
+    screen_query: Query<Entity, With<OnSpellBookScreen>>,
+    scroll_query: Query<&ScrollPosition, With<ScrollableSpellContainer>>,
 ) {
     for (entity, interaction, action, pressed_down) in &interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 // Mark button as pressed down
                 commands.entity(entity).insert(ButtonPressedDown);
+                continue;
             }
-            Interaction::Hovered => {
+            // Trigger action on release (touch goes Pressed → None, skipping Hovered)
+            Interaction::Hovered | Interaction::None => {
                 // Only trigger action if button was previously pressed
-                if pressed_down.is_some() {
-                    commands.entity(entity).remove::<ButtonPressedDown>();
-
-                    match action {
-                        SpellBookButtonAction::SelectSpell(spell) => {
-                            prime_spell.write(PrimeSpellMessage {
-                                spell: spell.primed_config(),
-                            });
-                            next_in_game_state.set(InGameState::Running);
-                        }
-                        SpellBookButtonAction::Close => {
-                            next_in_game_state.set(InGameState::Running);
-                        }
-                    }
+                if pressed_down.is_none() {
+                    continue;
                 }
+                commands.entity(entity).remove::<ButtonPressedDown>();
             }
-            Interaction::None => {
-                // Trigger action on release (touch goes Pressed → None, skipping Hovered)
-                if pressed_down.is_some() {
-                    commands.entity(entity).remove::<ButtonPressedDown>();
+        }
 
-                    match action {
-                        SpellBookButtonAction::SelectSpell(spell) => {
-                            prime_spell.write(PrimeSpellMessage {
-                                spell: spell.primed_config(),
-                            });
-                            next_in_game_state.set(InGameState::Running);
-                        }
-                        SpellBookButtonAction::Close => {
-                            next_in_game_state.set(InGameState::Running);
-                        }
-                    }
-                }
+        let reordered = match *action {
+            SpellBookButtonAction::SelectSpell(spell) => {
+                prime_spell.write(PrimeSpellMessage {
+                    spell: spell.primed_config(),
+                });
+                next_in_game_state.set(InGameState::Running);
+                false
+            }
+            SpellBookButtonAction::ToggleFavorite(spell) => {
+                config.toggle_favorite(spell);
+                true
+            }
+            SpellBookButtonAction::MoveEarlier(spell) => {
+                config.move_spell(spell, -1);
+                true
+            }
+            SpellBookButtonAction::MoveLater(spell) => {
+                config.move_spell(spell, 1);
+                true
+            }
+            SpellBookButtonAction::Close => {
+                next_in_game_state.set(InGameState::Running);
+                false
+            }
+        };
+
+        if reordered {
+            let scroll = scroll_query.iter().next().cloned().unwrap_or_default();
+            for screen_entity in &screen_query {
+                commands.entity(screen_entity).despawn();
             }
+            spawn_spell_book(&mut commands, &config, scroll);
         }
     }
 }
 
-/// Handles keyboard input (ESC to close).
+/// Handles keyboard input (ESC or B to close).
 pub fn keyboard_input(
     keys: Res<ButtonInput<KeyCode>>,
     mut next_in_game_state: ResMut<NextState<InGameState>>,
 ) {
-    if keys.just_pressed(KeyCode::Escape) {
+    if keys.any_just_pressed([KeyCode::Escape, KeyCode::KeyB]) {
         next_in_game_state.set(InGameState::Running);
     }
 }
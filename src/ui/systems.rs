@@ -1,9 +1,28 @@
 //! Shared UI systems used across all menus and screens.
 
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
 
 use super::components::{ButtonColors, ButtonStyle};
 use super::styles::{item_hovered, item_pressed};
+use super::theme::UiPalette;
+use crate::config::{GameConfig, MIN_SCROLL_SPEED};
+
+/// Converts a mouse wheel event into a scroll offset in pixels.
+///
+/// Applies the scroll direction and speed settings. Positive offsets scroll
+/// content forward (down or right) by default.
+pub fn wheel_scroll_delta(event: &MouseWheel, config: &GameConfig) -> f32 {
+    const LINE_HEIGHT: f32 = 10.0;
+    const PIXEL_SCROLL_MULTIPLIER: f32 = 0.3;
+
+    let delta = match event.unit {
+        MouseScrollUnit::Line => -event.y * LINE_HEIGHT,
+        MouseScrollUnit::Pixel => -event.y * PIXEL_SCROLL_MULTIPLIER,
+    };
+    let direction = if config.scroll_invert { -1.0 } else { 1.0 };
+    delta * direction * config.scroll_speed.max(MIN_SCROLL_SPEED)
+}
 
 /// Handles button interaction visual feedback for all buttons with `ButtonColors`.
 ///
@@ -45,12 +64,14 @@ pub fn button_interaction(
 /// * `parent` - The parent entity to spawn the button under
 /// * `text` - The button label text
 /// * `action` - Any component to attach as the button's action identifier
-/// * `style` - The `ButtonStyle` configuration for dimensions and colors
+/// * `style` - The `ButtonStyle` configuration for dimensions and font size
+/// * `palette` - The active theme's colors
 pub fn spawn_button(
     parent: &mut ChildSpawnerCommands,
     text: &str,
     action: impl Component,
     style: &ButtonStyle,
+    palette: &UiPalette,
 ) {
     parent
         .spawn((
@@ -63,12 +84,12 @@ pub fn spawn_button(
                 align_items: AlignItems::Center,
                 ..default()
             },
-            BorderColor::all(style.border),
+            BorderColor::all(palette.button_border),
             BorderRadius::all(Val::Px(8.0)),
-            BackgroundColor(style.background),
+            BackgroundColor(palette.button_background),
             ButtonColors {
-                background: style.background,
-                border: style.border,
+                background: palette.button_background,
+                border: palette.button_border,
             },
             action,
         ))
@@ -79,8 +100,44 @@ pub fn spawn_button(
                     font_size: style.font_size,
                     ..default()
                 },
-                TextColor(style.text_color),
+                TextColor(palette.text),
                 TextLayout::new_with_justify(Justify::Center),
             ));
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wheel(y: f32) -> MouseWheel {
+        MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.0,
+            y,
+            window: Entity::PLACEHOLDER,
+        }
+    }
+
+    #[test]
+    fn test_scroll_settings_invert_and_scale_the_wheel() {
+        let mut config = GameConfig::default();
+        assert_eq!(wheel_scroll_delta(&wheel(-1.0), &config), 10.0);
+
+        config.scroll_invert = true;
+        config.scroll_speed = 2.0;
+        assert_eq!(wheel_scroll_delta(&wheel(-1.0), &config), -20.0);
+    }
+
+    #[test]
+    fn test_zero_scroll_speed_still_scrolls() {
+        let config = GameConfig {
+            scroll_speed: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            wheel_scroll_delta(&wheel(-1.0), &config),
+            10.0 * MIN_SCROLL_SPEED
+        );
+    }
+}
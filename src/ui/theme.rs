@@ -0,0 +1,68 @@
+//! UI color themes.
+//!
+//! Menus and HUD buttons take their colors from the palette of the theme
+//! selected in `GameConfig::ui_theme`, resolved when the UI is spawned.
+
+use bevy::prelude::*;
+
+use crate::config::UiTheme;
+
+/// Colors shared by every menu screen and the HUD buttons.
+#[derive(Clone, Copy)]
+pub struct UiPalette {
+    /// Body and title text color.
+    pub text: Color,
+    /// Normal button background color.
+    pub button_background: Color,
+    /// Normal button border color.
+    pub button_border: Color,
+    /// Border color of the large menu buttons (main menu, game over, loadout navigation).
+    pub menu_button_border: Color,
+    /// Background of a selected option or equipped spell.
+    pub selected_background: Color,
+    /// Border of a selected option or equipped spell.
+    pub selected_border: Color,
+    /// Text color for warnings such as an invalid loadout.
+    pub warning: Color,
+}
+
+/// The standard palette: subtle grey buttons with light text.
+pub const DEFAULT_PALETTE: UiPalette = UiPalette {
+    text: Color::hsla(0.0, 0.0, 0.9, 1.0),
+    button_background: Color::hsla(0.0, 0.0, 0.15, 1.0),
+    button_border: Color::hsla(0.0, 0.0, 0.4, 1.0),
+    menu_button_border: Color::hsla(0.0, 0.0, 0.3, 1.0),
+    selected_background: Color::hsla(210.0, 0.7, 0.4, 1.0),
+    selected_border: Color::hsla(210.0, 0.8, 0.6, 1.0),
+    warning: Color::srgb(0.9, 0.4, 0.3),
+};
+
+/// High-contrast palette: black buttons, bright borders, and pure white text.
+pub const HIGH_CONTRAST_PALETTE: UiPalette = UiPalette {
+    text: Color::WHITE,
+    button_background: Color::BLACK,
+    button_border: Color::srgb(1.0, 1.0, 0.0),
+    menu_button_border: Color::srgb(1.0, 1.0, 0.0),
+    selected_background: Color::srgb(0.0, 0.25, 0.8),
+    selected_border: Color::WHITE,
+    warning: Color::srgb(1.0, 0.5, 0.0),
+};
+
+impl UiPalette {
+    /// Returns this palette with `menu_button_border` as the button border,
+    /// for spawning the large menu buttons.
+    pub const fn menu_buttons(&self) -> Self {
+        Self {
+            button_border: self.menu_button_border,
+            ..*self
+        }
+    }
+}
+
+/// Returns the palette for a theme.
+pub const fn palette(theme: UiTheme) -> &'static UiPalette {
+    match theme {
+        UiTheme::Default => &DEFAULT_PALETTE,
+        UiTheme::HighContrast => &HIGH_CONTRAST_PALETTE,
+    }
+}
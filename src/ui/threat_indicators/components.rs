@@ -0,0 +1,133 @@
+//! Threat indicator components and placement helpers.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use super::constants::*;
+
+/// One edge-of-screen arrow from the fixed pool, identified by its slot.
+#[derive(Component)]
+pub struct ThreatArrow(pub usize);
+
+/// An off-screen attacker near the wizard, in screen space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Threat {
+    /// Direction from the screen center toward the attacker (Y down).
+    pub direction: Vec2,
+    /// XZ distance from the attacker to the wizard.
+    pub distance: f32,
+}
+
+/// Keeps the nearest threat in each direction sector, nearest first, up to `max`.
+///
+/// Attackers approaching as a group share a sector, so a mob gets one arrow
+/// rather than one per unit.
+pub fn group_threats(threats: impl IntoIterator<Item = Threat>, max: usize) -> Vec<Threat> {
+    let mut nearest: [Option<Threat>; THREAT_SECTORS] = [None; THREAT_SECTORS];
+
+    for threat in threats {
+        let angle = threat.direction.y.atan2(threat.direction.x).rem_euclid(TAU);
+        let sector = ((angle / TAU) * THREAT_SECTORS as f32) as usize % THREAT_SECTORS;
+        if nearest[sector].is_none_or(|current| threat.distance < current.distance) {
+            nearest[sector] = Some(threat);
+        }
+    }
+
+    let mut grouped: Vec<Threat> = nearest.into_iter().flatten().collect();
+    grouped.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+    grouped.truncate(max);
+    grouped
+}
+
+/// Returns where an arrow pointing along `direction` sits on the screen edge.
+///
+/// The point is on the rectangle inset by `EDGE_MARGIN` from the viewport edges.
+pub fn edge_position(viewport_size: Vec2, direction: Vec2) -> Vec2 {
+    let center = viewport_size / 2.0;
+    let half = (center - Vec2::splat(EDGE_MARGIN)).max(Vec2::ZERO);
+
+    let scale_x = if direction.x != 0.0 {
+        half.x / direction.x.abs()
+    } else {
+        f32::INFINITY
+    };
+    let scale_y = if direction.y != 0.0 {
+        half.y / direction.y.abs()
+    } else {
+        f32::INFINITY
+    };
+
+    center + direction * scale_x.min(scale_y)
+}
+
+/// Returns how urgent a threat is, from 1.0 (at the wizard) to 0.0 (at the threat radius).
+pub fn proximity_intensity(distance: f32) -> f32 {
+    (1.0 - distance / THREAT_RADIUS).clamp(0.0, 1.0)
+}
+
+/// Returns the arrow color for a threat with the given intensity.
+pub fn threat_color(intensity: f32) -> Color {
+    FAR_THREAT_COLOR
+        .mix(&NEAR_THREAT_COLOR, intensity)
+        .with_alpha(FAR_THREAT_ALPHA + (1.0 - FAR_THREAT_ALPHA) * intensity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn threat(x: f32, y: f32, distance: f32) -> Threat {
+        Threat {
+            direction: Vec2::new(x, y),
+            distance,
+        }
+    }
+
+    #[test]
+    fn test_groups_keep_nearest_per_direction_and_cap() {
+        let threats = [
+            threat(1.0, 0.0, 900.0),
+            threat(1.0, 0.01, 300.0),
+            threat(-1.0, 0.0, 500.0),
+            threat(0.0, 1.0, 700.0),
+            threat(0.0, -1.0, 100.0),
+        ];
+
+        let grouped = group_threats(threats, 3);
+
+        assert_eq!(
+            grouped,
+            vec![
+                threat(0.0, -1.0, 100.0),
+                threat(1.0, 0.01, 300.0),
+                threat(-1.0, 0.0, 500.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edge_position_clamps_to_inset_rectangle() {
+        let size = Vec2::new(800.0, 600.0);
+
+        let right = edge_position(size, Vec2::new(1.0, 0.0));
+        assert_eq!(right, Vec2::new(800.0 - EDGE_MARGIN, 300.0));
+
+        let up = edge_position(size, Vec2::new(0.0, -5.0));
+        assert_eq!(up, Vec2::new(400.0, EDGE_MARGIN));
+
+        // A shallow diagonal hits the side edge before the top
+        let diagonal = edge_position(size, Vec2::new(2.0, -1.0));
+        assert_eq!(diagonal.x, 800.0 - EDGE_MARGIN);
+        assert!(diagonal.y > EDGE_MARGIN && diagonal.y < 300.0);
+    }
+
+    #[test]
+    fn test_intensity_rises_as_threats_close_in() {
+        assert_eq!(proximity_intensity(0.0), 1.0);
+        assert_eq!(proximity_intensity(THREAT_RADIUS), 0.0);
+        assert!(
+            proximity_intensity(THREAT_RADIUS * 0.25) > proximity_intensity(THREAT_RADIUS * 0.75)
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Threat indicator constants.
+
+use bevy::prelude::*;
+
+/// Attackers within this XZ distance of the wizard count as threats.
+pub const THREAT_RADIUS: f32 = 1500.0;
+
+/// Maximum number of arrows shown at once.
+pub const MAX_THREAT_ARROWS: usize = 4;
+
+/// Number of direction sectors threats are grouped into (one arrow per sector).
+pub const THREAT_SECTORS: usize = 12;
+
+/// Distance of the arrows from the screen edge in logical pixels.
+pub const EDGE_MARGIN: f32 = 30.0;
+
+/// Font size of the arrow glyph.
+pub const ARROW_FONT_SIZE: f32 = 36.0;
+
+/// Glyph drawn for each arrow, pointing right before rotation.
+pub const ARROW_GLYPH: &str = ">";
+
+/// Arrow color for the closest threats.
+pub const NEAR_THREAT_COLOR: Color = Color::srgb(1.0, 0.15, 0.1);
+
+/// Arrow color for threats at the edge of the threat radius.
+pub const FAR_THREAT_COLOR: Color = Color::srgb(1.0, 0.75, 0.2);
+
+/// Arrow opacity for threats at the edge of the threat radius.
+pub const FAR_THREAT_ALPHA: f32 = 0.35;
@@ -0,0 +1,9 @@
+//! Off-screen threat indicators.
+//!
+//! Shows arrows at the screen edge pointing toward attackers that are closing
+//! in on the wizard but can't be seen.
+
+mod components;
+mod constants;
+pub(super) mod plugin;
+mod systems;
@@ -0,0 +1,26 @@
+//! Threat indicator plugin.
+
+use bevy::prelude::*;
+
+use crate::state::InGameState;
+
+use super::systems;
+
+/// Plugin that points the player toward off-screen attackers near the wizard.
+///
+/// Registers systems for:
+/// - Spawning the pool of hidden edge arrows
+/// - Placing, rotating, and coloring arrows for the nearest off-screen threats
+#[derive(Default)]
+pub struct ThreatIndicatorsPlugin;
+
+impl Plugin for ThreatIndicatorsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (systems::spawn_threat_arrows, systems::update_threat_arrows)
+                .chain()
+                .run_if(in_state(InGameState::Running)),
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! Threat indicator systems.
+
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::*;
+use crate::game::components::OnGameplayScreen;
+use crate::game::units::components::{Corpse, Team};
+use crate::game::units::wizard::components::Wizard;
+
+/// Spawns the hidden arrow pool if it doesn't exist yet.
+///
+/// Arrows are tied to the gameplay screen, so a fresh pool is spawned each game.
+pub fn spawn_threat_arrows(mut commands: Commands, arrows: Query<(), With<ThreatArrow>>) {
+    if !arrows.is_empty() {
+        return;
+    }
+
+    for slot in 0..MAX_THREAT_ARROWS {
+        commands.spawn((
+            Text::new(ARROW_GLYPH),
+            TextFont {
+                font_size: ARROW_FONT_SIZE,
+                ..default()
+            },
+            TextColor(FAR_THREAT_COLOR),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            UiTransform::IDENTITY,
+            Visibility::Hidden,
+            Pickable::IGNORE,
+            ThreatArrow(slot),
+            OnGameplayScreen,
+        ));
+    }
+}
+
+/// Points edge arrows toward the nearest off-screen attackers within the threat radius.
+///
+/// Attackers are grouped by direction so a mob gets a single arrow. Closer
+/// threats get a redder, more opaque arrow.
+#[allow(clippy::type_complexity)]
+pub fn update_threat_arrows(
+    ui_scale: Res<UiScale>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    wizard_query: Query<&Transform, With<Wizard>>,
+    attackers: Query<(&Transform, &Team), Without<Corpse>>,
+    mut arrows: Query<(
+        &ThreatArrow,
+        &mut Node,
+        &mut UiTransform,
+        &mut TextColor,
+        &mut Visibility,
+    )>,
+) {
+    let threats = match (camera_query.single(), wizard_query.single()) {
+        (Ok((camera, camera_transform)), Ok(wizard_transform)) => {
+            camera.logical_viewport_size().map(|viewport_size| {
+                let wizard_pos = wizard_transform.translation.xz();
+                let center = viewport_size / 2.0;
+                let off_screen = attackers.iter().filter_map(|(transform, team)| {
+                    if *team != Team::Attackers {
+                        return None;
+                    }
+                    let distance = transform.translation.xz().distance(wizard_pos);
+                    if distance > THREAT_RADIUS {
+                        return None;
+                    }
+                    let screen_pos = camera
+                        .world_to_viewport(camera_transform, transform.translation)
+                        .ok()?;
+                    let on_screen =
+                        screen_pos.cmpge(Vec2::ZERO).all() && screen_pos.cmple(viewport_size).all();
+                    (!on_screen).then_some(Threat {
+                        direction: screen_pos - center,
+                        distance,
+                    })
+                });
+                (viewport_size, group_threats(off_screen, MAX_THREAT_ARROWS))
+            })
+        }
+        _ => None,
+    };
+
+    for (arrow, mut node, mut ui_transform, mut color, mut visibility) in &mut arrows {
+        let Some((viewport_size, threat)) = threats
+            .as_ref()
+            .and_then(|(size, threats)| threats.get(arrow.0).map(|threat| (*size, *threat)))
+        else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        let position = edge_position(viewport_size, threat.direction);
+        let half_glyph = ARROW_FONT_SIZE / 2.0;
+        // Viewport coordinates are logical pixels; UI Px values are scaled by UiScale
+        node.left = Val::Px((position.x - half_glyph) / ui_scale.0);
+        node.top = Val::Px((position.y - half_glyph) / ui_scale.0);
+        // UI rotation is clockwise, matching the Y-down screen angle
+        ui_transform.rotation = Rot2::radians(threat.direction.y.atan2(threat.direction.x));
+        color.0 = threat_color(proximity_intensity(threat.distance));
+        *visibility = Visibility::Inherited;
+    }
+}
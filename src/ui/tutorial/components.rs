@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+
+/// A single tutorial prompt, completed by performing its action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialStep {
+    /// Open the spell book.
+    OpenSpellBook,
+    /// Start casting the primed spell.
+    CastSpell,
+    /// Pause the game.
+    Pause,
+}
+
+impl TutorialStep {
+    /// Returns the prompt shown while this step is active.
+    pub fn prompt(&self) -> &'static str {
+        match self {
+            TutorialStep::OpenSpellBook => "Press B or click Spells to open the spell book",
+            TutorialStep::CastSpell => "Hold left-click on the battlefield to cast your spell",
+            TutorialStep::Pause => "Press Escape at any time to pause",
+        }
+    }
+
+    /// Returns the step after this one, or None if this is the last.
+    pub fn next(&self) -> Option<Self> {
+        match self {
+            TutorialStep::OpenSpellBook => Some(TutorialStep::CastSpell),
+            TutorialStep::CastSpell => Some(TutorialStep::Pause),
+            TutorialStep::Pause => None,
+        }
+    }
+}
+
+/// Progress through the first-run tutorial.
+#[derive(Resource, Default)]
+pub struct TutorialState {
+    /// Step currently shown, or None when the tutorial is not running.
+    pub step: Option<TutorialStep>,
+}
+
+impl TutorialState {
+    /// Starts the tutorial from the first step.
+    pub fn start(&mut self) {
+        self.step = Some(TutorialStep::OpenSpellBook);
+    }
+
+    /// Marks an action as performed.
+    ///
+    /// Only advances if `performed` is the current step, so doing things out of
+    /// order never skips a prompt. Returns true when this finishes the tutorial.
+    pub fn complete(&mut self, performed: TutorialStep) -> bool {
+        if self.step != Some(performed) {
+            return false;
+        }
+
+        self.step = performed.next();
+        self.step.is_none()
+    }
+}
+
+/// Marker component for the tutorial prompt container.
+#[derive(Component)]
+pub struct TutorialPrompt;
+
+/// Marker component for the tutorial prompt text.
+#[derive(Component)]
+pub struct TutorialPromptText;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tutorial_advances_through_steps() {
+        let mut tutorial = TutorialState::default();
+        tutorial.start();
+
+        assert!(!tutorial.complete(TutorialStep::OpenSpellBook));
+        assert_eq!(tutorial.step, Some(TutorialStep::CastSpell));
+        assert!(!tutorial.complete(TutorialStep::CastSpell));
+        assert_eq!(tutorial.step, Some(TutorialStep::Pause));
+        assert!(tutorial.complete(TutorialStep::Pause));
+        assert_eq!(tutorial.step, None);
+    }
+
+    #[test]
+    fn test_out_of_order_action_does_not_skip() {
+        let mut tutorial = TutorialState::default();
+        tutorial.start();
+
+        assert!(!tutorial.complete(TutorialStep::Pause));
+        assert_eq!(tutorial.step, Some(TutorialStep::OpenSpellBook));
+    }
+
+    #[test]
+    fn test_inactive_tutorial_ignores_actions() {
+        let mut tutorial = TutorialState::default();
+        assert!(!tutorial.complete(TutorialStep::OpenSpellBook));
+        assert_eq!(tutorial.step, None);
+    }
+}
@@ -0,0 +1,18 @@
+//! Tutorial prompt constants.
+
+use bevy::prelude::*;
+
+/// Distance of the prompt from the top of the screen.
+pub const PROMPT_TOP: Val = Val::Percent(18.0);
+
+/// Padding inside the prompt box.
+pub const PROMPT_PADDING: Val = Val::Px(12.0);
+
+/// Font size for the prompt text.
+pub const PROMPT_FONT_SIZE: f32 = 24.0;
+
+/// Background color of the prompt box.
+pub const PROMPT_BACKGROUND_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+
+/// Text color of the prompt.
+pub const PROMPT_TEXT_COLOR: Color = Color::srgb(1.0, 0.9, 0.5);
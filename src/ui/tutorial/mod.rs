@@ -0,0 +1,8 @@
+//! First-run tutorial.
+//!
+//! Shows contextual prompts that advance as the player performs each action.
+
+mod components;
+mod constants;
+pub(super) mod plugin;
+mod systems;
@@ -0,0 +1,39 @@
+//! Tutorial plugin.
+
+use bevy::prelude::*;
+
+use crate::state::{AppState, InGameState};
+
+use super::components::TutorialState;
+use super::systems;
+
+/// Plugin that walks first-time players through the basic controls.
+///
+/// Registers systems for:
+/// - Starting the tutorial on game start (unless already completed)
+/// - Completing steps as the player opens the spell book, casts, and pauses
+/// - Showing the current prompt during gameplay
+#[derive(Default)]
+pub struct TutorialPlugin;
+
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TutorialState>()
+            .add_systems(OnEnter(AppState::InGame), systems::start_tutorial)
+            .add_systems(OnExit(AppState::InGame), systems::stop_tutorial)
+            .add_systems(
+                OnEnter(InGameState::SpellBook),
+                systems::complete_open_spell_book,
+            )
+            .add_systems(OnEnter(InGameState::Paused), systems::complete_pause)
+            .add_systems(
+                Update,
+                (
+                    systems::complete_cast_spell,
+                    systems::update_tutorial_prompt,
+                )
+                    .chain()
+                    .run_if(in_state(InGameState::Running)),
+            );
+    }
+}
@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+
+use super::components::*;
+use super::constants::*;
+use crate::config::GameConfig;
+use crate::game::components::OnGameplayScreen;
+use crate::game::units::wizard::components::{CastingState, Wizard};
+
+/// Starts the tutorial at the beginning of a game if it hasn't been completed.
+pub fn start_tutorial(config: Res<GameConfig>, mut tutorial: ResMut<TutorialState>) {
+    if config.tutorial_completed {
+        tutorial.step = None;
+    } else {
+        tutorial.start();
+    }
+}
+
+/// Stops the tutorial when leaving the game.
+pub fn stop_tutorial(mut tutorial: ResMut<TutorialState>) {
+    tutorial.step = None;
+}
+
+/// Completes the spell book step when the spell book opens.
+pub fn complete_open_spell_book(tutorial: ResMut<TutorialState>, config: ResMut<GameConfig>) {
+    complete_step(tutorial, config, TutorialStep::OpenSpellBook);
+}
+
+/// Completes the casting step once the wizard starts casting or channeling.
+pub fn complete_cast_spell(
+    tutorial: ResMut<TutorialState>,
+    config: ResMut<GameConfig>,
+    wizard_query: Query<&CastingState, With<Wizard>>,
+) {
+    if tutorial.step == Some(TutorialStep::CastSpell)
+        && wizard_query
+            .single()
+            .is_ok_and(|state| !matches!(state, CastingState::Resting))
+    {
+        complete_step(tutorial, config, TutorialStep::CastSpell);
+    }
+}
+
+/// Completes the pause step when the game is paused.
+pub fn complete_pause(tutorial: ResMut<TutorialState>, config: ResMut<GameConfig>) {
+    complete_step(tutorial, config, TutorialStep::Pause);
+}
+
+/// Shows, updates, or removes the prompt to match the current step.
+///
+/// Also respawns the prompt after the gameplay screen is cleaned up for a replay.
+pub fn update_tutorial_prompt(
+    mut commands: Commands,
+    tutorial: Res<TutorialState>,
+    prompt_query: Query<Entity, With<TutorialPrompt>>,
+    mut text_query: Query<&mut Text, With<TutorialPromptText>>,
+) {
+    let Some(step) = tutorial.step else {
+        for entity in &prompt_query {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    if prompt_query.is_empty() {
+        spawn_tutorial_prompt(&mut commands, step.prompt());
+    } else if tutorial.is_changed()
+        && let Ok(mut text) = text_query.single_mut()
+    {
+        **text = step.prompt().to_string();
+    }
+}
+
+/// Advances the tutorial and persists completion after the last step.
+fn complete_step(
+    mut tutorial: ResMut<TutorialState>,
+    mut config: ResMut<GameConfig>,
+    step: TutorialStep,
+) {
+    if tutorial.complete(step) {
+        config.tutorial_completed = true;
+    }
+}
+
+/// Spawns the prompt box near the top center of the screen.
+fn spawn_tutorial_prompt(commands: &mut Commands, prompt: &str) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: PROMPT_TOP,
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            TutorialPrompt,
+            OnGameplayScreen,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        padding: UiRect::all(PROMPT_PADDING),
+                        ..default()
+                    },
+                    BackgroundColor(PROMPT_BACKGROUND_COLOR),
+                ))
+                .with_children(|prompt_box| {
+                    prompt_box.spawn((
+                        Text::new(prompt),
+                        TextFont {
+                            font_size: PROMPT_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(PROMPT_TEXT_COLOR),
+                        TutorialPromptText,
+                    ));
+                });
+        });
+}